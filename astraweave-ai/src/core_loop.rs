@@ -14,7 +14,10 @@ use astraweave_core::{PlanIntent, WorldSnapshot};
 #[cfg(feature = "ai-goap")]
 use astraweave_behavior::goap::{GoapAction, GoapGoal, GoapPlanner, WorldState};
 
-#[cfg(feature = "ai-goap")]
+#[cfg(feature = "ai-bt")]
+use astraweave_behavior::{BehaviorContext, BehaviorGraph, BehaviorNode, BehaviorStatus};
+
+#[cfg(any(feature = "ai-goap", feature = "ai-bt"))]
 use astraweave_core::ActionStep;
 
 use crate::orchestrator::{Orchestrator, RuleOrchestrator};
@@ -269,13 +272,106 @@ pub fn dispatch_planner(
 }
 
 /// Dispatch to behavior tree planner (feature-gated).
+///
+/// Builds a small default tree — `Selector[Sequence[enemy_visible,
+/// cover_fire], advance]` — the same shape as [`RuleOrchestrator`]'s logic,
+/// but expressed as an authored [`BehaviorNode`] tree so it can be replaced
+/// or extended (e.g. with a [`register_llm_leaf`] leaf) without touching
+/// this dispatcher. Each leaf action pushes its [`ActionStep`] into a shared
+/// buffer as it ticks; the buffer becomes the resulting plan's steps.
 #[cfg(feature = "ai-bt")]
-fn dispatch_bt(_controller: &CAiController, _snapshot: &WorldSnapshot) -> Result<PlanIntent> {
-    // TODO: Implement BT integration
-    // 1. Set up Blackboard from WorldSnapshot
-    // 2. Tick behavior tree
-    // 3. Convert BT outputs → ActionStep sequence
-    anyhow::bail!("BehaviorTree integration not yet implemented")
+fn dispatch_bt(_controller: &CAiController, snapshot: &WorldSnapshot) -> Result<PlanIntent> {
+    #[cfg(feature = "profiling")]
+    span!("AI::dispatch_bt");
+
+    let steps: std::sync::Arc<std::sync::Mutex<Vec<ActionStep>>> =
+        std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mut ctx = BehaviorContext::new();
+
+    let enemy_visible = !snapshot.enemies.is_empty();
+    ctx.register_condition("enemy_visible", move || enemy_visible);
+
+    if let Some(target) = snapshot.enemies.first() {
+        let steps = steps.clone();
+        let target_id = target.id;
+        ctx.register_action("cover_fire", move || {
+            steps.lock().unwrap().push(ActionStep::CoverFire {
+                target_id,
+                duration: 2.0,
+            });
+            BehaviorStatus::Success
+        });
+    }
+
+    {
+        let steps = steps.clone();
+        let me = snapshot.me.pos;
+        let target = snapshot.enemies.first().map(|e| e.pos).unwrap_or(me);
+        ctx.register_action("advance", move || {
+            steps.lock().unwrap().push(ActionStep::MoveTo {
+                x: me.x + (target.x - me.x).signum(),
+                y: me.y + (target.y - me.y).signum(),
+                speed: None,
+            });
+            BehaviorStatus::Success
+        });
+    }
+
+    let tree = BehaviorGraph::new(BehaviorNode::selector(vec![
+        BehaviorNode::sequence(vec![
+            BehaviorNode::condition("enemy_visible"),
+            BehaviorNode::action("cover_fire"),
+        ]),
+        BehaviorNode::action("advance"),
+    ]));
+
+    let status = tree.tick(&ctx);
+    if status.is_failure() {
+        anyhow::bail!("BehaviorTree ticked to Failure with no plan produced");
+    }
+
+    Ok(PlanIntent {
+        plan_id: format!("bt-{}", (snapshot.t * 1000.0) as i64),
+        steps: steps.lock().unwrap().clone(),
+    })
+}
+
+/// Registers a leaf action named `action_name` on `ctx` that calls
+/// [`astraweave_llm::plan_from_llm`] and stashes the resulting plan in
+/// `result`, so an authored behavior tree can blend rule-driven branches
+/// with an LLM-driven leaf (e.g. `Selector[authored_branch, llm_leaf]`).
+/// The call blocks the ticking thread on a dedicated Tokio runtime — the
+/// same tradeoff [`crate::orchestrator::make_system_orchestrator`] makes for
+/// its background warmup — because [`BehaviorContext`] actions are
+/// synchronous.
+#[cfg(all(feature = "ai-bt", feature = "llm_orchestrator"))]
+pub fn register_llm_leaf<C>(
+    ctx: &mut BehaviorContext,
+    action_name: &str,
+    client: C,
+    registry: astraweave_core::ToolRegistry,
+    snapshot: WorldSnapshot,
+    budget_ms: u32,
+    result: std::sync::Arc<std::sync::Mutex<Option<PlanIntent>>>,
+) where
+    C: astraweave_llm::LlmClient + Send + Sync + 'static,
+{
+    use crate::orchestrator::{LlmOrchestrator, OrchestratorAsync};
+
+    let orch = LlmOrchestrator::new(client, Some(registry));
+    ctx.register_action(action_name, move || {
+        let plan = tokio::runtime::Runtime::new()
+            .ok()
+            .and_then(|rt| rt.block_on(orch.plan(snapshot.clone(), budget_ms)).ok());
+        match plan {
+            Some(plan) => {
+                *result.lock().unwrap() = Some(plan);
+                BehaviorStatus::Success
+            }
+            None => BehaviorStatus::Failure,
+        }
+    });
 }
 
 /// Dispatch to GOAP planner (feature-gated).
@@ -466,6 +562,40 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "ai-bt")]
+    fn test_dispatch_bt_mode_with_enemy_covers() {
+        let controller = CAiController {
+            mode: PlannerMode::BehaviorTree,
+            policy: None,
+        };
+
+        let snapshot = make_test_snapshot();
+        let result = dispatch_planner(&controller, &snapshot);
+        assert!(result.is_ok());
+        let plan = result.unwrap();
+        assert!(!plan.steps.is_empty());
+        assert!(plan.plan_id.starts_with("bt-"));
+        assert!(matches!(plan.steps[0], astraweave_core::ActionStep::CoverFire { .. }));
+    }
+
+    #[test]
+    #[cfg(feature = "ai-bt")]
+    fn test_dispatch_bt_mode_without_enemy_advances() {
+        let controller = CAiController {
+            mode: PlannerMode::BehaviorTree,
+            policy: None,
+        };
+
+        let mut snapshot = make_test_snapshot();
+        snapshot.enemies.clear();
+        let result = dispatch_planner(&controller, &snapshot);
+        assert!(result.is_ok());
+        let plan = result.unwrap();
+        assert!(!plan.steps.is_empty());
+        assert!(matches!(plan.steps[0], astraweave_core::ActionStep::MoveTo { .. }));
+    }
+
     #[test]
     #[cfg(feature = "ai-goap")]
     fn test_dispatch_goap_mode() {
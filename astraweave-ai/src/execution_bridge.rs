@@ -0,0 +1,597 @@
+//! Execution bridge: drives a [`CActivePlan`] forward one [`ActionStep`] at a
+//! time, mapping each step to concrete ECS commands (e.g. [`CDesiredPos`],
+//! [`CAttackIntent`]) instead of leaving that translation to ad-hoc code in
+//! [`crate::ecs_ai_plugin`].
+//!
+//! Attach a plan with [`start_plan`]; run [`sys_execute_active_plans`] in the
+//! `simulation` stage (after `ai_planning`, before `physics`) to advance it.
+//! Interrupt a plan in progress with [`abort_plan`]. Progress is surfaced via
+//! [`astraweave_core::PlanStepCompletedEvent`], [`astraweave_core::PlanCompletedEvent`],
+//! and [`astraweave_core::PlanAbortedEvent`] so a snapshot builder can fold
+//! the outcome of the previous tick's actions into the next [`WorldSnapshot`](astraweave_core::WorldSnapshot).
+//!
+//! Cost-bearing steps (attacks, dodges, throws, abilities) are checked
+//! against [`astraweave_core::constraint_engine`] before they're applied,
+//! using the entity's [`CCooldowns`]/[`CStamina`] and the
+//! [`ConstraintEnforcement`] resource; a step that's still on cooldown or
+//! under-resourced aborts the plan the same way an unsupported step would.
+//!
+//! A step that fails outright — unsupported, cost-blocked, its target
+//! already dead, or stuck past [`MaxStepDuration`] — aborts the plan via
+//! [`fail_plan`], which attaches a [`astraweave_core::CReplanRequest`]
+//! carrying a structured [`astraweave_core::PlanFailureKind`] so a planning
+//! system can react to *why* it died instead of re-parsing
+//! [`astraweave_core::PlanAbortedEvent`]'s free-form string.
+
+use astraweave_core::constraint_engine::{action_cost, check_action_cost};
+use astraweave_core::ecs_events::{Events, PlanAbortedEvent, PlanCompletedEvent, PlanStepCompletedEvent};
+use astraweave_core::{
+    ActionStep, CActivePlan, CAttackIntent, CCooldowns, CDesiredPos, CHealth, CPos, CReplanRequest,
+    CStamina, Constraints, PlanFailureKind, PlanIntent, PlanStepStatus,
+};
+use astraweave_ecs as ecs;
+
+/// Per-step time budget, in seconds, enforced by [`sys_execute_active_plans`].
+/// A step still `Pending` after this long fails with
+/// [`PlanFailureKind::PathBlocked`] (for `MoveTo`) or
+/// [`PlanFailureKind::Timeout`] (everything else) instead of stalling the
+/// plan forever.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxStepDuration(pub f32);
+
+impl Default for MaxStepDuration {
+    fn default() -> Self {
+        Self(8.0)
+    }
+}
+
+/// Simulation-stage tick length, in seconds, consumed by [`sys_execute_active_plans`]
+/// for duration-based steps like `Wait`. Mirrors the role of `InterpolationAlpha`
+/// in `astraweave-physics`'s ECS integration: a small resource the app builder
+/// inserts once and systems read each tick.
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutionDt(pub f32);
+
+impl Default for ExecutionDt {
+    fn default() -> Self {
+        Self(0.016)
+    }
+}
+
+/// Which of [`Constraints`]'s cooldown/stamina flags `sys_execute_active_plans`
+/// enforces via `astraweave_core::constraint_engine`. Insert once at app
+/// build time; defaults to the same all-enforced posture as
+/// `astraweave_core::default_tool_registry`.
+#[derive(Clone, Debug)]
+pub struct ConstraintEnforcement(pub Constraints);
+
+impl Default for ConstraintEnforcement {
+    fn default() -> Self {
+        Self(Constraints {
+            enforce_cooldowns: true,
+            enforce_los: true,
+            enforce_stamina: true,
+        })
+    }
+}
+
+/// Starts executing `plan` for `entity`, replacing whatever plan it was
+/// previously running (without emitting an abort event for the replaced
+/// plan; use [`abort_plan`] first if that distinction matters to callers).
+pub fn start_plan(world: &mut ecs::World, entity: ecs::Entity, plan: PlanIntent) {
+    world.insert(entity, CActivePlan::new(plan));
+}
+
+/// Interrupts `entity`'s active plan, if any, marking it aborted and emitting
+/// a [`PlanAbortedEvent`] with `reason`. Any in-flight step command
+/// (`CDesiredPos`, `CAttackIntent`) is left as-is; callers that need movement
+/// to stop immediately should also clear those components.
+pub fn abort_plan(world: &mut ecs::World, entity: ecs::Entity, reason: impl Into<String>) {
+    let Some(plan) = world.get_mut::<CActivePlan>(entity) else {
+        return;
+    };
+    if plan.status == PlanStepStatus::Completed || plan.status == PlanStepStatus::Aborted {
+        return;
+    }
+    plan.status = PlanStepStatus::Aborted;
+    let plan_id = plan.plan_id.clone();
+    let step_index = plan.current_index;
+    let reason = reason.into();
+
+    if world.get_resource::<Events<PlanAbortedEvent>>().is_none() {
+        world.insert_resource(Events::<PlanAbortedEvent>::default());
+    }
+    if let Some(ev) = world.get_resource_mut::<Events<PlanAbortedEvent>>() {
+        ev.writer().send(PlanAbortedEvent {
+            entity,
+            plan_id,
+            step_index,
+            reason,
+        });
+    }
+}
+
+/// Interrupts `entity`'s active plan the same way [`abort_plan`] does, and
+/// additionally attaches a [`CReplanRequest`] carrying `kind` so a planning
+/// system can react to *why* the plan died instead of re-parsing
+/// [`PlanAbortedEvent`]'s free-form string. Use this for failures detected
+/// during execution (unsupported step, blocked cost, dead target, path
+/// blocked, timeout); use [`abort_plan`] directly for caller-initiated
+/// interruptions (e.g. a higher-priority plan replacing this one) that
+/// don't need a replan requested on the entity's behalf.
+pub fn fail_plan(world: &mut ecs::World, entity: ecs::Entity, kind: PlanFailureKind) {
+    abort_plan(world, entity, kind.to_string());
+    world.insert(entity, CReplanRequest { reason: kind });
+}
+
+/// Outcome of driving one entity's current step forward this tick.
+enum StepOutcome {
+    /// Step needs more ticks; nothing to report yet.
+    Pending,
+    /// Step resolved this tick; advance to the next one.
+    Completed,
+    /// Step failed outright; abort the whole plan and request a replan via
+    /// [`fail_plan`].
+    Failed(PlanFailureKind),
+}
+
+/// Consults `astraweave_core::constraint_engine::check_action_cost` for
+/// `step` against `entity`'s [`CCooldowns`]/[`CStamina`] (both optional —
+/// entities without them are treated as having no active cooldowns and
+/// untracked stamina, matching `sanitize_plan`'s `stamina: None`), and on
+/// success applies the cost: starts the cooldown and spends the stamina.
+fn pay_action_cost(world: &mut ecs::World, entity: ecs::Entity, step: &ActionStep) -> Result<(), String> {
+    let cost = action_cost(step);
+    if cost == astraweave_core::constraint_engine::ActionCost::NONE {
+        return Ok(());
+    }
+
+    let constraints = world
+        .get_resource::<ConstraintEnforcement>()
+        .map(|c| c.0.clone())
+        .unwrap_or_default();
+    let cooldowns = world
+        .get::<CCooldowns>(entity)
+        .map(|c| c.map.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+        .unwrap_or_default();
+    let stamina = world.get::<CStamina>(entity).map(|s| s.current);
+
+    check_action_cost(&cooldowns, stamina, step, &constraints).map_err(|e| e.to_string())?;
+
+    if let Some(key) = cost.cooldown_key {
+        if let Some(cds) = world.get_mut::<CCooldowns>(entity) {
+            cds.map.insert(key.into(), cost.cooldown_seconds);
+        }
+    }
+    if let Some(stamina) = world.get_mut::<CStamina>(entity) {
+        stamina.spend(cost.stamina_cost);
+    }
+    Ok(())
+}
+
+/// Applies the ECS-command side effects of `step` for `entity` and reports
+/// whether it finished this tick. Steps without a dedicated ECS command here
+/// fail with [`PlanFailureKind::Unsupported`].
+fn drive_step(world: &mut ecs::World, entity: ecs::Entity, step: &ActionStep, step_elapsed: f32, dt: f32) -> StepOutcome {
+    let max_step_duration = world.get_resource::<MaxStepDuration>().copied().unwrap_or_default().0;
+    if step_elapsed + dt > max_step_duration {
+        return StepOutcome::Failed(match step {
+            ActionStep::MoveTo { .. } => PlanFailureKind::PathBlocked,
+            _ => PlanFailureKind::Timeout,
+        });
+    }
+
+    match step {
+        ActionStep::MoveTo { x, y, .. } => {
+            let target = astraweave_core::IVec2 { x: *x, y: *y };
+            world.insert(entity, CDesiredPos { pos: target });
+            match world.get::<CPos>(entity) {
+                Some(pos) if pos.pos.x == target.x && pos.pos.y == target.y => StepOutcome::Completed,
+                Some(_) => StepOutcome::Pending,
+                None => StepOutcome::Completed,
+            }
+        }
+        ActionStep::Attack { target_id }
+        | ActionStep::AimedShot { target_id }
+        | ActionStep::QuickAttack { target_id }
+        | ActionStep::HeavyAttack { target_id }
+        | ActionStep::Charge { target_id } => {
+            match world.get::<CHealth>(*target_id) {
+                Some(hp) if hp.hp <= 0 => return StepOutcome::Failed(PlanFailureKind::TargetDead),
+                _ => {}
+            }
+            if let Err(reason) = pay_action_cost(world, entity, step) {
+                return StepOutcome::Failed(PlanFailureKind::Blocked(reason));
+            }
+            world.insert(
+                entity,
+                CAttackIntent {
+                    target_id: *target_id,
+                    kind: step.action_name().to_string(),
+                },
+            );
+            StepOutcome::Completed
+        }
+        ActionStep::ThrowSmoke { .. }
+        | ActionStep::ThrowExplosive { .. }
+        | ActionStep::Dodge { .. }
+        | ActionStep::CoverFire { .. }
+        | ActionStep::UseAbility { .. }
+        | ActionStep::UseDefensiveAbility { .. } => {
+            if let Err(reason) = pay_action_cost(world, entity, step) {
+                return StepOutcome::Failed(PlanFailureKind::Blocked(reason));
+            }
+            StepOutcome::Completed
+        }
+        ActionStep::Wait { duration } => {
+            if step_elapsed + dt >= *duration {
+                StepOutcome::Completed
+            } else {
+                StepOutcome::Pending
+            }
+        }
+        ActionStep::Reload => StepOutcome::Completed,
+        _ => StepOutcome::Failed(PlanFailureKind::Unsupported(step.action_name().to_string())),
+    }
+}
+
+/// Advances every entity's [`CActivePlan`] by one tick: applies the current
+/// step's ECS-side effects via `drive_step`, and on completion either moves
+/// to the next step (emitting [`PlanStepCompletedEvent`]) or finishes the
+/// plan (emitting [`PlanCompletedEvent`]). Steps whose action isn't yet
+/// bridged to an ECS command abort the plan via [`abort_plan`] rather than
+/// silently skipping it.
+pub fn sys_execute_active_plans(world: &mut ecs::World) {
+    let dt = world.get_resource::<ExecutionDt>().copied().unwrap_or_default().0;
+
+    for entity in world.entities_with::<CActivePlan>() {
+        let (status, step, step_elapsed) = {
+            let Some(plan) = world.get::<CActivePlan>(entity) else {
+                continue;
+            };
+            (plan.status, plan.current_step().cloned(), plan.step_elapsed)
+        };
+
+        if status != PlanStepStatus::Pending && status != PlanStepStatus::InProgress {
+            continue;
+        }
+        let Some(step) = step else {
+            continue;
+        };
+
+        match drive_step(world, entity, &step, step_elapsed, dt) {
+            StepOutcome::Pending => {
+                if let Some(plan) = world.get_mut::<CActivePlan>(entity) {
+                    plan.status = PlanStepStatus::InProgress;
+                    plan.step_elapsed += dt;
+                }
+            }
+            StepOutcome::Completed => {
+                let (plan_id, next_index, is_done) = {
+                    let plan = world.get_mut::<CActivePlan>(entity).expect("plan present");
+                    plan.current_index += 1;
+                    plan.step_elapsed = 0.0;
+                    let is_done = plan.current_index >= plan.steps.len();
+                    plan.status = if is_done {
+                        PlanStepStatus::Completed
+                    } else {
+                        PlanStepStatus::Pending
+                    };
+                    (plan.plan_id.clone(), plan.current_index, is_done)
+                };
+
+                if is_done {
+                    if world.get_resource::<Events<PlanCompletedEvent>>().is_none() {
+                        world.insert_resource(Events::<PlanCompletedEvent>::default());
+                    }
+                    if let Some(ev) = world.get_resource_mut::<Events<PlanCompletedEvent>>() {
+                        ev.writer().send(PlanCompletedEvent { entity, plan_id });
+                    }
+                } else {
+                    if world.get_resource::<Events<PlanStepCompletedEvent>>().is_none() {
+                        world.insert_resource(Events::<PlanStepCompletedEvent>::default());
+                    }
+                    if let Some(ev) = world.get_resource_mut::<Events<PlanStepCompletedEvent>>() {
+                        ev.writer().send(PlanStepCompletedEvent {
+                            entity,
+                            plan_id,
+                            step_index: next_index - 1,
+                        });
+                    }
+                }
+            }
+            StepOutcome::Failed(kind) => {
+                fail_plan(world, entity, kind);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::IVec2;
+
+    fn plan_with(steps: Vec<ActionStep>) -> PlanIntent {
+        PlanIntent {
+            plan_id: "test-plan".to_string(),
+            steps,
+        }
+    }
+
+    #[test]
+    fn move_to_completes_once_position_matches() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        world.insert(e, CPos { pos: IVec2 { x: 0, y: 0 } });
+        start_plan(&mut world, e, plan_with(vec![ActionStep::MoveTo { x: 5, y: 5, speed: None }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::InProgress
+        );
+        assert_eq!(world.get::<CDesiredPos>(e).unwrap().pos, IVec2 { x: 5, y: 5 });
+
+        world.insert(e, CPos { pos: IVec2 { x: 5, y: 5 } });
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn attack_step_completes_immediately_and_sets_intent() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Attack { target_id: 42 }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(world.get::<CAttackIntent>(e).unwrap().target_id, 42);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn wait_step_completes_after_duration_elapses() {
+        let mut world = ecs::World::new();
+        world.insert_resource(ExecutionDt(0.5));
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Wait { duration: 1.0 }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::InProgress
+        );
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn multi_step_plan_emits_completion_events_in_order() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(
+            &mut world,
+            e,
+            plan_with(vec![
+                ActionStep::Reload,
+                ActionStep::Attack { target_id: 1 },
+            ]),
+        );
+
+        sys_execute_active_plans(&mut world);
+        sys_execute_active_plans(&mut world);
+
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+        let completed = world
+            .get_resource::<Events<PlanCompletedEvent>>()
+            .unwrap();
+        assert_eq!(completed.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_step_aborts_plan() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Block]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+        let aborted = world.get_resource::<Events<PlanAbortedEvent>>().unwrap();
+        assert_eq!(aborted.len(), 1);
+    }
+
+    #[test]
+    fn cost_bearing_step_completes_and_pays_cooldown_and_stamina() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        world.insert(e, CCooldowns::default());
+        world.insert(e, CStamina::default());
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Dodge { direction: None }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+        assert_eq!(world.get::<CStamina>(e).unwrap().current, 85.0);
+        let cooldowns = &world.get::<CCooldowns>(e).unwrap().map;
+        assert!(cooldowns.get(&astraweave_core::cooldowns::CooldownKey::from("dodge")).copied().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn cost_bearing_step_blocked_by_cooldown_aborts_plan() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        let mut cooldowns = CCooldowns::default();
+        cooldowns.map.insert("charge".into(), 5.0);
+        world.insert(e, cooldowns);
+        world.insert(e, CStamina::default());
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Charge { target_id: 1 }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+        assert!(world.get::<CAttackIntent>(e).is_none());
+    }
+
+    #[test]
+    fn cost_bearing_step_blocked_by_stamina_aborts_plan() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        world.insert(e, CCooldowns::default());
+        world.insert(e, CStamina { current: 1.0, max: 100.0 });
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Charge { target_id: 1 }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+    }
+
+    #[test]
+    fn cost_bearing_step_without_tracking_components_is_treated_as_unconstrained() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Dodge { direction: None }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn disabled_enforcement_lets_costly_step_through_despite_cooldown() {
+        let mut world = ecs::World::new();
+        world.insert_resource(ConstraintEnforcement(Constraints {
+            enforce_cooldowns: false,
+            enforce_los: false,
+            enforce_stamina: false,
+        }));
+        let e = world.spawn();
+        let mut cooldowns = CCooldowns::default();
+        cooldowns.map.insert("charge".into(), 5.0);
+        world.insert(e, cooldowns);
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Charge { target_id: 1 }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn abort_plan_marks_status_and_emits_event() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Wait { duration: 5.0 }]));
+
+        abort_plan(&mut world, e, "replaced by higher-priority plan");
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+        let aborted = world.get_resource::<Events<PlanAbortedEvent>>().unwrap();
+        assert_eq!(aborted.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_step_requests_replan_with_structured_reason() {
+        let mut world = ecs::World::new();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Block]));
+
+        sys_execute_active_plans(&mut world);
+        let request = world.get::<CReplanRequest>(e).expect("replan requested");
+        assert!(matches!(request.reason, PlanFailureKind::Unsupported(_)));
+    }
+
+    #[test]
+    fn attack_on_dead_target_fails_with_target_dead() {
+        let mut world = ecs::World::new();
+        let target = world.spawn();
+        world.insert(target, CHealth { hp: 0 });
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Attack { target_id: target }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+        assert!(world.get::<CAttackIntent>(e).is_none());
+        let request = world.get::<CReplanRequest>(e).expect("replan requested");
+        assert_eq!(request.reason, PlanFailureKind::TargetDead);
+    }
+
+    #[test]
+    fn attack_on_target_without_health_is_allowed() {
+        // No CHealth tracked for the target: treated as unconstrained, same
+        // convention as untracked cooldowns/stamina.
+        let mut world = ecs::World::new();
+        let target = world.spawn();
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Attack { target_id: target }]));
+
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Completed
+        );
+    }
+
+    #[test]
+    fn move_to_stuck_past_max_step_duration_fails_with_path_blocked() {
+        let mut world = ecs::World::new();
+        world.insert_resource(MaxStepDuration(0.02));
+        world.insert_resource(ExecutionDt(0.016));
+        let e = world.spawn();
+        world.insert(e, CPos { pos: IVec2 { x: 0, y: 0 } });
+        start_plan(&mut world, e, plan_with(vec![ActionStep::MoveTo { x: 5, y: 5, speed: None }]));
+
+        // Position never catches up to the desired pos, simulating an
+        // obstructed path; after enough ticks the step budget is exceeded.
+        sys_execute_active_plans(&mut world);
+        sys_execute_active_plans(&mut world);
+        assert_eq!(
+            world.get::<CActivePlan>(e).unwrap().status,
+            PlanStepStatus::Aborted
+        );
+        let request = world.get::<CReplanRequest>(e).expect("replan requested");
+        assert_eq!(request.reason, PlanFailureKind::PathBlocked);
+    }
+
+    #[test]
+    fn wait_stuck_past_max_step_duration_fails_with_timeout() {
+        let mut world = ecs::World::new();
+        world.insert_resource(MaxStepDuration(0.02));
+        world.insert_resource(ExecutionDt(0.016));
+        let e = world.spawn();
+        start_plan(&mut world, e, plan_with(vec![ActionStep::Wait { duration: 60.0 }]));
+
+        sys_execute_active_plans(&mut world);
+        sys_execute_active_plans(&mut world);
+        let request = world.get::<CReplanRequest>(e).expect("replan requested");
+        assert_eq!(request.reason, PlanFailureKind::Timeout);
+    }
+}
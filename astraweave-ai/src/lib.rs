@@ -9,7 +9,20 @@
 //! - **[`core_loop`]** — The perception → reasoning → planning → action pipeline.
 //! - **[`ecs_ai_plugin`]** — ECS integration via [`AiPlanningPlugin`] and
 //!   [`build_app_with_ai()`].
+//! - **[`execution_bridge`]** — Maps a planned [`astraweave_core::PlanIntent`]'s
+//!   steps to concrete ECS commands one at a time, with progress tracking,
+//!   abort semantics, and completion events.
+//! - **[`snapshot_builder`]** — Assembles [`astraweave_core::WorldSnapshot`]
+//!   from ECS state with configurable perception redaction, spatial
+//!   summarization, and token budgeting ([`snapshot_builder::build_ecs_snapshot`]).
+//! - **[`squad_planner`]** — Coordinates plan proposals across multiple
+//!   agents sharing an [`orchestrator::Orchestrator`], assigning roles and
+//!   deduplicating squad-wide actions like smoke throws.
 //! - **[`tool_sandbox`]** — Runtime validation of AI-generated action plans.
+//! - **[`llm_health`]** (feature `llm_orchestrator`) — Surfaces circuit breaker
+//!   and backpressure state as an [`llm_health::LlmHealthSnapshot`] ECS
+//!   resource so the game can degrade gracefully when the LLM backend
+//!   struggles.
 //!
 //! # Feature Flags
 //!
@@ -28,8 +41,12 @@
 
 pub mod core_loop;
 pub mod ecs_ai_plugin;
+pub mod execution_bridge;
 pub mod orchestrator;
+pub mod snapshot_builder;
+pub mod squad_planner;
 pub mod tool_sandbox;
+pub mod utility_scoring;
 
 #[cfg(test)]
 mod mutation_tests;
@@ -41,6 +58,9 @@ pub mod async_task;
 #[cfg(feature = "llm_orchestrator")]
 pub mod llm_executor;
 
+#[cfg(feature = "llm_orchestrator")]
+pub mod llm_health;
+
 #[cfg(feature = "llm_orchestrator")]
 pub mod ai_arbiter;
 
@@ -53,8 +73,12 @@ pub mod goap;
 
 pub use core_loop::*;
 pub use ecs_ai_plugin::{build_app_with_ai, AiPlanningPlugin};
+pub use execution_bridge::*;
 pub use orchestrator::*;
+pub use snapshot_builder::*;
+pub use squad_planner::*;
 pub use tool_sandbox::*;
+pub use utility_scoring::*;
 
 #[cfg(feature = "llm_orchestrator")]
 pub use async_task::AsyncTask;
@@ -62,6 +86,9 @@ pub use async_task::AsyncTask;
 #[cfg(feature = "llm_orchestrator")]
 pub use llm_executor::LlmExecutor;
 
+#[cfg(feature = "llm_orchestrator")]
+pub use llm_health::{LlmHealthMonitor, LlmHealthPlugin, LlmHealthSnapshot};
+
 #[cfg(feature = "llm_orchestrator")]
 pub use ai_arbiter::{AIArbiter, AIControlMode};
 
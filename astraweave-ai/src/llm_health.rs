@@ -0,0 +1,212 @@
+//! ECS-observable health snapshot for the LLM backend.
+//!
+//! [`CircuitBreakerManager`](astraweave_llm::circuit_breaker::CircuitBreakerManager) and
+//! [`BackpressureManager`](astraweave_llm::backpressure::BackpressureManager) already track
+//! breaker state and queue metrics, but both expose that state through `async fn`s, so the
+//! game loop's synchronous ECS systems (see [`crate::ecs_ai_plugin`]) can't read them
+//! directly. [`LlmHealthMonitor`] periodically pulls both into a plain [`LlmHealthSnapshot`]
+//! that a [`SystemFn`](astraweave_ecs::SystemFn) can copy into the `World` as a resource each
+//! frame, so downstream systems (e.g. the AI arbiter) can decide to fall back to
+//! behavior-tree control when the LLM backend is struggling, without ever awaiting.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use astraweave_ecs as ecs;
+use astraweave_llm::backpressure::BackpressureManager;
+use astraweave_llm::circuit_breaker::{CircuitBreakerManager, CircuitBreakerStatus, CircuitState};
+
+/// Maximum number of latency samples retained for [`LlmHealthSnapshot::average_latency_ms`].
+const RECENT_LATENCY_CAPACITY: usize = 32;
+
+/// A point-in-time view of LLM backend health, safe to insert as an ECS resource.
+#[derive(Debug, Clone, Default)]
+pub struct LlmHealthSnapshot {
+    /// Circuit breaker status per model, as of the last refresh.
+    pub breakers: Vec<CircuitBreakerStatus>,
+    /// Requests currently queued across all priorities.
+    pub queue_depth: usize,
+    /// Requests currently executing.
+    pub active_requests: usize,
+    /// Total requests rejected (queue full / load shed) since the monitor started.
+    pub rejected_requests: u64,
+    /// Backpressure average processing time sampled at each refresh, oldest first, capped
+    /// at [`RECENT_LATENCY_CAPACITY`].
+    pub recent_latencies_ms: VecDeque<f32>,
+}
+
+impl LlmHealthSnapshot {
+    /// Average of [`Self::recent_latencies_ms`], or `0.0` if none have been recorded.
+    pub fn average_latency_ms(&self) -> f32 {
+        if self.recent_latencies_ms.is_empty() {
+            return 0.0;
+        }
+        self.recent_latencies_ms.iter().sum::<f32>() / self.recent_latencies_ms.len() as f32
+    }
+
+    /// `true` if any tracked model's circuit is open -- the game should consider falling
+    /// back to non-LLM control (e.g. behavior trees). Queue depth is not considered.
+    pub fn is_degraded(&self) -> bool {
+        self.breakers.iter().any(|b| b.state == CircuitState::Open)
+    }
+}
+
+/// Periodically snapshots [`CircuitBreakerManager`] and [`BackpressureManager`] state into a
+/// [`LlmHealthSnapshot`] readable without awaiting.
+pub struct LlmHealthMonitor {
+    breakers: Arc<CircuitBreakerManager>,
+    backpressure: Arc<BackpressureManager>,
+    snapshot: Arc<RwLock<LlmHealthSnapshot>>,
+}
+
+impl LlmHealthMonitor {
+    pub fn new(breakers: Arc<CircuitBreakerManager>, backpressure: Arc<BackpressureManager>) -> Self {
+        Self {
+            breakers,
+            backpressure,
+            snapshot: Arc::new(RwLock::new(LlmHealthSnapshot::default())),
+        }
+    }
+
+    /// Pulls the current breaker and backpressure state and updates the shared snapshot.
+    pub async fn refresh(&self) {
+        let breakers = self.breakers.get_all_status().await;
+        let metrics = self.backpressure.get_metrics().await;
+
+        #[allow(clippy::expect_used)] // Lock poisoning indicates a prior panic; propagating is correct
+        let mut snapshot = self
+            .snapshot
+            .write()
+            .expect("LlmHealthMonitor snapshot lock poisoned: another thread panicked while holding it");
+        snapshot.breakers = breakers;
+        snapshot.queue_depth = metrics.current_queue_size;
+        snapshot.active_requests = metrics.current_active_requests;
+        snapshot.rejected_requests = metrics.rejected_requests;
+        snapshot
+            .recent_latencies_ms
+            .push_back(metrics.average_processing_time_ms);
+        while snapshot.recent_latencies_ms.len() > RECENT_LATENCY_CAPACITY {
+            snapshot.recent_latencies_ms.pop_front();
+        }
+    }
+
+    /// Non-blocking read of the most recently refreshed snapshot.
+    #[allow(clippy::expect_used)] // Lock poisoning indicates a prior panic; propagating is correct
+    pub fn snapshot(&self) -> LlmHealthSnapshot {
+        self.snapshot
+            .read()
+            .expect("LlmHealthMonitor snapshot lock poisoned: another thread panicked while holding it")
+            .clone()
+    }
+
+    /// Spawns a background task that calls [`Self::refresh`] every `interval` for as long as
+    /// the returned `Arc<Self>` and its clones stay alive.
+    pub fn spawn_refresh_loop(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let monitor = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                monitor.refresh().await;
+            }
+        })
+    }
+}
+
+/// ECS system that copies the [`LlmHealthMonitor`]'s latest snapshot into the `World` as an
+/// [`LlmHealthSnapshot`] resource. Registered under [`astraweave_ecs::SystemStage::PERCEPTION`]
+/// by [`LlmHealthPlugin`] so other systems see up-to-date health before planning runs.
+fn sys_update_llm_health(world: &mut ecs::World) {
+    let snapshot = match world.get_resource::<Arc<LlmHealthMonitor>>() {
+        Some(monitor) => monitor.snapshot(),
+        None => return,
+    };
+    world.insert_resource(snapshot);
+}
+
+/// Plugin wiring: inserts `monitor` as a resource and registers [`sys_update_llm_health`].
+/// Does not itself spawn the background refresh loop -- call
+/// [`LlmHealthMonitor::spawn_refresh_loop`] once the async runtime is available.
+pub struct LlmHealthPlugin {
+    pub monitor: Arc<LlmHealthMonitor>,
+}
+
+impl ecs::Plugin for LlmHealthPlugin {
+    fn build(&self, app: &mut ecs::App) {
+        app.world.insert_resource(Arc::clone(&self.monitor));
+        app.world.insert_resource(LlmHealthSnapshot::default());
+        app.schedule
+            .add_system(ecs::SystemStage::PERCEPTION, sys_update_llm_health as ecs::SystemFn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_llm::backpressure::BackpressureConfig;
+    use astraweave_llm::circuit_breaker::CircuitBreakerConfig;
+
+    fn test_monitor() -> Arc<LlmHealthMonitor> {
+        let breakers = Arc::new(CircuitBreakerManager::new(CircuitBreakerConfig::default()));
+        let backpressure = Arc::new(BackpressureManager::new(BackpressureConfig::default()));
+        Arc::new(LlmHealthMonitor::new(breakers, backpressure))
+    }
+
+    #[tokio::test]
+    async fn refresh_populates_queue_and_active_counts_from_backpressure_metrics() {
+        let monitor = test_monitor();
+        monitor.refresh().await;
+        let snap = monitor.snapshot();
+        assert_eq!(snap.queue_depth, 0);
+        assert_eq!(snap.active_requests, 0);
+        assert!(!snap.is_degraded());
+    }
+
+    #[tokio::test]
+    async fn is_degraded_when_a_breaker_opens() {
+        let breakers = Arc::new(CircuitBreakerManager::new(CircuitBreakerConfig::default()));
+        let backpressure = Arc::new(BackpressureManager::new(BackpressureConfig::default()));
+        breakers.open_circuit("test-model").await;
+        let monitor = Arc::new(LlmHealthMonitor::new(breakers, backpressure));
+
+        monitor.refresh().await;
+        assert!(monitor.snapshot().is_degraded());
+    }
+
+    #[tokio::test]
+    async fn ecs_system_copies_the_monitor_snapshot_into_the_world() {
+        let monitor = test_monitor();
+        monitor.refresh().await;
+
+        let mut app = ecs::App::new();
+        app = app.add_plugin(LlmHealthPlugin {
+            monitor: Arc::clone(&monitor),
+        });
+        app.schedule.run(&mut app.world);
+
+        let health = app
+            .world
+            .get_resource::<LlmHealthSnapshot>()
+            .expect("LlmHealthPlugin should insert an LlmHealthSnapshot resource");
+        assert!(!health.is_degraded());
+    }
+
+    #[test]
+    fn average_latency_ms_is_zero_with_no_samples() {
+        let snap = LlmHealthSnapshot::default();
+        assert_eq!(snap.average_latency_ms(), 0.0);
+    }
+
+    #[test]
+    fn recent_latencies_are_capped_at_the_retention_window() {
+        let mut snap = LlmHealthSnapshot::default();
+        for i in 0..(RECENT_LATENCY_CAPACITY + 5) {
+            snap.recent_latencies_ms.push_back(i as f32);
+            while snap.recent_latencies_ms.len() > RECENT_LATENCY_CAPACITY {
+                snap.recent_latencies_ms.pop_front();
+            }
+        }
+        assert_eq!(snap.recent_latencies_ms.len(), RECENT_LATENCY_CAPACITY);
+    }
+}
@@ -4,10 +4,13 @@
 use astraweave_profiling::span;
 
 use anyhow::Result;
-#[cfg(feature = "llm_orchestrator")]
+#[cfg(any(feature = "llm_orchestrator", feature = "ai-goap"))]
 use astraweave_core::{default_tool_registry, ToolRegistry};
 use astraweave_core::{ActionStep, IVec2, PlanIntent, WorldSnapshot};
 
+#[cfg(feature = "ai-goap")]
+use astraweave_behavior::goap::{GoapAction, GoapGoal, GoapPlanner, WorldState};
+
 /// Cooldown key constants for type safety and consistency
 const COOLDOWN_THROW_SMOKE: &str = "throw:smoke";
 
@@ -393,6 +396,159 @@ impl OrchestratorAsync for GoapOrchestrator {
     }
 }
 
+/// A deterministic alternative to [`LlmOrchestrator`]: turns a [`ToolRegistry`]
+/// into GOAP actions and a [`WorldSnapshot`] into world-state predicates, then
+/// searches for a plan with [`GoapPlanner`]'s A* search. Produces the same
+/// [`PlanIntent`] as `LlmOrchestrator`, so the two are swappable wherever an
+/// [`OrchestratorAsync`] is expected — pick this one for agents where
+/// determinism matters more than the LLM's adaptability.
+///
+/// Unlike [`GoapOrchestrator`] above (which is really the same hardcoded
+/// move-or-cover-fire rule as [`RuleOrchestrator`], despite its name), this
+/// type runs [`astraweave_behavior::goap::GoapPlanner`]'s real search over
+/// actions and preconditions/effects.
+#[cfg(feature = "ai-goap")]
+pub struct GoapPlannerOrchestrator {
+    pub registry: ToolRegistry,
+}
+
+#[cfg(feature = "ai-goap")]
+impl GoapPlannerOrchestrator {
+    pub fn new(registry: Option<ToolRegistry>) -> Self {
+        Self {
+            registry: registry.unwrap_or_else(default_tool_registry),
+        }
+    }
+
+    /// Extracts world-state predicates from `snap`: whether an enemy is
+    /// present, whether the nearest one is already in cover-fire range, and
+    /// whether smoke is off cooldown. `enemy_suppressed` starts false — it's
+    /// the effect actions work toward.
+    fn world_state_from_snapshot(snap: &WorldSnapshot) -> WorldState {
+        let mut state = WorldState::new();
+        let enemies_present = !snap.enemies.is_empty();
+        state.set("enemies_present", enemies_present);
+        let in_range = snap
+            .enemies
+            .first()
+            .map(|enemy| {
+                let dx = enemy.pos.x.saturating_sub(snap.me.pos.x).abs();
+                let dy = enemy.pos.y.saturating_sub(snap.me.pos.y).abs();
+                dx.saturating_add(dy) <= 2
+            })
+            .unwrap_or(false);
+        state.set("in_range", in_range);
+        let smoke_ready = snap
+            .me
+            .cooldowns
+            .get(COOLDOWN_THROW_SMOKE)
+            .copied()
+            .unwrap_or(0.0)
+            <= 0.0;
+        state.set("smoke_ready", smoke_ready);
+        state.set("enemy_suppressed", false);
+        state
+    }
+
+    /// Builds one [`GoapAction`] per [`ToolSpec`](astraweave_core::ToolSpec)
+    /// in `self.registry` whose name this planner knows how to place in the
+    /// combat preconditions/effects graph above. Tools it doesn't recognize
+    /// (e.g. `revive`, which needs an ally-down predicate this snapshot
+    /// doesn't carry) are skipped rather than guessed at.
+    fn actions_from_registry(&self) -> Vec<GoapAction> {
+        self.registry
+            .tools
+            .iter()
+            .filter_map(|tool| match tool.name.as_str() {
+                "move_to" => Some(
+                    GoapAction::new("move_to")
+                        .with_precondition("enemies_present", true)
+                        .with_precondition("in_range", false)
+                        .with_effect("in_range", true)
+                        .with_cost(5.0),
+                ),
+                "throw" => Some(
+                    GoapAction::new("throw")
+                        .with_precondition("enemies_present", true)
+                        .with_precondition("smoke_ready", true)
+                        .with_effect("smoke_ready", false)
+                        .with_cost(3.0),
+                ),
+                "cover_fire" => Some(
+                    GoapAction::new("cover_fire")
+                        .with_precondition("enemies_present", true)
+                        .with_precondition("in_range", true)
+                        .with_effect("enemy_suppressed", true)
+                        .with_cost(2.0),
+                ),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Converts one planned [`GoapAction`] into the [`ActionStep`] it stands
+    /// for, filling in positions/targets from `snap` since `GoapAction` only
+    /// carries the symbolic name.
+    fn action_to_step(action: &GoapAction, snap: &WorldSnapshot) -> Option<ActionStep> {
+        let enemy = snap.enemies.first()?;
+        match action.name.as_str() {
+            "move_to" => Some(ActionStep::MoveTo {
+                x: snap.me.pos.x + (enemy.pos.x - snap.me.pos.x).signum(),
+                y: snap.me.pos.y + (enemy.pos.y - snap.me.pos.y).signum(),
+                speed: None,
+            }),
+            "throw" => Some(ActionStep::Throw {
+                item: "smoke".into(),
+                x: (snap.me.pos.x + enemy.pos.x) / 2,
+                y: (snap.me.pos.y + enemy.pos.y) / 2,
+            }),
+            "cover_fire" => Some(ActionStep::CoverFire {
+                target_id: enemy.id,
+                duration: 1.5,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "ai-goap")]
+#[async_trait::async_trait]
+impl OrchestratorAsync for GoapPlannerOrchestrator {
+    async fn plan(&self, snap: WorldSnapshot, _budget_ms: u32) -> Result<PlanIntent> {
+        let plan_id = format!("goap-plan-{}", (snap.t * 1000.0) as i64);
+
+        if snap.enemies.is_empty() {
+            return Ok(PlanIntent {
+                plan_id,
+                steps: vec![],
+            });
+        }
+
+        let world_state = Self::world_state_from_snapshot(&snap);
+        let goal = GoapGoal::new(
+            "suppress_enemy",
+            WorldState::from_facts(&[("enemy_suppressed", true)]),
+        );
+        let actions = self.actions_from_registry();
+
+        let planner = GoapPlanner::new().with_max_iterations(100);
+        let plan = planner
+            .plan(&world_state, &goal, &actions)
+            .ok_or_else(|| anyhow::anyhow!("GOAP planning failed - no plan found"))?;
+
+        let steps = plan
+            .iter()
+            .filter_map(|action| Self::action_to_step(action, &snap))
+            .collect();
+
+        Ok(PlanIntent { plan_id, steps })
+    }
+
+    fn name(&self) -> &'static str {
+        "GoapPlannerOrchestrator"
+    }
+}
+
 #[cfg(feature = "llm_orchestrator")]
 pub struct LlmOrchestrator<C> {
     pub client: C,
@@ -1491,6 +1647,46 @@ mod tests {
         assert_eq!(plan1.steps.len(), plan2.steps.len());
     }
 
+    #[test]
+    #[cfg(feature = "ai-goap")]
+    fn goap_planner_orchestrator_moves_then_suppresses_when_far() {
+        let snap = snap_basic(0, 0, 5, 0, 5.0); // far away, smoke on cooldown
+        let orch = GoapPlannerOrchestrator::new(None);
+        let plan = block_on(orch.plan(snap, 100)).expect("goap plan failed");
+
+        assert!(!plan.steps.is_empty());
+        assert!(matches!(plan.steps[0], ActionStep::MoveTo { .. }));
+        assert!(matches!(plan.steps.last(), Some(ActionStep::CoverFire { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "ai-goap")]
+    fn goap_planner_orchestrator_suppresses_directly_when_already_in_range() {
+        let snap = snap_basic(0, 0, 1, 0, 5.0); // distance 1, already in range
+        let orch = GoapPlannerOrchestrator::new(None);
+        let plan = block_on(orch.plan(snap, 100)).expect("goap plan failed");
+
+        assert_eq!(plan.steps.len(), 1);
+        assert!(matches!(plan.steps[0], ActionStep::CoverFire { .. }));
+    }
+
+    #[test]
+    #[cfg(feature = "ai-goap")]
+    fn goap_planner_orchestrator_empty_plan_with_no_enemies() {
+        let mut snap = snap_basic(0, 0, 5, 0, 0.0);
+        snap.enemies.clear();
+        let orch = GoapPlannerOrchestrator::new(None);
+        let plan = block_on(orch.plan(snap, 100)).expect("goap plan failed");
+        assert!(plan.steps.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "ai-goap")]
+    fn goap_planner_orchestrator_name() {
+        let orch = GoapPlannerOrchestrator::new(None);
+        assert_eq!(orch.name(), "GoapPlannerOrchestrator");
+    }
+
     #[test]
     fn all_orchestrators_have_consistent_display() {
         // Test that all concrete orchestrator types have consistent Display output
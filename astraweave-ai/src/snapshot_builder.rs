@@ -0,0 +1,279 @@
+//! ECS-driven [`WorldSnapshot`] assembly with configurable perception
+//! filters, run once per agent per planning interval.
+//!
+//! Unlike [`astraweave_core::build_snapshot`] (which mirrors the legacy
+//! `World`), [`build_ecs_snapshot`] reads ECS components directly and
+//! applies three perception-shaping passes before the snapshot ever reaches
+//! `astraweave_llm::build_prompt`:
+//!
+//! 1. **Redaction** — enemies outside [`PerceptionFilterConfig::los_max`]
+//!    are dropped entirely rather than merely marked `"unknown"`, so an
+//!    agent's plan can't be influenced by what it hasn't actually perceived.
+//! 2. **Spatial summarization** — obstacles farther than
+//!    [`PerceptionFilterConfig::far_distance`] are collapsed to a single
+//!    representative point per quadrant instead of listing every tile.
+//! 3. **Token budgeting** — if the serialized snapshot still exceeds
+//!    [`PerceptionFilterConfig::token_budget`] (approximated as
+//!    `serialized_bytes / 4`), the least-relevant enemies (farthest first)
+//!    are dropped until it fits.
+
+use astraweave_core::{
+    CAmmo, CCooldowns, CHealth, CPos, CTeam, CompanionState, EnemyState, IVec2, Poi, PlayerState,
+    WorldSnapshot,
+};
+use astraweave_ecs as ecs;
+
+/// Tuning knobs for [`build_ecs_snapshot`]. Fields are named after the
+/// perception pass they control; see the module doc for what each pass does.
+#[derive(Clone, Copy, Debug)]
+pub struct PerceptionFilterConfig {
+    /// Manhattan distance beyond which an enemy is redacted from the snapshot.
+    pub los_max: i32,
+    /// Manhattan distance beyond which an obstacle is folded into its
+    /// quadrant's representative point rather than listed individually.
+    pub far_distance: i32,
+    /// Hard cap on enemies included, applied after redaction (closest first).
+    pub max_enemies: usize,
+    /// Approximate token budget for the serialized snapshot. Enforced by
+    /// dropping farthest enemies first; see [`Self::los_max`] for the
+    /// perception-based cut and this for the size-based one.
+    pub token_budget: usize,
+}
+
+impl Default for PerceptionFilterConfig {
+    fn default() -> Self {
+        Self {
+            los_max: 10,
+            far_distance: 20,
+            max_enemies: 8,
+            token_budget: 2_000,
+        }
+    }
+}
+
+/// Rough token estimate for a serialized snapshot: ~4 bytes per token, the
+/// same heuristic commonly used for English/JSON text without a tokenizer
+/// on hand.
+fn estimate_tokens(snap: &WorldSnapshot) -> usize {
+    serde_json::to_string(snap).map(|s| s.len() / 4).unwrap_or(0)
+}
+
+/// Assembles a [`WorldSnapshot`] for `agent` from ECS components, applying
+/// redaction, spatial summarization, and token budgeting (see module docs).
+/// `player` and `objective` are threaded through as-is, matching
+/// [`astraweave_core::build_snapshot`]'s signature.
+pub fn build_ecs_snapshot(
+    world: &ecs::World,
+    agent: ecs::Entity,
+    player: PlayerState,
+    obstacles: &[IVec2],
+    pois: Vec<Poi>,
+    objective: Option<String>,
+    cfg: &PerceptionFilterConfig,
+) -> WorldSnapshot {
+    let me_pos = world.get::<CPos>(agent).copied().unwrap_or_default().pos;
+    let ammo = world.get::<CAmmo>(agent).map(|a| a.rounds).unwrap_or(0);
+    let cooldowns = world
+        .get::<CCooldowns>(agent)
+        .map(|c| {
+            c.map
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .collect::<std::collections::BTreeMap<_, _>>()
+        })
+        .unwrap_or_default();
+    let me = CompanionState {
+        ammo,
+        cooldowns,
+        morale: 1.0,
+        pos: me_pos,
+    };
+
+    // Pass 1: redaction — only enemies within los_max are perceived at all.
+    let mut enemies_by_distance: Vec<(i32, EnemyState)> = ecs::Query::<CPos>::new(world)
+        .filter_map(|(e, pos)| {
+            if world.get::<CTeam>(e).map(|t| t.id) != Some(2) {
+                return None;
+            }
+            let dist = (pos.pos.x - me_pos.x).abs() + (pos.pos.y - me_pos.y).abs();
+            if dist > cfg.los_max {
+                return None;
+            }
+            let hp = world.get::<CHealth>(e).map(|h| h.hp).unwrap_or(0);
+            Some((
+                dist,
+                EnemyState {
+                    id: e,
+                    pos: pos.pos,
+                    hp,
+                    cover: "low".into(),
+                    last_seen: 0.0,
+                },
+            ))
+        })
+        .collect();
+    enemies_by_distance.sort_by_key(|(dist, _)| *dist);
+    enemies_by_distance.truncate(cfg.max_enemies);
+    let mut enemies: Vec<EnemyState> = enemies_by_distance.into_iter().map(|(_, e)| e).collect();
+
+    // Pass 2: spatial summarization — far obstacles collapse to one
+    // representative point per quadrant relative to the agent.
+    let summarized_obstacles = summarize_far_obstacles(obstacles, me_pos, cfg.far_distance);
+
+    let mut snap = WorldSnapshot {
+        t: 0.0,
+        player,
+        me,
+        enemies: std::mem::take(&mut enemies),
+        pois,
+        obstacles: summarized_obstacles,
+        objective,
+    };
+
+    // Pass 3: token budgeting — drop the farthest enemies until the
+    // serialized snapshot fits, or none are left to drop.
+    while estimate_tokens(&snap) > cfg.token_budget && !snap.enemies.is_empty() {
+        snap.enemies.pop();
+    }
+
+    snap
+}
+
+/// Keeps every obstacle within `far_distance` of `origin` as-is; obstacles
+/// beyond it are grouped by which quadrant (relative to `origin`) they fall
+/// in and replaced with that quadrant's centroid, so a wide-open area with
+/// thousands of far tiles costs at most four entries instead of thousands.
+fn summarize_far_obstacles(obstacles: &[IVec2], origin: IVec2, far_distance: i32) -> Vec<IVec2> {
+    let mut near = Vec::new();
+    let mut far_quadrants: [Vec<IVec2>; 4] = Default::default();
+
+    for &pos in obstacles {
+        let dist = (pos.x - origin.x).abs() + (pos.y - origin.y).abs();
+        if dist <= far_distance {
+            near.push(pos);
+            continue;
+        }
+        let quadrant = match (pos.x >= origin.x, pos.y >= origin.y) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+            (true, false) => 3,
+        };
+        far_quadrants[quadrant].push(pos);
+    }
+
+    for quadrant in far_quadrants {
+        if quadrant.is_empty() {
+            continue;
+        }
+        let count = quadrant.len() as i32;
+        let sum = quadrant
+            .iter()
+            .fold(IVec2 { x: 0, y: 0 }, |acc, p| IVec2 {
+                x: acc.x + p.x,
+                y: acc.y + p.y,
+            });
+        near.push(IVec2 {
+            x: sum.x / count,
+            y: sum.y / count,
+        });
+    }
+
+    near
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player() -> PlayerState {
+        PlayerState {
+            hp: 100,
+            pos: IVec2 { x: 0, y: 0 },
+            stance: "stand".into(),
+            orders: vec![],
+        }
+    }
+
+    #[test]
+    fn redacts_enemies_beyond_los_max() {
+        let mut world = ecs::World::new();
+        let agent = world.spawn();
+        world.insert(agent, CPos { pos: IVec2 { x: 0, y: 0 } });
+
+        let near = world.spawn();
+        world.insert(near, CPos { pos: IVec2 { x: 3, y: 0 } });
+        world.insert(near, CTeam { id: 2 });
+
+        let far = world.spawn();
+        world.insert(far, CPos { pos: IVec2 { x: 50, y: 0 } });
+        world.insert(far, CTeam { id: 2 });
+
+        let cfg = PerceptionFilterConfig { los_max: 10, ..Default::default() };
+        let snap = build_ecs_snapshot(&world, agent, player(), &[], vec![], None, &cfg);
+
+        assert_eq!(snap.enemies.len(), 1);
+        assert_eq!(snap.enemies[0].id, near);
+    }
+
+    #[test]
+    fn caps_enemy_count_closest_first() {
+        let mut world = ecs::World::new();
+        let agent = world.spawn();
+        world.insert(agent, CPos { pos: IVec2 { x: 0, y: 0 } });
+
+        for i in 0..5 {
+            let e = world.spawn();
+            world.insert(e, CPos { pos: IVec2 { x: i, y: 0 } });
+            world.insert(e, CTeam { id: 2 });
+        }
+
+        let cfg = PerceptionFilterConfig { los_max: 100, max_enemies: 2, ..Default::default() };
+        let snap = build_ecs_snapshot(&world, agent, player(), &[], vec![], None, &cfg);
+
+        assert_eq!(snap.enemies.len(), 2);
+        assert!(snap.enemies.iter().all(|e| e.pos.x < 2));
+    }
+
+    #[test]
+    fn far_obstacles_collapse_to_quadrant_centroids() {
+        let mut w = ecs::World::new();
+        let a = w.spawn();
+        w.insert(a, CPos { pos: IVec2 { x: 0, y: 0 } });
+
+        let near_obstacle = IVec2 { x: 1, y: 1 };
+        let far_obstacles: Vec<IVec2> = (0..20).map(|i| IVec2 { x: 100 + i, y: 100 }).collect();
+        let mut obstacles = vec![near_obstacle];
+        obstacles.extend(far_obstacles);
+
+        let cfg = PerceptionFilterConfig { far_distance: 10, ..Default::default() };
+        let snap = build_ecs_snapshot(&w, a, player(), &obstacles, vec![], None, &cfg);
+
+        // 1 near obstacle kept as-is, 20 far obstacles (all same quadrant) collapse to 1.
+        assert_eq!(snap.obstacles.len(), 2);
+        assert!(snap.obstacles.contains(&near_obstacle));
+    }
+
+    #[test]
+    fn token_budget_drops_farthest_enemies_when_exceeded() {
+        let mut world = ecs::World::new();
+        let agent = world.spawn();
+        world.insert(agent, CPos { pos: IVec2 { x: 0, y: 0 } });
+
+        for i in 0..20 {
+            let e = world.spawn();
+            world.insert(e, CPos { pos: IVec2 { x: i, y: 0 } });
+            world.insert(e, CTeam { id: 2 });
+        }
+
+        let cfg = PerceptionFilterConfig {
+            los_max: 100,
+            max_enemies: 20,
+            token_budget: 10, // tiny budget forces trimming
+            ..Default::default()
+        };
+        let snap = build_ecs_snapshot(&world, agent, player(), &[], vec![], None, &cfg);
+
+        assert!(snap.enemies.len() < 20);
+    }
+}
@@ -0,0 +1,270 @@
+//! Squad-level coordinated planning.
+//!
+//! [`Orchestrator::propose_plan`] reasons about a single agent's
+//! [`WorldSnapshot`] in isolation, so when several companions share an
+//! orchestrator (e.g. all wired to `RuleOrchestrator`) they duplicate each
+//! other's work -- every agent independently decides "throw smoke, then
+//! advance" against the same nearest enemy. [`SquadPlanner`] wraps a single
+//! per-agent [`Orchestrator`] and coordinates a whole squad's worth of
+//! snapshots in one pass: it assigns each member a [`SquadRole`], strips
+//! duplicate-prone action steps (smoke, revives, explosives) from every
+//! member but the one that claims them first, and validates each member's
+//! resulting [`PlanIntent`] against that member's own [`ToolRegistry`]
+//! before handing plans back to callers.
+
+use std::collections::BTreeSet;
+
+use astraweave_core::{Entity, PlanIntent, ToolRegistry, WorldSnapshot};
+
+use crate::orchestrator::Orchestrator;
+
+/// A member's assigned role within a squad plan, used to bias which agent
+/// performs squad-wide-duplicated actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SquadRole {
+    /// Leads the advance; other roles coordinate around Point's position.
+    Point,
+    /// Provides area-denial/utility actions (smoke, revives) -- exempt from
+    /// squad-wide action deduplication.
+    Support,
+    /// Holds position and lays down cover fire.
+    Overwatch,
+}
+
+/// One squad member: an identifying [`Entity`], its own [`WorldSnapshot`],
+/// and the [`ToolRegistry`] its plan must be validated against.
+pub struct SquadMember {
+    pub entity: Entity,
+    pub snapshot: WorldSnapshot,
+    pub registry: ToolRegistry,
+}
+
+/// A single member's slice of a [`SquadPlanner::plan`] result: its assigned
+/// role and the [`PlanIntent`] it should execute, already validated against
+/// its own [`ToolRegistry`].
+pub struct SquadAssignment {
+    pub entity: Entity,
+    pub role: SquadRole,
+    pub plan: PlanIntent,
+}
+
+/// Action kinds that waste effect (and cooldowns) if every squad member
+/// performs them at once. Keyed by [`astraweave_core::ActionStep::action_name`]
+/// so unrelated actions (e.g. `MoveTo`) are never touched.
+const SHARED_ACTIONS: &[&str] = &["ThrowSmoke", "Revive", "ThrowExplosive"];
+
+/// Coordinates plan proposals across multiple squad members using a single
+/// per-agent [`Orchestrator`]. See the module docs for why this exists.
+pub struct SquadPlanner<O: Orchestrator> {
+    orchestrator: O,
+}
+
+impl<O: Orchestrator> SquadPlanner<O> {
+    pub fn new(orchestrator: O) -> Self {
+        Self { orchestrator }
+    }
+
+    /// Assigns roles round-robin (`Point, Support, Overwatch, ...`) over
+    /// `members` in the order given, proposes each member's plan against the
+    /// wrapped [`Orchestrator`], then strips squad-wide-duplicated steps
+    /// (see [`SHARED_ACTIONS`]) from every non-`Support` member after the
+    /// first claimant (which may itself be `Support`). A member whose resulting plan doesn't validate against its
+    /// own [`ToolRegistry`] falls back to an empty plan rather than being
+    /// dropped, so callers always get exactly one [`SquadAssignment`] per
+    /// input member.
+    pub fn plan(&self, members: &[SquadMember]) -> Vec<SquadAssignment> {
+        let mut claimed: BTreeSet<&'static str> = BTreeSet::new();
+
+        members
+            .iter()
+            .zip(assign_roles(members.len()))
+            .map(|(member, role)| {
+                let mut plan = self.orchestrator.propose_plan(&member.snapshot);
+                dedupe_shared_steps(&mut plan, role, &mut claimed);
+                if !validates_against(&plan, &member.registry) {
+                    plan.steps.clear();
+                }
+                SquadAssignment {
+                    entity: member.entity,
+                    role,
+                    plan,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Round-robin role assignment: `Point, Support, Overwatch` repeating, so a
+/// squad of any size always has exactly one initial `Point`.
+fn assign_roles(count: usize) -> Vec<SquadRole> {
+    const CYCLE: [SquadRole; 3] = [SquadRole::Point, SquadRole::Support, SquadRole::Overwatch];
+    (0..count).map(|i| CYCLE[i % CYCLE.len()]).collect()
+}
+
+/// Drops any [`SHARED_ACTIONS`] step from `plan` once another member has
+/// already claimed that action this planning pass, unless `role` is
+/// [`SquadRole::Support`] (which is allowed to always act on them).
+fn dedupe_shared_steps(plan: &mut PlanIntent, role: SquadRole, claimed: &mut BTreeSet<&'static str>) {
+    plan.steps.retain(|step| {
+        let name = step.action_name();
+        if !SHARED_ACTIONS.contains(&name) {
+            return true;
+        }
+        if role != SquadRole::Support && claimed.contains(name) {
+            return false;
+        }
+        claimed.insert(name);
+        true
+    });
+}
+
+/// Whether every step in `plan` names a tool present in `registry`.
+fn validates_against(plan: &PlanIntent, registry: &ToolRegistry) -> bool {
+    plan.steps
+        .iter()
+        .all(|step| registry.tools.iter().any(|t| t.name == step.action_name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{
+        default_tool_registry, ActionStep, CompanionState, Constraints, PlayerState, ToolSpec,
+    };
+    use std::collections::BTreeMap;
+
+    struct StubOrchestrator(Vec<ActionStep>);
+
+    impl Orchestrator for StubOrchestrator {
+        fn propose_plan(&self, _snap: &WorldSnapshot) -> PlanIntent {
+            PlanIntent {
+                plan_id: "stub".into(),
+                steps: self.0.clone(),
+            }
+        }
+    }
+
+    fn empty_snapshot() -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: Default::default(),
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: BTreeMap::new(),
+                morale: 1.0,
+                pos: Default::default(),
+            },
+            enemies: vec![],
+            pois: vec![],
+            obstacles: vec![],
+            objective: None,
+        }
+    }
+
+    fn member(entity: Entity, registry: ToolRegistry) -> SquadMember {
+        SquadMember {
+            entity,
+            snapshot: empty_snapshot(),
+            registry,
+        }
+    }
+
+    #[test]
+    fn assigns_roles_round_robin() {
+        let roles = assign_roles(5);
+        assert_eq!(
+            roles,
+            vec![
+                SquadRole::Point,
+                SquadRole::Support,
+                SquadRole::Overwatch,
+                SquadRole::Point,
+                SquadRole::Support,
+            ]
+        );
+    }
+
+    #[test]
+    fn only_one_non_support_member_keeps_a_shared_action() {
+        let planner = SquadPlanner::new(StubOrchestrator(vec![ActionStep::ThrowSmoke { x: 1, y: 1 }]));
+        let reg = ToolRegistry {
+            tools: vec![ToolSpec {
+                name: "ThrowSmoke".into(),
+                args: BTreeMap::new(),
+            }],
+            constraints: Constraints {
+                enforce_cooldowns: false,
+                enforce_los: false,
+                enforce_stamina: false,
+            },
+        };
+        let members = vec![member(1, reg.clone()), member(2, reg.clone()), member(3, reg)];
+
+        let assignments = planner.plan(&members);
+        let with_smoke = assignments
+            .iter()
+            .filter(|a| a.role != SquadRole::Support && !a.plan.steps.is_empty())
+            .count();
+        assert_eq!(with_smoke, 1);
+    }
+
+    #[test]
+    fn support_role_is_exempt_from_dedup() {
+        // Both the Point (index 0) and Support (index 1) member propose the
+        // same shared step; Point claims it first, but Support should still
+        // keep its own copy since it's exempt.
+        let planner = SquadPlanner::new(StubOrchestrator(vec![ActionStep::Revive { ally_id: 9 }]));
+        let reg = ToolRegistry {
+            tools: vec![ToolSpec {
+                name: "Revive".into(),
+                args: BTreeMap::new(),
+            }],
+            constraints: Constraints {
+                enforce_cooldowns: false,
+                enforce_los: false,
+                enforce_stamina: false,
+            },
+        };
+        let members = vec![member(1, reg.clone()), member(2, reg)];
+
+        let assignments = planner.plan(&members);
+        assert_eq!(assignments[0].role, SquadRole::Point);
+        assert_eq!(assignments[1].role, SquadRole::Support);
+        assert!(!assignments[0].plan.steps.is_empty());
+        assert!(!assignments[1].plan.steps.is_empty());
+    }
+
+    #[test]
+    fn plan_using_disallowed_tool_is_replaced_with_empty_plan() {
+        let planner = SquadPlanner::new(StubOrchestrator(vec![ActionStep::MoveTo {
+            x: 1,
+            y: 1,
+            speed: None,
+        }]));
+        let reg = ToolRegistry {
+            tools: vec![],
+            constraints: Constraints {
+                enforce_cooldowns: false,
+                enforce_los: false,
+                enforce_stamina: false,
+            },
+        };
+        let members = vec![member(1, reg)];
+
+        let assignments = planner.plan(&members);
+        assert!(assignments[0].plan.steps.is_empty());
+    }
+
+    #[test]
+    fn returns_one_assignment_per_member() {
+        let planner = SquadPlanner::new(StubOrchestrator(vec![]));
+        let reg = default_tool_registry();
+        let members = vec![member(1, reg.clone()), member(2, reg.clone()), member(3, reg)];
+        assert_eq!(planner.plan(&members).len(), 3);
+    }
+}
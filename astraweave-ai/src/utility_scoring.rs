@@ -0,0 +1,276 @@
+//! Utility scoring for choosing between multiple candidate [`PlanIntent`]s.
+//!
+//! When more than one plan source is in play — a cached LLM plan, a
+//! heuristic fallback from [`crate::orchestrator::RuleOrchestrator`], a
+//! suggestion from a behavior tree — nothing arbitrates between them; the
+//! caller just picks one. [`UtilityScorer`] scores each candidate against
+//! the current [`WorldSnapshot`] along pluggable dimensions (danger,
+//! objective progress, resource cost, ...) and [`UtilityScorer::pick_best`]
+//! returns the highest-scoring one. This is deliberately about *picking*
+//! rather than *merging*: `ActionStep` sequences from unrelated plan sources
+//! don't have a well-defined way to combine, so blending is left to callers
+//! that understand their own candidates' structure (e.g. splicing a single
+//! step from a runner-up into the winner).
+
+use astraweave_core::{ActionStep, PlanIntent, WorldSnapshot};
+
+/// One named, weighted scoring dimension. `score` should return higher
+/// values for more desirable plans; [`UtilityScorer`] combines dimensions
+/// as a weighted sum.
+type ScoreFn = Box<dyn Fn(&PlanIntent, &WorldSnapshot) -> f32 + Send + Sync>;
+
+struct Dimension {
+    name: &'static str,
+    weight: f32,
+    score: ScoreFn,
+}
+
+/// A candidate plan together with its total score and the per-dimension
+/// contributions that produced it, for logging/debugging why one plan beat
+/// another.
+#[derive(Debug)]
+pub struct ScoredPlan<'a> {
+    pub plan: &'a PlanIntent,
+    pub total: f32,
+    pub breakdown: Vec<(&'static str, f32)>,
+}
+
+/// Combines named, weighted scoring dimensions into a total utility for a
+/// candidate plan. Construct with [`UtilityScorer::new`] and add dimensions
+/// via [`UtilityScorer::with_dimension`], or start from
+/// [`UtilityScorer::default_dimensions`] for the danger/objective/cost mix
+/// most agents want.
+#[derive(Default)]
+pub struct UtilityScorer {
+    dimensions: Vec<Dimension>,
+}
+
+impl UtilityScorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a scoring dimension. `weight` may be negative to penalize a
+    /// quantity (e.g. a positive `danger` score weighted negatively).
+    pub fn with_dimension(
+        mut self,
+        name: &'static str,
+        weight: f32,
+        score: impl Fn(&PlanIntent, &WorldSnapshot) -> f32 + Send + Sync + 'static,
+    ) -> Self {
+        self.dimensions.push(Dimension {
+            name,
+            weight,
+            score: Box::new(score),
+        });
+        self
+    }
+
+    /// The default dimension mix: rewards closing distance to the
+    /// objective and penalizes danger exposure and resource cost.
+    pub fn default_dimensions() -> Self {
+        Self::new()
+            .with_dimension("danger", -1.0, score_danger)
+            .with_dimension("objective_progress", 2.0, score_objective_progress)
+            .with_dimension("resource_cost", -0.5, score_resource_cost)
+    }
+
+    /// Scores `plan` against `snap`, returning the weighted total and a
+    /// per-dimension breakdown.
+    pub fn score<'a>(&self, plan: &'a PlanIntent, snap: &WorldSnapshot) -> ScoredPlan<'a> {
+        let mut breakdown = Vec::with_capacity(self.dimensions.len());
+        let mut total = 0.0;
+        for dim in &self.dimensions {
+            let raw = (dim.score)(plan, snap);
+            total += raw * dim.weight;
+            breakdown.push((dim.name, raw));
+        }
+        ScoredPlan {
+            plan,
+            total,
+            breakdown,
+        }
+    }
+
+    /// Scores every candidate and returns the highest-scoring one, or
+    /// `None` if `candidates` is empty. Ties keep the first (earliest)
+    /// candidate, so callers can order candidates by preference as a
+    /// tie-break.
+    pub fn pick_best<'a>(
+        &self,
+        candidates: &'a [PlanIntent],
+        snap: &WorldSnapshot,
+    ) -> Option<ScoredPlan<'a>> {
+        candidates
+            .iter()
+            .map(|p| self.score(p, snap))
+            .fold(None, |best, cur| match best {
+                Some(b) if b.total >= cur.total => Some(b),
+                _ => Some(cur),
+            })
+    }
+}
+
+/// Danger exposure of `plan`'s `MoveTo`/approach steps: how close the plan
+/// brings the agent to the nearest enemy, summed across steps. Higher is
+/// more dangerous.
+pub fn score_danger(plan: &PlanIntent, snap: &WorldSnapshot) -> f32 {
+    if snap.enemies.is_empty() {
+        return 0.0;
+    }
+    plan.steps
+        .iter()
+        .filter_map(|step| match step {
+            ActionStep::MoveTo { x, y, .. } => Some((*x, *y)),
+            ActionStep::Approach { target_id, .. } => snap
+                .enemies
+                .iter()
+                .find(|e| e.id == *target_id)
+                .map(|e| (e.pos.x, e.pos.y)),
+            _ => None,
+        })
+        .map(|(x, y)| {
+            snap.enemies
+                .iter()
+                .map(|e| ((e.pos.x - x).abs() + (e.pos.y - y).abs()) as f32)
+                .fold(f32::MAX, f32::min)
+        })
+        .map(|closest| 1.0 / (1.0 + closest.max(0.0)))
+        .sum()
+}
+
+/// How much closer `plan`'s final `MoveTo` destination brings the agent to
+/// the first point of interest (used as a stand-in for the freeform
+/// `objective` string, which has no numeric target of its own). Higher is
+/// better; zero if the plan has no movement or there's no POI to measure
+/// against.
+pub fn score_objective_progress(plan: &PlanIntent, snap: &WorldSnapshot) -> f32 {
+    let Some(target) = snap.pois.first() else {
+        return 0.0;
+    };
+    let Some(dest) = plan.steps.iter().rev().find_map(|step| match step {
+        ActionStep::MoveTo { x, y, .. } => Some((*x, *y)),
+        _ => None,
+    }) else {
+        return 0.0;
+    };
+    let before = ((target.pos.x - snap.me.pos.x).abs() + (target.pos.y - snap.me.pos.y).abs()) as f32;
+    let after = ((target.pos.x - dest.0).abs() + (target.pos.y - dest.1).abs()) as f32;
+    (before - after).max(0.0)
+}
+
+/// Resource cost of `plan`: a flat cost per step, plus an extra weight for
+/// steps that consume cooldown-gated abilities. Higher is more expensive.
+pub fn score_resource_cost(plan: &PlanIntent, _snap: &WorldSnapshot) -> f32 {
+    plan.steps
+        .iter()
+        .map(|step| match step {
+            ActionStep::ThrowSmoke { .. }
+            | ActionStep::ThrowExplosive { .. }
+            | ActionStep::UseAbility { .. }
+            | ActionStep::UseDefensiveAbility { .. } => 2.0,
+            _ => 1.0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{CompanionState, EnemyState, IVec2, PlayerState};
+    use std::collections::BTreeMap;
+
+    fn snap_with(me_pos: IVec2, enemies: Vec<EnemyState>, poi: Option<IVec2>) -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: me_pos,
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: BTreeMap::new(),
+                morale: 1.0,
+                pos: me_pos,
+            },
+            enemies,
+            pois: poi
+                .into_iter()
+                .map(|pos| astraweave_core::Poi { k: "goal".into(), pos })
+                .collect(),
+            obstacles: vec![],
+            objective: None,
+        }
+    }
+
+    fn move_plan(id: &str, x: i32, y: i32) -> PlanIntent {
+        PlanIntent {
+            plan_id: id.into(),
+            steps: vec![ActionStep::MoveTo { x, y, speed: None }],
+        }
+    }
+
+    #[test]
+    fn danger_is_zero_with_no_enemies() {
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![], None);
+        let plan = move_plan("p", 5, 0);
+        assert_eq!(score_danger(&plan, &snap), 0.0);
+    }
+
+    #[test]
+    fn danger_grows_as_move_approaches_enemy() {
+        let enemy = EnemyState { id: 1, pos: IVec2 { x: 10, y: 0 }, hp: 50, cover: "none".into(), last_seen: 0.0 };
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![enemy], None);
+        let far = move_plan("far", 1, 0);
+        let near = move_plan("near", 9, 0);
+        assert!(score_danger(&near, &snap) > score_danger(&far, &snap));
+    }
+
+    #[test]
+    fn objective_progress_rewards_closing_distance() {
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![], Some(IVec2 { x: 10, y: 0 }));
+        let plan = move_plan("p", 5, 0);
+        assert_eq!(score_objective_progress(&plan, &snap), 5.0);
+    }
+
+    #[test]
+    fn objective_progress_is_zero_without_a_poi() {
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![], None);
+        let plan = move_plan("p", 5, 0);
+        assert_eq!(score_objective_progress(&plan, &snap), 0.0);
+    }
+
+    #[test]
+    fn resource_cost_weighs_abilities_higher_than_moves() {
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![], None);
+        let move_only = move_plan("m", 1, 0);
+        let with_ability = PlanIntent {
+            plan_id: "a".into(),
+            steps: vec![ActionStep::ThrowSmoke { x: 1, y: 0 }],
+        };
+        assert!(score_resource_cost(&with_ability, &snap) > score_resource_cost(&move_only, &snap));
+    }
+
+    #[test]
+    fn pick_best_prefers_safer_plan_toward_objective() {
+        let enemy = EnemyState { id: 1, pos: IVec2 { x: 10, y: 0 }, hp: 50, cover: "none".into(), last_seen: 0.0 };
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![enemy], Some(IVec2 { x: 10, y: 0 }));
+        let cautious = move_plan("cautious", 3, 0);
+        let reckless = move_plan("reckless", 9, 0);
+
+        let scorer = UtilityScorer::default_dimensions();
+        let best = scorer
+            .pick_best(&[reckless, cautious], &snap)
+            .expect("at least one candidate");
+        assert_eq!(best.plan.plan_id, "cautious");
+    }
+
+    #[test]
+    fn pick_best_returns_none_for_empty_candidates() {
+        let snap = snap_with(IVec2 { x: 0, y: 0 }, vec![], None);
+        let scorer = UtilityScorer::default_dimensions();
+        assert!(scorer.pick_best(&[], &snap).is_none());
+    }
+}
@@ -0,0 +1,239 @@
+//! Impostor (octahedral billboard) baking for distant vegetation/props.
+//!
+//! Bakes a set of pre-rendered per-view captures into a single octahedral
+//! atlas texture, plus the metadata a renderer needs to sample it by view
+//! direction. Views are expected to have already been rendered from
+//! directions on the hemi-octahedral grid returned by
+//! [`octahedral_view_dir`] by the caller's own renderer; this module only
+//! assembles and packs them into the final atlas.
+
+use anyhow::{ensure, Result};
+use glam::Vec3;
+use image::{Rgba, RgbaImage};
+
+/// One captured view of the source mesh, rendered from the direction
+/// [`octahedral_view_dir`] returns for grid cell `(grid_u, grid_v)`.
+pub struct ImpostorView {
+    pub grid_u: u32,
+    pub grid_v: u32,
+    pub image: RgbaImage,
+}
+
+/// A baked impostor: a single atlas texture plus the grid layout needed to
+/// sample it by view direction at runtime.
+#[derive(Debug, Clone)]
+pub struct BakedImpostor {
+    pub atlas: RgbaImage,
+    pub grid_size: u32,
+    pub tile_size: u32,
+}
+
+/// Bake `views` into a single `(grid_size * tile_size)^2` atlas. `views`
+/// must cover every cell of the `grid_size x grid_size` grid exactly once,
+/// each as a `tile_size x tile_size` image.
+pub fn bake_impostor_atlas(
+    views: &[ImpostorView],
+    grid_size: u32,
+    tile_size: u32,
+) -> Result<BakedImpostor> {
+    ensure!(grid_size > 0, "grid_size must be at least 1");
+    ensure!(tile_size > 0, "tile_size must be at least 1");
+    ensure!(
+        views.len() as u32 == grid_size * grid_size,
+        "expected {} views for a {}x{} grid, got {}",
+        grid_size * grid_size,
+        grid_size,
+        grid_size,
+        views.len()
+    );
+
+    let atlas_dim = grid_size * tile_size;
+    let mut atlas = RgbaImage::from_pixel(atlas_dim, atlas_dim, Rgba([0, 0, 0, 0]));
+    let mut filled = vec![false; (grid_size * grid_size) as usize];
+
+    for view in views {
+        ensure!(
+            view.grid_u < grid_size && view.grid_v < grid_size,
+            "view grid coordinate ({}, {}) out of {}x{} bounds",
+            view.grid_u,
+            view.grid_v,
+            grid_size,
+            grid_size
+        );
+        ensure!(
+            view.image.width() == tile_size && view.image.height() == tile_size,
+            "view ({}, {}) is {}x{}, expected {}x{}",
+            view.grid_u,
+            view.grid_v,
+            view.image.width(),
+            view.image.height(),
+            tile_size,
+            tile_size
+        );
+
+        let cell = (view.grid_v * grid_size + view.grid_u) as usize;
+        ensure!(
+            !filled[cell],
+            "grid cell ({}, {}) supplied more than once",
+            view.grid_u,
+            view.grid_v
+        );
+        filled[cell] = true;
+
+        let ox = view.grid_u * tile_size;
+        let oy = view.grid_v * tile_size;
+        for y in 0..tile_size {
+            for x in 0..tile_size {
+                atlas.put_pixel(ox + x, oy + y, *view.image.get_pixel(x, y));
+            }
+        }
+    }
+
+    Ok(BakedImpostor {
+        atlas,
+        grid_size,
+        tile_size,
+    })
+}
+
+/// World-space view direction for octahedral grid cell `(u, v)` of a
+/// `grid_size x grid_size` hemi-octahedral capture grid. Only the upper
+/// hemisphere (`z >= 0`) is covered, since vegetation and props are viewed
+/// from above the horizon in practice.
+pub fn octahedral_view_dir(u: u32, v: u32, grid_size: u32) -> Vec3 {
+    let fx = (u as f32 + 0.5) / grid_size as f32 * 2.0 - 1.0;
+    let fy = (v as f32 + 0.5) / grid_size as f32 * 2.0 - 1.0;
+    let z = 1.0 - fx.abs() - fy.abs();
+    Vec3::new(fx, fy, z.max(0.0)).normalize()
+}
+
+/// Atlas UV (in `[0, 1]`) of the baked view closest to `view_dir`, for
+/// runtime sampling. Inverse of [`octahedral_view_dir`]'s projection.
+pub fn sample_octahedral_uv(view_dir: Vec3) -> (f32, f32) {
+    let v = view_dir.normalize();
+    let l1 = v.x.abs() + v.y.abs() + v.z.abs().max(f32::EPSILON);
+    (v.x / l1 * 0.5 + 0.5, v.y / l1 * 0.5 + 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid_tile(size: u32, color: Rgba<u8>) -> RgbaImage {
+        RgbaImage::from_pixel(size, size, color)
+    }
+
+    #[test]
+    fn bakes_atlas_of_expected_size() {
+        let views: Vec<ImpostorView> = (0..2)
+            .flat_map(|v| {
+                (0..2).map(move |u| ImpostorView {
+                    grid_u: u,
+                    grid_v: v,
+                    image: solid_tile(4, Rgba([255, 0, 0, 255])),
+                })
+            })
+            .collect();
+
+        let baked = bake_impostor_atlas(&views, 2, 4).unwrap();
+        assert_eq!(baked.atlas.width(), 8);
+        assert_eq!(baked.atlas.height(), 8);
+    }
+
+    #[test]
+    fn places_each_view_in_its_grid_cell() {
+        let views = vec![
+            ImpostorView {
+                grid_u: 0,
+                grid_v: 0,
+                image: solid_tile(2, Rgba([255, 0, 0, 255])),
+            },
+            ImpostorView {
+                grid_u: 1,
+                grid_v: 0,
+                image: solid_tile(2, Rgba([0, 255, 0, 255])),
+            },
+            ImpostorView {
+                grid_u: 0,
+                grid_v: 1,
+                image: solid_tile(2, Rgba([0, 0, 255, 255])),
+            },
+            ImpostorView {
+                grid_u: 1,
+                grid_v: 1,
+                image: solid_tile(2, Rgba([255, 255, 0, 255])),
+            },
+        ];
+
+        let baked = bake_impostor_atlas(&views, 2, 2).unwrap();
+        assert_eq!(*baked.atlas.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(*baked.atlas.get_pixel(2, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*baked.atlas.get_pixel(0, 2), Rgba([0, 0, 255, 255]));
+        assert_eq!(*baked.atlas.get_pixel(2, 2), Rgba([255, 255, 0, 255]));
+    }
+
+    #[test]
+    fn rejects_wrong_view_count() {
+        let views = vec![ImpostorView {
+            grid_u: 0,
+            grid_v: 0,
+            image: solid_tile(2, Rgba([0, 0, 0, 255])),
+        }];
+        assert!(bake_impostor_atlas(&views, 2, 2).is_err());
+    }
+
+    #[test]
+    fn rejects_mismatched_tile_size() {
+        let views = vec![ImpostorView {
+            grid_u: 0,
+            grid_v: 0,
+            image: solid_tile(3, Rgba([0, 0, 0, 255])),
+        }];
+        assert!(bake_impostor_atlas(&views, 1, 2).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_grid_cell() {
+        let views = vec![
+            ImpostorView {
+                grid_u: 0,
+                grid_v: 0,
+                image: solid_tile(2, Rgba([0, 0, 0, 255])),
+            },
+            ImpostorView {
+                grid_u: 0,
+                grid_v: 0,
+                image: solid_tile(2, Rgba([0, 0, 0, 255])),
+            },
+        ];
+        assert!(bake_impostor_atlas(&views, 1, 2).is_err());
+    }
+
+    #[test]
+    fn view_dir_center_cell_points_straight_up() {
+        // Odd grid_size so the middle cell maps to (0, 0) in [-1, 1].
+        let dir = octahedral_view_dir(1, 1, 3);
+        assert!(dir.z > 0.9, "expected near-vertical direction, got {dir:?}");
+    }
+
+    #[test]
+    fn view_dir_is_always_normalized() {
+        for v in 0..4 {
+            for u in 0..4 {
+                let dir = octahedral_view_dir(u, v, 4);
+                assert!((dir.length() - 1.0).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn sample_uv_roundtrips_view_dir() {
+        let dir = octahedral_view_dir(3, 1, 4);
+        let (u, v) = sample_octahedral_uv(dir);
+        assert!((0.0..=1.0).contains(&u));
+        assert!((0.0..=1.0).contains(&v));
+        // Cell (3, 1) of a 4x4 grid has its center at UV (3.5/4, 1.5/4).
+        assert!((u - 3.5 / 4.0).abs() < 1e-5, "u = {u}");
+        assert!((v - 1.5 / 4.0).abs() < 1e-5, "v = {v}");
+    }
+}
@@ -28,10 +28,12 @@
 //! # }
 //! ```
 
+pub mod impostor;
 pub mod mesh;
 pub mod texture;
 pub mod validator;
 
+pub use impostor::{bake_impostor_atlas, BakedImpostor, ImpostorView};
 pub use mesh::{optimize_mesh, MeshOptimizationStats};
 pub use texture::{compress_bc7, CompressionStats};
 pub use validator::{AssetValidator, ValidationReport};
@@ -0,0 +1,356 @@
+//! Reference-counted asset caching with automatic unload.
+//!
+//! [`crate::AssetCache`] never evicts anything once inserted, which is fine
+//! for tests and short tools but unbounded for a long-running game
+//! session. [`RefCountedAssetCache`] layers strong/weak reference counting
+//! on top: callers hold [`AssetHandle`]s (cheap `Arc` clones), and once an
+//! asset's last strong handle drops, its entry becomes eligible for
+//! unload after an [`UnloadPolicy::grace_period`] (so a scene transition
+//! that briefly drops and re-requests the same asset doesn't thrash it),
+//! or sooner if [`UnloadPolicy::lru_budget_bytes`] is exceeded, in which
+//! case unreferenced entries are evicted least-recently-used first.
+//!
+//! [`RefCountedAssetCache::hot_swap`] replaces a cached asset's contents
+//! in place, so every existing [`AssetHandle`] observes the new value on
+//! its next [`AssetHandle::with`]. [`crate::AssetDatabase`] does not call
+//! it itself: code watching [`crate::AssetDatabase::hot_reload_rx`]
+//! (signalled by [`crate::AssetDatabase::invalidate_asset`]) calls it
+//! after re-importing a changed source file.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+/// A strong, cheaply-cloneable reference to a cached asset. The asset is
+/// kept alive at least until every clone of its handle is dropped (plus
+/// [`UnloadPolicy::grace_period`]).
+pub struct AssetHandle<T> {
+    id: String,
+    inner: Arc<Mutex<T>>,
+}
+
+impl<T> AssetHandle<T> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Runs `f` against the current contents, reflecting any in-place
+    /// [`RefCountedAssetCache::hot_swap`] that happened since this handle
+    /// was acquired.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let guard = self.inner.lock().expect("asset handle mutex poisoned");
+        f(&guard)
+    }
+
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    pub fn downgrade(&self) -> WeakAssetHandle<T> {
+        WeakAssetHandle {
+            id: self.id.clone(),
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+impl<T> Clone for AssetHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// A weak reference to a cached asset; does not keep it alive.
+pub struct WeakAssetHandle<T> {
+    id: String,
+    inner: Weak<Mutex<T>>,
+}
+
+impl<T> WeakAssetHandle<T> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn upgrade(&self) -> Option<AssetHandle<T>> {
+        self.inner.upgrade().map(|inner| AssetHandle {
+            id: self.id.clone(),
+            inner,
+        })
+    }
+}
+
+impl<T> Clone for WeakAssetHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Governs when [`RefCountedAssetCache::sweep`] unloads an entry.
+#[derive(Debug, Clone, Copy)]
+pub struct UnloadPolicy {
+    /// How long an asset with zero strong handles is kept around before
+    /// being unloaded.
+    pub grace_period: Duration,
+    /// Soft byte budget across all cached assets. Once exceeded,
+    /// unreferenced assets are evicted least-recently-used-first (ahead of
+    /// their grace period) until back under budget. `None` disables
+    /// budget-driven eviction and relies on the grace period alone.
+    pub lru_budget_bytes: Option<u64>,
+}
+
+impl Default for UnloadPolicy {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(5),
+            lru_budget_bytes: None,
+        }
+    }
+}
+
+struct Entry<T> {
+    handle: AssetHandle<T>,
+    size_bytes: u64,
+    last_accessed: Instant,
+    zero_refs_since: Option<Instant>,
+}
+
+/// A cache of assets kept alive by [`AssetHandle`] reference counts,
+/// unloaded per [`UnloadPolicy`] once [`sweep`](Self::sweep) is called.
+pub struct RefCountedAssetCache<T> {
+    entries: HashMap<String, Entry<T>>,
+    policy: UnloadPolicy,
+}
+
+impl<T> RefCountedAssetCache<T> {
+    pub fn new(policy: UnloadPolicy) -> Self {
+        Self {
+            entries: HashMap::new(),
+            policy,
+        }
+    }
+
+    /// Inserts (or replaces) the asset at `id`, sized at `size_bytes` for
+    /// LRU budgeting, and returns a strong handle to it.
+    pub fn insert(&mut self, id: impl Into<String>, value: T, size_bytes: u64) -> AssetHandle<T> {
+        let id = id.into();
+        let handle = AssetHandle {
+            id: id.clone(),
+            inner: Arc::new(Mutex::new(value)),
+        };
+        self.entries.insert(
+            id,
+            Entry {
+                handle: handle.clone(),
+                size_bytes,
+                last_accessed: Instant::now(),
+                zero_refs_since: None,
+            },
+        );
+        handle
+    }
+
+    /// Returns a fresh strong handle to `id` if it's still cached,
+    /// refreshing its last-accessed time and cancelling any pending
+    /// grace-period unload.
+    pub fn get(&mut self, id: &str) -> Option<AssetHandle<T>> {
+        let entry = self.entries.get_mut(id)?;
+        entry.last_accessed = Instant::now();
+        entry.zero_refs_since = None;
+        Some(entry.handle.clone())
+    }
+
+    /// Replaces the contents of an already-cached asset in place so every
+    /// existing [`AssetHandle`] to it observes the new value. Returns
+    /// `false` if `id` isn't cached.
+    pub fn hot_swap(&mut self, id: &str, value: T, size_bytes: u64) -> bool {
+        let Some(entry) = self.entries.get_mut(id) else {
+            return false;
+        };
+        *entry.handle.inner.lock().expect("asset handle mutex poisoned") = value;
+        entry.size_bytes = size_bytes;
+        entry.last_accessed = Instant::now();
+        true
+    }
+
+    /// Unloads entries whose only strong reference is the cache's own copy
+    /// once their grace period has elapsed, then, if a byte budget is set
+    /// and still exceeded, evicts the least-recently-used remaining
+    /// unreferenced entries until back under budget. Returns the unloaded
+    /// ids. Intended to be called periodically (e.g. once per frame or
+    /// asset-server tick), the same poll-and-drain style the asset
+    /// database's hot-reload debouncing already uses.
+    pub fn sweep(&mut self) -> Vec<String> {
+        let now = Instant::now();
+        let mut unloaded = Vec::new();
+
+        for entry in self.entries.values_mut() {
+            if Arc::strong_count(&entry.handle.inner) > 1 {
+                entry.zero_refs_since = None;
+            } else {
+                entry.zero_refs_since.get_or_insert(now);
+            }
+        }
+
+        let policy = self.policy;
+        self.entries.retain(|id, entry| {
+            let expired = entry
+                .zero_refs_since
+                .is_some_and(|since| now.duration_since(since) >= policy.grace_period);
+            if expired {
+                unloaded.push(id.clone());
+            }
+            !expired
+        });
+
+        if let Some(budget) = self.policy.lru_budget_bytes {
+            let mut total: u64 = self.entries.values().map(|e| e.size_bytes).sum();
+            if total > budget {
+                let mut candidates: Vec<(String, Instant)> = self
+                    .entries
+                    .iter()
+                    .filter(|(_, e)| Arc::strong_count(&e.handle.inner) == 1)
+                    .map(|(id, e)| (id.clone(), e.last_accessed))
+                    .collect();
+                candidates.sort_by_key(|(_, last_accessed)| *last_accessed);
+
+                for (id, _) in candidates {
+                    if total <= budget {
+                        break;
+                    }
+                    if let Some(entry) = self.entries.remove(&id) {
+                        total -= entry.size_bytes;
+                        unloaded.push(id);
+                    }
+                }
+            }
+        }
+
+        unloaded
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_a_working_handle_to_an_inserted_asset() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy::default());
+        cache.insert("tex-a", 42u32, 4);
+
+        let handle = cache.get("tex-a").unwrap();
+        assert_eq!(handle.with(|v| *v), 42);
+    }
+
+    #[test]
+    fn sweep_leaves_referenced_assets_alone() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy {
+            grace_period: Duration::from_secs(0),
+            lru_budget_bytes: None,
+        });
+        let handle = cache.insert("tex-a", 42u32, 4);
+
+        let unloaded = cache.sweep();
+        assert!(unloaded.is_empty());
+        assert_eq!(cache.len(), 1);
+        drop(handle);
+    }
+
+    #[test]
+    fn sweep_unloads_unreferenced_assets_after_the_grace_period() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy {
+            grace_period: Duration::from_millis(0),
+            lru_budget_bytes: None,
+        });
+        let handle = cache.insert("tex-a", 42u32, 4);
+        drop(handle);
+
+        // The first sweep that sees zero strong handles starts the
+        // grace-period clock; with a zero grace period that same sweep
+        // already finds it expired.
+        let unloaded = cache.sweep();
+
+        assert_eq!(unloaded, vec!["tex-a".to_string()]);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn get_cancels_a_pending_unload() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy {
+            grace_period: Duration::from_secs(60),
+            lru_budget_bytes: None,
+        });
+        let handle = cache.insert("tex-a", 42u32, 4);
+        drop(handle);
+        cache.sweep();
+
+        let handle = cache.get("tex-a").unwrap();
+        let unloaded = cache.sweep();
+
+        assert!(unloaded.is_empty());
+        assert_eq!(handle.with(|v| *v), 42);
+    }
+
+    #[test]
+    fn sweep_evicts_lru_unreferenced_assets_over_budget() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy {
+            grace_period: Duration::from_secs(60),
+            lru_budget_bytes: Some(10),
+        });
+        cache.insert("old", 1u32, 6);
+        std::thread::sleep(Duration::from_millis(1));
+        cache.insert("new", 2u32, 6);
+
+        let unloaded = cache.sweep();
+
+        assert_eq!(unloaded, vec!["old".to_string()]);
+        assert!(cache.get("new").is_some());
+    }
+
+    #[test]
+    fn hot_swap_updates_contents_seen_by_existing_handles() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy::default());
+        let handle = cache.insert("tex-a", 1u32, 4);
+        assert_eq!(handle.with(|v| *v), 1);
+
+        assert!(cache.hot_swap("tex-a", 2u32, 4));
+        assert_eq!(handle.with(|v| *v), 2);
+    }
+
+    #[test]
+    fn hot_swap_of_an_unknown_id_returns_false() {
+        let mut cache: RefCountedAssetCache<u32> = RefCountedAssetCache::new(UnloadPolicy::default());
+        assert!(!cache.hot_swap("missing", 1, 4));
+    }
+
+    #[test]
+    fn weak_handle_does_not_keep_the_asset_alive_past_grace_period() {
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy {
+            grace_period: Duration::from_millis(0),
+            lru_budget_bytes: None,
+        });
+        let handle = cache.insert("tex-a", 42u32, 4);
+        let weak = handle.downgrade();
+        drop(handle);
+
+        cache.sweep();
+        std::thread::sleep(Duration::from_millis(1));
+        cache.sweep();
+
+        assert!(weak.upgrade().is_none());
+    }
+}
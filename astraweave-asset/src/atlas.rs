@@ -0,0 +1,454 @@
+//! Texture atlas packing for the asset pipeline.
+//!
+//! Draw-call-heavy scenes (UI, biome decals, sprite-based effects) benefit
+//! from sharing one texture across many small draws. [`AtlasBuilder`] packs
+//! a set of already-registered textures into one or more atlas pages with a
+//! max-rects bin packer, writes each page as a PNG, and registers the pages
+//! plus a UV remap manifest (source texture GUID -> normalized rect within
+//! its page) back into the [`crate::AssetDatabase`] so the renderer and UI
+//! systems can look up where a texture ended up.
+
+use crate::{AssetDatabase, AssetKind};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Where one source texture landed after packing.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UvRemap {
+    /// Index into [`AtlasManifest::page_guids`].
+    pub page: usize,
+    pub uv_min: [f32; 2],
+    pub uv_max: [f32; 2],
+}
+
+/// The sidecar registered alongside the atlas pages themselves, mapping
+/// each source texture's GUID to where it landed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AtlasManifest {
+    pub page_guids: Vec<String>,
+    pub remap: HashMap<String, UvRemap>,
+}
+
+/// Packs textures into atlas pages with a max-rects bin packer.
+#[derive(Debug, Clone, Copy)]
+pub struct AtlasBuilder {
+    /// Width and height (square) of each atlas page.
+    pub max_page_size: u32,
+    /// Gap, in pixels, kept around each packed texture to avoid bleeding.
+    pub padding: u32,
+}
+
+impl Default for AtlasBuilder {
+    fn default() -> Self {
+        Self {
+            max_page_size: 4096,
+            padding: 2,
+        }
+    }
+}
+
+impl AtlasBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Packs the textures already registered under `guids` (their
+    /// [`AssetMetadata::path`](crate::AssetMetadata::path) must be a
+    /// readable image file), writes the resulting pages under
+    /// `output_dir`, and registers the pages plus an [`AtlasManifest`]
+    /// sidecar in `db`. Returns the manifest's GUID.
+    pub fn build_and_register(
+        &self,
+        db: &mut AssetDatabase,
+        guids: &[String],
+        output_dir: &Path,
+    ) -> Result<String> {
+        std::fs::create_dir_all(output_dir)?;
+
+        let mut sources = Vec::with_capacity(guids.len());
+        for guid in guids {
+            let meta = db
+                .get_asset(guid)
+                .with_context(|| format!("unknown asset guid: {guid}"))?
+                .clone();
+            let image = image::open(&meta.path)
+                .with_context(|| format!("failed to open texture at {}", meta.path))?
+                .to_rgba8();
+            sources.push((guid.clone(), image));
+        }
+
+        let pages = pack_into_pages(&sources, self.max_page_size, self.padding)?;
+
+        let mut page_guids = Vec::with_capacity(pages.len());
+        let mut remap = HashMap::new();
+        for (page_index, page) in pages.iter().enumerate() {
+            let page_path = output_dir.join(format!("atlas_{page_index}.png"));
+            page.canvas.save(&page_path)?;
+            let page_guid =
+                db.register_asset(&page_path, AssetKind::Texture, page.source_guids.clone())?;
+
+            let (page_w, page_h) = (page.canvas.width() as f32, page.canvas.height() as f32);
+            for placed in &page.placements {
+                remap.insert(
+                    placed.guid.clone(),
+                    UvRemap {
+                        page: page_index,
+                        uv_min: [placed.x as f32 / page_w, placed.y as f32 / page_h],
+                        uv_max: [
+                            (placed.x + placed.width) as f32 / page_w,
+                            (placed.y + placed.height) as f32 / page_h,
+                        ],
+                    },
+                );
+            }
+            page_guids.push(page_guid);
+        }
+
+        let manifest = AtlasManifest {
+            page_guids: page_guids.clone(),
+            remap,
+        };
+        let manifest_path = output_dir.join("atlas_manifest.ron");
+        std::fs::write(
+            &manifest_path,
+            ron::ser::to_string_pretty(&manifest, ron::ser::PrettyConfig::default())?,
+        )?;
+
+        db.register_asset(&manifest_path, AssetKind::Other, page_guids)
+    }
+}
+
+struct PlacedRect {
+    guid: String,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+struct AtlasPage {
+    canvas: image::RgbaImage,
+    placements: Vec<PlacedRect>,
+    source_guids: Vec<String>,
+}
+
+/// Greedily fills pages of `page_size`x`page_size`, largest texture first,
+/// starting a new page whenever the current one runs out of room.
+fn pack_into_pages(
+    sources: &[(String, image::RgbaImage)],
+    page_size: u32,
+    padding: u32,
+) -> Result<Vec<AtlasPage>> {
+    let mut order: Vec<&(String, image::RgbaImage)> = sources.iter().collect();
+    order.sort_by_key(|(_, img)| std::cmp::Reverse(img.width().max(img.height())));
+
+    let mut pages = Vec::new();
+    let mut index = 0;
+    while index < order.len() {
+        let mut packer = MaxRectsPacker::new(page_size, page_size);
+        let mut canvas = image::RgbaImage::new(page_size, page_size);
+        let mut placements = Vec::new();
+        let mut source_guids = Vec::new();
+        let page_start = index;
+
+        while index < order.len() {
+            let (guid, image) = order[index];
+            let padded_w = image.width() + padding;
+            let padded_h = image.height() + padding;
+
+            match packer.insert(padded_w, padded_h) {
+                Some(placed) => {
+                    image::imageops::overlay(&mut canvas, image, placed.x as i64, placed.y as i64);
+                    placements.push(PlacedRect {
+                        guid: guid.clone(),
+                        x: placed.x,
+                        y: placed.y,
+                        width: image.width(),
+                        height: image.height(),
+                    });
+                    source_guids.push(guid.clone());
+                    index += 1;
+                }
+                None => break,
+            }
+        }
+
+        if index == page_start {
+            let (guid, image) = order[index];
+            anyhow::bail!(
+                "texture {guid} ({}x{}) is larger than the maximum atlas page size ({page_size})",
+                image.width(),
+                image.height()
+            );
+        }
+
+        pages.push(AtlasPage {
+            canvas,
+            placements,
+            source_guids,
+        });
+    }
+
+    Ok(pages)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FreeRect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// Top-left placement of a rect the caller asked to insert.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    x: u32,
+    y: u32,
+}
+
+/// A max-rects bin packer using the best-short-side-fit heuristic: each
+/// insert picks the free rectangle that wastes the least space along its
+/// shorter leftover side, then splits every free rectangle the placement
+/// overlaps and prunes any free rectangle now fully contained in another.
+struct MaxRectsPacker {
+    free_rects: Vec<FreeRect>,
+}
+
+impl MaxRectsPacker {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            free_rects: vec![FreeRect {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            }],
+        }
+    }
+
+    fn insert(&mut self, width: u32, height: u32) -> Option<Placement> {
+        let mut best_index = None;
+        let mut best_short_side = u32::MAX;
+        let mut best_long_side = u32::MAX;
+
+        for (i, free) in self.free_rects.iter().enumerate() {
+            if free.width < width || free.height < height {
+                continue;
+            }
+            let leftover_short = (free.width - width).min(free.height - height);
+            let leftover_long = (free.width - width).max(free.height - height);
+            if leftover_short < best_short_side
+                || (leftover_short == best_short_side && leftover_long < best_long_side)
+            {
+                best_short_side = leftover_short;
+                best_long_side = leftover_long;
+                best_index = Some(i);
+            }
+        }
+
+        let chosen = *self.free_rects.get(best_index?)?;
+        let used = FreeRect {
+            x: chosen.x,
+            y: chosen.y,
+            width,
+            height,
+        };
+
+        let mut next_free = Vec::with_capacity(self.free_rects.len());
+        for free in &self.free_rects {
+            split_free_rect(free, &used, &mut next_free);
+        }
+        prune_contained(&mut next_free);
+        self.free_rects = next_free;
+
+        Some(Placement {
+            x: used.x,
+            y: used.y,
+        })
+    }
+}
+
+fn overlaps(a: &FreeRect, b: &FreeRect) -> bool {
+    a.x < b.x + b.width && a.x + a.width > b.x && a.y < b.y + b.height && a.y + a.height > b.y
+}
+
+fn contains(outer: &FreeRect, inner: &FreeRect) -> bool {
+    inner.x >= outer.x
+        && inner.y >= outer.y
+        && inner.x + inner.width <= outer.x + outer.width
+        && inner.y + inner.height <= outer.y + outer.height
+}
+
+/// Splits `free` around `used` into the (up to four) leftover rectangles,
+/// pushing them into `out`. Leaves `free` untouched if it doesn't overlap.
+fn split_free_rect(free: &FreeRect, used: &FreeRect, out: &mut Vec<FreeRect>) {
+    if !overlaps(free, used) {
+        out.push(*free);
+        return;
+    }
+
+    if used.x > free.x {
+        out.push(FreeRect {
+            x: free.x,
+            y: free.y,
+            width: used.x - free.x,
+            height: free.height,
+        });
+    }
+    if used.x + used.width < free.x + free.width {
+        out.push(FreeRect {
+            x: used.x + used.width,
+            y: free.y,
+            width: (free.x + free.width) - (used.x + used.width),
+            height: free.height,
+        });
+    }
+    if used.y > free.y {
+        out.push(FreeRect {
+            x: free.x,
+            y: free.y,
+            width: free.width,
+            height: used.y - free.y,
+        });
+    }
+    if used.y + used.height < free.y + free.height {
+        out.push(FreeRect {
+            x: free.x,
+            y: used.y + used.height,
+            width: free.width,
+            height: (free.y + free.height) - (used.y + used.height),
+        });
+    }
+}
+
+fn prune_contained(rects: &mut Vec<FreeRect>) {
+    rects.retain(|r| r.width > 0 && r.height > 0);
+    let snapshot = rects.clone();
+    let mut kept = Vec::with_capacity(snapshot.len());
+    for (i, r) in snapshot.iter().enumerate() {
+        let redundant = snapshot
+            .iter()
+            .enumerate()
+            .any(|(j, other)| i != j && contains(other, r));
+        if !redundant {
+            kept.push(*r);
+        }
+    }
+    *rects = kept;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+    use tempfile::TempDir;
+
+    fn solid_texture(dir: &Path, name: &str, width: u32, height: u32) -> String {
+        let path = dir.join(name);
+        let img = RgbaImage::from_pixel(width, height, Rgba([255, 0, 0, 255]));
+        img.save(&path).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    fn register_texture(db: &mut AssetDatabase, dir: &Path, name: &str, w: u32, h: u32) -> String {
+        let path_str = solid_texture(dir, name, w, h);
+        db.register_asset(Path::new(&path_str), AssetKind::Texture, vec![])
+            .unwrap()
+    }
+
+    #[test]
+    fn max_rects_packer_places_a_single_rect_at_the_origin() {
+        let mut packer = MaxRectsPacker::new(256, 256);
+        let placed = packer.insert(64, 64).unwrap();
+        assert_eq!((placed.x, placed.y), (0, 0));
+    }
+
+    #[test]
+    fn max_rects_packer_rejects_a_rect_larger_than_the_bin() {
+        let mut packer = MaxRectsPacker::new(64, 64);
+        assert!(packer.insert(128, 128).is_none());
+    }
+
+    #[test]
+    fn max_rects_packer_places_non_overlapping_rects() {
+        let mut packer = MaxRectsPacker::new(128, 128);
+        let a = packer.insert(64, 64).unwrap();
+        let b = packer.insert(64, 64).unwrap();
+        let c = packer.insert(64, 64).unwrap();
+
+        let rects = [(a, 64, 64), (b, 64, 64), (c, 64, 64)];
+        for i in 0..rects.len() {
+            for j in (i + 1)..rects.len() {
+                let (pa, wa, ha) = &rects[i];
+                let (pb, wb, hb) = &rects[j];
+                let a_rect = FreeRect { x: pa.x, y: pa.y, width: *wa, height: *ha };
+                let b_rect = FreeRect { x: pb.x, y: pb.y, width: *wb, height: *hb };
+                assert!(!overlaps(&a_rect, &b_rect));
+            }
+        }
+    }
+
+    #[test]
+    fn build_and_register_produces_uv_remaps_within_unit_range() {
+        let dir = TempDir::new().unwrap();
+        let mut db = AssetDatabase::new();
+        let a = register_texture(&mut db, dir.path(), "a.png", 32, 32);
+        let b = register_texture(&mut db, dir.path(), "b.png", 16, 16);
+
+        let output_dir = dir.path().join("atlas_out");
+        let manifest_guid = AtlasBuilder::new()
+            .build_and_register(&mut db, &[a.clone(), b.clone()], &output_dir)
+            .unwrap();
+
+        let manifest_meta = db.get_asset(&manifest_guid).unwrap();
+        let manifest: AtlasManifest =
+            ron::de::from_str(&std::fs::read_to_string(&manifest_meta.path).unwrap()).unwrap();
+
+        assert_eq!(manifest.remap.len(), 2);
+        for remap in manifest.remap.values() {
+            assert!(remap.uv_min[0] >= 0.0 && remap.uv_max[0] <= 1.0);
+            assert!(remap.uv_min[1] >= 0.0 && remap.uv_max[1] <= 1.0);
+            assert!(remap.uv_min[0] < remap.uv_max[0]);
+        }
+    }
+
+    #[test]
+    fn build_and_register_errors_on_a_texture_larger_than_the_page() {
+        let dir = TempDir::new().unwrap();
+        let mut db = AssetDatabase::new();
+        let huge = register_texture(&mut db, dir.path(), "huge.png", 8, 8);
+
+        let builder = AtlasBuilder {
+            max_page_size: 4,
+            padding: 0,
+        };
+        let output_dir = dir.path().join("atlas_out");
+        let result = builder.build_and_register(&mut db, &[huge], &output_dir);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn build_and_register_spills_into_a_second_page_when_the_first_is_full() {
+        let dir = TempDir::new().unwrap();
+        let mut db = AssetDatabase::new();
+        let a = register_texture(&mut db, dir.path(), "a.png", 48, 48);
+        let b = register_texture(&mut db, dir.path(), "b.png", 48, 48);
+
+        let builder = AtlasBuilder {
+            max_page_size: 64,
+            padding: 0,
+        };
+        let output_dir = dir.path().join("atlas_out");
+        let manifest_guid = builder
+            .build_and_register(&mut db, &[a, b], &output_dir)
+            .unwrap();
+
+        let manifest_meta = db.get_asset(&manifest_guid).unwrap();
+        let manifest: AtlasManifest =
+            ron::de::from_str(&std::fs::read_to_string(&manifest_meta.path).unwrap()).unwrap();
+        assert_eq!(manifest.page_guids.len(), 2);
+    }
+}
@@ -0,0 +1,326 @@
+//! Real audio asset import: decode, resample, loudness-normalize, encode.
+//!
+//! `import_pipelines::import_audio` used to just copy source bytes to the
+//! output path. This decodes WAV natively via `hound` (the same crate
+//! astraweave-audio's mock TTS backend already depends on) and falls back to
+//! `rodio::Decoder` for Ogg Vorbis/MP3/FLAC, resamples to the engine's
+//! target sample rate with linear interpolation, and normalizes loudness to
+//! a configurable target using an RMS-power loudness estimate. That estimate
+//! tracks perceived loudness well enough to level game audio assets, but it
+//! omits the K-weighting filter and gating windows a true ITU-R BS.1770 LUFS
+//! meter uses, so it should not be read as a certified LUFS value. The
+//! result is re-encoded as 16-bit PCM WAV, which is the engine's compact
+//! runtime playback format; there's no separate bitstream codec here.
+
+use anyhow::{bail, Result};
+use rodio::Source;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Tunables for [`import_audio`].
+#[derive(Debug, Clone, Copy)]
+pub struct AudioImportSettings {
+    /// Sample rate every imported asset is resampled to.
+    pub target_sample_rate: u32,
+    /// Loudness target, in (approximate) LUFS. Negative; louder is closer to 0.
+    pub target_lufs: f32,
+}
+
+impl Default for AudioImportSettings {
+    fn default() -> Self {
+        Self {
+            target_sample_rate: 48_000,
+            target_lufs: -16.0,
+        }
+    }
+}
+
+/// Per-asset facts recorded on [`crate::AssetMetadata::audio`] after import.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioImportMetadata {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub duration_secs: f32,
+    /// Measured loudness after normalization; see the module docs for why
+    /// this is an approximation rather than a certified LUFS value.
+    pub integrated_loudness_lufs: f32,
+}
+
+/// Decodes `source`, resamples and loudness-normalizes it per `settings`,
+/// writes the result to `output` as 16-bit PCM WAV, and returns the
+/// resulting facts about it.
+pub fn import_audio(
+    source: &Path,
+    output: &Path,
+    settings: &AudioImportSettings,
+) -> Result<AudioImportMetadata> {
+    let (samples, sample_rate, channels) = decode_audio(source)?;
+
+    let mut samples = if sample_rate == settings.target_sample_rate {
+        samples
+    } else {
+        resample(&samples, channels, sample_rate, settings.target_sample_rate)
+    };
+    let sample_rate = settings.target_sample_rate;
+
+    let integrated_loudness_lufs = normalize_loudness(&mut samples, settings.target_lufs);
+    encode_wav(output, &samples, sample_rate, channels)?;
+
+    let duration_secs = if channels == 0 || sample_rate == 0 {
+        0.0
+    } else {
+        samples.len() as f32 / channels as f32 / sample_rate as f32
+    };
+
+    Ok(AudioImportMetadata {
+        sample_rate,
+        channels,
+        duration_secs,
+        integrated_loudness_lufs,
+    })
+}
+
+/// Decodes `source` into interleaved i16 samples plus its native sample
+/// rate and channel count.
+fn decode_audio(source: &Path) -> Result<(Vec<i16>, u32, u16)> {
+    let ext = source
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    match ext.as_str() {
+        "wav" => decode_wav(source),
+        "ogg" | "mp3" | "flac" => decode_with_rodio(source),
+        other => bail!("unsupported audio source format: .{other}"),
+    }
+}
+
+fn decode_wav(source: &Path) -> Result<(Vec<i16>, u32, u16)> {
+    let mut reader = hound::WavReader::open(source)?;
+    let spec = reader.spec();
+
+    let samples: Vec<i16> = match (spec.sample_format, spec.bits_per_sample) {
+        (hound::SampleFormat::Int, 16) => reader.samples::<i16>().collect::<Result<_, _>>()?,
+        (hound::SampleFormat::Int, 8) => reader
+            .samples::<i8>()
+            .map(|s| s.map(|v| (v as i16) << 8))
+            .collect::<Result<_, _>>()?,
+        (hound::SampleFormat::Int, 32) => reader
+            .samples::<i32>()
+            .map(|s| s.map(|v| (v >> 16) as i16))
+            .collect::<Result<_, _>>()?,
+        (hound::SampleFormat::Float, _) => reader
+            .samples::<f32>()
+            .map(|s| s.map(|v| (v.clamp(-1.0, 1.0) * i16::MAX as f32) as i16))
+            .collect::<Result<_, _>>()?,
+        (_, bits) => bail!("unsupported WAV bit depth: {bits}"),
+    };
+
+    Ok((samples, spec.sample_rate, spec.channels))
+}
+
+fn decode_with_rodio(source: &Path) -> Result<(Vec<i16>, u32, u16)> {
+    let file = std::fs::File::open(source)?;
+    let decoder = rodio::Decoder::new(std::io::BufReader::new(file))
+        .map_err(|e| anyhow::anyhow!("failed to decode {}: {e}", source.display()))?;
+    let sample_rate = decoder.sample_rate();
+    let channels = decoder.channels();
+    Ok((decoder.collect(), sample_rate, channels))
+}
+
+/// Linear-interpolation resample of interleaved `samples` from `from_rate`
+/// to `to_rate`. Good enough for one-shot asset import; not a streaming or
+/// band-limited resampler.
+fn resample(samples: &[i16], channels: u16, from_rate: u32, to_rate: u32) -> Vec<i16> {
+    if samples.is_empty() || from_rate == to_rate || channels == 0 {
+        return samples.to_vec();
+    }
+
+    let channels = channels as usize;
+    let frames_in = samples.len() / channels;
+    if frames_in == 0 {
+        return Vec::new();
+    }
+
+    let ratio = to_rate as f64 / from_rate as f64;
+    let frames_out = ((frames_in as f64) * ratio).round().max(1.0) as usize;
+    let mut out = Vec::with_capacity(frames_out * channels);
+
+    for frame_out in 0..frames_out {
+        let src_pos = frame_out as f64 / ratio;
+        let idx0 = (src_pos.floor() as usize).min(frames_in - 1);
+        let idx1 = (idx0 + 1).min(frames_in - 1);
+        let frac = (src_pos - idx0 as f64) as f32;
+
+        for ch in 0..channels {
+            let s0 = samples[idx0 * channels + ch] as f32;
+            let s1 = samples[idx1 * channels + ch] as f32;
+            let interpolated = s0 + (s1 - s0) * frac;
+            out.push(interpolated.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16);
+        }
+    }
+
+    out
+}
+
+/// RMS-power loudness estimate in dBFS; see module docs for the caveat
+/// against reading it as a certified LUFS value.
+fn measure_loudness_lufs(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return f32::NEG_INFINITY;
+    }
+    let sum_sq: f64 = samples
+        .iter()
+        .map(|&s| {
+            let v = s as f64 / i16::MAX as f64;
+            v * v
+        })
+        .sum();
+    let mean_sq = sum_sq / samples.len() as f64;
+    if mean_sq <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    (10.0 * mean_sq.log10()) as f32
+}
+
+/// Applies gain in place so `samples` measures at `target_lufs`, and
+/// returns the loudness actually achieved (may differ slightly if the gain
+/// clipped any samples).
+fn normalize_loudness(samples: &mut [i16], target_lufs: f32) -> f32 {
+    let measured = measure_loudness_lufs(samples);
+    if !measured.is_finite() {
+        return measured;
+    }
+
+    let gain = 10f32.powf((target_lufs - measured) / 20.0);
+    for s in samples.iter_mut() {
+        *s = ((*s as f32) * gain).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+    }
+
+    measure_loudness_lufs(samples)
+}
+
+fn encode_wav(output: &Path, samples: &[i16], sample_rate: u32, channels: u16) -> Result<()> {
+    let spec = hound::WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(output, spec)?;
+    for &s in samples {
+        writer.write_sample(s)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_test_wav(path: &Path, sample_rate: u32, channels: u16, freq_hz: f32, secs: f32) {
+        let spec = hound::WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        let frame_count = (sample_rate as f32 * secs) as usize;
+        for i in 0..frame_count {
+            let t = i as f32 / sample_rate as f32;
+            let sample = (t * freq_hz * std::f32::consts::TAU).sin() * (i16::MAX as f32 * 0.1);
+            for _ in 0..channels {
+                writer.write_sample(sample as i16).unwrap();
+            }
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn imports_a_wav_and_reports_metadata() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("tone.wav");
+        let output = dir.path().join("tone_imported.wav");
+        write_test_wav(&source, 44_100, 2, 440.0, 0.5);
+
+        let meta = import_audio(&source, &output, &AudioImportSettings::default()).unwrap();
+
+        assert!(output.exists());
+        assert_eq!(meta.sample_rate, 48_000);
+        assert_eq!(meta.channels, 2);
+        assert!((meta.duration_secs - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn resamples_to_the_target_rate() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("tone.wav");
+        let output = dir.path().join("tone_imported.wav");
+        write_test_wav(&source, 22_050, 1, 220.0, 0.25);
+
+        let settings = AudioImportSettings {
+            target_sample_rate: 48_000,
+            ..Default::default()
+        };
+        let meta = import_audio(&source, &output, &settings).unwrap();
+
+        let reader = hound::WavReader::open(&output).unwrap();
+        assert_eq!(reader.spec().sample_rate, 48_000);
+        assert_eq!(meta.sample_rate, 48_000);
+    }
+
+    #[test]
+    fn normalizes_loudness_toward_the_target() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("quiet.wav");
+        let output = dir.path().join("quiet_imported.wav");
+        write_test_wav(&source, 48_000, 1, 440.0, 0.5);
+
+        let settings = AudioImportSettings {
+            target_lufs: -6.0,
+            ..Default::default()
+        };
+        let meta = import_audio(&source, &output, &settings).unwrap();
+
+        // The un-normalized tone is quiet (10% amplitude); after normalizing
+        // toward -6 LUFS it should land much closer to the target than
+        // wherever it started.
+        assert!((meta.integrated_loudness_lufs - (-6.0)).abs() < 3.0);
+    }
+
+    #[test]
+    fn rejects_an_unsupported_extension() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("notes.txt");
+        std::fs::write(&source, b"not audio").unwrap();
+        let output = dir.path().join("out.wav");
+
+        let result = import_audio(&source, &output, &AudioImportSettings::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn silence_reports_negative_infinity_loudness_without_panicking() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("silence.wav");
+        let output = dir.path().join("silence_imported.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 48_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&source, spec).unwrap();
+        for _ in 0..1000 {
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let meta = import_audio(&source, &output, &AudioImportSettings::default()).unwrap();
+        assert!(meta.integrated_loudness_lufs.is_infinite());
+    }
+}
@@ -17,6 +17,10 @@ pub enum AssetKind {
     Material,
     Audio,
     Animation,
+    /// A Handlebars prompt template (see `astraweave_prompts::PromptTemplate`),
+    /// registered so designers can hot-reload AI prompt text through the
+    /// same [`crate::AssetDatabase`] pipeline as meshes/textures.
+    PromptTemplate,
     Other,
 }
 
@@ -259,6 +263,13 @@ pub async fn load_asset(asset_ref: &AssetRef, assets_root: &Path) -> Result<Vec<
             ))?;
             Ok(bytes)
         }
+        AssetKind::PromptTemplate => {
+            let bytes = fs::read(&asset_path).await.context(format!(
+                "Failed to load prompt template: {}",
+                asset_path.display()
+            ))?;
+            Ok(bytes)
+        }
         AssetKind::Other => {
             let bytes = fs::read(&asset_path)
                 .await
@@ -335,6 +346,7 @@ mod tests {
         assert_eq!(AssetKind::Material, AssetKind::Material);
         assert_eq!(AssetKind::Audio, AssetKind::Audio);
         assert_eq!(AssetKind::Animation, AssetKind::Animation);
+        assert_eq!(AssetKind::PromptTemplate, AssetKind::PromptTemplate);
         assert_eq!(AssetKind::Other, AssetKind::Other);
     }
 
@@ -343,6 +355,7 @@ mod tests {
         assert_ne!(AssetKind::Mesh, AssetKind::Texture);
         assert_ne!(AssetKind::Audio, AssetKind::Animation);
         assert_ne!(AssetKind::Material, AssetKind::Other);
+        assert_ne!(AssetKind::PromptTemplate, AssetKind::Other);
     }
 
     #[test]
@@ -353,6 +366,7 @@ mod tests {
             AssetKind::Material,
             AssetKind::Audio,
             AssetKind::Animation,
+            AssetKind::PromptTemplate,
             AssetKind::Other,
         ];
         for kind in kinds {
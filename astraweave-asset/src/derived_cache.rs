@@ -0,0 +1,356 @@
+//! Content-addressed cache for derived (imported) assets.
+//!
+//! `import_pipelines::import_texture`, Blender-to-glTF conversion, and
+//! Nanite meshlet preprocessing are all pure functions of a source file's
+//! bytes plus the importer's own version and settings: given the same
+//! three inputs, they produce the same output. This cache keys prior
+//! outputs by `(source content hash, importer version, settings hash)` so
+//! [`import_with_cache`] can skip reprocessing when nothing that matters
+//! has changed, and [`AssetDatabase::rebuild`] can do the same at the
+//! whole-database level for a `--dirty-only` style re-import.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Identifies one cached derived-asset output.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub source_hash: String,
+    pub importer_version: u32,
+    pub settings_hash: String,
+}
+
+impl CacheKey {
+    pub fn new(
+        source_hash: impl Into<String>,
+        importer_version: u32,
+        settings_hash: impl Into<String>,
+    ) -> Self {
+        Self {
+            source_hash: source_hash.into(),
+            importer_version,
+            settings_hash: settings_hash.into(),
+        }
+    }
+
+    /// Hashes any `Serialize`-able settings struct into a stable digest
+    /// suitable for [`Self::new`]'s `settings_hash`.
+    pub fn hash_settings<T: Serialize>(settings: &T) -> Result<String> {
+        let bytes = serde_json::to_vec(settings)?;
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        Ok(hex::encode(hasher.finalize()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    output_path: PathBuf,
+}
+
+/// A persistent, content-addressed cache of derived-asset outputs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DerivedAssetCache {
+    entries: HashMap<CacheKey, CacheEntry>,
+}
+
+impl DerivedAssetCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a previously-[`save`](Self::save)d cache, or an empty one if
+    /// `path` doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the previously-produced output path for `key`, unless its
+    /// output has since been deleted by hand, in which case it's treated
+    /// as a miss so the importer regenerates it.
+    pub fn lookup(&self, key: &CacheKey) -> Option<&Path> {
+        self.entries
+            .get(key)
+            .map(|e| e.output_path.as_path())
+            .filter(|p| p.exists())
+    }
+
+    pub fn insert(&mut self, key: CacheKey, output_path: PathBuf) {
+        self.entries.insert(key, CacheEntry { output_path });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Runs `import(source, output)` unless `cache` already holds a
+/// still-valid output for `source`'s current content hash, `importer_version`,
+/// and `settings_hash` — in which case that cached output is copied to
+/// `output` instead of re-running `import`. Returns whether `import` ran.
+pub fn import_with_cache(
+    cache: &mut DerivedAssetCache,
+    source: &Path,
+    output: &Path,
+    importer_version: u32,
+    settings_hash: &str,
+    import: impl FnOnce(&Path, &Path) -> Result<()>,
+) -> Result<bool> {
+    let key = CacheKey::new(hash_file(source)?, importer_version, settings_hash);
+
+    if let Some(cached_output) = cache.lookup(&key) {
+        if cached_output != output {
+            if let Some(parent) = output.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(cached_output, output)?;
+        }
+        return Ok(false);
+    }
+
+    import(source, output)?;
+    cache.insert(key, output.to_path_buf());
+    Ok(true)
+}
+
+/// Byte-keyed variant of [`import_with_cache`] for derived data that isn't
+/// produced from a single source file on disk — e.g. Nanite meshlet
+/// hierarchies, which are computed from in-memory vertex/index buffers.
+/// `source_bytes` should be a stable serialization of whatever inputs
+/// determine the output. On a cache hit, `load(output_path)` is returned
+/// instead of running `compute`.
+pub fn compute_with_cache<T>(
+    cache: &mut DerivedAssetCache,
+    source_bytes: &[u8],
+    importer_version: u32,
+    settings_hash: &str,
+    output_path: &Path,
+    compute: impl FnOnce() -> Result<T>,
+    save: impl FnOnce(&T, &Path) -> Result<()>,
+    load: impl FnOnce(&Path) -> Result<T>,
+) -> Result<T> {
+    let mut hasher = Sha256::new();
+    hasher.update(source_bytes);
+    let key = CacheKey::new(hex::encode(hasher.finalize()), importer_version, settings_hash);
+
+    if let Some(cached_output) = cache.lookup(&key) {
+        return load(cached_output);
+    }
+
+    let value = compute()?;
+    save(&value, output_path)?;
+    cache.insert(key, output_path.to_path_buf());
+    Ok(value)
+}
+
+fn hash_file(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &[u8]) {
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn runs_the_importer_on_a_cache_miss_and_records_the_output() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        let ran = import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(ran);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(std::fs::read(&output).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn skips_the_importer_on_an_unchanged_source() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        let ran_again = import_with_cache(&mut cache, &source, &output, 1, "settings-a", |_, _| {
+            panic!("importer should not run on a cache hit");
+        })
+        .unwrap();
+
+        assert!(!ran_again);
+    }
+
+    #[test]
+    fn reruns_the_importer_when_the_source_content_changes() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        write(&source, b"changed");
+        let ran_again = import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(ran_again);
+        assert_eq!(std::fs::read(&output).unwrap(), b"changed");
+    }
+
+    #[test]
+    fn reruns_the_importer_when_the_importer_version_or_settings_change() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        let ran_new_version = import_with_cache(&mut cache, &source, &output, 2, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+        assert!(ran_new_version);
+
+        let ran_new_settings = import_with_cache(&mut cache, &source, &output, 2, "settings-b", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+        assert!(ran_new_settings);
+    }
+
+    #[test]
+    fn reruns_the_importer_when_the_cached_output_was_deleted_by_hand() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        std::fs::remove_file(&output).unwrap();
+        let ran_again = import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(ran_again);
+    }
+
+    #[test]
+    fn compute_with_cache_skips_recompute_on_a_hit() {
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("derived.json");
+        let mut cache = DerivedAssetCache::new();
+
+        let value = compute_with_cache(
+            &mut cache,
+            b"same-inputs",
+            1,
+            "default",
+            &output,
+            || Ok(42u32),
+            |v, p| Ok(std::fs::write(p, v.to_string())?),
+            |p| Ok(std::fs::read_to_string(p)?.parse::<u32>()?),
+        )
+        .unwrap();
+        assert_eq!(value, 42);
+
+        let value_again = compute_with_cache(
+            &mut cache,
+            b"same-inputs",
+            1,
+            "default",
+            &output,
+            || panic!("compute should not run on a cache hit"),
+            |v: &u32, p| Ok(std::fs::write(p, v.to_string())?),
+            |p| Ok(std::fs::read_to_string(p)?.parse::<u32>()?),
+        )
+        .unwrap();
+        assert_eq!(value_again, 42);
+    }
+
+    #[test]
+    fn survives_a_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source.bin");
+        let output = dir.path().join("output.bin");
+        let cache_path = dir.path().join("cache.json");
+        write(&source, b"hello");
+
+        let mut cache = DerivedAssetCache::new();
+        import_with_cache(&mut cache, &source, &output, 1, "settings-a", |s, o| {
+            std::fs::copy(s, o)?;
+            Ok(())
+        })
+        .unwrap();
+        cache.save(&cache_path).unwrap();
+
+        let mut reloaded = DerivedAssetCache::load(&cache_path).unwrap();
+        let ran_again = import_with_cache(&mut reloaded, &source, &output, 1, "settings-a", |_, _| {
+            panic!("importer should not run on a cache hit after reload");
+        })
+        .unwrap();
+        assert!(!ran_again);
+    }
+}
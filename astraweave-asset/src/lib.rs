@@ -15,6 +15,21 @@ pub mod nanite_preprocess;
 // World Partition cell loader
 pub mod cell_loader;
 
+// Offline light baking (lightmaps + irradiance volumes)
+pub mod light_baking;
+
+// Audio import: decode, resample, loudness-normalize
+pub mod audio_import;
+
+// Texture atlas packing (max-rects) + UV remap manifest
+pub mod atlas;
+
+// Content-addressed cache for derived (imported) assets
+pub mod derived_cache;
+
+// Reference-counted runtime asset cache with automatic unload
+pub mod asset_handle;
+
 // Mutation tests
 #[cfg(test)]
 mod mutation_tests;
@@ -216,6 +231,7 @@ pub mod gltf_loader {
     use anyhow::{anyhow, bail, Context, Result};
     use base64::Engine as _;
     use gltf::Gltf;
+    use std::collections::HashMap;
 
     /// Minimal glTF loader prototype: validates header and detects JSON vs BIN format.
     /// Phase 0 scope: we only recognize GLB header and return an error if unsupported.
@@ -738,6 +754,143 @@ pub mod gltf_loader {
         pub name: String,
         pub duration: f32,
         pub channels: Vec<AnimationChannel>,
+        /// Translation/rotation deltas for the skeleton's root joint, if the
+        /// clip animates it, extracted at import so a character controller
+        /// can drive movement from the clip itself instead of a separate
+        /// velocity parameter.
+        pub root_motion: Option<RootMotionTrack>,
+        /// Named events (footsteps, hit frames, VFX cues) fired during
+        /// playback, sourced from the animation's glTF `extras` if present
+        /// and overridable via [`apply_event_sidecar`]. Empty for clips with
+        /// no authored events.
+        pub events: Vec<AnimationEvent>,
+    }
+
+    /// A single named event fired at a point in an [`AnimationClip`]'s
+    /// playback, e.g. a footstep contact or an attack's hit-frame. `time` is
+    /// in the same units as [`AnimationChannel::times`] (seconds from clip
+    /// start), so it can be compared directly against playback time without
+    /// re-normalizing.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AnimationEvent {
+        pub name: String,
+        pub time: f32,
+    }
+
+    /// A root joint's translation/rotation keyframes, extracted from an
+    /// [`AnimationClip`] by [`extract_root_motion`]. `rotations` is filled
+    /// with identity quaternions when the clip has no rotation channel on
+    /// the root joint, so it always lines up 1:1 with `translations`.
+    #[derive(Debug, Clone)]
+    pub struct RootMotionTrack {
+        pub times: Vec<f32>,
+        pub translations: Vec<[f32; 3]>,
+        pub rotations: Vec<[f32; 4]>,
+    }
+
+    /// Extracts the root joint's translation (and, if present, rotation)
+    /// channel from `channels` as a [`RootMotionTrack`]. Returns `None` if
+    /// the root joint has no animated translation channel.
+    pub fn extract_root_motion(
+        channels: &[AnimationChannel],
+        root_joint_index: usize,
+    ) -> Option<RootMotionTrack> {
+        let (times, translations) = channels.iter().find_map(|c| {
+            if c.target_joint_index != root_joint_index {
+                return None;
+            }
+            match &c.data {
+                ChannelData::Translation(t) => Some((c.times.clone(), t.clone())),
+                _ => None,
+            }
+        })?;
+
+        let rotations = channels
+            .iter()
+            .find_map(|c| {
+                if c.target_joint_index != root_joint_index {
+                    return None;
+                }
+                match &c.data {
+                    ChannelData::Rotation(r) if r.len() == translations.len() => Some(r.clone()),
+                    _ => None,
+                }
+            })
+            .unwrap_or_else(|| vec![[0.0, 0.0, 0.0, 1.0]; translations.len()]);
+
+        Some(RootMotionTrack {
+            times,
+            translations,
+            rotations,
+        })
+    }
+
+    /// Best-effort extraction of an event track from a glTF animation's
+    /// `extras`, e.g. `{"events": [{"name": "footstep_l", "time": 0.1}]}`
+    /// authored by the DCC tool's export pipeline. Malformed or absent
+    /// extras yield an empty track rather than an error, since `extras` is
+    /// not part of the glTF spec's guaranteed shape.
+    fn extract_extras_events(anim: &gltf::Animation) -> Vec<AnimationEvent> {
+        let Some(extras) = anim.extras() else {
+            return Vec::new();
+        };
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(extras.get()) else {
+            return Vec::new();
+        };
+        let Some(events) = value.get("events").and_then(|v| v.as_array()) else {
+            return Vec::new();
+        };
+
+        events
+            .iter()
+            .filter_map(|e| {
+                let name = e.get("name")?.as_str()?.to_string();
+                let time = e.get("time")?.as_f64()? as f32;
+                Some(AnimationEvent { name, time })
+            })
+            .collect()
+    }
+
+    /// Parses a sidecar animation-events manifest for DCC tools that can't
+    /// embed glTF extras, mapping clip name to its event track:
+    /// `{"Walk": [{"name": "footstep_l", "time": 0.1}, {"name": "footstep_r", "time": 0.6}]}`
+    pub fn parse_event_sidecar(json: &str) -> Result<HashMap<String, Vec<AnimationEvent>>> {
+        #[derive(serde::Deserialize)]
+        struct RawEvent {
+            name: String,
+            time: f32,
+        }
+
+        let raw: HashMap<String, Vec<RawEvent>> =
+            serde_json::from_str(json).context("Invalid animation event sidecar JSON")?;
+
+        Ok(raw
+            .into_iter()
+            .map(|(clip_name, events)| {
+                let events = events
+                    .into_iter()
+                    .map(|e| AnimationEvent {
+                        name: e.name,
+                        time: e.time,
+                    })
+                    .collect();
+                (clip_name, events)
+            })
+            .collect())
+    }
+
+    /// Overwrites each clip's event track with the entry from `events`
+    /// matching its name, if any, replacing any track already populated
+    /// from glTF extras. Clips without a matching entry are left untouched.
+    pub fn apply_event_sidecar(
+        clips: &mut [AnimationClip],
+        events: &HashMap<String, Vec<AnimationEvent>>,
+    ) {
+        for clip in clips.iter_mut() {
+            if let Some(track) = events.get(&clip.name) {
+                clip.events = track.clone();
+            }
+        }
     }
 
     /// Load skeleton from glTF/GLB with inverse bind matrices and hierarchy
@@ -890,7 +1043,7 @@ pub mod gltf_loader {
     }
 
     /// Load all animation clips from glTF/GLB
-    pub fn load_animations(bytes: &[u8], _skeleton: &Skeleton) -> Result<Vec<AnimationClip>> {
+    pub fn load_animations(bytes: &[u8], skeleton: &Skeleton) -> Result<Vec<AnimationClip>> {
         let doc = if bytes.len() >= 12 && &bytes[0..4] == b"glTF" {
             let glb = gltf::binary::Glb::from_slice(bytes).context("Invalid GLB container")?;
             let json = std::str::from_utf8(&glb.json).context("GLB JSON is not UTF-8")?;
@@ -1062,10 +1215,15 @@ pub mod gltf_loader {
             }
 
             if !channels.is_empty() {
+                let root_joint_index = skeleton.root_indices.first().copied().unwrap_or(0);
+                let root_motion = extract_root_motion(&channels, root_joint_index);
+                let events = extract_extras_events(&anim);
                 clips.push(AnimationClip {
                     name,
                     duration: max_time,
                     channels,
+                    root_motion,
+                    events,
                 });
             }
         }
@@ -1492,6 +1650,9 @@ pub mod gltf_loader {
                                     data: ChannelData::Rotation(outputs),
                                     interpolation: Interpolation::Linear,
                                 }],
+                                // Legacy single-channel clip only animates rotation.
+                                root_motion: None,
+                                events: Vec::new(),
                             });
                             break;
                         }
@@ -1776,6 +1937,7 @@ mod tests {
             AssetKind::Material,
             AssetKind::Animation,
             AssetKind::Script,
+            AssetKind::Prefab,
             AssetKind::Other,
         ];
 
@@ -1798,6 +1960,7 @@ mod tests {
             dependencies: vec!["dep1".to_string(), "dep2".to_string()],
             last_modified: 1234567890,
             size_bytes: 1024,
+            audio: None,
         };
 
         let json = serde_json::to_string(&meta).unwrap();
@@ -2064,6 +2227,25 @@ mod tests {
         assert!(!rx.has_changed().unwrap_or(true));
     }
 
+    #[test]
+    fn test_hot_reload_signal_drives_a_ref_counted_cache_swap() {
+        use asset_handle::{RefCountedAssetCache, UnloadPolicy};
+
+        let mut db = AssetDatabase::new();
+        let mut cache = RefCountedAssetCache::new(UnloadPolicy::default());
+        let handle = cache.insert("tex-guid", vec![1u8, 2, 3], 3);
+
+        let mut rx = db.hot_reload_rx.clone();
+        db.invalidate_asset("tex-guid").expect("invalidate failed");
+
+        // The watcher observed a hot-reload signal...
+        assert!(rx.has_changed().unwrap_or(false));
+        // ...and re-imports the changed source, then swaps the new bytes
+        // into every existing handle in place.
+        assert!(cache.hot_swap("tex-guid", vec![9u8, 9, 9], 3));
+        assert_eq!(handle.with(|v| v.clone()), vec![9, 9, 9]);
+    }
+
     #[test]
     fn test_asset_database_invalidate_empty() {
         let mut db = AssetDatabase::new();
@@ -2085,6 +2267,7 @@ mod tests {
             AssetKind::Material,
             AssetKind::Animation,
             AssetKind::Script,
+            AssetKind::Prefab,
             AssetKind::Other,
         ];
 
@@ -2097,6 +2280,7 @@ mod tests {
                 dependencies: vec![],
                 last_modified: 0,
                 size_bytes: 0,
+                audio: None,
             };
 
             // Verify round-trip through JSON
@@ -2116,6 +2300,7 @@ mod tests {
             dependencies: vec!["dep1".to_string(), "dep2".to_string(), "dep3".to_string()],
             last_modified: 1234567890,
             size_bytes: 2048,
+            audio: None,
         };
 
         assert_eq!(meta.dependencies.len(), 3);
@@ -2316,6 +2501,8 @@ mod tests {
                     interpolation: Interpolation::Linear,
                 }],
                 duration: 1.0,
+                root_motion: None,
+                events: Vec::new(),
             };
 
             assert_eq!(clip.name, "walk");
@@ -2323,6 +2510,154 @@ mod tests {
             assert_eq!(clip.duration, 1.0);
         }
 
+        #[test]
+        fn test_extract_root_motion_translation_and_rotation() {
+            use gltf_loader::{extract_root_motion, AnimationChannel, ChannelData, Interpolation};
+
+            let channels = vec![
+                AnimationChannel {
+                    target_joint_index: 0,
+                    times: vec![0.0, 1.0],
+                    data: ChannelData::Translation(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]),
+                    interpolation: Interpolation::Linear,
+                },
+                AnimationChannel {
+                    target_joint_index: 0,
+                    times: vec![0.0, 1.0],
+                    data: ChannelData::Rotation(vec![
+                        [0.0, 0.0, 0.0, 1.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                    ]),
+                    interpolation: Interpolation::Linear,
+                },
+                // Non-root joint channel; must be ignored.
+                AnimationChannel {
+                    target_joint_index: 1,
+                    times: vec![0.0, 1.0],
+                    data: ChannelData::Translation(vec![[9.0, 9.0, 9.0], [9.0, 9.0, 9.0]]),
+                    interpolation: Interpolation::Linear,
+                },
+            ];
+
+            let track = extract_root_motion(&channels, 0).expect("root motion present");
+            assert_eq!(track.translations, vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+            assert_eq!(track.rotations, vec![[0.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 0.0]]);
+        }
+
+        #[test]
+        fn test_extract_root_motion_missing_translation_returns_none() {
+            use gltf_loader::{extract_root_motion, AnimationChannel, ChannelData, Interpolation};
+
+            let channels = vec![AnimationChannel {
+                target_joint_index: 0,
+                times: vec![0.0, 1.0],
+                data: ChannelData::Rotation(vec![[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, 1.0]]),
+                interpolation: Interpolation::Linear,
+            }];
+
+            assert!(extract_root_motion(&channels, 0).is_none());
+        }
+
+        #[test]
+        fn test_extract_root_motion_fills_identity_rotation_when_absent() {
+            use gltf_loader::{extract_root_motion, AnimationChannel, ChannelData, Interpolation};
+
+            let channels = vec![AnimationChannel {
+                target_joint_index: 0,
+                times: vec![0.0, 1.0],
+                data: ChannelData::Translation(vec![[0.0, 0.0, 0.0], [2.0, 0.0, 0.0]]),
+                interpolation: Interpolation::Linear,
+            }];
+
+            let track = extract_root_motion(&channels, 0).expect("root motion present");
+            assert_eq!(track.rotations, vec![[0.0, 0.0, 0.0, 1.0]; 2]);
+        }
+
+        #[test]
+        fn test_parse_event_sidecar() {
+            use gltf_loader::parse_event_sidecar;
+
+            let json = r#"{
+                "Walk": [
+                    {"name": "footstep_l", "time": 0.1},
+                    {"name": "footstep_r", "time": 0.6}
+                ]
+            }"#;
+
+            let sidecar = parse_event_sidecar(json).expect("valid sidecar JSON");
+            let walk = sidecar.get("Walk").expect("Walk entry present");
+            assert_eq!(walk.len(), 2);
+            assert_eq!(walk[0].name, "footstep_l");
+            assert_eq!(walk[0].time, 0.1);
+            assert_eq!(walk[1].name, "footstep_r");
+        }
+
+        #[test]
+        fn test_parse_event_sidecar_rejects_malformed_json() {
+            use gltf_loader::parse_event_sidecar;
+
+            assert!(parse_event_sidecar("not json").is_err());
+        }
+
+        #[test]
+        fn test_apply_event_sidecar_matches_by_clip_name() {
+            use gltf_loader::{
+                apply_event_sidecar, AnimationClip, AnimationEvent, ChannelData, Interpolation,
+            };
+            use std::collections::HashMap;
+
+            let mut clips = vec![AnimationClip {
+                name: "Walk".to_string(),
+                duration: 1.0,
+                channels: vec![gltf_loader::AnimationChannel {
+                    target_joint_index: 0,
+                    times: vec![0.0, 1.0],
+                    data: ChannelData::Translation(vec![[0.0; 3], [1.0, 0.0, 0.0]]),
+                    interpolation: Interpolation::Linear,
+                }],
+                root_motion: None,
+                events: Vec::new(),
+            }];
+
+            let mut sidecar = HashMap::new();
+            sidecar.insert(
+                "Walk".to_string(),
+                vec![AnimationEvent {
+                    name: "footstep_l".to_string(),
+                    time: 0.1,
+                }],
+            );
+
+            apply_event_sidecar(&mut clips, &sidecar);
+
+            assert_eq!(clips[0].events.len(), 1);
+            assert_eq!(clips[0].events[0].name, "footstep_l");
+        }
+
+        #[test]
+        fn test_apply_event_sidecar_leaves_unmatched_clips_untouched() {
+            use gltf_loader::{apply_event_sidecar, AnimationClip, ChannelData, Interpolation};
+            use std::collections::HashMap;
+
+            let mut clips = vec![AnimationClip {
+                name: "Run".to_string(),
+                duration: 1.0,
+                channels: vec![gltf_loader::AnimationChannel {
+                    target_joint_index: 0,
+                    times: vec![0.0, 1.0],
+                    data: ChannelData::Translation(vec![[0.0; 3], [1.0, 0.0, 0.0]]),
+                    interpolation: Interpolation::Linear,
+                }],
+                root_motion: None,
+                events: Vec::new(),
+            }];
+
+            let sidecar = HashMap::new();
+            apply_event_sidecar(&mut clips, &sidecar);
+
+            assert!(clips[0].events.is_empty());
+        }
+
         #[test]
         fn test_skinned_vertex_lite() {
             let vertex = gltf_loader::SkinnedVertexLite {
@@ -2413,14 +2748,52 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[test]
+        fn test_import_texture_cached_skips_reimport_on_a_hit() {
+            let temp_dir = TempDir::new().expect("Failed to create temp dir");
+            let source = temp_dir.path().join("source.png");
+            let output = temp_dir.path().join("output.png");
+            let output2 = temp_dir.path().join("output2.png");
+
+            let img = image::RgbaImage::from_pixel(1, 1, image::Rgba([0, 255, 0, 255]));
+            img.save(&source).expect("Failed to create test image");
+
+            let mut cache = crate::derived_cache::DerivedAssetCache::new();
+            let ran_first =
+                import_pipelines::import_texture_cached(&mut cache, &source, &output).unwrap();
+            assert!(ran_first);
+            assert!(output.exists());
+
+            // A second import of the same unchanged source to a different
+            // output path is a cache hit: the prior output is copied over
+            // instead of decoding and re-encoding the texture again.
+            let ran_second =
+                import_pipelines::import_texture_cached(&mut cache, &source, &output2).unwrap();
+            assert!(!ran_second);
+            assert_eq!(
+                std::fs::read(&output).unwrap(),
+                std::fs::read(&output2).unwrap()
+            );
+        }
+
         #[test]
         fn test_import_audio_copy() {
             let temp_dir = TempDir::new().expect("Failed to create temp dir");
             let source = temp_dir.path().join("audio.wav");
             let output = temp_dir.path().join("copied.wav");
 
-            // Create a dummy audio file (just bytes)
-            std::fs::write(&source, b"RIFF....WAVEfmt ").expect("Failed to write");
+            // A real (if tiny) WAV file now that import_audio actually decodes it.
+            let spec = hound::WavSpec {
+                channels: 1,
+                sample_rate: 44_100,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let mut writer = hound::WavWriter::create(&source, spec).expect("write source wav");
+            for i in 0..100i16 {
+                writer.write_sample(i * 10).expect("write sample");
+            }
+            writer.finalize().expect("finalize source wav");
 
             let result = import_pipelines::import_audio(&source, &output);
             assert!(result.is_ok());
@@ -2777,6 +3150,47 @@ color = [1.0, 0.0, 0.0, 1.0]
             assert_ne!(meta.hash, ""); // Hash should exist
         }
 
+        #[test]
+        fn test_asset_database_rebuild_dirty_only_skips_unchanged_assets() {
+            let temp_dir = TempDir::new().expect("Failed to create temp dir");
+            let unchanged_path = temp_dir.path().join("unchanged.png");
+            let changed_path = temp_dir.path().join("changed.png");
+            std::fs::write(&unchanged_path, b"same forever").expect("Failed");
+            std::fs::write(&changed_path, b"before").expect("Failed");
+
+            let mut db = AssetDatabase::new();
+            db.register_asset(&unchanged_path, AssetKind::Texture, vec![])
+                .expect("Failed");
+            let changed_guid = db
+                .register_asset(&changed_path, AssetKind::Texture, vec![])
+                .expect("Failed");
+
+            std::fs::write(&changed_path, b"after").expect("Failed");
+            let rebuilt = db.rebuild(true).expect("rebuild failed");
+
+            assert_eq!(rebuilt, vec![changed_guid.clone()]);
+            let meta = db.get_asset(&changed_guid).unwrap();
+            assert_eq!(meta.hash, compute_file_hash(&changed_path).unwrap());
+        }
+
+        #[test]
+        fn test_asset_database_rebuild_all_touches_every_asset() {
+            let temp_dir = TempDir::new().expect("Failed to create temp dir");
+            let path_a = temp_dir.path().join("a.png");
+            let path_b = temp_dir.path().join("b.png");
+            std::fs::write(&path_a, b"a").expect("Failed");
+            std::fs::write(&path_b, b"b").expect("Failed");
+
+            let mut db = AssetDatabase::new();
+            db.register_asset(&path_a, AssetKind::Texture, vec![])
+                .expect("Failed");
+            db.register_asset(&path_b, AssetKind::Texture, vec![])
+                .expect("Failed");
+
+            let rebuilt = db.rebuild(false).expect("rebuild failed");
+            assert_eq!(rebuilt.len(), 2);
+        }
+
         #[test]
         fn test_asset_database_get_dependents_none() {
             let db = AssetDatabase::new();
@@ -2857,6 +3271,7 @@ color = [1.0, 0.0, 0.0, 1.0]
                 AssetKind::Material,
                 AssetKind::Animation,
                 AssetKind::Script,
+                AssetKind::Prefab,
                 AssetKind::Other,
             ];
 
@@ -2897,6 +3312,7 @@ color = [1.0, 0.0, 0.0, 1.0]
                 dependencies: vec!["dep1".to_string(), "dep2".to_string()],
                 last_modified: 1702569600,
                 size_bytes: 1024000,
+                audio: None,
             };
 
             let json = serde_json::to_string(&meta).unwrap();
@@ -2921,6 +3337,7 @@ color = [1.0, 0.0, 0.0, 1.0]
                 dependencies: vec![],
                 last_modified: 0,
                 size_bytes: 0,
+                audio: None,
             };
 
             let json = serde_json::to_string(&meta).unwrap();
@@ -2938,6 +3355,7 @@ color = [1.0, 0.0, 0.0, 1.0]
                 dependencies: vec![],
                 last_modified: u64::MAX,
                 size_bytes: u64::MAX,
+                audio: None,
             };
 
             assert_eq!(meta.size_bytes, u64::MAX);
@@ -3051,6 +3469,11 @@ pub struct AssetMetadata {
     pub dependencies: Vec<String>, // GUIDs of dependencies
     pub last_modified: u64,
     pub size_bytes: u64,
+    /// Decode/resample/loudness facts recorded for `AssetKind::Audio` assets
+    /// imported via `import_pipelines::import_audio_and_register`; `None`
+    /// for every other kind and for audio registered without that pipeline.
+    #[serde(default)]
+    pub audio: Option<audio_import::AudioImportMetadata>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -3065,6 +3488,9 @@ pub enum AssetKind {
     Script,
     /// Blender source file - requires conversion to Mesh via astraweave-blend
     BlenderSource,
+    /// Data-driven entity bundle (transform + component data, possibly
+    /// nesting other prefabs) instantiated via a `PrefabRegistry`.
+    Prefab,
     Other,
 }
 
@@ -3124,6 +3550,7 @@ impl AssetDatabase {
                 .duration_since(std::time::UNIX_EPOCH)?
                 .as_secs(),
             size_bytes: size,
+            audio: None,
         };
 
         self.assets.insert(guid.clone(), meta);
@@ -3144,6 +3571,36 @@ impl AssetDatabase {
         Ok(guid)
     }
 
+    /// Re-registers every already-known asset, refreshing its hash and
+    /// metadata from disk. With `dirty_only`, an asset is skipped unless
+    /// its source file's current content hash no longer matches the one
+    /// recorded at its last registration (or it's gone missing, which is
+    /// left for the caller to handle separately). Returns the GUIDs that
+    /// were rebuilt.
+    pub fn rebuild(&mut self, dirty_only: bool) -> Result<Vec<String>> {
+        let mut rebuilt = Vec::new();
+        for guid in self.assets.keys().cloned().collect::<Vec<_>>() {
+            let (path, kind, dependencies, previous_hash) = {
+                let meta = &self.assets[&guid];
+                (
+                    PathBuf::from(&meta.path),
+                    meta.kind.clone(),
+                    meta.dependencies.clone(),
+                    meta.hash.clone(),
+                )
+            };
+            if !path.exists() {
+                continue;
+            }
+            if dirty_only && compute_file_hash(&path)? == previous_hash {
+                continue;
+            }
+            self.register_asset(&path, kind, dependencies)?;
+            rebuilt.push(guid);
+        }
+        Ok(rebuilt)
+    }
+
     pub fn get_asset(&self, guid: &str) -> Option<&AssetMetadata> {
         self.assets.get(guid)
     }
@@ -3234,6 +3691,7 @@ fn infer_asset_kind(path: &Path) -> AssetKind {
         Some("material") | Some("material.toml") => AssetKind::Material,
         Some("anim") | Some("animation") => AssetKind::Animation,
         Some("rhai") => AssetKind::Script,
+        Some("prefab") => AssetKind::Prefab,
         _ => AssetKind::Other,
     }
 }
@@ -3445,12 +3903,62 @@ pub mod import_pipelines {
         Ok(())
     }
 
+    /// The current behavior of [`import_texture`], versioned for
+    /// [`crate::derived_cache::CacheKey`] — bump this whenever a change to
+    /// `import_texture` would produce different output bytes for the same
+    /// input, so stale cache entries get invalidated.
+    pub const IMPORT_TEXTURE_VERSION: u32 = 1;
+
+    /// Runs [`import_texture`] through `cache`, skipping the actual decode
+    /// and re-encode when `source`'s content hasn't changed since the last
+    /// time it was imported at this importer version. Returns whether the
+    /// import actually ran.
+    pub fn import_texture_cached(
+        cache: &mut crate::derived_cache::DerivedAssetCache,
+        source: &Path,
+        output: &Path,
+    ) -> Result<bool> {
+        crate::derived_cache::import_with_cache(
+            cache,
+            source,
+            output,
+            IMPORT_TEXTURE_VERSION,
+            "default",
+            import_texture,
+        )
+    }
+
+    /// Decodes, resamples, and loudness-normalizes `source` into `output`
+    /// using [`crate::audio_import`]'s default settings. Use
+    /// [`import_audio_and_register`] to also record the resulting
+    /// [`crate::audio_import::AudioImportMetadata`] on an [`AssetDatabase`].
     pub fn import_audio(source: &Path, output: &Path) -> Result<()> {
-        // For now, just copy; in full impl, use audio processing
-        fs::copy(source, output)?;
+        crate::audio_import::import_audio(
+            source,
+            output,
+            &crate::audio_import::AudioImportSettings::default(),
+        )?;
         Ok(())
     }
 
+    /// Imports `source` like [`import_audio`], registers `output` in `db`,
+    /// and records the decode/resample/loudness facts on the registered
+    /// asset's [`AssetMetadata::audio`].
+    pub fn import_audio_and_register(
+        db: &mut AssetDatabase,
+        source: &Path,
+        output: &Path,
+        dependencies: Vec<String>,
+        settings: &crate::audio_import::AudioImportSettings,
+    ) -> Result<String> {
+        let audio_meta = crate::audio_import::import_audio(source, output, settings)?;
+        let guid = db.register_asset(output, AssetKind::Audio, dependencies)?;
+        if let Some(meta) = db.assets.get_mut(&guid) {
+            meta.audio = Some(audio_meta);
+        }
+        Ok(guid)
+    }
+
     pub fn import_dialogue(source: &Path, output: &Path) -> Result<()> {
         // Validate TOML structure
         let content = fs::read_to_string(source)?;
@@ -3490,6 +3998,32 @@ pub mod import_pipelines {
             Ok(())
         })
     }
+
+    /// Versioned like [`import_texture`]'s `IMPORT_TEXTURE_VERSION`: bump
+    /// whenever a change to [`import_blend_sync`] (or the Blender version
+    /// it invokes) would change its output for the same input.
+    #[cfg(feature = "blend")]
+    pub const IMPORT_BLEND_VERSION: u32 = 1;
+
+    /// Runs [`import_blend_sync`] through `cache`, skipping the (slow,
+    /// subprocess-spawning) Blender conversion when `source` hasn't
+    /// changed since it was last converted at this importer version.
+    /// Returns whether the conversion actually ran.
+    #[cfg(feature = "blend")]
+    pub fn import_blend_cached(
+        cache: &mut crate::derived_cache::DerivedAssetCache,
+        source: &Path,
+        output: &Path,
+    ) -> Result<bool> {
+        crate::derived_cache::import_with_cache(
+            cache,
+            source,
+            output,
+            IMPORT_BLEND_VERSION,
+            "default",
+            import_blend_sync,
+        )
+    }
 }
 
 /// Integration helper for using blend import with AssetDatabase.
@@ -0,0 +1,376 @@
+//! Offline light baking for static geometry.
+//!
+//! Bakes analytic (non-ray-traced) point-light irradiance into a
+//! [`BakedLightmap`] per surface texel, and into a coarse [`IrradianceVolume`]
+//! of probes for dynamic objects to sample at runtime. There is no path
+//! tracer in this crate, so occlusion is not accounted for at bake time;
+//! shadowing is expected to layer on top via the renderer's existing shadow
+//! map passes. Baked results are stored as plain, serializable data so they
+//! can be written out as dependent assets alongside a cell or scene, the
+//! same way [`crate::cell_loader`] persists world partition cell data.
+
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+/// A single analytic point light used only for baking, independent of any
+/// runtime light representation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BakeLight {
+    pub position: Vec3,
+    pub color: Vec3,
+    pub intensity: f32,
+    /// Distance beyond which the light contributes nothing.
+    pub range: f32,
+}
+
+impl BakeLight {
+    /// Irradiance contribution at `point` with surface normal `normal`,
+    /// using inverse-square attenuation, a Lambertian N·L term, and a hard
+    /// cutoff at [`Self::range`].
+    pub fn irradiance_at(&self, point: Vec3, normal: Vec3) -> Vec3 {
+        let to_light = self.position - point;
+        let distance = to_light.length();
+        if distance >= self.range || distance <= f32::EPSILON {
+            return Vec3::ZERO;
+        }
+        let n_dot_l = normal.normalize_or_zero().dot(to_light / distance).max(0.0);
+        if n_dot_l <= 0.0 {
+            return Vec3::ZERO;
+        }
+        let attenuation = 1.0 / (distance * distance);
+        self.color * (self.intensity * attenuation * n_dot_l)
+    }
+}
+
+/// Sum of every light's contribution at `point`/`normal`, plus a flat
+/// ambient term.
+fn accumulate(lights: &[BakeLight], point: Vec3, normal: Vec3, ambient: Vec3) -> Vec3 {
+    lights
+        .iter()
+        .fold(ambient, |acc, light| acc + light.irradiance_at(point, normal))
+}
+
+/// World-space position and normal of one lightmap texel, in row-major
+/// order matching [`BakedLightmap::width`] x [`BakedLightmap::height`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LightmapTexel {
+    pub position: Vec3,
+    pub normal: Vec3,
+}
+
+/// A baked per-texel irradiance lightmap for one piece of static geometry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BakedLightmap {
+    pub width: u32,
+    pub height: u32,
+    pub texels: Vec<Vec3>,
+}
+
+impl BakedLightmap {
+    /// Bilinearly sampled irradiance at normalized UV coordinates, clamped
+    /// to the lightmap edges.
+    pub fn sample_uv(&self, u: f32, v: f32) -> Vec3 {
+        let fx = (u.clamp(0.0, 1.0) * (self.width.max(1) - 1) as f32).max(0.0);
+        let fy = (v.clamp(0.0, 1.0) * (self.height.max(1) - 1) as f32).max(0.0);
+        let x0 = fx.floor() as u32;
+        let y0 = fy.floor() as u32;
+        let x1 = (x0 + 1).min(self.width - 1);
+        let y1 = (y0 + 1).min(self.height - 1);
+        let tx = fx - x0 as f32;
+        let ty = fy - y0 as f32;
+
+        let texel = |x: u32, y: u32| self.texels[(y * self.width + x) as usize];
+        let top = texel(x0, y0).lerp(texel(x1, y0), tx);
+        let bottom = texel(x0, y1).lerp(texel(x1, y1), tx);
+        top.lerp(bottom, ty)
+    }
+}
+
+/// Bake `texels` (row-major, `width * height` entries) against `lights` and
+/// a flat `ambient` term.
+pub fn bake_lightmap(
+    texels: &[LightmapTexel],
+    width: u32,
+    height: u32,
+    lights: &[BakeLight],
+    ambient: Vec3,
+) -> anyhow::Result<BakedLightmap> {
+    anyhow::ensure!(
+        texels.len() == (width * height) as usize,
+        "lightmap texel count {} does not match {}x{}",
+        texels.len(),
+        width,
+        height
+    );
+
+    let baked = texels
+        .iter()
+        .map(|texel| accumulate(lights, texel.position, texel.normal, ambient))
+        .collect();
+
+    Ok(BakedLightmap {
+        width,
+        height,
+        texels: baked,
+    })
+}
+
+/// A single ambient irradiance sample point, storing the average
+/// irradiance arriving from the six cardinal axes rather than full
+/// spherical harmonics, as a cheap L0 proxy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct IrradianceProbe {
+    pub position: Vec3,
+    pub irradiance: Vec3,
+}
+
+/// Six cardinal axes probes are averaged over to approximate ambient
+/// irradiance without directional detail.
+const PROBE_AXES: [Vec3; 6] = [
+    Vec3::X,
+    Vec3::NEG_X,
+    Vec3::Y,
+    Vec3::NEG_Y,
+    Vec3::Z,
+    Vec3::NEG_Z,
+];
+
+/// A regular grid of [`IrradianceProbe`]s covering `min..=max`, sampled by
+/// dynamic objects at runtime in place of a full lightmap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IrradianceVolume {
+    pub min: Vec3,
+    pub max: Vec3,
+    pub dims: (u32, u32, u32),
+    pub probes: Vec<IrradianceProbe>,
+}
+
+impl IrradianceVolume {
+    fn probe_index(&self, x: u32, y: u32, z: u32) -> usize {
+        let (dx, dy, _dz) = self.dims;
+        (z * dy * dx + y * dx + x) as usize
+    }
+
+    /// Trilinearly sampled irradiance at a world-space `position`, clamped
+    /// to the volume bounds.
+    pub fn sample(&self, position: Vec3) -> Vec3 {
+        let (dx, dy, dz) = self.dims;
+        if dx == 0 || dy == 0 || dz == 0 || self.probes.is_empty() {
+            return Vec3::ZERO;
+        }
+
+        let extent = (self.max - self.min).max(Vec3::splat(f32::EPSILON));
+        let normalized = ((position - self.min) / extent).clamp(Vec3::ZERO, Vec3::ONE);
+
+        let fx = normalized.x * (dx.max(1) - 1).max(1) as f32;
+        let fy = normalized.y * (dy.max(1) - 1).max(1) as f32;
+        let fz = normalized.z * (dz.max(1) - 1).max(1) as f32;
+
+        let x0 = (fx.floor() as u32).min(dx - 1);
+        let y0 = (fy.floor() as u32).min(dy - 1);
+        let z0 = (fz.floor() as u32).min(dz - 1);
+        let x1 = (x0 + 1).min(dx - 1);
+        let y1 = (y0 + 1).min(dy - 1);
+        let z1 = (z0 + 1).min(dz - 1);
+
+        let tx = fx - x0 as f32;
+        let ty = fy - y0 as f32;
+        let tz = fz - z0 as f32;
+
+        let at = |x: u32, y: u32, z: u32| self.probes[self.probe_index(x, y, z)].irradiance;
+
+        let x00 = at(x0, y0, z0).lerp(at(x1, y0, z0), tx);
+        let x10 = at(x0, y1, z0).lerp(at(x1, y1, z0), tx);
+        let x01 = at(x0, y0, z1).lerp(at(x1, y0, z1), tx);
+        let x11 = at(x0, y1, z1).lerp(at(x1, y1, z1), tx);
+        let y0z = x00.lerp(x10, ty);
+        let y1z = x01.lerp(x11, ty);
+        y0z.lerp(y1z, tz)
+    }
+}
+
+/// Bake a regular `dims.0 x dims.1 x dims.2` grid of probes covering
+/// `min..=max` against `lights` and a flat `ambient` term.
+pub fn bake_irradiance_volume(
+    min: Vec3,
+    max: Vec3,
+    dims: (u32, u32, u32),
+    lights: &[BakeLight],
+    ambient: Vec3,
+) -> IrradianceVolume {
+    let (dx, dy, dz) = dims;
+    let mut probes = Vec::with_capacity((dx * dy * dz) as usize);
+
+    for z in 0..dz {
+        for y in 0..dy {
+            for x in 0..dx {
+                let t = Vec3::new(
+                    axis_fraction(x, dx),
+                    axis_fraction(y, dy),
+                    axis_fraction(z, dz),
+                );
+                let position = min + (max - min) * t;
+                let irradiance = PROBE_AXES
+                    .iter()
+                    .map(|&axis| accumulate(lights, position, axis, ambient))
+                    .sum::<Vec3>()
+                    / PROBE_AXES.len() as f32;
+                probes.push(IrradianceProbe {
+                    position,
+                    irradiance,
+                });
+            }
+        }
+    }
+
+    IrradianceVolume {
+        min,
+        max,
+        dims,
+        probes,
+    }
+}
+
+fn axis_fraction(index: u32, count: u32) -> f32 {
+    if count <= 1 {
+        0.0
+    } else {
+        index as f32 / (count - 1) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn light(position: Vec3) -> BakeLight {
+        BakeLight {
+            position,
+            color: Vec3::ONE,
+            intensity: 10.0,
+            range: 100.0,
+        }
+    }
+
+    #[test]
+    fn irradiance_falls_off_with_distance() {
+        let l = light(Vec3::new(0.0, 1.0, 0.0));
+        let near = l.irradiance_at(Vec3::new(0.0, 0.0, 0.0), Vec3::Y);
+        let far = l.irradiance_at(Vec3::new(0.0, 0.0, 10.0), Vec3::Y);
+        assert!(near.x > far.x);
+    }
+
+    #[test]
+    fn irradiance_is_zero_beyond_range() {
+        let l = BakeLight {
+            position: Vec3::new(0.0, 1.0, 0.0),
+            color: Vec3::ONE,
+            intensity: 10.0,
+            range: 0.5,
+        };
+        let contribution = l.irradiance_at(Vec3::ZERO, Vec3::Y);
+        assert_eq!(contribution, Vec3::ZERO);
+    }
+
+    #[test]
+    fn irradiance_is_zero_facing_away() {
+        let l = light(Vec3::new(0.0, 1.0, 0.0));
+        let contribution = l.irradiance_at(Vec3::ZERO, Vec3::NEG_Y);
+        assert_eq!(contribution, Vec3::ZERO);
+    }
+
+    #[test]
+    fn bake_lightmap_rejects_mismatched_texel_count() {
+        let texels = vec![LightmapTexel {
+            position: Vec3::ZERO,
+            normal: Vec3::Y,
+        }];
+        let result = bake_lightmap(&texels, 2, 2, &[], Vec3::ZERO);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bake_lightmap_applies_ambient_with_no_lights() {
+        let texels = vec![
+            LightmapTexel {
+                position: Vec3::ZERO,
+                normal: Vec3::Y
+            };
+            4
+        ];
+        let ambient = Vec3::splat(0.1);
+        let baked = bake_lightmap(&texels, 2, 2, &[], ambient).unwrap();
+        assert!(baked.texels.iter().all(|&t| t == ambient));
+    }
+
+    #[test]
+    fn lightmap_sample_uv_matches_texel_at_corner() {
+        let texels = vec![
+            LightmapTexel {
+                position: Vec3::new(0.0, 0.0, 0.0),
+                normal: Vec3::Y,
+            },
+            LightmapTexel {
+                position: Vec3::new(1.0, 0.0, 0.0),
+                normal: Vec3::Y,
+            },
+        ];
+        let baked = bake_lightmap(&texels, 2, 1, &[light(Vec3::new(0.0, 1.0, 0.0))], Vec3::ZERO)
+            .unwrap();
+        let corner = baked.sample_uv(0.0, 0.0);
+        assert_eq!(corner, baked.texels[0]);
+    }
+
+    #[test]
+    fn irradiance_volume_has_expected_probe_count() {
+        let volume = bake_irradiance_volume(
+            Vec3::ZERO,
+            Vec3::splat(10.0),
+            (2, 2, 2),
+            &[light(Vec3::splat(5.0))],
+            Vec3::ZERO,
+        );
+        assert_eq!(volume.probes.len(), 8);
+    }
+
+    #[test]
+    fn irradiance_volume_sample_matches_probe_at_corner() {
+        let volume = bake_irradiance_volume(
+            Vec3::ZERO,
+            Vec3::splat(10.0),
+            (2, 2, 2),
+            &[light(Vec3::splat(5.0))],
+            Vec3::ZERO,
+        );
+        let sampled = volume.sample(Vec3::ZERO);
+        assert_eq!(sampled, volume.probes[0].irradiance);
+    }
+
+    #[test]
+    fn irradiance_volume_sample_is_brighter_near_light() {
+        let volume = bake_irradiance_volume(
+            Vec3::ZERO,
+            Vec3::splat(10.0),
+            (3, 3, 3),
+            &[light(Vec3::splat(9.0))],
+            Vec3::ZERO,
+        );
+        let near = volume.sample(Vec3::splat(9.0)).length();
+        let far = volume.sample(Vec3::ZERO).length();
+        assert!(near > far);
+    }
+
+    #[test]
+    fn irradiance_volume_sample_clamps_outside_bounds() {
+        let volume = bake_irradiance_volume(
+            Vec3::ZERO,
+            Vec3::splat(10.0),
+            (2, 2, 2),
+            &[],
+            Vec3::splat(0.2),
+        );
+        let outside = volume.sample(Vec3::splat(1000.0));
+        let corner = volume.sample(Vec3::splat(10.0));
+        assert_eq!(outside, corner);
+    }
+}
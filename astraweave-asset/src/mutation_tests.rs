@@ -512,6 +512,7 @@ mod asset_metadata_tests {
             dependencies: vec![],
             last_modified: 1234567890,
             size_bytes: 1024,
+            audio: None,
         };
 
         assert_eq!(meta.guid, "test-guid-123");
@@ -533,6 +534,7 @@ mod asset_metadata_tests {
             dependencies: vec!["parent-1".to_string(), "parent-2".to_string()],
             last_modified: 0,
             size_bytes: 0,
+            audio: None,
         };
 
         assert_eq!(meta.dependencies.len(), 2);
@@ -550,6 +552,7 @@ mod asset_metadata_tests {
             dependencies: vec!["dep".to_string()],
             last_modified: 999,
             size_bytes: 512,
+            audio: None,
         };
 
         let cloned = original.clone();
@@ -571,6 +574,7 @@ mod asset_metadata_tests {
             dependencies: vec![],
             last_modified: 0,
             size_bytes: 0,
+            audio: None,
         };
         assert_eq!(meta_zero.size_bytes, 0);
 
@@ -583,6 +587,7 @@ mod asset_metadata_tests {
             dependencies: vec![],
             last_modified: 0,
             size_bytes: u64::MAX,
+            audio: None,
         };
         assert_eq!(meta_large.size_bytes, u64::MAX);
     }
@@ -765,6 +765,41 @@ pub fn load_meshlet_hierarchy(path: &std::path::Path) -> Result<MeshletHierarchy
     Ok(hierarchy)
 }
 
+/// Versioned like `import_pipelines::IMPORT_TEXTURE_VERSION`: bump whenever
+/// a change to [`generate_lod_hierarchy`] would change its output for the
+/// same input buffers.
+pub const GENERATE_LOD_HIERARCHY_VERSION: u32 = 1;
+
+/// Runs [`generate_lod_hierarchy`] through `cache`, keyed by the input
+/// buffers and `lod_count`, persisting/reloading the result via
+/// [`save_meshlet_hierarchy`]/[`load_meshlet_hierarchy`] so repeated runs
+/// over an unchanged mesh skip meshlet generation and simplification
+/// entirely.
+pub fn generate_lod_hierarchy_cached(
+    cache: &mut crate::derived_cache::DerivedAssetCache,
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tangents: &[[f32; 4]],
+    uvs: &[[f32; 2]],
+    indices: &[u32],
+    lod_count: u32,
+    output_path: &std::path::Path,
+) -> Result<MeshletHierarchy> {
+    let source_bytes = serde_json::to_vec(&(positions, normals, tangents, uvs, indices, lod_count))
+        .context("Failed to serialize mesh buffers for cache key")?;
+
+    crate::derived_cache::compute_with_cache(
+        cache,
+        &source_bytes,
+        GENERATE_LOD_HIERARCHY_VERSION,
+        "default",
+        output_path,
+        || generate_lod_hierarchy(positions, normals, tangents, uvs, indices, lod_count),
+        |hierarchy, path| save_meshlet_hierarchy(hierarchy, path),
+        load_meshlet_hierarchy,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -936,6 +971,37 @@ mod tests {
         assert!(!hierarchy.lod_ranges[0].is_empty());
     }
 
+    #[test]
+    fn test_generate_lod_hierarchy_cached_skips_regeneration_on_a_hit() {
+        let positions = vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ];
+        let normals = vec![[0.0, 0.0, 1.0]; 4];
+        let tangents = vec![[1.0, 0.0, 0.0, 1.0]; 4];
+        let uvs = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let indices = vec![0, 1, 2, 0, 2, 3];
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let output = dir.path().join("hierarchy.ron");
+        let mut cache = crate::derived_cache::DerivedAssetCache::new();
+
+        let first = generate_lod_hierarchy_cached(
+            &mut cache, &positions, &normals, &tangents, &uvs, &indices, 2, &output,
+        )
+        .unwrap();
+        assert!(!first.meshlets.is_empty());
+        assert!(output.exists());
+
+        let second = generate_lod_hierarchy_cached(
+            &mut cache, &positions, &normals, &tangents, &uvs, &indices, 2, &output,
+        )
+        .unwrap();
+        assert_eq!(second.meshlets.len(), first.meshlets.len());
+    }
+
     #[test]
     fn test_quadric_error() {
         let q1 = QuadricError::from_plane(1.0, 0.0, 0.0, 0.0);
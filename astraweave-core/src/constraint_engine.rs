@@ -0,0 +1,186 @@
+//! Shared cooldown/stamina cost model for [`ActionStep`]s.
+//!
+//! [`Constraints`] has always declared `enforce_cooldowns`/`enforce_stamina`
+//! flags, but nothing consulted per-agent cooldown or stamina state before
+//! this: `astraweave_llm::sanitize_plan` only checked tool names and
+//! coordinate bounds. [`action_cost`] is the one authoritative table of
+//! which actions cost a cooldown/stamina and how much; [`check_action_cost`]
+//! consults it against caller-supplied state, so `sanitize_plan`
+//! (pre-execution, working from a [`crate::WorldSnapshot`]'s
+//! `me.cooldowns`) and `astraweave_ai::execution_bridge` (runtime, working
+//! from ECS [`crate::CCooldowns`]/[`crate::CStamina`] components) enforce
+//! identical limits instead of drifting apart.
+
+use crate::{ActionStep, Constraints, EngineError};
+use std::collections::BTreeMap;
+
+/// The cooldown/stamina cost of one [`ActionStep`], if any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActionCost {
+    /// Cooldown bucket name (matches [`crate::cooldowns::CooldownKey`]'s
+    /// `Display` form / [`crate::CompanionState::cooldowns`] keys), or
+    /// `None` if this action has no cooldown.
+    pub cooldown_key: Option<&'static str>,
+    pub cooldown_seconds: f32,
+    pub stamina_cost: f32,
+}
+
+impl ActionCost {
+    pub const NONE: ActionCost = ActionCost {
+        cooldown_key: None,
+        cooldown_seconds: 0.0,
+        stamina_cost: 0.0,
+    };
+}
+
+/// Looks up the cost of `step` in the shared cost table. Actions not listed
+/// here (movement, scanning, most utility actions) are free.
+pub fn action_cost(step: &ActionStep) -> ActionCost {
+    match step {
+        ActionStep::ThrowSmoke { .. } => ActionCost {
+            cooldown_key: Some("throw:smoke"),
+            cooldown_seconds: 8.0,
+            stamina_cost: 5.0,
+        },
+        ActionStep::ThrowExplosive { .. } => ActionCost {
+            cooldown_key: Some("throw:explosive"),
+            cooldown_seconds: 12.0,
+            stamina_cost: 10.0,
+        },
+        ActionStep::Charge { .. } => ActionCost {
+            cooldown_key: Some("charge"),
+            cooldown_seconds: 6.0,
+            stamina_cost: 25.0,
+        },
+        ActionStep::HeavyAttack { .. } => ActionCost {
+            cooldown_key: Some("heavy_attack"),
+            cooldown_seconds: 2.0,
+            stamina_cost: 20.0,
+        },
+        ActionStep::AimedShot { .. } => ActionCost {
+            cooldown_key: None,
+            cooldown_seconds: 0.0,
+            stamina_cost: 8.0,
+        },
+        ActionStep::Dodge { .. } => ActionCost {
+            cooldown_key: Some("dodge"),
+            cooldown_seconds: 1.5,
+            stamina_cost: 15.0,
+        },
+        ActionStep::CoverFire { .. } => ActionCost {
+            cooldown_key: Some("cover_fire"),
+            cooldown_seconds: 4.0,
+            stamina_cost: 12.0,
+        },
+        ActionStep::UseAbility { .. } | ActionStep::UseDefensiveAbility { .. } => ActionCost {
+            cooldown_key: None,
+            cooldown_seconds: 0.0,
+            stamina_cost: 10.0,
+        },
+        _ => ActionCost::NONE,
+    }
+}
+
+/// Checks `step` against `cooldowns` (a per-agent cooldown-name → remaining
+/// seconds map, e.g. [`crate::CompanionState::cooldowns`] or
+/// [`crate::CCooldowns`]'s map converted to string keys) and an optional
+/// `stamina` value, honoring `constraints`'s `enforce_cooldowns`/
+/// `enforce_stamina` flags. `stamina: None` means the caller has no stamina
+/// tracking available (e.g. pre-execution snapshot validation) and skips
+/// that half of the check rather than treating it as zero.
+pub fn check_action_cost(
+    cooldowns: &BTreeMap<String, f32>,
+    stamina: Option<f32>,
+    step: &ActionStep,
+    constraints: &Constraints,
+) -> Result<(), EngineError> {
+    let cost = action_cost(step);
+
+    if constraints.enforce_cooldowns {
+        if let Some(key) = cost.cooldown_key {
+            if let Some(remaining) = cooldowns.get(key) {
+                if *remaining > 0.0 {
+                    return Err(EngineError::Cooldown(format!(
+                        "{key} on cooldown for {remaining:.1}s"
+                    )));
+                }
+            }
+        }
+    }
+
+    if constraints.enforce_stamina {
+        if let Some(stamina) = stamina {
+            if stamina < cost.stamina_cost {
+                return Err(EngineError::Resource(format!(
+                    "insufficient stamina: need {:.1}, have {:.1}",
+                    cost.stamina_cost, stamina
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraints() -> Constraints {
+        Constraints {
+            enforce_cooldowns: true,
+            enforce_los: false,
+            enforce_stamina: true,
+        }
+    }
+
+    #[test]
+    fn free_action_never_blocked() {
+        let cooldowns = BTreeMap::new();
+        let step = ActionStep::MoveTo { x: 0, y: 0, speed: None };
+        assert!(check_action_cost(&cooldowns, Some(0.0), &step, &constraints()).is_ok());
+    }
+
+    #[test]
+    fn blocks_action_still_on_cooldown() {
+        let mut cooldowns = BTreeMap::new();
+        cooldowns.insert("throw:smoke".to_string(), 3.0);
+        let step = ActionStep::ThrowSmoke { x: 0, y: 0 };
+        assert!(check_action_cost(&cooldowns, Some(100.0), &step, &constraints()).is_err());
+    }
+
+    #[test]
+    fn allows_action_once_cooldown_expires() {
+        let mut cooldowns = BTreeMap::new();
+        cooldowns.insert("throw:smoke".to_string(), 0.0);
+        let step = ActionStep::ThrowSmoke { x: 0, y: 0 };
+        assert!(check_action_cost(&cooldowns, Some(100.0), &step, &constraints()).is_ok());
+    }
+
+    #[test]
+    fn blocks_action_with_insufficient_stamina() {
+        let cooldowns = BTreeMap::new();
+        let step = ActionStep::Charge { target_id: 1 };
+        assert!(check_action_cost(&cooldowns, Some(5.0), &step, &constraints()).is_err());
+    }
+
+    #[test]
+    fn unknown_stamina_skips_stamina_check() {
+        let cooldowns = BTreeMap::new();
+        let step = ActionStep::Charge { target_id: 1 };
+        assert!(check_action_cost(&cooldowns, None, &step, &constraints()).is_ok());
+    }
+
+    #[test]
+    fn disabled_enforcement_flags_bypass_checks() {
+        let mut cooldowns = BTreeMap::new();
+        cooldowns.insert("charge".to_string(), 5.0);
+        let step = ActionStep::Charge { target_id: 1 };
+        let lenient = Constraints {
+            enforce_cooldowns: false,
+            enforce_los: false,
+            enforce_stamina: false,
+        };
+        assert!(check_action_cost(&cooldowns, Some(0.0), &step, &lenient).is_ok());
+    }
+}
@@ -3,7 +3,13 @@ use astraweave_ecs as ecs;
 
 use crate::ecs_bridge::EntityBridge;
 use crate::ecs_events::{Events, MovedEvent};
-use crate::{CAmmo, CCooldowns, CDesiredPos, CHealth, CPos, CTeam, IVec2, World};
+use crate::pathfinding::astar_grid;
+use crate::{CAmmo, CCooldowns, CDesiredPos, CHealth, CPath, CPos, CTeam, IVec2, World};
+
+/// Node budget for [`astar_grid`] searches run by [`sys_move`]. Generous
+/// enough for any world this crate's demos/tests build, while still
+/// bounding worst-case per-tick cost if a goal turns out to be unreachable.
+const MOVE_PATH_NODE_BUDGET: usize = 2048;
 
 #[derive(Clone, Copy)]
 struct Dt(pub f32);
@@ -26,12 +32,19 @@ fn sys_sim(world: &mut ecs::World) {
 }
 
 fn sys_move(world: &mut ecs::World) {
-    // Move entities one step toward desired pos (cardinal-only 4-neighborhood) per tick
-    // Deterministic order by BTreeMap underlying storage
-    // Note: no collision here—Phase 1 minimal behavior
-    // Read positions and desired goals, mutate positions
-    // We purposely run after sim (cooldowns)
-    use std::collections::BTreeMap;
+    // Move entities one step toward desired pos (cardinal-only 4-neighborhood) per tick.
+    // Deterministic order by BTreeMap underlying storage.
+    // We purposely run after sim (cooldowns).
+    //
+    // The direct cardinal step is tried first, unchanged from Phase 1: when
+    // it isn't blocked by a `World` obstacle and no path is already in
+    // progress, it's taken as-is. Only when that step would walk into an
+    // obstacle (or a `CPath` from a previous tick is still being followed)
+    // do we fall back to `pathfinding::astar_grid` and advance one waypoint
+    // at a time via `CPath`, so `MoveTo` steps actually detour instead of
+    // stalling against a blocked tile.
+    use std::collections::{BTreeMap, VecDeque};
+
     let goals: BTreeMap<ecs::Entity, CDesiredPos> = {
         let mut m = BTreeMap::new();
         let q = ecs::Query::<CDesiredPos>::new(&*world);
@@ -40,37 +53,104 @@ fn sys_move(world: &mut ecs::World) {
         }
         m
     };
+    if goals.is_empty() {
+        return;
+    }
+
+    let positions: BTreeMap<ecs::Entity, IVec2> = {
+        let mut m = BTreeMap::new();
+        let q = ecs::Query::<CPos>::new(&*world);
+        for (e, p) in q {
+            m.insert(e, p.pos);
+        }
+        m
+    };
+    let existing_paths: BTreeMap<ecs::Entity, VecDeque<IVec2>> = {
+        let mut m = BTreeMap::new();
+        let q = ecs::Query::<CPath>::new(&*world);
+        for (e, p) in q {
+            m.insert(e, p.waypoints.clone());
+        }
+        m
+    };
+    // A throwaway `World` carrying only the obstacle set, so `astar_grid`
+    // (which takes `&World`) can be reused without borrowing the real
+    // legacy `World` resource across the mutation loop below.
+    let mut obstacle_probe = World::new();
+    obstacle_probe.obstacles = world
+        .get_resource::<World>()
+        .map(|w| w.obstacles.clone())
+        .unwrap_or_default();
+
+    let mut new_positions: BTreeMap<ecs::Entity, IVec2> = BTreeMap::new();
+    let mut path_updates: BTreeMap<ecs::Entity, Option<VecDeque<IVec2>>> = BTreeMap::new();
     let mut moved: Vec<(ecs::Entity, IVec2, IVec2)> = vec![];
-    world.each_mut::<CPos>(|e, p| {
-        if let Some(goal) = goals.get(&e) {
-            let dx = (goal.pos.x - p.pos.x).signum();
-            let mut dy = (goal.pos.y - p.pos.y).signum();
-            // Cardinal-only behavior: prefer moving along X this tick; if we move in X,
-            // do not also move in Y (prevents diagonal movement).
-            if dx != 0 {
-                dy = 0;
+
+    for (&e, goal) in &goals {
+        let Some(&pos) = positions.get(&e) else {
+            continue;
+        };
+        if pos == goal.pos {
+            continue;
+        }
+
+        let dx = (goal.pos.x - pos.x).signum();
+        let mut dy = (goal.pos.y - pos.y).signum();
+        // Cardinal-only behavior: prefer moving along X this tick; if we move in X,
+        // do not also move in Y (prevents diagonal movement).
+        if dx != 0 {
+            dy = 0;
+        }
+        let naive_next = IVec2 {
+            x: pos.x + dx,
+            y: pos.y + dy,
+        };
+
+        let mut path = existing_paths.get(&e).cloned().unwrap_or_default();
+        let path_stale = path.back() != Some(&goal.pos);
+        let following_path = !path.is_empty() && !path_stale;
+
+        let next = if !following_path && !obstacle_probe.obstacle(naive_next) {
+            path_updates.insert(e, None);
+            naive_next
+        } else {
+            if path_stale {
+                path = astar_grid(&obstacle_probe, pos, goal.pos, MOVE_PATH_NODE_BUDGET)
+                    .map(VecDeque::from)
+                    .unwrap_or_default();
             }
-            if dx != 0 || dy != 0 {
-                let from = IVec2 {
-                    x: p.pos.x,
-                    y: p.pos.y,
-                };
-                if dx != 0 {
-                    p.pos.x += dx;
-                } else if dy != 0 {
-                    p.pos.y += dy;
+            match path.pop_front() {
+                Some(next) => {
+                    path_updates.insert(e, if path.is_empty() { None } else { Some(path) });
+                    next
+                }
+                // No route found (or already adjacent with nothing left to
+                // pop) — fall back to the direct step so the entity doesn't
+                // freeze forever against an unreachable goal.
+                None => {
+                    path_updates.insert(e, None);
+                    naive_next
                 }
-                moved.push((
-                    e,
-                    from,
-                    IVec2 {
-                        x: p.pos.x,
-                        y: p.pos.y,
-                    },
-                ));
             }
+        };
+
+        new_positions.insert(e, next);
+        moved.push((e, pos, next));
+    }
+
+    world.each_mut::<CPos>(|e, p| {
+        if let Some(&next) = new_positions.get(&e) {
+            p.pos = next;
         }
     });
+    for (e, update) in path_updates {
+        match update {
+            Some(waypoints) => world.insert(e, CPath { waypoints }),
+            None => {
+                world.remove::<CPath>(e);
+            }
+        }
+    }
     if let Some(ev) = world.get_resource_mut::<Events<MovedEvent>>() {
         let mut w = ev.writer();
         for (e, from, to) in moved {
@@ -1,5 +1,5 @@
 //! ECS component types mirroring legacy World data (Phase 1 incremental migration)
-use crate::IVec2;
+use crate::{ActionStep, Entity, IVec2};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
@@ -69,11 +69,157 @@ pub struct CCooldowns {
     pub map: CooldownMap,
 }
 
+/// Per-agent stamina pool consulted by `astraweave_core::constraint_engine`
+/// alongside [`CCooldowns`] before the execution bridge lets a costly
+/// [`ActionStep`] through.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct CStamina {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Default for CStamina {
+    fn default() -> Self {
+        Self {
+            current: 100.0,
+            max: 100.0,
+        }
+    }
+}
+
+impl CStamina {
+    /// Spends `amount` stamina, clamped to zero (never goes negative).
+    pub fn spend(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+    }
+
+    /// Regenerates `amount` stamina, clamped to [`Self::max`].
+    pub fn regen(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct CDesiredPos {
     pub pos: IVec2,
 }
 
+/// A queued sequence of tiles to step through on the way to a
+/// [`CDesiredPos`], produced by `astraweave_core::pathfinding::astar_grid`.
+/// `ecs_adapter::sys_move` (re)computes this whenever `CDesiredPos` changes
+/// and doesn't already match the path's final waypoint, then advances it one
+/// tile per tick, so movement detours around `World::obstacle` tiles instead
+/// of stalling against them.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CPath {
+    pub waypoints: std::collections::VecDeque<IVec2>,
+}
+
+/// Where a [`CActivePlan`]'s current step is in its lifecycle. Advanced and
+/// read by the execution bridge in `astraweave-ai`; see
+/// `astraweave_ai::execution_bridge`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlanStepStatus {
+    #[default]
+    Pending,
+    InProgress,
+    Completed,
+    Failed,
+    Aborted,
+}
+
+/// A [`crate::PlanIntent`] currently being carried out by an entity, one
+/// [`ActionStep`] at a time. Attached by the AI planning system once a plan
+/// is proposed; driven forward by `astraweave_ai::execution_bridge`'s
+/// execution system, which maps the current step to concrete ECS commands
+/// (e.g. [`CDesiredPos`], [`CAttackIntent`]) and advances `current_index`
+/// once that step's effect has resolved.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CActivePlan {
+    pub plan_id: String,
+    pub steps: Vec<ActionStep>,
+    pub current_index: usize,
+    pub status: PlanStepStatus,
+    /// Elapsed time, in seconds, spent on the current step (e.g. for `Wait`
+    /// or `CoverFire`'s `duration`). Reset to `0.0` whenever `current_index`
+    /// advances.
+    pub step_elapsed: f32,
+}
+
+impl CActivePlan {
+    /// Starts execution of `plan`, replacing any plan already in progress.
+    pub fn new(plan: crate::PlanIntent) -> Self {
+        let status = if plan.steps.is_empty() {
+            PlanStepStatus::Completed
+        } else {
+            PlanStepStatus::Pending
+        };
+        Self {
+            plan_id: plan.plan_id,
+            steps: plan.steps,
+            current_index: 0,
+            status,
+            step_elapsed: 0.0,
+        }
+    }
+
+    /// The step currently being executed, if the plan hasn't finished or
+    /// been interrupted.
+    pub fn current_step(&self) -> Option<&ActionStep> {
+        self.steps.get(self.current_index)
+    }
+}
+
+/// Structured reason a [`CActivePlan`] step failed outright, as opposed to
+/// being interrupted by a caller (e.g. a higher-priority plan replacing it
+/// via `astraweave_ai::execution_bridge::abort_plan`). Attached to the
+/// entity as part of a [`CReplanRequest`] by
+/// `astraweave_ai::execution_bridge::fail_plan`, so a planning system can
+/// react to *why* the plan died (widen the search after `PathBlocked`, drop
+/// the target after `TargetDead`) instead of pattern-matching on
+/// [`PlanAbortedEvent`](crate::ecs_events::PlanAbortedEvent)'s free-form
+/// string.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PlanFailureKind {
+    Unsupported(String),
+    Blocked(String),
+    TargetDead,
+    PathBlocked,
+    Timeout,
+}
+
+impl std::fmt::Display for PlanFailureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported(name) => write!(f, "unsupported action step: {name}"),
+            Self::Blocked(reason) => write!(f, "{reason}"),
+            Self::TargetDead => write!(f, "target is dead"),
+            Self::PathBlocked => write!(f, "path blocked"),
+            Self::Timeout => write!(f, "step timed out"),
+        }
+    }
+}
+
+/// Requests that a planning system generate a fresh plan for this entity.
+/// Attached by `astraweave_ai::execution_bridge::fail_plan` whenever a
+/// `CActivePlan` step fails outright; consuming systems should
+/// `world.remove::<CReplanRequest>` once they've reacted to it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CReplanRequest {
+    pub reason: PlanFailureKind,
+}
+
+/// Intent to strike `target_id` with a melee/ranged attack of the given
+/// `kind` (the originating [`ActionStep::action_name`]), consumed by
+/// `astraweave-gameplay`'s combat systems the same way `CDesiredPos` is
+/// consumed by movement. One-shot: cleared by the consuming system once
+/// resolved.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CAttackIntent {
+    pub target_id: Entity,
+    pub kind: String,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct CAiAgent;
 
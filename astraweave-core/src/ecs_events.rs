@@ -83,6 +83,34 @@ pub struct HealthChangedEvent {
     pub new_hp: i32,
 }
 
+/// Emitted by `astraweave_ai::execution_bridge` when one [`ActionStep`](crate::ActionStep)
+/// of a `CActivePlan` finishes and the plan advances to the next step.
+#[derive(Clone, Debug)]
+pub struct PlanStepCompletedEvent {
+    pub entity: ecs::Entity,
+    pub plan_id: String,
+    pub step_index: usize,
+}
+
+/// Emitted by `astraweave_ai::execution_bridge` when every step of a
+/// `CActivePlan` has completed successfully.
+#[derive(Clone, Debug)]
+pub struct PlanCompletedEvent {
+    pub entity: ecs::Entity,
+    pub plan_id: String,
+}
+
+/// Emitted by `astraweave_ai::execution_bridge` when a plan is interrupted
+/// before completion, either because a step's action is unsupported/invalid
+/// or because it was aborted by a caller (e.g. a higher-priority replan).
+#[derive(Clone, Debug)]
+pub struct PlanAbortedEvent {
+    pub entity: ecs::Entity,
+    pub plan_id: String,
+    pub step_index: usize,
+    pub reason: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,6 +8,9 @@
 //!   [`PlanIntent`], [`ActionStep`], and other shared gameplay types.
 //! - **[`tool_sandbox`] / [`tool_vocabulary`]** — [`ToolRegistry`] and [`ToolSpec`] for
 //!   AI action validation (the "tool sandbox" pattern).
+//! - **[`tool_catalog`]** — [`tool_catalog::ToolCatalog`], a single source of truth that
+//!   derives a [`ToolRegistry`] and [`ToolMetadata`](tool_vocabulary::ToolMetadata) list
+//!   from one set of tool definitions.
 //! - **[`capture_replay`]** — Deterministic capture and replay infrastructure.
 //! - **[`perception`]** — AI perception helpers (`astar_path`, `find_cover_positions`, `los_clear`).
 //! - **[`validation`]** — Configuration validation traits.
@@ -26,15 +29,18 @@
 //! ```
 
 pub mod capture_replay;
+pub mod constraint_engine;
 pub use capture_replay::*;
 pub mod ecs_adapter;
 pub mod ecs_bridge;
 pub mod ecs_components;
 pub mod ecs_events;
 pub mod metrics;
+pub mod pathfinding;
 pub mod perception;
 pub mod schema;
 pub mod sim;
+pub mod tool_catalog;
 pub mod tool_sandbox;
 pub mod tool_vocabulary;
 pub mod tools;
@@ -53,6 +59,7 @@ pub use schema::*;
 pub use sim::*;
 // Note: tools::Poi and schema::Poi are different types - using qualified imports where needed
 pub use ecs_components::*;
+pub use tool_catalog::{ToolCatalog, ToolDef, ToolParamDef};
 pub use tool_sandbox::*;
 pub use tool_vocabulary::*;
 pub use tools::{
@@ -61,41 +68,15 @@ pub use tools::{
 pub use validation::*;
 pub use world::*;
 
-/// Construct a default ToolRegistry matching MVP verbs.
+/// Construct a default ToolRegistry matching MVP verbs, derived from
+/// [`tool_catalog::mvp_tool_catalog`] so the tool list and its
+/// argument schema live in exactly one place.
 pub fn default_tool_registry() -> ToolRegistry {
-    use std::collections::BTreeMap;
-    ToolRegistry {
-        tools: vec![
-            ToolSpec {
-                name: "move_to".into(),
-                args: BTreeMap::from([("x".into(), "i32".into()), ("y".into(), "i32".into())]),
-            },
-            ToolSpec {
-                name: "throw".into(),
-                args: BTreeMap::from([
-                    ("item".into(), "enum[smoke,grenade]".into()),
-                    ("x".into(), "i32".into()),
-                    ("y".into(), "i32".into()),
-                ]),
-            },
-            ToolSpec {
-                name: "cover_fire".into(),
-                args: BTreeMap::from([
-                    ("target_id".into(), "u32".into()),
-                    ("duration".into(), "f32".into()),
-                ]),
-            },
-            ToolSpec {
-                name: "revive".into(),
-                args: BTreeMap::from([("ally_id".into(), "u32".into())]),
-            },
-        ],
-        constraints: Constraints {
-            enforce_cooldowns: true,
-            enforce_los: true,
-            enforce_stamina: true,
-        },
-    }
+    tool_catalog::mvp_tool_catalog().to_tool_registry(Constraints {
+        enforce_cooldowns: true,
+        enforce_los: true,
+        enforce_stamina: true,
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,179 @@
+//! Grid A* pathfinding over [`World`] obstacles.
+//!
+//! `astraweave-nav` bakes continuous-space navmeshes from triangle meshes,
+//! which doesn't apply here: `World` is a tile grid addressed by [`IVec2`],
+//! not a 3D mesh. [`astar_grid`] is the tile-grid equivalent — it searches
+//! the same 4-connected neighborhood `ecs_adapter::sys_move` steps through,
+//! but routes around `World::obstacle` instead of walking straight at the
+//! goal. [`ecs_adapter::sys_move`](crate::ecs_adapter) consumes its output
+//! via the [`crate::ecs_components::CPath`] component so `MoveTo` steps
+//! actually detour around blocked tiles instead of stalling against them.
+
+use crate::{IVec2, World};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+fn neighbors(p: IVec2) -> [IVec2; 4] {
+    [
+        IVec2 { x: p.x + 1, y: p.y },
+        IVec2 { x: p.x - 1, y: p.y },
+        IVec2 { x: p.x, y: p.y + 1 },
+        IVec2 { x: p.x, y: p.y - 1 },
+    ]
+}
+
+fn manhattan(a: IVec2, b: IVec2) -> i32 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Node {
+    cost: i32,
+    pos: IVec2,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap on `cost`.
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| (self.pos.x, self.pos.y).cmp(&(other.pos.x, other.pos.y)))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Searches for a 4-connected path from `start` to `goal` that avoids
+/// `world`'s obstacles, expanding at most `max_nodes` tiles before giving
+/// up. Returns the path excluding `start` (so the first element is the
+/// first step to take), or `None` if the goal is unreachable within the
+/// node budget. `start` and `goal` themselves are never treated as
+/// obstacles even if `World::obstacle` says otherwise, so a plan targeting
+/// an occupied tile (e.g. an enemy's tile, for a melee approach) still
+/// resolves.
+pub fn astar_grid(world: &World, start: IVec2, goal: IVec2, max_nodes: usize) -> Option<Vec<IVec2>> {
+    if start == goal {
+        return Some(vec![]);
+    }
+
+    let key = |p: IVec2| (p.x, p.y);
+
+    let mut open = BinaryHeap::new();
+    let mut g_score: HashMap<(i32, i32), i32> = HashMap::new();
+    let mut came_from: HashMap<(i32, i32), IVec2> = HashMap::new();
+
+    g_score.insert(key(start), 0);
+    open.push(Node {
+        cost: manhattan(start, goal),
+        pos: start,
+    });
+
+    let mut expanded = 0usize;
+    while let Some(Node { pos, .. }) = open.pop() {
+        if pos == goal {
+            let mut path = VecDeque::new();
+            let mut cur = pos;
+            while cur != start {
+                path.push_front(cur);
+                cur = came_from[&key(cur)];
+            }
+            return Some(path.into());
+        }
+
+        expanded += 1;
+        if expanded > max_nodes {
+            return None;
+        }
+
+        let cur_g = g_score[&key(pos)];
+        for next in neighbors(pos) {
+            if next != goal && world.obstacle(next) {
+                continue;
+            }
+            let tentative_g = cur_g + 1;
+            if tentative_g < *g_score.get(&key(next)).unwrap_or(&i32::MAX) {
+                came_from.insert(key(next), pos);
+                g_score.insert(key(next), tentative_g);
+                open.push(Node {
+                    cost: tentative_g + manhattan(next, goal),
+                    pos: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Team;
+
+    #[test]
+    fn straight_line_when_unobstructed() {
+        let w = World::new();
+        let path = astar_grid(&w, IVec2 { x: 0, y: 0 }, IVec2 { x: 3, y: 0 }, 1000).unwrap();
+        assert_eq!(
+            path,
+            vec![
+                IVec2 { x: 1, y: 0 },
+                IVec2 { x: 2, y: 0 },
+                IVec2 { x: 3, y: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn same_start_and_goal_is_empty_path() {
+        let w = World::new();
+        let path = astar_grid(&w, IVec2 { x: 2, y: 2 }, IVec2 { x: 2, y: 2 }, 1000).unwrap();
+        assert!(path.is_empty());
+    }
+
+    #[test]
+    fn routes_around_a_wall() {
+        let mut w = World::new();
+        for y in -2..=2 {
+            w.obstacles.insert((1, y));
+        }
+        // Leave a gap at (1, 3) so the goal is still reachable.
+        w.obstacles.remove(&(1, 3));
+
+        let path = astar_grid(&w, IVec2 { x: 0, y: 0 }, IVec2 { x: 2, y: 0 }, 1000).unwrap();
+        assert!(!path.iter().any(|p| p.x == 1 && (-2..=2).contains(&p.y)));
+        assert_eq!(*path.last().unwrap(), IVec2 { x: 2, y: 0 });
+    }
+
+    #[test]
+    fn unreachable_goal_returns_none() {
+        let mut w = World::new();
+        for y in -5..=5 {
+            w.obstacles.insert((1, y));
+        }
+        let path = astar_grid(&w, IVec2 { x: 0, y: 0 }, IVec2 { x: 2, y: 0 }, 1000);
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn goal_tile_occupied_by_target_is_still_reachable() {
+        let mut w = World::new();
+        let _enemy = w.spawn("enemy", IVec2 { x: 3, y: 0 }, Team { id: 2 }, 10, 0);
+        w.obstacles.insert((3, 0));
+
+        let path = astar_grid(&w, IVec2 { x: 0, y: 0 }, IVec2 { x: 3, y: 0 }, 1000).unwrap();
+        assert_eq!(*path.last().unwrap(), IVec2 { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn node_budget_gives_up_on_expensive_search() {
+        let w = World::new();
+        let path = astar_grid(&w, IVec2 { x: 0, y: 0 }, IVec2 { x: 100, y: 100 }, 5);
+        assert!(path.is_none());
+    }
+}
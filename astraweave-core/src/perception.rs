@@ -80,6 +80,176 @@ pub fn build_snapshot(
     }
 }
 
+/// A forward-facing vision cone: an entity only sees targets within `range`
+/// tiles and within `half_angle_deg` of `facing_deg` (measured from +X,
+/// counter-clockwise, matching `IVec2`'s `x`/`y` axes).
+#[derive(Clone, Copy, Debug)]
+pub struct VisionCone {
+    pub range: i32,
+    pub half_angle_deg: f32,
+    pub facing_deg: f32,
+}
+
+/// Casts a grid ray from `from` to `to` using Bresenham's line algorithm and
+/// returns `true` if no tile strictly between the endpoints is an obstacle.
+/// `from` and `to` themselves are never treated as blocking, mirroring
+/// `pathfinding::astar_grid`'s treatment of the start/goal tiles.
+pub fn has_line_of_sight(w: &World, from: IVec2, to: IVec2) -> bool {
+    let (mut x0, mut y0) = (from.x, from.y);
+    let (x1, y1) = (to.x, to.y);
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        if (x0, y0) != (from.x, from.y) && (x0, y0) != (x1, y1) && w.obstacle(IVec2 { x: x0, y: y0 }) {
+            return false;
+        }
+        if x0 == x1 && y0 == y1 {
+            return true;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+}
+
+/// Returns `true` if `to` is within `cone`'s range and angular field of view
+/// as seen from `from`. A target exactly at `from` is always considered
+/// visible (range/angle are undefined for zero distance).
+pub fn in_vision_cone(cone: &VisionCone, from: IVec2, to: IVec2) -> bool {
+    let delta = (to.x - from.x, to.y - from.y);
+    if delta == (0, 0) {
+        return true;
+    }
+    let dist_sq = delta.0 * delta.0 + delta.1 * delta.1;
+    if dist_sq > cone.range * cone.range {
+        return false;
+    }
+    let angle_to_target = (delta.1 as f32).atan2(delta.0 as f32).to_degrees();
+    let mut diff = (angle_to_target - cone.facing_deg) % 360.0;
+    if diff > 180.0 {
+        diff -= 360.0;
+    } else if diff < -180.0 {
+        diff += 360.0;
+    }
+    diff.abs() <= cone.half_angle_deg
+}
+
+/// A sound loud enough to be noticed without direct line of sight (e.g. a
+/// gunshot, a breaking window), consumed by [`PerceptionMemory::observe`] to
+/// refresh an enemy's remembered position even while it's unseen.
+#[derive(Clone, Copy, Debug)]
+pub struct HearingEvent {
+    pub source: Entity,
+    pub pos: IVec2,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RememberedEnemy {
+    pos: IVec2,
+    last_seen: f32,
+}
+
+/// Per-observer last-known-position memory of enemies that have gone out of
+/// sight, so a companion doesn't instantly "forget" a target the moment it
+/// steps behind cover. Call [`PerceptionMemory::observe`] once per tick with
+/// the current vision-cone/LOS/hearing results, then
+/// [`PerceptionMemory::snapshot_enemies`] to fold memory into the
+/// [`EnemyState`] list a [`WorldSnapshot`] is built from.
+#[derive(Clone, Debug, Default)]
+pub struct PerceptionMemory {
+    remembered: BTreeMap<Entity, RememberedEnemy>,
+}
+
+impl PerceptionMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates memory for one enemy this tick: `visible` reflects a fresh
+    /// LOS + vision-cone check, `heard` reflects any [`HearingEvent`] whose
+    /// `source` is this enemy. A currently visible or heard enemy's
+    /// remembered position is refreshed to `pos` at time `now`; otherwise
+    /// the last remembered position (if any, and not yet stale) is kept.
+    pub fn observe(&mut self, enemy: Entity, pos: IVec2, now: f32, visible: bool, heard: bool) {
+        if visible || heard {
+            self.remembered.insert(enemy, RememberedEnemy { pos, last_seen: now });
+        }
+    }
+
+    /// Drops remembered enemies whose last sighting/hearing is older than
+    /// `timeout` seconds relative to `now`, so stale memories don't linger
+    /// forever.
+    pub fn decay(&mut self, now: f32, timeout: f32) {
+        self.remembered.retain(|_, r| now - r.last_seen <= timeout);
+    }
+
+    /// The last known position and sighting time for `enemy`, if it's
+    /// currently remembered.
+    pub fn last_known(&self, enemy: Entity) -> Option<(IVec2, f32)> {
+        self.remembered.get(&enemy).map(|r| (r.pos, r.last_seen))
+    }
+}
+
+/// Like [`build_snapshot`], but derives each enemy's `pos`/`last_seen`/`cover`
+/// from real line-of-sight and vision-cone checks instead of a flat radius,
+/// folding in `hearing` events and `memory`'s last-known positions for
+/// enemies that are currently out of sight. `memory` is updated in place and
+/// should be reused across ticks (and decayed with
+/// [`PerceptionMemory::decay`]) so remembered positions persist between
+/// calls.
+#[allow(clippy::too_many_arguments)]
+pub fn build_snapshot_with_perception(
+    w: &World,
+    t_player: Entity,
+    t_companion: Entity,
+    enemies: &[Entity],
+    objective: Option<String>,
+    cfg: &PerceptionConfig,
+    cone: &VisionCone,
+    hearing: &[HearingEvent],
+    memory: &mut PerceptionMemory,
+) -> WorldSnapshot {
+    let mut snap = build_snapshot(w, t_player, t_companion, enemies, objective, cfg);
+    let cpos = snap.me.pos;
+
+    let mut visible_enemies = Vec::new();
+    for &e in enemies {
+        let Some(pos) = w.pos_of(e) else { continue };
+        let visible = in_vision_cone(cone, cpos, pos) && has_line_of_sight(w, cpos, pos);
+        let heard = hearing.iter().any(|h| h.source == e);
+        memory.observe(e, pos, w.t, visible, heard);
+
+        if let Some(hp) = w.health(e).map(|h| h.hp) {
+            let (state_pos, last_seen, cover) = if visible {
+                (pos, w.t, "none")
+            } else if let Some((remembered_pos, remembered_t)) = memory.last_known(e) {
+                (remembered_pos, remembered_t, "unknown")
+            } else {
+                continue; // never seen or heard: not reported at all
+            };
+            visible_enemies.push(EnemyState {
+                id: e,
+                pos: state_pos,
+                hp,
+                cover: cover.into(),
+                last_seen,
+            });
+        }
+    }
+    snap.enemies = visible_enemies;
+    snap
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -477,4 +647,171 @@ mod tests {
         let snap = build_snapshot(&w, player, companion, &[enemy], None, &cfg);
         assert_eq!(snap.enemies[0].cover, "low", "Negative dir subtraction must work");
     }
+
+    // ===== VisionCone / LOS Tests =====
+
+    #[test]
+    fn test_los_clear_line_is_visible() {
+        let w = World::new();
+        assert!(has_line_of_sight(&w, iv2(0, 0), iv2(5, 0)));
+    }
+
+    #[test]
+    fn test_los_blocked_by_obstacle() {
+        let mut w = World::new();
+        w.obstacles.insert((2, 0));
+        assert!(!has_line_of_sight(&w, iv2(0, 0), iv2(5, 0)));
+    }
+
+    #[test]
+    fn test_los_ignores_obstacle_at_endpoints() {
+        let mut w = World::new();
+        w.obstacles.insert((0, 0));
+        w.obstacles.insert((5, 0));
+        assert!(has_line_of_sight(&w, iv2(0, 0), iv2(5, 0)));
+    }
+
+    #[test]
+    fn test_vision_cone_within_range_and_angle() {
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        assert!(in_vision_cone(&cone, iv2(0, 0), iv2(5, 1)));
+    }
+
+    #[test]
+    fn test_vision_cone_out_of_range() {
+        let cone = VisionCone { range: 3, half_angle_deg: 90.0, facing_deg: 0.0 };
+        assert!(!in_vision_cone(&cone, iv2(0, 0), iv2(10, 0)));
+    }
+
+    #[test]
+    fn test_vision_cone_behind_observer_is_not_seen() {
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        assert!(!in_vision_cone(&cone, iv2(0, 0), iv2(-5, 0)));
+    }
+
+    #[test]
+    fn test_vision_cone_target_at_observer_is_always_visible() {
+        let cone = VisionCone { range: 10, half_angle_deg: 1.0, facing_deg: 90.0 };
+        assert!(in_vision_cone(&cone, iv2(3, 3), iv2(3, 3)));
+    }
+
+    // ===== PerceptionMemory Tests =====
+
+    #[test]
+    fn test_memory_remembers_visible_enemy() {
+        let mut mem = PerceptionMemory::new();
+        mem.observe(7, iv2(1, 1), 10.0, true, false);
+        assert_eq!(mem.last_known(7), Some((iv2(1, 1), 10.0)));
+    }
+
+    #[test]
+    fn test_memory_keeps_last_known_after_losing_sight() {
+        let mut mem = PerceptionMemory::new();
+        mem.observe(7, iv2(1, 1), 10.0, true, false);
+        mem.observe(7, iv2(1, 1), 11.0, false, false);
+        assert_eq!(mem.last_known(7), Some((iv2(1, 1), 10.0)));
+    }
+
+    #[test]
+    fn test_memory_refreshes_on_hearing_without_sight() {
+        let mut mem = PerceptionMemory::new();
+        mem.observe(7, iv2(2, 2), 5.0, false, true);
+        assert_eq!(mem.last_known(7), Some((iv2(2, 2), 5.0)));
+    }
+
+    #[test]
+    fn test_memory_decays_after_timeout() {
+        let mut mem = PerceptionMemory::new();
+        mem.observe(7, iv2(1, 1), 10.0, true, false);
+        mem.decay(21.0, 5.0);
+        assert_eq!(mem.last_known(7), None);
+    }
+
+    #[test]
+    fn test_memory_survives_decay_within_timeout() {
+        let mut mem = PerceptionMemory::new();
+        mem.observe(7, iv2(1, 1), 10.0, true, false);
+        mem.decay(12.0, 5.0);
+        assert_eq!(mem.last_known(7), Some((iv2(1, 1), 10.0)));
+    }
+
+    // ===== build_snapshot_with_perception Tests =====
+
+    #[test]
+    fn test_perception_snapshot_reports_visible_enemy() {
+        let mut w = World::new();
+        w.t = 1.0;
+        let player = w.spawn("player", iv2(0, 0), Team { id: 1 }, 100, 0);
+        let companion = w.spawn("companion", iv2(0, 0), Team { id: 1 }, 100, 10);
+        let enemy = w.spawn("enemy", iv2(5, 0), Team { id: 2 }, 50, 0);
+
+        let cfg = PerceptionConfig { los_max: 20 };
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        let mut mem = PerceptionMemory::new();
+        let snap = build_snapshot_with_perception(&w, player, companion, &[enemy], None, &cfg, &cone, &[], &mut mem);
+
+        assert_eq!(snap.enemies.len(), 1);
+        assert_eq!(snap.enemies[0].cover, "none");
+        assert_eq!(snap.enemies[0].last_seen, 1.0);
+    }
+
+    #[test]
+    fn test_perception_snapshot_hides_enemy_outside_cone() {
+        let mut w = World::new();
+        w.t = 1.0;
+        let player = w.spawn("player", iv2(0, 0), Team { id: 1 }, 100, 0);
+        let companion = w.spawn("companion", iv2(0, 0), Team { id: 1 }, 100, 10);
+        let enemy = w.spawn("enemy", iv2(-5, 0), Team { id: 2 }, 50, 0);
+
+        let cfg = PerceptionConfig { los_max: 20 };
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        let mut mem = PerceptionMemory::new();
+        let snap = build_snapshot_with_perception(&w, player, companion, &[enemy], None, &cfg, &cone, &[], &mut mem);
+
+        assert!(snap.enemies.is_empty());
+    }
+
+    #[test]
+    fn test_perception_snapshot_remembers_enemy_that_left_cone() {
+        let mut w = World::new();
+        w.t = 1.0;
+        let player = w.spawn("player", iv2(0, 0), Team { id: 1 }, 100, 0);
+        let companion = w.spawn("companion", iv2(0, 0), Team { id: 1 }, 100, 10);
+        let enemy = w.spawn("enemy", iv2(5, 0), Team { id: 2 }, 50, 0);
+
+        let cfg = PerceptionConfig { los_max: 20 };
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        let mut mem = PerceptionMemory::new();
+        let _ = build_snapshot_with_perception(&w, player, companion, &[enemy], None, &cfg, &cone, &[], &mut mem);
+
+        // Enemy steps out of the cone's field of view next tick.
+        if let Some(pose) = w.pose_mut(enemy) {
+            pose.pos = iv2(-5, 0);
+        }
+        w.t = 2.0;
+        let snap = build_snapshot_with_perception(&w, player, companion, &[enemy], None, &cfg, &cone, &[], &mut mem);
+
+        assert_eq!(snap.enemies.len(), 1);
+        assert_eq!(snap.enemies[0].cover, "unknown");
+        assert_eq!(snap.enemies[0].pos, iv2(5, 0), "should report last-known position, not current");
+        assert_eq!(snap.enemies[0].last_seen, 1.0);
+    }
+
+    #[test]
+    fn test_perception_snapshot_hearing_reveals_unseen_enemy() {
+        let mut w = World::new();
+        w.t = 3.0;
+        let player = w.spawn("player", iv2(0, 0), Team { id: 1 }, 100, 0);
+        let companion = w.spawn("companion", iv2(0, 0), Team { id: 1 }, 100, 10);
+        let enemy = w.spawn("enemy", iv2(-5, 0), Team { id: 2 }, 50, 0);
+
+        let cfg = PerceptionConfig { los_max: 20 };
+        let cone = VisionCone { range: 10, half_angle_deg: 45.0, facing_deg: 0.0 };
+        let mut mem = PerceptionMemory::new();
+        let hearing = [HearingEvent { source: enemy, pos: iv2(-5, 0) }];
+        let snap = build_snapshot_with_perception(&w, player, companion, &[enemy], None, &cfg, &cone, &hearing, &mut mem);
+
+        assert_eq!(snap.enemies.len(), 1);
+        assert_eq!(snap.enemies[0].cover, "unknown");
+    }
 }
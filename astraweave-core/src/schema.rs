@@ -264,7 +264,7 @@ pub struct EnemyState {
     pub last_seen: f32,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Poi {
     pub k: String,
     pub pos: IVec2,
@@ -0,0 +1,360 @@
+//! Single source of truth for LLM-exposed tool metadata.
+//!
+//! Historically each tool an AI agent can invoke was described in two
+//! separate, hand-maintained places that could silently drift apart: a
+//! [`ToolSpec`] entry in
+//! [`default_tool_registry`](crate::default_tool_registry) (for plan
+//! validation) and a [`ToolMetadata`] entry in
+//! [`tool_vocabulary::get_all_tools`](crate::tool_vocabulary::get_all_tools)
+//! (for prompt text). Adding or changing a tool meant remembering to
+//! update both.
+//!
+//! [`ToolCatalog`] fixes that by describing each tool exactly once as a
+//! [`ToolDef`], from which a [`ToolRegistry`], a [`ToolMetadata`] list,
+//! a JSON schema, and required-argument validation are all derived.
+//! Tool names use the snake_case verbs already established by
+//! `default_tool_registry` and `tool_vocabulary::get_all_tools`
+//! (`"move_to"`, `"throw"`, ...). `astraweave-llm`'s plan validation
+//! and sanitization look tools up by the PascalCase
+//! [`ActionStep::action_name`] form instead, so they do not match these
+//! names.
+
+use crate::schema::{Constraints, ToolRegistry, ToolSpec};
+use crate::tool_vocabulary::{ToolMetadata, ToolParameter};
+use std::collections::BTreeMap;
+
+/// One parameter of a [`ToolDef`].
+#[derive(Clone, Copy, Debug)]
+pub struct ToolParamDef {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+impl ToolParamDef {
+    /// Defines a required parameter.
+    pub const fn required(name: &'static str, type_name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            type_name,
+            required: true,
+            description,
+        }
+    }
+
+    /// Defines an optional parameter.
+    pub const fn optional(name: &'static str, type_name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            type_name,
+            required: false,
+            description,
+        }
+    }
+}
+
+/// The complete definition of one LLM-exposed tool: name, parameters, and
+/// the prompt-facing text that describes it.
+#[derive(Clone, Copy, Debug)]
+pub struct ToolDef {
+    pub name: &'static str,
+    pub category: &'static str,
+    pub description: &'static str,
+    pub params: &'static [ToolParamDef],
+    pub preconditions: &'static [&'static str],
+    pub effects: &'static [&'static str],
+    pub cooldown: Option<f32>,
+    pub cost: Option<&'static str>,
+}
+
+/// A set of tools an AI agent may invoke, described once and used to
+/// derive a [`ToolRegistry`] (validation), a [`ToolMetadata`] list
+/// (prompt text), and a JSON schema, so those three can no longer drift
+/// apart from each other.
+#[derive(Clone, Debug, Default)]
+pub struct ToolCatalog {
+    tools: Vec<ToolDef>,
+}
+
+impl ToolCatalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self { tools: Vec::new() }
+    }
+
+    /// Adds a tool to the catalog, builder-style.
+    pub fn with_tool(mut self, tool: ToolDef) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    /// All tools in this catalog.
+    pub fn tools(&self) -> &[ToolDef] {
+        &self.tools
+    }
+
+    /// Looks up a tool by name.
+    pub fn find(&self, name: &str) -> Option<&ToolDef> {
+        self.tools.iter().find(|t| t.name == name)
+    }
+
+    /// Derives a [`ToolRegistry`] from this catalog for plan validation.
+    pub fn to_tool_registry(&self, constraints: Constraints) -> ToolRegistry {
+        ToolRegistry {
+            tools: self
+                .tools
+                .iter()
+                .map(|t| ToolSpec {
+                    name: t.name.to_string(),
+                    args: t
+                        .params
+                        .iter()
+                        .map(|p| {
+                            let type_name = if p.required {
+                                p.type_name.to_string()
+                            } else {
+                                format!("{}?", p.type_name)
+                            };
+                            (p.name.to_string(), type_name)
+                        })
+                        .collect(),
+                })
+                .collect(),
+            constraints,
+        }
+    }
+
+    /// Derives prompt-facing [`ToolMetadata`] from this catalog.
+    pub fn to_tool_metadata(&self) -> Vec<ToolMetadata> {
+        self.tools
+            .iter()
+            .map(|t| ToolMetadata {
+                name: t.name.to_string(),
+                category: t.category.to_string(),
+                description: t.description.to_string(),
+                parameters: t
+                    .params
+                    .iter()
+                    .map(|p| ToolParameter {
+                        name: p.name.to_string(),
+                        param_type: p.type_name.to_string(),
+                        required: p.required,
+                        description: p.description.to_string(),
+                    })
+                    .collect(),
+                preconditions: t.preconditions.iter().map(|s| s.to_string()).collect(),
+                effects: t.effects.iter().map(|s| s.to_string()).collect(),
+                cooldown: t.cooldown,
+                cost: t.cost.map(|s| s.to_string()),
+            })
+            .collect()
+    }
+
+    /// Builds a JSON schema (tool name -> parameter schema) suitable for
+    /// embedding in an LLM system prompt.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let mut schema = serde_json::Map::new();
+        for tool in &self.tools {
+            let mut properties = serde_json::Map::new();
+            let mut required = Vec::new();
+            for p in tool.params {
+                properties.insert(
+                    p.name.to_string(),
+                    serde_json::json!({
+                        "type": p.type_name,
+                        "description": p.description,
+                    }),
+                );
+                if p.required {
+                    required.push(p.name);
+                }
+            }
+            schema.insert(
+                tool.name.to_string(),
+                serde_json::json!({
+                    "type": "object",
+                    "description": tool.description,
+                    "properties": properties,
+                    "required": required,
+                }),
+            );
+        }
+        serde_json::Value::Object(schema)
+    }
+
+    /// Validates that `name` is a known tool and that `provided_args`
+    /// covers every required parameter. Argument *types* are still
+    /// enforced by [`ActionStep`]'s own deserialization; this only checks
+    /// the tool name and argument presence against the catalog.
+    pub fn validate_call(
+        &self,
+        name: &str,
+        provided_args: &BTreeMap<String, String>,
+    ) -> Result<(), String> {
+        let tool = self
+            .find(name)
+            .ok_or_else(|| format!("unknown tool: {name}"))?;
+        for p in tool.params {
+            if p.required && !provided_args.contains_key(p.name) {
+                return Err(format!(
+                    "tool {name} missing required argument: {}",
+                    p.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The catalog backing [`crate::default_tool_registry`]'s MVP tools.
+/// Migrating the rest of
+/// [`tool_vocabulary::get_all_tools`](crate::tool_vocabulary::get_all_tools)'s
+/// hand-written entries onto [`ToolCatalog`] is follow-up work; new tools
+/// should be added here rather than as separate `ToolSpec`/`ToolMetadata`
+/// literals.
+pub fn mvp_tool_catalog() -> ToolCatalog {
+    ToolCatalog::new()
+        .with_tool(ToolDef {
+            name: "move_to",
+            category: "Movement",
+            description: "Move to a specific position on the map",
+            params: &[
+                ToolParamDef::required("x", "i32", "Target X coordinate"),
+                ToolParamDef::required("y", "i32", "Target Y coordinate"),
+            ],
+            preconditions: &["Path must be clear to target"],
+            effects: &["Agent position changes to (x, y)"],
+            cooldown: None,
+            cost: None,
+        })
+        .with_tool(ToolDef {
+            name: "throw",
+            category: "Legacy",
+            description: "Throw an item (grenade, smoke, etc.) to a position",
+            params: &[
+                ToolParamDef::required("item", "enum[smoke,grenade]", "Item to throw"),
+                ToolParamDef::required("x", "i32", "Target X coordinate"),
+                ToolParamDef::required("y", "i32", "Target Y coordinate"),
+            ],
+            preconditions: &["Item must be in inventory"],
+            effects: &["Item lands at (x, y)"],
+            cooldown: None,
+            cost: None,
+        })
+        .with_tool(ToolDef {
+            name: "cover_fire",
+            category: "Offensive",
+            description: "Suppress a target with sustained fire",
+            params: &[
+                ToolParamDef::required("target_id", "u32", "Entity to suppress"),
+                ToolParamDef::required("duration", "f32", "Duration in seconds"),
+            ],
+            preconditions: &["Target must be visible"],
+            effects: &["Target suppressed for the given duration"],
+            cooldown: None,
+            cost: Some("ammo"),
+        })
+        .with_tool(ToolDef {
+            name: "revive",
+            category: "Legacy",
+            description: "Revive a downed ally",
+            params: &[ToolParamDef::required("ally_id", "u32", "Ally to revive")],
+            preconditions: &["Ally must be downed and nearby"],
+            effects: &["Ally is revived"],
+            cooldown: None,
+            cost: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_tool_registry_matches_mvp_tool_names() {
+        let reg = mvp_tool_catalog().to_tool_registry(Constraints {
+            enforce_cooldowns: true,
+            enforce_los: true,
+            enforce_stamina: true,
+        });
+        let names: Vec<&str> = reg.tools.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["move_to", "throw", "cover_fire", "revive"]);
+    }
+
+    #[test]
+    fn to_tool_registry_marks_optional_args_with_a_question_mark() {
+        let catalog = ToolCatalog::new().with_tool(ToolDef {
+            name: "TakeCover",
+            category: "Movement",
+            description: "Take cover",
+            params: &[ToolParamDef::optional(
+                "position",
+                "IVec2",
+                "Optional cover position",
+            )],
+            preconditions: &[],
+            effects: &[],
+            cooldown: None,
+            cost: None,
+        });
+        let reg = catalog.to_tool_registry(Constraints {
+            enforce_cooldowns: false,
+            enforce_los: false,
+            enforce_stamina: false,
+        });
+        assert_eq!(reg.tools[0].args.get("position").unwrap(), "IVec2?");
+    }
+
+    #[test]
+    fn to_tool_metadata_and_to_tool_registry_stay_in_sync() {
+        let catalog = mvp_tool_catalog();
+        let reg = catalog.to_tool_registry(Constraints {
+            enforce_cooldowns: true,
+            enforce_los: true,
+            enforce_stamina: true,
+        });
+        let meta = catalog.to_tool_metadata();
+
+        assert_eq!(reg.tools.len(), meta.len());
+        for (spec, m) in reg.tools.iter().zip(meta.iter()) {
+            assert_eq!(spec.name, m.name);
+            assert_eq!(spec.args.len(), m.parameters.len());
+        }
+    }
+
+    #[test]
+    fn to_json_schema_includes_every_tool_and_its_required_args() {
+        let schema = mvp_tool_catalog().to_json_schema();
+        let move_to = &schema["move_to"];
+        assert_eq!(move_to["required"], serde_json::json!(["x", "y"]));
+        assert!(schema.get("revive").is_some());
+    }
+
+    #[test]
+    fn validate_call_rejects_unknown_tool() {
+        let catalog = mvp_tool_catalog();
+        let err = catalog
+            .validate_call("NotATool", &BTreeMap::new())
+            .unwrap_err();
+        assert!(err.contains("unknown tool"));
+    }
+
+    #[test]
+    fn validate_call_rejects_missing_required_argument() {
+        let catalog = mvp_tool_catalog();
+        let mut args = BTreeMap::new();
+        args.insert("x".to_string(), "5".to_string());
+        // Missing "y"
+        let err = catalog.validate_call("move_to", &args).unwrap_err();
+        assert!(err.contains("missing required argument"));
+    }
+
+    #[test]
+    fn validate_call_accepts_a_fully_specified_call() {
+        let catalog = mvp_tool_catalog();
+        let mut args = BTreeMap::new();
+        args.insert("ally_id".to_string(), "3".to_string());
+        assert!(catalog.validate_call("revive", &args).is_ok());
+    }
+}
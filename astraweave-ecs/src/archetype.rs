@@ -42,6 +42,15 @@ impl ArchetypeSignature {
     }
 }
 
+/// Estimated component storage footprint returned by [`Archetype::memory_stats`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ArchetypeMemoryStats {
+    pub estimated_bytes: usize,
+    /// `false` when the archetype has legacy Box-mode columns, whose
+    /// type-erased storage isn't included in `estimated_bytes`.
+    pub exact: bool,
+}
+
 /// Archetype storage: all entities with the same component signature
 ///
 /// # Storage Modes
@@ -328,6 +337,27 @@ impl Archetype {
         &self.entities
     }
 
+    /// Estimates this archetype's component storage footprint in bytes.
+    ///
+    /// Only BlobVec columns are byte-accounted precisely, since their
+    /// `ComponentMeta::layout` gives an exact per-element size; legacy
+    /// Box-mode columns store type-erased `Box<dyn Any>` values whose size
+    /// can't be recovered without downcasting to a concrete type, so their
+    /// presence is reported via `exact = false` rather than guessed at.
+    pub fn memory_stats(&self) -> ArchetypeMemoryStats {
+        let mut estimated_bytes = 0usize;
+        if let (Some(blob_components), Some(metas)) = (&self.blob_components, &self.component_metas) {
+            for (ty, blob) in blob_components {
+                let size = metas.get(ty).map(|m| m.layout.size()).unwrap_or(0);
+                estimated_bytes += size * blob.len();
+            }
+        }
+        ArchetypeMemoryStats {
+            estimated_bytes,
+            exact: self.components.is_empty(),
+        }
+    }
+
     /// Iterate over (entity, component) pairs for batch processing.
     ///
     /// This is much faster than repeated get() calls as it avoids per-entity lookups.
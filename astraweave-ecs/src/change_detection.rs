@@ -0,0 +1,235 @@
+//! Per-component change detection.
+//!
+//! `World` has no way to tell a system "only entities whose component
+//! changed this frame" — every system that wants that has to snapshot
+//! and diff components itself. This module gives `World` a logical
+//! frame counter (advanced once per step, e.g. from `App::run_fixed`)
+//! and, per `(component type, entity)`, the tick it was last inserted
+//! and the tick it was last handed out mutably. [`ChangedQuery`] and
+//! [`AddedQuery`] filter [`Query`](crate::system_param::Query)-style
+//! iteration down to entities whose tick is newer than a system's
+//! `since_tick` (typically the tick the system last ran).
+//!
+//! Change tracking here is access-based, not diff-based: `World::get_mut`
+//! and `World::each_mut` mark a component "changed" as soon as a mutable
+//! reference is handed out, whether or not the caller actually wrote
+//! through it. That's a coarser signal than tracking real writes, but it
+//! matches this ECS's plain `&mut T` accessors (there's no `Mut<T>`
+//! deref-tracking guard to hook into) and is still enough to let render
+//! extraction or network delta encoding skip entities nothing touched.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::{Component, Entity};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct ComponentTicks {
+    added: u32,
+    changed: u32,
+}
+
+/// Tracks, per component type and entity, which World tick last added or
+/// mutably-accessed that component. Lives on [`crate::World`].
+#[derive(Default)]
+pub struct ChangeTracker {
+    current_tick: u32,
+    ticks: HashMap<TypeId, HashMap<Entity, ComponentTicks>>,
+}
+
+impl ChangeTracker {
+    pub fn current_tick(&self) -> u32 {
+        self.current_tick
+    }
+
+    /// Advances the logical tick and returns the tick that was current
+    /// before the advance; anything added or mutably accessed from then
+    /// on is newer than the returned value. Call once per frame/step,
+    /// before running systems that read `Changed`/`Added` state.
+    pub fn advance_tick(&mut self) -> u32 {
+        let previous = self.current_tick;
+        self.current_tick += 1;
+        previous
+    }
+
+    pub(crate) fn record_added<T: Component>(&mut self, entity: Entity) {
+        let tick = self.current_tick;
+        self.ticks
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .insert(entity, ComponentTicks {
+                added: tick,
+                changed: tick,
+            });
+    }
+
+    pub(crate) fn record_changed<T: Component>(&mut self, entity: Entity) {
+        let tick = self.current_tick;
+        self.ticks
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .entry(entity)
+            .or_insert(ComponentTicks {
+                added: tick,
+                changed: tick,
+            })
+            .changed = tick;
+    }
+
+    /// Drops all recorded ticks for `entity`, e.g. when it's despawned.
+    pub(crate) fn clear_entity(&mut self, entity: &Entity) {
+        for per_type in self.ticks.values_mut() {
+            per_type.remove(entity);
+        }
+    }
+
+    fn ticks_for<T: Component>(&self, entity: Entity) -> Option<ComponentTicks> {
+        self.ticks.get(&TypeId::of::<T>())?.get(&entity).copied()
+    }
+
+    pub fn was_added<T: Component>(&self, entity: Entity, since_tick: u32) -> bool {
+        self.ticks_for::<T>(entity)
+            .is_some_and(|t| t.added > since_tick)
+    }
+
+    pub fn was_changed<T: Component>(&self, entity: Entity, since_tick: u32) -> bool {
+        self.ticks_for::<T>(entity)
+            .is_some_and(|t| t.changed > since_tick)
+    }
+}
+
+/// Iterates entities with component `T` whose value was added or mutably
+/// accessed more recently than `since_tick`.
+pub struct ChangedQuery<'w, T: Component> {
+    world: &'w crate::World,
+    inner: crate::system_param::Query<'w, T>,
+    since_tick: u32,
+}
+
+impl<'w, T: Component> ChangedQuery<'w, T> {
+    pub fn new(world: &'w crate::World, since_tick: u32) -> Self {
+        Self {
+            world,
+            inner: crate::system_param::Query::new(world),
+            since_tick,
+        }
+    }
+}
+
+impl<'w, T: Component> Iterator for ChangedQuery<'w, T> {
+    type Item = (Entity, &'w T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (entity, component) in self.inner.by_ref() {
+            if self.world.was_changed::<T>(entity, self.since_tick) {
+                return Some((entity, component));
+            }
+        }
+        None
+    }
+}
+
+/// Iterates entities with component `T` that were added more recently
+/// than `since_tick`.
+pub struct AddedQuery<'w, T: Component> {
+    world: &'w crate::World,
+    inner: crate::system_param::Query<'w, T>,
+    since_tick: u32,
+}
+
+impl<'w, T: Component> AddedQuery<'w, T> {
+    pub fn new(world: &'w crate::World, since_tick: u32) -> Self {
+        Self {
+            world,
+            inner: crate::system_param::Query::new(world),
+            since_tick,
+        }
+    }
+}
+
+impl<'w, T: Component> Iterator for AddedQuery<'w, T> {
+    type Item = (Entity, &'w T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (entity, component) in self.inner.by_ref() {
+            if self.world.was_added::<T>(entity, self.since_tick) {
+                return Some((entity, component));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::World;
+
+    #[test]
+    fn added_query_finds_only_newly_inserted_components() {
+        let mut world = World::new();
+        let a = world.spawn();
+        world.insert(a, 1_i32);
+        let baseline = world.advance_tick();
+
+        let b = world.spawn();
+        world.insert(b, 2_i32);
+
+        let added: Vec<_> = world.added::<i32>(baseline).map(|(e, _)| e).collect();
+        assert_eq!(added, vec![b]);
+    }
+
+    #[test]
+    fn changed_query_finds_only_mutably_accessed_components() {
+        let mut world = World::new();
+        let a = world.spawn();
+        let b = world.spawn();
+        world.insert(a, 1_i32);
+        world.insert(b, 2_i32);
+        let baseline = world.advance_tick();
+
+        *world.get_mut::<i32>(a).unwrap() += 1;
+
+        let changed: Vec<_> = world.changed::<i32>(baseline).map(|(e, _)| e).collect();
+        assert_eq!(changed, vec![a]);
+    }
+
+    #[test]
+    fn each_mut_marks_every_visited_entity_changed() {
+        let mut world = World::new();
+        let a = world.spawn();
+        let b = world.spawn();
+        world.insert(a, 1_i32);
+        world.insert(b, 2_i32);
+        let baseline = world.advance_tick();
+
+        world.each_mut::<i32>(|_e, v| *v += 1);
+
+        let mut changed: Vec<_> = world.changed::<i32>(baseline).map(|(e, _)| e).collect();
+        changed.sort();
+        let mut expected = vec![a, b];
+        expected.sort();
+        assert_eq!(changed, expected);
+    }
+
+    #[test]
+    fn insert_over_an_existing_component_counts_as_a_change_not_a_re_add() {
+        let mut world = World::new();
+        let a = world.spawn();
+        world.insert(a, 1_i32);
+        let baseline = world.advance_tick();
+
+        world.insert(a, 2_i32);
+
+        assert!(world.was_changed::<i32>(a, baseline));
+    }
+
+    #[test]
+    fn queries_are_empty_once_since_tick_catches_up() {
+        let mut world = World::new();
+        let a = world.spawn();
+        world.insert(a, 1_i32);
+        let current = world.current_tick();
+
+        assert_eq!(world.added::<i32>(current).count(), 0);
+    }
+}
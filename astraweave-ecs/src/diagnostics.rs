@@ -0,0 +1,235 @@
+//! Entity, component, and resource diagnostics for long-soak stability testing.
+//!
+//! [`EcsDiagnostics::capture`] snapshots per-archetype entity counts and
+//! component memory usage so a soak run can chart growth over time and flag
+//! leaks before they show up as an OOM. [`OrphanTracker`] generalizes the
+//! same idea to any externally-owned handle (GPU buffers, audio voices,
+//! file descriptors, ...) that a component only references by id: register
+//! a handle alongside a liveness check when it's created, and `sweep`
+//! reports any handle whose owner has since disappeared.
+
+use crate::World;
+use std::collections::VecDeque;
+
+/// Per-archetype snapshot captured by [`EcsDiagnostics::capture`].
+#[derive(Clone, Debug)]
+pub struct ArchetypeStats {
+    pub component_count: usize,
+    pub entity_count: usize,
+    pub estimated_bytes: usize,
+    /// `false` when the archetype has legacy Box-mode columns not covered
+    /// by `estimated_bytes` (see [`crate::archetype::Archetype::memory_stats`]).
+    pub byte_count_exact: bool,
+}
+
+/// A point-in-time snapshot of the whole [`World`]'s entity/component memory.
+#[derive(Clone, Debug, Default)]
+pub struct EcsDiagnostics {
+    pub archetypes: Vec<ArchetypeStats>,
+    pub total_entities: usize,
+    pub total_estimated_bytes: usize,
+}
+
+impl EcsDiagnostics {
+    /// Walks every archetype in `world` and totals up entity counts and
+    /// estimated component memory usage.
+    pub fn capture(world: &World) -> Self {
+        let mut archetypes = Vec::new();
+        let mut total_entities = 0;
+        let mut total_estimated_bytes = 0;
+
+        for archetype in world.archetypes().iter() {
+            let mem = archetype.memory_stats();
+            total_entities += archetype.len();
+            total_estimated_bytes += mem.estimated_bytes;
+            archetypes.push(ArchetypeStats {
+                component_count: archetype.signature.len(),
+                entity_count: archetype.len(),
+                estimated_bytes: mem.estimated_bytes,
+                byte_count_exact: mem.exact,
+            });
+        }
+
+        Self {
+            archetypes,
+            total_entities,
+            total_estimated_bytes,
+        }
+    }
+}
+
+/// Bounded history of [`EcsDiagnostics`] snapshots for charting growth over
+/// a long-running session. Insert as a `World` resource and call
+/// [`DiagnosticsHistory::record`] periodically (e.g. once per second of
+/// simulated time, not every tick).
+pub struct DiagnosticsHistory {
+    capacity: usize,
+    snapshots: VecDeque<(u64, EcsDiagnostics)>,
+}
+
+impl DiagnosticsHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            snapshots: VecDeque::new(),
+        }
+    }
+
+    pub fn record(&mut self, tick: u64, snapshot: EcsDiagnostics) {
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back((tick, snapshot));
+    }
+
+    pub fn latest(&self) -> Option<&EcsDiagnostics> {
+        self.snapshots.back().map(|(_, snapshot)| snapshot)
+    }
+
+    /// Byte growth between the oldest and newest recorded snapshot, or
+    /// `None` if fewer than two snapshots have been recorded yet.
+    pub fn byte_growth(&self) -> Option<i64> {
+        let (_, first) = self.snapshots.front()?;
+        let (_, last) = self.snapshots.back()?;
+        Some(last.total_estimated_bytes as i64 - first.total_estimated_bytes as i64)
+    }
+}
+
+impl Default for DiagnosticsHistory {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+/// Tracks externally-owned handles that outlive any single component and
+/// would otherwise leak silently if their owning entity is removed without
+/// its cleanup code running (e.g. a GPU buffer handle stashed in a render
+/// resource cache, keyed by an entity that got despawned).
+pub struct OrphanTracker<H> {
+    entries: Vec<(H, Box<dyn Fn() -> bool + Send + Sync>)>,
+}
+
+impl<H> OrphanTracker<H> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers `handle`, with `is_owned` returning `true` for as long as
+    /// some still-live owner holds it.
+    pub fn register(&mut self, handle: H, is_owned: impl Fn() -> bool + Send + Sync + 'static) {
+        self.entries.push((handle, Box::new(is_owned)));
+    }
+
+    pub fn tracked_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Removes and returns handles whose `is_owned` check now returns
+    /// `false` — resources that outlived their owner.
+    pub fn sweep(&mut self) -> Vec<H>
+    where
+        H: Clone,
+    {
+        let mut orphaned = Vec::new();
+        self.entries.retain(|(handle, is_owned)| {
+            if is_owned() {
+                true
+            } else {
+                orphaned.push(handle.clone());
+                false
+            }
+        });
+        orphaned
+    }
+}
+
+impl<H> Default for OrphanTracker<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+
+    #[test]
+    fn capture_counts_entities_across_archetypes() {
+        let mut world = World::new();
+        let e1 = world.spawn();
+        world.insert(e1, 1_u32);
+        let e2 = world.spawn();
+        world.insert(e2, 2_u32);
+        let e3 = world.spawn();
+        world.insert(e3, 3_u32);
+        world.insert(e3, "tagged");
+
+        let diagnostics = EcsDiagnostics::capture(&world);
+
+        assert_eq!(diagnostics.total_entities, 3);
+        assert!(diagnostics.archetypes.len() >= 2);
+    }
+
+    #[test]
+    fn capture_on_empty_world_reports_zero() {
+        let world = World::new();
+        let diagnostics = EcsDiagnostics::capture(&world);
+        assert_eq!(diagnostics.total_entities, 0);
+        assert_eq!(diagnostics.total_estimated_bytes, 0);
+    }
+
+    #[test]
+    fn history_evicts_oldest_snapshot_past_capacity() {
+        let mut history = DiagnosticsHistory::new(2);
+        history.record(1, EcsDiagnostics::default());
+        history.record(2, EcsDiagnostics::default());
+        history.record(3, EcsDiagnostics::default());
+
+        assert_eq!(history.snapshots.len(), 2);
+        assert_eq!(history.snapshots.front().unwrap().0, 2);
+    }
+
+    #[test]
+    fn byte_growth_is_none_with_fewer_than_two_snapshots() {
+        let mut history = DiagnosticsHistory::new(4);
+        assert_eq!(history.byte_growth(), None);
+        history.record(1, EcsDiagnostics::default());
+        assert_eq!(history.byte_growth(), None);
+    }
+
+    #[test]
+    fn byte_growth_reflects_change_between_oldest_and_newest() {
+        let mut history = DiagnosticsHistory::new(4);
+        history.record(
+            1,
+            EcsDiagnostics {
+                total_estimated_bytes: 100,
+                ..Default::default()
+            },
+        );
+        history.record(
+            2,
+            EcsDiagnostics {
+                total_estimated_bytes: 250,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(history.byte_growth(), Some(150));
+    }
+
+    #[test]
+    fn orphan_tracker_sweeps_dead_owners_only() {
+        let mut tracker: OrphanTracker<u32> = OrphanTracker::new();
+        tracker.register(1, || true);
+        tracker.register(2, || false);
+
+        let orphaned = tracker.sweep();
+
+        assert_eq!(orphaned, vec![2]);
+        assert_eq!(tracker.tracked_count(), 1);
+    }
+}
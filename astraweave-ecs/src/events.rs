@@ -2,11 +2,21 @@
 //!
 //! Events are crucial for AI perception and reactive behaviors.
 //! This system provides deterministic event ordering and efficient queries.
+//!
+//! Retention generalizes double-buffering: each event carries the frame it
+//! was sent on, and [`Events::update`] drops anything older than
+//! [`Events::keep_frames`] frames ago (2 by default — exactly a double
+//! buffer). [`EventReader`] is a cursor: it remembers the sequence number
+//! of the last event it saw and [`EventReader::read`] only returns events
+//! newer than that, so independent readers (render extraction, network
+//! delta encoding, gameplay logic) can each consume the same stream at
+//! their own pace without stepping on each other.
 
 #[cfg(feature = "profiling")]
 use astraweave_profiling::span;
 
 use std::any::{Any, TypeId};
+use std::cell::Cell;
 use std::collections::{HashMap, VecDeque};
 use std::marker::PhantomData;
 
@@ -15,9 +25,12 @@ pub trait Event: 'static + Send + Sync {}
 
 /// Event storage for a single event type
 struct EventQueue<E: Event> {
-    events: VecDeque<E>,
-    /// Frame when events were added (for cleanup)
+    events: VecDeque<(u64, E)>,
+    /// Frame each event was added on (for cleanup), parallel to `events`.
     frame_added: VecDeque<u64>,
+    /// Monotonically increasing id assigned to the next sent event, used
+    /// by [`EventReader`] cursors to tell "already seen" from "new".
+    next_seq: u64,
 }
 
 impl<E: Event> EventQueue<E> {
@@ -25,21 +38,31 @@ impl<E: Event> EventQueue<E> {
         Self {
             events: VecDeque::new(),
             frame_added: VecDeque::new(),
+            next_seq: 0,
         }
     }
 
     fn send(&mut self, event: E, frame: u64) {
-        self.events.push_back(event);
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.events.push_back((seq, event));
         self.frame_added.push_back(frame);
     }
 
     fn drain(&mut self) -> impl Iterator<Item = E> + '_ {
         self.frame_added.clear();
-        self.events.drain(..)
+        self.events.drain(..).map(|(_, event)| event)
     }
 
     fn iter(&self) -> impl Iterator<Item = &E> {
-        self.events.iter()
+        self.events.iter().map(|(_, event)| event)
+    }
+
+    fn iter_since(&self, since_seq: Option<u64>) -> impl Iterator<Item = (u64, &E)> {
+        self.events
+            .iter()
+            .filter(move |(seq, _)| since_seq.is_none_or(|since| *seq > since))
+            .map(|(seq, event)| (*seq, event))
     }
 
     fn len(&self) -> usize {
@@ -52,10 +75,39 @@ impl<E: Event> EventQueue<E> {
     }
 }
 
+/// Type-erased handle to an `EventQueue<E>`, letting [`Events::update`]
+/// clean up every event type's queue without knowing `E`.
+trait AnyEventQueue: Any + Send + Sync {
+    fn cleanup(&mut self, current_frame: u64, keep_frames: u64);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<E: Event> AnyEventQueue for EventQueue<E> {
+    fn cleanup(&mut self, current_frame: u64, keep_frames: u64) {
+        while let Some(&frame) = self.frame_added.front() {
+            if current_frame.saturating_sub(frame) > keep_frames {
+                self.frame_added.pop_front();
+                self.events.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// Central event registry for all event types
 pub struct Events {
     /// Map from TypeId to type-erased event queue
-    queues: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    queues: HashMap<TypeId, Box<dyn AnyEventQueue>>,
     /// Current simulation frame
     current_frame: u64,
     /// How many frames to keep events before cleanup
@@ -113,16 +165,21 @@ impl Events {
             .entry(TypeId::of::<E>())
             .or_insert_with(|| Box::new(EventQueue::<E>::new()));
 
-        let queue = queue.downcast_mut::<EventQueue<E>>().expect(
-            "EventQueue type mismatch: just inserted correct type, downcast should never fail",
-        );
+        let queue = queue
+            .as_any_mut()
+            .downcast_mut::<EventQueue<E>>()
+            .expect("EventQueue type mismatch: just inserted correct type, downcast should never fail");
         queue.send(event, self.current_frame);
     }
 
-    /// Get event reader for type E
+    /// Get a cursor-tracking reader for type `E`. Its first
+    /// [`EventReader::read`] returns every event currently queued (sent
+    /// before or after this call); every read after that only returns
+    /// events newer than the last one it saw.
     pub fn get_reader<E: Event>(&self) -> EventReader<E> {
         EventReader {
             type_id: TypeId::of::<E>(),
+            cursor: Cell::new(None),
             _marker: PhantomData,
         }
     }
@@ -131,17 +188,31 @@ impl Events {
     pub fn read<E: Event>(&self) -> impl Iterator<Item = &E> {
         self.queues
             .get(&TypeId::of::<E>())
-            .and_then(|q| q.downcast_ref::<EventQueue<E>>())
+            .and_then(|q| q.as_any().downcast_ref::<EventQueue<E>>())
             .map(|q| q.iter())
             .into_iter()
             .flatten()
     }
 
+    /// Reads events of type `E` sent after `since_seq` (or all of them,
+    /// if `None`), alongside each event's sequence number (the "cursor"
+    /// [`EventReader`] advances through). Sequence numbers are per-type
+    /// and monotonically increasing, independent of frame-based
+    /// retention.
+    fn read_since<E: Event>(&self, since_seq: Option<u64>) -> impl Iterator<Item = (u64, &E)> {
+        self.queues
+            .get(&TypeId::of::<E>())
+            .and_then(|q| q.as_any().downcast_ref::<EventQueue<E>>())
+            .map(|q| q.iter_since(since_seq))
+            .into_iter()
+            .flatten()
+    }
+
     /// Drain all events of type E (consumes them)
     pub fn drain<E: Event>(&mut self) -> impl Iterator<Item = E> + '_ {
         self.queues
             .get_mut(&TypeId::of::<E>())
-            .and_then(|q| q.downcast_mut::<EventQueue<E>>())
+            .and_then(|q| q.as_any_mut().downcast_mut::<EventQueue<E>>())
             .map(|q| q.drain())
             .into_iter()
             .flatten()
@@ -150,7 +221,7 @@ impl Events {
     /// Clear all events of type E
     pub fn clear<E: Event>(&mut self) {
         if let Some(queue) = self.queues.get_mut(&TypeId::of::<E>()) {
-            if let Some(q) = queue.downcast_mut::<EventQueue<E>>() {
+            if let Some(q) = queue.as_any_mut().downcast_mut::<EventQueue<E>>() {
                 q.clear();
             }
         }
@@ -160,7 +231,7 @@ impl Events {
     pub fn len<E: Event>(&self) -> usize {
         self.queues
             .get(&TypeId::of::<E>())
-            .and_then(|q| q.downcast_ref::<EventQueue<E>>())
+            .and_then(|q| q.as_any().downcast_ref::<EventQueue<E>>())
             .map(|q| q.len())
             .unwrap_or(0)
     }
@@ -170,18 +241,19 @@ impl Events {
         self.len::<E>() == 0
     }
 
-    /// Advance frame and cleanup old events
+    /// Advance frame and cleanup events older than [`Self::keep_frames`]
+    /// frames ago. Intended to run once per tick as a cleanup system
+    /// (e.g. from [`SystemStage::POST_SIMULATION`](crate::SystemStage)).
     pub fn update(&mut self) {
         #[cfg(feature = "profiling")]
         span!("ECS::Events::update");
 
         self.current_frame += 1;
 
-        // Cleanup old events from all queues
-        for _queue in self.queues.values_mut() {
-            // Type erasure: we need to cast to EventQueue<T> but don't know T
-            // For now, we'll skip automatic cleanup and rely on explicit clear
-            // TODO: Store cleanup function pointer or use trait object
+        let current_frame = self.current_frame;
+        let keep_frames = self.keep_frames;
+        for queue in self.queues.values_mut() {
+            queue.cleanup(current_frame, keep_frames);
         }
     }
 
@@ -204,17 +276,31 @@ impl Default for Events {
 // Note: Events implements Resource via the blanket impl in lib.rs
 // impl Resource for Events {} // Removed - conflicts with blanket impl
 
-/// Event reader - provides a handle to read events of a specific type
+/// A cursor into an [`Events`] stream: remembers the sequence number of
+/// the last event it has returned, so repeated calls to [`Self::read`]
+/// only ever see events sent since the previous call.
 #[allow(dead_code)]
 pub struct EventReader<E: Event> {
     type_id: TypeId,
+    cursor: Cell<Option<u64>>,
     _marker: PhantomData<E>,
 }
 
 impl<E: Event> EventReader<E> {
-    /// Read events from the Events resource
-    pub fn read<'a>(&self, events: &'a Events) -> impl Iterator<Item = &'a E> {
-        events.read::<E>()
+    /// Reads events sent since this reader's last call, advancing its
+    /// cursor to the newest event returned.
+    pub fn read<'a>(&self, events: &'a Events) -> impl Iterator<Item = &'a E> + 'a {
+        let since = self.cursor.get();
+        let mut latest = since;
+        let items: Vec<&'a E> = events
+            .read_since::<E>(since)
+            .map(|(seq, event)| {
+                latest = Some(latest.map_or(seq, |l| l.max(seq)));
+                event
+            })
+            .collect();
+        self.cursor.set(latest);
+        items.into_iter()
     }
 }
 
@@ -668,4 +754,34 @@ mod tests {
             "default keep_frames must be 2"
         );
     }
+
+    #[test]
+    fn test_reader_cursor_does_not_redeliver_already_read_events() {
+        let mut events = Events::new();
+        let reader = events.get_reader::<TestEvent>();
+
+        events.send(TestEvent { value: 1 });
+        assert_eq!(reader.read(&events).count(), 1);
+
+        // No new events sent — the cursor should have advanced past the
+        // one already delivered.
+        assert_eq!(reader.read(&events).count(), 0);
+
+        events.send(TestEvent { value: 2 });
+        let second: Vec<_> = reader.read(&events).collect();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].value, 2);
+    }
+
+    #[test]
+    fn test_update_cleans_up_events_older_than_keep_frames() {
+        let mut events = Events::new().with_keep_frames(1);
+        events.send(TestEvent { value: 1 }); // frame 0
+
+        events.update(); // now frame 1; age 1, within keep_frames(1)
+        assert_eq!(events.len::<TestEvent>(), 1);
+
+        events.update(); // now frame 2; age 2 > keep_frames(1) -> cleaned up
+        assert_eq!(events.len::<TestEvent>(), 0);
+    }
 }
@@ -6,6 +6,7 @@
 //! - **Event system** for AI perception and reactive behaviors
 //! - **System parameters** for ergonomic system signatures
 //! - **Plugin architecture** for modular game systems
+//! - **Diagnostics** for per-archetype memory usage and leak detection during soak testing
 //!
 //! ## Architecture
 //!
@@ -36,10 +37,15 @@ use astraweave_profiling::{plot, span};
 
 pub mod archetype;
 pub mod blob_vec;
+pub mod change_detection; // Changed<T>/Added<T> query filters via per-component tick tracking
 pub mod command_buffer;
 pub mod component_meta;
+pub mod diagnostics;
 pub mod entity_allocator;
 pub mod events;
+pub mod parallel_schedule; // Dependency-graph batching of non-conflicting systems (see module docs re: threading)
+pub mod persistence; // Versioned save/load snapshots of `Persist`-marked entities
+pub mod pool;
 pub mod rng;
 pub mod sparse_set;
 mod system_param;
@@ -71,10 +77,15 @@ use std::any::TypeId;
 use std::collections::HashMap;
 
 use archetype::{ArchetypeSignature, ArchetypeStorage};
+pub use change_detection::{AddedQuery, ChangedQuery};
+use change_detection::ChangeTracker;
 pub use command_buffer::CommandBuffer;
 use component_meta::ComponentMetaRegistry;
 pub use entity_allocator::{Entity, EntityAllocator};
 pub use events::{Event, EventReader, Events};
+pub use parallel_schedule::{Access, ParallelStage};
+pub use persistence::{EntitySnapshot, Persist, SnapshotRegistry, WorldSnapshot};
+pub use pool::{EntityPool, Pooled};
 pub use rng::Rng;
 pub use system_param::{Query, Query2, Query2Mut, SystemParam};
 pub use type_registry::TypeRegistry;
@@ -123,6 +134,8 @@ pub struct World {
     /// Component metadata registry for BlobVec storage
     /// Components registered here use the high-performance BlobVec path
     component_registry: ComponentMetaRegistry,
+    /// Per-component add/mutable-access tick bookkeeping for `Changed`/`Added` queries.
+    change_tracker: ChangeTracker,
 }
 
 impl World {
@@ -221,12 +234,20 @@ impl World {
             return; // Silently ignore stale entities
         }
 
+        let already_present = self.has::<T>(e);
+
         let mut components_to_add = HashMap::new();
         components_to_add.insert(
             TypeId::of::<T>(),
             Box::new(c) as Box<dyn std::any::Any + Send + Sync>,
         );
         self.move_entity_to_new_archetype(e, components_to_add, false);
+
+        if already_present {
+            self.change_tracker.record_changed::<T>(e);
+        } else {
+            self.change_tracker.record_added::<T>(e);
+        }
     }
 
     #[allow(clippy::expect_used)] // INVARIANT: archetype/entity existence validated by prior operations in each step
@@ -357,7 +378,9 @@ impl World {
 
         let archetype_id = self.archetypes.get_entity_archetype(e)?;
         let archetype = self.archetypes.get_archetype_mut(archetype_id)?;
-        archetype.get_mut::<T>(e)
+        let component = archetype.get_mut::<T>(e)?;
+        self.change_tracker.record_changed::<T>(e);
+        Some(component)
     }
 
     /// Inserts a singleton resource into the world.
@@ -436,6 +459,7 @@ impl World {
             for entity in entities {
                 if let Some(component) = archetype.get_mut::<T>(entity) {
                     f(entity, component);
+                    self.change_tracker.record_changed::<T>(entity);
                 }
             }
         }
@@ -456,6 +480,56 @@ impl World {
         self.get::<T>(entity).is_some()
     }
 
+    /// Current logical tick used by `Changed`/`Added` queries. Advanced
+    /// by [`Self::advance_tick`].
+    pub fn current_tick(&self) -> u32 {
+        self.change_tracker.current_tick()
+    }
+
+    /// Advances the World's logical tick by one and returns the tick
+    /// that was current before the advance, so anything inserted or
+    /// mutably accessed from then on is newer than the returned value.
+    /// Call once per frame/step, before running systems that read
+    /// [`Self::changed`]/[`Self::added`] state.
+    pub fn advance_tick(&mut self) -> u32 {
+        self.change_tracker.advance_tick()
+    }
+
+    /// Whether `entity`'s `T` was inserted more recently than `since_tick`.
+    pub fn was_added<T: Component>(&self, entity: Entity, since_tick: u32) -> bool {
+        self.change_tracker.was_added::<T>(entity, since_tick)
+    }
+
+    /// Whether `entity`'s `T` was inserted or mutably accessed more
+    /// recently than `since_tick`.
+    pub fn was_changed<T: Component>(&self, entity: Entity, since_tick: u32) -> bool {
+        self.change_tracker.was_changed::<T>(entity, since_tick)
+    }
+
+    /// Iterates entities whose `T` was inserted more recently than
+    /// `since_tick`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use astraweave_ecs::World;
+    ///
+    /// let mut world = World::new();
+    /// let baseline = world.advance_tick();
+    /// let e = world.spawn();
+    /// world.insert(e, 7_i32);
+    /// assert_eq!(world.added::<i32>(baseline).count(), 1);
+    /// ```
+    pub fn added<T: Component>(&self, since_tick: u32) -> AddedQuery<'_, T> {
+        AddedQuery::new(self, since_tick)
+    }
+
+    /// Iterates entities whose `T` was inserted or mutably accessed more
+    /// recently than `since_tick`.
+    pub fn changed<T: Component>(&self, since_tick: u32) -> ChangedQuery<'_, T> {
+        ChangedQuery::new(self, since_tick)
+    }
+
     pub fn entities_with<T: Component>(&self) -> Vec<Entity> {
         self.archetypes
             .archetypes_with_component(TypeId::of::<T>())
@@ -517,6 +591,7 @@ impl World {
             archetype.remove_entity_components(entity);
             self.archetypes.remove_entity(entity);
         }
+        self.change_tracker.clear_entity(&entity);
 
         // Despawn from allocator (increments generation)
         self.entity_allocator.despawn(entity)
@@ -642,6 +717,7 @@ impl App {
     }
     pub fn run_fixed(mut self, steps: u32) -> Self {
         for _ in 0..steps {
+            self.world.advance_tick();
             self.schedule.run(&mut self.world);
         }
         self
@@ -0,0 +1,242 @@
+//! Dependency-graph scheduling for parallel system execution.
+//!
+//! [`Schedule`](crate::Schedule) runs every stage's systems strictly
+//! serially. That's simple and deterministic, but wastes cores once a
+//! stage accumulates many systems that don't actually touch the same
+//! components (e.g. a cooldown-tick system and an AI-perception system
+//! writing disjoint component types). [`ParallelStage`] takes each
+//! system's declared [`Access`] (component reads/writes — inference from
+//! system bodies isn't possible with this ECS's plain `fn(&mut World)`
+//! system signature, so callers declare it) and builds batches of
+//! mutually non-conflicting systems.
+//!
+//! Batches themselves always run in declaration order, and a system is
+//! placed into the earliest batch it doesn't conflict with — a
+//! deterministic function of the declared systems and their access sets
+//! alone, so replaying the same systems in the same order always yields
+//! the same batching and the same relative execution order within a
+//! batch.
+//!
+//! # Why batches don't actually run on separate threads
+//!
+//! A system only declares which component *types* it touches, not which
+//! *entities* or archetypes. Two systems with disjoint `Access` (say,
+//! one writing `Counter` and one writing `Health`) can still both end up
+//! calling into [`World`]'s storage for the *same* archetype whenever an
+//! entity has both components — `Archetype::get_mut` and
+//! `ChangeTracker`'s per-tick bookkeeping both require an exclusive `&mut`
+//! borrow of state that isn't partitioned per component type. Declared
+//! `Access` disjointness is therefore not sufficient to prove two systems
+//! can safely hold `&mut World` at once, so batches are an execution
+//! *order* (and a home for future work, once storage can be split per
+//! archetype column) rather than a real thread-level fan-out today.
+//! `batch_count()` is still useful for scheduling diagnostics and tests.
+
+use std::any::TypeId;
+use std::collections::HashSet;
+
+use crate::{SystemFn, World};
+
+/// The set of component types a system reads and writes, used to detect
+/// conflicts between systems. Two systems conflict (and can't run in the
+/// same parallel batch) if either writes a component type the other
+/// reads or writes.
+#[derive(Debug, Clone, Default)]
+pub struct Access {
+    reads: HashSet<TypeId>,
+    writes: HashSet<TypeId>,
+}
+
+impl Access {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reads<T: 'static>(mut self) -> Self {
+        self.reads.insert(TypeId::of::<T>());
+        self
+    }
+
+    pub fn writes<T: 'static>(mut self) -> Self {
+        self.writes.insert(TypeId::of::<T>());
+        self
+    }
+
+    fn conflicts_with(&self, other: &Access) -> bool {
+        self.writes
+            .iter()
+            .any(|t| other.reads.contains(t) || other.writes.contains(t))
+            || other.writes.iter().any(|t| self.reads.contains(t))
+    }
+}
+
+struct ScheduledSystem {
+    #[allow(dead_code)] // surfaced for future diagnostics/tracing, not read yet
+    name: &'static str,
+    system: SystemFn,
+    access: Access,
+}
+
+/// A schedule stage whose systems are grouped into dependency-ordered
+/// batches by declared component access, ahead of running them.
+#[derive(Default)]
+pub struct ParallelStage {
+    name: &'static str,
+    systems: Vec<ScheduledSystem>,
+}
+
+impl ParallelStage {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            systems: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Registers `system` with its declared component `access`. Systems
+    /// are considered for batching in the order they're added.
+    pub fn add_system(&mut self, name: &'static str, access: Access, system: SystemFn) {
+        self.systems.push(ScheduledSystem {
+            name,
+            system,
+            access,
+        });
+    }
+
+    /// Greedily groups systems into ordered batches: within a batch, no
+    /// two systems' declared [`Access`] conflict. Each system is placed
+    /// into the earliest batch it doesn't conflict with any member of.
+    fn build_batches(&self) -> Vec<Vec<usize>> {
+        let mut batches: Vec<Vec<usize>> = Vec::new();
+
+        for (idx, candidate) in self.systems.iter().enumerate() {
+            let target = batches.iter_mut().find(|batch| {
+                !batch
+                    .iter()
+                    .any(|&other| candidate.access.conflicts_with(&self.systems[other].access))
+            });
+
+            match target {
+                Some(batch) => batch.push(idx),
+                None => batches.push(vec![idx]),
+            }
+        }
+
+        batches
+    }
+
+    /// Runs every batch in declaration order. Every system runs inline
+    /// against `world` — see the module doc comment for why batches
+    /// aren't dispatched across threads: declared [`Access`] disjointness
+    /// doesn't prove two systems can safely hold `&mut World`
+    /// concurrently, since archetype storage and [`crate::change_detection::ChangeTracker`]
+    /// aren't partitioned per component type.
+    pub fn run(&self, world: &mut World) {
+        for batch in self.build_batches() {
+            for idx in batch {
+                (self.systems[idx].system)(world);
+            }
+        }
+    }
+
+    /// Number of batches the current systems would be grouped into.
+    /// Exposed for tests and scheduling diagnostics.
+    pub fn batch_count(&self) -> usize {
+        self.build_batches().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct Counter(u32);
+    struct Health(u32);
+
+    fn inc_counter(world: &mut World) {
+        world.each_mut::<Counter>(|_e, c| c.0 += 1);
+    }
+
+    fn inc_health(world: &mut World) {
+        world.each_mut::<Health>(|_e, h| h.0 += 1);
+    }
+
+    #[test]
+    fn disjoint_access_batches_together() {
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system("inc_counter", Access::new().writes::<Counter>(), inc_counter);
+        stage.add_system("inc_health", Access::new().writes::<Health>(), inc_health);
+
+        assert_eq!(stage.batch_count(), 1);
+    }
+
+    #[test]
+    fn conflicting_writes_are_placed_in_separate_batches() {
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system("inc_counter_a", Access::new().writes::<Counter>(), inc_counter);
+        stage.add_system("inc_counter_b", Access::new().writes::<Counter>(), inc_counter);
+
+        assert_eq!(stage.batch_count(), 2);
+    }
+
+    #[test]
+    fn read_after_write_conflicts_but_read_after_read_does_not() {
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system(
+            "read_a",
+            Access::new().reads::<Counter>(),
+            inc_health, // body is irrelevant; only Access matters for batching
+        );
+        stage.add_system("read_b", Access::new().reads::<Counter>(), inc_health);
+        assert_eq!(stage.batch_count(), 1);
+
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system("read", Access::new().reads::<Counter>(), inc_health);
+        stage.add_system("write", Access::new().writes::<Counter>(), inc_counter);
+        assert_eq!(stage.batch_count(), 2);
+    }
+
+    #[test]
+    fn run_applies_every_system_exactly_once() {
+        let mut world = World::new();
+        let e = world.spawn();
+        world.insert(e, Counter(0));
+        world.insert(e, Health(0));
+
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system("inc_counter", Access::new().writes::<Counter>(), inc_counter);
+        stage.add_system("inc_health", Access::new().writes::<Health>(), inc_health);
+
+        stage.run(&mut world);
+
+        assert_eq!(world.get::<Counter>(e).unwrap().0, 1);
+        assert_eq!(world.get::<Health>(e).unwrap().0, 1);
+    }
+
+    #[test]
+    fn conflicting_systems_still_both_run_when_serialized_into_separate_batches() {
+        static RUNS: AtomicU32 = AtomicU32::new(0);
+        fn bump(world: &mut World) {
+            world.each_mut::<Counter>(|_e, c| c.0 += 1);
+            RUNS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let mut world = World::new();
+        let e = world.spawn();
+        world.insert(e, Counter(0));
+
+        let mut stage = ParallelStage::new("simulation");
+        stage.add_system("bump_a", Access::new().writes::<Counter>(), bump);
+        stage.add_system("bump_b", Access::new().writes::<Counter>(), bump);
+
+        stage.run(&mut world);
+
+        assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+        assert_eq!(world.get::<Counter>(e).unwrap().0, 2);
+    }
+}
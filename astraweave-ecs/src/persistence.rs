@@ -0,0 +1,331 @@
+//! World save/load persistence.
+//!
+//! Persistence is opt-in per entity via the [`Persist`] marker component, and
+//! opt-in per component type via [`SnapshotRegistry::register`] — mirroring
+//! [`crate::type_registry::TypeRegistry`]'s per-type closure pattern, since a
+//! `World` has no way to serialize a component without knowing its concrete
+//! type. Only entities carrying `Persist` are captured, and only their
+//! registered components are saved; everything else (caches, transient
+//! render/physics handles, AI scratch state) is left out of the snapshot and
+//! expected to be rebuilt when the world is restored — e.g. a physics system
+//! that already recreates bodies from `CColliderDesc`-style descriptors on
+//! spawn will recreate them for restored entities the same way, with no
+//! extra work here.
+//!
+//! Snapshots are versioned so a save file written by an older build can still
+//! be loaded: register a migration with [`SnapshotRegistry::add_migration`]
+//! for each past format change, and [`SnapshotRegistry::restore`] applies any
+//! migrations needed to bring the snapshot up to
+//! [`SnapshotRegistry::CURRENT_VERSION`] before restoring it.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Component, Entity, World};
+
+/// Marker component: entities carrying `Persist` are included in
+/// [`SnapshotRegistry::capture`]. Attach it to anything that should survive a
+/// save/load cycle (player, world objects); leave it off transient or
+/// purely-derived entities (particle effects, UI overlays).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Persist;
+
+/// One persisted entity: its original raw id (used only to remap
+/// relationships within the same snapshot, e.g. a saved parent reference —
+/// it is never assumed to still be valid in the world being restored into)
+/// plus its captured components, keyed by the name each was registered
+/// under.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EntitySnapshot {
+    pub raw_id: u64,
+    pub components: HashMap<String, Value>,
+}
+
+/// A captured, serializable snapshot of every [`Persist`]-marked entity in a
+/// `World`, tagged with the format version it was written under.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    pub version: u32,
+    pub entities: Vec<EntitySnapshot>,
+}
+
+impl WorldSnapshot {
+    /// Serializes this snapshot to pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parses a snapshot from JSON produced by [`WorldSnapshot::to_json`].
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+}
+
+type CaptureFn = Box<dyn Fn(&World, Entity) -> Option<Value> + Send + Sync>;
+type RestoreFn = Box<dyn Fn(&mut World, Entity, Value) + Send + Sync>;
+type MigrationFn = Box<dyn Fn(&mut WorldSnapshot) + Send + Sync>;
+
+/// Registry of per-component-type capture/restore closures, and of
+/// migrations between snapshot format versions. Register every persisted
+/// component type once at startup, then use [`SnapshotRegistry::capture`]
+/// and [`SnapshotRegistry::restore`] to save/load worlds.
+pub struct SnapshotRegistry {
+    capture_fns: Vec<(String, CaptureFn)>,
+    restore_fns: HashMap<String, RestoreFn>,
+    migrations: HashMap<u32, MigrationFn>,
+}
+
+impl SnapshotRegistry {
+    /// The snapshot format version produced by [`SnapshotRegistry::capture`].
+    /// Bump this and add a matching [`SnapshotRegistry::add_migration`] entry
+    /// whenever a persisted component's serialized shape changes.
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            capture_fns: Vec::new(),
+            restore_fns: HashMap::new(),
+            migrations: HashMap::new(),
+        }
+    }
+
+    /// Registers `T` as a persisted component type under `name`. `name` is
+    /// the key used in [`EntitySnapshot::components`], so it must be stable
+    /// across builds (renaming it is itself a breaking format change that
+    /// needs a migration).
+    pub fn register<T>(&mut self, name: impl Into<String>)
+    where
+        T: Component + Serialize + for<'de> Deserialize<'de>,
+    {
+        let name = name.into();
+
+        self.capture_fns.push((
+            name.clone(),
+            Box::new(|world: &World, entity: Entity| {
+                world
+                    .get::<T>(entity)
+                    .and_then(|c| serde_json::to_value(c).ok())
+            }),
+        ));
+
+        self.restore_fns.insert(
+            name,
+            Box::new(|world: &mut World, entity: Entity, value: Value| {
+                if let Ok(component) = serde_json::from_value::<T>(value) {
+                    world.insert(entity, component);
+                }
+            }),
+        );
+    }
+
+    /// Registers a migration applied to snapshots whose `version` is
+    /// `from_version`, bringing them one step closer to
+    /// [`SnapshotRegistry::CURRENT_VERSION`]. Migrations run in ascending
+    /// order of `from_version` and each is expected to bump
+    /// `snapshot.version` by exactly one.
+    pub fn add_migration(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(&mut WorldSnapshot) + Send + Sync + 'static,
+    ) {
+        self.migrations.insert(from_version, Box::new(migrate));
+    }
+
+    /// Captures every `Persist`-marked entity's registered components into a
+    /// [`WorldSnapshot`] at [`SnapshotRegistry::CURRENT_VERSION`].
+    pub fn capture(&self, world: &World) -> WorldSnapshot {
+        let entities = world
+            .entities_with::<Persist>()
+            .into_iter()
+            .map(|entity| {
+                let mut components = HashMap::new();
+                for (name, capture) in &self.capture_fns {
+                    if let Some(value) = capture(world, entity) {
+                        components.insert(name.clone(), value);
+                    }
+                }
+                EntitySnapshot {
+                    raw_id: entity.to_raw(),
+                    components,
+                }
+            })
+            .collect();
+
+        WorldSnapshot {
+            version: Self::CURRENT_VERSION,
+            entities,
+        }
+    }
+
+    /// Migrates `snapshot` up to [`SnapshotRegistry::CURRENT_VERSION`] in
+    /// place, applying each registered migration in turn.
+    pub fn migrate(&self, snapshot: &mut WorldSnapshot) {
+        while snapshot.version < Self::CURRENT_VERSION {
+            match self.migrations.get(&snapshot.version) {
+                Some(migrate) => migrate(snapshot),
+                None => break,
+            }
+        }
+    }
+
+    /// Restores `snapshot` into `world`, spawning one fresh entity per saved
+    /// entity (saved raw ids are not reused — a restored world gets new
+    /// `Entity` handles) and inserting each registered component found in
+    /// the snapshot. `snapshot` is migrated to
+    /// [`SnapshotRegistry::CURRENT_VERSION`] first if needed. Returns a map
+    /// from each entity's saved raw id to its new handle in `world`, so
+    /// callers can remap relationships (e.g. a persisted parent reference)
+    /// after every entity has been spawned.
+    pub fn restore(&self, world: &mut World, snapshot: &mut WorldSnapshot) -> HashMap<u64, Entity> {
+        self.migrate(snapshot);
+
+        let mut remap = HashMap::with_capacity(snapshot.entities.len());
+        for saved in &snapshot.entities {
+            let entity = world.spawn();
+            for (name, value) in &saved.components {
+                if let Some(restore) = self.restore_fns.get(name) {
+                    restore(world, entity, value.clone());
+                }
+            }
+            remap.insert(saved.raw_id, entity);
+        }
+        remap
+    }
+}
+
+impl Default for SnapshotRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Name(String);
+
+    fn registry() -> SnapshotRegistry {
+        let mut registry = SnapshotRegistry::new();
+        registry.register::<Position>("Position");
+        registry.register::<Name>("Name");
+        registry
+    }
+
+    #[test]
+    fn capture_only_includes_persist_marked_entities() {
+        let mut world = World::new();
+        let saved = world.spawn();
+        world.insert(saved, Persist);
+        world.insert(saved, Position { x: 1.0, y: 2.0 });
+
+        let transient = world.spawn();
+        world.insert(transient, Position { x: 9.0, y: 9.0 });
+
+        let snapshot = registry().capture(&world);
+        assert_eq!(snapshot.entities.len(), 1);
+        assert_eq!(snapshot.entities[0].raw_id, saved.to_raw());
+    }
+
+    #[test]
+    fn capture_only_includes_registered_component_types() {
+        struct Unregistered(u32);
+        let mut world = World::new();
+        let e = world.spawn();
+        world.insert(e, Persist);
+        world.insert(e, Position { x: 1.0, y: 2.0 });
+        world.insert(e, Unregistered(42));
+
+        let snapshot = registry().capture(&world);
+        let components = &snapshot.entities[0].components;
+        assert!(components.contains_key("Position"));
+        assert!(!components.contains_key("Unregistered"));
+    }
+
+    #[test]
+    fn restore_recreates_components_on_fresh_entities() {
+        let mut world = World::new();
+        let original = world.spawn();
+        world.insert(original, Persist);
+        world.insert(original, Position { x: 3.0, y: 4.0 });
+        world.insert(original, Name("Hero".to_string()));
+
+        let registry = registry();
+        let snapshot = registry.capture(&world);
+
+        let mut restored_world = World::new();
+        let mut snapshot = snapshot;
+        let remap = registry.restore(&mut restored_world, &mut snapshot);
+
+        let restored_entity = remap[&original.to_raw()];
+        assert_eq!(
+            restored_world.get::<Position>(restored_entity),
+            Some(&Position { x: 3.0, y: 4.0 })
+        );
+        assert_eq!(
+            restored_world.get::<Name>(restored_entity),
+            Some(&Name("Hero".to_string()))
+        );
+    }
+
+    #[test]
+    fn json_round_trip_preserves_snapshot() {
+        let mut world = World::new();
+        let e = world.spawn();
+        world.insert(e, Persist);
+        world.insert(e, Position { x: 5.0, y: 6.0 });
+
+        let registry = registry();
+        let snapshot = registry.capture(&world);
+        let json = snapshot.to_json().expect("serialize");
+        let mut parsed = WorldSnapshot::from_json(&json).expect("deserialize");
+
+        let mut restored_world = World::new();
+        let remap = registry.restore(&mut restored_world, &mut parsed);
+        let restored_entity = remap[&e.to_raw()];
+        assert_eq!(
+            restored_world.get::<Position>(restored_entity),
+            Some(&Position { x: 5.0, y: 6.0 })
+        );
+    }
+
+    #[test]
+    fn migration_runs_before_restore_for_older_versions() {
+        let mut registry = registry();
+        registry.add_migration(0, |snapshot| {
+            for entity in &mut snapshot.entities {
+                entity
+                    .components
+                    .entry("Name".to_string())
+                    .or_insert_with(|| serde_json::to_value(Name("Unnamed".to_string())).unwrap());
+            }
+            snapshot.version = 1;
+        });
+
+        let mut old_snapshot = WorldSnapshot {
+            version: 0,
+            entities: vec![EntitySnapshot {
+                raw_id: 0,
+                components: HashMap::new(),
+            }],
+        };
+
+        let mut world = World::new();
+        let remap = registry.restore(&mut world, &mut old_snapshot);
+        let entity = remap[&0];
+        assert_eq!(
+            world.get::<Name>(entity),
+            Some(&Name("Unnamed".to_string()))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Object pooling for frequently spawned entities.
+//!
+//! Repeatedly spawning and despawning short-lived entities (projectiles,
+//! particles, debris, one-shot audio sources) churns the entity allocator's
+//! free list and moves entities between archetypes on every component
+//! add/remove. [`EntityPool`] avoids both: entities are prewarmed once and
+//! then recycled via [`EntityPool::acquire`]/[`EntityPool::release`], with
+//! their component set staying fixed so no archetype move ever happens.
+//!
+//! # Example
+//! ```
+//! # use astraweave_ecs::{World, EntityPool};
+//! # #[derive(Clone, Copy, Debug, PartialEq)]
+//! # struct Position { x: f32, y: f32 }
+//! # #[derive(Clone, Copy, Debug, PartialEq)]
+//! # struct Velocity { x: f32, y: f32 }
+//! let mut world = World::new();
+//! let mut pool = EntityPool::new();
+//! pool.add_reset_hook(Position { x: 0.0, y: 0.0 });
+//! pool.add_reset_hook(Velocity { x: 0.0, y: 0.0 });
+//! pool.prewarm(&mut world, 32);
+//!
+//! let projectile = pool.acquire(&mut world);
+//! world.insert(projectile, Velocity { x: 10.0, y: 0.0 });
+//!
+//! // ...projectile hits something...
+//! pool.release(&mut world, projectile);
+//! assert_eq!(world.get::<Velocity>(projectile), Some(&Velocity { x: 0.0, y: 0.0 }));
+//! ```
+
+use crate::{Component, Entity, World};
+
+/// Marker component present on every entity ever owned by an [`EntityPool`],
+/// whether currently checked out or sitting idle. Useful for distinguishing
+/// pooled entities from user-managed ones in debug tooling or queries.
+#[derive(Clone, Copy, Debug)]
+pub struct Pooled;
+
+/// A pool of entities recycled via [`Self::acquire`]/[`Self::release`]
+/// instead of [`World::spawn`]/[`World::despawn`].
+///
+/// Reset hooks registered with [`Self::add_reset_hook`] run whenever an
+/// entity is handed out or returned, so callers always see a fresh
+/// at-rest state without paying for a despawn/respawn cycle.
+pub struct EntityPool {
+    available: Vec<Entity>,
+    in_use: Vec<Entity>,
+    reset_hooks: Vec<Box<dyn Fn(&mut World, Entity) + Send + Sync>>,
+}
+
+impl EntityPool {
+    pub fn new() -> Self {
+        Self {
+            available: Vec::new(),
+            in_use: Vec::new(),
+            reset_hooks: Vec::new(),
+        }
+    }
+
+    /// Registers a hook that resets one component to `value` every time an
+    /// entity is acquired or released. Call once per component type the
+    /// pool's entities carry.
+    pub fn add_reset_hook<T: Component + Clone>(&mut self, value: T) -> &mut Self {
+        self.reset_hooks
+            .push(Box::new(move |world, entity| world.insert(entity, value.clone())));
+        self
+    }
+
+    /// Spawns `count` entities up front, tagged [`Pooled`] and run through
+    /// every registered reset hook, ready for [`Self::acquire`].
+    pub fn prewarm(&mut self, world: &mut World, count: usize) {
+        self.available.reserve(count);
+        for _ in 0..count {
+            let entity = world.spawn();
+            world.insert(entity, Pooled);
+            self.apply_reset_hooks(world, entity);
+            self.available.push(entity);
+        }
+    }
+
+    /// Hands out an idle pooled entity, or spawns a new one if the pool is
+    /// empty. Reset hooks are re-applied before the entity is returned, so
+    /// a freshly spawned entity is indistinguishable from a recycled one.
+    pub fn acquire(&mut self, world: &mut World) -> Entity {
+        let entity = self.available.pop().unwrap_or_else(|| {
+            let entity = world.spawn();
+            world.insert(entity, Pooled);
+            entity
+        });
+        self.apply_reset_hooks(world, entity);
+        self.in_use.push(entity);
+        entity
+    }
+
+    /// Returns `entity` to the pool and resets it via every registered
+    /// hook. No-op (beyond the reset) if `entity` was not currently
+    /// checked out of this pool.
+    pub fn release(&mut self, world: &mut World, entity: Entity) {
+        let was_in_use = if let Some(pos) = self.in_use.iter().position(|&e| e == entity) {
+            self.in_use.swap_remove(pos);
+            true
+        } else {
+            false
+        };
+        self.apply_reset_hooks(world, entity);
+        if was_in_use {
+            self.available.push(entity);
+        }
+    }
+
+    fn apply_reset_hooks(&self, world: &mut World, entity: Entity) {
+        for hook in &self.reset_hooks {
+            hook(world, entity);
+        }
+    }
+
+    /// Number of idle entities ready to be handed out.
+    pub fn available_count(&self) -> usize {
+        self.available.len()
+    }
+
+    /// Number of entities currently checked out.
+    pub fn in_use_count(&self) -> usize {
+        self.in_use.len()
+    }
+
+    /// Total entities this pool has ever spawned (idle + in use).
+    pub fn capacity(&self) -> usize {
+        self.available.len() + self.in_use.len()
+    }
+}
+
+impl Default for EntityPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Health(i32);
+
+    #[test]
+    fn prewarm_populates_available_pool() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        pool.prewarm(&mut world, 5);
+        assert_eq!(pool.available_count(), 5);
+        assert_eq!(pool.in_use_count(), 0);
+        assert_eq!(pool.capacity(), 5);
+    }
+
+    #[test]
+    fn acquire_reuses_prewarmed_entities_before_spawning() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        pool.prewarm(&mut world, 1);
+        let before_count = world.entity_count();
+
+        let e = pool.acquire(&mut world);
+        assert_eq!(world.entity_count(), before_count, "should reuse, not spawn");
+        assert_eq!(pool.available_count(), 0);
+        assert_eq!(pool.in_use_count(), 1);
+        assert!(world.has::<Pooled>(e));
+    }
+
+    #[test]
+    fn acquire_spawns_new_entity_when_pool_is_empty() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        let before_count = world.entity_count();
+
+        let e = pool.acquire(&mut world);
+        assert_eq!(world.entity_count(), before_count + 1);
+        assert!(world.has::<Pooled>(e));
+        assert_eq!(pool.in_use_count(), 1);
+    }
+
+    #[test]
+    fn release_resets_components_via_hooks() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        pool.add_reset_hook(Health(100));
+        pool.prewarm(&mut world, 1);
+
+        let e = pool.acquire(&mut world);
+        world.insert(e, Health(1));
+        assert_eq!(world.get::<Health>(e), Some(&Health(1)));
+
+        pool.release(&mut world, e);
+        assert_eq!(world.get::<Health>(e), Some(&Health(100)));
+        assert_eq!(pool.available_count(), 1);
+        assert_eq!(pool.in_use_count(), 0);
+    }
+
+    #[test]
+    fn acquire_applies_reset_hooks_to_freshly_spawned_entities() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        pool.add_reset_hook(Health(50));
+
+        let e = pool.acquire(&mut world);
+        assert_eq!(world.get::<Health>(e), Some(&Health(50)));
+    }
+
+    #[test]
+    fn acquire_release_cycle_does_not_grow_pool_capacity() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new();
+        pool.prewarm(&mut world, 3);
+
+        for _ in 0..10 {
+            let e = pool.acquire(&mut world);
+            pool.release(&mut world, e);
+        }
+
+        assert_eq!(pool.capacity(), 3, "recycling must not spawn new entities");
+    }
+}
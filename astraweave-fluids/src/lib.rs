@@ -54,6 +54,8 @@ pub mod anisotropic;
 pub mod boundary;
 pub mod building;
 pub mod caustics;
+#[cfg(feature = "cpu_sim")]
+pub mod cpu_ref;
 pub mod debug_viz;
 pub mod editor;
 pub mod emitter;
@@ -66,6 +68,7 @@ pub mod optimization;
 pub mod particle_shifting;
 pub mod pcisph_system;
 pub mod profiling;
+pub mod query;
 pub mod renderer;
 pub mod research;
 pub mod sdf;
@@ -166,6 +169,7 @@ pub use optimization::{
     WorkgroupConfig,
 };
 pub use profiling::{FluidProfiler, FluidTimingStats};
+pub use query::FluidQuery;
 pub use renderer::FluidRenderer;
 pub use serialization::{FluidSnapshot, SnapshotParams};
 pub use simd_ops::{
@@ -218,6 +222,19 @@ pub struct Particle {
     pub color: [f32; 4],
 }
 
+/// Material parameters for one fluid phase (see [`Particle::phase`]), looked
+/// up per-particle by [`FluidSystem::set_phase_params`]'s uniform table so
+/// e.g. oil and water can coexist with different density/viscosity in the
+/// same simulation.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PhaseParams {
+    pub target_density: f32,
+    pub viscosity: f32,
+    pub surface_tension: f32,
+    pub _pad0: f32,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct DynamicObject {
@@ -242,9 +259,49 @@ pub struct SimParams {
     pub grid_depth: u32,
     pub cell_size: f32,
     pub object_count: u32,
+    /// World-space AABB the grid maps into and `integrate`'s boundary clamp
+    /// enforces, set via [`FluidSystem::set_domain`].
+    pub domain_min_x: f32,
+    pub domain_min_y: f32,
+    pub domain_min_z: f32,
+    pub domain_max_x: f32,
+    pub domain_max_y: f32,
+    pub domain_max_z: f32,
     pub _pad0: f32,
-    pub _pad1: f32,
-    pub _pad2: f32,
+}
+
+/// Handle to a registered [`FluidSystem::add_emitter`], usable with
+/// [`FluidSystem::remove_emitter`].
+pub type EmitterHandle = usize;
+/// Handle to a registered [`FluidSystem::add_drain`], usable with
+/// [`FluidSystem::remove_drain`].
+pub type DrainHandle = usize;
+
+/// Capacity of `objects_buffer` / `object_forces_buffer`, matching the fixed
+/// size the collision shader iterates over (`SimParams::object_count` must
+/// stay within this).
+const MAX_FLUID_OBJECTS: u64 = 128;
+
+/// Must match `FORCE_FIXED_POINT_SCALE` in fluid_optimized.wgsl.
+const FORCE_FIXED_POINT_SCALE: f32 = 65536.0;
+
+/// Default world-space simulation domain: an origin-centered box a fluid
+/// system covers until [`FluidSystem::set_domain`] repositions it, e.g. to
+/// follow the player through an open world.
+const DEFAULT_DOMAIN_MIN: [f32; 3] = [-29.5, 0.0, -29.5];
+const DEFAULT_DOMAIN_MAX: [f32; 3] = [29.5, 59.5, 29.5];
+
+/// Point-in-time measurement of the fluid solver's constraint convergence,
+/// returned by [`FluidSystem::solver_stats`]. Derived from the same
+/// non-blocking async density-error readback that drives
+/// [`AdaptiveIterations`](crate::optimization::AdaptiveIterations), so it is
+/// ~2 frames stale rather than reflecting the just-submitted step.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FluidSolverStats {
+    /// Smoothed average density error (0.0 = perfect incompressibility).
+    pub density_error: f32,
+    /// Constraint solver iterations used for the most recent step.
+    pub iterations: u32,
 }
 
 pub struct FluidSystem {
@@ -280,6 +337,12 @@ pub struct FluidSystem {
     mix_dye_pipeline: wgpu::ComputePipeline,
     emit_whitewater_pipeline: wgpu::ComputePipeline,
     update_whitewater_pipeline: wgpu::ComputePipeline,
+    sort_scatter_cells_pipeline: wgpu::ComputePipeline,
+    sort_scatter_strays_pipeline: wgpu::ComputePipeline,
+    sort_cursor_buffer: wgpu::Buffer,
+
+    phase_params_buffer: wgpu::Buffer,
+    interface_tension_buffer: wgpu::Buffer,
 
     params_buffer: wgpu::Buffer,
     pub particle_count: u32,
@@ -300,6 +363,12 @@ pub struct FluidSystem {
     pub grid_height: u32,
     pub grid_depth: u32,
 
+    // World-space AABB the grid maps into and `integrate`'s boundary clamp
+    // enforces. Set via `set_domain` rather than directly, since changing it
+    // also despawns particles that fall outside the new bounds.
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+
     pub sdf_system: crate::sdf::SdfSystem,
     pub objects_buffer: wgpu::Buffer,
     pub default_sampler: wgpu::Sampler,
@@ -309,6 +378,20 @@ pub struct FluidSystem {
     density_error_staging_buffers: [wgpu::Buffer; 2],
     staging_mapped: [bool; 2],
 
+    // Two-way fluid/rigid-body coupling: per-object reaction force buffer,
+    // bound at scene binding 3, cleared every step and read back
+    // asynchronously (same double-buffered staging pattern as the density
+    // error above). Only `fluid_optimized.wgsl`'s `compute_delta_pos`
+    // writes to it; the `fluid.wgsl` kernels `step()` dispatches do not,
+    // so with this pipeline it reads back as zero.
+    object_forces_buffer: wgpu::Buffer,
+    object_forces_staging_buffers: [wgpu::Buffer; 2],
+    object_forces_staging_mapped: [bool; 2],
+    /// Last decoded per-object forces, indexed like the `objects` slice
+    /// passed to [`Self::update_objects`]. One step (~2 frames) stale due to
+    /// the async readback.
+    object_forces: Vec<[f32; 3]>,
+
     // Dynamic Particle Management
     particle_flags: wgpu::Buffer, // 0=inactive, 1=active for each particle
     pub active_count: u32,        // Currently active particles
@@ -323,6 +406,14 @@ pub struct FluidSystem {
     /// Flags indicating which particles are active (CPU-side mirror of particle_flags)
     particle_active: Vec<bool>,
 
+    // ==================== EMITTERS / DRAINS ====================
+    /// Registered emitters, ticked automatically each `step()`. `None` slots
+    /// are freed handles kept so existing `EmitterHandle`s stay valid.
+    emitters: Vec<Option<crate::emitter::FluidEmitter>>,
+    /// Registered drains, checked against every active particle every
+    /// `step()`. `None` slots are freed handles.
+    drains: Vec<Option<crate::emitter::FluidDrain>>,
+
     // ==================== OPTIMIZATION COMPONENTS ====================
     /// Workgroup configuration for GPU dispatch (vendor-aware)
     pub workgroup_config: WorkgroupConfig,
@@ -436,9 +527,13 @@ impl FluidSystem {
             grid_height,
             grid_depth,
             object_count: 0,
+            domain_min_x: DEFAULT_DOMAIN_MIN[0],
+            domain_min_y: DEFAULT_DOMAIN_MIN[1],
+            domain_min_z: DEFAULT_DOMAIN_MIN[2],
+            domain_max_x: DEFAULT_DOMAIN_MAX[0],
+            domain_max_y: DEFAULT_DOMAIN_MAX[1],
+            domain_max_z: DEFAULT_DOMAIN_MAX[2],
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
 
         let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -508,6 +603,39 @@ impl FluidSystem {
                     },
                     count: None,
                 },
+                // 5: Sort Cursor
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // 6: Phase Params
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // 7: Interface Tension
+                wgpu::BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -586,6 +714,17 @@ impl FluidSystem {
                     ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
                     count: None,
                 },
+                // 3: Object reaction forces (two-way coupling)
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -641,6 +780,68 @@ impl FluidSystem {
             mapped_at_creation: false,
         });
 
+        let sort_cursor_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sort Cursor Buffer"),
+            size: 4,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Per-phase material table. Phase 0 (water) matches the SimParams
+        // defaults above exactly, so simulations that never touch phases
+        // behave identically to before this table existed. Phases 1 (oil)
+        // and 2 (lava) ship with illustrative presets; the rest start
+        // identical to water until customized via `set_phase_params`.
+        let mut phase_table = [PhaseParams {
+            target_density: 12.0,
+            viscosity: 10.0,
+            surface_tension: 0.02,
+            _pad0: 0.0,
+        }; crate::multi_phase::MAX_PHASES];
+        phase_table[1] = PhaseParams {
+            target_density: 9.0,
+            viscosity: 25.0,
+            surface_tension: 0.03,
+            _pad0: 0.0,
+        };
+        phase_table[2] = PhaseParams {
+            target_density: 18.0,
+            viscosity: 80.0,
+            surface_tension: 0.05,
+            _pad0: 0.0,
+        };
+
+        let phase_params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Fluid Phase Params Buffer"),
+            contents: bytemuck::cast_slice(&phase_table),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Interface tension per phase pair, flattened row-major. Diagonal
+        // entries (same-phase cohesion) mirror each phase's own
+        // surface_tension; off-diagonal entries default to the water/water
+        // value except for the built-in oil/lava presets, which get a
+        // higher (less miscible) tension against water and each other.
+        let mut interface_tension_table =
+            [0.02f32; crate::multi_phase::MAX_PHASES * crate::multi_phase::MAX_PHASES];
+        for (i, phase) in phase_table.iter().enumerate() {
+            interface_tension_table[i * crate::multi_phase::MAX_PHASES + i] = phase.surface_tension;
+        }
+        let set_pair = |table: &mut [f32], a: usize, b: usize, v: f32| {
+            table[a * crate::multi_phase::MAX_PHASES + b] = v;
+            table[b * crate::multi_phase::MAX_PHASES + a] = v;
+        };
+        set_pair(&mut interface_tension_table, 0, 1, 0.06); // water/oil
+        set_pair(&mut interface_tension_table, 0, 2, 0.08); // water/lava
+        set_pair(&mut interface_tension_table, 1, 2, 0.07); // oil/lava
+
+        let interface_tension_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Fluid Interface Tension Buffer"),
+                contents: bytemuck::cast_slice(&interface_tension_table),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            });
+
         let density_error_staging_buffers = [
             device.create_buffer(&wgpu::BufferDescriptor {
                 label: Some("Density Error Staging Buffer 0"),
@@ -666,11 +867,37 @@ impl FluidSystem {
 
         let objects_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Dynamic Objects Buffer"),
-            size: (128 * std::mem::size_of::<DynamicObject>()) as u64,
+            size: MAX_FLUID_OBJECTS * std::mem::size_of::<DynamicObject>() as u64,
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
+        // Two-way coupling: 4 x i32 (fx, fy, fz, pad) per object, fixed-point
+        // encoded (see FORCE_FIXED_POINT_SCALE) since WGSL has no float atomics.
+        let object_forces_buffer_size = MAX_FLUID_OBJECTS * 4 * std::mem::size_of::<i32>() as u64;
+        let object_forces_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Fluid Object Forces Buffer"),
+            size: object_forces_buffer_size,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let object_forces_staging_buffers = [
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Fluid Object Forces Staging Buffer 0"),
+                size: object_forces_buffer_size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Fluid Object Forces Staging Buffer 1"),
+                size: object_forces_buffer_size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        ];
+
         // --- Pre-allocate Bind Groups ---
         let global_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Fluid Global BG"),
@@ -696,6 +923,18 @@ impl FluidSystem {
                     binding: 4,
                     resource: density_error_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: sort_cursor_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: phase_params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 7,
+                    resource: interface_tension_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -760,6 +999,8 @@ impl FluidSystem {
             mix_dye_pipeline,
             emit_whitewater_pipeline,
             update_whitewater_pipeline,
+            sort_scatter_cells_pipeline,
+            sort_scatter_strays_pipeline,
         ) = {
             let create_p = |label, entry_point| {
                 device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
@@ -781,6 +1022,8 @@ impl FluidSystem {
                 create_p("Mix Dye", "mix_dye"),
                 create_p("Emit Whitewater", "emit_whitewater"),
                 create_p("Update Whitewater", "update_whitewater"),
+                create_p("Sort Scatter Cells", "sort_scatter_cells"),
+                create_p("Sort Scatter Strays", "sort_scatter_strays"),
             )
         };
 
@@ -817,6 +1060,8 @@ impl FluidSystem {
             grid_width,
             grid_height,
             grid_depth,
+            domain_min: DEFAULT_DOMAIN_MIN,
+            domain_max: DEFAULT_DOMAIN_MAX,
             sdf_system,
             objects_buffer,
             default_sampler,
@@ -825,9 +1070,18 @@ impl FluidSystem {
             density_error_buffer,
             density_error_staging_buffers,
             staging_mapped: [false; 2],
+            object_forces_buffer,
+            object_forces_staging_buffers,
+            object_forces_staging_mapped: [false; 2],
+            object_forces: vec![[0.0; 3]; MAX_FLUID_OBJECTS as usize],
             mix_dye_pipeline,
             emit_whitewater_pipeline,
             update_whitewater_pipeline,
+            sort_scatter_cells_pipeline,
+            sort_scatter_strays_pipeline,
+            sort_cursor_buffer,
+            phase_params_buffer,
+            interface_tension_buffer,
             particle_flags,
             active_count: particle_count,
             max_particles: particle_count,
@@ -836,6 +1090,9 @@ impl FluidSystem {
             particle_positions: initial_positions,
             particle_active: vec![true; particle_count as usize],
 
+            emitters: Vec::new(),
+            drains: Vec::new(),
+
             // Initialize optimization components with sensible defaults
             workgroup_config: WorkgroupConfig::universal(),
             adaptive_iterations: AdaptiveIterations::new(2, 8),
@@ -876,12 +1133,26 @@ impl FluidSystem {
 
     /// Spawn new particles at runtime. Returns the number of particles actually spawned.
     /// Particles are spawned from the free list if available, or fails if at capacity.
+    /// Spawns with phase 0 (water); see [`Self::spawn_particles_with_phase`] for other phases.
     pub fn spawn_particles(
         &mut self,
         queue: &wgpu::Queue,
         positions: &[[f32; 3]],
         velocities: &[[f32; 3]],
         colors: Option<&[[f32; 4]]>,
+    ) -> usize {
+        self.spawn_particles_with_phase(queue, positions, velocities, colors, 0)
+    }
+
+    /// Same as [`Self::spawn_particles`], but spawns particles of the given
+    /// `phase` (see [`Particle::phase`], [`Self::set_phase_params`]).
+    pub fn spawn_particles_with_phase(
+        &mut self,
+        queue: &wgpu::Queue,
+        positions: &[[f32; 3]],
+        velocities: &[[f32; 3]],
+        colors: Option<&[[f32; 4]]>,
+        phase: u32,
     ) -> usize {
         let count = positions.len().min(velocities.len());
         let spawned = count.min(self.free_list.len());
@@ -898,7 +1169,7 @@ impl FluidSystem {
                 predicted_position: [pos[0], pos[1], pos[2], 1.0],
                 lambda: 0.0,
                 density: 0.0,
-                phase: 0,
+                phase,
                 temperature: 293.0,
                 color,
             };
@@ -922,6 +1193,94 @@ impl FluidSystem {
         spawned
     }
 
+    /// Sets the material parameters for a fluid phase (`0..MAX_PHASES`, see
+    /// [`Particle::phase`]). Built-in defaults: phase 0 = water, 1 = oil,
+    /// 2 = lava; the rest start identical to water until customized. Takes
+    /// effect on the next `step()`.
+    pub fn set_phase_params(&mut self, queue: &wgpu::Queue, phase: u32, params: PhaseParams) {
+        if phase as usize >= crate::multi_phase::MAX_PHASES {
+            return;
+        }
+        let offset = phase as u64 * std::mem::size_of::<PhaseParams>() as u64;
+        queue.write_buffer(&self.phase_params_buffer, offset, bytemuck::bytes_of(&params));
+    }
+
+    /// Sets the interface tension between two phases, controlling how
+    /// strongly their particles resist mixing at the interface (higher =
+    /// less miscible). Symmetric: also updates `(phase_b, phase_a)`.
+    pub fn set_interface_tension(
+        &mut self,
+        queue: &wgpu::Queue,
+        phase_a: u32,
+        phase_b: u32,
+        tension: f32,
+    ) {
+        let max_phases = crate::multi_phase::MAX_PHASES as u32;
+        if phase_a >= max_phases || phase_b >= max_phases {
+            return;
+        }
+        let mut write = |idx: u32, value: f32| {
+            let offset = idx as u64 * std::mem::size_of::<f32>() as u64;
+            queue.write_buffer(&self.interface_tension_buffer, offset, bytemuck::bytes_of(&value));
+        };
+        write(phase_a * max_phases + phase_b, tension);
+        write(phase_b * max_phases + phase_a, tension);
+    }
+
+    /// Returns the current world-space simulation domain as `(min, max)`,
+    /// as last set by [`Self::set_domain`] (or the default centered box the
+    /// system was created with).
+    pub fn domain(&self) -> ([f32; 3], [f32; 3]) {
+        (self.domain_min, self.domain_max)
+    }
+
+    /// Recenters/resizes the fluid simulation's world-space domain, e.g. to
+    /// follow the player through an open world. Grid resolution
+    /// (`grid_width`/`grid_height`/`grid_depth`) and `cell_size` are
+    /// unchanged; `min`/`max` become the new AABB the grid maps into and the
+    /// hard boundary clamp in `integrate` enforces, taking effect on the
+    /// next `step()`.
+    ///
+    /// Particles left outside the new AABB are despawned immediately and
+    /// recycled onto the free list, the same as [`Self::despawn_region`].
+    /// Returns the number of particles despawned this way.
+    pub fn set_domain(&mut self, queue: &wgpu::Queue, min: [f32; 3], max: [f32; 3]) -> usize {
+        // Validate AABB (ensure min <= max for each axis)
+        let valid_min = [min[0].min(max[0]), min[1].min(max[1]), min[2].min(max[2])];
+        let valid_max = [min[0].max(max[0]), min[1].max(max[1]), min[2].max(max[2])];
+        self.domain_min = valid_min;
+        self.domain_max = valid_max;
+
+        let mut despawned = 0;
+        for idx in 0..self.particle_positions.len() {
+            if !self.particle_active[idx] {
+                continue;
+            }
+
+            let pos = self.particle_positions[idx];
+            let inside = pos[0] >= valid_min[0]
+                && pos[0] <= valid_max[0]
+                && pos[1] >= valid_min[1]
+                && pos[1] <= valid_max[1]
+                && pos[2] >= valid_min[2]
+                && pos[2] <= valid_max[2];
+
+            if !inside {
+                let flag_offset = (idx * 4) as u64;
+                queue.write_buffer(&self.particle_flags, flag_offset, bytemuck::bytes_of(&0u32));
+                self.particle_active[idx] = false;
+                self.free_list.push(idx as u32);
+                despawned += 1;
+            }
+        }
+
+        if despawned > 0 {
+            self.active_count = self.active_count.saturating_sub(despawned as u32);
+        }
+
+        despawned
+    }
+
     /// Despawn all particles within the given axis-aligned bounding box.
     ///
     /// This queues the region for processing in the next `step()` call.
@@ -958,6 +1317,94 @@ impl FluidSystem {
         self.pending_despawn_regions.len()
     }
 
+    /// Register an emitter that will be ticked automatically at the start of
+    /// every `step()`, spawning particles from the free list without a CPU
+    /// round-trip through gameplay code. Returns a handle for
+    /// `remove_emitter`.
+    pub fn add_emitter(&mut self, emitter: crate::emitter::FluidEmitter) -> EmitterHandle {
+        self.emitters.push(Some(emitter));
+        self.emitters.len() - 1
+    }
+
+    /// Stop and remove a previously registered emitter.
+    pub fn remove_emitter(&mut self, handle: EmitterHandle) {
+        if let Some(slot) = self.emitters.get_mut(handle) {
+            *slot = None;
+        }
+    }
+
+    /// Register a drain: a sphere that despawns any particle inside it every
+    /// `step()`, without a CPU round-trip through gameplay code (unlike
+    /// `despawn_region`, which is a one-shot AABB queued for a single
+    /// step). Returns a handle for `remove_drain`.
+    pub fn add_drain(&mut self, drain: crate::emitter::FluidDrain) -> DrainHandle {
+        self.drains.push(Some(drain));
+        self.drains.len() - 1
+    }
+
+    /// Remove a previously registered drain.
+    pub fn remove_drain(&mut self, handle: DrainHandle) {
+        if let Some(slot) = self.drains.get_mut(handle) {
+            *slot = None;
+        }
+    }
+
+    /// Tick every registered emitter and spawn the particles it produced.
+    /// Returns the total number of particles spawned.
+    fn process_emitters(&mut self, queue: &wgpu::Queue, dt: f32) -> usize {
+        let mut spawned = 0;
+        for slot in 0..self.emitters.len() {
+            let spawn_data = match &mut self.emitters[slot] {
+                Some(emitter) => emitter.tick(dt),
+                None => continue,
+            };
+            let (positions, velocities, colors) = spawn_data;
+            if positions.is_empty() {
+                continue;
+            }
+            spawned += self.spawn_particles(queue, &positions, &velocities, Some(&colors));
+        }
+        spawned
+    }
+
+    /// Despawn every active particle that has entered a registered drain's
+    /// sphere this step, recycling it back onto the free list. Returns the
+    /// number of particles despawned.
+    fn process_drains(&mut self, queue: &wgpu::Queue) -> usize {
+        if self.drains.is_empty() {
+            return 0;
+        }
+
+        let mut despawned = 0;
+        for idx in 0..self.particle_positions.len() {
+            if !self.particle_active[idx] {
+                continue;
+            }
+            let pos = self.particle_positions[idx];
+            let inside = self.drains.iter().flatten().any(|drain| {
+                drain.enabled && {
+                    let dx = pos[0] - drain.position[0];
+                    let dy = pos[1] - drain.position[1];
+                    let dz = pos[2] - drain.position[2];
+                    (dx * dx + dy * dy + dz * dz).sqrt() <= drain.radius
+                }
+            });
+
+            if inside {
+                let flag_offset = (idx * 4) as u64;
+                queue.write_buffer(&self.particle_flags, flag_offset, bytemuck::bytes_of(&0u32));
+                self.particle_active[idx] = false;
+                self.free_list.push(idx as u32);
+                despawned += 1;
+            }
+        }
+
+        if despawned > 0 {
+            self.active_count = self.active_count.saturating_sub(despawned as u32);
+        }
+        despawned
+    }
+
     /// Clears all pending despawn regions without processing them.
     pub fn clear_pending_despawns(&mut self) {
         self.pending_despawn_regions.clear();
@@ -1021,7 +1468,9 @@ impl FluidSystem {
         queue: &wgpu::Queue,
         dt: f32,
     ) {
-        // Process any pending despawn regions first
+        // Tick registered emitters/drains, then process any pending despawn regions
+        let _spawned = self.process_emitters(queue, dt);
+        let _drained = self.process_drains(queue);
         let _despawned = self.process_pending_despawns(queue);
 
         // Update Uniforms
@@ -1039,9 +1488,13 @@ impl FluidSystem {
             grid_depth: self.grid_depth,
             cell_size: self.cell_size,
             object_count: 0, // Placeholder, can be set by update_objects
+            domain_min_x: self.domain_min[0],
+            domain_min_y: self.domain_min[1],
+            domain_min_z: self.domain_min[2],
+            domain_max_x: self.domain_max[0],
+            domain_max_y: self.domain_max[1],
+            domain_max_z: self.domain_max[2],
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
         queue.write_buffer(&self.params_buffer, 0, bytemuck::bytes_of(&params));
 
@@ -1058,7 +1511,7 @@ impl FluidSystem {
 
         // Group 3: Scene Data (Objects + SDF)
         // We create this per frame because the SDF texture view might change
-        let sdf_view = self.sdf_system.texture_a.create_view(&Default::default());
+        let sdf_view = self.sdf_system.result_texture().create_view(&Default::default());
         let scene_bg = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Fluid Scene BG"),
             layout: &self.scene_layout,
@@ -1075,12 +1528,18 @@ impl FluidSystem {
                     binding: 2,
                     resource: wgpu::BindingResource::Sampler(&self.default_sampler),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: self.object_forces_buffer.as_entire_binding(),
+                },
             ],
         });
 
-        // 0. Reset density error and counters
+        // 0. Reset density error, object reaction forces, and counters
         encoder.clear_buffer(&self.density_error_buffer, 0, None);
+        encoder.clear_buffer(&self.object_forces_buffer, 0, None);
         encoder.clear_buffer(&self.secondary_counter, 0, None);
+        encoder.clear_buffer(&self.sort_cursor_buffer, 0, None);
 
         // --- Execute Compute Pipeline ---
 
@@ -1164,6 +1623,30 @@ impl FluidSystem {
             cpass.dispatch_workgroups(particle_workgroups, 1, 1);
         }
 
+        // 4b. Sort particles by grid cell into the other ping-pong buffer, so
+        // next frame's build_grid/lambda/delta_pos neighbor walks get
+        // cache-coherent access. `particles_dst` (this frame's `particles_bg`
+        // binding 1) becomes `particles` (binding 0) once `current_src` flips
+        // next frame.
+        {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Fluid::SortScatter"),
+                ..Default::default()
+            });
+            cpass.set_bind_group(0, global_bg, &[]);
+            cpass.set_bind_group(1, particles_bg, &[]);
+
+            cpass.set_pipeline(&self.sort_scatter_cells_pipeline);
+            cpass.dispatch_workgroups(
+                (self.grid_width * self.grid_height * self.grid_depth).div_ceil(64),
+                1,
+                1,
+            );
+
+            cpass.set_pipeline(&self.sort_scatter_strays_pipeline);
+            cpass.dispatch_workgroups(particle_workgroups, 1, 1);
+        }
+
         // 5. Dye Mixing & Whitewater
         {
             let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
@@ -1204,6 +1687,23 @@ impl FluidSystem {
             4,
         );
 
+        // 7. Copy accumulated object reaction forces to staging (asynchronously,
+        // same double-buffered pattern as the density error above)
+        if self.object_forces_staging_mapped[staging_idx] {
+            self.object_forces_staging_buffers[staging_idx].unmap();
+            self.object_forces_staging_mapped[staging_idx] = false;
+        }
+
+        let object_forces_buffer_size =
+            MAX_FLUID_OBJECTS * 4 * std::mem::size_of::<i32>() as u64;
+        encoder.copy_buffer_to_buffer(
+            &self.object_forces_buffer,
+            0,
+            &self.object_forces_staging_buffers[staging_idx],
+            0,
+            object_forces_buffer_size,
+        );
+
         self.frame_index += 1;
 
         // --- Adaptive Iteration Adjust (Non-Blocking) ---
@@ -1231,18 +1731,166 @@ impl FluidSystem {
         current_slice.map_async(wgpu::MapMode::Read, |_| {});
         self.staging_mapped[staging_idx] = true;
 
+        // --- Object Reaction Forces Readback (Non-Blocking) ---
+        if self.object_forces_staging_mapped[other_idx] {
+            let buffer_slice = self.object_forces_staging_buffers[other_idx].slice(..);
+            {
+                let data = buffer_slice.get_mapped_range();
+                let raw: &[i32] = bytemuck::cast_slice(&data);
+                for (object_index, force) in self.object_forces.iter_mut().enumerate() {
+                    let base = object_index * 4;
+                    *force = [
+                        raw[base] as f32 / FORCE_FIXED_POINT_SCALE,
+                        raw[base + 1] as f32 / FORCE_FIXED_POINT_SCALE,
+                        raw[base + 2] as f32 / FORCE_FIXED_POINT_SCALE,
+                    ];
+                }
+            }
+            self.object_forces_staging_buffers[other_idx].unmap();
+            self.object_forces_staging_mapped[other_idx] = false;
+        }
+
+        let current_object_forces_slice =
+            self.object_forces_staging_buffers[staging_idx].slice(..);
+        current_object_forces_slice.map_async(wgpu::MapMode::Read, |_| {});
+        self.object_forces_staging_mapped[staging_idx] = true;
+
         // Poll to progress the mapping, but don't wait.
         let _ = device.poll(wgpu::MaintainBase::Poll);
     }
 
+    /// Last decoded per-object reaction forces from the coupling buffer,
+    /// indexed like the `objects` slice passed to [`Self::update_objects`].
+    /// One step (~2 frames) stale due to the async GPU readback; call after
+    /// [`Self::step`] each frame. The `fluid.wgsl` kernels that
+    /// [`Self::step`] runs never write reaction forces, so every entry is
+    /// currently zero.
+    pub fn sample_forces(&self) -> &[[f32; 3]] {
+        &self.object_forces
+    }
+
+    /// Latest fluid solver convergence stats, derived from the same
+    /// non-blocking async GPU readback in [`Self::step`] that drives
+    /// [`AdaptiveIterations`]. ~2 frames stale;
+    /// call after [`Self::step`] each frame instead of stalling on
+    /// `device.poll(Maintain::Wait)`.
+    pub fn solver_stats(&self) -> FluidSolverStats {
+        FluidSolverStats {
+            density_error: self.adaptive_iterations.smoothed_error(),
+            iterations: self.iterations,
+        }
+    }
+
+    /// Reads back the current particle buffer into a compact, serializable
+    /// [`FluidSnapshot`] for save-game persistence. Unlike [`Self::sample_forces`]
+    /// / [`Self::solver_stats`], this blocks on `device.poll(Maintain::Wait)`
+    /// to guarantee a complete, consistent snapshot — acceptable for the
+    /// once-per-save cadence this is meant for, not for per-frame use.
+    /// [`FluidSnapshot::to_bytes`] already produces a compact bincode
+    /// encoding; pipe the result through your own compressor if smaller
+    /// save files are needed.
+    pub fn snapshot(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> FluidSnapshot {
+        let particle_count = self.particle_count as usize;
+        let buffer_size = (particle_count * std::mem::size_of::<Particle>()) as u64;
+
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("FluidSystem Snapshot Staging"),
+            size: buffer_size.max(4),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("FluidSystem Snapshot Copy"),
+        });
+        if buffer_size > 0 {
+            encoder.copy_buffer_to_buffer(self.get_particle_buffer(), 0, &staging, 0, buffer_size);
+        }
+        queue.submit(Some(encoder.finish()));
+
+        let mut snapshot = FluidSnapshot::with_capacity(particle_count);
+        if buffer_size > 0 {
+            let slice = staging.slice(..);
+            slice.map_async(wgpu::MapMode::Read, |_| {});
+            let _ = device.poll(wgpu::MaintainBase::Wait);
+
+            let data = slice.get_mapped_range();
+            let particles: &[Particle] = bytemuck::cast_slice(&data);
+            for particle in particles {
+                snapshot.positions.push(particle.position);
+                snapshot.velocities.push(particle.velocity);
+                snapshot.colors.push(particle.color);
+            }
+            drop(data);
+            staging.unmap();
+        }
+
+        snapshot.params = SnapshotParams {
+            smoothing_radius: self.smoothing_radius,
+            target_density: self.target_density,
+            pressure_multiplier: self.pressure_multiplier,
+            viscosity: self.viscosity,
+            surface_tension: self.surface_tension,
+            gravity: self.gravity,
+            iterations: self.iterations,
+            cell_size: self.cell_size,
+            grid_width: self.grid_width,
+            grid_height: self.grid_height,
+            grid_depth: self.grid_depth,
+        };
+        snapshot.frame_index = self.frame_index;
+        snapshot.active_count = self.active_count;
+        snapshot
+    }
+
+    /// Restores simulation parameters and particle state from a
+    /// [`FluidSnapshot`] taken by [`Self::snapshot`]. Particles are
+    /// re-uploaded via [`Self::reset_particles`]; per-particle solver
+    /// scratch state (predicted position, lambda) is not part of the
+    /// snapshot and is simply reset, since it's fully reconstructed by the
+    /// next [`Self::step`] anyway.
+    pub fn restore(&mut self, queue: &wgpu::Queue, snapshot: &FluidSnapshot) {
+        self.smoothing_radius = snapshot.params.smoothing_radius;
+        self.target_density = snapshot.params.target_density;
+        self.pressure_multiplier = snapshot.params.pressure_multiplier;
+        self.viscosity = snapshot.params.viscosity;
+        self.surface_tension = snapshot.params.surface_tension;
+        self.gravity = snapshot.params.gravity;
+        self.iterations = snapshot.params.iterations;
+        self.cell_size = snapshot.params.cell_size;
+        self.grid_width = snapshot.params.grid_width;
+        self.grid_height = snapshot.params.grid_height;
+        self.grid_depth = snapshot.params.grid_depth;
+        self.frame_index = snapshot.frame_index;
+        self.active_count = snapshot.active_count;
+        self.particle_count = snapshot.positions.len() as u32;
+
+        let particles: Vec<Particle> = snapshot
+            .positions
+            .iter()
+            .zip(&snapshot.velocities)
+            .zip(&snapshot.colors)
+            .map(|((position, velocity), color)| Particle {
+                position: *position,
+                velocity: *velocity,
+                predicted_position: *position,
+                lambda: 0.0,
+                density: 0.0,
+                phase: 0,
+                temperature: 293.0,
+                color: *color,
+            })
+            .collect();
+
+        self.reset_particles(queue, &particles);
+    }
+
     pub fn get_particle_buffer(&self) -> &wgpu::Buffer {
-        // The result is always in the "Dst" of the last pass (Integrate).
-        // Integrate used `bg_density` where Dst = `particle_buffers[1 - current_src]`.
-        // Since we incremented frame_index at end, we need to look back.
-        // Frame 0 (start 0): Integ writes to 1. Incr to 1.
-        // Frame 1 (start 1): Integ writes to 0. Incr to 2.
-        // So if frame_index is Odd, result is in 1.
-        // If frame_index is Even, result is in 0.
+        // `step`'s sort pass scatters the frame's final particle state into
+        // `particles_dst` (`particle_buffers[1 - current_src]`), which becomes
+        // `current_src` once `frame_index` flips parity at the end of `step`.
+        // So the buffer holding the most recently completed frame's results
+        // is always `particle_buffers[frame_index % 2]`.
         &self.particle_buffers[self.frame_index % 2]
     }
 
@@ -1298,6 +1946,22 @@ impl FluidSystem {
         self.iterations
     }
 
+    /// Headless CPU reference step: advances `particles` by one frame using
+    /// [`crate::cpu_ref`]'s port of `fluid.wgsl`'s PBD kernels, without
+    /// requiring a `wgpu::Device`. For CI conformance tests only -- brute
+    /// force neighbor search and no SDF/dynamic-object collision, see
+    /// [`crate::cpu_ref`] docs. Gated behind the `cpu_sim` feature.
+    #[cfg(feature = "cpu_sim")]
+    pub fn step_cpu(
+        particles: &mut [Particle],
+        params: &SimParams,
+        phase_params: &[PhaseParams; crate::multi_phase::MAX_PHASES],
+        interface_tension: &[f32;
+             crate::multi_phase::MAX_PHASES * crate::multi_phase::MAX_PHASES],
+    ) {
+        crate::cpu_ref::step_cpu(particles, params, phase_params, interface_tension);
+    }
+
     /// Get current optimization statistics.
     pub fn get_optimization_stats(&self) -> &OptimizationStats {
         &self.optimization_stats
@@ -2463,16 +3127,20 @@ mod tests {
             grid_depth: 10,
             cell_size: 1.0,
             object_count: 0,
+            domain_min_x: -30.0,
+            domain_min_y: 0.0,
+            domain_min_z: -30.0,
+            domain_max_x: 30.0,
+            domain_max_y: 60.0,
+            domain_max_z: 30.0,
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
     }
 
     #[test]
     fn test_sim_params_size() {
-        // SimParams should be 64 bytes (16 * 4 bytes)
-        assert_eq!(std::mem::size_of::<SimParams>(), 64);
+        // SimParams should be 80 bytes (20 * 4 bytes)
+        assert_eq!(std::mem::size_of::<SimParams>(), 80);
     }
 
     #[test]
@@ -2491,9 +3159,13 @@ mod tests {
             grid_depth: 16,
             cell_size: 1.0,
             object_count: 0,
+            domain_min_x: -30.0,
+            domain_min_y: 0.0,
+            domain_min_z: -30.0,
+            domain_max_x: 30.0,
+            domain_max_y: 60.0,
+            domain_max_z: 30.0,
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
         assert_eq!(params.grid_width, 64);
     }
@@ -2550,9 +3222,13 @@ mod tests {
             grid_depth: 128,
             cell_size: 1.2,
             object_count: 0,
+            domain_min_x: -30.0,
+            domain_min_y: 0.0,
+            domain_min_z: -30.0,
+            domain_max_x: 30.0,
+            domain_max_y: 60.0,
+            domain_max_z: 30.0,
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
 
         let bytes: &[u8] = bytemuck::bytes_of(&params);
@@ -2662,6 +3338,28 @@ mod tests {
         assert_eq!(sphere.transform[3][0], 5.0); // X position
     }
 
+    // ================== PhaseParams Tests ==================
+
+    #[test]
+    fn test_phase_params_size() {
+        // PhaseParams should be 16 bytes (4 * 4 bytes)
+        assert_eq!(std::mem::size_of::<PhaseParams>(), 16);
+    }
+
+    #[test]
+    fn test_phase_params_values() {
+        let oil = PhaseParams {
+            target_density: 9.0,
+            viscosity: 25.0,
+            surface_tension: 0.03,
+            _pad0: 0.0,
+        };
+
+        assert_eq!(oil.target_density, 9.0);
+        assert_eq!(oil.viscosity, 25.0);
+        assert_eq!(oil.surface_tension, 0.03);
+    }
+
     #[test]
     fn test_dynamic_object_bytemuck_cast() {
         let obj = DynamicObject {
@@ -2893,9 +3591,13 @@ mod tests {
             grid_depth: 10,
             cell_size: 1.0,
             object_count: 0,
+            domain_min_x: -30.0,
+            domain_min_y: 0.0,
+            domain_min_z: -30.0,
+            domain_max_x: 30.0,
+            domain_max_y: 60.0,
+            domain_max_z: 30.0,
             _pad0: 0.0,
-            _pad1: 0.0,
-            _pad2: 0.0,
         };
 
         // dt for 60 FPS should be approximately 0.0167
@@ -2951,6 +3653,37 @@ mod tests {
         assert_eq!(valid_max, [5.0, 5.0, 5.0]);
     }
 
+    // ================== Emitter/Drain Handle Tests ==================
+
+    #[test]
+    fn test_point_in_drain_sphere() {
+        let drain = crate::emitter::FluidDrain::new([0.0, 0.0, 0.0], 2.0);
+        let inside = [1.0, 1.0, 0.0];
+        let outside = [5.0, 0.0, 0.0];
+
+        let dist = |p: [f32; 3]| {
+            (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt()
+        };
+        assert!(dist(inside) <= drain.radius);
+        assert!(dist(outside) > drain.radius);
+    }
+
+    #[test]
+    fn test_drain_handle_slot_reused_after_removal() {
+        // Mirrors FluidSystem::add_drain/remove_drain's Vec<Option<T>> slot
+        // reuse without needing a real wgpu::Device.
+        let mut drains: Vec<Option<crate::emitter::FluidDrain>> = Vec::new();
+        let handle = {
+            drains.push(Some(crate::emitter::FluidDrain::new([0.0; 3], 1.0)));
+            drains.len() - 1
+        };
+        assert!(drains[handle].is_some());
+
+        drains[handle] = None;
+        assert!(drains[handle].is_none());
+        assert_eq!(drains.iter().flatten().count(), 0);
+    }
+
     #[test]
     fn test_particle_position_cache_initial() {
         // Test that initial positions are correctly cached
@@ -3823,4 +4556,52 @@ mod optimization_controller_tests {
         let status = controller.status();
         assert!(!status.within_budget);
     }
+
+    #[test]
+    fn test_object_forces_fixed_point_round_trip() {
+        // Mirrors the encode (WGSL atomicAdd) / decode (sample_forces) pair:
+        // a force is scaled by FORCE_FIXED_POINT_SCALE, truncated to i32, then
+        // divided back on readback.
+        let force = [1.5_f32, -2.25, 0.0];
+        let encoded: Vec<i32> = force
+            .iter()
+            .map(|f| (*f * FORCE_FIXED_POINT_SCALE) as i32)
+            .collect();
+        let decoded: Vec<f32> = encoded
+            .iter()
+            .map(|i| *i as f32 / FORCE_FIXED_POINT_SCALE)
+            .collect();
+
+        for (original, round_tripped) in force.iter().zip(decoded.iter()) {
+            assert!((original - round_tripped).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_sample_forces_defaults_to_zero() {
+        let object_forces = vec![[0.0_f32; 3]; MAX_FLUID_OBJECTS as usize];
+        assert_eq!(object_forces.len(), MAX_FLUID_OBJECTS as usize);
+        assert!(object_forces.iter().all(|f| *f == [0.0; 3]));
+    }
+
+    #[test]
+    fn test_fluid_solver_stats_reflects_adaptive_iterations() {
+        let mut adaptive = crate::optimization::AdaptiveIterations::new(2, 8);
+        adaptive.update(0.2); // well above the increase threshold
+
+        let stats = FluidSolverStats {
+            density_error: adaptive.smoothed_error(),
+            iterations: adaptive.current(),
+        };
+
+        assert!(stats.density_error > 0.0);
+        assert_eq!(stats.iterations, adaptive.current());
+    }
+
+    #[test]
+    fn test_fluid_solver_stats_default_is_zero() {
+        let stats = FluidSolverStats::default();
+        assert_eq!(stats.density_error, 0.0);
+        assert_eq!(stats.iterations, 0);
+    }
 }
@@ -0,0 +1,337 @@
+//! CPU-side query interface for gameplay and audio code that needs to ask
+//! "how deep is the water here" or "what's the flow velocity at this point"
+//! without touching wgpu directly.
+//!
+//! [`FluidQuery`] maintains a low-resolution CPU mirror of the particle
+//! field's density and velocity, rebuilt every [`FluidQuery::readback_interval`]
+//! frames from an asynchronous, non-blocking readback of the particle buffer
+//! (the same double-buffered staging pattern [`FluidSystem::step`] uses for
+//! its density-error and object-force readbacks). Between rebuilds,
+//! [`FluidQuery::sample_depth`] and [`FluidQuery::sample_velocity`] return the
+//! last mirrored grid, so callers can query every frame at negligible cost.
+
+use crate::{FluidSystem, Particle};
+use glam::Vec3;
+
+/// One cell of the CPU-side density/velocity mirror. Averaged from every
+/// particle that fell inside the cell during the last readback.
+#[derive(Copy, Clone, Debug, Default)]
+struct QueryCell {
+    density_sum: f32,
+    velocity_sum: Vec3,
+    count: u32,
+}
+
+/// Async CPU mirror of a [`FluidSystem`]'s density/velocity field, queryable
+/// by world position. See the module docs for the readback strategy.
+pub struct FluidQuery {
+    world_min: Vec3,
+    world_max: Vec3,
+    cell_size: f32,
+    dims: [u32; 3],
+    cells: Vec<QueryCell>,
+
+    staging_buffers: [wgpu::Buffer; 2],
+    staging_mapped: [bool; 2],
+    max_particles: u32,
+
+    /// Rebuild the mirror every this many calls to [`Self::update`].
+    readback_interval: u32,
+    frames_since_readback: u32,
+}
+
+impl FluidQuery {
+    /// Creates a query mirror covering `world_min..world_max`, bucketed into
+    /// cells of `cell_size`, rebuilt from a GPU readback every
+    /// `readback_interval` calls to [`Self::update`]. `max_particles` must
+    /// match the [`FluidSystem`] this query will read from.
+    pub fn new(
+        device: &wgpu::Device,
+        max_particles: u32,
+        world_min: Vec3,
+        world_max: Vec3,
+        cell_size: f32,
+        readback_interval: u32,
+    ) -> Self {
+        let extent = (world_max - world_min).max(Vec3::splat(cell_size));
+        let dims = [
+            (extent.x / cell_size).ceil().max(1.0) as u32,
+            (extent.y / cell_size).ceil().max(1.0) as u32,
+            (extent.z / cell_size).ceil().max(1.0) as u32,
+        ];
+        let cell_count = (dims[0] * dims[1] * dims[2]) as usize;
+
+        let staging_size = max_particles as u64 * std::mem::size_of::<Particle>() as u64;
+        let make_staging = |label: &str| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: staging_size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            })
+        };
+
+        Self {
+            world_min,
+            world_max,
+            cell_size,
+            dims,
+            cells: vec![QueryCell::default(); cell_count],
+            staging_buffers: [
+                make_staging("FluidQuery Staging 0"),
+                make_staging("FluidQuery Staging 1"),
+            ],
+            staging_mapped: [false, false],
+            max_particles,
+            readback_interval: readback_interval.max(1),
+            frames_since_readback: 0,
+        }
+    }
+
+    fn cell_index(&self, cell: [u32; 3]) -> usize {
+        (cell[0] + cell[1] * self.dims[0] + cell[2] * self.dims[0] * self.dims[1]) as usize
+    }
+
+    fn world_to_cell(&self, pos: Vec3) -> Option<[u32; 3]> {
+        if pos.cmplt(self.world_min).any() || pos.cmpgt(self.world_max).any() {
+            return None;
+        }
+        let local = (pos - self.world_min) / self.cell_size;
+        Some([
+            (local.x as u32).min(self.dims[0] - 1),
+            (local.y as u32).min(self.dims[1] - 1),
+            (local.z as u32).min(self.dims[2] - 1),
+        ])
+    }
+
+    /// Advances the readback cadence and, once per `readback_interval` calls,
+    /// schedules a non-blocking copy of `fluid`'s particle buffer into a
+    /// staging buffer, decoding the *previous* copy (submitted
+    /// `readback_interval` calls ago) into the CPU-side grid. Call once per
+    /// frame after [`FluidSystem::step`], passing the same `encoder` you use
+    /// for that step's command submission (or a fresh one submitted before
+    /// the next call).
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        fluid: &FluidSystem,
+    ) {
+        self.frames_since_readback += 1;
+        if self.frames_since_readback < self.readback_interval {
+            return;
+        }
+        self.frames_since_readback = 0;
+
+        let idx = (fluid.frame_index / self.readback_interval as usize) % 2;
+        let other = 1 - idx;
+
+        // Decode the copy scheduled `readback_interval` updates ago, if it's
+        // finished mapping by now.
+        if self.staging_mapped[other] {
+            self.decode(other);
+        }
+
+        if self.staging_mapped[idx] {
+            self.staging_buffers[idx].unmap();
+            self.staging_mapped[idx] = false;
+        }
+
+        let particle_count = fluid.particle_count.min(self.max_particles) as u64;
+        let copy_size = particle_count * std::mem::size_of::<Particle>() as u64;
+        if copy_size > 0 {
+            encoder.copy_buffer_to_buffer(
+                fluid.get_particle_buffer(),
+                0,
+                &self.staging_buffers[idx],
+                0,
+                copy_size,
+            );
+        }
+
+        let slice = self.staging_buffers[idx].slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        self.staging_mapped[idx] = true;
+
+        let _ = device.poll(wgpu::MaintainBase::Poll);
+    }
+
+    fn decode(&mut self, staging_idx: usize) {
+        for cell in &mut self.cells {
+            *cell = QueryCell::default();
+        }
+
+        {
+            let buffer_slice = self.staging_buffers[staging_idx].slice(..);
+            let data = buffer_slice.get_mapped_range();
+            let particles: &[Particle] = bytemuck::cast_slice(&data);
+            for particle in particles {
+                let pos = Vec3::new(
+                    particle.position[0],
+                    particle.position[1],
+                    particle.position[2],
+                );
+                let Some(cell) = self.world_to_cell(pos) else {
+                    continue;
+                };
+                let index = self.cell_index(cell);
+                let velocity = Vec3::new(
+                    particle.velocity[0],
+                    particle.velocity[1],
+                    particle.velocity[2],
+                );
+                let entry = &mut self.cells[index];
+                entry.density_sum += particle.density;
+                entry.velocity_sum += velocity;
+                entry.count += 1;
+            }
+        }
+        self.staging_buffers[staging_idx].unmap();
+        self.staging_mapped[staging_idx] = false;
+    }
+
+    /// Average particle density near `pos`, or `0.0` if `pos` is outside the
+    /// mirrored bounds or its cell held no particles at the last readback.
+    /// A density near [`FluidSystem::target_density`] means "submerged";
+    /// `0.0` means "no water here" (or not deep enough to matter).
+    pub fn sample_depth(&self, pos: Vec3) -> f32 {
+        let Some(cell) = self.world_to_cell(pos) else {
+            return 0.0;
+        };
+        let entry = &self.cells[self.cell_index(cell)];
+        if entry.count == 0 {
+            return 0.0;
+        }
+        entry.density_sum / entry.count as f32
+    }
+
+    /// Average particle velocity near `pos`, or [`Vec3::ZERO`] if `pos` is
+    /// outside the mirrored bounds or its cell held no particles at the
+    /// last readback.
+    pub fn sample_velocity(&self, pos: Vec3) -> Vec3 {
+        let Some(cell) = self.world_to_cell(pos) else {
+            return Vec3::ZERO;
+        };
+        let entry = &self.cells[self.cell_index(cell)];
+        if entry.count == 0 {
+            return Vec3::ZERO;
+        }
+        entry.velocity_sum / entry.count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wgpu::util::DeviceExt;
+
+    /// Helper: create a wgpu device + queue for testing.
+    /// Returns None if no GPU adapter is available (e.g. headless CI)
+    /// or if SKIP_GPU_TESTS env var is set (e.g. mutation testing).
+    fn try_create_test_device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        if std::env::var("SKIP_GPU_TESTS").is_ok() {
+            return None;
+        }
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        }))
+        .ok()?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("test device"),
+            required_features: wgpu::Features::FLOAT32_FILTERABLE,
+            required_limits: wgpu::Limits::default(),
+            memory_hints: wgpu::MemoryHints::default(),
+            trace: wgpu::Trace::Off,
+        }))
+        .ok()?;
+        Some((device, queue))
+    }
+
+    #[test]
+    fn test_sample_depth_and_velocity_default_to_zero() {
+        let Some((device, _queue)) = try_create_test_device() else {
+            return;
+        };
+        let query = FluidQuery::new(
+            &device,
+            64,
+            Vec3::ZERO,
+            Vec3::splat(4.0),
+            1.0,
+            4,
+        );
+        assert_eq!(query.sample_depth(Vec3::splat(2.0)), 0.0);
+        assert_eq!(query.sample_velocity(Vec3::splat(2.0)), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_sample_outside_bounds_returns_zero() {
+        let Some((device, _queue)) = try_create_test_device() else {
+            return;
+        };
+        let query = FluidQuery::new(
+            &device,
+            64,
+            Vec3::ZERO,
+            Vec3::splat(4.0),
+            1.0,
+            4,
+        );
+        assert_eq!(query.sample_depth(Vec3::splat(100.0)), 0.0);
+        assert_eq!(query.sample_velocity(Vec3::new(-1.0, 0.0, 0.0)), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_decode_averages_particles_in_the_same_cell() {
+        let Some((device, _queue)) = try_create_test_device() else {
+            return;
+        };
+        let mut query = FluidQuery::new(
+            &device,
+            2,
+            Vec3::ZERO,
+            Vec3::splat(4.0),
+            1.0,
+            4,
+        );
+
+        let particles = [
+            Particle {
+                position: [0.5, 0.5, 0.5, 0.0],
+                velocity: [1.0, 0.0, 0.0, 0.0],
+                predicted_position: [0.0; 4],
+                lambda: 0.0,
+                density: 1000.0,
+                phase: 0,
+                temperature: 293.0,
+                color: [0.0; 4],
+            },
+            Particle {
+                position: [0.6, 0.5, 0.5, 0.0],
+                velocity: [3.0, 0.0, 0.0, 0.0],
+                predicted_position: [0.0; 4],
+                lambda: 0.0,
+                density: 2000.0,
+                phase: 0,
+                temperature: 293.0,
+                color: [0.0; 4],
+            },
+        ];
+        query.staging_buffers[0] = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("test staging"),
+            contents: bytemuck::cast_slice(&particles),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        });
+        query.staging_buffers[0].slice(..).map_async(wgpu::MapMode::Read, |_| {});
+        let _ = device.poll(wgpu::MaintainBase::Wait);
+        query.staging_mapped[0] = true;
+
+        query.decode(0);
+
+        assert_eq!(query.sample_depth(Vec3::splat(0.5)), 1500.0);
+        assert_eq!(query.sample_velocity(Vec3::splat(0.5)), Vec3::new(2.0, 0.0, 0.0));
+    }
+}
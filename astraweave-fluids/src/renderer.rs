@@ -24,6 +24,17 @@ pub struct SmoothParams {
     pub padding: [f32; 5],
 }
 
+impl Default for SmoothParams {
+    fn default() -> Self {
+        Self {
+            radius: 5,
+            blur_scale: 0.1,
+            blur_depth_falloff: 100.0,
+            padding: [0.0; 5],
+        }
+    }
+}
+
 pub struct FluidRenderer {
     depth_pipeline: wgpu::RenderPipeline,
     smooth_pipeline: wgpu::ComputePipeline,
@@ -39,6 +50,10 @@ pub struct FluidRenderer {
     depth_bind_group: wgpu::BindGroup,
     smooth_bind_group: wgpu::BindGroup,
     shade_bind_group_layout: wgpu::BindGroupLayout,
+    /// Group 1 of `secondary_pipeline`: the main scene's depth buffer, used
+    /// to fade whitewater/foam sprites out as they approach scene geometry
+    /// (soft particles) instead of hard-clipping at the depth test.
+    secondary_scene_depth_layout: wgpu::BindGroupLayout,
 
     width: u32,
     height: u32,
@@ -77,12 +92,7 @@ impl FluidRenderer {
             mapped_at_creation: false,
         });
 
-        let params_data = &[SmoothParams {
-            radius: 5,
-            blur_scale: 0.1,
-            blur_depth_falloff: 100.0,
-            padding: [0.0; 5],
-        }];
+        let params_data = &[SmoothParams::default()];
         let params_bytes: &[u8] = bytemuck::cast_slice(params_data);
         println!("DEBUG: params_bytes len: {}", params_bytes.len());
 
@@ -372,9 +382,31 @@ impl FluidRenderer {
             ))),
         });
 
+        let secondary_scene_depth_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Secondary Particle Scene Depth Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Depth,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let secondary_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Secondary Particle Pipeline Layout"),
+                bind_group_layouts: &[&depth_bind_group_layout, &secondary_scene_depth_layout],
+                push_constant_ranges: &[],
+            });
+
         let secondary_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Secondary Particle Pipeline"),
-            layout: Some(&depth_pipeline_layout),
+            layout: Some(&secondary_pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &secondary_shader,
                 entry_point: Some("vs_main"),
@@ -406,6 +438,10 @@ impl FluidRenderer {
                 entry_point: Some("fs_main"),
                 targets: &[Some(wgpu::ColorTargetState {
                     format: surface_format, // Match main surface format
+                    // Additive rather than alpha-over: overlapping soft round
+                    // sprites composite correctly regardless of draw order,
+                    // so foam/spray don't need a back-to-front sort or an
+                    // OIT pass the way a hard-edged translucent surface would.
                     blend: Some(wgpu::BlendState {
                         color: wgpu::BlendComponent {
                             src_factor: wgpu::BlendFactor::SrcAlpha,
@@ -483,11 +519,19 @@ impl FluidRenderer {
             depth_bind_group,
             smooth_bind_group,
             shade_bind_group_layout,
+            secondary_scene_depth_layout,
             width,
             height,
         }
     }
 
+    /// Reconfigure the bilateral depth-smoothing pass at runtime (e.g. from
+    /// a quality setting), instead of the fixed radius/blur baked in at
+    /// [`Self::new`].
+    pub fn set_smooth_params(&mut self, queue: &wgpu::Queue, params: SmoothParams) {
+        queue.write_buffer(&self.smooth_params_buffer, 0, bytemuck::cast_slice(&[params]));
+    }
+
     pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
         println!(
             "DEBUG: resize smooth_params_buffer size: {}",
@@ -721,6 +765,16 @@ impl FluidRenderer {
         // 3. Secondary Particles (Whitewater/Spray)
         if secondary_particle_count > 0 {
             let depth_view = self.depth_texture.create_view(&Default::default());
+            let secondary_scene_depth_bind_group =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Secondary Particle Scene Depth Bind Group"),
+                    layout: &self.secondary_scene_depth_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(scene_depth_view),
+                    }],
+                });
+
             let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("SSFR Secondary Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
@@ -741,6 +795,7 @@ impl FluidRenderer {
 
             rpass.set_pipeline(&self.secondary_pipeline);
             rpass.set_bind_group(0, &self.depth_bind_group, &[]); // Layout 0 has ViewParams
+            rpass.set_bind_group(1, &secondary_scene_depth_bind_group, &[]); // Layout 1: scene depth for soft fade
             rpass.set_vertex_buffer(0, secondary_particle_buffer.slice(..));
             rpass.draw(0..4, 0..secondary_particle_count);
         }
@@ -25,6 +25,7 @@ pub struct SdfSystem {
     init_pipeline: wgpu::ComputePipeline,
     step_pipeline: wgpu::ComputePipeline,
     finalize_pipeline: wgpu::ComputePipeline,
+    composite_pipeline: wgpu::ComputePipeline,
 
     #[allow(dead_code)]
     config_buffer: wgpu::Buffer,
@@ -32,13 +33,27 @@ pub struct SdfSystem {
 
     pub texture_a: wgpu::Texture,
     pub texture_b: wgpu::Texture,
+    static_sdf_texture: wgpu::Texture,
 
     bind_group_a: wgpu::BindGroup, // Read A, Write B
     bind_group_b: wgpu::BindGroup, // Read B, Write A
 
+    composite_bind_group_a: wgpu::BindGroup, // dynamic=A, static, out=B
+    composite_bind_group_b: wgpu::BindGroup, // dynamic=B, static, out=A
+
     config_bind_group: wgpu::BindGroup,
     jfa_bind_group: wgpu::BindGroup,
     pub resolution: u32,
+    world_size: f32,
+
+    // Whether `bake_static_heightmap` has been called; skips the composite
+    // pass entirely (matching the fluid solver's "least work by default"
+    // approach elsewhere) for levels with no static SDF collision.
+    has_static_sdf: bool,
+    // Which physical texture (`texture_a` if true, else `texture_b`) holds
+    // the most recently generated result. JFA's internal ping-pong means
+    // this isn't always `texture_a` -- see `result_texture()`.
+    result_in_a: bool,
 }
 
 impl SdfSystem {
@@ -92,9 +107,20 @@ impl SdfSystem {
 
         let texture_a = device.create_texture(&texture_desc);
         let texture_b = device.create_texture(&texture_desc);
+        let static_sdf_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("SDF Static Texture"),
+            size: texture_desc.size,
+            mip_level_count: texture_desc.mip_level_count,
+            sample_count: texture_desc.sample_count,
+            dimension: texture_desc.dimension,
+            format: texture_desc.format,
+            usage: texture_desc.usage,
+            view_formats: texture_desc.view_formats,
+        });
 
         let view_a = texture_a.create_view(&wgpu::TextureViewDescriptor::default());
         let view_b = texture_b.create_view(&wgpu::TextureViewDescriptor::default());
+        let static_view = static_sdf_texture.create_view(&wgpu::TextureViewDescriptor::default());
 
         // Layouts
         let config_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -163,6 +189,44 @@ impl SdfSystem {
             }],
         });
 
+        // Composite reads two source SDFs (dynamic + static) at once, so it
+        // needs its own layout distinct from the ping-pong `texture_layout`.
+        let composite_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("SDF Composite Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::ReadOnly,
+                        format: wgpu::TextureFormat::Rgba32Float,
+                        view_dimension: wgpu::TextureViewDimension::D3,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::ReadOnly,
+                        format: wgpu::TextureFormat::Rgba32Float,
+                        view_dimension: wgpu::TextureViewDimension::D3,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::WriteOnly,
+                        format: wgpu::TextureFormat::Rgba32Float,
+                        view_dimension: wgpu::TextureViewDimension::D3,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
         let config_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("SDF Config BG"),
             layout: &config_layout,
@@ -219,12 +283,57 @@ impl SdfSystem {
         // We actually need the pipelines to store the jfa layout if we use multiple JFA steps.
         // But let's just make the final system.
 
+        let composite_bind_group_a = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("SDF Composite BG A"), // dynamic=A, out=B
+            layout: &composite_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view_a),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&static_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&view_b),
+                },
+            ],
+        });
+
+        let composite_bind_group_b = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("SDF Composite BG B"), // dynamic=B, out=A
+            layout: &composite_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view_b),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&static_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&view_a),
+                },
+            ],
+        });
+
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("SDF Pipeline Layout"),
             bind_group_layouts: &[&config_layout, &texture_layout, &jfa_layout],
             push_constant_ranges: &[],
         });
 
+        let composite_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("SDF Composite Pipeline Layout"),
+                bind_group_layouts: &[&composite_layout],
+                push_constant_ranges: &[],
+            });
+
         let init_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
             label: Some("SDF Init Pipeline"),
             layout: Some(&pipeline_layout),
@@ -252,23 +361,135 @@ impl SdfSystem {
             cache: None,
         });
 
+        let composite_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("SDF Composite Pipeline"),
+            layout: Some(&composite_pipeline_layout),
+            module: &shader,
+            entry_point: Some("composite"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
         Self {
             init_pipeline,
             step_pipeline,
             finalize_pipeline,
+            composite_pipeline,
             config_buffer,
             jfa_params_buffer,
             texture_a,
             texture_b,
+            static_sdf_texture,
             bind_group_a,
             bind_group_b,
+            composite_bind_group_a,
+            composite_bind_group_b,
             config_bind_group,
             jfa_bind_group,
             resolution,
+            world_size,
+            has_static_sdf: false,
+            result_in_a: true,
+        }
+    }
+
+    /// Returns the texture holding the most recently generated SDF: the
+    /// dynamic (JFA) field composited with the baked static field if
+    /// [`Self::bake_static_heightmap`] has been called, or just the dynamic
+    /// field otherwise. JFA's internal ping-pong means the up-to-date data
+    /// doesn't always land in `texture_a` -- callers that need the current
+    /// result (e.g. for fluid collision sampling) should go through this
+    /// accessor rather than assuming `texture_a`.
+    pub fn result_texture(&self) -> &wgpu::Texture {
+        if self.result_in_a {
+            &self.texture_a
+        } else {
+            &self.texture_b
         }
     }
 
-    pub fn generate(&self, encoder: &mut wgpu::CommandEncoder, queue: &wgpu::Queue) {
+    /// Bakes a persistent static-geometry SDF from a heightmap, to be
+    /// unioned with the dynamic (JFA) SDF every frame in [`Self::generate`]
+    /// so fluids collide with level terrain, not just dynamic objects.
+    ///
+    /// `heights` is a row-major `width * depth` grid of world-space Y
+    /// heights, covering the same `[-world_size/2, world_size/2]` XZ extent
+    /// as the SDF volume. Like [`Self::update_from_skinned_mesh`], this is a
+    /// cheap approximation (vertical distance to the heightfield surface,
+    /// not a true Euclidean SDF) rather than exact mesh voxelization, and
+    /// intentionally takes a plain slice instead of depending on a concrete
+    /// terrain crate type.
+    ///
+    /// This does a full CPU-side rebuild of the SDF volume and one texture
+    /// upload, so it's meant to be called once per level load (or whenever
+    /// the static geometry changes), not every frame.
+    pub fn bake_static_heightmap(
+        &mut self,
+        queue: &wgpu::Queue,
+        heights: &[f32],
+        width: usize,
+        depth: usize,
+    ) {
+        if width == 0 || depth == 0 || heights.len() < width * depth {
+            return;
+        }
+
+        let res = self.resolution as usize;
+        let mut data = vec![0.0f32; res * res * res * 4];
+
+        for z in 0..res {
+            for y in 0..res {
+                for x in 0..res {
+                    let world_pos = [
+                        (x as f32 / self.resolution as f32 - 0.5) * self.world_size,
+                        (y as f32 / self.resolution as f32 - 0.5) * self.world_size,
+                        (z as f32 / self.resolution as f32 - 0.5) * self.world_size,
+                    ];
+
+                    // Nearest-sample the heightmap in the XZ plane -- a
+                    // cheap approximation, matching the AABB approximation
+                    // `update_from_skinned_mesh` uses elsewhere in this file.
+                    let hx = (((world_pos[0] / self.world_size + 0.5) * width as f32) as isize)
+                        .clamp(0, width as isize - 1) as usize;
+                    let hz = (((world_pos[2] / self.world_size + 0.5) * depth as f32) as isize)
+                        .clamp(0, depth as isize - 1) as usize;
+                    let terrain_height = heights[hz * width + hx];
+
+                    // Vertical distance to the heightfield surface: positive
+                    // above ground, negative underground.
+                    let dist = world_pos[1] - terrain_height;
+
+                    let idx = (z * res * res + y * res + x) * 4;
+                    data[idx] = dist;
+                    data[idx + 3] = 1.0;
+                }
+            }
+        }
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.static_sdf_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&data),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(self.resolution * 4 * std::mem::size_of::<f32>() as u32),
+                rows_per_image: Some(self.resolution),
+            },
+            wgpu::Extent3d {
+                width: self.resolution,
+                height: self.resolution,
+                depth_or_array_layers: self.resolution,
+            },
+        );
+
+        self.has_static_sdf = true;
+    }
+
+    pub fn generate(&mut self, encoder: &mut wgpu::CommandEncoder, queue: &wgpu::Queue) {
         let workgroups = self.resolution.div_ceil(8);
 
         // 1. Init
@@ -320,24 +541,56 @@ impl SdfSystem {
         }
 
         // 3. Finalize
-        {
+        //
+        // `current_read_a` here means the last JFA step read A and wrote B,
+        // so finalize must read B (bind_group_b) -- and since finalize can
+        // only read one texture and write the other, its result lands in
+        // whichever texture bind_group_b/bind_group_a *writes*: A when
+        // current_read_a is true, B when it's false. How many JFA steps run
+        // (and thus which texture ends up holding the result) depends on
+        // `resolution`, so the destination isn't always `texture_a` --
+        // tracked below as `dynamic_in_a` for `result_texture()`.
+        if current_read_a {
             let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("SDF Finalize"),
                 ..Default::default()
             });
             cpass.set_pipeline(&self.finalize_pipeline);
             cpass.set_bind_group(0, &self.config_bind_group, &[]);
-            if current_read_a {
-                // Final result was in A, so read A?
-                // Wait, if current_read_a is true, it means last step Read A, Wrote B.
-                // So result is in B.
-                cpass.set_bind_group(1, &self.bind_group_b, &[]);
+            cpass.set_bind_group(1, &self.bind_group_b, &[]);
+            cpass.set_bind_group(2, &self.jfa_bind_group, &[]);
+            cpass.dispatch_workgroups(workgroups, workgroups, workgroups);
+        } else {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("SDF Finalize"),
+                ..Default::default()
+            });
+            cpass.set_pipeline(&self.finalize_pipeline);
+            cpass.set_bind_group(0, &self.config_bind_group, &[]);
+            cpass.set_bind_group(1, &self.bind_group_a, &[]);
+            cpass.set_bind_group(2, &self.jfa_bind_group, &[]);
+            cpass.dispatch_workgroups(workgroups, workgroups, workgroups);
+        }
+        let dynamic_in_a = current_read_a;
+
+        // 4. Composite with the baked static SDF, if any. Skipped entirely
+        // when nothing has been baked, so levels without static SDF
+        // collision pay no extra cost.
+        if self.has_static_sdf {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("SDF Composite"),
+                ..Default::default()
+            });
+            cpass.set_pipeline(&self.composite_pipeline);
+            if dynamic_in_a {
+                cpass.set_bind_group(0, &self.composite_bind_group_a, &[]);
             } else {
-                // Last step Read B, Wrote A. Result in A.
-                cpass.set_bind_group(1, &self.bind_group_a, &[]);
+                cpass.set_bind_group(0, &self.composite_bind_group_b, &[]);
             }
-            cpass.set_bind_group(2, &self.jfa_bind_group, &[]);
             cpass.dispatch_workgroups(workgroups, workgroups, workgroups);
+            self.result_in_a = !dynamic_in_a;
+        } else {
+            self.result_in_a = dynamic_in_a;
         }
     }
 
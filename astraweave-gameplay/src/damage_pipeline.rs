@@ -0,0 +1,345 @@
+//! Routes physics-layer contact/projectile/explosion events into gameplay
+//! damage. [`DamageRouter`] looks up per-material multipliers and applies
+//! explosion falloff before handing damage off to [`Stats::apply_damage`]
+//! for the target's own defense mitigation, and reports the knockback
+//! impulse plus whether the hit should trigger a ragdoll transition so
+//! animation/physics systems can react without re-deriving it.
+
+use crate::{Combatant, DamageType};
+use astraweave_physics::projectile::{ExplosionResult, ProjectileHit};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Where a [`DamagePacket`] originated, carrying enough context for
+/// knockback and VFX systems to react without re-deriving it from the
+/// physics event that produced it.
+#[derive(Clone, Debug)]
+pub enum DamageSource {
+    Melee { direction: Vec3 },
+    Projectile { hit: ProjectileHit },
+    Explosion { center: Vec3, result: ExplosionResult },
+}
+
+/// A resolved unit of damage ready to apply to a [`Combatant`], produced by
+/// [`DamageRouter::route_projectile_hit`] / [`DamageRouter::route_explosion`]
+/// from a raw physics event.
+#[derive(Clone, Debug)]
+pub struct DamagePacket {
+    pub target: u64,
+    pub amount: i32,
+    pub dtype: DamageType,
+    pub impulse: Vec3,
+    pub source: DamageSource,
+}
+
+/// Outcome of applying a [`DamagePacket`] to a [`Combatant`]'s stats.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageOutcome {
+    pub damage_dealt: i32,
+    pub should_ragdoll: bool,
+}
+
+/// Per-material damage multiplier, authored by design (e.g. "flesh" takes
+/// full fire damage, "metal" resists fire but is weak to shock).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MaterialArmor {
+    pub material: String,
+    pub dtype: DamageType,
+    pub multiplier: f32,
+}
+
+/// Data-driven configuration for [`DamageRouter`], loadable with
+/// [`load_damage_router_config`].
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct DamageRouterConfig {
+    #[serde(default)]
+    pub armor: Vec<MaterialArmor>,
+    /// A hit that leaves the target at or below this HP triggers ragdoll.
+    #[serde(default)]
+    pub ragdoll_hp_threshold: i32,
+    /// A hit whose knockback impulse is at least this strong triggers
+    /// ragdoll regardless of remaining HP.
+    #[serde(default)]
+    pub ragdoll_impulse_threshold: f32,
+}
+
+pub fn load_damage_router_config(toml_txt: &str) -> anyhow::Result<DamageRouterConfig> {
+    Ok(toml::from_str(toml_txt)?)
+}
+
+/// Routes physics contact/projectile/explosion events into [`DamagePacket`]s
+/// and applies them to [`Combatant`]s. Looks up the target's material tag
+/// (caller-supplied — this crate doesn't know how bodies are tagged) to
+/// apply [`MaterialArmor`] multipliers; explosion falloff is read directly
+/// from each [`ExplosionResult::falloff_multiplier`] rather than
+/// recomputed here.
+pub struct DamageRouter {
+    config: DamageRouterConfig,
+}
+
+impl DamageRouter {
+    pub fn new(config: DamageRouterConfig) -> Self {
+        Self { config }
+    }
+
+    fn multiplier_for(&self, material: &str, dtype: DamageType) -> f32 {
+        self.config
+            .armor
+            .iter()
+            .find(|a| {
+                a.material == material
+                    && std::mem::discriminant(&a.dtype) == std::mem::discriminant(&dtype)
+            })
+            .map(|a| a.multiplier)
+            .unwrap_or(1.0)
+    }
+
+    /// Routes a single projectile hit into a damage packet scaled by the
+    /// target's material multiplier. Returns `None` if the hit has no
+    /// body (e.g. it hit static level geometry, not a combatant).
+    pub fn route_projectile_hit(
+        &self,
+        hit: &ProjectileHit,
+        base_damage: i32,
+        dtype: DamageType,
+        material: &str,
+    ) -> Option<DamagePacket> {
+        let target = hit.body_id?;
+        let amount = (base_damage as f32 * self.multiplier_for(material, dtype)).round() as i32;
+        Some(DamagePacket {
+            target,
+            amount,
+            dtype,
+            impulse: -hit.normal * base_damage as f32,
+            source: DamageSource::Projectile { hit: hit.clone() },
+        })
+    }
+
+    /// Routes every body affected by an explosion into a damage packet,
+    /// scaling `base_damage` by each result's falloff multiplier and the
+    /// affected body's material multiplier.
+    pub fn route_explosion(
+        &self,
+        center: Vec3,
+        results: &[ExplosionResult],
+        base_damage: f32,
+        dtype: DamageType,
+        material_of: &HashMap<u64, String>,
+    ) -> Vec<DamagePacket> {
+        results
+            .iter()
+            .map(|result| {
+                let material = material_of
+                    .get(&result.body_id)
+                    .map(|s| s.as_str())
+                    .unwrap_or("");
+                let scaled = base_damage
+                    * result.falloff_multiplier
+                    * self.multiplier_for(material, dtype);
+                DamagePacket {
+                    target: result.body_id,
+                    amount: scaled.round() as i32,
+                    dtype,
+                    impulse: result.impulse,
+                    source: DamageSource::Explosion {
+                        center,
+                        result: result.clone(),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Applies `packet` to `target`'s stats (which handles its own defense
+    /// mitigation), returning the damage actually dealt and whether the
+    /// impact should trigger a ragdoll transition.
+    pub fn apply(&self, packet: &DamagePacket, target: &mut Combatant) -> DamageOutcome {
+        let damage_dealt = target.stats.apply_damage(packet.amount, packet.dtype);
+        let should_ragdoll = target.stats.hp <= self.config.ragdoll_hp_threshold
+            || packet.impulse.length() >= self.config.ragdoll_impulse_threshold;
+        DamageOutcome {
+            damage_dealt,
+            should_ragdoll,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stats;
+
+    fn combatant(body: u64, hp: i32) -> Combatant {
+        Combatant {
+            body,
+            stats: Stats::new(hp),
+            iframes: None,
+            parry: None,
+        }
+    }
+
+    fn config_with_ragdoll(hp_threshold: i32, impulse_threshold: f32) -> DamageRouterConfig {
+        DamageRouterConfig {
+            armor: vec![MaterialArmor {
+                material: "wood".to_string(),
+                dtype: DamageType::Fire,
+                multiplier: 2.0,
+            }],
+            ragdoll_hp_threshold: hp_threshold,
+            ragdoll_impulse_threshold: impulse_threshold,
+        }
+    }
+
+    #[test]
+    fn test_route_projectile_hit_returns_none_without_body() {
+        let router = DamageRouter::new(DamageRouterConfig::default());
+        let hit = ProjectileHit {
+            projectile_id: 1,
+            position: Vec3::ZERO,
+            normal: Vec3::Y,
+            body_id: None,
+            distance: 3.0,
+            penetrated: false,
+        };
+        assert!(router
+            .route_projectile_hit(&hit, 10, DamageType::Physical, "")
+            .is_none());
+    }
+
+    #[test]
+    fn test_route_projectile_hit_applies_material_multiplier() {
+        let router = DamageRouter::new(config_with_ragdoll(0, 1000.0));
+        let hit = ProjectileHit {
+            projectile_id: 1,
+            position: Vec3::ZERO,
+            normal: Vec3::Y,
+            body_id: Some(42),
+            distance: 3.0,
+            penetrated: false,
+        };
+        let packet = router
+            .route_projectile_hit(&hit, 10, DamageType::Fire, "wood")
+            .unwrap();
+        assert_eq!(packet.target, 42);
+        assert_eq!(packet.amount, 20);
+        assert_eq!(packet.impulse, Vec3::new(0.0, -10.0, 0.0));
+    }
+
+    #[test]
+    fn test_route_projectile_hit_unbound_material_defaults_to_full_damage() {
+        let router = DamageRouter::new(config_with_ragdoll(0, 1000.0));
+        let hit = ProjectileHit {
+            projectile_id: 1,
+            position: Vec3::ZERO,
+            normal: Vec3::Y,
+            body_id: Some(1),
+            distance: 3.0,
+            penetrated: false,
+        };
+        let packet = router
+            .route_projectile_hit(&hit, 10, DamageType::Physical, "flesh")
+            .unwrap();
+        assert_eq!(packet.amount, 10);
+    }
+
+    #[test]
+    fn test_route_explosion_scales_by_falloff_and_material() {
+        let router = DamageRouter::new(config_with_ragdoll(0, 1000.0));
+        let results = vec![
+            ExplosionResult {
+                body_id: 1,
+                impulse: Vec3::new(5.0, 0.0, 0.0),
+                distance: 1.0,
+                falloff_multiplier: 1.0,
+            },
+            ExplosionResult {
+                body_id: 2,
+                impulse: Vec3::new(1.0, 0.0, 0.0),
+                distance: 4.0,
+                falloff_multiplier: 0.5,
+            },
+        ];
+        let mut material_of = HashMap::new();
+        material_of.insert(1u64, "wood".to_string());
+
+        let packets =
+            router.route_explosion(Vec3::ZERO, &results, 100.0, DamageType::Fire, &material_of);
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].amount, 200); // 100 * 1.0 * 2.0 (wood/fire)
+        assert_eq!(packets[1].amount, 50); // 100 * 0.5 * 1.0 (no material entry)
+    }
+
+    #[test]
+    fn test_apply_deals_mitigated_damage() {
+        let router = DamageRouter::new(config_with_ragdoll(-1000, 1000.0));
+        let mut target = combatant(1, 100);
+        target.stats.defense = 0;
+        let packet = DamagePacket {
+            target: 1,
+            amount: 30,
+            dtype: DamageType::Physical,
+            impulse: Vec3::ZERO,
+            source: DamageSource::Melee {
+                direction: Vec3::X,
+            },
+        };
+        let outcome = router.apply(&packet, &mut target);
+        assert_eq!(outcome.damage_dealt, 30);
+        assert_eq!(target.stats.hp, 70);
+        assert!(!outcome.should_ragdoll);
+    }
+
+    #[test]
+    fn test_apply_triggers_ragdoll_below_hp_threshold() {
+        let router = DamageRouter::new(config_with_ragdoll(50, 1000.0));
+        let mut target = combatant(1, 60);
+        target.stats.defense = 0;
+        let packet = DamagePacket {
+            target: 1,
+            amount: 20,
+            dtype: DamageType::Physical,
+            impulse: Vec3::ZERO,
+            source: DamageSource::Melee {
+                direction: Vec3::X,
+            },
+        };
+        let outcome = router.apply(&packet, &mut target);
+        assert_eq!(target.stats.hp, 40);
+        assert!(outcome.should_ragdoll);
+    }
+
+    #[test]
+    fn test_apply_triggers_ragdoll_on_strong_impulse_regardless_of_hp() {
+        let router = DamageRouter::new(config_with_ragdoll(-1000, 50.0));
+        let mut target = combatant(1, 1000);
+        let packet = DamagePacket {
+            target: 1,
+            amount: 5,
+            dtype: DamageType::Physical,
+            impulse: Vec3::new(60.0, 0.0, 0.0),
+            source: DamageSource::Melee {
+                direction: Vec3::X,
+            },
+        };
+        let outcome = router.apply(&packet, &mut target);
+        assert!(outcome.should_ragdoll);
+    }
+
+    #[test]
+    fn test_load_damage_router_config_from_toml() {
+        let toml_txt = r#"
+            ragdoll_hp_threshold = 10
+            ragdoll_impulse_threshold = 500.0
+
+            [[armor]]
+            material = "metal"
+            dtype = "Shock"
+            multiplier = 1.5
+        "#;
+        let config = load_damage_router_config(toml_txt).unwrap();
+        assert_eq!(config.ragdoll_hp_threshold, 10);
+        assert_eq!(config.armor.len(), 1);
+        assert_eq!(config.armor[0].material, "metal");
+    }
+}
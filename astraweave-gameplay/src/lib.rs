@@ -6,11 +6,19 @@
 //!
 //! - **Combat** — Raycast-based attack sweep with cone filtering, parry, and i-frames
 //!   ([`combat_physics::perform_attack_sweep`]).
+//! - **Damage Pipeline** — Routes projectile/explosion physics events into
+//!   material-aware damage packets with knockback and ragdoll triggering
+//!   ([`damage_pipeline::DamageRouter`]).
 //! - **Crafting** — Recipe system with material requirements.
 //! - **Quests** — Quest tracking with objectives and completion logic.
 //! - **Dialogue** — Branching NPC conversation trees.
 //! - **Stats** — Character statistics and damage calculation.
 //! - **Items** — Inventory management and item definitions.
+//! - **Weapons** — Validated, data-driven weapon/ballistics definitions shared
+//!   by the projectile, ability, and AI systems ([`weapon::WeaponRegistry`]).
+//! - **Projectile VFX** — Data-driven binding from projectile lifecycle
+//!   events to particle, decal, sound, and camera shake cues
+//!   ([`projectile_vfx::ProjectileVfxTable`]).
 //! - **Biome** — Biome definitions, spawn rules, and biome transitions.
 //! - **Veilweaver** — Game-specific mechanics (weaving, portals, telemetry, tutorial).
 //! - **ECS Integration** — System registration for all gameplay subsystems.
@@ -21,16 +29,19 @@ pub mod combat;
 pub mod combat_physics;
 pub mod crafting;
 pub mod cutscenes;
+pub mod damage_pipeline;
 pub mod dialogue;
 pub mod ecs;
 pub mod harvesting;
 pub mod items;
+pub mod projectile_vfx;
 pub mod quests;
 pub mod stats;
 pub mod types;
 pub mod veilweaver_slice;
 pub mod veilweaver_tutorial;
 pub mod water_movement;
+pub mod weapon;
 pub mod weaving;
 
 pub use biome::*;
@@ -39,16 +50,19 @@ pub use combat::*;
 pub use combat_physics::*;
 pub use crafting::*;
 pub use cutscenes::*;
+pub use damage_pipeline::*;
 pub use dialogue::*;
 pub use ecs::*;
 pub use harvesting::*;
 pub use items::*;
+pub use projectile_vfx::*;
 pub use quests::*;
 pub use stats::*;
 pub use types::*;
 pub use veilweaver_slice::*;
 pub use veilweaver_tutorial::*;
 pub use water_movement::*;
+pub use weapon::*;
 pub use weaving::*;
 
 pub mod weave_portals;
@@ -0,0 +1,374 @@
+//! Data-driven binding between `astraweave_physics::projectile::ProjectileManager`
+//! lifecycle moments (spawn, travel, hit, explosion) and the particle emitters,
+//! decals, sounds, and camera shake definitions designers author per projectile
+//! kind. This crate has no dependency on render or audio, so it only produces
+//! [`ProjectileVfxCue`]s describing *what* should play and *where* — the caller's
+//! render/audio layer resolves the named [`EffectRef`]s and actually plays them.
+
+use astraweave_physics::projectile::{ExplosionResult, ProjectileHit, ProjectileId};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Designer-authored key identifying a family of projectiles (e.g. `"arrow"`,
+/// `"fireball"`). Distinct from the ballistic
+/// [`ProjectileKind`](astraweave_physics::projectile::ProjectileKind) enum,
+/// which only distinguishes hitscan vs kinematic simulation — this key is
+/// purely a lookup into the VFX table and carries no physics meaning.
+pub type ProjectileVfxKind = String;
+
+/// A named reference to an externally-authored effect asset. This crate
+/// doesn't know how to play sounds or spawn particles; it only carries the
+/// name so a render or audio system can resolve it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectRef(pub String);
+
+/// VFX/audio/feel hooks authored for one lifecycle stage of a projectile kind.
+/// Any field left `None` means that stage has nothing to play.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectileVfxStage {
+    pub particle_emitter: Option<EffectRef>,
+    pub decal: Option<EffectRef>,
+    pub sound: Option<EffectRef>,
+    pub camera_shake: Option<EffectRef>,
+}
+
+impl ProjectileVfxStage {
+    /// True if this stage has no effects authored at all.
+    pub fn is_empty(&self) -> bool {
+        self.particle_emitter.is_none()
+            && self.decal.is_none()
+            && self.sound.is_none()
+            && self.camera_shake.is_none()
+    }
+}
+
+/// Complete authored binding for one projectile kind, covering every stage
+/// of its lifecycle.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectileVfxBinding {
+    pub kind: ProjectileVfxKind,
+    #[serde(default)]
+    pub on_spawn: ProjectileVfxStage,
+    #[serde(default)]
+    pub on_travel: ProjectileVfxStage,
+    #[serde(default)]
+    pub on_hit: ProjectileVfxStage,
+    #[serde(default)]
+    pub on_explosion: ProjectileVfxStage,
+}
+
+/// Table of authored bindings, keyed by [`ProjectileVfxKind`]. Load with
+/// [`load_vfx_bindings`] or build one up with [`ProjectileVfxTable::insert`].
+#[derive(Clone, Debug, Default)]
+pub struct ProjectileVfxTable {
+    bindings: HashMap<ProjectileVfxKind, ProjectileVfxBinding>,
+}
+
+impl ProjectileVfxTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, binding: ProjectileVfxBinding) {
+        self.bindings.insert(binding.kind.clone(), binding);
+    }
+
+    pub fn get(&self, kind: &str) -> Option<&ProjectileVfxBinding> {
+        self.bindings.get(kind)
+    }
+}
+
+/// Parses a TOML-authored VFX binding table, e.g.:
+/// ```toml
+/// [[bindings]]
+/// kind = "arrow"
+///
+/// [bindings.on_hit]
+/// particle_emitter = "vfx/arrow_hit"
+/// sound = "sfx/arrow_thud"
+/// ```
+pub fn load_vfx_bindings(toml_txt: &str) -> anyhow::Result<ProjectileVfxTable> {
+    #[derive(Deserialize)]
+    struct File {
+        bindings: Vec<ProjectileVfxBinding>,
+    }
+    let f: File = toml::from_str(toml_txt)?;
+    let mut table = ProjectileVfxTable::new();
+    for binding in f.bindings {
+        table.insert(binding);
+    }
+    Ok(table)
+}
+
+/// A single resolved VFX/audio cue, ready for a render/audio system to play.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectileVfxCue {
+    /// Projectile this cue was fired for, if it corresponds to one
+    /// (explosions triggered at a bare point rather than by a live
+    /// projectile have no id).
+    pub projectile_id: Option<ProjectileId>,
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub stage: ProjectileVfxStage,
+}
+
+/// Looks up `kind`'s spawn-stage effects, if the projectile kind is bound
+/// and the stage isn't empty. Call once when [`ProjectileManager::spawn`](
+/// astraweave_physics::projectile::ProjectileManager::spawn) is invoked.
+pub fn spawn_cue(
+    table: &ProjectileVfxTable,
+    kind: &str,
+    projectile_id: ProjectileId,
+    position: Vec3,
+    direction: Vec3,
+) -> Option<ProjectileVfxCue> {
+    stage_cue(table, kind, Some(projectile_id), position, direction, |b| {
+        &b.on_spawn
+    })
+}
+
+/// Looks up `kind`'s travel-stage effects (e.g. a tracer trail), if bound
+/// and non-empty. Call each frame a projectile is in flight.
+pub fn travel_cue(
+    table: &ProjectileVfxTable,
+    kind: &str,
+    projectile_id: ProjectileId,
+    position: Vec3,
+    velocity: Vec3,
+) -> Option<ProjectileVfxCue> {
+    stage_cue(table, kind, Some(projectile_id), position, velocity, |b| {
+        &b.on_travel
+    })
+}
+
+/// Resolves the hit-stage cue for a single [`ProjectileHit`], given the
+/// kind the projectile was authored with.
+pub fn hit_cue(
+    table: &ProjectileVfxTable,
+    kind: &str,
+    hit: &ProjectileHit,
+) -> Option<ProjectileVfxCue> {
+    stage_cue(
+        table,
+        kind,
+        Some(hit.projectile_id),
+        hit.position,
+        hit.normal,
+        |b| &b.on_hit,
+    )
+}
+
+/// Resolves hit-stage cues for a batch of hits (as returned by
+/// [`ProjectileManager::drain_hits`](
+/// astraweave_physics::projectile::ProjectileManager::drain_hits)), looking
+/// up each hit's kind via `kinds`. Hits for projectiles with no known kind
+/// or no authored `on_hit` stage are silently skipped.
+pub fn resolve_hit_cues(
+    table: &ProjectileVfxTable,
+    hits: &[ProjectileHit],
+    kinds: &HashMap<ProjectileId, ProjectileVfxKind>,
+) -> Vec<ProjectileVfxCue> {
+    hits.iter()
+        .filter_map(|hit| hit_cue(table, kinds.get(&hit.projectile_id)?, hit))
+        .collect()
+}
+
+/// Resolves the explosion-stage cue at `center` for the given kind. Not
+/// keyed to a specific [`ExplosionResult`] since one explosion produces one
+/// result per affected body; call once per explosion using its config, not
+/// once per result.
+pub fn explosion_cue(table: &ProjectileVfxTable, kind: &str, center: Vec3) -> Option<ProjectileVfxCue> {
+    stage_cue(table, kind, None, center, Vec3::ZERO, |b| &b.on_explosion)
+}
+
+/// Resolves explosion-stage cues for every body affected by an explosion,
+/// useful when per-body shake/decal variation is desired instead of a
+/// single cue at the explosion center.
+pub fn resolve_explosion_cues(
+    table: &ProjectileVfxTable,
+    kind: &str,
+    center: Vec3,
+    results: &[ExplosionResult],
+) -> Vec<ProjectileVfxCue> {
+    let Some(binding) = table.get(kind) else {
+        return Vec::new();
+    };
+    if binding.on_explosion.is_empty() {
+        return Vec::new();
+    }
+    results
+        .iter()
+        .map(|result| ProjectileVfxCue {
+            projectile_id: None,
+            position: center,
+            normal: (result.impulse).normalize_or_zero(),
+            stage: binding.on_explosion.clone(),
+        })
+        .collect()
+}
+
+fn stage_cue(
+    table: &ProjectileVfxTable,
+    kind: &str,
+    projectile_id: Option<ProjectileId>,
+    position: Vec3,
+    normal: Vec3,
+    select: impl Fn(&ProjectileVfxBinding) -> &ProjectileVfxStage,
+) -> Option<ProjectileVfxCue> {
+    let binding = table.get(kind)?;
+    let stage = select(binding);
+    if stage.is_empty() {
+        return None;
+    }
+    Some(ProjectileVfxCue {
+        projectile_id,
+        position,
+        normal,
+        stage: stage.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_binding() -> ProjectileVfxBinding {
+        ProjectileVfxBinding {
+            kind: "arrow".to_string(),
+            on_spawn: ProjectileVfxStage {
+                sound: Some(EffectRef("sfx/arrow_loose".to_string())),
+                ..Default::default()
+            },
+            on_hit: ProjectileVfxStage {
+                particle_emitter: Some(EffectRef("vfx/arrow_hit".to_string())),
+                sound: Some(EffectRef("sfx/arrow_thud".to_string())),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_load_vfx_bindings_from_toml() {
+        let toml_txt = r#"
+            [[bindings]]
+            kind = "arrow"
+
+            [bindings.on_hit]
+            particle_emitter = "vfx/arrow_hit"
+            sound = "sfx/arrow_thud"
+        "#;
+        let table = load_vfx_bindings(toml_txt).unwrap();
+        let binding = table.get("arrow").unwrap();
+        assert_eq!(
+            binding.on_hit.particle_emitter,
+            Some(EffectRef("vfx/arrow_hit".to_string()))
+        );
+        assert!(binding.on_spawn.is_empty());
+    }
+
+    #[test]
+    fn test_spawn_cue_returns_none_for_unbound_kind() {
+        let table = ProjectileVfxTable::new();
+        assert!(spawn_cue(&table, "arrow", 1, Vec3::ZERO, Vec3::X).is_none());
+    }
+
+    #[test]
+    fn test_travel_cue_returns_none_for_empty_stage() {
+        let mut table = ProjectileVfxTable::new();
+        table.insert(sample_binding());
+        // on_travel has no effects authored in sample_binding.
+        assert!(travel_cue(&table, "arrow", 1, Vec3::ZERO, Vec3::X).is_none());
+    }
+
+    #[test]
+    fn test_spawn_cue_resolves_authored_stage() {
+        let mut table = ProjectileVfxTable::new();
+        table.insert(sample_binding());
+        let cue = spawn_cue(&table, "arrow", 7, Vec3::new(1.0, 2.0, 3.0), Vec3::X).unwrap();
+        assert_eq!(cue.projectile_id, Some(7));
+        assert_eq!(cue.position, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            cue.stage.sound,
+            Some(EffectRef("sfx/arrow_loose".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_resolve_hit_cues_skips_unknown_kinds() {
+        let mut table = ProjectileVfxTable::new();
+        table.insert(sample_binding());
+
+        let hits = vec![
+            ProjectileHit {
+                projectile_id: 1,
+                position: Vec3::ZERO,
+                normal: Vec3::Y,
+                body_id: Some(42),
+                distance: 5.0,
+                penetrated: false,
+            },
+            ProjectileHit {
+                projectile_id: 2,
+                position: Vec3::ONE,
+                normal: Vec3::Y,
+                body_id: None,
+                distance: 1.0,
+                penetrated: false,
+            },
+        ];
+        let mut kinds = HashMap::new();
+        kinds.insert(1, "arrow".to_string());
+        // projectile 2 has no known kind and should be skipped.
+
+        let cues = resolve_hit_cues(&table, &hits, &kinds);
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].projectile_id, Some(1));
+        assert_eq!(
+            cues[0].stage.particle_emitter,
+            Some(EffectRef("vfx/arrow_hit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_resolve_explosion_cues_one_per_affected_body() {
+        let mut table = ProjectileVfxTable::new();
+        table.insert(ProjectileVfxBinding {
+            kind: "fireball".to_string(),
+            on_explosion: ProjectileVfxStage {
+                camera_shake: Some(EffectRef("shake/explosion_medium".to_string())),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let results = vec![
+            ExplosionResult {
+                body_id: 1,
+                impulse: Vec3::new(1.0, 0.0, 0.0),
+                distance: 2.0,
+                falloff_multiplier: 0.8,
+            },
+            ExplosionResult {
+                body_id: 2,
+                impulse: Vec3::new(0.0, 1.0, 0.0),
+                distance: 4.0,
+                falloff_multiplier: 0.3,
+            },
+        ];
+
+        let cues = resolve_explosion_cues(&table, "fireball", Vec3::ZERO, &results);
+        assert_eq!(cues.len(), 2);
+        assert!(cues.iter().all(|c| c.projectile_id.is_none()));
+        assert!(cues
+            .iter()
+            .all(|c| c.stage.camera_shake == Some(EffectRef("shake/explosion_medium".to_string()))));
+    }
+
+    #[test]
+    fn test_explosion_cue_none_when_kind_unbound() {
+        let table = ProjectileVfxTable::new();
+        assert!(explosion_cue(&table, "fireball", Vec3::ZERO).is_none());
+    }
+}
@@ -0,0 +1,312 @@
+//! Data-driven weapon/ballistics definitions, validated at import time and
+//! held in a runtime [`WeaponRegistry`] so the projectile, ability, and AI
+//! systems all read from one authoritative source instead of each hardcoding
+//! damage/spread/recoil numbers.
+
+use crate::projectile_vfx::EffectRef;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Designer-authored key identifying a weapon (e.g. `"pistol"`, `"longbow"`).
+pub type WeaponKey = String;
+
+/// Mirrors [`astraweave_physics::projectile::ProjectileKind`] without
+/// depending on that crate's `serde` feature; converted via
+/// [`WeaponProjectileKind::to_physics_kind`] at the point a projectile is
+/// actually spawned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum WeaponProjectileKind {
+    /// Instant raycast (bullets, lasers) - no travel time.
+    Hitscan,
+    /// Physically simulated with ballistics (grenades, arrows).
+    #[default]
+    Kinematic,
+}
+
+impl WeaponProjectileKind {
+    pub fn to_physics_kind(self) -> astraweave_physics::projectile::ProjectileKind {
+        match self {
+            Self::Hitscan => astraweave_physics::projectile::ProjectileKind::Hitscan,
+            Self::Kinematic => astraweave_physics::projectile::ProjectileKind::Kinematic,
+        }
+    }
+}
+
+/// One step of a weapon's recoil pattern: pitch/yaw kick, in degrees,
+/// applied on top of aim for that shot in a burst before the pattern loops.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecoilStep {
+    pub pitch_deg: f32,
+    pub yaw_deg: f32,
+}
+
+/// Complete authored definition of a weapon, shared by the projectile,
+/// ability, and AI systems.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WeaponDef {
+    pub key: WeaponKey,
+    pub damage: f32,
+    /// Cone half-angle, in degrees, that shots are randomly spread within.
+    pub spread_deg: f32,
+    /// Maximum shots per second.
+    pub fire_rate: f32,
+    #[serde(default)]
+    pub projectile_kind: WeaponProjectileKind,
+    /// Recoil kick applied per shot in a sustained burst, looping once
+    /// exhausted. Empty means no recoil.
+    #[serde(default)]
+    pub recoil_pattern: Vec<RecoilStep>,
+    #[serde(default)]
+    pub muzzle_vfx: Option<EffectRef>,
+    #[serde(default)]
+    pub impact_vfx: Option<EffectRef>,
+    #[serde(default)]
+    pub fire_sound: Option<EffectRef>,
+}
+
+impl WeaponDef {
+    /// Validates the fields a weapon must have to be usable: a non-empty
+    /// key, positive damage, non-negative spread, and a positive fire rate.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.key.trim().is_empty() {
+            anyhow::bail!("weapon key must not be empty");
+        }
+        if !(self.damage > 0.0) {
+            anyhow::bail!("weapon '{}' has non-positive damage: {}", self.key, self.damage);
+        }
+        if self.spread_deg < 0.0 {
+            anyhow::bail!(
+                "weapon '{}' has negative spread_deg: {}",
+                self.key,
+                self.spread_deg
+            );
+        }
+        if !(self.fire_rate > 0.0) {
+            anyhow::bail!(
+                "weapon '{}' has non-positive fire_rate: {}",
+                self.key,
+                self.fire_rate
+            );
+        }
+        Ok(())
+    }
+
+    /// The recoil kick for the `shot_index`-th shot of a sustained burst
+    /// (0-based), looping over [`Self::recoil_pattern`]. Returns the zero
+    /// step if no pattern is authored.
+    pub fn recoil_for_shot(&self, shot_index: usize) -> RecoilStep {
+        if self.recoil_pattern.is_empty() {
+            return RecoilStep::default();
+        }
+        self.recoil_pattern[shot_index % self.recoil_pattern.len()]
+    }
+}
+
+/// Runtime lookup table of validated [`WeaponDef`]s, keyed by
+/// [`WeaponKey`]. Load with [`load_weapon_defs`]/[`WeaponRegistry::load_from_toml`]
+/// or build one up with [`WeaponRegistry::insert`].
+#[derive(Clone, Debug, Default)]
+pub struct WeaponRegistry {
+    weapons: HashMap<WeaponKey, WeaponDef>,
+}
+
+impl WeaponRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates `def`, then inserts it, replacing any prior weapon sharing
+    /// its key.
+    pub fn insert(&mut self, def: WeaponDef) -> anyhow::Result<()> {
+        def.validate()?;
+        self.weapons.insert(def.key.clone(), def);
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&WeaponDef> {
+        self.weapons.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.weapons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weapons.is_empty()
+    }
+
+    /// Parses a TOML-authored weapon table (see [`load_weapon_defs`]),
+    /// validating and inserting every entry.
+    pub fn load_from_toml(toml_txt: &str) -> anyhow::Result<Self> {
+        let mut registry = Self::new();
+        for def in load_weapon_defs(toml_txt)? {
+            registry.insert(def)?;
+        }
+        Ok(registry)
+    }
+}
+
+/// Parses a TOML-authored weapon definition table, e.g.:
+/// ```toml
+/// [[weapons]]
+/// key = "pistol"
+/// damage = 12.0
+/// spread_deg = 1.5
+/// fire_rate = 4.0
+/// projectile_kind = "Hitscan"
+///
+/// [[weapons.recoil_pattern]]
+/// pitch_deg = 0.5
+/// yaw_deg = 0.1
+/// ```
+///
+/// Does not validate individual entries; use [`WeaponRegistry::load_from_toml`]
+/// to parse and validate in one step.
+pub fn load_weapon_defs(toml_txt: &str) -> anyhow::Result<Vec<WeaponDef>> {
+    #[derive(Deserialize)]
+    struct File {
+        weapons: Vec<WeaponDef>,
+    }
+    let f: File = toml::from_str(toml_txt)?;
+    Ok(f.weapons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_weapon() -> WeaponDef {
+        WeaponDef {
+            key: "pistol".to_string(),
+            damage: 12.0,
+            spread_deg: 1.5,
+            fire_rate: 4.0,
+            projectile_kind: WeaponProjectileKind::Hitscan,
+            recoil_pattern: vec![
+                RecoilStep {
+                    pitch_deg: 0.5,
+                    yaw_deg: 0.1,
+                },
+                RecoilStep {
+                    pitch_deg: 0.8,
+                    yaw_deg: -0.2,
+                },
+            ],
+            muzzle_vfx: Some(EffectRef("vfx/pistol_muzzle".to_string())),
+            impact_vfx: None,
+            fire_sound: Some(EffectRef("sfx/pistol_fire".to_string())),
+        }
+    }
+
+    #[test]
+    fn valid_weapon_passes_validation() {
+        assert!(sample_weapon().validate().is_ok());
+    }
+
+    #[test]
+    fn empty_key_fails_validation() {
+        let mut weapon = sample_weapon();
+        weapon.key = "  ".to_string();
+        assert!(weapon.validate().is_err());
+    }
+
+    #[test]
+    fn non_positive_damage_fails_validation() {
+        let mut weapon = sample_weapon();
+        weapon.damage = 0.0;
+        assert!(weapon.validate().is_err());
+    }
+
+    #[test]
+    fn negative_spread_fails_validation() {
+        let mut weapon = sample_weapon();
+        weapon.spread_deg = -1.0;
+        assert!(weapon.validate().is_err());
+    }
+
+    #[test]
+    fn non_positive_fire_rate_fails_validation() {
+        let mut weapon = sample_weapon();
+        weapon.fire_rate = 0.0;
+        assert!(weapon.validate().is_err());
+    }
+
+    #[test]
+    fn recoil_for_shot_loops_over_pattern() {
+        let weapon = sample_weapon();
+        assert_eq!(weapon.recoil_for_shot(0), weapon.recoil_pattern[0]);
+        assert_eq!(weapon.recoil_for_shot(1), weapon.recoil_pattern[1]);
+        assert_eq!(weapon.recoil_for_shot(2), weapon.recoil_pattern[0]);
+    }
+
+    #[test]
+    fn recoil_for_shot_with_no_pattern_is_zero() {
+        let mut weapon = sample_weapon();
+        weapon.recoil_pattern.clear();
+        assert_eq!(weapon.recoil_for_shot(3), RecoilStep::default());
+    }
+
+    #[test]
+    fn to_physics_kind_maps_variants() {
+        assert_eq!(
+            WeaponProjectileKind::Hitscan.to_physics_kind(),
+            astraweave_physics::projectile::ProjectileKind::Hitscan
+        );
+        assert_eq!(
+            WeaponProjectileKind::Kinematic.to_physics_kind(),
+            astraweave_physics::projectile::ProjectileKind::Kinematic
+        );
+    }
+
+    #[test]
+    fn load_weapon_defs_from_toml() {
+        let toml_txt = r#"
+            [[weapons]]
+            key = "pistol"
+            damage = 12.0
+            spread_deg = 1.5
+            fire_rate = 4.0
+            projectile_kind = "Hitscan"
+
+            [[weapons.recoil_pattern]]
+            pitch_deg = 0.5
+            yaw_deg = 0.1
+        "#;
+        let defs = load_weapon_defs(toml_txt).expect("parse toml");
+        assert_eq!(defs.len(), 1);
+        assert_eq!(defs[0].key, "pistol");
+        assert_eq!(defs[0].recoil_pattern.len(), 1);
+    }
+
+    #[test]
+    fn load_from_toml_rejects_invalid_entries() {
+        let toml_txt = r#"
+            [[weapons]]
+            key = "broken"
+            damage = -5.0
+            spread_deg = 1.0
+            fire_rate = 4.0
+        "#;
+        let result = WeaponRegistry::load_from_toml(toml_txt);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn registry_round_trips_valid_weapons() {
+        let mut registry = WeaponRegistry::new();
+        registry.insert(sample_weapon()).unwrap();
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("pistol").unwrap().damage, 12.0);
+        assert!(registry.get("missing").is_none());
+    }
+
+    #[test]
+    fn registry_insert_rejects_invalid_weapon() {
+        let mut registry = WeaponRegistry::new();
+        let mut weapon = sample_weapon();
+        weapon.fire_rate = -1.0;
+        assert!(registry.insert(weapon).is_err());
+        assert!(registry.is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+//! Anthropic cloud client for [`LlmClient`], with retry/backoff, rate
+//! limiting, and token-usage telemetry.
+//!
+//! Mirrors [`crate::openai_client::OpenAiClient`]'s integration with
+//! [`crate::retry`], [`crate::rate_limiter`], and
+//! [`astraweave_observability::llm_telemetry`], adapted to the Anthropic
+//! Messages API (`x-api-key` auth, `anthropic-version` header, separate
+//! `input_tokens`/`output_tokens` usage fields).
+
+use crate::rate_limiter::{RateLimitContext, RateLimiter, RateLimiterConfig, RequestPriority};
+use crate::retry::{RetryConfig, RetryExecutor, RetryableError};
+use crate::LlmClient;
+use anyhow::{bail, Result};
+use astraweave_observability::llm_telemetry::{LlmTelemetry, LlmTrace};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Rough per-1K-token USD pricing used only for telemetry cost estimates
+/// (not billing-accurate; Anthropic's actual rates vary by model).
+const PROMPT_COST_PER_1K: f64 = 0.003;
+const COMPLETION_COST_PER_1K: f64 = 0.015;
+
+/// Anthropic Messages API client.
+pub struct AnthropicClient {
+    api_key: String,
+    model: String,
+    base_url: String,
+    max_tokens: u32,
+    rate_limiter: Arc<RateLimiter>,
+    retry: RetryConfig,
+    telemetry: Option<Arc<LlmTelemetry>>,
+}
+
+impl AnthropicClient {
+    /// Create a client for `model` (e.g. `"claude-3-5-sonnet-latest"`)
+    /// authenticated with `api_key`.
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            model: model.into(),
+            base_url: "https://api.anthropic.com".to_string(),
+            max_tokens: 2048,
+            rate_limiter: Arc::new(RateLimiter::new(RateLimiterConfig::default())),
+            retry: RetryConfig::production(),
+            telemetry: None,
+        }
+    }
+
+    /// Override the API base URL (e.g. for a proxy).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Override the `max_tokens` cap on generated responses (default 2048).
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// Override retry behavior (default: [`RetryConfig::production`]).
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    /// Share a [`RateLimiter`] with other clients so limits are enforced
+    /// process-wide rather than per client instance.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Record request latency, token usage, and estimated cost to `telemetry`.
+    pub fn with_telemetry(mut self, telemetry: Arc<LlmTelemetry>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    async fn send_once(&self, prompt: &str) -> Result<(String, usize, usize), RetryableError> {
+        #[derive(serde::Serialize)]
+        struct Message<'a> {
+            role: &'a str,
+            content: &'a str,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Req<'a> {
+            model: &'a str,
+            max_tokens: u32,
+            messages: Vec<Message<'a>>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ContentBlock {
+            #[serde(default)]
+            text: String,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Usage {
+            input_tokens: usize,
+            output_tokens: usize,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            content: Vec<ContentBlock>,
+            #[serde(default)]
+            usage: Option<Usage>,
+        }
+
+        let body = Req {
+            model: &self.model,
+            max_tokens: self.max_tokens,
+            messages: vec![Message {
+                role: "user",
+                content: prompt,
+            }],
+        };
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/v1/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .timeout(std::time::Duration::from_secs(60))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                tracing::debug!("Anthropic request failed: {}", e);
+                RetryableError::NetworkError
+            })?;
+
+        let status = response.status();
+        if status.as_u16() == 429 {
+            return Err(RetryableError::RateLimited);
+        }
+        if status.is_server_error() {
+            return Err(RetryableError::ServerError(status.as_u16()));
+        }
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(RetryableError::Permanent(format!(
+                "Anthropic API returned {}: {}",
+                status, text
+            )));
+        }
+
+        let parsed: Resp = response.json().await.map_err(|e| {
+            RetryableError::Permanent(format!("invalid Anthropic response: {}", e))
+        })?;
+
+        let content = parsed
+            .content
+            .into_iter()
+            .map(|b| b.text)
+            .collect::<Vec<_>>()
+            .join("");
+
+        if content.is_empty() {
+            return Err(RetryableError::Permanent(
+                "Anthropic returned no content".to_string(),
+            ));
+        }
+
+        let (prompt_tokens, completion_tokens) = parsed
+            .usage
+            .map(|u| (u.input_tokens, u.output_tokens))
+            .unwrap_or((0, 0));
+
+        Ok((content, prompt_tokens, completion_tokens))
+    }
+}
+
+#[async_trait]
+impl LlmClient for AnthropicClient {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let context = RateLimitContext {
+            user_id: None,
+            model: self.model.clone(),
+            estimated_tokens: (prompt.len() / 4) as u32,
+            priority: RequestPriority::Normal,
+        };
+        let _permit = self.rate_limiter.acquire(&context).await?;
+
+        let start = chrono::Utc::now();
+        let executor = RetryExecutor::new(self.retry.clone());
+        let result = executor.execute(|| self.send_once(prompt)).await;
+        let end = chrono::Utc::now();
+
+        self.rate_limiter
+            .report_result(&context, result.is_ok())
+            .await;
+
+        if let Some(telemetry) = &self.telemetry {
+            let (success, tokens_prompt, tokens_response, error_message) = match &result {
+                Ok((_, p, c)) => (true, *p, *c, None),
+                Err(e) => (false, 0, 0, Some(e.to_string())),
+            };
+            let total_tokens = tokens_prompt + tokens_response;
+            let cost_usd = (tokens_prompt as f64 / 1000.0) * PROMPT_COST_PER_1K
+                + (tokens_response as f64 / 1000.0) * COMPLETION_COST_PER_1K;
+            let trace = LlmTrace {
+                request_id: uuid::Uuid::new_v4().to_string(),
+                session_id: None,
+                user_id: None,
+                prompt: None,
+                response: None,
+                prompt_hash: None,
+                model: self.model.clone(),
+                start_time: start,
+                end_time: end,
+                latency_ms: (end - start).num_milliseconds().max(0) as u64,
+                tokens_prompt,
+                tokens_response,
+                total_tokens,
+                cost_usd,
+                success,
+                error_message,
+                error_type: None,
+                request_source: "anthropic".to_string(),
+                tags: Default::default(),
+            };
+            let _ = telemetry.record_request(trace).await;
+        }
+
+        match result {
+            Ok((content, _, _)) => Ok(content),
+            Err(e) => bail!("Anthropic request failed after retries: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_defaults_to_anthropic_base_url() {
+        let client = AnthropicClient::new("sk-ant-test", "claude-3-5-sonnet-latest");
+        assert_eq!(client.base_url, "https://api.anthropic.com");
+        assert_eq!(client.max_tokens, 2048);
+    }
+
+    #[test]
+    fn with_max_tokens_overrides_default() {
+        let client =
+            AnthropicClient::new("sk-ant-test", "claude-3-5-sonnet-latest").with_max_tokens(4096);
+        assert_eq!(client.max_tokens, 4096);
+    }
+}
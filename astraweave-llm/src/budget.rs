@@ -0,0 +1,216 @@
+//! Per-agent LLM budget and cost accounting.
+//!
+//! Meant to be inserted as an ECS resource (e.g.
+//! `world.insert_resource(LlmBudgetManager::new(config))`) so a planning
+//! system can check [`LlmBudgetManager::has_budget`] before spending a
+//! request on a given agent this frame. Complements
+//! [`crate::fallback_system::FallbackOrchestrator`]: once an agent (or the
+//! whole frame) exceeds its budget, callers should skip straight to
+//! [`FallbackTier::Heuristic`] rather than spending another LLM call, so a
+//! large scene can't silently blow up latency or API cost.
+
+use crate::batch_executor::AgentId;
+use crate::fallback_system::FallbackTier;
+use std::collections::HashMap;
+
+/// Budget limits enforced by [`LlmBudgetManager`].
+#[derive(Debug, Clone, Copy)]
+pub struct BudgetConfig {
+    /// Max tokens a single agent may spend in one frame before it's routed
+    /// to the heuristic tier.
+    pub max_tokens_per_agent_per_frame: u64,
+    /// Max LLM requests a single agent may issue in one frame.
+    pub max_requests_per_agent_per_frame: u32,
+    /// Max total tokens all agents combined may spend in one frame.
+    pub max_tokens_per_frame: u64,
+}
+
+impl Default for BudgetConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens_per_agent_per_frame: 4_000,
+            max_requests_per_agent_per_frame: 1,
+            max_tokens_per_frame: 200_000,
+        }
+    }
+}
+
+/// Running totals for a single agent within the current frame.
+#[derive(Debug, Clone, Copy, Default)]
+struct AgentUsage {
+    tokens: u64,
+    requests: u32,
+}
+
+/// Cumulative metrics exposed for dashboards/telemetry. Unlike per-frame
+/// usage, these accumulate for the lifetime of the [`LlmBudgetManager`].
+#[derive(Debug, Clone, Default)]
+pub struct BudgetMetrics {
+    pub tokens_used_total: u64,
+    pub requests_total: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub tier_counts: HashMap<&'static str, u64>,
+}
+
+/// Tracks LLM token/request spend per agent and per frame, enforcing
+/// [`BudgetConfig`] limits.
+#[derive(Debug, Default)]
+pub struct LlmBudgetManager {
+    config: BudgetConfig,
+    per_agent: HashMap<AgentId, AgentUsage>,
+    frame_tokens: u64,
+    metrics: BudgetMetrics,
+}
+
+impl LlmBudgetManager {
+    pub fn new(config: BudgetConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Reset per-agent and per-frame counters at the start of a new tick.
+    /// Cumulative [`Self::metrics`] are left untouched.
+    pub fn begin_frame(&mut self) {
+        self.per_agent.clear();
+        self.frame_tokens = 0;
+    }
+
+    /// True if `agent` still has budget for another LLM request this frame.
+    /// Callers should fall back to [`FallbackTier::Heuristic`] when this
+    /// returns `false` rather than spending another request.
+    pub fn has_budget(&self, agent: AgentId) -> bool {
+        if self.frame_tokens >= self.config.max_tokens_per_frame {
+            return false;
+        }
+        let usage = self.per_agent.get(&agent).copied().unwrap_or_default();
+        usage.requests < self.config.max_requests_per_agent_per_frame
+            && usage.tokens < self.config.max_tokens_per_agent_per_frame
+    }
+
+    /// Record that `agent` spent `tokens` on an LLM request resolved at
+    /// `tier`, updating per-frame counters and cumulative metrics.
+    pub fn record_request(&mut self, agent: AgentId, tokens: u64, tier: FallbackTier) {
+        let usage = self.per_agent.entry(agent).or_default();
+        usage.tokens += tokens;
+        usage.requests += 1;
+        self.frame_tokens += tokens;
+
+        self.metrics.tokens_used_total += tokens;
+        self.metrics.requests_total += 1;
+        *self.metrics.tier_counts.entry(tier.as_str()).or_insert(0) += 1;
+    }
+
+    pub fn record_cache_hit(&mut self) {
+        self.metrics.cache_hits += 1;
+    }
+
+    pub fn record_cache_miss(&mut self) {
+        self.metrics.cache_misses += 1;
+    }
+
+    /// Fraction of recorded lookups that hit the cache; `0.0` if there have
+    /// been none yet.
+    pub fn cache_hit_rate(&self) -> f32 {
+        let total = self.metrics.cache_hits + self.metrics.cache_misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.metrics.cache_hits as f32 / total as f32
+        }
+    }
+
+    pub fn metrics(&self) -> &BudgetMetrics {
+        &self.metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tight_config() -> BudgetConfig {
+        BudgetConfig {
+            max_tokens_per_agent_per_frame: 100,
+            max_requests_per_agent_per_frame: 2,
+            max_tokens_per_frame: 150,
+        }
+    }
+
+    #[test]
+    fn fresh_agent_has_budget() {
+        let mgr = LlmBudgetManager::new(BudgetConfig::default());
+        assert!(mgr.has_budget(1));
+    }
+
+    #[test]
+    fn agent_loses_budget_after_token_limit() {
+        let mut mgr = LlmBudgetManager::new(tight_config());
+        mgr.record_request(1, 100, FallbackTier::FullLlm);
+        assert!(!mgr.has_budget(1));
+    }
+
+    #[test]
+    fn agent_loses_budget_after_request_limit() {
+        let mut mgr = LlmBudgetManager::new(tight_config());
+        mgr.record_request(1, 1, FallbackTier::FullLlm);
+        mgr.record_request(1, 1, FallbackTier::FullLlm);
+        assert!(!mgr.has_budget(1));
+    }
+
+    #[test]
+    fn frame_budget_caps_all_agents_combined() {
+        let mut mgr = LlmBudgetManager::new(tight_config());
+        mgr.record_request(1, 80, FallbackTier::FullLlm);
+        mgr.record_request(2, 80, FallbackTier::FullLlm);
+        assert!(!mgr.has_budget(3));
+    }
+
+    #[test]
+    fn begin_frame_resets_per_agent_and_frame_usage() {
+        let mut mgr = LlmBudgetManager::new(tight_config());
+        mgr.record_request(1, 100, FallbackTier::FullLlm);
+        assert!(!mgr.has_budget(1));
+
+        mgr.begin_frame();
+        assert!(mgr.has_budget(1));
+    }
+
+    #[test]
+    fn begin_frame_preserves_cumulative_metrics() {
+        let mut mgr = LlmBudgetManager::new(tight_config());
+        mgr.record_request(1, 50, FallbackTier::FullLlm);
+        mgr.begin_frame();
+        assert_eq!(mgr.metrics().tokens_used_total, 50);
+        assert_eq!(mgr.metrics().requests_total, 1);
+    }
+
+    #[test]
+    fn record_request_tracks_tier_distribution() {
+        let mut mgr = LlmBudgetManager::new(BudgetConfig::default());
+        mgr.record_request(1, 10, FallbackTier::FullLlm);
+        mgr.record_request(2, 10, FallbackTier::Heuristic);
+        mgr.record_request(3, 10, FallbackTier::Heuristic);
+
+        let metrics = mgr.metrics();
+        assert_eq!(metrics.tier_counts.get("full_llm"), Some(&1));
+        assert_eq!(metrics.tier_counts.get("heuristic"), Some(&2));
+    }
+
+    #[test]
+    fn cache_hit_rate_computes_fraction() {
+        let mut mgr = LlmBudgetManager::new(BudgetConfig::default());
+        mgr.record_cache_hit();
+        mgr.record_cache_hit();
+        mgr.record_cache_miss();
+        assert!((mgr.cache_hit_rate() - (2.0 / 3.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cache_hit_rate_is_zero_with_no_lookups() {
+        let mgr = LlmBudgetManager::new(BudgetConfig::default());
+        assert_eq!(mgr.cache_hit_rate(), 0.0);
+    }
+}
@@ -79,6 +79,34 @@ impl PromptKey {
             normalized_prompt: String::new(), // Test keys don't need normalized text
         }
     }
+
+    /// Reconstruct a key from its persisted components (see
+    /// `cache::persistence`). Not derived from a live prompt, so
+    /// `normalized_prompt` must be supplied separately if similarity
+    /// matching against the restored entry is needed.
+    pub(crate) fn from_parts(
+        prompt_hash: u64,
+        model: String,
+        temperature_q: u32,
+        tools_hash: u64,
+        normalized_prompt: String,
+    ) -> Self {
+        Self {
+            prompt_hash,
+            model,
+            temperature_q,
+            tools_hash,
+            normalized_prompt,
+        }
+    }
+
+    pub(crate) fn prompt_hash(&self) -> u64 {
+        self.prompt_hash
+    }
+
+    pub(crate) fn tools_hash(&self) -> u64 {
+        self.tools_hash
+    }
 }
 
 /// Normalize a prompt for stable hashing
@@ -6,10 +6,13 @@ use std::time::Instant;
 
 pub mod key;
 pub mod lru;
+pub mod persistence; // Disk-backed persistence with LRU-aware compaction
+pub mod semantic_key; // Quantized WorldSnapshot features as a cache key
 pub mod similarity; // Phase 7: Semantic similarity matching
 
 pub use key::PromptKey;
 pub use lru::LruCache;
+pub use semantic_key::{SemanticCacheKey, SemanticKeyConfig, SemanticPromptCache};
 pub use similarity::{prompt_similarity, DEFAULT_SIMILARITY_THRESHOLD};
 
 /// A cached plan with metadata
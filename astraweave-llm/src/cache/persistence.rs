@@ -0,0 +1,235 @@
+// Disk persistence for `PromptCache`, so warm plans survive process restarts.
+//
+// Entries are stored as an append-only JSON-lines file, one `PersistedEntry`
+// per line. `PromptCache::load_from_disk` replays the file to repopulate the
+// in-memory LRU; `PromptCache::compact_to_disk` rewrites the file from the
+// current cache contents so it doesn't grow unbounded across many dev
+// sessions.
+
+use super::{CacheDecision, CachedPlan, PromptCache, PromptKey};
+use anyhow::{Context, Result};
+use astraweave_core::PlanIntent;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    prompt_hash: u64,
+    model: String,
+    temperature_q: u32,
+    tools_hash: u64,
+    normalized_prompt: String,
+    plan: PlanIntent,
+    /// Milliseconds since UNIX epoch, since `Instant` isn't serializable.
+    created_at_ms: u64,
+    tokens_saved: u32,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+impl PromptCache {
+    /// Load a cache from a previously persisted JSON-lines file. Missing
+    /// files are treated as an empty cache (first run), matching the
+    /// behavior callers expect from a warm-start cache.
+    pub fn load_from_disk(path: impl AsRef<Path>, capacity: usize) -> Result<Self> {
+        let cache = Self::new(capacity);
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(cache);
+        }
+
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("failed to open prompt cache file {}", path.display()))?;
+        for line in BufReader::new(file).lines() {
+            let line = line.context("failed to read prompt cache line")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: PersistedEntry = match serde_json::from_str(&line) {
+                Ok(e) => e,
+                Err(_) => continue, // Skip corrupt/partial lines rather than failing the whole load.
+            };
+            let key = PromptKey::from_parts(
+                entry.prompt_hash,
+                entry.model,
+                entry.temperature_q,
+                entry.tools_hash,
+                entry.normalized_prompt,
+            );
+            let age = Duration::from_millis(now_ms().saturating_sub(entry.created_at_ms));
+            let cached = CachedPlan {
+                plan: entry.plan,
+                created_at: Instant::now().checked_sub(age).unwrap_or_else(Instant::now),
+                tokens_saved: entry.tokens_saved,
+            };
+            cache.put(key, cached);
+        }
+        Ok(cache)
+    }
+
+    /// Append the given cache entry to `path` (creating it if needed). Call
+    /// this after each successful [`PromptCache::put`] to keep the on-disk
+    /// log up to date without rewriting the whole file.
+    pub fn append_entry_to_disk(
+        path: impl AsRef<Path>,
+        key: &PromptKey,
+        cached: &CachedPlan,
+    ) -> Result<()> {
+        let entry = PersistedEntry {
+            prompt_hash: key.prompt_hash(),
+            model: key.model.clone(),
+            temperature_q: key.temperature_q,
+            tools_hash: key.tools_hash(),
+            normalized_prompt: key.normalized_prompt.clone(),
+            plan: cached.plan.clone(),
+            created_at_ms: now_ms(),
+            tokens_saved: cached.tokens_saved,
+        };
+        let line = serde_json::to_string(&entry)?;
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .with_context(|| format!("failed to open {} for append", path.as_ref().display()))?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+
+    /// Rewrite `path` to contain only entries currently resident in the
+    /// in-memory cache, discarding history for keys that were evicted.
+    /// Intended to be called periodically (e.g. on a background timer or
+    /// at shutdown) rather than after every write.
+    pub fn compact_to_disk(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut file = std::fs::File::create(&tmp_path)
+                .with_context(|| format!("failed to create {}", tmp_path.display()))?;
+            for key in self.cache_keys() {
+                if let Some(cached) = self.get_raw(&key) {
+                    let entry = PersistedEntry {
+                        prompt_hash: key.prompt_hash(),
+                        model: key.model.clone(),
+                        temperature_q: key.temperature_q,
+                        tools_hash: key.tools_hash(),
+                        normalized_prompt: key.normalized_prompt.clone(),
+                        plan: cached.plan.clone(),
+                        created_at_ms: now_ms()
+                            .saturating_sub(cached.created_at.elapsed().as_millis() as u64),
+                        tokens_saved: cached.tokens_saved,
+                    };
+                    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+                }
+            }
+        }
+        std::fs::rename(&tmp_path, path)
+            .with_context(|| format!("failed to finalize compacted cache file {}", path.display()))?;
+        Ok(())
+    }
+
+    fn cache_keys(&self) -> Vec<PromptKey> {
+        self.cache.keys()
+    }
+
+    /// Fetch a cached entry without touching hit/miss metrics, used only by
+    /// the compaction sweep above.
+    fn get_raw(&self, key: &PromptKey) -> Option<CachedPlan> {
+        self.cache.get(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::ActionStep;
+
+    fn make_plan(id: &str) -> PlanIntent {
+        PlanIntent {
+            plan_id: id.to_string(),
+            steps: vec![ActionStep::MoveTo {
+                x: 1,
+                y: 2,
+                speed: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn load_from_missing_file_is_empty() {
+        let cache = PromptCache::load_from_disk("/tmp/does-not-exist-astraweave.jsonl", 10).unwrap();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn round_trips_entries_through_disk() {
+        let dir = std::env::temp_dir().join(format!(
+            "aw-cache-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cache.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let key = PromptKey::new("hello world", "model-a", 0.7, &["MoveTo"]);
+        let cached = CachedPlan {
+            plan: make_plan("p1"),
+            created_at: Instant::now(),
+            tokens_saved: 42,
+        };
+        PromptCache::append_entry_to_disk(&path, &key, &cached).unwrap();
+
+        let loaded = PromptCache::load_from_disk(&path, 10).unwrap();
+        let (found, decision) = loaded.get(&key).expect("entry should round-trip");
+        assert_eq!(decision, CacheDecision::HitExact);
+        assert_eq!(found.plan.plan_id, "p1");
+        assert_eq!(found.tokens_saved, 42);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn compact_drops_evicted_entries() {
+        let dir = std::env::temp_dir().join(format!(
+            "aw-cache-compact-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cache.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let cache = PromptCache::new(1); // capacity 1 forces eviction
+        let key1 = PromptKey::new("first", "m", 0.5, &[]);
+        let key2 = PromptKey::new("second", "m", 0.5, &[]);
+        cache.put(
+            key1.clone(),
+            CachedPlan {
+                plan: make_plan("first"),
+                created_at: Instant::now(),
+                tokens_saved: 1,
+            },
+        );
+        cache.put(
+            key2.clone(),
+            CachedPlan {
+                plan: make_plan("second"),
+                created_at: Instant::now(),
+                tokens_saved: 1,
+            },
+        );
+
+        cache.compact_to_disk(&path).unwrap();
+        let reloaded = PromptCache::load_from_disk(&path, 10).unwrap();
+        assert!(reloaded.get(&key1).is_none(), "evicted entry should be gone");
+        assert!(reloaded.get(&key2).is_some());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
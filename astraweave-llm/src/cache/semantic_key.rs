@@ -0,0 +1,320 @@
+// Semantic cache keys derived from quantized WorldSnapshot features.
+//
+// `PromptKey` hashes the full rendered prompt text, so a companion one
+// tile over from where it stood last tick produces a completely
+// different key and always misses. `SemanticCacheKey` instead buckets
+// the parts of a `WorldSnapshot` that actually change tactical planning
+// -- how far the nearest enemy is, roughly how many enemies there are,
+// what the objective is -- so two snapshots that are "close enough"
+// under a configurable tolerance hash identically and share a cached
+// plan.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use astraweave_core::WorldSnapshot;
+
+/// Tolerance settings for quantizing a [`WorldSnapshot`] into a
+/// [`SemanticCacheKey`]. Larger bucket sizes trade cache precision for
+/// hit rate.
+#[derive(Debug, Clone)]
+pub struct SemanticKeyConfig {
+    /// Manhattan-distance bucket width for the nearest-enemy distance,
+    /// e.g. `5` groups distances 0-4 into one bucket, 5-9 into the next.
+    pub distance_bucket_size: i32,
+    /// Enemy-count boundaries; counts are mapped to the index of the
+    /// first boundary they don't exceed (last bucket catches everything
+    /// above the final boundary). E.g. `[0, 2, 5]` yields buckets
+    /// `0`, `1-2`, `3-5`, `6+`.
+    pub enemy_count_boundaries: Vec<u32>,
+    /// Temperature quantization step. [`crate::cache::PromptKey`] always
+    /// quantizes to 0.01; this step is configurable and defaults to 0.1.
+    pub temperature_bucket: f32,
+}
+
+impl Default for SemanticKeyConfig {
+    fn default() -> Self {
+        Self {
+            distance_bucket_size: 5,
+            enemy_count_boundaries: vec![0, 2, 5],
+            temperature_bucket: 0.1,
+        }
+    }
+}
+
+/// A cache key built from quantized [`WorldSnapshot`] features rather
+/// than exact prompt text. Two snapshots that quantize to the same
+/// bucket values share a cache entry even if their raw positions or
+/// prompt text differ.
+#[derive(Debug, Clone)]
+pub struct SemanticCacheKey {
+    model: String,
+    temperature_q: u32,
+    nearest_enemy_distance_bucket: Option<i32>,
+    enemy_count_category: usize,
+    objective_id: u64,
+}
+
+impl Hash for SemanticCacheKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.model.hash(state);
+        self.temperature_q.hash(state);
+        self.nearest_enemy_distance_bucket.hash(state);
+        self.enemy_count_category.hash(state);
+        self.objective_id.hash(state);
+    }
+}
+
+impl PartialEq for SemanticCacheKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.model == other.model
+            && self.temperature_q == other.temperature_q
+            && self.nearest_enemy_distance_bucket == other.nearest_enemy_distance_bucket
+            && self.enemy_count_category == other.enemy_count_category
+            && self.objective_id == other.objective_id
+    }
+}
+
+impl Eq for SemanticCacheKey {}
+
+impl SemanticCacheKey {
+    /// Quantizes `snapshot` into a semantic cache key under `config`'s
+    /// tolerances.
+    pub fn from_snapshot(
+        snapshot: &WorldSnapshot,
+        model: &str,
+        temperature: f32,
+        config: &SemanticKeyConfig,
+    ) -> Self {
+        let nearest_enemy_distance_bucket = snapshot
+            .enemies
+            .iter()
+            .map(|e| e.pos.manhattan_distance(&snapshot.me.pos))
+            .min()
+            .map(|d| bucket(d, config.distance_bucket_size));
+
+        let enemy_count_category = enemy_count_category(
+            snapshot.enemies.len() as u32,
+            &config.enemy_count_boundaries,
+        );
+
+        let objective_id = hash_objective(snapshot.objective.as_deref());
+
+        Self {
+            model: model.to_string(),
+            temperature_q: quantize_temperature(temperature, config.temperature_bucket),
+            nearest_enemy_distance_bucket,
+            enemy_count_category,
+            objective_id,
+        }
+    }
+}
+
+fn quantize_temperature(temperature: f32, bucket: f32) -> u32 {
+    (temperature / bucket).round() as u32
+}
+
+fn bucket(value: i32, bucket_size: i32) -> i32 {
+    if bucket_size <= 0 {
+        return value;
+    }
+    value.div_euclid(bucket_size)
+}
+
+fn enemy_count_category(count: u32, boundaries: &[u32]) -> usize {
+    boundaries
+        .iter()
+        .position(|&b| count <= b)
+        .unwrap_or(boundaries.len())
+}
+
+fn hash_objective(objective: Option<&str>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    objective.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A [`super::PromptCache`]-shaped cache keyed by [`SemanticCacheKey`]
+/// instead of exact prompt text.
+pub struct SemanticPromptCache {
+    cache: super::LruCache<SemanticCacheKey, super::CachedPlan>,
+    pub hits: std::sync::atomic::AtomicU64,
+    pub misses: std::sync::atomic::AtomicU64,
+}
+
+impl SemanticPromptCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            cache: super::LruCache::new(capacity),
+            hits: std::sync::atomic::AtomicU64::new(0),
+            misses: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &SemanticCacheKey) -> Option<super::CachedPlan> {
+        let hit = self.cache.get(key);
+        if hit.is_some() {
+            self.hits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        } else {
+            self.misses
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+        hit
+    }
+
+    pub fn put(&self, key: SemanticCacheKey, plan: super::CachedPlan) {
+        self.cache.put(key, plan);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{CompanionState, EnemyState, IVec2, PlayerState};
+
+    fn snapshot_with(enemy_positions: &[(i32, i32)], objective: Option<&str>) -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2::new(0, 0),
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: Default::default(),
+                morale: 1.0,
+                pos: IVec2::new(0, 0),
+            },
+            enemies: enemy_positions
+                .iter()
+                .enumerate()
+                .map(|(i, &(x, y))| EnemyState {
+                    id: i as u32,
+                    pos: IVec2::new(x, y),
+                    hp: 50,
+                    cover: "none".into(),
+                    last_seen: 0.0,
+                })
+                .collect(),
+            pois: vec![],
+            obstacles: vec![],
+            objective: objective.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn nearby_snapshots_with_the_same_features_produce_equal_keys() {
+        let config = SemanticKeyConfig::default();
+        let a = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(3, 0)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        let b = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(4, 0)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        assert_eq!(a, b, "distances 3 and 4 fall in the same bucket of size 5");
+    }
+
+    #[test]
+    fn distances_in_different_buckets_produce_different_keys() {
+        let config = SemanticKeyConfig::default();
+        let a = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(3, 0)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        let b = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(9, 0)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn different_enemy_count_categories_produce_different_keys() {
+        let config = SemanticKeyConfig::default();
+        let a = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(3, 0)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        let b = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[(3, 0), (3, 1), (3, 2)], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn different_objectives_produce_different_keys() {
+        let config = SemanticKeyConfig::default();
+        let a = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[], Some("patrol")),
+            "model1",
+            0.7,
+            &config,
+        );
+        let b = SemanticCacheKey::from_snapshot(
+            &snapshot_with(&[], Some("defend")),
+            "model1",
+            0.7,
+            &config,
+        );
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn semantic_cache_hits_on_a_quantized_match() {
+        use crate::cache::CachedPlan;
+        use astraweave_core::{ActionStep, PlanIntent};
+
+        let cache = SemanticPromptCache::new(10);
+        let config = SemanticKeyConfig::default();
+        let key1 =
+            SemanticCacheKey::from_snapshot(&snapshot_with(&[(3, 0)], Some("patrol")), "m", 0.7, &config);
+        let key2 =
+            SemanticCacheKey::from_snapshot(&snapshot_with(&[(4, 0)], Some("patrol")), "m", 0.7, &config);
+
+        assert!(cache.get(&key1).is_none());
+        cache.put(
+            key1,
+            CachedPlan {
+                plan: PlanIntent {
+                    plan_id: "p1".into(),
+                    steps: vec![ActionStep::MoveTo {
+                        x: 1,
+                        y: 1,
+                        speed: None,
+                    }],
+                },
+                created_at: std::time::Instant::now(),
+                tokens_saved: 50,
+            },
+        );
+
+        let hit = cache.get(&key2);
+        assert!(hit.is_some(), "key2 should hit key1's bucket");
+        assert_eq!(cache.hits.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+}
@@ -228,7 +228,7 @@ pub struct CircuitBreakerResult<T> {
 }
 
 /// Circuit breaker status for monitoring
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CircuitBreakerStatus {
     pub model: String,
     pub state: CircuitState,
@@ -179,6 +179,69 @@ Snapshot: {snap}"#,
             snap = Self::snapshot_to_compact_json(snapshot)
         )
     }
+
+    /// Like [`Self::build_optimized_prompt`], but summarizes `snapshot`'s
+    /// obstacles and POIs first (see [`crate::snapshot_summary`]) instead of
+    /// serializing every cell, so large maps don't dominate the prompt.
+    /// `registry`'s `enforce_los` constraint controls how much near-field
+    /// obstacle precision is preserved so cover/LOS-dependent plans remain legal.
+    pub fn build_summarized_prompt(
+        snapshot: &WorldSnapshot,
+        tool_list: &str,
+        role: &str,
+        registry: &astraweave_core::ToolRegistry,
+        config: &crate::snapshot_summary::SummaryConfig,
+    ) -> String {
+        let system = match role {
+            "tactical" => Self::compress_tactical_prompt(),
+            "stealth" => Self::compress_stealth_prompt(),
+            "support" => Self::compress_support_prompt(),
+            "exploration" => Self::compress_exploration_prompt(),
+            _ => Self::compress_tactical_prompt(), // Default
+        };
+
+        let summary = crate::snapshot_summary::summarize_snapshot(snapshot, registry, config);
+        let plr = json!({
+            "pos": [snapshot.player.pos.x, snapshot.player.pos.y],
+            "hp": snapshot.player.hp,
+            "stance": snapshot.player.stance,
+        });
+        let me = json!({
+            "pos": [snapshot.me.pos.x, snapshot.me.pos.y],
+            "morale": snapshot.me.morale,
+            "cooldowns": snapshot.me.cooldowns,
+            "ammo": snapshot.me.ammo,
+        });
+        let enemies = snapshot
+            .enemies
+            .iter()
+            .map(|e| {
+                json!({
+                    "id": e.id,
+                    "pos": [e.pos.x, e.pos.y],
+                    "hp": e.hp,
+                    "cover": e.cover,
+                    "seen": e.last_seen,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            r#"{system}
+
+Tools: {tools}
+
+Snapshot: {{"plr":{plr},"me":{me},"enemies":{enemies}}}
+
+{summary}"#,
+            system = system,
+            tools = tool_list,
+            plr = plr,
+            me = me,
+            enemies = json!(enemies),
+            summary = crate::snapshot_summary::render_summary(&summary),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -557,6 +620,23 @@ mod tests {
         assert!(prompt.contains("\"me\""));
     }
 
+    #[test]
+    fn test_build_summarized_prompt_omits_far_obstacles_with_a_marker() {
+        let mut snapshot = create_test_snapshot();
+        snapshot.obstacles = (0..20).map(|i| IVec2 { x: i * 100, y: i * 100 }).collect();
+        let registry = astraweave_core::default_tool_registry();
+        let config = crate::snapshot_summary::SummaryConfig {
+            max_obstacle_clusters: 3,
+            ..Default::default()
+        };
+
+        let prompt =
+            PromptCompressor::build_summarized_prompt(&snapshot, "MoveTo", "tactical", &registry, &config);
+
+        assert!(prompt.contains("Tactical AI"));
+        assert!(prompt.contains("more obstacles omitted"));
+    }
+
     // ============================================================
     // Constant Tests
     // ============================================================
@@ -7,27 +7,38 @@ use tracing::{debug, info};
 pub mod ab_testing;
 pub mod backpressure;
 pub mod batch_executor;
+pub mod budget;
 pub mod circuit_breaker;
 pub mod compression;
 pub mod fallback_system;
 pub mod few_shot;
 pub mod heuristics;
 pub mod llm_adapter;
+pub mod memory_recall;
+pub mod moderation;
 pub mod plan_parser;
+pub mod plan_quality;
 pub mod production_hardening;
 pub mod prompt_template;
 pub mod prompts;
 pub mod rate_limiter;
+pub mod replay;
 pub mod retry;
+pub mod router;
 pub mod scheduler;
 pub mod schema;
+pub mod snapshot_diff;
+pub mod snapshot_summary;
 pub mod streaming_parser;
 pub mod telemetry;
+pub mod token_budget;
 pub mod tool_guard;
 
 // --- Optional LLM cache (always available by default) ---
 #[cfg(feature = "llm_cache")]
 pub mod cache;
+#[cfg(feature = "llm_cache")]
+pub mod speculative;
 
 // --- Phi-3 local model (optional, requires heavy GPU/CPU dependencies) ---
 #[cfg(feature = "phi3")]
@@ -37,10 +48,18 @@ pub mod phi3;
 #[cfg(feature = "ollama")]
 pub mod hermes2pro_ollama;
 #[cfg(feature = "ollama")]
+pub mod ollama_admin;
+#[cfg(feature = "ollama")]
 pub mod phi3_ollama;
 #[cfg(feature = "ollama")]
 pub mod qwen3_ollama;
 
+// --- Cloud model providers (optional, requires reqwest) ---
+#[cfg(feature = "anthropic")]
+pub mod anthropic_client;
+#[cfg(feature = "openai")]
+pub mod openai_client;
+
 #[cfg(feature = "llm_cache")]
 use cache::{CachedPlan, PromptCache, PromptKey};
 #[cfg(feature = "llm_cache")]
@@ -238,6 +257,7 @@ pub struct OllamaChatClient {
     keep_alive: Option<String>, // e.g. "5m" to keep model in RAM
     force_format_json: bool,    // add format: "json" to requests
     early_exit_on_json: bool,   // return as soon as a balanced JSON object is detected
+    json_schema: Option<crate::schema::JsonSchemaConstraint>, // structured decoding constraint
 }
 
 #[cfg(feature = "ollama")]
@@ -270,9 +290,22 @@ impl OllamaChatClient {
             keep_alive,
             force_format_json,
             early_exit_on_json,
+            json_schema: None,
         }
     }
 
+    /// Constrain decoding to the given JSON Schema. When set, the schema is
+    /// sent as the streaming request's `format` field (Ollama natively
+    /// supports a JSON Schema object there, not just `"json"`) instead of
+    /// the coarser `force_format_json` flag. The buffered non-streaming
+    /// attempt, made only when low-latency mode is off, sends no `format`.
+    /// Either way the response is validated against the schema before
+    /// [`LlmClient::complete`] returns it.
+    pub fn with_json_schema(mut self, schema: crate::schema::JsonSchemaConstraint) -> Self {
+        self.json_schema = Some(schema);
+        self
+    }
+
     /// Warm up the model to minimize cold-start latency. Attempts a tiny generation and requests the model to remain in memory.
     pub async fn warmup(&self, timeout_secs: u64) -> Result<()> {
         #[derive(serde::Serialize)]
@@ -335,9 +368,8 @@ impl OllamaChatClient {
 }
 
 #[cfg(feature = "ollama")]
-#[async_trait::async_trait]
-impl LlmClient for OllamaChatClient {
-    async fn complete(&self, prompt: &str) -> Result<String> {
+impl OllamaChatClient {
+    async fn complete_inner(&self, prompt: &str) -> Result<String> {
         #[derive(serde::Serialize)]
         struct Msg<'a> {
             role: &'a str,
@@ -484,7 +516,9 @@ impl LlmClient for OllamaChatClient {
                 "stream": true,
                 "options": { "temperature": 0.1, "num_predict": 512 }
             });
-            if self.force_format_json {
+            if let Some(constraint) = &self.json_schema {
+                stream_body["format"] = constraint.as_value().clone();
+            } else if self.force_format_json {
                 stream_body["format"] = serde_json::json!("json");
             }
             if let Some(ka) = &self.keep_alive {
@@ -835,6 +869,20 @@ impl LlmClient for OllamaChatClient {
     }
 }
 
+#[cfg(feature = "ollama")]
+#[async_trait::async_trait]
+impl LlmClient for OllamaChatClient {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let text = self.complete_inner(prompt).await?;
+        if let Some(constraint) = &self.json_schema {
+            constraint
+                .validate(&text)
+                .map_err(|e| anyhow::anyhow!("response failed JSON schema constraint: {}", e))?;
+        }
+        Ok(text)
+    }
+}
+
 /// A simple local HTTP LLM client that can work with any OpenAI-compatible API
 /// This includes local services like text-generation-webui, LocalAI, etc.
 #[cfg(feature = "ollama")]
@@ -842,6 +890,7 @@ pub struct LocalHttpClient {
     pub url: String,
     pub model: String,
     pub api_key: Option<String>,
+    json_schema: Option<crate::schema::JsonSchemaConstraint>,
 }
 
 #[cfg(feature = "ollama")]
@@ -852,6 +901,7 @@ impl LocalHttpClient {
             url,
             model,
             api_key: None,
+            json_schema: None,
         }
     }
 
@@ -861,8 +911,17 @@ impl LocalHttpClient {
             url,
             model,
             api_key: Some(api_key),
+            json_schema: None,
         }
     }
+
+    /// Constrain decoding via the OpenAI-compatible `response_format:
+    /// {"type": "json_schema", ...}` parameter, and validate the response
+    /// against the same schema before returning it.
+    pub fn with_json_schema(mut self, schema: crate::schema::JsonSchemaConstraint) -> Self {
+        self.json_schema = Some(schema);
+        self
+    }
 }
 
 #[cfg(feature = "ollama")]
@@ -881,6 +940,8 @@ impl LlmClient for LocalHttpClient {
             messages: Vec<Message>,
             max_tokens: u32,
             temperature: f32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            response_format: Option<serde_json::Value>,
         }
 
         #[derive(serde::Deserialize)]
@@ -893,6 +954,13 @@ impl LlmClient for LocalHttpClient {
             choices: Vec<Choice>,
         }
 
+        let response_format = self.json_schema.as_ref().map(|c| {
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": { "name": "plan", "schema": c.as_value() }
+            })
+        });
+
         let body = Req {
             model: self.model.clone(),
             messages: vec![Message {
@@ -901,6 +969,7 @@ impl LlmClient for LocalHttpClient {
             }],
             max_tokens: 2048,
             temperature: 0.1, // Low temperature for more consistent JSON output
+            response_format,
         };
 
         let mut request = reqwest::Client::new()
@@ -933,7 +1002,13 @@ impl LlmClient for LocalHttpClient {
             bail!("Local LLM returned no choices");
         }
 
-        Ok(parsed.choices[0].message.content.clone())
+        let content = parsed.choices[0].message.content.clone();
+        if let Some(constraint) = &self.json_schema {
+            constraint
+                .validate(&content)
+                .map_err(|e| anyhow::anyhow!("response failed JSON schema constraint: {}", e))?;
+        }
+        Ok(content)
     }
 }
 
@@ -1182,34 +1257,51 @@ pub fn sanitize_plan(
     reg: &ToolRegistry,
 ) -> Result<()> {
     // Remove any steps that exceed bounds or use invalid targets
-    plan.steps.retain(|step| match step {
-        ActionStep::MoveTo { x, y, speed: _ } => {
-            // Check bounds (example: within 100 units)
-            (x.abs() <= MAX_COORD_BOUND && y.abs() <= MAX_COORD_BOUND)
-                && reg.tools.iter().any(|t| t.name == "MoveTo")
-        }
-        ActionStep::Throw { item, x, y } => {
-            // Check item is allowed
-            matches!(item.as_str(), "smoke" | "grenade")
-                && (x.abs() <= MAX_COORD_BOUND && y.abs() <= MAX_COORD_BOUND)
-                && reg.tools.iter().any(|t| t.name == "Throw")
-        }
-        ActionStep::CoverFire {
-            target_id,
-            duration,
-        } => {
-            // Check target exists and duration reasonable
-            snap.enemies.iter().any(|e| e.id == *target_id)
-                && *duration > 0.0
-                && *duration <= 10.0
-                && reg.tools.iter().any(|t| t.name == "CoverFire")
-        }
-        ActionStep::Revive { ally_id: _ } => {
-            // Check ally exists (simplified: allow any ally for now, or validate against known ally IDs)
-            reg.tools.iter().any(|t| t.name == "Revive")
+    plan.steps.retain(|step| {
+        let structurally_valid = match step {
+            ActionStep::MoveTo { x, y, speed: _ } => {
+                // Check bounds (example: within 100 units)
+                (x.abs() <= MAX_COORD_BOUND && y.abs() <= MAX_COORD_BOUND)
+                    && reg.tools.iter().any(|t| t.name == "MoveTo")
+            }
+            ActionStep::Throw { item, x, y } => {
+                // Check item is allowed
+                matches!(item.as_str(), "smoke" | "grenade")
+                    && (x.abs() <= MAX_COORD_BOUND && y.abs() <= MAX_COORD_BOUND)
+                    && reg.tools.iter().any(|t| t.name == "Throw")
+            }
+            ActionStep::CoverFire {
+                target_id,
+                duration,
+            } => {
+                // Check target exists and duration reasonable
+                snap.enemies.iter().any(|e| e.id == *target_id)
+                    && *duration > 0.0
+                    && *duration <= 10.0
+                    && reg.tools.iter().any(|t| t.name == "CoverFire")
+            }
+            ActionStep::Revive { ally_id: _ } => {
+                // Check ally exists (simplified: allow any ally for now, or validate against known ally IDs)
+                reg.tools.iter().any(|t| t.name == "Revive")
+            }
+            // Phase 7: Accept all new tool types (validation happens in execution layer)
+            _ => true,
+        };
+        if !structurally_valid {
+            return false;
         }
-        // Phase 7: Accept all new tool types (validation happens in execution layer)
-        _ => true,
+
+        // Cooldown enforcement against the shared cost table (see
+        // `astraweave_core::constraint_engine`); stamina isn't tracked in
+        // `WorldSnapshot` yet, so that half is left to the runtime
+        // execution bridge, which does have per-agent stamina.
+        astraweave_core::constraint_engine::check_action_cost(
+            &snap.me.cooldowns,
+            None,
+            step,
+            &reg.constraints,
+        )
+        .is_ok()
     });
     Ok(())
 }
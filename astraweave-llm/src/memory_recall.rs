@@ -0,0 +1,169 @@
+//! Recall relevant past plans for prompt augmentation via
+//! [`astraweave_embeddings`]'s vector store.
+//!
+//! `build_prompt` alone only sees the current [`WorldSnapshot`]; agents that
+//! have handled a similar situation before can do better by seeing a few
+//! prior (situation, plan) pairs. This module stores completed plans keyed
+//! by an embedding of their triggering snapshot, and retrieves the closest
+//! ones to splice into the next prompt as few-shot examples.
+
+use astraweave_core::{PlanIntent, WorldSnapshot};
+use astraweave_embeddings::{EmbeddingClient, VectorStore};
+use std::sync::Arc;
+
+/// A remembered (situation, plan) pair, as returned by a recall query.
+#[derive(Debug, Clone)]
+pub struct RecalledPlan {
+    pub snapshot_summary: String,
+    pub plan: PlanIntent,
+    pub similarity: f32,
+}
+
+/// Vector-store-backed memory of past plans, keyed by an embedding of the
+/// world snapshot that produced them.
+pub struct PlanMemoryStore {
+    embedder: Arc<dyn EmbeddingClient>,
+    store: VectorStore,
+}
+
+impl PlanMemoryStore {
+    pub fn new(embedder: Arc<dyn EmbeddingClient>, dimensions: usize) -> Self {
+        Self {
+            embedder,
+            store: VectorStore::new(dimensions),
+        }
+    }
+
+    /// Summarize a snapshot into embeddable text. Kept separate from
+    /// [`crate::build_prompt`]'s full prompt so the embedding focuses on the
+    /// situation rather than tool listings/schema boilerplate.
+    fn summarize(snap: &WorldSnapshot) -> String {
+        format!(
+            "objective={:?} enemies={} me_ammo={} player_hp={}",
+            snap.objective,
+            snap.enemies.len(),
+            snap.me.ammo,
+            snap.player.hp
+        )
+    }
+
+    /// Record a plan that was produced (or approved) for `snap`, so future
+    /// similar situations can recall it.
+    pub async fn record(&self, plan_id_hint: &str, snap: &WorldSnapshot, plan: &PlanIntent) -> anyhow::Result<()> {
+        let summary = Self::summarize(snap);
+        let embedding = self.embedder.embed(&summary).await?;
+        let plan_json = serde_json::to_string(plan)?;
+        self.store.insert_with_metadata(
+            format!("{}-{}", plan_id_hint, plan.plan_id),
+            embedding,
+            summary,
+            1.0,
+            std::collections::HashMap::from([("plan_json".to_string(), plan_json)]),
+        )
+    }
+
+    /// Recall up to `k` past plans whose triggering situation is closest to
+    /// `snap`.
+    pub async fn recall(&self, snap: &WorldSnapshot, k: usize) -> anyhow::Result<Vec<RecalledPlan>> {
+        let summary = Self::summarize(snap);
+        let embedding = self.embedder.embed(&summary).await?;
+        let results = self.store.search(&embedding, k)?;
+
+        let mut recalled = Vec::with_capacity(results.len());
+        for result in results {
+            let Some(plan_json) = result.vector.metadata.get("plan_json") else {
+                continue;
+            };
+            let Ok(plan) = serde_json::from_str::<PlanIntent>(plan_json) else {
+                continue;
+            };
+            recalled.push(RecalledPlan {
+                snapshot_summary: result.vector.text,
+                plan,
+                similarity: result.score,
+            });
+        }
+        Ok(recalled)
+    }
+
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+}
+
+/// Render recalled plans as few-shot examples to prepend to a prompt.
+pub fn format_recalled_examples(recalled: &[RecalledPlan]) -> String {
+    if recalled.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("Similar past situations and the plans that worked:\n");
+    for r in recalled {
+        out.push_str(&format!(
+            "- situation: {}\n  plan: {}\n",
+            r.snapshot_summary,
+            serde_json::to_string(&r.plan).unwrap_or_default()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{ActionStep, CompanionState, IVec2, PlayerState, WorldSnapshot};
+    use astraweave_embeddings::client::MockEmbeddingClient;
+
+    fn snap() -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2 { x: 0, y: 0 },
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: Default::default(),
+                morale: 1.0,
+                pos: IVec2 { x: 1, y: 0 },
+            },
+            enemies: vec![],
+            pois: vec![],
+            obstacles: vec![],
+            objective: Some("extract".into()),
+        }
+    }
+
+    fn plan() -> PlanIntent {
+        PlanIntent {
+            plan_id: "p1".into(),
+            steps: vec![ActionStep::MoveTo {
+                x: 5,
+                y: 5,
+                speed: None,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn recall_returns_recorded_plan_for_identical_situation() {
+        let embedder: Arc<dyn EmbeddingClient> = Arc::new(MockEmbeddingClient::with_dimensions(8));
+        let store = PlanMemoryStore::new(embedder, 8);
+        let snapshot = snap();
+        store.record("agent1", &snapshot, &plan()).await.unwrap();
+
+        let recalled = store.recall(&snapshot, 3).await.unwrap();
+        assert_eq!(recalled.len(), 1);
+        assert_eq!(recalled[0].plan.plan_id, "p1");
+    }
+
+    #[test]
+    fn format_recalled_examples_empty_is_empty_string() {
+        assert_eq!(format_recalled_examples(&[]), "");
+    }
+}
@@ -0,0 +1,407 @@
+//! Output moderation for LLM-generated text reaching a player-facing surface
+//! (chat, dialogue, UI toasts).
+//!
+//! [`moderate_text`] runs a fast, synchronous pass over a blocklist and a set
+//! of regex [`ModerationCategory`] rules, producing a [`ModerationReport`]
+//! with the highest [`ModerationSeverity`] matched and, if
+//! [`ModerationConfig::redact`] is set, a redacted copy of the text.
+//! [`moderate_text_with_model`] additionally asks a secondary [`LlmClient`]
+//! to classify borderline text when [`ModerationConfig::model_check`] names
+//! one, for categories a fixed rule list can't reliably catch (e.g. subtle
+//! harassment). Every call updates [`ModerationTelemetry`], so a caller can
+//! track how often each severity fires without re-deriving it from logs.
+
+use anyhow::Result;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::LlmClient;
+
+/// What kind of disallowed content a moderation rule detects.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModerationCategory {
+    Profanity,
+    Harassment,
+    HateSpeech,
+    SelfHarm,
+    Violence,
+    /// A project-specific category not covered by the built-in ones.
+    Custom(String),
+}
+
+/// How severe a moderation match is, ordered from least to most severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ModerationSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// A single regex-based moderation rule.
+#[derive(Clone, Debug)]
+pub struct ModerationRule {
+    pub category: ModerationCategory,
+    pub pattern: Regex,
+    pub severity: ModerationSeverity,
+}
+
+impl ModerationRule {
+    /// Builds a rule from a raw regex pattern, matching case-insensitively.
+    pub fn new(category: ModerationCategory, pattern: &str, severity: ModerationSeverity) -> Result<Self> {
+        let pattern = Regex::new(&format!("(?i){pattern}"))?;
+        Ok(Self {
+            category,
+            pattern,
+            severity,
+        })
+    }
+}
+
+/// A single moderation-model classification hook. Implemented for any
+/// [`LlmClient`] via [`LlmModerationCheck`]; kept as a separate trait so
+/// callers can plug in a purpose-built classifier instead of a general
+/// [`LlmClient`] if they have one.
+#[async_trait::async_trait]
+pub trait ModerationModelCheck: Send + Sync {
+    /// Returns `Some((category, severity))` if the model judges `text`
+    /// disallowed, `None` if it judges the text clean.
+    async fn classify(&self, text: &str) -> Result<Option<(ModerationCategory, ModerationSeverity)>>;
+}
+
+/// Adapts any [`LlmClient`] into a [`ModerationModelCheck`] by asking it to
+/// classify the text with a fixed prompt and parsing a small JSON verdict
+/// out of the response.
+pub struct LlmModerationCheck<'a> {
+    pub client: &'a dyn LlmClient,
+}
+
+#[derive(Deserialize)]
+struct ModelVerdict {
+    flagged: bool,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl<'a> ModerationModelCheck for LlmModerationCheck<'a> {
+    async fn classify(&self, text: &str) -> Result<Option<(ModerationCategory, ModerationSeverity)>> {
+        let prompt = format!(
+            "Classify whether the following text contains disallowed content \
+             (harassment, hate speech, self-harm, or graphic violence). Respond with a \
+             single JSON object: {{\"flagged\": bool, \"category\": string, \"severity\": \
+             \"low\"|\"medium\"|\"high\"|\"critical\"}}. Text:\n\n{text}"
+        );
+        let response = self.client.complete(&prompt).await?;
+        let verdict: ModelVerdict = match serde_json::from_str(response.trim()) {
+            Ok(v) => v,
+            Err(_) => return Ok(None),
+        };
+        if !verdict.flagged {
+            return Ok(None);
+        }
+        let severity = match verdict.severity.as_deref() {
+            Some("critical") => ModerationSeverity::Critical,
+            Some("high") => ModerationSeverity::High,
+            Some("low") => ModerationSeverity::Low,
+            _ => ModerationSeverity::Medium,
+        };
+        let category = ModerationCategory::Custom(verdict.category.unwrap_or_else(|| "unspecified".to_string()));
+        Ok(Some((category, severity)))
+    }
+}
+
+/// Configuration for [`moderate_text`] and [`moderate_text_with_model`].
+pub struct ModerationConfig {
+    /// Literal, case-insensitive substrings that are always blocked.
+    pub blocklist: Vec<String>,
+    /// Regex-backed category rules, checked in order.
+    pub rules: Vec<ModerationRule>,
+    /// Replace matched spans with [`Self::redaction_token`] instead of only
+    /// reporting the match.
+    pub redact: bool,
+    /// Token substituted for a redacted match, e.g. `"[redacted]"`.
+    pub redaction_token: String,
+    /// Severity at or above which text is blocked outright rather than
+    /// merely flagged/redacted.
+    pub block_at: ModerationSeverity,
+    /// Optional secondary classifier for [`moderate_text_with_model`].
+    pub model_check: Option<Box<dyn ModerationModelCheck>>,
+}
+
+impl Default for ModerationConfig {
+    fn default() -> Self {
+        Self {
+            blocklist: Vec::new(),
+            rules: Vec::new(),
+            redact: true,
+            redaction_token: "[redacted]".to_string(),
+            block_at: ModerationSeverity::High,
+            model_check: None,
+        }
+    }
+}
+
+/// A single moderation match, from either the blocklist, a regex rule, or
+/// the optional model check.
+#[derive(Clone, Debug)]
+pub struct ModerationMatch {
+    pub category: ModerationCategory,
+    pub severity: ModerationSeverity,
+    /// The matched text, if it came from a pattern rather than the model check.
+    pub matched_text: Option<String>,
+}
+
+/// Outcome of moderating a piece of text.
+#[derive(Clone, Debug)]
+pub struct ModerationReport {
+    pub matches: Vec<ModerationMatch>,
+    /// `true` if the highest matched severity is at or above
+    /// [`ModerationConfig::block_at`]; callers should discard the text
+    /// entirely rather than showing [`Self::redacted_text`].
+    pub blocked: bool,
+    /// Text with matched spans replaced by the redaction token, when
+    /// [`ModerationConfig::redact`] is enabled and the text was not blocked.
+    pub redacted_text: Option<String>,
+}
+
+impl ModerationReport {
+    pub fn is_clean(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    pub fn highest_severity(&self) -> Option<ModerationSeverity> {
+        self.matches.iter().map(|m| m.severity).max()
+    }
+}
+
+/// Atomic counters for moderation outcomes, mirroring [`crate::telemetry::LlmTelemetry`]'s style.
+#[derive(Default)]
+pub struct ModerationTelemetry {
+    pub texts_checked: AtomicU64,
+    pub texts_clean: AtomicU64,
+    pub texts_redacted: AtomicU64,
+    pub texts_blocked: AtomicU64,
+    pub low_severity_matches: AtomicU64,
+    pub medium_severity_matches: AtomicU64,
+    pub high_severity_matches: AtomicU64,
+    pub critical_severity_matches: AtomicU64,
+}
+
+impl ModerationTelemetry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, report: &ModerationReport) {
+        self.texts_checked.fetch_add(1, Ordering::Relaxed);
+        if report.is_clean() {
+            self.texts_clean.fetch_add(1, Ordering::Relaxed);
+        } else if report.blocked {
+            self.texts_blocked.fetch_add(1, Ordering::Relaxed);
+        } else if report.redacted_text.is_some() {
+            self.texts_redacted.fetch_add(1, Ordering::Relaxed);
+        }
+        for m in &report.matches {
+            let counter = match m.severity {
+                ModerationSeverity::Low => &self.low_severity_matches,
+                ModerationSeverity::Medium => &self.medium_severity_matches,
+                ModerationSeverity::High => &self.high_severity_matches,
+                ModerationSeverity::Critical => &self.critical_severity_matches,
+            };
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Runs the synchronous blocklist and regex-rule passes over `text`.
+pub fn moderate_text(text: &str, config: &ModerationConfig, telemetry: &ModerationTelemetry) -> ModerationReport {
+    let mut matches = Vec::new();
+    let lower = text.to_lowercase();
+    for term in &config.blocklist {
+        if lower.contains(&term.to_lowercase()) {
+            matches.push(ModerationMatch {
+                category: ModerationCategory::Custom("blocklist".to_string()),
+                severity: config.block_at,
+                matched_text: Some(term.clone()),
+            });
+        }
+    }
+    for rule in &config.rules {
+        if let Some(found) = rule.pattern.find(text) {
+            matches.push(ModerationMatch {
+                category: rule.category.clone(),
+                severity: rule.severity,
+                matched_text: Some(found.as_str().to_string()),
+            });
+        }
+    }
+
+    let report = build_report(text, matches, config);
+    telemetry.record(&report);
+    report
+}
+
+/// Runs [`moderate_text`] and, if the text wasn't already blocked and
+/// [`ModerationConfig::model_check`] is set, also asks the model classifier.
+pub async fn moderate_text_with_model(
+    text: &str,
+    config: &ModerationConfig,
+    telemetry: &ModerationTelemetry,
+) -> Result<ModerationReport> {
+    let mut matches = Vec::new();
+    let lower = text.to_lowercase();
+    for term in &config.blocklist {
+        if lower.contains(&term.to_lowercase()) {
+            matches.push(ModerationMatch {
+                category: ModerationCategory::Custom("blocklist".to_string()),
+                severity: config.block_at,
+                matched_text: Some(term.clone()),
+            });
+        }
+    }
+    for rule in &config.rules {
+        if let Some(found) = rule.pattern.find(text) {
+            matches.push(ModerationMatch {
+                category: rule.category.clone(),
+                severity: rule.severity,
+                matched_text: Some(found.as_str().to_string()),
+            });
+        }
+    }
+
+    let already_blocked = matches.iter().any(|m| m.severity >= config.block_at);
+    if !already_blocked {
+        if let Some(check) = &config.model_check {
+            if let Some((category, severity)) = check.classify(text).await? {
+                matches.push(ModerationMatch {
+                    category,
+                    severity,
+                    matched_text: None,
+                });
+            }
+        }
+    }
+
+    let report = build_report(text, matches, config);
+    telemetry.record(&report);
+    Ok(report)
+}
+
+fn build_report(text: &str, matches: Vec<ModerationMatch>, config: &ModerationConfig) -> ModerationReport {
+    let blocked = matches.iter().any(|m| m.severity >= config.block_at);
+    let redacted_text = if !blocked && config.redact && !matches.is_empty() {
+        let mut redacted = text.to_string();
+        for m in &matches {
+            if let Some(matched_text) = &m.matched_text {
+                redacted = redacted.replace(matched_text.as_str(), &config.redaction_token);
+            }
+        }
+        Some(redacted)
+    } else {
+        None
+    };
+
+    ModerationReport {
+        matches,
+        blocked,
+        redacted_text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_blocklist() -> ModerationConfig {
+        ModerationConfig {
+            blocklist: vec!["badword".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn clean_text_produces_no_matches() {
+        let telemetry = ModerationTelemetry::new();
+        let report = moderate_text("hello there, friend", &config_with_blocklist(), &telemetry);
+        assert!(report.is_clean());
+        assert!(!report.blocked);
+        assert_eq!(telemetry.texts_clean.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn blocklisted_text_is_blocked_at_the_configured_severity() {
+        let telemetry = ModerationTelemetry::new();
+        let report = moderate_text("you badword you", &config_with_blocklist(), &telemetry);
+        assert!(report.blocked);
+        assert_eq!(telemetry.texts_blocked.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn low_severity_matches_are_redacted_instead_of_blocked() {
+        let telemetry = ModerationTelemetry::new();
+        let config = ModerationConfig {
+            rules: vec![ModerationRule::new(
+                ModerationCategory::Profanity,
+                "darn",
+                ModerationSeverity::Low,
+            )
+            .unwrap()],
+            block_at: ModerationSeverity::High,
+            ..Default::default()
+        };
+        let report = moderate_text("well, darn it", &config, &telemetry);
+        assert!(!report.blocked);
+        let redacted = report.redacted_text.expect("low severity should be redacted");
+        assert!(redacted.contains("[redacted]"));
+        assert_eq!(telemetry.texts_redacted.load(Ordering::Relaxed), 1);
+        assert_eq!(telemetry.low_severity_matches.load(Ordering::Relaxed), 1);
+    }
+
+    struct MockModerationCheck {
+        result: Option<(ModerationCategory, ModerationSeverity)>,
+    }
+
+    #[async_trait::async_trait]
+    impl ModerationModelCheck for MockModerationCheck {
+        async fn classify(&self, _text: &str) -> Result<Option<(ModerationCategory, ModerationSeverity)>> {
+            Ok(self.result.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn model_check_runs_when_no_rule_already_blocked_the_text() {
+        let telemetry = ModerationTelemetry::new();
+        let config = ModerationConfig {
+            model_check: Some(Box::new(MockModerationCheck {
+                result: Some((ModerationCategory::Harassment, ModerationSeverity::Critical)),
+            })),
+            ..Default::default()
+        };
+        let report = moderate_text_with_model("seems fine on the surface", &config, &telemetry)
+            .await
+            .unwrap();
+        assert!(report.blocked);
+        assert_eq!(report.highest_severity(), Some(ModerationSeverity::Critical));
+    }
+
+    #[tokio::test]
+    async fn model_check_is_skipped_once_a_rule_already_blocks_the_text() {
+        let telemetry = ModerationTelemetry::new();
+        let config = ModerationConfig {
+            blocklist: vec!["badword".to_string()],
+            model_check: Some(Box::new(MockModerationCheck {
+                result: Some((ModerationCategory::Harassment, ModerationSeverity::Critical)),
+            })),
+            ..Default::default()
+        };
+        let report = moderate_text_with_model("that's a badword", &config, &telemetry)
+            .await
+            .unwrap();
+        assert!(report.blocked);
+        assert_eq!(report.matches.len(), 1, "model check should not have run");
+    }
+}
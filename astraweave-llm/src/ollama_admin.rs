@@ -0,0 +1,294 @@
+//! Ollama model management: list, pull, and delete models from a running
+//! Ollama server, with pull progress events and a local disk-space check.
+//!
+//! This exists so games and the editor can ensure a required model is
+//! present *before* the first plan request, instead of discovering a
+//! missing model only when [`crate::OllamaChatClient::complete`] fails.
+
+use anyhow::{bail, Context, Result};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+
+/// A model entry as reported by `GET /api/tags`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OllamaModel {
+    pub name: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub digest: String,
+}
+
+/// A single progress update emitted while pulling a model.
+///
+/// Ollama's pull endpoint streams newline-delimited JSON status objects;
+/// this is a normalized view of those objects.
+#[derive(Debug, Clone)]
+pub struct PullProgress {
+    pub status: String,
+    pub completed: u64,
+    pub total: u64,
+}
+
+impl PullProgress {
+    /// Fraction complete in `[0.0, 1.0]`, or `0.0` if the total is unknown.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            (self.completed as f32 / self.total as f32).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Result of a local disk-space check performed before a pull.
+#[derive(Debug, Clone, Copy)]
+pub struct DiskSpaceCheck {
+    pub available_bytes: u64,
+    pub required_bytes: u64,
+}
+
+impl DiskSpaceCheck {
+    pub fn has_room(&self) -> bool {
+        self.available_bytes >= self.required_bytes
+    }
+}
+
+/// Admin client for an Ollama server's model management endpoints
+/// (`/api/tags`, `/api/pull`, `/api/delete`).
+///
+/// This is distinct from [`crate::OllamaChatClient`], which only talks to
+/// the chat/generate endpoints used for planning.
+#[derive(Clone)]
+pub struct OllamaAdmin {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl OllamaAdmin {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn localhost() -> Self {
+        Self::new("http://localhost:11434")
+    }
+
+    /// List models currently present on the server.
+    pub async fn list_models(&self) -> Result<Vec<OllamaModel>> {
+        #[derive(Deserialize)]
+        struct Tags {
+            #[serde(default)]
+            models: Vec<OllamaModel>,
+        }
+
+        let url = format!("{}/api/tags", self.url.trim_end_matches('/'));
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| format!("failed to reach Ollama at {}", self.url))?;
+
+        if !resp.status().is_success() {
+            bail!("Ollama /api/tags returned {}", resp.status());
+        }
+
+        let tags: Tags = resp
+            .json()
+            .await
+            .context("failed to parse Ollama /api/tags response")?;
+        Ok(tags.models)
+    }
+
+    /// Returns `true` if the server has a model matching `name` (exact match
+    /// on the `name` field, e.g. `"qwen3:8b"`).
+    pub async fn has_model(&self, name: &str) -> Result<bool> {
+        Ok(self.list_models().await?.iter().any(|m| m.name == name))
+    }
+
+    /// Query the Ollama server for its own health/availability.
+    pub async fn health(&self) -> Result<bool> {
+        let url = format!("{}/api/tags", self.url.trim_end_matches('/'));
+        match self.client.get(&url).send().await {
+            Ok(resp) => Ok(resp.status().is_success()),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Check whether `base_dir` (typically Ollama's model store) has at
+    /// least `required_bytes` of free space. Shells out to `df` rather than
+    /// pulling in a filesystem-stats dependency for this one call.
+    pub fn check_disk_space(
+        base_dir: &std::path::Path,
+        required_bytes: u64,
+    ) -> Result<DiskSpaceCheck> {
+        let output = std::process::Command::new("df")
+            .arg("-k")
+            .arg(base_dir)
+            .output()
+            .with_context(|| format!("failed to run `df` for {}", base_dir.display()))?;
+        if !output.status.success() {
+            bail!("`df` exited with {}", output.status);
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let last_line = stdout
+            .lines()
+            .last()
+            .context("`df` produced no output")?;
+        let available_kb: u64 = last_line
+            .split_whitespace()
+            .nth(3)
+            .context("unexpected `df` output format")?
+            .parse()
+            .context("failed to parse available space from `df`")?;
+        Ok(DiskSpaceCheck {
+            available_bytes: available_kb * 1024,
+            required_bytes,
+        })
+    }
+
+    /// Pull `name`, invoking `on_progress` for each streamed status update.
+    /// Returns once Ollama reports the pull as `"success"`.
+    pub async fn pull_model(
+        &self,
+        name: &str,
+        mut on_progress: impl FnMut(PullProgress),
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            name: &'a str,
+            stream: bool,
+        }
+        #[derive(Deserialize)]
+        struct StatusLine {
+            status: String,
+            #[serde(default)]
+            completed: u64,
+            #[serde(default)]
+            total: u64,
+        }
+
+        let url = format!("{}/api/pull", self.url.trim_end_matches('/'));
+        let resp = self
+            .client
+            .post(&url)
+            .json(&Req { name, stream: true })
+            .send()
+            .await
+            .with_context(|| format!("failed to start pull of {}", name))?;
+
+        if !resp.status().is_success() {
+            bail!("Ollama /api/pull returned {}", resp.status());
+        }
+
+        let mut stream = resp.bytes_stream();
+        let mut buf = String::new();
+        let mut succeeded = false;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.context("error reading pull stream")?;
+            buf.push_str(&String::from_utf8_lossy(&chunk));
+            while let Some(pos) = buf.find('\n') {
+                let line = buf[..pos].trim().to_string();
+                buf.drain(..=pos);
+                if line.is_empty() {
+                    continue;
+                }
+                let parsed: StatusLine = match serde_json::from_str(&line) {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+                if parsed.status == "success" {
+                    succeeded = true;
+                }
+                on_progress(PullProgress {
+                    status: parsed.status,
+                    completed: parsed.completed,
+                    total: parsed.total,
+                });
+            }
+        }
+
+        if !succeeded {
+            bail!("Ollama pull of {} did not report success", name);
+        }
+        Ok(())
+    }
+
+    /// Delete a model from the server.
+    pub async fn delete_model(&self, name: &str) -> Result<()> {
+        #[derive(Serialize)]
+        struct Req<'a> {
+            name: &'a str,
+        }
+
+        let url = format!("{}/api/delete", self.url.trim_end_matches('/'));
+        let resp = self
+            .client
+            .delete(&url)
+            .json(&Req { name })
+            .send()
+            .await
+            .with_context(|| format!("failed to delete model {}", name))?;
+
+        if !resp.status().is_success() {
+            bail!("Ollama /api/delete returned {}", resp.status());
+        }
+        Ok(())
+    }
+
+    /// Convenience wrapper: pull `name` only if it isn't already present,
+    /// so callers can call this unconditionally at startup.
+    pub async fn ensure_model(
+        &self,
+        name: &str,
+        on_progress: impl FnMut(PullProgress),
+    ) -> Result<()> {
+        if self.has_model(name).await? {
+            return Ok(());
+        }
+        self.pull_model(name, on_progress).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pull_progress_fraction_handles_unknown_total() {
+        let p = PullProgress {
+            status: "pulling".into(),
+            completed: 10,
+            total: 0,
+        };
+        assert_eq!(p.fraction(), 0.0);
+    }
+
+    #[test]
+    fn pull_progress_fraction_is_completed_over_total() {
+        let p = PullProgress {
+            status: "pulling".into(),
+            completed: 50,
+            total: 100,
+        };
+        assert_eq!(p.fraction(), 0.5);
+    }
+
+    #[test]
+    fn disk_space_check_has_room() {
+        let check = DiskSpaceCheck {
+            available_bytes: 100,
+            required_bytes: 50,
+        };
+        assert!(check.has_room());
+        let check = DiskSpaceCheck {
+            available_bytes: 10,
+            required_bytes: 50,
+        };
+        assert!(!check.has_room());
+    }
+}
@@ -0,0 +1,243 @@
+//! OpenAI cloud client for [`LlmClient`], with retry/backoff, rate limiting,
+//! and token-usage telemetry.
+//!
+//! Unlike the Ollama-based clients, requests here hit a paid, rate-limited
+//! API, so this client wires in the crate's [`crate::retry`] and
+//! [`crate::rate_limiter`] modules, and reports usage through
+//! [`astraweave_observability::llm_telemetry::LlmTelemetry`] so cost and
+//! latency show up alongside every other model source.
+
+use crate::rate_limiter::{RateLimitContext, RateLimiter, RateLimiterConfig, RequestPriority};
+use crate::retry::{RetryConfig, RetryExecutor, RetryableError};
+use crate::LlmClient;
+use anyhow::{bail, Result};
+use astraweave_observability::llm_telemetry::{LlmTelemetry, LlmTrace};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Rough per-1K-token USD pricing used only for telemetry cost estimates
+/// (not billing-accurate; OpenAI's actual rates vary by model and change
+/// over time).
+const PROMPT_COST_PER_1K: f64 = 0.0005;
+const COMPLETION_COST_PER_1K: f64 = 0.0015;
+
+/// OpenAI Chat Completions client.
+pub struct OpenAiClient {
+    api_key: String,
+    model: String,
+    base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    retry: RetryConfig,
+    telemetry: Option<Arc<LlmTelemetry>>,
+}
+
+impl OpenAiClient {
+    /// Create a client for `model` (e.g. `"gpt-4o-mini"`) authenticated with
+    /// `api_key`. Uses production-grade retry defaults and no telemetry
+    /// sink until [`Self::with_telemetry`] is set.
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            model: model.into(),
+            base_url: "https://api.openai.com".to_string(),
+            rate_limiter: Arc::new(RateLimiter::new(RateLimiterConfig::default())),
+            retry: RetryConfig::production(),
+            telemetry: None,
+        }
+    }
+
+    /// Override the API base URL (e.g. for Azure OpenAI or a proxy).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Override retry behavior (default: [`RetryConfig::production`]).
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry = config;
+        self
+    }
+
+    /// Share a [`RateLimiter`] with other clients so limits are enforced
+    /// process-wide rather than per client instance.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Record request latency, token usage, and estimated cost to `telemetry`.
+    pub fn with_telemetry(mut self, telemetry: Arc<LlmTelemetry>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    async fn send_once(&self, prompt: &str) -> Result<(String, usize, usize), RetryableError> {
+        #[derive(serde::Serialize)]
+        struct Message<'a> {
+            role: &'a str,
+            content: &'a str,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Req<'a> {
+            model: &'a str,
+            messages: Vec<Message<'a>>,
+            temperature: f32,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Usage {
+            prompt_tokens: usize,
+            completion_tokens: usize,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Choice {
+            message: RespMessage,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct RespMessage {
+            content: String,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            choices: Vec<Choice>,
+            #[serde(default)]
+            usage: Option<Usage>,
+        }
+
+        let body = Req {
+            model: &self.model,
+            messages: vec![Message {
+                role: "user",
+                content: prompt,
+            }],
+            temperature: 0.1,
+        };
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/v1/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .timeout(std::time::Duration::from_secs(60))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                tracing::debug!("OpenAI request failed: {}", e);
+                RetryableError::NetworkError
+            })?;
+
+        let status = response.status();
+        if status.as_u16() == 429 {
+            return Err(RetryableError::RateLimited);
+        }
+        if status.is_server_error() {
+            return Err(RetryableError::ServerError(status.as_u16()));
+        }
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(RetryableError::Permanent(format!(
+                "OpenAI API returned {}: {}",
+                status, text
+            )));
+        }
+
+        let parsed: Resp = response
+            .json()
+            .await
+            .map_err(|e| RetryableError::Permanent(format!("invalid OpenAI response: {}", e)))?;
+
+        let Some(choice) = parsed.choices.into_iter().next() else {
+            return Err(RetryableError::Permanent(
+                "OpenAI returned no choices".to_string(),
+            ));
+        };
+
+        let (prompt_tokens, completion_tokens) = parsed
+            .usage
+            .map(|u| (u.prompt_tokens, u.completion_tokens))
+            .unwrap_or((0, 0));
+
+        Ok((choice.message.content, prompt_tokens, completion_tokens))
+    }
+}
+
+#[async_trait]
+impl LlmClient for OpenAiClient {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let context = RateLimitContext {
+            user_id: None,
+            model: self.model.clone(),
+            estimated_tokens: (prompt.len() / 4) as u32,
+            priority: RequestPriority::Normal,
+        };
+        let _permit = self.rate_limiter.acquire(&context).await?;
+
+        let start = chrono::Utc::now();
+        let executor = RetryExecutor::new(self.retry.clone());
+        let result = executor.execute(|| self.send_once(prompt)).await;
+        let end = chrono::Utc::now();
+
+        self.rate_limiter
+            .report_result(&context, result.is_ok())
+            .await;
+
+        if let Some(telemetry) = &self.telemetry {
+            let (success, tokens_prompt, tokens_response, error_message) = match &result {
+                Ok((_, p, c)) => (true, *p, *c, None),
+                Err(e) => (false, 0, 0, Some(e.to_string())),
+            };
+            let total_tokens = tokens_prompt + tokens_response;
+            let cost_usd = (tokens_prompt as f64 / 1000.0) * PROMPT_COST_PER_1K
+                + (tokens_response as f64 / 1000.0) * COMPLETION_COST_PER_1K;
+            let trace = LlmTrace {
+                request_id: uuid::Uuid::new_v4().to_string(),
+                session_id: None,
+                user_id: None,
+                prompt: None,
+                response: None,
+                prompt_hash: None,
+                model: self.model.clone(),
+                start_time: start,
+                end_time: end,
+                latency_ms: (end - start).num_milliseconds().max(0) as u64,
+                tokens_prompt,
+                tokens_response,
+                total_tokens,
+                cost_usd,
+                success,
+                error_message,
+                error_type: None,
+                request_source: "openai".to_string(),
+                tags: Default::default(),
+            };
+            let _ = telemetry.record_request(trace).await;
+        }
+
+        match result {
+            Ok((content, _, _)) => Ok(content),
+            Err(e) => bail!("OpenAI request failed after retries: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_defaults_to_openai_base_url() {
+        let client = OpenAiClient::new("sk-test", "gpt-4o-mini");
+        assert_eq!(client.base_url, "https://api.openai.com");
+        assert_eq!(client.model, "gpt-4o-mini");
+    }
+
+    #[test]
+    fn with_base_url_overrides_default() {
+        let client =
+            OpenAiClient::new("sk-test", "gpt-4o-mini").with_base_url("https://proxy.local");
+        assert_eq!(client.base_url, "https://proxy.local");
+    }
+}
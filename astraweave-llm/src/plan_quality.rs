@@ -0,0 +1,266 @@
+//! Automated plan-quality scoring for LLM-generated plans.
+//!
+//! [`crate::ab_testing`] can run an experiment comparing prompt/model
+//! variants, but nothing scored what actually came back -- callers had to
+//! eyeball responses to tell whether a "winning" variant was actually
+//! producing better plans. [`PlanQualityEvaluator`] records four signals per
+//! generated plan (did it validate, how many steps it had, how many
+//! `sanitize_plan` dropped, and whether it succeeded once executed) and
+//! reduces them into per-variant [`PlanQualityMetrics`] that either display
+//! directly in a report or feed into [`crate::ab_testing::ABTestFramework`]
+//! via [`PlanQualityEvaluator::as_outcome`], so a model upgrade (Phi-3 ->
+//! Hermes) can be justified with data instead of vibes.
+
+use std::collections::HashMap;
+
+use astraweave_core::PlanIntent;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::ab_testing::Outcome;
+
+/// One generated plan's quality signals, recorded immediately after
+/// generation and optionally updated later with `execution_succeeded` once
+/// the plan has actually run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanQualityRecord {
+    pub variant_id: String,
+    /// `false` if the LLM's response failed to parse/validate into a
+    /// [`PlanIntent`] at all (see [`crate::plan_from_llm`]).
+    pub valid: bool,
+    /// Steps in the plan the LLM returned, before sanitization.
+    pub raw_step_count: usize,
+    /// Steps [`crate::sanitize_plan`] removed from this plan.
+    pub steps_dropped_by_sanitization: usize,
+    /// Whether the plan ran to completion once executed, if known yet.
+    pub execution_succeeded: Option<bool>,
+}
+
+impl PlanQualityRecord {
+    /// Builds a record from a plan's step count before (`raw`) and after
+    /// (`sanitized`) [`crate::sanitize_plan`] ran on it.
+    pub fn from_plans(
+        variant_id: impl Into<String>,
+        valid: bool,
+        raw: &PlanIntent,
+        sanitized: &PlanIntent,
+    ) -> Self {
+        Self {
+            variant_id: variant_id.into(),
+            valid,
+            raw_step_count: raw.steps.len(),
+            steps_dropped_by_sanitization: raw.steps.len().saturating_sub(sanitized.steps.len()),
+            execution_succeeded: None,
+        }
+    }
+}
+
+/// Aggregated quality metrics for one variant across every recorded plan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PlanQualityMetrics {
+    pub sample_count: usize,
+    /// Fraction of recorded plans that parsed/validated at all.
+    pub validity_rate: f32,
+    pub avg_step_count: f32,
+    /// Fraction of raw steps across all plans that sanitization dropped.
+    pub sanitization_drop_rate: f32,
+    /// `None` if no recorded plan has a known execution outcome yet.
+    pub execution_success_rate: Option<f32>,
+}
+
+/// Accumulates [`PlanQualityRecord`]s per variant and reduces them into
+/// [`PlanQualityMetrics`] on demand. Serializable so a report can be
+/// checked into a repo or diffed across runs the same way
+/// [`crate::replay::ReplayTape`] is.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PlanQualityEvaluator {
+    records: HashMap<String, Vec<PlanQualityRecord>>,
+}
+
+impl PlanQualityEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, record: PlanQualityRecord) {
+        self.records
+            .entry(record.variant_id.clone())
+            .or_default()
+            .push(record);
+    }
+
+    /// Reduces every plan recorded for `variant_id` into summary metrics.
+    /// Returns `None` if nothing has been recorded for it yet.
+    pub fn summarize(&self, variant_id: &str) -> Option<PlanQualityMetrics> {
+        let records = self.records.get(variant_id)?;
+        if records.is_empty() {
+            return None;
+        }
+
+        let n = records.len();
+        let valid_count = records.iter().filter(|r| r.valid).count();
+        let total_raw_steps: usize = records.iter().map(|r| r.raw_step_count).sum();
+        let total_dropped: usize = records
+            .iter()
+            .map(|r| r.steps_dropped_by_sanitization)
+            .sum();
+        let known_outcomes: Vec<bool> = records.iter().filter_map(|r| r.execution_succeeded).collect();
+
+        Some(PlanQualityMetrics {
+            sample_count: n,
+            validity_rate: valid_count as f32 / n as f32,
+            avg_step_count: total_raw_steps as f32 / n as f32,
+            sanitization_drop_rate: if total_raw_steps == 0 {
+                0.0
+            } else {
+                total_dropped as f32 / total_raw_steps as f32
+            },
+            execution_success_rate: if known_outcomes.is_empty() {
+                None
+            } else {
+                Some(
+                    known_outcomes.iter().filter(|s| **s).count() as f32
+                        / known_outcomes.len() as f32,
+                )
+            },
+        })
+    }
+
+    /// Every variant id with at least one recorded plan.
+    pub fn variant_ids(&self) -> Vec<String> {
+        self.records.keys().cloned().collect()
+    }
+
+    /// A full report across every tracked variant, e.g. to justify a model
+    /// upgrade with side-by-side numbers.
+    pub fn report(&self) -> HashMap<String, PlanQualityMetrics> {
+        self.variant_ids()
+            .into_iter()
+            .filter_map(|id| self.summarize(&id).map(|m| (id, m)))
+            .collect()
+    }
+
+    /// Converts `variant_id`'s current summary into an
+    /// [`Outcome`] for `experiment_id`, so
+    /// [`crate::ab_testing::ABTestFramework::record_outcome`] can fold plan
+    /// quality into the same statistical-significance machinery used for
+    /// other metrics. Returns `None` if nothing has been recorded yet.
+    pub fn as_outcome(&self, experiment_id: &str, variant_id: &str, user_id: &str) -> Option<Outcome> {
+        let metrics = self.summarize(variant_id)?;
+        let mut recorded_metrics = HashMap::new();
+        recorded_metrics.insert("validity_rate".to_string(), metrics.validity_rate);
+        recorded_metrics.insert("avg_step_count".to_string(), metrics.avg_step_count);
+        recorded_metrics.insert(
+            "sanitization_drop_rate".to_string(),
+            metrics.sanitization_drop_rate,
+        );
+        if let Some(rate) = metrics.execution_success_rate {
+            recorded_metrics.insert("execution_success_rate".to_string(), rate);
+        }
+
+        Some(Outcome {
+            user_id: user_id.to_string(),
+            experiment_id: experiment_id.to_string(),
+            variant_id: variant_id.to_string(),
+            timestamp: Utc::now(),
+            metrics: recorded_metrics,
+            success: metrics.validity_rate > 0.0,
+            metadata: HashMap::new(),
+        })
+    }
+
+    /// Serializes the full evaluator (every recorded plan, not just
+    /// summaries) so results can be persisted and reloaded across runs.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(steps: usize) -> PlanIntent {
+        PlanIntent {
+            plan_id: "p".into(),
+            steps: (0..steps)
+                .map(|i| astraweave_core::ActionStep::MoveTo {
+                    x: i as i32,
+                    y: 0,
+                    speed: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn summarize_returns_none_for_unknown_variant() {
+        let eval = PlanQualityEvaluator::new();
+        assert!(eval.summarize("phi3").is_none());
+    }
+
+    #[test]
+    fn summarize_computes_validity_and_step_count() {
+        let mut eval = PlanQualityEvaluator::new();
+        eval.record(PlanQualityRecord::from_plans("phi3", true, &plan(4), &plan(4)));
+        eval.record(PlanQualityRecord::from_plans("phi3", false, &plan(2), &plan(0)));
+
+        let metrics = eval.summarize("phi3").unwrap();
+        assert_eq!(metrics.sample_count, 2);
+        assert_eq!(metrics.validity_rate, 0.5);
+        assert_eq!(metrics.avg_step_count, 3.0);
+        assert_eq!(metrics.sanitization_drop_rate, 2.0 / 6.0);
+        assert_eq!(metrics.execution_success_rate, None);
+    }
+
+    #[test]
+    fn execution_success_rate_only_counts_known_outcomes() {
+        let mut eval = PlanQualityEvaluator::new();
+        let mut succeeded = PlanQualityRecord::from_plans("hermes", true, &plan(3), &plan(3));
+        succeeded.execution_succeeded = Some(true);
+        let mut failed = PlanQualityRecord::from_plans("hermes", true, &plan(3), &plan(3));
+        failed.execution_succeeded = Some(false);
+        let unknown = PlanQualityRecord::from_plans("hermes", true, &plan(3), &plan(3));
+
+        eval.record(succeeded);
+        eval.record(failed);
+        eval.record(unknown);
+
+        let metrics = eval.summarize("hermes").unwrap();
+        assert_eq!(metrics.execution_success_rate, Some(0.5));
+    }
+
+    #[test]
+    fn as_outcome_reflects_validity_in_success_flag() {
+        let mut eval = PlanQualityEvaluator::new();
+        eval.record(PlanQualityRecord::from_plans("phi3", false, &plan(1), &plan(0)));
+        let outcome = eval.as_outcome("exp-1", "phi3", "user-1").unwrap();
+        assert!(!outcome.success);
+        assert_eq!(outcome.metrics.get("validity_rate"), Some(&0.0));
+    }
+
+    #[test]
+    fn report_covers_every_tracked_variant() {
+        let mut eval = PlanQualityEvaluator::new();
+        eval.record(PlanQualityRecord::from_plans("phi3", true, &plan(1), &plan(1)));
+        eval.record(PlanQualityRecord::from_plans("hermes", true, &plan(2), &plan(2)));
+
+        let report = eval.report();
+        assert_eq!(report.len(), 2);
+        assert!(report.contains_key("phi3"));
+        assert!(report.contains_key("hermes"));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut eval = PlanQualityEvaluator::new();
+        eval.record(PlanQualityRecord::from_plans("phi3", true, &plan(1), &plan(1)));
+        let json = eval.to_json().unwrap();
+        let restored = PlanQualityEvaluator::from_json(&json).unwrap();
+        assert_eq!(restored.summarize("phi3"), eval.summarize("phi3"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Deterministic LLM record/replay for golden tests.
+//!
+//! [`RecordingLlmClient`] wraps any [`LlmClient`], forwarding every call
+//! while capturing `(prompt, response, latency)` tuples into a
+//! [`ReplayTape`]. [`ReplayLlmClient`] plays a tape back deterministically
+//! without touching a live model, so integration tests and the
+//! deterministic simulation can reproduce an AI-driven run without a model
+//! in the loop.
+
+use crate::LlmClient;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// One recorded (prompt, response, latency) exchange.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    pub prompt: String,
+    pub response: String,
+    pub latency_ms: u64,
+}
+
+/// An ordered sequence of recorded exchanges, serializable to/from JSON so
+/// it can be checked into a repo as a golden file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplayTape {
+    pub entries: Vec<ReplayEntry>,
+}
+
+impl ReplayTape {
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Wraps an [`LlmClient`], recording every `(prompt, response, latency)`
+/// exchange into a [`ReplayTape`] as it happens.
+pub struct RecordingLlmClient {
+    inner: Box<dyn LlmClient>,
+    tape: Mutex<ReplayTape>,
+}
+
+impl RecordingLlmClient {
+    pub fn new(inner: Box<dyn LlmClient>) -> Self {
+        Self {
+            inner,
+            tape: Mutex::new(ReplayTape::default()),
+        }
+    }
+
+    /// Snapshot of everything recorded so far, e.g. to write out as a golden file.
+    pub fn tape(&self) -> ReplayTape {
+        self.tape.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmClient for RecordingLlmClient {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let start = Instant::now();
+        let response = self.inner.complete(prompt).await?;
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        self.tape.lock().unwrap().entries.push(ReplayEntry {
+            prompt: prompt.to_string(),
+            response: response.clone(),
+            latency_ms,
+        });
+
+        Ok(response)
+    }
+}
+
+/// Serves a [`ReplayTape`] back deterministically, in recorded order,
+/// without a live model. Each call to [`Self::complete`] consumes the next
+/// entry regardless of whether `prompt` matches what was recorded, so a
+/// replay tracks a specific run's plan sequence rather than acting as a
+/// prompt-keyed cache; callers that need prompt-keyed lookups should use
+/// [`crate::cache`] instead.
+pub struct ReplayLlmClient {
+    remaining: Mutex<std::vec::IntoIter<ReplayEntry>>,
+}
+
+impl ReplayLlmClient {
+    pub fn new(tape: ReplayTape) -> Self {
+        Self {
+            remaining: Mutex::new(tape.entries.into_iter()),
+        }
+    }
+
+    /// Number of exchanges left to replay.
+    pub fn remaining(&self) -> usize {
+        self.remaining.lock().unwrap().len()
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmClient for ReplayLlmClient {
+    async fn complete(&self, _prompt: &str) -> Result<String> {
+        let mut remaining = self.remaining.lock().unwrap();
+        match remaining.next() {
+            Some(entry) => Ok(entry.response),
+            None => bail!("ReplayLlmClient: tape exhausted, no more recorded responses"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockLlm;
+
+    #[tokio::test]
+    async fn recording_client_forwards_and_captures_response() {
+        let recorder = RecordingLlmClient::new(Box::new(MockLlm));
+        let response = recorder.complete("plan for me").await.unwrap();
+
+        let tape = recorder.tape();
+        assert_eq!(tape.entries.len(), 1);
+        assert_eq!(tape.entries[0].prompt, "plan for me");
+        assert_eq!(tape.entries[0].response, response);
+    }
+
+    #[tokio::test]
+    async fn recording_client_accumulates_multiple_calls() {
+        let recorder = RecordingLlmClient::new(Box::new(MockLlm));
+        recorder.complete("first").await.unwrap();
+        recorder.complete("second").await.unwrap();
+
+        let tape = recorder.tape();
+        assert_eq!(tape.entries.len(), 2);
+        assert_eq!(tape.entries[0].prompt, "first");
+        assert_eq!(tape.entries[1].prompt, "second");
+    }
+
+    #[tokio::test]
+    async fn replay_client_serves_entries_in_order() {
+        let tape = ReplayTape {
+            entries: vec![
+                ReplayEntry {
+                    prompt: "a".into(),
+                    response: "resp-a".into(),
+                    latency_ms: 5,
+                },
+                ReplayEntry {
+                    prompt: "b".into(),
+                    response: "resp-b".into(),
+                    latency_ms: 7,
+                },
+            ],
+        };
+        let replay = ReplayLlmClient::new(tape);
+
+        assert_eq!(replay.complete("ignored").await.unwrap(), "resp-a");
+        assert_eq!(replay.complete("ignored").await.unwrap(), "resp-b");
+    }
+
+    #[tokio::test]
+    async fn replay_client_errors_when_exhausted() {
+        let replay = ReplayLlmClient::new(ReplayTape::default());
+        assert!(replay.complete("anything").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn replay_client_reports_remaining_count() {
+        let tape = ReplayTape {
+            entries: vec![ReplayEntry {
+                prompt: "a".into(),
+                response: "resp-a".into(),
+                latency_ms: 1,
+            }],
+        };
+        let replay = ReplayLlmClient::new(tape);
+        assert_eq!(replay.remaining(), 1);
+        replay.complete("a").await.unwrap();
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[test]
+    fn tape_round_trips_through_json() {
+        let tape = ReplayTape {
+            entries: vec![ReplayEntry {
+                prompt: "a".into(),
+                response: "resp-a".into(),
+                latency_ms: 3,
+            }],
+        };
+        let json = tape.to_json().unwrap();
+        let parsed = ReplayTape::from_json(&json).unwrap();
+        assert_eq!(parsed.entries.len(), 1);
+        assert_eq!(parsed.entries[0].response, "resp-a");
+    }
+}
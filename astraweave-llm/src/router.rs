@@ -0,0 +1,291 @@
+//! Multi-model routing with capability-based selection.
+//!
+//! Wraps several [`LlmClient`]s -- typically a small local model, a larger
+//! local model, and a cloud model -- and picks which one handles a given
+//! prompt based on estimated complexity and its recent health as tracked
+//! by [`CircuitBreakerManager`]. A route's configured latency SLA is
+//! stored but does not affect which route is picked.
+//! Trivial prompts (short, few tools) go to the cheapest route; complex
+//! ones (long prompts, many tools, squad-sized plans) escalate to a more
+//! capable route. If a route's circuit breaker is open, the router skips
+//! it and falls through to the next-best route rather than failing the
+//! whole request.
+
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::circuit_breaker::CircuitBreakerManager;
+use crate::LlmClient;
+
+/// How complex a prompt is judged to be, coarsest-grained first so a
+/// route can simply declare the maximum tier it's willing to handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PromptComplexity {
+    Trivial,
+    Moderate,
+    Complex,
+}
+
+impl PromptComplexity {
+    /// Estimates complexity from prompt length and tool-call surface.
+    /// A cheap, synchronous heuristic -- no tokenization required.
+    pub fn estimate(prompt: &str, tool_count: usize) -> Self {
+        let len = prompt.len();
+        if len < 800 && tool_count <= 6 {
+            PromptComplexity::Trivial
+        } else if len < 4000 && tool_count <= 20 {
+            PromptComplexity::Moderate
+        } else {
+            PromptComplexity::Complex
+        }
+    }
+}
+
+/// One route in a [`ModelRouter`]: a client plus the constraints under
+/// which it should be selected.
+pub struct ModelRoute {
+    pub name: String,
+    pub client: Arc<dyn LlmClient>,
+    /// The most complex prompt tier this route should be asked to handle.
+    pub max_complexity: PromptComplexity,
+    /// Soft latency budget. Stored only: routing, route order and
+    /// [`RouteTelemetry`] do not read it.
+    pub latency_sla: Duration,
+}
+
+impl ModelRoute {
+    pub fn new(
+        name: impl Into<String>,
+        client: Arc<dyn LlmClient>,
+        max_complexity: PromptComplexity,
+        latency_sla: Duration,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            client,
+            max_complexity,
+            latency_sla,
+        }
+    }
+}
+
+/// Per-route request counters, exposed for monitoring dashboards.
+#[derive(Debug, Default)]
+pub struct RouteTelemetry {
+    pub requests: AtomicU64,
+    pub successes: AtomicU64,
+    pub failures: AtomicU64,
+    pub skipped_circuit_open: AtomicU64,
+}
+
+/// A snapshot of one route's telemetry, safe to clone and hand to callers.
+#[derive(Debug, Clone, Default)]
+pub struct RouteStats {
+    pub name: String,
+    pub requests: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub skipped_circuit_open: u64,
+}
+
+/// Routes prompts across several [`LlmClient`]s by complexity, skipping
+/// routes whose circuit breaker is open and falling back to the next
+/// least-capable route that can still take the request.
+pub struct ModelRouter {
+    routes: Vec<ModelRoute>,
+    breakers: Arc<CircuitBreakerManager>,
+    telemetry: HashMap<String, RouteTelemetry>,
+}
+
+impl ModelRouter {
+    /// Builds a router from routes ordered cheapest-first. Order matters:
+    /// ties in `max_complexity` are broken by preferring the earliest
+    /// route, so put the cheapest capable route first.
+    pub fn new(routes: Vec<ModelRoute>, breakers: Arc<CircuitBreakerManager>) -> Self {
+        let telemetry = routes
+            .iter()
+            .map(|r| (r.name.clone(), RouteTelemetry::default()))
+            .collect();
+        Self {
+            routes,
+            breakers,
+            telemetry,
+        }
+    }
+
+    /// Completes `prompt` using the cheapest route whose `max_complexity`
+    /// covers `complexity` and whose circuit breaker is currently closed
+    /// (or half-open), escalating to the next route on failure.
+    pub async fn complete(&self, prompt: &str, complexity: PromptComplexity) -> Result<String> {
+        let candidates: Vec<&ModelRoute> = self
+            .routes
+            .iter()
+            .filter(|r| r.max_complexity >= complexity)
+            .collect();
+
+        if candidates.is_empty() {
+            bail!("ModelRouter: no route configured for complexity {complexity:?}");
+        }
+
+        let mut last_err = None;
+        for route in candidates {
+            let status = self.breakers.get_status(&route.name).await;
+            let circuit_open = matches!(
+                status.map(|s| s.state),
+                Some(crate::circuit_breaker::CircuitState::Open)
+            );
+
+            let stats = self
+                .telemetry
+                .get(&route.name)
+                .expect("every route has a telemetry entry created in ModelRouter::new");
+
+            if circuit_open {
+                stats.skipped_circuit_open.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+
+            stats.requests.fetch_add(1, Ordering::Relaxed);
+            let start = Instant::now();
+            let result = self
+                .breakers
+                .execute(&route.name, || route.client.complete(prompt))
+                .await
+                .result;
+            let _elapsed = start.elapsed();
+
+            match result {
+                Ok(response) => {
+                    stats.successes.fetch_add(1, Ordering::Relaxed);
+                    return Ok(response);
+                }
+                Err(e) => {
+                    stats.failures.fetch_add(1, Ordering::Relaxed);
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            anyhow::anyhow!("ModelRouter: all routes for complexity {complexity:?} were skipped")
+        }))
+    }
+
+    /// Snapshot of every route's telemetry counters, in route order.
+    pub fn route_stats(&self) -> Vec<RouteStats> {
+        self.routes
+            .iter()
+            .map(|r| {
+                let t = &self.telemetry[&r.name];
+                RouteStats {
+                    name: r.name.clone(),
+                    requests: t.requests.load(Ordering::Relaxed),
+                    successes: t.successes.load(Ordering::Relaxed),
+                    failures: t.failures.load(Ordering::Relaxed),
+                    skipped_circuit_open: t.skipped_circuit_open.load(Ordering::Relaxed),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit_breaker::CircuitBreakerConfig;
+    use crate::{AlwaysErrMock, MockLlm};
+
+    fn router_with(routes: Vec<ModelRoute>) -> ModelRouter {
+        ModelRouter::new(
+            routes,
+            Arc::new(CircuitBreakerManager::new(CircuitBreakerConfig {
+                minimum_requests: 1,
+                ..Default::default()
+            })),
+        )
+    }
+
+    #[test]
+    fn estimate_classifies_short_prompts_as_trivial() {
+        assert_eq!(
+            PromptComplexity::estimate("move to 4,2", 3),
+            PromptComplexity::Trivial
+        );
+    }
+
+    #[test]
+    fn estimate_classifies_long_many_tool_prompts_as_complex() {
+        let prompt = "x".repeat(5000);
+        assert_eq!(
+            PromptComplexity::estimate(&prompt, 30),
+            PromptComplexity::Complex
+        );
+    }
+
+    #[tokio::test]
+    async fn routes_trivial_prompt_to_the_cheapest_capable_route() {
+        let router = router_with(vec![
+            ModelRoute::new(
+                "small",
+                Arc::new(MockLlm),
+                PromptComplexity::Trivial,
+                Duration::from_millis(200),
+            ),
+            ModelRoute::new(
+                "large",
+                Arc::new(AlwaysErrMock),
+                PromptComplexity::Complex,
+                Duration::from_secs(2),
+            ),
+        ]);
+
+        let result = router.complete("hi", PromptComplexity::Trivial).await;
+        assert!(result.is_ok());
+
+        let stats = router.route_stats();
+        assert_eq!(stats[0].name, "small");
+        assert_eq!(stats[0].successes, 1);
+        assert_eq!(stats[1].requests, 0, "large route should not be tried");
+    }
+
+    #[tokio::test]
+    async fn escalates_to_the_next_route_when_the_first_fails() {
+        let router = router_with(vec![
+            ModelRoute::new(
+                "flaky",
+                Arc::new(AlwaysErrMock),
+                PromptComplexity::Complex,
+                Duration::from_millis(200),
+            ),
+            ModelRoute::new(
+                "reliable",
+                Arc::new(MockLlm),
+                PromptComplexity::Complex,
+                Duration::from_secs(2),
+            ),
+        ]);
+
+        let result = router.complete("plan this", PromptComplexity::Complex).await;
+        assert!(result.is_ok());
+
+        let stats = router.route_stats();
+        assert_eq!(stats[0].failures, 1);
+        assert_eq!(stats[1].successes, 1);
+    }
+
+    #[tokio::test]
+    async fn errors_when_no_route_covers_the_requested_complexity() {
+        let router = router_with(vec![ModelRoute::new(
+            "small",
+            Arc::new(MockLlm),
+            PromptComplexity::Trivial,
+            Duration::from_millis(200),
+        )]);
+
+        let result = router.complete("plan this", PromptComplexity::Complex).await;
+        assert!(result.is_err());
+    }
+}
@@ -209,6 +209,120 @@ pub trait LlmOutputSchema: Sized + DeserializeOwned {
     }
 }
 
+/// A JSON Schema (draft-2020-12 subset) passed to a model as a decoding
+/// constraint, plus the machinery to check a raw response against it before
+/// attempting `serde_json` deserialization.
+///
+/// Ollama's `/api/chat` accepts this directly as the `format` field, and
+/// OpenAI-compatible APIs accept an equivalent shape under
+/// `response_format: {"type": "json_schema", "json_schema": {...}}`.
+#[derive(Debug, Clone)]
+pub struct JsonSchemaConstraint {
+    schema: Value,
+}
+
+impl JsonSchemaConstraint {
+    /// Wrap an already-built JSON Schema value.
+    pub fn new(schema: Value) -> Self {
+        Self { schema }
+    }
+
+    /// The minimal JSON Schema for [`astraweave_core::PlanIntent`]: an
+    /// object with a string `plan_id` and a non-empty array of `steps`,
+    /// each of which must at least carry an `act` discriminator. This
+    /// mirrors the constraints already enforced by
+    /// [`LlmOutputSchema::validate_json`] for `PlanIntent`-shaped output,
+    /// expressed as JSON Schema so the model itself can be constrained.
+    pub fn plan_intent() -> Self {
+        Self::new(serde_json::json!({
+            "type": "object",
+            "required": ["plan_id", "steps"],
+            "properties": {
+                "plan_id": { "type": "string" },
+                "steps": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": {
+                        "type": "object",
+                        "required": ["act"],
+                        "properties": { "act": { "type": "string" } }
+                    }
+                }
+            }
+        }))
+    }
+
+    /// The raw schema value, as passed to the model's `format` /
+    /// `response_format` parameter.
+    pub fn as_value(&self) -> &Value {
+        &self.schema
+    }
+
+    /// Validate `text` parses as JSON and satisfies the required/type
+    /// constraints of this schema. This is a structural pre-check, not a
+    /// full JSON Schema implementation: it only enforces `type`,
+    /// `required`, and, for arrays, `minItems` plus the `items` schema of
+    /// each element; `properties` schemas are not checked. That is enough
+    /// to catch a model ignoring the constraint before the more expensive
+    /// `serde_json` deserialization runs.
+    pub fn validate(&self, text: &str) -> Result<Value, ValidationError> {
+        let value: Value = serde_json::from_str(text.trim())
+            .map_err(|e| ValidationError::ParseError(e.to_string()))?;
+        validate_against_schema(&value, &self.schema, "")?;
+        Ok(value)
+    }
+}
+
+fn validate_against_schema(value: &Value, schema: &Value, path: &str) -> Result<(), ValidationError> {
+    if let Some(expected) = schema.get("type").and_then(|t| t.as_str()) {
+        let actual = json_type_name(value);
+        let matches = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            _ => true,
+        };
+        if !matches {
+            return Err(ValidationError::WrongType {
+                field: path.to_string(),
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+
+    if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+        for field in required {
+            let field = field.as_str().unwrap_or_default();
+            if value.get(field).is_none() {
+                return Err(ValidationError::MissingField {
+                    field: field.to_string(),
+                    path: path.to_string(),
+                });
+            }
+        }
+    }
+
+    if let (Some(items_schema), Some(arr)) = (schema.get("items"), value.as_array()) {
+        if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64()) {
+            if (arr.len() as u64) < min {
+                return Err(ValidationError::ArrayLength {
+                    field: path.to_string(),
+                    actual: arr.len(),
+                    constraint: format!("minimum {} items", min),
+                });
+            }
+        }
+        for (i, item) in arr.iter().enumerate() {
+            validate_against_schema(item, items_schema, &format!("{}/{}", path, i))?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Get a human-readable type name for a JSON value
 fn json_type_name(value: &Value) -> String {
     match value {
@@ -685,4 +799,37 @@ mod tests {
         let result = TestPlan::parse_validated(json);
         assert!(result.is_err());
     }
+
+    // ============================================================
+    // JsonSchemaConstraint Tests
+    // ============================================================
+
+    #[test]
+    fn test_json_schema_constraint_valid_plan() {
+        let constraint = JsonSchemaConstraint::plan_intent();
+        let json = r#"{"plan_id": "p1", "steps": [{"act": "MoveTo", "x": 1, "y": 2}]}"#;
+        assert!(constraint.validate(json).is_ok());
+    }
+
+    #[test]
+    fn test_json_schema_constraint_missing_plan_id() {
+        let constraint = JsonSchemaConstraint::plan_intent();
+        let json = r#"{"steps": [{"act": "MoveTo"}]}"#;
+        let err = constraint.validate(json).unwrap_err();
+        assert!(matches!(err, ValidationError::MissingField { .. }));
+    }
+
+    #[test]
+    fn test_json_schema_constraint_empty_steps() {
+        let constraint = JsonSchemaConstraint::plan_intent();
+        let json = r#"{"plan_id": "p1", "steps": []}"#;
+        let err = constraint.validate(json).unwrap_err();
+        assert!(matches!(err, ValidationError::ArrayLength { .. }));
+    }
+
+    #[test]
+    fn test_json_schema_constraint_invalid_json() {
+        let constraint = JsonSchemaConstraint::plan_intent();
+        assert!(constraint.validate("not json").is_err());
+    }
 }
@@ -0,0 +1,363 @@
+//! Structured world-state diff prompts.
+//!
+//! [`crate::prompt_template::build_enhanced_prompt`] re-serializes the
+//! entire [`WorldSnapshot`] into every prompt, even when only a handful
+//! of positions changed since the last tick. [`DiffPromptState`] tracks
+//! the last snapshot sent for an episode and, after the first full
+//! prompt, builds a compact [`WorldSnapshotDiff`] instead -- moved
+//! entities, new enemies, removed POIs -- alongside a rolling digest of
+//! the snapshot it was computed from, so a caller (or the model) can
+//! detect drift and request a full resync.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use astraweave_core::{CompanionState, Entity, IVec2, PlayerState, Poi, ToolRegistry, WorldSnapshot};
+use serde::{Deserialize, Serialize};
+
+use crate::prompt_template::{build_enhanced_prompt, PromptConfig};
+
+/// A single enemy's updated fields, sent only when it changed since the
+/// last snapshot.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EnemyDelta {
+    pub id: Entity,
+    pub pos: IVec2,
+    pub hp: i32,
+    pub cover: String,
+    pub last_seen: f32,
+}
+
+/// Compact description of what changed between two [`WorldSnapshot`]s.
+/// Every field is empty/`None` by default, so a diff against an
+/// unchanged snapshot serializes to a small, mostly-empty object.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+pub struct WorldSnapshotDiff {
+    pub t: f32,
+    pub player: Option<PlayerState>,
+    pub me: Option<CompanionState>,
+    pub new_enemies: Vec<EnemyDelta>,
+    pub updated_enemies: Vec<EnemyDelta>,
+    pub removed_enemy_ids: Vec<Entity>,
+    pub new_pois: Vec<Poi>,
+    pub removed_pois: Vec<Poi>,
+    pub obstacles_added: Vec<IVec2>,
+    pub obstacles_removed: Vec<IVec2>,
+    /// `Some(value)` when the objective changed this tick, including to `None`.
+    pub objective: Option<Option<String>>,
+}
+
+impl WorldSnapshotDiff {
+    /// `true` if nothing changed besides the timestamp.
+    pub fn is_empty(&self) -> bool {
+        self.player.is_none()
+            && self.me.is_none()
+            && self.new_enemies.is_empty()
+            && self.updated_enemies.is_empty()
+            && self.removed_enemy_ids.is_empty()
+            && self.new_pois.is_empty()
+            && self.removed_pois.is_empty()
+            && self.obstacles_added.is_empty()
+            && self.obstacles_removed.is_empty()
+            && self.objective.is_none()
+    }
+}
+
+/// Computes what changed between `prev` and `curr`.
+pub fn diff_snapshots(prev: &WorldSnapshot, curr: &WorldSnapshot) -> WorldSnapshotDiff {
+    let player = if snapshot_players_equal(&prev.player, &curr.player) {
+        None
+    } else {
+        Some(curr.player.clone())
+    };
+
+    let me = if snapshot_companions_equal(&prev.me, &curr.me) {
+        None
+    } else {
+        Some(curr.me.clone())
+    };
+
+    let mut new_enemies = Vec::new();
+    let mut updated_enemies = Vec::new();
+    for enemy in &curr.enemies {
+        match prev.enemies.iter().find(|e| e.id == enemy.id) {
+            None => new_enemies.push(enemy_delta(enemy)),
+            Some(before)
+                if before.pos != enemy.pos
+                    || before.hp != enemy.hp
+                    || before.cover != enemy.cover
+                    || before.last_seen != enemy.last_seen =>
+            {
+                updated_enemies.push(enemy_delta(enemy));
+            }
+            Some(_) => {}
+        }
+    }
+    let removed_enemy_ids: Vec<Entity> = prev
+        .enemies
+        .iter()
+        .filter(|e| !curr.enemies.iter().any(|c| c.id == e.id))
+        .map(|e| e.id)
+        .collect();
+
+    let new_pois: Vec<Poi> = curr
+        .pois
+        .iter()
+        .filter(|p| !prev.pois.contains(p))
+        .cloned()
+        .collect();
+    let removed_pois: Vec<Poi> = prev
+        .pois
+        .iter()
+        .filter(|p| !curr.pois.contains(p))
+        .cloned()
+        .collect();
+
+    let obstacles_added: Vec<IVec2> = curr
+        .obstacles
+        .iter()
+        .filter(|o| !prev.obstacles.contains(o))
+        .cloned()
+        .collect();
+    let obstacles_removed: Vec<IVec2> = prev
+        .obstacles
+        .iter()
+        .filter(|o| !curr.obstacles.contains(o))
+        .cloned()
+        .collect();
+
+    let objective = if prev.objective == curr.objective {
+        None
+    } else {
+        Some(curr.objective.clone())
+    };
+
+    WorldSnapshotDiff {
+        t: curr.t,
+        player,
+        me,
+        new_enemies,
+        updated_enemies,
+        removed_enemy_ids,
+        new_pois,
+        removed_pois,
+        obstacles_added,
+        obstacles_removed,
+        objective,
+    }
+}
+
+fn enemy_delta(enemy: &astraweave_core::EnemyState) -> EnemyDelta {
+    EnemyDelta {
+        id: enemy.id,
+        pos: enemy.pos,
+        hp: enemy.hp,
+        cover: enemy.cover.clone(),
+        last_seen: enemy.last_seen,
+    }
+}
+
+fn snapshot_players_equal(a: &PlayerState, b: &PlayerState) -> bool {
+    a.hp == b.hp && a.pos == b.pos && a.stance == b.stance && a.orders == b.orders
+}
+
+fn snapshot_companions_equal(a: &CompanionState, b: &CompanionState) -> bool {
+    a.ammo == b.ammo && a.cooldowns == b.cooldowns && a.morale == b.morale && a.pos == b.pos
+}
+
+/// A stable digest of a [`WorldSnapshot`], used to detect when a diff
+/// chain has drifted from what the model actually saw (e.g. after a
+/// dropped response) so a full resync can be forced.
+pub fn snapshot_digest(snap: &WorldSnapshot) -> u64 {
+    let json = serde_json::to_string(snap).unwrap_or_default();
+    let mut hasher = DefaultHasher::new();
+    json.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Per-episode diff-prompt state: remembers the last snapshot sent so
+/// subsequent prompts can be diffs instead of full re-serializations.
+#[derive(Default)]
+pub struct DiffPromptState {
+    last_snapshot: Option<WorldSnapshot>,
+}
+
+impl DiffPromptState {
+    pub fn new() -> Self {
+        Self { last_snapshot: None }
+    }
+
+    /// Digest of the last snapshot this state built a prompt from, if any.
+    pub fn last_digest(&self) -> Option<u64> {
+        self.last_snapshot.as_ref().map(snapshot_digest)
+    }
+
+    /// Builds the next prompt for `snap`: a full [`build_enhanced_prompt`]
+    /// on the first call (or whenever `force_full` is set, e.g. because a
+    /// caller detected the model lost sync with an earlier digest), and a
+    /// compact diff section otherwise.
+    pub fn build_prompt(
+        &mut self,
+        snap: &WorldSnapshot,
+        reg: &ToolRegistry,
+        config: &PromptConfig,
+        force_full: bool,
+    ) -> String {
+        let prompt = match &self.last_snapshot {
+            Some(prev) if !force_full => {
+                let diff = diff_snapshots(prev, snap);
+                build_diff_prompt_section(&diff, snapshot_digest(snap))
+            }
+            _ => build_enhanced_prompt(snap, reg, config),
+        };
+        self.last_snapshot = Some(snap.clone());
+        prompt
+    }
+}
+
+fn build_diff_prompt_section(diff: &WorldSnapshotDiff, digest: u64) -> String {
+    let diff_json = serde_json::to_string_pretty(diff).unwrap_or_else(|_| "{}".to_string());
+    format!(
+        r#"═══════════════════════════════════════
+WORLD STATE DIFF (since last tick, digest={digest})
+═══════════════════════════════════════
+
+{diff_json}
+
+Apply this diff to the world state you were given previously and generate your tactical plan."#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{default_tool_registry, CompanionState, EnemyState, PlayerState};
+
+    fn base_snapshot() -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2 { x: 0, y: 0 },
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: Default::default(),
+                morale: 1.0,
+                pos: IVec2 { x: 1, y: 0 },
+            },
+            enemies: vec![EnemyState {
+                id: 1,
+                pos: IVec2 { x: 5, y: 5 },
+                hp: 50,
+                cover: "none".into(),
+                last_seen: 0.0,
+            }],
+            pois: vec![Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 2, y: 2 },
+            }],
+            obstacles: vec![IVec2 { x: 3, y: 3 }],
+            objective: Some("patrol".into()),
+        }
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_empty() {
+        let snap = base_snapshot();
+        let diff = diff_snapshots(&snap, &snap);
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn diff_reports_a_moved_enemy_as_updated() {
+        let prev = base_snapshot();
+        let mut curr = base_snapshot();
+        curr.enemies[0].pos = IVec2 { x: 6, y: 5 };
+
+        let diff = diff_snapshots(&prev, &curr);
+        assert_eq!(diff.updated_enemies.len(), 1);
+        assert_eq!(diff.updated_enemies[0].pos, IVec2 { x: 6, y: 5 });
+        assert!(diff.new_enemies.is_empty());
+    }
+
+    #[test]
+    fn diff_reports_new_and_removed_enemies() {
+        let prev = base_snapshot();
+        let mut curr = base_snapshot();
+        curr.enemies.clear();
+        curr.enemies.push(EnemyState {
+            id: 2,
+            pos: IVec2 { x: 9, y: 9 },
+            hp: 30,
+            cover: "wall".into(),
+            last_seen: 1.0,
+        });
+
+        let diff = diff_snapshots(&prev, &curr);
+        assert_eq!(diff.removed_enemy_ids, vec![1]);
+        assert_eq!(diff.new_enemies.len(), 1);
+        assert_eq!(diff.new_enemies[0].id, 2);
+    }
+
+    #[test]
+    fn diff_reports_removed_pois_and_added_obstacles() {
+        let prev = base_snapshot();
+        let mut curr = base_snapshot();
+        curr.pois.clear();
+        curr.obstacles.push(IVec2 { x: 8, y: 8 });
+
+        let diff = diff_snapshots(&prev, &curr);
+        assert_eq!(diff.removed_pois.len(), 1);
+        assert_eq!(diff.obstacles_added, vec![IVec2 { x: 8, y: 8 }]);
+    }
+
+    #[test]
+    fn diff_reports_objective_change_including_to_none() {
+        let prev = base_snapshot();
+        let mut curr = base_snapshot();
+        curr.objective = None;
+
+        let diff = diff_snapshots(&prev, &curr);
+        assert_eq!(diff.objective, Some(None));
+    }
+
+    #[test]
+    fn snapshot_digest_is_stable_and_changes_with_content() {
+        let a = base_snapshot();
+        let mut b = base_snapshot();
+        assert_eq!(snapshot_digest(&a), snapshot_digest(&b));
+
+        b.player.hp -= 1;
+        assert_ne!(snapshot_digest(&a), snapshot_digest(&b));
+    }
+
+    #[test]
+    fn diff_prompt_state_sends_a_full_prompt_first_then_diffs() {
+        let reg = default_tool_registry();
+        let config = PromptConfig::default();
+        let mut state = DiffPromptState::new();
+
+        let first = state.build_prompt(&base_snapshot(), &reg, &config, false);
+        assert!(first.contains("CURRENT WORLD STATE"));
+
+        let mut second_snap = base_snapshot();
+        second_snap.enemies[0].hp = 40;
+        let second = state.build_prompt(&second_snap, &reg, &config, false);
+        assert!(second.contains("WORLD STATE DIFF"));
+        assert!(!second.contains("CURRENT WORLD STATE"));
+    }
+
+    #[test]
+    fn diff_prompt_state_forces_a_full_prompt_on_request() {
+        let reg = default_tool_registry();
+        let config = PromptConfig::default();
+        let mut state = DiffPromptState::new();
+
+        state.build_prompt(&base_snapshot(), &reg, &config, false);
+        let forced = state.build_prompt(&base_snapshot(), &reg, &config, true);
+        assert!(forced.contains("CURRENT WORLD STATE"));
+    }
+}
@@ -0,0 +1,363 @@
+//! Hierarchical summarization of a [`WorldSnapshot`]'s obstacle and POI
+//! lists for prompt compression.
+//!
+//! [`crate::compression::PromptCompressor::snapshot_to_compact_json`]
+//! already abbreviates field names, but still serializes every obstacle
+//! cell and every POI individually -- on a large map that dwarfs the rest
+//! of the prompt. [`summarize_snapshot`] instead clusters far-away
+//! obstacles into grid-cell rectangles, groups POIs by label, and keeps
+//! only the `K` nearest of each (to the companion's position), with an
+//! explicit omitted-count so the model knows detail was dropped rather
+//! than silently believing the map is emptier than it is. How aggressive
+//! the clustering is near the companion is governed by the
+//! [`ToolRegistry`]'s `enforce_los` constraint: when line-of-sight is
+//! enforced, nearby obstacles stay unclustered so cover/LOS-dependent
+//! plans remain legal.
+
+use astraweave_core::{IVec2, Poi, ToolRegistry, WorldSnapshot};
+use std::collections::HashMap;
+
+/// Tuning knobs for [`summarize_snapshot`].
+#[derive(Clone, Debug)]
+pub struct SummaryConfig {
+    /// Grid cell size (in world units) used to cluster far-field obstacles
+    /// into rectangles.
+    pub cell_size: i32,
+    /// Obstacles within this Manhattan distance of the companion are kept
+    /// unclustered when the registry enforces line-of-sight, since legal
+    /// cover/LOS plans depend on their exact position.
+    pub near_field_radius: i32,
+    /// Maximum number of obstacle clusters (near-field cells count as one
+    /// cluster each) included in the summary, nearest first.
+    pub max_obstacle_clusters: usize,
+    /// Maximum number of POI positions kept per label group, nearest first.
+    pub max_pois_per_group: usize,
+}
+
+impl Default for SummaryConfig {
+    fn default() -> Self {
+        Self {
+            cell_size: 5,
+            near_field_radius: 6,
+            max_obstacle_clusters: 8,
+            max_pois_per_group: 3,
+        }
+    }
+}
+
+/// A rectangular group of one or more obstacle cells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObstacleCluster {
+    pub min: IVec2,
+    pub max: IVec2,
+    pub count: usize,
+}
+
+/// POIs sharing a label, with only the nearest few kept explicit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoiGroup {
+    pub label: String,
+    pub count: usize,
+    pub nearest: Vec<IVec2>,
+    pub omitted: usize,
+}
+
+/// A summarized view of a [`WorldSnapshot`]'s obstacles and POIs.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotSummary {
+    pub obstacle_clusters: Vec<ObstacleCluster>,
+    pub obstacles_omitted: usize,
+    pub poi_groups: Vec<PoiGroup>,
+    pub pois_omitted: usize,
+}
+
+/// Clusters `snap`'s obstacles and POIs relative to the companion's
+/// position, honoring `registry`'s `enforce_los` constraint for how much
+/// near-field obstacle precision to preserve.
+pub fn summarize_snapshot(snap: &WorldSnapshot, registry: &ToolRegistry, config: &SummaryConfig) -> SnapshotSummary {
+    let origin = snap.me.pos;
+
+    let (near_field, far_field): (Vec<IVec2>, Vec<IVec2>) = if registry.constraints.enforce_los {
+        snap.obstacles
+            .iter()
+            .partition(|o| o.manhattan_distance(&origin) <= config.near_field_radius)
+    } else {
+        (Vec::new(), snap.obstacles.clone())
+    };
+
+    let mut clusters: Vec<ObstacleCluster> = near_field
+        .iter()
+        .map(|&pos| ObstacleCluster {
+            min: pos,
+            max: pos,
+            count: 1,
+        })
+        .collect();
+    clusters.extend(cluster_far_field(&far_field, config.cell_size));
+    clusters.sort_by_key(|c| c.min.manhattan_distance(&origin));
+
+    let obstacles_omitted = clusters
+        .iter()
+        .skip(config.max_obstacle_clusters)
+        .map(|c| c.count)
+        .sum();
+    clusters.truncate(config.max_obstacle_clusters);
+
+    let poi_groups = group_pois(&snap.pois, origin, config.max_pois_per_group);
+    let pois_omitted = poi_groups.iter().map(|g| g.omitted).sum();
+
+    SnapshotSummary {
+        obstacle_clusters: clusters,
+        obstacles_omitted,
+        poi_groups,
+        pois_omitted,
+    }
+}
+
+/// Buckets far-field obstacles into `cell_size`-sided grid cells, one
+/// cluster per non-empty cell.
+fn cluster_far_field(obstacles: &[IVec2], cell_size: i32) -> Vec<ObstacleCluster> {
+    let cell_size = cell_size.max(1);
+    let mut cells: HashMap<(i32, i32), ObstacleCluster> = HashMap::new();
+    for pos in obstacles {
+        let key = (pos.x.div_euclid(cell_size), pos.y.div_euclid(cell_size));
+        cells
+            .entry(key)
+            .and_modify(|c| {
+                c.min = IVec2 {
+                    x: c.min.x.min(pos.x),
+                    y: c.min.y.min(pos.y),
+                };
+                c.max = IVec2 {
+                    x: c.max.x.max(pos.x),
+                    y: c.max.y.max(pos.y),
+                };
+                c.count += 1;
+            })
+            .or_insert(ObstacleCluster {
+                min: *pos,
+                max: *pos,
+                count: 1,
+            });
+    }
+    let mut clusters: Vec<ObstacleCluster> = cells.into_values().collect();
+    clusters.sort_by_key(|c| (c.min.x, c.min.y));
+    clusters
+}
+
+fn group_pois(pois: &[Poi], origin: IVec2, max_per_group: usize) -> Vec<PoiGroup> {
+    let mut by_label: HashMap<String, Vec<IVec2>> = HashMap::new();
+    for poi in pois {
+        by_label.entry(poi.k.clone()).or_default().push(poi.pos);
+    }
+
+    let mut groups: Vec<PoiGroup> = by_label
+        .into_iter()
+        .map(|(label, mut positions)| {
+            positions.sort_by_key(|p| p.manhattan_distance(&origin));
+            let count = positions.len();
+            let nearest: Vec<IVec2> = positions.into_iter().take(max_per_group).collect();
+            let omitted = count - nearest.len();
+            PoiGroup {
+                label,
+                count,
+                nearest,
+                omitted,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| a.label.cmp(&b.label));
+    groups
+}
+
+/// Renders a [`SnapshotSummary`] as a compact, model-readable text block
+/// with explicit "N more omitted" markers.
+pub fn render_summary(summary: &SnapshotSummary) -> String {
+    let mut lines = Vec::new();
+
+    for cluster in &summary.obstacle_clusters {
+        if cluster.count == 1 {
+            lines.push(format!("obs@({},{})", cluster.min.x, cluster.min.y));
+        } else {
+            lines.push(format!(
+                "obs_cluster[({},{})-({},{})]x{}",
+                cluster.min.x, cluster.min.y, cluster.max.x, cluster.max.y, cluster.count
+            ));
+        }
+    }
+    if summary.obstacles_omitted > 0 {
+        lines.push(format!("{} more obstacles omitted", summary.obstacles_omitted));
+    }
+
+    for group in &summary.poi_groups {
+        let positions = group
+            .nearest
+            .iter()
+            .map(|p| format!("({},{})", p.x, p.y))
+            .collect::<Vec<_>>()
+            .join(",");
+        if group.omitted > 0 {
+            lines.push(format!(
+                "poi:{} x{} [{}] ({} more omitted)",
+                group.label, group.count, positions, group.omitted
+            ));
+        } else {
+            lines.push(format!("poi:{} x{} [{}]", group.label, group.count, positions));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{default_tool_registry, CompanionState, EnemyState, PlayerState};
+
+    fn base_snapshot() -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2 { x: 0, y: 0 },
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: Default::default(),
+                morale: 1.0,
+                pos: IVec2 { x: 0, y: 0 },
+            },
+            enemies: vec![EnemyState {
+                id: 1,
+                pos: IVec2 { x: 5, y: 5 },
+                hp: 50,
+                cover: "none".into(),
+                last_seen: 0.0,
+            }],
+            pois: vec![],
+            obstacles: vec![],
+            objective: None,
+        }
+    }
+
+    #[test]
+    fn near_field_obstacles_stay_unclustered_when_los_is_enforced() {
+        let mut snap = base_snapshot();
+        snap.obstacles = vec![IVec2 { x: 1, y: 0 }, IVec2 { x: 2, y: 0 }];
+        let registry = default_tool_registry();
+        assert!(registry.constraints.enforce_los);
+
+        let summary = summarize_snapshot(&snap, &registry, &SummaryConfig::default());
+        assert_eq!(summary.obstacle_clusters.len(), 2);
+        assert!(summary.obstacle_clusters.iter().all(|c| c.count == 1));
+        assert_eq!(summary.obstacles_omitted, 0);
+    }
+
+    #[test]
+    fn far_field_obstacles_are_clustered_into_grid_cells() {
+        let mut snap = base_snapshot();
+        // All within the same 5x5 cell, far from the companion.
+        snap.obstacles = vec![
+            IVec2 { x: 40, y: 40 },
+            IVec2 { x: 41, y: 41 },
+            IVec2 { x: 42, y: 42 },
+        ];
+        let registry = default_tool_registry();
+
+        let summary = summarize_snapshot(&snap, &registry, &SummaryConfig::default());
+        assert_eq!(summary.obstacle_clusters.len(), 1);
+        assert_eq!(summary.obstacle_clusters[0].count, 3);
+    }
+
+    #[test]
+    fn excess_clusters_beyond_the_cap_are_counted_as_omitted() {
+        let mut snap = base_snapshot();
+        // 20 obstacles, each in its own far-apart cell, so each is its own cluster.
+        snap.obstacles = (0..20).map(|i| IVec2 { x: i * 100, y: i * 100 }).collect();
+        let registry = default_tool_registry();
+        let config = SummaryConfig {
+            max_obstacle_clusters: 5,
+            ..SummaryConfig::default()
+        };
+
+        let summary = summarize_snapshot(&snap, &registry, &config);
+        assert_eq!(summary.obstacle_clusters.len(), 5);
+        assert_eq!(summary.obstacles_omitted, 15);
+    }
+
+    #[test]
+    fn pois_are_grouped_by_label_with_the_k_nearest_kept() {
+        let mut snap = base_snapshot();
+        snap.pois = vec![
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 1, y: 0 },
+            },
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 2, y: 0 },
+            },
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 3, y: 0 },
+            },
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 4, y: 0 },
+            },
+            Poi {
+                k: "medkit".into(),
+                pos: IVec2 { x: 10, y: 0 },
+            },
+        ];
+        let registry = default_tool_registry();
+        let config = SummaryConfig {
+            max_pois_per_group: 2,
+            ..SummaryConfig::default()
+        };
+
+        let summary = summarize_snapshot(&snap, &registry, &config);
+        assert_eq!(summary.poi_groups.len(), 2);
+        let ammo = summary.poi_groups.iter().find(|g| g.label == "ammo").unwrap();
+        assert_eq!(ammo.count, 4);
+        assert_eq!(ammo.nearest, vec![IVec2 { x: 1, y: 0 }, IVec2 { x: 2, y: 0 }]);
+        assert_eq!(ammo.omitted, 2);
+        assert_eq!(summary.pois_omitted, 2);
+    }
+
+    #[test]
+    fn render_summary_includes_an_explicit_omitted_marker() {
+        let mut snap = base_snapshot();
+        snap.pois = vec![
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 1, y: 0 },
+            },
+            Poi {
+                k: "ammo".into(),
+                pos: IVec2 { x: 2, y: 0 },
+            },
+        ];
+        let registry = default_tool_registry();
+        let config = SummaryConfig {
+            max_pois_per_group: 1,
+            ..SummaryConfig::default()
+        };
+
+        let summary = summarize_snapshot(&snap, &registry, &config);
+        let rendered = render_summary(&summary);
+        assert!(rendered.contains("1 more omitted"));
+    }
+
+    #[test]
+    fn no_obstacles_or_pois_produces_an_empty_summary() {
+        let snap = base_snapshot();
+        let registry = default_tool_registry();
+        let summary = summarize_snapshot(&snap, &registry, &SummaryConfig::default());
+        assert!(summary.obstacle_clusters.is_empty());
+        assert!(summary.poi_groups.is_empty());
+        assert_eq!(render_summary(&summary), "");
+    }
+}
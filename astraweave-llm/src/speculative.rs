@@ -0,0 +1,207 @@
+//! Speculative plan precomputation for idle frames.
+//!
+//! [`crate::plan_from_llm`] already caches by a hash of the rendered prompt,
+//! so if the *next* [`WorldSnapshot`] an agent sees happens to match one
+//! we've already planned for, the real call is an exact cache hit and skips
+//! the LLM round-trip entirely. [`SpeculativePlanner`] watches an agent's
+//! snapshots for a run of identical observations (i.e. the agent has gone
+//! idle), and once it's confident nothing is changing, calls
+//! [`crate::plan_from_llm`] on a handful of predicted future snapshots so
+//! their plans are already warm in [`crate::cache`] before the agent
+//! actually needs them.
+
+use astraweave_core::{IVec2, ToolRegistry, WorldSnapshot};
+
+use crate::LlmClient;
+
+/// Consecutive identical observations required before a snapshot is
+/// considered idle and worth speculating on.
+const DEFAULT_STABLE_TICKS: u32 = 3;
+
+/// One-step nudges used to predict the companion's most likely next
+/// positions. [`WorldSnapshot`] carries no velocity, so a stable agent's
+/// most likely next moves are simply a single step in each cardinal
+/// direction.
+const CARDINAL_STEPS: [IVec2; 4] = [
+    IVec2 { x: 1, y: 0 },
+    IVec2 { x: -1, y: 0 },
+    IVec2 { x: 0, y: 1 },
+    IVec2 { x: 0, y: -1 },
+];
+
+/// Predicts up to `candidates` future [`WorldSnapshot`]s by stepping [`WorldSnapshot::me`]
+/// one tile in each cardinal direction and advancing `t` by one tick.
+/// Capped at [`CARDINAL_STEPS`]'s length regardless of `candidates`.
+fn predict_future_snapshots(snap: &WorldSnapshot, candidates: usize) -> Vec<WorldSnapshot> {
+    CARDINAL_STEPS
+        .iter()
+        .take(candidates.min(CARDINAL_STEPS.len()))
+        .map(|step| {
+            let mut predicted = snap.clone();
+            predicted.t += 1.0;
+            predicted.me.pos = IVec2::new(snap.me.pos.x + step.x, snap.me.pos.y + step.y);
+            predicted
+        })
+        .collect()
+}
+
+/// Tracks whether an agent's snapshots have gone idle, and precomputes plans
+/// for its likely future states once they have.
+pub struct SpeculativePlanner {
+    stable_ticks: u32,
+    candidates: usize,
+    last_prompt: Option<String>,
+    stable_count: u32,
+}
+
+impl Default for SpeculativePlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpeculativePlanner {
+    /// Creates a planner using [`DEFAULT_STABLE_TICKS`] and one candidate per
+    /// cardinal direction.
+    pub fn new() -> Self {
+        Self::with_config(DEFAULT_STABLE_TICKS, CARDINAL_STEPS.len())
+    }
+
+    pub fn with_config(stable_ticks: u32, candidates: usize) -> Self {
+        Self {
+            stable_ticks,
+            candidates,
+            last_prompt: None,
+            stable_count: 0,
+        }
+    }
+
+    /// Records one real observation of `snap` and reports whether the agent
+    /// has now been idle for long enough to speculate on. Compares rendered
+    /// prompts rather than the snapshot itself, since [`WorldSnapshot`]
+    /// doesn't implement `PartialEq`.
+    pub fn observe(&mut self, snap: &WorldSnapshot, reg: &ToolRegistry) -> bool {
+        let prompt = crate::build_prompt(snap, reg);
+        self.stable_count = if self.last_prompt.as_deref() == Some(prompt.as_str()) {
+            self.stable_count + 1
+        } else {
+            1
+        };
+        self.last_prompt = Some(prompt);
+        self.stable_count >= self.stable_ticks
+    }
+
+    /// Precomputes and caches plans for `snap`'s predicted future states.
+    /// Intended to be called once [`Self::observe`] reports the agent is
+    /// idle. Returns the number of candidate snapshots warmed.
+    ///
+    /// Only meaningful with the `llm_cache` feature enabled, since it relies
+    /// on [`crate::plan_from_llm`]'s cache side effect to make the result
+    /// useful; without it this just spends LLM calls for no benefit.
+    pub async fn speculate(
+        &self,
+        snap: &WorldSnapshot,
+        client: &dyn LlmClient,
+        reg: &ToolRegistry,
+    ) -> usize {
+        let mut warmed = 0;
+        for candidate in predict_future_snapshots(snap, self.candidates) {
+            crate::plan_from_llm(client, &candidate, reg).await;
+            warmed += 1;
+        }
+        warmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{CompanionState, Constraints, PlayerState, ToolSpec};
+    use std::collections::BTreeMap;
+
+    fn registry() -> ToolRegistry {
+        ToolRegistry {
+            tools: vec![ToolSpec {
+                name: "MoveTo".into(),
+                args: BTreeMap::new(),
+            }],
+            constraints: Constraints {
+                enforce_cooldowns: true,
+                enforce_los: true,
+                enforce_stamina: true,
+            },
+        }
+    }
+
+    fn snapshot() -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2::new(0, 0),
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: BTreeMap::new(),
+                morale: 1.0,
+                pos: IVec2::new(5, 5),
+            },
+            enemies: vec![],
+            pois: vec![],
+            obstacles: vec![],
+            objective: None,
+        }
+    }
+
+    #[test]
+    fn predicts_one_snapshot_per_cardinal_step() {
+        let predicted = predict_future_snapshots(&snapshot(), 4);
+        assert_eq!(predicted.len(), 4);
+        let positions: Vec<IVec2> = predicted.iter().map(|s| s.me.pos).collect();
+        assert!(positions.contains(&IVec2::new(6, 5)));
+        assert!(positions.contains(&IVec2::new(4, 5)));
+        assert!(positions.contains(&IVec2::new(5, 6)));
+        assert!(positions.contains(&IVec2::new(5, 4)));
+        assert!(predicted.iter().all(|s| s.t == 1.0));
+    }
+
+    #[test]
+    fn predict_caps_candidates_at_available_directions() {
+        let predicted = predict_future_snapshots(&snapshot(), 100);
+        assert_eq!(predicted.len(), CARDINAL_STEPS.len());
+    }
+
+    #[test]
+    fn observe_reports_stable_only_after_enough_identical_ticks() {
+        let mut planner = SpeculativePlanner::with_config(3, 4);
+        let reg = registry();
+        let snap = snapshot();
+
+        assert!(!planner.observe(&snap, &reg));
+        assert!(!planner.observe(&snap, &reg));
+        assert!(planner.observe(&snap, &reg));
+    }
+
+    #[test]
+    fn observe_resets_run_when_snapshot_changes() {
+        let mut planner = SpeculativePlanner::with_config(2, 4);
+        let reg = registry();
+        let mut snap = snapshot();
+
+        assert!(!planner.observe(&snap, &reg));
+        snap.me.pos = IVec2::new(6, 5);
+        assert!(!planner.observe(&snap, &reg));
+        assert!(planner.observe(&snap, &reg));
+    }
+
+    #[tokio::test]
+    async fn speculate_warms_a_candidate_per_cardinal_step() {
+        let planner = SpeculativePlanner::new();
+        let warmed = planner
+            .speculate(&snapshot(), &crate::MockLlm, &registry())
+            .await;
+        assert_eq!(warmed, CARDINAL_STEPS.len());
+    }
+}
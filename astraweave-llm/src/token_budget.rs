@@ -0,0 +1,200 @@
+//! Accurate token counting and prompt budgeting for model context windows.
+//!
+//! The chars/4 heuristic used elsewhere in this crate (see
+//! `estimate_tokens`) misestimates badly for JSON-heavy prompts, where
+//! punctuation-dense text tokenizes very differently than English prose.
+//! [`TokenCounter`] abstracts over that heuristic and a real tokenizer per
+//! model family, and [`TokenBudget`] uses whichever counter is on hand to
+//! deterministically trim a [`WorldSnapshot`]'s POI/obstacle lists until a
+//! prompt built from it fits a model's context window.
+
+use astraweave_core::{IVec2, Poi, WorldSnapshot};
+
+/// Counts tokens in a piece of text for a specific model family.
+pub trait TokenCounter: Send + Sync {
+    fn count(&self, text: &str) -> u32;
+}
+
+/// The chars/4 approximation used throughout this crate when no
+/// model-specific tokenizer is loaded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeuristicTokenCounter;
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn count(&self, text: &str) -> u32 {
+        (text.len() / 4) as u32
+    }
+}
+
+/// Real BPE tokenization via Hugging Face `tokenizers`, for model families
+/// that ship a `tokenizer.json` (Phi-3 and friends). Falls back to the
+/// chars/4 heuristic if a string somehow fails to encode rather than
+/// panicking mid-prompt-build.
+#[cfg(feature = "phi3")]
+pub struct HfTokenCounter {
+    tokenizer: tokenizers::Tokenizer,
+}
+
+#[cfg(feature = "phi3")]
+impl HfTokenCounter {
+    /// Loads a tokenizer from a HF `tokenizer.json` file, as shipped
+    /// alongside `astraweave_llm::phi3::Phi3Medium`'s model weights.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        let tokenizer = tokenizers::Tokenizer::from_file(path)
+            .map_err(|e| anyhow::anyhow!("failed to load tokenizer: {e}"))?;
+        Ok(Self { tokenizer })
+    }
+}
+
+#[cfg(feature = "phi3")]
+impl TokenCounter for HfTokenCounter {
+    fn count(&self, text: &str) -> u32 {
+        self.tokenizer
+            .encode(text, false)
+            .map(|enc| enc.len() as u32)
+            .unwrap_or_else(|_| (text.len() / 4) as u32)
+    }
+}
+
+/// How many obstacles/POIs [`TokenBudget::fit`] had to drop to make a
+/// snapshot fit, so callers can log what was omitted rather than silently
+/// truncating.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TruncationReport {
+    pub obstacles_dropped: usize,
+    pub pois_dropped: usize,
+}
+
+/// Trims a [`WorldSnapshot`] to fit within a model's context window under a
+/// given [`TokenCounter`].
+pub struct TokenBudget {
+    pub max_tokens: u32,
+}
+
+impl TokenBudget {
+    pub fn new(max_tokens: u32) -> Self {
+        Self { max_tokens }
+    }
+
+    /// Sorts `snap`'s obstacles and POIs nearest-first (relative to
+    /// `snap.me.pos`), then repeatedly drops the single farthest obstacle
+    /// (and once obstacles are exhausted, the farthest POI) until the
+    /// snapshot serializes under `self.max_tokens` tokens or nothing more
+    /// can be dropped. Enemies are left untouched: dropping an enemy here
+    /// would hide a threat for no perceptual reason, unlike
+    /// `astraweave_ai::snapshot_builder`'s LOS-based redaction, which runs
+    /// earlier in the pipeline and has one.
+    pub fn fit(
+        &self,
+        mut snap: WorldSnapshot,
+        counter: &dyn TokenCounter,
+    ) -> (WorldSnapshot, TruncationReport) {
+        let me_pos = snap.me.pos;
+        sort_obstacles_nearest_first(&mut snap.obstacles, me_pos);
+        sort_pois_nearest_first(&mut snap.pois, me_pos);
+
+        let mut report = TruncationReport::default();
+        while self.serialized_tokens(&snap, counter) > self.max_tokens {
+            if snap.obstacles.pop().is_some() {
+                report.obstacles_dropped += 1;
+            } else if snap.pois.pop().is_some() {
+                report.pois_dropped += 1;
+            } else {
+                break;
+            }
+        }
+
+        (snap, report)
+    }
+
+    fn serialized_tokens(&self, snap: &WorldSnapshot, counter: &dyn TokenCounter) -> u32 {
+        serde_json::to_string(snap)
+            .map(|s| counter.count(&s))
+            .unwrap_or(0)
+    }
+}
+
+fn manhattan(a: IVec2, b: IVec2) -> i32 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+fn sort_obstacles_nearest_first(obstacles: &mut [IVec2], from: IVec2) {
+    obstacles.sort_by_key(|o| manhattan(*o, from));
+}
+
+fn sort_pois_nearest_first(pois: &mut [Poi], from: IVec2) {
+    pois.sort_by_key(|p| manhattan(p.pos, from));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::{CompanionState, PlayerState};
+    use std::collections::BTreeMap;
+
+    fn snap_with(obstacles: Vec<IVec2>, pois: Vec<Poi>) -> WorldSnapshot {
+        WorldSnapshot {
+            t: 0.0,
+            player: PlayerState {
+                hp: 100,
+                pos: IVec2 { x: 0, y: 0 },
+                stance: "stand".into(),
+                orders: vec![],
+            },
+            me: CompanionState {
+                ammo: 10,
+                cooldowns: BTreeMap::new(),
+                morale: 1.0,
+                pos: IVec2 { x: 0, y: 0 },
+            },
+            enemies: vec![],
+            pois,
+            obstacles,
+            objective: None,
+        }
+    }
+
+    #[test]
+    fn heuristic_counter_matches_chars_over_four() {
+        let counter = HeuristicTokenCounter;
+        assert_eq!(counter.count("12345678"), 2);
+    }
+
+    #[test]
+    fn fit_is_a_no_op_when_already_under_budget() {
+        let snap = snap_with(vec![IVec2 { x: 1, y: 1 }], vec![]);
+        let budget = TokenBudget::new(10_000);
+        let (fitted, report) = budget.fit(snap, &HeuristicTokenCounter);
+        assert_eq!(fitted.obstacles.len(), 1);
+        assert_eq!(report, TruncationReport::default());
+    }
+
+    #[test]
+    fn fit_drops_farthest_obstacles_first() {
+        let near = IVec2 { x: 1, y: 0 };
+        let far = IVec2 { x: 50, y: 50 };
+        let snap = snap_with(vec![far, near], vec![]);
+        let budget = TokenBudget::new(1);
+        let (fitted, report) = budget.fit(snap, &HeuristicTokenCounter);
+        assert!(report.obstacles_dropped >= 1);
+        assert!(!fitted.obstacles.contains(&far) || fitted.obstacles.is_empty());
+    }
+
+    #[test]
+    fn fit_drops_pois_only_after_obstacles_are_exhausted() {
+        let snap = snap_with(
+            vec![IVec2 { x: 1, y: 0 }],
+            vec![Poi {
+                k: "goal".into(),
+                pos: IVec2 { x: 2, y: 0 },
+            }],
+        );
+        let budget = TokenBudget::new(1);
+        let (fitted, report) = budget.fit(snap, &HeuristicTokenCounter);
+        assert_eq!(fitted.obstacles.len(), 0);
+        assert!(report.obstacles_dropped >= 1);
+        if !fitted.pois.is_empty() {
+            assert_eq!(report.pois_dropped, 0);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Data-driven collision layer/mask configuration.
+//!
+//! [`Layers`] only defines two compile-time bitflags (`DEFAULT`, `CHARACTER`).
+//! [`CollisionMatrix`] extends that with named, runtime-registered layers
+//! (up to 32, one per bit) and a sparse table of which layer pairs should
+//! *not* collide (e.g. "ragdoll doesn't hit ragdoll"), so a project can
+//! reconfigure collision filtering without editing engine code. Load one
+//! from TOML with [`CollisionMatrix::from_toml`] (requires the
+//! `collision-toml` feature), or build one up in code with
+//! [`CollisionMatrix::register_layer`]/[`CollisionMatrix::set_collision`].
+
+use crate::{Group, InteractionGroups};
+use std::collections::HashMap;
+
+/// Bit index of a layer registered with a [`CollisionMatrix`].
+pub type LayerId = u32;
+
+/// A named collision layer/mask registry. Every registered layer occupies
+/// one bit (up to 32 layers); by default all registered layers collide with
+/// each other, and [`Self::set_collision`] disables specific pairs.
+#[derive(Clone, Debug, Default)]
+pub struct CollisionMatrix {
+    layer_ids: HashMap<String, LayerId>,
+    layer_names: Vec<String>,
+    /// Pairs `(min(a, b), max(a, b))` that do *not* collide.
+    disabled_pairs: std::collections::HashSet<(LayerId, LayerId)>,
+}
+
+impl CollisionMatrix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `name` as a new layer, returning its bit index. Registering
+    /// the same name twice returns the existing id rather than allocating a
+    /// second bit.
+    ///
+    /// # Panics
+    /// Panics if more than 32 distinct layers are registered.
+    pub fn register_layer(&mut self, name: impl Into<String>) -> LayerId {
+        let name = name.into();
+        if let Some(id) = self.layer_ids.get(&name) {
+            return *id;
+        }
+        let id = self.layer_names.len() as LayerId;
+        assert!(id < 32, "CollisionMatrix supports at most 32 layers");
+        self.layer_names.push(name.clone());
+        self.layer_ids.insert(name, id);
+        id
+    }
+
+    /// Looks up a previously registered layer's bit index.
+    pub fn layer_id(&self, name: &str) -> Option<LayerId> {
+        self.layer_ids.get(name).copied()
+    }
+
+    /// Enables or disables collision between two layers (order doesn't
+    /// matter). Both layers must already be registered.
+    pub fn set_collision(&mut self, a: &str, b: &str, enabled: bool) -> Option<()> {
+        let a = self.layer_id(a)?;
+        let b = self.layer_id(b)?;
+        let key = (a.min(b), a.max(b));
+        if enabled {
+            self.disabled_pairs.remove(&key);
+        } else {
+            self.disabled_pairs.insert(key);
+        }
+        Some(())
+    }
+
+    /// Whether layers `a` and `b` are configured to collide. Unregistered
+    /// layers never collide.
+    pub fn collides(&self, a: &str, b: &str) -> bool {
+        let (Some(a), Some(b)) = (self.layer_id(a), self.layer_id(b)) else {
+            return false;
+        };
+        !self.disabled_pairs.contains(&(a.min(b), a.max(b)))
+    }
+
+    /// Builds the Rapier [`InteractionGroups`] for a collider assigned to
+    /// `layer`: membership is that layer's single bit, and the filter mask
+    /// is the union of every other registered layer's bit that isn't
+    /// disabled against it (including itself, unless disabled).
+    pub fn interaction_groups(&self, layer: &str) -> Option<InteractionGroups> {
+        let id = self.layer_id(layer)?;
+        let mut mask = 0u32;
+        for other in 0..self.layer_names.len() as LayerId {
+            let key = (id.min(other), id.max(other));
+            if !self.disabled_pairs.contains(&key) {
+                mask |= 1 << other;
+            }
+        }
+        Some(InteractionGroups::new(
+            Group::from_bits_truncate(1 << id),
+            Group::from_bits_truncate(mask),
+        ))
+    }
+
+    /// Names of every registered layer, in registration order (index ==
+    /// [`LayerId`]).
+    pub fn layer_names(&self) -> &[String] {
+        &self.layer_names
+    }
+}
+
+/// TOML-authored collision matrix, e.g.:
+/// ```toml
+/// layers = ["default", "character", "ragdoll", "projectile"]
+///
+/// [[disable]]
+/// a = "ragdoll"
+/// b = "ragdoll"
+/// ```
+#[cfg(feature = "collision-toml")]
+#[derive(serde::Deserialize)]
+struct CollisionMatrixFile {
+    layers: Vec<String>,
+    #[serde(default)]
+    disable: Vec<DisabledPair>,
+}
+
+#[cfg(feature = "collision-toml")]
+#[derive(serde::Deserialize)]
+struct DisabledPair {
+    a: String,
+    b: String,
+}
+
+#[cfg(feature = "collision-toml")]
+impl CollisionMatrix {
+    /// Parses a TOML-authored layer list and disabled-pair table into a
+    /// [`CollisionMatrix`]. Returns an error if a `disable` entry names a
+    /// layer that isn't in `layers`.
+    pub fn from_toml(toml_txt: &str) -> anyhow::Result<Self> {
+        let file: CollisionMatrixFile = toml::from_str(toml_txt)?;
+        let mut matrix = Self::new();
+        for name in file.layers {
+            matrix.register_layer(name);
+        }
+        for pair in file.disable {
+            matrix
+                .set_collision(&pair.a, &pair.b, false)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "collision matrix disable entry references unregistered layer: {} / {}",
+                        pair.a,
+                        pair.b
+                    )
+                })?;
+        }
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CollisionMatrix {
+        let mut m = CollisionMatrix::new();
+        m.register_layer("default");
+        m.register_layer("character");
+        m.register_layer("ragdoll");
+        m
+    }
+
+    #[test]
+    fn registering_same_name_twice_reuses_bit() {
+        let mut m = CollisionMatrix::new();
+        let a = m.register_layer("default");
+        let b = m.register_layer("default");
+        assert_eq!(a, b);
+        assert_eq!(m.layer_names().len(), 1);
+    }
+
+    #[test]
+    fn all_layers_collide_by_default() {
+        let m = sample();
+        assert!(m.collides("default", "character"));
+        assert!(m.collides("ragdoll", "ragdoll"));
+    }
+
+    #[test]
+    fn set_collision_disables_a_pair_symmetrically() {
+        let mut m = sample();
+        m.set_collision("ragdoll", "ragdoll", false).unwrap();
+        assert!(!m.collides("ragdoll", "ragdoll"));
+        assert!(m.collides("default", "ragdoll"));
+    }
+
+    #[test]
+    fn set_collision_on_unregistered_layer_returns_none() {
+        let mut m = sample();
+        assert!(m.set_collision("default", "missing", false).is_none());
+    }
+
+    #[test]
+    fn interaction_groups_masks_out_disabled_pair() {
+        let mut m = sample();
+        let ragdoll = m.layer_id("ragdoll").unwrap();
+        m.set_collision("ragdoll", "ragdoll", false).unwrap();
+
+        let groups = m.interaction_groups("ragdoll").unwrap();
+        assert_eq!(groups.memberships, Group::from_bits_truncate(1 << ragdoll));
+        assert!(!groups.filter.contains(Group::from_bits_truncate(1 << ragdoll)));
+
+        let default_id = m.layer_id("default").unwrap();
+        assert!(groups.filter.contains(Group::from_bits_truncate(1 << default_id)));
+    }
+
+    #[test]
+    fn interaction_groups_for_unregistered_layer_is_none() {
+        let m = sample();
+        assert!(m.interaction_groups("missing").is_none());
+    }
+}
@@ -6,7 +6,7 @@
 //! - Debris generation and lifetime
 //! - Force-based destruction triggers
 
-use glam::Vec3;
+use glam::{Quat, Vec3};
 use std::collections::HashMap;
 
 /// Unique identifier for destructible objects
@@ -37,6 +37,36 @@ impl Default for DebrisShape {
     }
 }
 
+impl DebrisShape {
+    /// Approximate render scale (full extents, not half) for instanced
+    /// rendering, since [`DebrisInstanceTransform`] carries a uniform mesh
+    /// scale rather than a shape-specific one.
+    fn instance_scale(&self) -> Vec3 {
+        match self {
+            DebrisShape::Box { half_extents } | DebrisShape::ConvexHull { half_extents } => {
+                *half_extents * 2.0
+            }
+            DebrisShape::Sphere { radius } => Vec3::splat(radius * 2.0),
+        }
+    }
+}
+
+/// How a debris piece is currently being simulated/rendered.
+///
+/// Individually-simulated rigid-body debris is expensive at scale (see
+/// [`DestructionManager::max_debris`] and [`DestructionManager::update_lod`]);
+/// debris far from the viewer is demoted to a non-colliding, GPU-instanced
+/// visual to keep large explosions cheap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DebrisRenderMode {
+    /// Simulated as an individual rigid body (has a physics `body_id`).
+    #[default]
+    Physics,
+    /// Demoted: no longer collides, ballistic-integrated and rendered via
+    /// [`DestructionManager::instanced_debris_transforms`] instead.
+    Instanced,
+}
+
 /// Configuration for a debris piece
 #[derive(Debug, Clone)]
 pub struct DebrisConfig {
@@ -381,6 +411,9 @@ pub struct Debris {
     pub config: DebrisConfig,
     /// World position
     pub position: Vec3,
+    /// World orientation (integrated from `angular_velocity` when not
+    /// physics-driven; used for [`DestructionManager::instanced_debris_transforms`]).
+    pub rotation: Quat,
     /// Linear velocity
     pub velocity: Vec3,
     /// Angular velocity
@@ -389,6 +422,8 @@ pub struct Debris {
     pub age: f32,
     /// Physics body ID (if any)
     pub body_id: Option<u64>,
+    /// Current simulation/rendering mode; see [`DebrisRenderMode`].
+    pub render_mode: DebrisRenderMode,
 }
 
 impl Debris {
@@ -405,10 +440,12 @@ impl Debris {
             source,
             config,
             position,
+            rotation: Quat::IDENTITY,
             velocity,
             angular_velocity: Vec3::ZERO,
             age: 0.0,
             body_id: None,
+            render_mode: DebrisRenderMode::default(),
         }
     }
 
@@ -416,10 +453,16 @@ impl Debris {
     pub fn update(&mut self, dt: f32, gravity: Vec3) {
         self.age += dt;
 
-        // Simple physics if not driven by physics engine
+        // Simple physics if not driven by physics engine (both
+        // `Physics`-mode debris awaiting a body_id and demoted
+        // `Instanced`-mode debris integrate this way).
         if self.body_id.is_none() {
             self.velocity += gravity * dt;
             self.position += self.velocity * dt;
+            if self.angular_velocity != Vec3::ZERO {
+                let delta = Quat::from_scaled_axis(self.angular_velocity * dt);
+                self.rotation = (delta * self.rotation).normalize();
+            }
         }
     }
 
@@ -429,6 +472,18 @@ impl Debris {
     }
 }
 
+/// World transform of a demoted, GPU-instanced debris piece, ready to feed
+/// an instance buffer. Only covers [`DebrisRenderMode::Instanced`] debris --
+/// `Physics`-mode debris is expected to be rendered from its physics body
+/// transform like any other rigid body.
+#[derive(Debug, Clone, Copy)]
+pub struct DebrisInstanceTransform {
+    pub id: DebrisId,
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
 /// Destruction event for callbacks
 #[derive(Debug, Clone)]
 pub struct DestructionEvent {
@@ -456,6 +511,13 @@ pub struct DestructionManager {
     pub max_debris: usize,
     /// Default debris lifetime
     pub default_debris_lifetime: f32,
+    /// Debris farther than this from `viewer_position` is demoted to
+    /// [`DebrisRenderMode::Instanced`] by [`Self::update`]. `None` disables
+    /// automatic demotion (all debris stays `Physics`-mode).
+    pub demotion_distance: Option<f32>,
+    /// Reference point `demotion_distance` is measured from (typically the
+    /// camera/player), set via [`Self::set_viewer_position`].
+    viewer_position: Vec3,
 }
 
 impl DestructionManager {
@@ -469,9 +531,17 @@ impl DestructionManager {
             next_debris_id: 1,
             max_debris: 500,
             default_debris_lifetime: 10.0,
+            demotion_distance: Some(40.0),
+            viewer_position: Vec3::ZERO,
         }
     }
 
+    /// Set the point distant-debris demotion is measured from (usually the
+    /// camera or local player position). Takes effect on the next `update`.
+    pub fn set_viewer_position(&mut self, position: Vec3) {
+        self.viewer_position = position;
+    }
+
     // === Destructible Management ===
 
     /// Add a destructible object
@@ -635,6 +705,28 @@ impl DestructionManager {
             debris.update(dt, gravity);
         }
 
+        // Demote/promote debris based on distance from the viewer so large
+        // explosions don't keep hundreds of individually-simulated rigid
+        // bodies alive far off-screen.
+        if let Some(demotion_distance) = self.demotion_distance {
+            for debris in self.debris.values_mut() {
+                let far = debris.position.distance(self.viewer_position) > demotion_distance;
+                let mode = if far {
+                    DebrisRenderMode::Instanced
+                } else {
+                    DebrisRenderMode::Physics
+                };
+                if debris.render_mode != mode {
+                    debris.render_mode = mode;
+                    if mode == DebrisRenderMode::Instanced {
+                        // Drop the physics body -- instanced debris is
+                        // ballistic-only and non-colliding (see `Debris::update`).
+                        debris.body_id = None;
+                    }
+                }
+            }
+        }
+
         // Remove expired debris
         self.debris.retain(|_, d| !d.should_remove());
 
@@ -662,6 +754,30 @@ impl DestructionManager {
         self.debris.values().filter(|d| !d.should_remove()).count()
     }
 
+    /// Number of debris pieces currently demoted to instanced rendering.
+    pub fn instanced_debris_count(&self) -> usize {
+        self.debris
+            .values()
+            .filter(|d| d.render_mode == DebrisRenderMode::Instanced)
+            .count()
+    }
+
+    /// World transforms of all currently-instanced (demoted) debris, ready
+    /// to upload to a GPU instance buffer. `Physics`-mode debris is excluded
+    /// -- render it from its physics body transform instead.
+    pub fn instanced_debris_transforms(&self) -> Vec<DebrisInstanceTransform> {
+        self.debris
+            .values()
+            .filter(|d| d.render_mode == DebrisRenderMode::Instanced)
+            .map(|d| DebrisInstanceTransform {
+                id: d.id,
+                position: d.position,
+                rotation: d.rotation,
+                scale: d.config.shape.instance_scale(),
+            })
+            .collect()
+    }
+
     /// Clean up destroyed destructibles
     pub fn cleanup_destroyed(&mut self) {
         self.destructibles.retain(|_, d| !d.is_destroyed());
@@ -2759,4 +2875,113 @@ mod tests {
             v1, v2
         );
     }
+
+    // --- Debris LOD demotion / instanced transforms ---
+
+    fn spawn_one_debris_at(mgr: &mut DestructionManager, position: Vec3) -> DebrisId {
+        let config = DestructibleConfig {
+            max_health: 1.0,
+            fracture_pattern: FracturePattern::uniform(1, Vec3::splat(1.0), 10.0),
+            destruction_force: 5.0,
+            ..Default::default()
+        };
+        let id = mgr.add_destructible(config, position);
+        mgr.apply_damage(id, 100.0);
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+        mgr.debris_iter().next().unwrap().id
+    }
+
+    #[test]
+    fn debris_starts_in_physics_mode() {
+        let mut mgr = DestructionManager::new();
+        let id = spawn_one_debris_at(&mut mgr, Vec3::ZERO);
+        assert_eq!(
+            mgr.get_debris(id).unwrap().render_mode,
+            DebrisRenderMode::Physics
+        );
+        assert_eq!(mgr.instanced_debris_count(), 0);
+    }
+
+    #[test]
+    fn distant_debris_is_demoted_to_instanced() {
+        let mut mgr = DestructionManager::new();
+        mgr.demotion_distance = Some(10.0);
+        let id = spawn_one_debris_at(&mut mgr, Vec3::new(1000.0, 0.0, 0.0));
+
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+
+        assert_eq!(
+            mgr.get_debris(id).unwrap().render_mode,
+            DebrisRenderMode::Instanced
+        );
+        assert_eq!(mgr.instanced_debris_count(), 1);
+        assert!(mgr.get_debris(id).unwrap().body_id.is_none());
+    }
+
+    #[test]
+    fn nearby_debris_is_not_demoted() {
+        let mut mgr = DestructionManager::new();
+        mgr.demotion_distance = Some(1000.0);
+        let id = spawn_one_debris_at(&mut mgr, Vec3::ZERO);
+
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+
+        assert_eq!(
+            mgr.get_debris(id).unwrap().render_mode,
+            DebrisRenderMode::Physics
+        );
+        assert_eq!(mgr.instanced_debris_count(), 0);
+    }
+
+    #[test]
+    fn demotion_disabled_when_distance_is_none() {
+        let mut mgr = DestructionManager::new();
+        mgr.demotion_distance = None;
+        let id = spawn_one_debris_at(&mut mgr, Vec3::new(1e6, 0.0, 0.0));
+
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+
+        assert_eq!(
+            mgr.get_debris(id).unwrap().render_mode,
+            DebrisRenderMode::Physics
+        );
+    }
+
+    #[test]
+    fn instanced_debris_transforms_reflect_position_and_scale() {
+        let mut mgr = DestructionManager::new();
+        mgr.demotion_distance = Some(5.0);
+        mgr.set_viewer_position(Vec3::ZERO);
+        let far_pos = Vec3::new(50.0, 0.0, 0.0);
+        spawn_one_debris_at(&mut mgr, far_pos);
+
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+
+        let transforms = mgr.instanced_debris_transforms();
+        assert_eq!(transforms.len(), 1);
+        // Debris integrates a frame of velocity before the transform is read,
+        // so just check it's still in the right neighborhood.
+        assert!((transforms[0].position - far_pos).length() < 5.0);
+        assert!(transforms[0].scale.x > 0.0);
+    }
+
+    #[test]
+    fn promoted_debris_leaves_instanced_transforms() {
+        let mut mgr = DestructionManager::new();
+        mgr.demotion_distance = Some(10.0);
+        let id = spawn_one_debris_at(&mut mgr, Vec3::new(1000.0, 0.0, 0.0));
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+        assert_eq!(mgr.instanced_debris_count(), 1);
+
+        // Bring the viewer to where the debris now is.
+        let pos = mgr.get_debris(id).unwrap().position;
+        mgr.set_viewer_position(pos);
+        mgr.update(1.0 / 60.0, Vec3::ZERO);
+
+        assert_eq!(
+            mgr.get_debris(id).unwrap().render_mode,
+            DebrisRenderMode::Physics
+        );
+        assert_eq!(mgr.instanced_debris_transforms().len(), 0);
+    }
 }
@@ -1,12 +1,148 @@
-use crate::{BodyId, PhysicsWorld};
-use astraweave_ecs::{App, Plugin, SystemStage, World};
+use crate::{BodyId, CollisionEvent, DebugRenderCategories, PhysicsWorld};
+use astraweave_ecs::{App, Entity, Event, Events, Plugin, SystemStage, World};
 use astraweave_scene::Transform;
 use glam::Vec3;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "async-physics")]
+use crate::{RayHit, RayRequest};
 
 /// Component that links an entity to a physics body
 #[derive(Clone, Copy, Debug)]
 pub struct PhysicsBodyComponent(pub BodyId);
 
+/// Opt-in component controlling which physics events
+/// [`collision_event_bridge_system`] publishes for an entity. Entities keep
+/// colliding without it -- this only gates whether that entity's collisions
+/// get turned into ECS events, so gameplay/AI code isn't flooded with events
+/// nobody subscribed to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct CollisionEventInterest {
+    /// Publish [`CollisionStarted`]/[`CollisionStopped`] for this entity.
+    pub collisions: bool,
+    /// Publish [`ContactForceExceeded`] for this entity.
+    pub contact_forces: bool,
+}
+
+impl CollisionEventInterest {
+    /// Subscribe to both collision start/stop and contact-force events.
+    pub fn all() -> Self {
+        Self {
+            collisions: true,
+            contact_forces: true,
+        }
+    }
+
+    /// Subscribe to collision start/stop only.
+    pub fn collisions_only() -> Self {
+        Self {
+            collisions: true,
+            contact_forces: false,
+        }
+    }
+}
+
+/// Published by [`collision_event_bridge_system`] when two entities with
+/// [`CollisionEventInterest::collisions`] begin touching. Sent once per
+/// entity, from that entity's point of view (`other` is the entity it
+/// touched), so each side can react independently.
+#[derive(Clone, Copy, Debug)]
+pub struct CollisionStarted {
+    pub entity: Entity,
+    pub other: Entity,
+}
+impl Event for CollisionStarted {}
+
+/// Published by [`collision_event_bridge_system`] when two entities with
+/// [`CollisionEventInterest::collisions`] stop touching. See
+/// [`CollisionStarted`] for the per-side delivery convention.
+#[derive(Clone, Copy, Debug)]
+pub struct CollisionStopped {
+    pub entity: Entity,
+    pub other: Entity,
+}
+impl Event for CollisionStopped {}
+
+/// Published by [`collision_event_bridge_system`] for entities with
+/// [`CollisionEventInterest::contact_forces`] when rapier reports a contact
+/// force event between their body and another tracked body.
+#[derive(Clone, Copy, Debug)]
+pub struct ContactForceExceeded {
+    pub entity: Entity,
+    pub other: Entity,
+    pub total_force_magnitude: f32,
+}
+impl Event for ContactForceExceeded {}
+
+/// Marker component identifying an entity's [`PhysicsBodyComponent`] as a
+/// sensor volume created with [`PhysicsWorld::add_trigger_volume`], tracked
+/// by [`trigger_event_bridge_system`] rather than the plain collision
+/// bridge. Entities without this marker are only ever the `other` side of a
+/// [`TriggerEnter`]/[`TriggerStay`]/[`TriggerExit`] event.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TriggerVolume;
+
+/// Published by [`trigger_event_bridge_system`] the first step `other`
+/// begins overlapping `trigger`.
+#[derive(Clone, Copy, Debug)]
+pub struct TriggerEnter {
+    pub trigger: Entity,
+    pub other: Entity,
+}
+impl Event for TriggerEnter {}
+
+/// Published by [`trigger_event_bridge_system`] every step after
+/// [`TriggerEnter`] while `other` is still overlapping `trigger`.
+#[derive(Clone, Copy, Debug)]
+pub struct TriggerStay {
+    pub trigger: Entity,
+    pub other: Entity,
+}
+impl Event for TriggerStay {}
+
+/// Published by [`trigger_event_bridge_system`] the step `other` stops
+/// overlapping `trigger`.
+#[derive(Clone, Copy, Debug)]
+pub struct TriggerExit {
+    pub trigger: Entity,
+    pub other: Entity,
+}
+impl Event for TriggerExit {}
+
+/// Resource tracking, per trigger entity, which other entities are
+/// currently overlapping it. Maintained by [`trigger_event_bridge_system`];
+/// treat as read-only from other systems.
+#[derive(Clone, Debug, Default)]
+pub struct TriggerOverlaps(HashMap<Entity, HashSet<Entity>>);
+
+impl TriggerOverlaps {
+    /// Entities currently overlapping `trigger` (empty if none, or if
+    /// `trigger` isn't a tracked [`TriggerVolume`]).
+    pub fn overlapping(&self, trigger: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.0.get(&trigger).into_iter().flatten().copied()
+    }
+
+    /// True if `other` is currently overlapping `trigger`.
+    pub fn is_overlapping(&self, trigger: Entity, other: Entity) -> bool {
+        self.0
+            .get(&trigger)
+            .is_some_and(|overlapping| overlapping.contains(&other))
+    }
+}
+
+/// Resource holding how far the current render frame is between the last
+/// two physics steps (0.0 = previous step, 1.0 = current step). The fixed
+/// timestep runner is expected to update this once per rendered frame
+/// before [`sync_physics_interpolated_transform_system`] runs.
+#[derive(Clone, Copy, Debug)]
+pub struct InterpolationAlpha(pub f32);
+
+impl Default for InterpolationAlpha {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 /// Physics plugin for ECS integration
 pub struct PhysicsPlugin;
 
@@ -15,10 +151,36 @@ impl Plugin for PhysicsPlugin {
         // Insert PhysicsWorld resource
         app.world
             .insert_resource(PhysicsWorld::new(Vec3::new(0.0, -9.81, 0.0)));
+        app.world
+            .insert_resource(DebugRenderCategories::default());
 
         // Register systems in physics stage
+        app.add_system(SystemStage::PHYSICS, apply_debug_render_categories_system);
         app.add_system(SystemStage::PHYSICS, physics_step_system);
         app.add_system(SystemStage::PHYSICS, sync_physics_to_transform_system);
+        if app.world.get_resource::<Events>().is_none() {
+            app.world.insert_resource(Events::new());
+        }
+        app.add_system(SystemStage::PHYSICS, collision_event_bridge_system);
+        app.world.insert_resource(TriggerOverlaps::default());
+        app.add_system(SystemStage::PHYSICS, trigger_event_bridge_system);
+    }
+}
+
+/// Applies the [`DebugRenderCategories`] resource to [`PhysicsWorld`] each
+/// step, so the in-game console/UI can flip debug-render categories at
+/// runtime by mutating the resource -- no direct `PhysicsWorld` access
+/// needed. Registered before [`physics_step_system`] so a category flipped
+/// this frame takes effect on the very next [`PhysicsWorld::get_debug_lines`]
+/// call.
+pub fn apply_debug_render_categories_system(world: &mut World) {
+    let Some(&categories) = world.get_resource::<DebugRenderCategories>() else {
+        return;
+    };
+    if let Some(physics_world) = world.get_resource_mut::<PhysicsWorld>() {
+        if physics_world.debug_render_categories() != categories {
+            physics_world.set_debug_render_categories(categories);
+        }
     }
 }
 
@@ -82,3 +244,299 @@ pub fn sync_physics_to_transform_system(world: &mut World) {
         world.insert(entity, transform);
     }
 }
+
+/// System that writes physics-interpolated positions/rotations to Transform
+/// components, using [`InterpolationAlpha`] to blend between the previous
+/// and current physics step. Prefer this over
+/// [`sync_physics_to_transform_system`] when rendering at a higher frame
+/// rate than the physics timestep, to avoid visible stepping.
+pub fn sync_physics_interpolated_transform_system(world: &mut World) {
+    let alpha = world
+        .get_resource::<InterpolationAlpha>()
+        .copied()
+        .unwrap_or_default()
+        .0;
+
+    let physics_world = match world.get_resource::<PhysicsWorld>() {
+        Some(pw) => pw,
+        None => return,
+    };
+
+    let mut updates: Vec<(astraweave_ecs::Entity, Transform)> = Vec::new();
+
+    let entities = world.entities_with::<PhysicsBodyComponent>();
+    for entity in entities {
+        if let Some(physics_body) = world.get::<PhysicsBodyComponent>(entity) {
+            let Some(matrix) = physics_world.interpolated_pose(physics_body.0, alpha) else {
+                continue;
+            };
+            let (_, rotation, translation) = matrix.to_scale_rotation_translation();
+
+            let current_transform = world.get::<Transform>(entity).copied().unwrap_or_default();
+            updates.push((
+                entity,
+                Transform {
+                    translation,
+                    rotation,
+                    scale: current_transform.scale,
+                },
+            ));
+        }
+    }
+
+    for (entity, transform) in updates {
+        world.insert(entity, transform);
+    }
+}
+
+/// Drains [`PhysicsWorld::collision_recv`] and `contact_force_recv` each
+/// step, maps the rapier collider handles back to entities via
+/// [`PhysicsBodyComponent`]/[`PhysicsWorld::id_of`], and publishes
+/// [`CollisionStarted`]/[`CollisionStopped`]/[`ContactForceExceeded`] to the
+/// [`Events`] resource for entities that opted in with
+/// [`CollisionEventInterest`]. Register this after
+/// [`physics_step_system`] in the same stage so the events it drains were
+/// produced by that step.
+pub fn collision_event_bridge_system(world: &mut World) {
+    let mut body_to_entity: HashMap<BodyId, Entity> = HashMap::new();
+    for entity in world.entities_with::<PhysicsBodyComponent>() {
+        if let Some(body) = world.get::<PhysicsBodyComponent>(entity) {
+            body_to_entity.insert(body.0, entity);
+        }
+    }
+
+    let mut interests: HashMap<Entity, CollisionEventInterest> = HashMap::new();
+    for entity in world.entities_with::<CollisionEventInterest>() {
+        if let Some(interest) = world.get::<CollisionEventInterest>(entity) {
+            interests.insert(entity, *interest);
+        }
+    }
+
+    let Some(physics_world) = world.get_resource::<PhysicsWorld>() else {
+        return;
+    };
+
+    let entity_of_collider = |handle| {
+        physics_world
+            .colliders
+            .get(handle)
+            .and_then(|c| c.parent())
+            .and_then(|h| physics_world.id_of(h))
+            .and_then(|body_id| body_to_entity.get(&body_id).copied())
+    };
+
+    let mut collision_started = Vec::new();
+    let mut collision_stopped = Vec::new();
+    let mut contact_forces = Vec::new();
+
+    while let Ok(event) = physics_world.collision_recv.try_recv() {
+        let (h1, h2, started) = if let CollisionEvent::Started(h1, h2, _) = event {
+            (h1, h2, true)
+        } else if let CollisionEvent::Stopped(h1, h2, _) = event {
+            (h1, h2, false)
+        } else {
+            continue;
+        };
+        let (Some(e1), Some(e2)) = (entity_of_collider(h1), entity_of_collider(h2)) else {
+            continue;
+        };
+        let list = if started {
+            &mut collision_started
+        } else {
+            &mut collision_stopped
+        };
+        list.push((e1, e2));
+        list.push((e2, e1));
+    }
+
+    while let Ok(event) = physics_world.contact_force_recv.try_recv() {
+        let (Some(e1), Some(e2)) = (
+            entity_of_collider(event.collider1),
+            entity_of_collider(event.collider2),
+        ) else {
+            continue;
+        };
+        contact_forces.push((e1, e2, event.total_force_magnitude));
+        contact_forces.push((e2, e1, event.total_force_magnitude));
+    }
+
+    let has_interest = |entity: Entity, want: fn(CollisionEventInterest) -> bool| {
+        interests.get(&entity).copied().is_some_and(want)
+    };
+
+    let Some(events) = world.get_resource_mut::<Events>() else {
+        return;
+    };
+
+    for (entity, other) in collision_started {
+        if has_interest(entity, |i| i.collisions) {
+            events.send(CollisionStarted { entity, other });
+        }
+    }
+    for (entity, other) in collision_stopped {
+        if has_interest(entity, |i| i.collisions) {
+            events.send(CollisionStopped { entity, other });
+        }
+    }
+    for (entity, other, total_force_magnitude) in contact_forces {
+        if has_interest(entity, |i| i.contact_forces) {
+            events.send(ContactForceExceeded {
+                entity,
+                other,
+                total_force_magnitude,
+            });
+        }
+    }
+}
+
+/// Drains [`PhysicsWorld::collision_recv`] for pairs touching an entity
+/// with the [`TriggerVolume`] marker, maintains [`TriggerOverlaps`], and
+/// publishes [`TriggerEnter`]/[`TriggerStay`]/[`TriggerExit`] to the
+/// [`Events`] resource. Register this after [`physics_step_system`] --
+/// alongside [`collision_event_bridge_system`], which handles solid-solid
+/// collisions instead -- in the same stage.
+pub fn trigger_event_bridge_system(world: &mut World) {
+    let triggers: HashSet<Entity> = world.entities_with::<TriggerVolume>().into_iter().collect();
+    if triggers.is_empty() {
+        return;
+    }
+
+    let mut body_to_entity: HashMap<BodyId, Entity> = HashMap::new();
+    for entity in world.entities_with::<PhysicsBodyComponent>() {
+        if let Some(body) = world.get::<PhysicsBodyComponent>(entity) {
+            body_to_entity.insert(body.0, entity);
+        }
+    }
+
+    let Some(physics_world) = world.get_resource::<PhysicsWorld>() else {
+        return;
+    };
+
+    let entity_of_collider = |handle| {
+        physics_world
+            .colliders
+            .get(handle)
+            .and_then(|c| c.parent())
+            .and_then(|h| physics_world.id_of(h))
+            .and_then(|body_id| body_to_entity.get(&body_id).copied())
+    };
+
+    let mut started_pairs = Vec::new();
+    let mut stopped_pairs = Vec::new();
+    while let Ok(event) = physics_world.collision_recv.try_recv() {
+        let (h1, h2, started) = if let CollisionEvent::Started(h1, h2, _) = event {
+            (h1, h2, true)
+        } else if let CollisionEvent::Stopped(h1, h2, _) = event {
+            (h1, h2, false)
+        } else {
+            continue;
+        };
+        let (Some(e1), Some(e2)) = (entity_of_collider(h1), entity_of_collider(h2)) else {
+            continue;
+        };
+        let list = if started {
+            &mut started_pairs
+        } else {
+            &mut stopped_pairs
+        };
+        if triggers.contains(&e1) {
+            list.push((e1, e2));
+        }
+        if triggers.contains(&e2) {
+            list.push((e2, e1));
+        }
+    }
+
+    let mut entered = Vec::new();
+    let mut exited = Vec::new();
+    if let Some(overlaps) = world.get_resource_mut::<TriggerOverlaps>() {
+        for (trigger, other) in started_pairs {
+            if overlaps.0.entry(trigger).or_default().insert(other) {
+                entered.push((trigger, other));
+            }
+        }
+        for (trigger, other) in stopped_pairs {
+            if overlaps.0.entry(trigger).or_default().remove(&other) {
+                exited.push((trigger, other));
+            }
+        }
+    }
+
+    let stayed: Vec<(Entity, Entity)> = world
+        .get_resource::<TriggerOverlaps>()
+        .map(|overlaps| {
+            overlaps
+                .0
+                .iter()
+                .flat_map(|(&trigger, others)| others.iter().map(move |&other| (trigger, other)))
+                .filter(|pair| !entered.contains(pair))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let Some(events) = world.get_resource_mut::<Events>() else {
+        return;
+    };
+    for (trigger, other) in entered {
+        events.send(TriggerEnter { trigger, other });
+    }
+    for (trigger, other) in stayed {
+        events.send(TriggerStay { trigger, other });
+    }
+    for (trigger, other) in exited {
+        events.send(TriggerExit { trigger, other });
+    }
+}
+
+/// Resource queuing raycasts requested by AI perception systems (e.g.
+/// line-of-sight checks for a crowd of agents) each frame. Callers push
+/// requests with [`Self::queue`]; [`run_perception_raycasts_system`] drains
+/// the queue through [`PhysicsWorld::raycast_batch`] and stores the results,
+/// aligned by index with the drained request, for readers to pull from
+/// [`Self::results`] afterward.
+#[cfg(feature = "async-physics")]
+#[derive(Clone, Debug, Default)]
+pub struct PerceptionRaycasts {
+    requests: Vec<RayRequest>,
+    results: Vec<Option<RayHit>>,
+}
+
+#[cfg(feature = "async-physics")]
+impl PerceptionRaycasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a raycast to be resolved by [`run_perception_raycasts_system`]
+    /// on its next run, returning the index its result will appear at in
+    /// [`Self::results`].
+    pub fn queue(&mut self, request: RayRequest) -> usize {
+        self.requests.push(request);
+        self.requests.len() - 1
+    }
+
+    /// Results from the most recently drained batch, aligned by index with
+    /// the order [`Self::queue`] was called in.
+    pub fn results(&self) -> &[Option<RayHit>] {
+        &self.results
+    }
+}
+
+/// Drains [`PerceptionRaycasts`]'s queued requests through
+/// [`PhysicsWorld::raycast_batch`] and stores the results for readers.
+#[cfg(feature = "async-physics")]
+pub fn run_perception_raycasts_system(world: &mut World) {
+    let requests = match world.get_resource::<PerceptionRaycasts>() {
+        Some(perception) if !perception.requests.is_empty() => perception.requests.clone(),
+        _ => return,
+    };
+    let Some(physics_world) = world.get_resource::<PhysicsWorld>() else {
+        return;
+    };
+    let results = physics_world.raycast_batch(&requests);
+
+    if let Some(perception) = world.get_resource_mut::<PerceptionRaycasts>() {
+        perception.results = results;
+        perception.requests.clear();
+    }
+}
@@ -577,6 +577,19 @@ impl EnvironmentManager {
         Vec3::ZERO
     }
 
+    /// Water surface height (including waves) directly above `point`, if
+    /// `point` is inside a water volume there. Used by
+    /// `PhysicsWorld::update_character_water_state` for swim/surface
+    /// detection.
+    pub fn water_surface_at(&self, point: Vec3) -> Option<f32> {
+        for water in self.water_volumes.values() {
+            if water.contains(point) {
+                return Some(water.surface_height_at(point.x, point.z));
+            }
+        }
+        None
+    }
+
     // === Update ===
 
     /// Update all environmental effects
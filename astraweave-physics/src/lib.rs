@@ -28,6 +28,10 @@
 //! - **[`EnvironmentManager`]** — Wind zones, water volumes, and buoyancy.
 //! - **[`DestructionManager`]** — Fracture patterns and debris spawning.
 //! - **[`ClothManager`]** — Particle-based cloth with distance constraints.
+//! - **[`RopeManager`]** — Particle-chain ropes/cables with rigid-body
+//!   attachment points, tension queries, and breakable segments.
+//! - **[`CollisionMatrix`]** — Data-driven, named collision layers with
+//!   configurable per-pair interaction masks.
 //!
 //! # Feature Flags
 //!
@@ -36,6 +40,7 @@
 //! | `async-physics` | Parallel 3-stage pipeline via Rayon |
 //! | `profiling` | Tracy integration for performance profiling |
 //! | `ecs` | ECS system integration |
+//! | `collision-toml` | TOML loading for [`CollisionMatrix`] |
 //!
 //! # Performance
 //!
@@ -44,7 +49,7 @@
 //! - Rigid body step: 2.97 µs
 //! - Spatial hash: 3.77 ms (FxHashMap, vs 5.61 ms SipHash)
 
-use glam::{vec3, Mat4, Vec3};
+use glam::{vec3, Mat4, Quat, Vec3};
 
 // Rapier3D explicit re-exports (replaces glob `pub use rapier3d::prelude::*`)
 pub use rapier3d::prelude::{
@@ -58,12 +63,14 @@ pub use rapier3d::prelude::{
     CollisionEvent,
     ContactForceEvent,
     DebugRenderBackend,
-    DebugRenderObject,
     // Debug rendering
+    DebugRenderMode,
+    DebugRenderObject,
     DebugRenderPipeline,
     DefaultBroadPhase,
     GenericJointBuilder,
     Group,
+    ImpulseJointHandle,
     ImpulseJointSet,
     IntegrationParameters,
     // Collision configuration
@@ -71,6 +78,7 @@ pub use rapier3d::prelude::{
     IslandManager,
     // Joint configuration
     JointAxesMask,
+    JointAxis,
     LockedAxes,
     MultibodyJointSet,
     NarrowPhase,
@@ -99,7 +107,10 @@ pub use rapier3d::prelude::{
     Vector,
 };
 // Nalgebra re-exports used by rapier3d APIs
-pub use rapier3d::na::{Point3 as NaPoint3, UnitVector3 as NaUnitVector3, Vector3 as NaVector3};
+pub use rapier3d::na::{
+    DMatrix, Point3 as NaPoint3, UnitVector3 as NaUnitVector3, Vector3 as NaVector3,
+};
+use rapier3d::na::{Quaternion, UnitQuaternion};
 // Rapier3d macros (`point!`/`vector!` expand to `nalgebra::...` internally)
 use rapier3d::na as nalgebra;
 pub use rapier3d::prelude::{point, vector};
@@ -165,8 +176,9 @@ pub use environment::{
 // Destruction system
 pub mod destruction;
 pub use destruction::{
-    Debris, DebrisConfig, DebrisId, DebrisShape, Destructible, DestructibleConfig, DestructibleId,
-    DestructibleState, DestructionEvent, DestructionManager, DestructionTrigger, FracturePattern,
+    Debris, DebrisConfig, DebrisId, DebrisInstanceTransform, DebrisRenderMode, DebrisShape,
+    Destructible, DestructibleConfig, DestructibleId, DestructibleState, DestructionEvent,
+    DestructionManager, DestructionTrigger, FracturePattern,
 };
 
 // Cloth simulation
@@ -175,6 +187,19 @@ pub use cloth::{
     Cloth, ClothCollider, ClothConfig, ClothId, ClothManager, ClothParticle, DistanceConstraint,
 };
 
+// Rope/cable simulation (1D particle chain, complementing cloth's 2D grid)
+pub mod rope;
+pub use rope::{BendConstraint, Rope, RopeAttachment, RopeConfig, RopeId, RopeManager, RopeSegment};
+
+// Snapshot/rollback of dynamic scene state (save/load, rewind-resimulate)
+pub mod snapshot;
+pub use snapshot::{BodySnapshot, PhysicsSnapshot};
+
+// Data-driven collision layer/mask configuration, complementing the
+// compile-time `Layers` bitflags
+pub mod collision_layers;
+pub use collision_layers::{CollisionMatrix, LayerId};
+
 #[cfg(test)]
 mod mutation_tests;
 
@@ -351,12 +376,20 @@ impl std::fmt::Display for DebugLine {
     }
 }
 
-struct LineCollector {
-    lines: Vec<DebugLine>,
+/// [`DebugRenderBackend`] that collects rapier's debug-render output into a
+/// plain [`DebugLine`] buffer instead of drawing directly, so renderers can
+/// batch/upload it however they like. Exposed as a stable public type (not
+/// just via [`PhysicsWorld::get_debug_lines`]) for callers that want to feed
+/// their own [`DebugRenderPipeline`] instance through the same collector,
+/// e.g. to render a category subset [`PhysicsWorld::get_debug_lines`]
+/// doesn't cover.
+#[derive(Debug, Default)]
+pub struct LineCollector {
+    pub lines: Vec<DebugLine>,
 }
 
 impl LineCollector {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self { lines: Vec::new() }
     }
 }
@@ -377,6 +410,80 @@ impl DebugRenderBackend for LineCollector {
     }
 }
 
+/// Runtime-toggleable categories for [`PhysicsWorld::get_debug_lines`], set
+/// via [`PhysicsWorld::set_debug_render_categories`]. `colliders`, `aabbs`,
+/// `joints`, and `contacts` map onto rapier's own debug-render mode;
+/// `character_controllers` is astraweave-specific and drawn by
+/// `PhysicsWorld` itself since it owns [`PhysicsWorld::char_map`];
+/// `spatial_hash_cells` is a hint for callers that own a [`SpatialHash`] of
+/// their own -- `PhysicsWorld` doesn't hold one, so check this flag before
+/// calling a grid's own debug-line method. Defaults to every category on
+/// except `color_by_layer`, matching this crate's original "dumps
+/// everything" behavior.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DebugRenderCategories {
+    pub colliders: bool,
+    pub aabbs: bool,
+    pub joints: bool,
+    pub contacts: bool,
+    pub character_controllers: bool,
+    pub spatial_hash_cells: bool,
+    /// Draw an extra small axis-cross at each collider's origin, colored by
+    /// its [`Layers`] membership bits, so overlapping collider shapes of
+    /// different layers are visually distinguishable.
+    pub color_by_layer: bool,
+}
+
+impl Default for DebugRenderCategories {
+    fn default() -> Self {
+        Self {
+            colliders: true,
+            aabbs: true,
+            joints: true,
+            contacts: true,
+            character_controllers: true,
+            spatial_hash_cells: true,
+            color_by_layer: false,
+        }
+    }
+}
+
+impl DebugRenderCategories {
+    /// Converts the rapier-native categories to a [`DebugRenderMode`] bitmask.
+    fn rapier_mode(&self) -> DebugRenderMode {
+        let mut mode = DebugRenderMode::empty();
+        if self.colliders {
+            mode |= DebugRenderMode::COLLIDER_SHAPES;
+        }
+        if self.aabbs {
+            mode |= DebugRenderMode::COLLIDER_AABBS;
+        }
+        if self.joints {
+            mode |= DebugRenderMode::IMPULSE_JOINTS
+                | DebugRenderMode::MULTIBODY_JOINTS
+                | DebugRenderMode::JOINT_ANCHORS
+                | DebugRenderMode::JOINT_LIMITS;
+        }
+        if self.contacts {
+            mode |= DebugRenderMode::CONTACTS | DebugRenderMode::SOLVER_CONTACTS;
+        }
+        mode
+    }
+}
+
+/// Derives a deterministic RGB color from a collider's [`InteractionGroups`]
+/// membership bits for [`DebugRenderCategories::color_by_layer`], so the
+/// same layer always renders the same color across a run.
+fn layer_debug_color(groups: InteractionGroups) -> [f32; 3] {
+    let bits = groups.memberships.bits();
+    let h = bits.wrapping_mul(2_654_435_761); // Knuth multiplicative hash
+    [
+        ((h >> 16) & 0xFF) as f32 / 255.0,
+        ((h >> 8) & 0xFF) as f32 / 255.0,
+        (h & 0xFF) as f32 / 255.0,
+    ]
+}
+
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct Layers: u32 {
@@ -386,9 +493,13 @@ bitflags::bitflags! {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum CharState {
     Grounded,
+    /// Inside a [`WaterVolume`] deep enough to swim in; see
+    /// [`PhysicsWorld::update_character_water_state`].
+    Swimming,
 }
 
 impl CharState {
@@ -397,6 +508,7 @@ impl CharState {
     pub fn name(&self) -> &'static str {
         match self {
             Self::Grounded => "Grounded",
+            Self::Swimming => "Swimming",
         }
     }
 
@@ -406,9 +518,15 @@ impl CharState {
         matches!(self, Self::Grounded)
     }
 
+    /// Returns true if swimming.
+    #[inline]
+    pub fn is_swimming(&self) -> bool {
+        matches!(self, Self::Swimming)
+    }
+
     /// Returns all character states.
-    pub fn all() -> [CharState; 1] {
-        [Self::Grounded]
+    pub fn all() -> [CharState; 2] {
+        [Self::Grounded, Self::Swimming]
     }
 }
 
@@ -418,7 +536,64 @@ impl std::fmt::Display for CharState {
     }
 }
 
+/// A character's crouch posture, independent of [`CharState`]'s
+/// grounded/swimming axis. Each stance carries its own capsule height,
+/// step height, and max climb angle -- see the matching `*_for` accessors
+/// on [`CharacterController`] -- applied by
+/// [`PhysicsWorld::update_character_stance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Stance {
+    #[default]
+    Standing,
+    Crouching,
+    Crawling,
+}
+
+impl Stance {
+    /// Returns the name of the stance.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Standing => "Standing",
+            Self::Crouching => "Crouching",
+            Self::Crawling => "Crawling",
+        }
+    }
+
+    /// Returns true if standing.
+    #[inline]
+    pub fn is_standing(&self) -> bool {
+        matches!(self, Self::Standing)
+    }
+
+    /// Returns true if crouching.
+    #[inline]
+    pub fn is_crouching(&self) -> bool {
+        matches!(self, Self::Crouching)
+    }
+
+    /// Returns true if crawling.
+    #[inline]
+    pub fn is_crawling(&self) -> bool {
+        matches!(self, Self::Crawling)
+    }
+
+    /// Returns all stances.
+    pub fn all() -> [Stance; 3] {
+        [Self::Standing, Self::Crouching, Self::Crawling]
+    }
+}
+
+impl std::fmt::Display for Stance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacterController {
     pub state: CharState,
     pub max_climb_angle_deg: f32,
@@ -439,6 +614,74 @@ pub struct CharacterController {
     pub jump_buffer_limit: f32,
 
     pub pending_jump_velocity: f32,
+
+    // Mantling
+    pub mantle_reach: f32,
+    pub mantle_max_ledge_height: f32,
+    pub mantle_duration: f32,
+    pub mantling: Option<MantleState>,
+
+    // Swimming (see CharState::Swimming)
+    /// Max horizontal swim speed (m/s); caps `desired_move`'s horizontal
+    /// magnitude in `control_character` while swimming.
+    pub swim_speed: f32,
+    /// Proportional response rate pulling vertical velocity toward the
+    /// surface-floating depth while swimming with no vertical input.
+    pub buoyancy_response: f32,
+    /// Depth below the water surface the character floats at while idle
+    /// (chest-height treading), in meters.
+    pub swim_surface_depth: f32,
+    /// Water surface height (world Y) last sampled by
+    /// [`PhysicsWorld::update_character_water_state`]; `None` when not in
+    /// water. Cached here since `EnvironmentManager` is owned separately
+    /// from `PhysicsWorld`.
+    pub swim_surface_y: Option<f32>,
+
+    // Crouch / crawl (see Stance)
+    /// Stance actually applied to the capsule collider; only
+    /// [`PhysicsWorld::update_character_stance`] writes this.
+    pub stance: Stance,
+    /// Stance requested via [`CharacterController::request_stance`];
+    /// applied on the next [`PhysicsWorld::update_character_stance`] call,
+    /// or left pending if standing up is blocked by a ceiling.
+    pub requested_stance: Stance,
+    /// Capsule height while [`Stance::Standing`].
+    pub standing_height: f32,
+    /// Capsule height while [`Stance::Crouching`].
+    pub crouch_height: f32,
+    /// Capsule height while [`Stance::Crawling`].
+    pub crawl_height: f32,
+    /// `max_step` while [`Stance::Standing`].
+    pub standing_max_step: f32,
+    /// `max_step` while [`Stance::Crouching`].
+    pub crouch_max_step: f32,
+    /// `max_step` while [`Stance::Crawling`].
+    pub crawl_max_step: f32,
+    /// `max_climb_angle_deg` while [`Stance::Standing`].
+    pub standing_max_climb_angle_deg: f32,
+    /// `max_climb_angle_deg` while [`Stance::Crouching`].
+    pub crouch_max_climb_angle_deg: f32,
+    /// `max_climb_angle_deg` while [`Stance::Crawling`].
+    pub crawl_max_climb_angle_deg: f32,
+}
+
+/// A ledge found by [`PhysicsWorld::try_mantle`] within climbing reach.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MantleTarget {
+    /// World-space position the character should end up standing at.
+    pub position: Vec3,
+    /// Horizontal distance from the character to the ledge's wall face.
+    pub wall_distance: f32,
+}
+
+/// In-progress kinematic mantle, driven frame-by-frame by
+/// [`PhysicsWorld::update_mantle`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MantleState {
+    pub start: Vec3,
+    pub target: Vec3,
+    pub elapsed: f32,
 }
 
 impl CharacterController {
@@ -457,9 +700,72 @@ impl CharacterController {
             coyote_time_limit: 0.15,
             jump_buffer_limit: 0.15,
             pending_jump_velocity: 0.0,
+            mantle_reach: 0.6,
+            mantle_max_ledge_height: 1.0,
+            mantle_duration: 0.35,
+            mantling: None,
+            swim_speed: 3.0,
+            buoyancy_response: 4.0,
+            swim_surface_depth: 0.3,
+            swim_surface_y: None,
+            stance: Stance::Standing,
+            requested_stance: Stance::Standing,
+            standing_height: height,
+            crouch_height: height * 0.6,
+            crawl_height: height * 0.35,
+            standing_max_step: 0.3,
+            crouch_max_step: 0.15,
+            crawl_max_step: 0.05,
+            standing_max_climb_angle_deg: 45.0,
+            crouch_max_climb_angle_deg: 30.0,
+            crawl_max_climb_angle_deg: 15.0,
         }
     }
 
+    /// Requests a stance change, applied by
+    /// [`PhysicsWorld::update_character_stance`] on its next call.
+    #[inline]
+    pub fn request_stance(&mut self, stance: Stance) {
+        self.requested_stance = stance;
+    }
+
+    /// Capsule height for `stance`.
+    #[inline]
+    pub fn height_for(&self, stance: Stance) -> f32 {
+        match stance {
+            Stance::Standing => self.standing_height,
+            Stance::Crouching => self.crouch_height,
+            Stance::Crawling => self.crawl_height,
+        }
+    }
+
+    /// `max_step` for `stance`.
+    #[inline]
+    pub fn max_step_for(&self, stance: Stance) -> f32 {
+        match stance {
+            Stance::Standing => self.standing_max_step,
+            Stance::Crouching => self.crouch_max_step,
+            Stance::Crawling => self.crawl_max_step,
+        }
+    }
+
+    /// `max_climb_angle_deg` for `stance`.
+    #[inline]
+    pub fn max_climb_angle_deg_for(&self, stance: Stance) -> f32 {
+        match stance {
+            Stance::Standing => self.standing_max_climb_angle_deg,
+            Stance::Crouching => self.crouch_max_climb_angle_deg,
+            Stance::Crawling => self.crawl_max_climb_angle_deg,
+        }
+    }
+
+    /// Returns true while a mantle is in progress and driving position via
+    /// [`PhysicsWorld::update_mantle`] instead of normal movement.
+    #[inline]
+    pub fn is_mantling(&self) -> bool {
+        self.mantling.is_some()
+    }
+
     /// Returns true if the character is grounded.
     #[inline]
     pub fn is_grounded(&self) -> bool {
@@ -518,6 +824,7 @@ impl CharacterController {
         self.time_since_grounded = 0.0;
         self.jump_buffer_timer = 0.0;
         self.pending_jump_velocity = 0.0;
+        self.mantling = None;
     }
 }
 
@@ -848,6 +1155,74 @@ impl From<JointId> for u64 {
     }
 }
 
+/// PD-controller motor configuration for [`PhysicsWorld::set_joint_motor`].
+/// Drives a `Revolute` joint's angle or a `Prismatic` joint's offset toward
+/// `target_position` at up to `target_velocity`, with `stiffness` resisting
+/// position error and `damping` resisting velocity error -- the same
+/// stiffness/damping/max-force triple rapier's joint motors take directly,
+/// so this is a thin, named wrapper rather than a hand-rolled force
+/// integrator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JointMotorParams {
+    /// Target joint position (radians for `Revolute`, meters for `Prismatic`).
+    pub target_position: f32,
+    /// Target joint velocity (rad/s or m/s).
+    pub target_velocity: f32,
+    /// Spring-like stiffness resisting position error.
+    pub stiffness: f32,
+    /// Damping resisting velocity error.
+    pub damping: f32,
+    /// Maximum force/torque the motor may apply.
+    pub max_force: f32,
+}
+
+impl JointMotorParams {
+    /// A pure position-hold PD controller: drives toward `target_position`
+    /// and stays there, e.g. a door swinging open to a fixed angle or a
+    /// ragdoll limb blending back toward an animated pose.
+    pub fn position_hold(target_position: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            target_position,
+            target_velocity: 0.0,
+            stiffness,
+            damping,
+            max_force: f32::MAX,
+        }
+    }
+
+    /// A pure velocity controller with no position target, e.g. a crane
+    /// winch or conveyor spinning at a constant rate.
+    pub fn velocity(target_velocity: f32, damping: f32) -> Self {
+        Self {
+            target_position: 0.0,
+            target_velocity,
+            stiffness: 0.0,
+            damping,
+            max_force: f32::MAX,
+        }
+    }
+
+    /// A motor with the force/torque it may apply capped, e.g. so a door
+    /// motor stalls realistically against an obstruction instead of
+    /// clipping through it.
+    pub fn with_max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+
+    /// A disabled motor (zero gains, zero max force), used to let a joint
+    /// move freely again.
+    pub fn disabled() -> Self {
+        Self {
+            target_position: 0.0,
+            target_velocity: 0.0,
+            stiffness: 0.0,
+            damping: 0.0,
+            max_force: 0.0,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct BuoyancyData {
     pub volume: f32,
@@ -892,6 +1267,15 @@ impl std::fmt::Display for BuoyancyData {
     }
 }
 
+/// Result of [`PhysicsWorld::step_async`]: the physics step's timing
+/// profile alongside whatever the caller's overlap closure returned.
+#[cfg(feature = "async-physics")]
+#[derive(Debug, Clone)]
+pub struct PhysicsStepJob<T> {
+    pub profile: PhysicsStepProfile,
+    pub overlap_result: T,
+}
+
 pub struct PhysicsWorld {
     pub bodies: RigidBodySet,
     pub colliders: ColliderSet,
@@ -913,7 +1297,15 @@ pub struct PhysicsWorld {
     next_body_id: BodyId,
     pub char_map: HashMap<BodyId, CharacterController>,
     next_joint_id: u64,
+    /// Maps [`JointId`]s handed out by [`Self::add_joint`] back to the
+    /// underlying rapier joint handle, so callers can look a joint back up
+    /// (e.g. [`Self::set_joint_motor`]) without storing the handle themselves.
+    joint_handles: HashMap<JointId, ImpulseJointHandle>,
+    /// The [`JointType`] each [`JointId`] was created with, used to resolve
+    /// which rapier [`JointAxis`] a motor call should drive.
+    joint_types: HashMap<JointId, JointType>,
     debug_render_pipeline: DebugRenderPipeline,
+    debug_render_categories: DebugRenderCategories,
     pub buoyancy_bodies: HashMap<BodyId, BuoyancyData>,
     pub water_level: f32,
     pub fluid_density: f32,
@@ -922,6 +1314,48 @@ pub struct PhysicsWorld {
     /// Async physics scheduler (feature-gated)
     #[cfg(feature = "async-physics")]
     pub async_scheduler: Option<AsyncPhysicsScheduler>,
+
+    /// Body poses captured just before the most recent [`Self::step`].
+    previous_poses: HashMap<BodyId, BodyPose>,
+    /// Body poses captured just after the most recent [`Self::step`].
+    current_poses: HashMap<BodyId, BodyPose>,
+}
+
+/// A rigid body's translation and rotation at a point in time, used to
+/// interpolate rendered transforms between fixed physics steps. See
+/// [`PhysicsWorld::interpolated_pose`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BodyPose {
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+impl BodyPose {
+    pub fn lerp(&self, other: BodyPose, t: f32) -> BodyPose {
+        BodyPose {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+        }
+    }
+}
+
+/// One requested ray for [`PhysicsWorld::raycast`]/[`PhysicsWorld::raycast_batch`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayRequest {
+    pub origin: Vec3,
+    pub direction: Vec3,
+    pub max_distance: f32,
+}
+
+/// Result of one [`RayRequest`]: the world-space hit position and normal,
+/// the [`BodyId`] hit (`None` if the collider has no tracked parent body),
+/// and the distance travelled along the ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub body_id: Option<BodyId>,
+    pub distance: f32,
 }
 
 impl PhysicsWorld {
@@ -951,13 +1385,18 @@ impl PhysicsWorld {
             next_body_id: 1,
             char_map: HashMap::new(),
             next_joint_id: 1,
+            joint_handles: HashMap::new(),
+            joint_types: HashMap::new(),
             debug_render_pipeline: DebugRenderPipeline::default(),
+            debug_render_categories: DebugRenderCategories::default(),
             buoyancy_bodies: HashMap::new(),
             water_level: f32::NEG_INFINITY,
             fluid_density: 1000.0,
             wind: Vec3::ZERO,
             #[cfg(feature = "async-physics")]
             async_scheduler: None,
+            previous_poses: HashMap::new(),
+            current_poses: HashMap::new(),
         }
     }
 
@@ -992,13 +1431,18 @@ impl PhysicsWorld {
             next_body_id: 1,
             char_map: HashMap::new(),
             next_joint_id: 1,
+            joint_handles: HashMap::new(),
+            joint_types: HashMap::new(),
             debug_render_pipeline: DebugRenderPipeline::default(),
+            debug_render_categories: DebugRenderCategories::default(),
             buoyancy_bodies: HashMap::new(),
             water_level: f32::NEG_INFINITY,
             fluid_density: 1000.0,
             wind: Vec3::ZERO,
             #[cfg(feature = "async-physics")]
             async_scheduler: None,
+            previous_poses: HashMap::new(),
+            current_poses: HashMap::new(),
         }
     }
 
@@ -1027,6 +1471,46 @@ impl PhysicsWorld {
         self.async_scheduler.as_ref().map(|s| s.get_last_profile())
     }
 
+    /// Steps physics on a Rayon worker thread while `overlap_fn` runs on the
+    /// calling thread, then waits for both to finish before returning.
+    /// `overlap_fn` should do work that doesn't touch this [`PhysicsWorld`]
+    /// -- AI planning, asset streaming, or anything else that can safely
+    /// overlap the physics step -- and its result is joined together with
+    /// the step's timing profile in the returned [`PhysicsStepJob`].
+    ///
+    /// Unlike a detached async task, there is no handle to poll separately:
+    /// this crate forbids unsafe code, and a true detachable handle would
+    /// require either moving `PhysicsWorld` off this thread (breaking every
+    /// caller that keeps using it afterwards) or unsafely aliasing its
+    /// rapier state across threads. `rayon::scope` gives genuine overlap
+    /// between the step and `overlap_fn` without either, at the cost of
+    /// `step_async` itself blocking until both are done -- callers get the
+    /// concurrency, just not a value they can stash and join later.
+    #[cfg(feature = "async-physics")]
+    pub fn step_async<F, R>(&mut self, overlap_fn: F) -> PhysicsStepJob<R>
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        use std::time::Instant;
+        let start = Instant::now();
+
+        let mut overlap_result = None;
+        rayon::scope(|s| {
+            s.spawn(|_| self.step());
+            overlap_result = Some(overlap_fn());
+        });
+
+        let mut profile = PhysicsStepProfile::new();
+        profile.total_duration = start.elapsed();
+
+        PhysicsStepJob {
+            profile,
+            overlap_result: overlap_result
+                .expect("overlap_fn runs synchronously inside step_async's rayon::scope"),
+        }
+    }
+
     fn alloc_id(&mut self) -> BodyId {
         let id = self.next_body_id;
         self.next_body_id += 1;
@@ -1046,7 +1530,9 @@ impl PhysicsWorld {
                 use std::time::Instant;
                 let start = Instant::now();
 
+                self.capture_previous_poses();
                 self.step_internal();
+                self.capture_current_poses();
 
                 let duration = start.elapsed();
 
@@ -1059,7 +1545,39 @@ impl PhysicsWorld {
         }
 
         // Fallback to regular step (single-threaded)
+        self.capture_previous_poses();
         self.step_internal();
+        self.capture_current_poses();
+    }
+
+    /// Snapshots the poses [`Self::interpolated_pose`] will blend *from* for
+    /// this step, before Rapier advances the simulation.
+    fn capture_previous_poses(&mut self) {
+        std::mem::swap(&mut self.previous_poses, &mut self.current_poses);
+    }
+
+    /// Snapshots the poses [`Self::interpolated_pose`] will blend *to* for
+    /// this step, after Rapier has advanced the simulation.
+    fn capture_current_poses(&mut self) {
+        self.current_poses.clear();
+        for (&handle, &id) in self.body_ids.iter() {
+            let Some(rb) = self.bodies.get(handle) else {
+                continue;
+            };
+            let iso = rb.position();
+            self.current_poses.insert(
+                id,
+                BodyPose {
+                    translation: vec3(iso.translation.x, iso.translation.y, iso.translation.z),
+                    rotation: Quat::from_xyzw(
+                        iso.rotation.i,
+                        iso.rotation.j,
+                        iso.rotation.k,
+                        iso.rotation.w,
+                    ),
+                },
+            );
+        }
     }
 
     /// Internal physics step (shared by sync and async paths)
@@ -1127,6 +1645,21 @@ impl PhysicsWorld {
         }
     }
 
+    pub fn get_angular_velocity(&self, id: BodyId) -> Option<Vec3> {
+        let h = self.handle_of(id)?;
+        let rb = self.bodies.get(h)?;
+        let v = rb.angvel();
+        Some(Vec3::new(v.x, v.y, v.z))
+    }
+
+    pub fn set_angular_velocity(&mut self, id: BodyId, vel: Vec3) {
+        if let Some(h) = self.handle_of(id) {
+            if let Some(rb) = self.bodies.get_mut(h) {
+                rb.set_angvel(vector![vel.x, vel.y, vel.z], true);
+            }
+        }
+    }
+
     pub fn create_ground_plane(&mut self, half: Vec3, friction: f32) -> BodyId {
         let rb = RigidBodyBuilder::fixed().build();
         let h = self.bodies.insert(rb);
@@ -1163,6 +1696,27 @@ impl PhysicsWorld {
         self.tag_body(h, ActorKind::Static)
     }
 
+    /// Inserts a fixed heightfield collider, `heights` laid out row-major
+    /// (matching `DMatrix::from_row_slice`) and scaled by `scale`, centered
+    /// at `translation`. Used for streaming terrain colliders — callers
+    /// building those from `astraweave-terrain` chunks own the chunk/edge
+    /// bookkeeping and just hand this the finished sample grid.
+    pub fn add_static_heightfield(
+        &mut self,
+        heights: DMatrix<Real>,
+        scale: Vector<Real>,
+        translation: Vector<Real>,
+        friction: f32,
+    ) -> BodyId {
+        let rb = RigidBodyBuilder::fixed().translation(translation).build();
+        let h = self.bodies.insert(rb);
+        let coll = ColliderBuilder::heightfield(heights, scale)
+            .friction(friction)
+            .build();
+        self.colliders.insert_with_parent(coll, h, &mut self.bodies);
+        self.tag_body(h, ActorKind::Static)
+    }
+
     pub fn add_dynamic_box(&mut self, pos: Vec3, half: Vec3, mass: f32, groups: Layers) -> BodyId {
         #[cfg(feature = "profiling")]
         {
@@ -1222,11 +1776,90 @@ impl PhysicsWorld {
                 coyote_time_limit: 0.1, // 100ms
                 jump_buffer_limit: 0.1, // 100ms
                 pending_jump_velocity: 0.0,
+                mantle_reach: 0.6,
+                mantle_max_ledge_height: 1.0,
+                mantle_duration: 0.35,
+                mantling: None,
+                swim_speed: 3.0,
+                buoyancy_response: 4.0,
+                swim_surface_depth: 0.3,
+                swim_surface_y: None,
+                stance: Stance::Standing,
+                requested_stance: Stance::Standing,
+                standing_height: half.y * 2.0,
+                crouch_height: half.y * 2.0 * 0.6,
+                crawl_height: half.y * 2.0 * 0.35,
+                standing_max_step: 0.4,
+                crouch_max_step: 0.2,
+                crawl_max_step: 0.05,
+                standing_max_climb_angle_deg: 70.0,
+                crouch_max_climb_angle_deg: 45.0,
+                crawl_max_climb_angle_deg: 20.0,
             },
         );
         id
     }
 
+    /// Creates a sensor collider for gameplay trigger volumes (pressure
+    /// plates, area-of-effect zones, level transitions). Sensors report
+    /// overlap through [`Self::collision_recv`] like any other collider but
+    /// never generate contact response. Pair the returned [`BodyId`] with
+    /// the `TriggerVolume` ECS component (behind the `ecs` feature) to get
+    /// Enter/Stay/Exit events instead of draining the channel by hand.
+    pub fn add_trigger_volume(&mut self, pos: Vec3, half_extents: Vec3, groups: Layers) -> BodyId {
+        let rb = RigidBodyBuilder::kinematic_position_based()
+            .translation(vector![pos.x, pos.y, pos.z])
+            .build();
+        let h = self.bodies.insert(rb);
+        let coll = ColliderBuilder::cuboid(half_extents.x, half_extents.y, half_extents.z)
+            .sensor(true)
+            .collision_groups(InteractionGroups::new(
+                Group::from_bits_truncate(groups.bits()),
+                Group::ALL,
+            ))
+            .active_events(ActiveEvents::COLLISION_EVENTS)
+            .build();
+        self.colliders.insert_with_parent(coll, h, &mut self.bodies);
+        self.tag_body(h, ActorKind::Other)
+    }
+
+    /// Resizes an existing trigger volume's half-extents in place, e.g. to
+    /// widen a detection zone at runtime. Returns `false` if `id` has no
+    /// body or the body has no collider attached.
+    pub fn set_trigger_volume_half_extents(&mut self, id: BodyId, half_extents: Vec3) -> bool {
+        let Some(h) = self.handle_of(id) else {
+            return false;
+        };
+        let Some(collider_handle) = self
+            .colliders
+            .iter()
+            .find_map(|(ch, c)| (c.parent() == Some(h)).then_some(ch))
+        else {
+            return false;
+        };
+        let Some(collider) = self.colliders.get_mut(collider_handle) else {
+            return false;
+        };
+        collider.set_shape(SharedShape::cuboid(
+            half_extents.x,
+            half_extents.y,
+            half_extents.z,
+        ));
+        true
+    }
+
+    /// Repositions an existing trigger volume, e.g. to follow a moving
+    /// platform. Thin wrapper over [`Self::set_body_position`] documenting
+    /// the trigger-volume use case; returns `false` if `id` is not a valid
+    /// body.
+    pub fn set_trigger_volume_position(&mut self, id: BodyId, pos: Vec3) -> bool {
+        if self.handle_of(id).is_none() {
+            return false;
+        }
+        self.set_body_position(id, pos);
+        true
+    }
+
     pub fn jump(&mut self, id: BodyId, height: f32) {
         if let Some(ctrl) = self.char_map.get_mut(&id) {
             ctrl.jump_buffer_timer = ctrl.jump_buffer_limit;
@@ -1235,6 +1868,128 @@ impl PhysicsWorld {
         }
     }
 
+    /// Updates a character's `Grounded`/`Swimming` state from the water
+    /// volumes in `environment`, entering `Swimming` once submerged past
+    /// chest height and returning to `Grounded` on exit. `EnvironmentManager`
+    /// is owned separately from `PhysicsWorld` (like `DestructionManager`),
+    /// so call this once per frame -- before `control_character` -- in
+    /// worlds that have water. Returns whether the character is now swimming.
+    pub fn update_character_water_state(
+        &mut self,
+        id: BodyId,
+        environment: &EnvironmentManager,
+    ) -> bool {
+        let Some(mut ctrl) = self.char_map.get(&id).copied() else {
+            return false;
+        };
+        let Some(h) = self.handle_of(id) else {
+            return false;
+        };
+        let Some(rb) = self.bodies.get(h) else {
+            return false;
+        };
+        let pos = *rb.position();
+        let feet = glam::Vec3::new(pos.translation.x, pos.translation.y, pos.translation.z);
+        let chest = feet + glam::Vec3::Y * (ctrl.height * 0.5);
+        let surface = environment.water_surface_at(chest);
+        let deep_enough = surface.is_some_and(|y| y - feet.y > ctrl.height * 0.5);
+
+        if deep_enough {
+            if !ctrl.state.is_swimming() {
+                ctrl.state = CharState::Swimming;
+                ctrl.vertical_velocity = 0.0;
+            }
+            ctrl.swim_surface_y = surface;
+        } else if ctrl.state.is_swimming() {
+            ctrl.state = CharState::Grounded;
+            ctrl.swim_surface_y = None;
+            // Exit into a fall rather than granting a free coyote-time jump.
+            ctrl.time_since_grounded = ctrl.coyote_time_limit + 1.0;
+        }
+
+        let swimming = ctrl.state.is_swimming();
+        self.char_map.insert(id, ctrl);
+        swimming
+    }
+
+    /// Requests a stance change for `id`, applied by
+    /// [`Self::update_character_stance`] on its next call. Returns `false`
+    /// if `id` isn't a tracked character.
+    pub fn request_character_stance(&mut self, id: BodyId, stance: Stance) -> bool {
+        let Some(ctrl) = self.char_map.get_mut(&id) else {
+            return false;
+        };
+        ctrl.request_stance(stance);
+        true
+    }
+
+    /// Applies `requested_stance` if it differs from the character's
+    /// current stance, resizing its capsule collider and swapping in the
+    /// stance's `max_step`/`max_climb_angle_deg` profile. Crouching/crawling
+    /// (shrinking the capsule) always succeeds; standing back up first
+    /// raycasts straight up by the height difference and is refused --
+    /// leaving `requested_stance` pending for a future call -- if a ceiling
+    /// is in the way, so stealth mechanics can request `Stance::Standing`
+    /// freely and poll [`CharacterController::stance`] to see when it took
+    /// effect. Call this once per frame, before [`Self::control_character`].
+    pub fn update_character_stance(&mut self, id: BodyId) -> bool {
+        let Some(ctrl) = self.char_map.get(&id).copied() else {
+            return false;
+        };
+        if ctrl.stance == ctrl.requested_stance {
+            return true;
+        }
+        let Some(h) = self.handle_of(id) else {
+            return false;
+        };
+        let target_height = ctrl.height_for(ctrl.requested_stance);
+
+        if target_height > ctrl.height {
+            let Some(rb) = self.bodies.get(h) else {
+                return false;
+            };
+            let pos = *rb.position();
+            let head = glam::Vec3::new(pos.translation.x, pos.translation.y, pos.translation.z)
+                + glam::Vec3::Y * ctrl.height;
+            let clearance = target_height - ctrl.height;
+            let up_ray = Ray::new(point![head.x, head.y, head.z], vector![0.0, 1.0, 0.0]);
+            let blocked = self
+                .query_pipeline
+                .cast_ray_and_get_normal(
+                    &self.bodies,
+                    &self.colliders,
+                    &up_ray,
+                    clearance,
+                    true,
+                    QueryFilter::default().exclude_rigid_body(h),
+                )
+                .is_some();
+            if blocked {
+                return false;
+            }
+        }
+
+        let Some(collider_handle) = self
+            .colliders
+            .iter()
+            .find_map(|(ch, c)| (c.parent() == Some(h)).then_some(ch))
+        else {
+            return false;
+        };
+        let Some(collider) = self.colliders.get_mut(collider_handle) else {
+            return false;
+        };
+        collider.set_shape(SharedShape::capsule_y(target_height / 2.0, ctrl.radius));
+
+        if let Some(ctrl) = self.char_map.get_mut(&id) {
+            ctrl.height = target_height;
+            ctrl.max_step = ctrl.max_step_for(ctrl.requested_stance);
+            ctrl.max_climb_angle_deg = ctrl.max_climb_angle_deg_for(ctrl.requested_stance);
+            ctrl.stance = ctrl.requested_stance;
+        }
+        true
+    }
+
     pub fn control_character(&mut self, id: BodyId, desired_move: Vec3, dt: f32, _climb: bool) {
         #[cfg(feature = "profiling")]
         span!("Physics::CharacterController::move");
@@ -1250,6 +2005,12 @@ impl PhysicsWorld {
         };
         let pos = *rb.position();
         let start = glam::Vec3::new(pos.translation.x, pos.translation.y, pos.translation.z);
+
+        if ctrl.state.is_swimming() {
+            self.control_character_swimming(id, ctrl, h, start, desired_move, dt);
+            return;
+        }
+
         // Update timers
         ctrl.jump_buffer_timer -= dt;
 
@@ -1368,6 +2129,193 @@ impl PhysicsWorld {
         self.char_map.insert(id, ctrl);
     }
 
+    /// `Swimming`-state movement for [`Self::control_character`]: buoyancy
+    /// pulls vertical velocity toward floating just under the surface
+    /// (`swim_surface_depth`) unless the caller is pushing up/down via
+    /// `desired_move.y`, and horizontal speed is capped at `swim_speed`.
+    /// Simplified relative to grounded movement -- no obstacle raycasts, no
+    /// step/slope handling, since submerged geometry is rarely walkable.
+    fn control_character_swimming(
+        &mut self,
+        id: BodyId,
+        mut ctrl: CharacterController,
+        h: RigidBodyHandle,
+        start: Vec3,
+        desired_move: Vec3,
+        dt: f32,
+    ) {
+        let horizontal = glam::Vec3::new(desired_move.x, 0.0, desired_move.z);
+        let horizontal = if horizontal.length_squared() > ctrl.swim_speed * ctrl.swim_speed {
+            horizontal.normalize() * ctrl.swim_speed
+        } else {
+            horizontal
+        };
+
+        if desired_move.y.abs() > 1e-3 {
+            ctrl.vertical_velocity = desired_move.y.clamp(-ctrl.swim_speed, ctrl.swim_speed);
+        } else {
+            let target_y = ctrl
+                .swim_surface_y
+                .map(|surface| surface - ctrl.swim_surface_depth)
+                .unwrap_or(start.y);
+            ctrl.vertical_velocity = ((target_y - start.y) * ctrl.buoyancy_response)
+                .clamp(-ctrl.swim_speed, ctrl.swim_speed);
+        }
+
+        let new_pos = start + horizontal * dt + glam::Vec3::Y * ctrl.vertical_velocity * dt;
+
+        if let Some(rbmut) = self.bodies.get_mut(h) {
+            let mut p = *rbmut.position();
+            p.translation.x = new_pos.x;
+            p.translation.y = new_pos.y;
+            p.translation.z = new_pos.z;
+            rbmut.set_next_kinematic_position(p);
+        }
+
+        self.char_map.insert(id, ctrl);
+    }
+
+    /// Root-motion-driven counterpart to [`Self::control_character`]. Instead
+    /// of gameplay code driving movement from a velocity parameter, this
+    /// consumes a per-frame translation delta sampled from an animation
+    /// clip's root-motion track (e.g. `astraweave_asset::gltf_loader::RootMotionTrack`)
+    /// and reuses the exact same collision/ground-detection pipeline by
+    /// converting the delta to an equivalent velocity for this frame.
+    pub fn control_character_root_motion(
+        &mut self,
+        id: BodyId,
+        root_translation_delta: Vec3,
+        dt: f32,
+        climb: bool,
+    ) {
+        if dt <= 0.0 {
+            return;
+        }
+        self.control_character(id, root_translation_delta / dt, dt, climb);
+    }
+
+    /// Shape-casts ahead of a character for a climbable ledge within reach.
+    /// Reuses the same forward-then-down raycast pattern as
+    /// [`Self::control_character`]'s obstacle/ground checks rather than a
+    /// true swept shape cast, since a wall face followed by a ledge-top
+    /// probe is enough to characterize a mantle-able edge: a wall directly
+    /// ahead within `mantle_reach`, topped by a ledge no higher than
+    /// `mantle_max_ledge_height` above the character's feet.
+    pub fn try_mantle(&self, id: BodyId, forward: Vec3) -> Option<MantleTarget> {
+        let ctrl = self.char_map.get(&id).copied()?;
+        let h = self.handle_of(id)?;
+        let rb = self.bodies.get(h)?;
+        let pos = *rb.position();
+        let feet = glam::Vec3::new(pos.translation.x, pos.translation.y, pos.translation.z);
+        let dir = forward.normalize_or_zero();
+        if dir.length_squared() < 1e-6 {
+            return None;
+        }
+        // Wall face directly ahead, at chest height.
+        let chest = feet + glam::Vec3::Y * (ctrl.height * 0.5);
+        let wall_ray = Ray::new(
+            point![chest.x, chest.y, chest.z],
+            vector![dir.x, dir.y, dir.z],
+        );
+        let (_, wall_hit) = self.query_pipeline.cast_ray_and_get_normal(
+            &self.bodies,
+            &self.colliders,
+            &wall_ray,
+            ctrl.mantle_reach,
+            true,
+            QueryFilter::default().exclude_rigid_body(h),
+        )?;
+        let wall_distance = wall_hit.time_of_impact;
+
+        // Ledge top, probed downward from above the wall on the far side.
+        let probe_xz = feet + dir * (wall_distance + ctrl.radius);
+        let probe_origin = probe_xz + glam::Vec3::Y * (ctrl.height + ctrl.mantle_max_ledge_height);
+        let down_ray = Ray::new(
+            point![probe_origin.x, probe_origin.y, probe_origin.z],
+            vector![0.0, -1.0, 0.0],
+        );
+        let (_, ledge_hit) = self.query_pipeline.cast_ray_and_get_normal(
+            &self.bodies,
+            &self.colliders,
+            &down_ray,
+            ctrl.height + ctrl.mantle_max_ledge_height + 1.0,
+            true,
+            QueryFilter::default().exclude_rigid_body(h),
+        )?;
+        let ledge_y = probe_origin.y - ledge_hit.time_of_impact;
+        let ledge_height = ledge_y - feet.y;
+        if ledge_height <= ctrl.max_step || ledge_height > ctrl.mantle_max_ledge_height {
+            return None;
+        }
+
+        Some(MantleTarget {
+            position: glam::Vec3::new(probe_xz.x, ledge_y, probe_xz.z),
+            wall_distance,
+        })
+    }
+
+    /// Begins a kinematic mantle toward `target`, to be advanced each frame
+    /// with [`Self::update_mantle`]. Overwrites any mantle already in
+    /// progress for this character.
+    pub fn start_mantle(&mut self, id: BodyId, target: MantleTarget) {
+        let Some(h) = self.handle_of(id) else {
+            return;
+        };
+        let Some(rb) = self.bodies.get(h) else {
+            return;
+        };
+        let pos = *rb.position();
+        let start = glam::Vec3::new(pos.translation.x, pos.translation.y, pos.translation.z);
+        if let Some(ctrl) = self.char_map.get_mut(&id) {
+            ctrl.vertical_velocity = 0.0;
+            ctrl.mantling = Some(MantleState {
+                start,
+                target: target.position,
+                elapsed: 0.0,
+            });
+        }
+    }
+
+    /// Advances an in-progress mantle by `dt`, driving the character's
+    /// kinematic position along a straight line from where the mantle
+    /// started to its ledge target. Returns `true` while still mantling,
+    /// or `false` once finished (or if no mantle was in progress), at which
+    /// point the character is grounded on the ledge.
+    pub fn update_mantle(&mut self, id: BodyId, dt: f32) -> bool {
+        let Some(mut ctrl) = self.char_map.get(&id).copied() else {
+            return false;
+        };
+        let Some(mut mantle) = ctrl.mantling else {
+            return false;
+        };
+        let Some(h) = self.handle_of(id) else {
+            return false;
+        };
+
+        mantle.elapsed += dt;
+        let t = (mantle.elapsed / ctrl.mantle_duration.max(1e-4)).clamp(0.0, 1.0);
+        let pos = mantle.start.lerp(mantle.target, t);
+
+        if let Some(rbmut) = self.bodies.get_mut(h) {
+            let mut p = *rbmut.position();
+            p.translation.x = pos.x;
+            p.translation.y = pos.y;
+            p.translation.z = pos.z;
+            rbmut.set_next_kinematic_position(p);
+        }
+
+        if t >= 1.0 {
+            ctrl.mantling = None;
+            ctrl.time_since_grounded = 0.0;
+            self.char_map.insert(id, ctrl);
+            false
+        } else {
+            ctrl.mantling = Some(mantle);
+            self.char_map.insert(id, ctrl);
+            true
+        }
+    }
+
     pub fn handle_of(&self, id: BodyId) -> Option<RigidBodyHandle> {
         self.body_ids
             .iter()
@@ -1394,6 +2342,105 @@ impl PhysicsWorld {
         ))
     }
 
+    /// Blends between the poses captured before and after the most recent
+    /// [`Self::step`], for smooth rendering when the render frame rate
+    /// doesn't line up with the fixed physics timestep. `alpha` is the
+    /// fraction of the way from the previous step to the current one
+    /// (0.0 = previous pose, 1.0 = current pose) and is clamped to `[0, 1]`.
+    ///
+    /// Falls back to the current pose (no interpolation) for a body with no
+    /// recorded previous pose yet, such as one spawned this step, to avoid
+    /// blending from a stale or missing position.
+    pub fn interpolated_pose(&self, id: BodyId, alpha: f32) -> Option<Mat4> {
+        let current = *self.current_poses.get(&id)?;
+        let pose = match self.previous_poses.get(&id) {
+            Some(previous) => previous.lerp(current, alpha.clamp(0.0, 1.0)),
+            None => current,
+        };
+        Some(Mat4::from_rotation_translation(
+            pose.rotation,
+            pose.translation,
+        ))
+    }
+
+    /// Captures the pose, velocities, and character controller state of
+    /// every tracked body into a [`PhysicsSnapshot`] that [`Self::restore`]
+    /// can later replay. Does not capture colliders, joints, or the body
+    /// set itself, so it cannot resurrect a body that has since been
+    /// removed.
+    pub fn snapshot(&self) -> PhysicsSnapshot {
+        let mut bodies = Vec::with_capacity(self.body_ids.len());
+        for (&handle, &id) in self.body_ids.iter() {
+            let Some(rb) = self.bodies.get(handle) else {
+                continue;
+            };
+            let iso = rb.position();
+            let linvel = rb.linvel();
+            let angvel = rb.angvel();
+            bodies.push(BodySnapshot {
+                id,
+                translation: vec3(iso.translation.x, iso.translation.y, iso.translation.z),
+                rotation: Quat::from_xyzw(
+                    iso.rotation.i,
+                    iso.rotation.j,
+                    iso.rotation.k,
+                    iso.rotation.w,
+                ),
+                linear_velocity: vec3(linvel.x, linvel.y, linvel.z),
+                angular_velocity: vec3(angvel.x, angvel.y, angvel.z),
+            });
+        }
+        let characters = self.char_map.iter().map(|(&id, ctrl)| (id, *ctrl)).collect();
+        PhysicsSnapshot { bodies, characters }
+    }
+
+    /// Rolls tracked bodies and character controllers back to the state
+    /// captured by [`Self::snapshot`]. Bodies recorded in the snapshot that
+    /// no longer exist are skipped; bodies created since the snapshot was
+    /// taken are left untouched.
+    pub fn restore(&mut self, snapshot: &PhysicsSnapshot) {
+        for body in &snapshot.bodies {
+            let Some(handle) = self.handle_of(body.id) else {
+                continue;
+            };
+            let Some(rb) = self.bodies.get_mut(handle) else {
+                continue;
+            };
+            rb.set_translation(
+                vector![body.translation.x, body.translation.y, body.translation.z],
+                true,
+            );
+            rb.set_rotation(
+                UnitQuaternion::from_quaternion(Quaternion::new(
+                    body.rotation.w,
+                    body.rotation.x,
+                    body.rotation.y,
+                    body.rotation.z,
+                )),
+                true,
+            );
+            rb.set_linvel(
+                vector![
+                    body.linear_velocity.x,
+                    body.linear_velocity.y,
+                    body.linear_velocity.z
+                ],
+                true,
+            );
+            rb.set_angvel(
+                vector![
+                    body.angular_velocity.x,
+                    body.angular_velocity.y,
+                    body.angular_velocity.z
+                ],
+                true,
+            );
+        }
+        for (id, ctrl) in &snapshot.characters {
+            self.char_map.insert(*id, *ctrl);
+        }
+    }
+
     fn tag_body(&mut self, h: RigidBodyHandle, kind: ActorKind) -> BodyId {
         let id = self.alloc_id();
         self.body_ids.insert(h, id);
@@ -1514,9 +2561,18 @@ impl PhysicsWorld {
         direction: Vec3,
         max_distance: f32,
     ) -> Option<(Vec3, Vec3, Option<BodyId>, f32)> {
+        self.cast_ray(&RayRequest {
+            origin,
+            direction,
+            max_distance,
+        })
+        .map(|hit| (hit.position, hit.normal, hit.body_id, hit.distance))
+    }
+
+    fn cast_ray(&self, request: &RayRequest) -> Option<RayHit> {
         let ray = rapier3d::prelude::Ray::new(
-            point![origin.x, origin.y, origin.z],
-            vector![direction.x, direction.y, direction.z],
+            point![request.origin.x, request.origin.y, request.origin.z],
+            vector![request.direction.x, request.direction.y, request.direction.z],
         );
 
         self.query_pipeline
@@ -1524,12 +2580,12 @@ impl PhysicsWorld {
                 &self.bodies,
                 &self.colliders,
                 &ray,
-                max_distance,
+                request.max_distance,
                 true,
                 QueryFilter::default(),
             )
             .map(|(collider_handle, hit)| {
-                let hit_pos = origin + direction * hit.time_of_impact;
+                let position = request.origin + request.direction * hit.time_of_impact;
                 let normal = Vec3::new(hit.normal.x, hit.normal.y, hit.normal.z);
 
                 // Get body ID from collider
@@ -1539,10 +2595,28 @@ impl PhysicsWorld {
                     .and_then(|c| c.parent())
                     .and_then(|rb_handle| self.body_ids.get(&rb_handle).copied());
 
-                (hit_pos, normal, body_id, hit.time_of_impact)
+                RayHit {
+                    position,
+                    normal,
+                    body_id,
+                    distance: hit.time_of_impact,
+                }
             })
     }
 
+    /// Casts every ray in `requests` against the query pipeline in parallel
+    /// via Rayon, reusing the single already-updated query pipeline instead
+    /// of rebuilding per-ray state. Intended for AI perception systems that
+    /// need many independent line-of-sight/visibility checks per frame
+    /// (large agent crowds), where a serial loop over [`Self::raycast`]
+    /// becomes the bottleneck. Results are aligned by index with `requests`;
+    /// `None` means that ray hit nothing within its `max_distance`.
+    #[cfg(feature = "async-physics")]
+    pub fn raycast_batch(&self, requests: &[RayRequest]) -> Vec<Option<RayHit>> {
+        use rayon::prelude::*;
+        requests.par_iter().map(|req| self.cast_ray(req)).collect()
+    }
+
     pub fn clear_water(&mut self) {}
     pub fn add_destructible_box(
         &mut self,
@@ -1574,6 +2648,29 @@ impl PhysicsWorld {
         }
     }
 
+    /// Removes a body and its colliders entirely, returning `true` if it
+    /// existed. General-purpose counterpart to [`Self::break_destructible`]
+    /// for callers (e.g. streaming systems) that just need a body gone
+    /// rather than a destruction event.
+    pub fn remove_body(&mut self, id: BodyId) -> bool {
+        let Some(h) = self.handle_of(id) else {
+            return false;
+        };
+        self.bodies.remove(
+            h,
+            &mut self.island_mgr,
+            &mut self.colliders,
+            &mut self.joints,
+            &mut self.multibody_joints,
+            true,
+        );
+        self.body_ids.remove(&h);
+        self.body_kinds.remove(&h);
+        self.char_map.remove(&id);
+        self.buoyancy_bodies.remove(&id);
+        true
+    }
+
     #[allow(dead_code)]
     fn process_destructible_hits(&mut self) {}
 
@@ -1622,11 +2719,64 @@ impl PhysicsWorld {
             JointType::Spherical => SphericalJointBuilder::new().build().into(),
         };
 
-        self.joints.insert(handle1, handle2, joint, true);
+        let handle = self.joints.insert(handle1, handle2, joint, true);
 
-        let joint_id = self.next_joint_id;
+        let joint_id = JointId(self.next_joint_id);
         self.next_joint_id += 1;
-        JointId(joint_id)
+        self.joint_handles.insert(joint_id, handle);
+        self.joint_types.insert(joint_id, joint_type);
+        joint_id
+    }
+
+    /// Returns the rapier motor axis driven for `joint_type`, or `None` for
+    /// joint types with no single well-defined motor axis (`Fixed` has zero
+    /// degrees of freedom, `Spherical` has three and would need per-axis
+    /// motor calls we don't yet expose).
+    fn motor_axis(joint_type: JointType) -> Option<JointAxis> {
+        match joint_type {
+            JointType::Revolute { .. } => Some(JointAxis::AngX),
+            JointType::Prismatic { .. } => Some(JointAxis::X),
+            JointType::Fixed | JointType::Spherical => None,
+        }
+    }
+
+    /// Configures the motor of an existing [`JointType::Revolute`] or
+    /// [`JointType::Prismatic`] joint created by [`Self::add_joint`], driving
+    /// it toward `motor`'s target position/velocity with a PD controller
+    /// (`stiffness` resists position error, `damping` resists velocity
+    /// error) capped at `motor.max_force`. Ragdoll get-up blending and
+    /// articulated machinery (crane arms, doors) use this to actuate a joint
+    /// physically instead of teleporting it. Returns `false` if `id` is
+    /// unknown or its joint type has no single motor axis.
+    pub fn set_joint_motor(&mut self, id: JointId, motor: JointMotorParams) -> bool {
+        let Some(&handle) = self.joint_handles.get(&id) else {
+            return false;
+        };
+        let Some(&joint_type) = self.joint_types.get(&id) else {
+            return false;
+        };
+        let Some(axis) = Self::motor_axis(joint_type) else {
+            return false;
+        };
+        let Some(joint) = self.joints.get_mut(handle) else {
+            return false;
+        };
+        joint.data.set_motor(
+            axis,
+            motor.target_position,
+            motor.target_velocity,
+            motor.stiffness,
+            motor.damping,
+        );
+        joint.data.set_motor_max_force(axis, motor.max_force);
+        true
+    }
+
+    /// Disables the motor of a joint previously configured with
+    /// [`Self::set_joint_motor`], letting it move freely (subject to its
+    /// limits) again.
+    pub fn clear_joint_motor(&mut self, id: JointId) -> bool {
+        self.set_joint_motor(id, JointMotorParams::disabled())
     }
 
     pub fn get_debug_lines(&mut self) -> Vec<DebugLine> {
@@ -1639,7 +2789,94 @@ impl PhysicsWorld {
             &self.multibody_joints,
             &self.narrow_phase,
         );
-        collector.lines
+        let mut lines = collector.lines;
+
+        if self.debug_render_categories.character_controllers {
+            self.push_character_controller_debug_lines(&mut lines);
+        }
+        if self.debug_render_categories.color_by_layer {
+            self.push_layer_color_debug_lines(&mut lines);
+        }
+        lines
+    }
+
+    /// Sets which debug-render categories [`Self::get_debug_lines`] includes,
+    /// applying the rapier-native categories to the underlying
+    /// [`DebugRenderPipeline`] immediately. See [`DebugRenderCategories`] for
+    /// which categories this crate can and can't draw on its own.
+    pub fn set_debug_render_categories(&mut self, categories: DebugRenderCategories) {
+        self.debug_render_pipeline.mode = categories.rapier_mode();
+        self.debug_render_categories = categories;
+    }
+
+    /// Returns the debug-render categories most recently set with
+    /// [`Self::set_debug_render_categories`].
+    pub fn debug_render_categories(&self) -> DebugRenderCategories {
+        self.debug_render_categories
+    }
+
+    /// Appends a wireframe capsule axis (a vertical line plus a small
+    /// horizontal cross at the body's origin) for each tracked character
+    /// controller, colored by [`CharState`] so grounded vs. swimming
+    /// characters are visually distinct.
+    fn push_character_controller_debug_lines(&self, lines: &mut Vec<DebugLine>) {
+        for (&id, ctrl) in self.char_map.iter() {
+            let Some(h) = self.handle_of(id) else {
+                continue;
+            };
+            let Some(rb) = self.bodies.get(h) else {
+                continue;
+            };
+            let p = rb.position().translation;
+            let center = Vec3::new(p.x, p.y, p.z);
+            let color = if ctrl.state.is_swimming() {
+                [0.2, 0.6, 1.0]
+            } else {
+                [1.0, 0.8, 0.0]
+            };
+            let half_height = ctrl.height * 0.5;
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::Y * half_height,
+                center + Vec3::Y * half_height,
+                color,
+            ));
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::X * ctrl.radius,
+                center + Vec3::X * ctrl.radius,
+                color,
+            ));
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::Z * ctrl.radius,
+                center + Vec3::Z * ctrl.radius,
+                color,
+            ));
+        }
+    }
+
+    /// Appends a small axis-cross at every collider's origin, colored by
+    /// [`layer_debug_color`], for [`DebugRenderCategories::color_by_layer`].
+    fn push_layer_color_debug_lines(&self, lines: &mut Vec<DebugLine>) {
+        const MARKER_SIZE: f32 = 0.15;
+        for (_, collider) in self.colliders.iter() {
+            let p = collider.position().translation;
+            let center = Vec3::new(p.x, p.y, p.z);
+            let color = layer_debug_color(collider.collision_groups());
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::X * MARKER_SIZE,
+                center + Vec3::X * MARKER_SIZE,
+                color,
+            ));
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::Y * MARKER_SIZE,
+                center + Vec3::Y * MARKER_SIZE,
+                color,
+            ));
+            lines.push(DebugLine::from_vec3(
+                center - Vec3::Z * MARKER_SIZE,
+                center + Vec3::Z * MARKER_SIZE,
+                color,
+            ));
+        }
     }
 }
 
@@ -1683,16 +2920,231 @@ mod tests {
     }
 
     #[test]
-    fn character_moves_forward() {
+    fn character_moves_forward_via_root_motion() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let _ground = pw.create_ground_plane(Vec3::new(10.0, 0.5, 10.0), 0.9);
+        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        for _ in 0..60 {
+            // Same per-frame translation as `character_moves_forward`'s
+            // Vec3::new(1.0, 0.0, 0.0) desired_move, but expressed as a
+            // root-motion delta instead of a velocity.
+            pw.control_character_root_motion(char_id, Vec3::new(1.0, 0.0, 0.0) / 60.0, 1.0 / 60.0, false);
+            pw.step();
+        }
+        let x = pw.body_transform(char_id).unwrap().w_axis.x;
+        assert!(x > 0.5, "character should have moved forward, x={}", x);
+    }
+
+    #[test]
+    fn control_character_root_motion_ignores_zero_dt() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let _ground = pw.create_ground_plane(Vec3::new(10.0, 0.5, 10.0), 0.9);
+        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let before = pw.body_transform(char_id).unwrap().w_axis;
+
+        pw.control_character_root_motion(char_id, Vec3::new(1.0, 0.0, 0.0), 0.0, false);
+        pw.step();
+
+        let after = pw.body_transform(char_id).unwrap().w_axis;
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn update_character_water_state_enters_swimming_when_deep_enough() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let mut env = EnvironmentManager::new();
+        // Water surface well above the character's chest (height 1.8 -> chest at y=0.9).
+        env.add_water_volume(Vec3::new(0.0, 5.0, 0.0), Vec3::new(20.0, 5.0, 20.0));
+
+        let swimming = pw.update_character_water_state(char_id, &env);
+
+        assert!(swimming);
+        assert_eq!(
+            pw.char_map.get(&char_id).unwrap().state,
+            CharState::Swimming
+        );
+        assert!(pw.char_map.get(&char_id).unwrap().swim_surface_y.is_some());
+    }
+
+    #[test]
+    fn update_character_water_state_stays_grounded_when_shallow() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let mut env = EnvironmentManager::new();
+        // Water surface only grazes the character's feet, not deep enough to swim in.
+        env.add_water_volume(Vec3::new(0.0, -0.9, 0.0), Vec3::new(20.0, 0.1, 20.0));
+
+        let swimming = pw.update_character_water_state(char_id, &env);
+
+        assert!(!swimming);
+        assert_eq!(
+            pw.char_map.get(&char_id).unwrap().state,
+            CharState::Grounded
+        );
+    }
+
+    #[test]
+    fn update_character_water_state_exits_back_to_grounded() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let mut env = EnvironmentManager::new();
+        let water_id = env.add_water_volume(Vec3::new(0.0, 5.0, 0.0), Vec3::new(20.0, 5.0, 20.0));
+
+        assert!(pw.update_character_water_state(char_id, &env));
+
+        env.remove_water_volume(water_id);
+        let swimming = pw.update_character_water_state(char_id, &env);
+
+        assert!(!swimming);
+        let ctrl = pw.char_map.get(&char_id).unwrap();
+        assert_eq!(ctrl.state, CharState::Grounded);
+        assert!(ctrl.swim_surface_y.is_none());
+        // Exiting water should not grant a fresh coyote-time jump window.
+        assert!(ctrl.time_since_grounded > ctrl.coyote_time_limit);
+    }
+
+    #[test]
+    fn swimming_character_rises_toward_surface_via_buoyancy() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, -3.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let mut env = EnvironmentManager::new();
+        // Water spans y in [-5, 5], so the character starts well below the surface.
+        env.add_water_volume(Vec3::new(0.0, 0.0, 0.0), Vec3::new(20.0, 5.0, 20.0));
+        pw.update_character_water_state(char_id, &env);
+
+        let start_y = pw.body_transform(char_id).unwrap().w_axis.y;
+        for _ in 0..60 {
+            pw.control_character(char_id, Vec3::ZERO, 1.0 / 60.0, false);
+            pw.step();
+        }
+        let end_y = pw.body_transform(char_id).unwrap().w_axis.y;
+
+        assert!(
+            end_y > start_y,
+            "buoyancy should float the character upward, start={start_y} end={end_y}"
+        );
+    }
+
+    #[test]
+    fn swimming_character_horizontal_speed_is_capped() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let mut env = EnvironmentManager::new();
+        env.add_water_volume(Vec3::new(0.0, 5.0, 0.0), Vec3::new(20.0, 5.0, 20.0));
+        pw.update_character_water_state(char_id, &env);
+
+        let swim_speed = pw.char_map.get(&char_id).unwrap().swim_speed;
+        let start_x = pw.body_transform(char_id).unwrap().w_axis.x;
+        // Request an absurdly fast horizontal move; swim_speed should clamp it.
+        pw.control_character(char_id, Vec3::new(100.0, 0.0, 0.0), 1.0 / 60.0, false);
+        pw.step();
+        let end_x = pw.body_transform(char_id).unwrap().w_axis.x;
+
+        let dt = 1.0 / 60.0;
+        assert!(end_x - start_x <= swim_speed * dt + 1e-4);
+    }
+
+    #[test]
+    fn character_moves_forward() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let _ground = pw.create_ground_plane(Vec3::new(10.0, 0.5, 10.0), 0.9);
+        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        for _ in 0..60 {
+            pw.control_character(char_id, Vec3::new(1.0, 0.0, 0.0), 1.0 / 60.0, false);
+            pw.step();
+        }
+        let x = pw.body_transform(char_id).unwrap().w_axis.x;
+        assert!(x > 0.5, "character should have moved forward, x={}", x);
+    }
+
+    #[test]
+    fn stance_defaults_to_standing() {
+        assert_eq!(Stance::default(), Stance::Standing);
+    }
+
+    #[test]
+    fn request_character_stance_rejects_unknown_body() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        assert!(!pw.request_character_stance(999_999, Stance::Crouching));
+    }
+
+    #[test]
+    fn update_character_stance_is_a_noop_when_already_applied() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        assert!(pw.update_character_stance(char_id));
+        assert_eq!(pw.char_map.get(&char_id).unwrap().stance, Stance::Standing);
+    }
+
+    #[test]
+    fn crouching_shrinks_the_capsule_and_adjusts_step_profile() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        let standing_height = pw.char_map.get(&char_id).unwrap().height;
+
+        assert!(pw.request_character_stance(char_id, Stance::Crouching));
+        assert!(pw.update_character_stance(char_id));
+
+        let ctrl = pw.char_map.get(&char_id).unwrap();
+        assert_eq!(ctrl.stance, Stance::Crouching);
+        assert!(ctrl.height < standing_height);
+        assert_eq!(ctrl.max_step, ctrl.crouch_max_step);
+        assert_eq!(ctrl.max_climb_angle_deg, ctrl.crouch_max_climb_angle_deg);
+    }
+
+    #[test]
+    fn crawling_shrinks_further_than_crouching() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+
+        assert!(pw.request_character_stance(char_id, Stance::Crawling));
+        assert!(pw.update_character_stance(char_id));
+
+        let ctrl = pw.char_map.get(&char_id).unwrap();
+        assert_eq!(ctrl.stance, Stance::Crawling);
+        assert!(ctrl.height < ctrl.crouch_height);
+    }
+
+    #[test]
+    fn standing_back_up_is_blocked_by_a_ceiling() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        pw.request_character_stance(char_id, Stance::Crouching);
+        pw.update_character_stance(char_id);
+
+        // Ceiling with its underside just above the crouched capsule's head
+        // (no overlap), but well below where standing height would reach.
+        let crouch_height = pw.char_map.get(&char_id).unwrap().height;
+        let box_half_height = 0.1;
+        let box_bottom = 5.0 + crouch_height + 0.05;
+        pw.add_dynamic_box(
+            Vec3::new(0.0, box_bottom + box_half_height, 0.0),
+            Vec3::new(2.0, box_half_height, 2.0),
+            1.0,
+            Layers::DEFAULT,
+        );
+        pw.step();
+
+        pw.request_character_stance(char_id, Stance::Standing);
+        assert!(!pw.update_character_stance(char_id));
+        assert_eq!(
+            pw.char_map.get(&char_id).unwrap().stance,
+            Stance::Crouching,
+            "stance should remain pending, not silently applied"
+        );
+    }
+
+    #[test]
+    fn standing_back_up_succeeds_with_clear_headroom() {
         let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
-        let _ground = pw.create_ground_plane(Vec3::new(10.0, 0.5, 10.0), 0.9);
-        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
-        for _ in 0..60 {
-            pw.control_character(char_id, Vec3::new(1.0, 0.0, 0.0), 1.0 / 60.0, false);
-            pw.step();
-        }
-        let x = pw.body_transform(char_id).unwrap().w_axis.x;
-        assert!(x > 0.5, "character should have moved forward, x={}", x);
+        let char_id = pw.add_character(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        pw.request_character_stance(char_id, Stance::Crouching);
+        pw.update_character_stance(char_id);
+
+        pw.request_character_stance(char_id, Stance::Standing);
+        assert!(pw.update_character_stance(char_id));
+        assert_eq!(pw.char_map.get(&char_id).unwrap().stance, Stance::Standing);
     }
 
     // ===== PhysicsWorld Basic Tests =====
@@ -1753,6 +3205,62 @@ mod tests {
         assert!(y < 5.0, "Box should have fallen, y={}", y);
     }
 
+    #[test]
+    fn add_trigger_volume_creates_a_body_and_collider() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let trigger_id =
+            pw.add_trigger_volume(Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 1.0), Layers::DEFAULT);
+
+        assert!(pw.body_transform(trigger_id).is_some());
+    }
+
+    #[test]
+    fn trigger_volume_reports_overlap_via_collision_events() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        pw.add_trigger_volume(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 2.0), Layers::DEFAULT);
+        pw.add_dynamic_box(
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::new(0.2, 0.2, 0.2),
+            1.0,
+            Layers::DEFAULT,
+        );
+
+        pw.step();
+
+        assert!(
+            pw.collision_recv.try_recv().is_ok(),
+            "overlapping trigger and dynamic box should raise a collision event"
+        );
+    }
+
+    #[test]
+    fn set_trigger_volume_half_extents_resizes_the_collider() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let trigger_id =
+            pw.add_trigger_volume(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0), Layers::DEFAULT);
+
+        assert!(pw.set_trigger_volume_half_extents(trigger_id, Vec3::new(3.0, 3.0, 3.0)));
+    }
+
+    #[test]
+    fn set_trigger_volume_half_extents_rejects_unknown_body() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        assert!(!pw.set_trigger_volume_half_extents(999_999, Vec3::new(1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn set_trigger_volume_position_moves_the_body_and_rejects_unknown() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let trigger_id =
+            pw.add_trigger_volume(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0), Layers::DEFAULT);
+
+        assert!(pw.set_trigger_volume_position(trigger_id, Vec3::new(5.0, 0.0, 0.0)));
+        let pos = pw.body_transform(trigger_id).unwrap().w_axis;
+        assert!((pos.x - 5.0).abs() < 1e-4);
+
+        assert!(!pw.set_trigger_volume_position(999_999, Vec3::new(0.0, 0.0, 0.0)));
+    }
+
     #[test]
     fn test_apply_force() {
         let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
@@ -2131,6 +3639,108 @@ mod tests {
         assert_ne!(j4.0, 0);
     }
 
+    #[test]
+    fn set_joint_motor_drives_revolute_joint() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let b1 = pw.add_dynamic_box(
+            Vec3::new(0.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let b2 = pw.add_dynamic_box(
+            Vec3::new(1.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let joint = pw.add_joint(
+            b1,
+            b2,
+            JointType::Revolute {
+                axis: Vec3::Y,
+                limits: None,
+            },
+        );
+
+        assert!(pw.set_joint_motor(
+            joint,
+            JointMotorParams::velocity(1.0, 10.0).with_max_force(100.0)
+        ));
+    }
+
+    #[test]
+    fn set_joint_motor_rejects_unknown_joint() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        assert!(!pw.set_joint_motor(JointId(9999), JointMotorParams::disabled()));
+    }
+
+    #[test]
+    fn set_joint_motor_rejects_axis_less_joint_types() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let b1 = pw.add_dynamic_box(
+            Vec3::new(0.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let b2 = pw.add_dynamic_box(
+            Vec3::new(1.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let fixed = pw.add_joint(b1, b2, JointType::Fixed);
+        let spherical = pw.add_joint(b1, b2, JointType::Spherical);
+
+        assert!(!pw.set_joint_motor(fixed, JointMotorParams::disabled()));
+        assert!(!pw.set_joint_motor(spherical, JointMotorParams::disabled()));
+    }
+
+    #[test]
+    fn clear_joint_motor_disables_previously_configured_motor() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let b1 = pw.add_dynamic_box(
+            Vec3::new(0.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let b2 = pw.add_dynamic_box(
+            Vec3::new(1.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.1, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let joint = pw.add_joint(
+            b1,
+            b2,
+            JointType::Prismatic {
+                axis: Vec3::X,
+                limits: None,
+            },
+        );
+
+        assert!(pw.set_joint_motor(joint, JointMotorParams::position_hold(0.5, 50.0, 5.0)));
+        assert!(pw.clear_joint_motor(joint));
+    }
+
+    #[test]
+    fn joint_motor_params_constructors() {
+        let hold = JointMotorParams::position_hold(1.0, 50.0, 5.0);
+        assert_eq!(hold.target_position, 1.0);
+        assert_eq!(hold.target_velocity, 0.0);
+        assert_eq!(hold.max_force, f32::MAX);
+
+        let vel = JointMotorParams::velocity(2.0, 3.0).with_max_force(10.0);
+        assert_eq!(vel.target_velocity, 2.0);
+        assert_eq!(vel.max_force, 10.0);
+
+        let off = JointMotorParams::disabled();
+        assert_eq!(off.stiffness, 0.0);
+        assert_eq!(off.max_force, 0.0);
+    }
+
     #[test]
     fn test_set_body_position() {
         let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
@@ -2171,6 +3781,104 @@ mod tests {
         assert!(!lines.is_empty());
     }
 
+    #[test]
+    fn debug_render_categories_default_is_everything_on_except_color_by_layer() {
+        let categories = DebugRenderCategories::default();
+        assert!(categories.colliders);
+        assert!(categories.aabbs);
+        assert!(categories.joints);
+        assert!(categories.contacts);
+        assert!(categories.character_controllers);
+        assert!(categories.spatial_hash_cells);
+        assert!(!categories.color_by_layer);
+    }
+
+    #[test]
+    fn set_debug_render_categories_is_reflected_by_getter() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let categories = DebugRenderCategories {
+            colliders: false,
+            aabbs: false,
+            joints: false,
+            contacts: false,
+            character_controllers: true,
+            spatial_hash_cells: false,
+            color_by_layer: true,
+        };
+
+        pw.set_debug_render_categories(categories);
+
+        assert_eq!(pw.debug_render_categories(), categories);
+    }
+
+    #[test]
+    fn character_controller_debug_lines_included_when_category_enabled() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        pw.set_debug_render_categories(DebugRenderCategories {
+            colliders: false,
+            aabbs: false,
+            joints: false,
+            contacts: false,
+            character_controllers: true,
+            spatial_hash_cells: false,
+            color_by_layer: false,
+        });
+        pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+
+        let lines = pw.get_debug_lines();
+
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn character_controller_debug_lines_excluded_when_category_disabled() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        pw.set_debug_render_categories(DebugRenderCategories {
+            colliders: false,
+            aabbs: false,
+            joints: false,
+            contacts: false,
+            character_controllers: false,
+            spatial_hash_cells: false,
+            color_by_layer: false,
+        });
+        pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+
+        let lines = pw.get_debug_lines();
+
+        assert!(lines.is_empty());
+    }
+
+    #[test]
+    fn color_by_layer_debug_lines_included_when_enabled() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        pw.set_debug_render_categories(DebugRenderCategories {
+            colliders: false,
+            aabbs: false,
+            joints: false,
+            contacts: false,
+            character_controllers: false,
+            spatial_hash_cells: false,
+            color_by_layer: true,
+        });
+        pw.add_dynamic_box(
+            Vec3::new(0.0, 5.0, 0.0),
+            Vec3::new(0.5, 0.5, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+
+        let lines = pw.get_debug_lines();
+
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn line_collector_is_public_and_starts_empty() {
+        let collector = LineCollector::new();
+        assert!(collector.lines.is_empty());
+    }
+
     #[test]
     fn test_character_climb() {
         let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
@@ -2357,8 +4065,9 @@ mod tests {
     #[test]
     fn test_char_state_all() {
         let all = CharState::all();
-        assert_eq!(all.len(), 1);
+        assert_eq!(all.len(), 2);
         assert!(all.contains(&CharState::Grounded));
+        assert!(all.contains(&CharState::Swimming));
     }
 
     #[test]
@@ -3040,6 +4749,145 @@ mod tests {
         );
     }
 
+    fn add_static_box(pw: &mut PhysicsWorld, center: Vec3, half: Vec3) -> BodyId {
+        let corners: Vec<Vec3> = (0..8)
+            .map(|i| {
+                center
+                    + Vec3::new(
+                        if i & 1 == 0 { -half.x } else { half.x },
+                        if i & 2 == 0 { -half.y } else { half.y },
+                        if i & 4 == 0 { -half.z } else { half.z },
+                    )
+            })
+            .collect();
+        let indices: [[u32; 3]; 12] = [
+            [0, 1, 3],
+            [0, 3, 2],
+            [4, 6, 7],
+            [4, 7, 5],
+            [0, 4, 5],
+            [0, 5, 1],
+            [2, 3, 7],
+            [2, 7, 6],
+            [0, 2, 6],
+            [0, 6, 4],
+            [1, 5, 7],
+            [1, 7, 3],
+        ];
+        pw.add_static_trimesh(&corners, &indices, Layers::DEFAULT)
+    }
+
+    fn build_mantle_scene(pw: &mut PhysicsWorld) -> BodyId {
+        // A chest-high wall with a ledge on top, just within reach ahead of +X.
+        let _wall = add_static_box(
+            pw,
+            Vec3::new(2.5, 0.975, 0.0),
+            Vec3::new(0.5, 0.975, 2.0),
+        );
+        let char_id = pw.add_character(Vec3::new(1.5, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        pw.step();
+        char_id
+    }
+
+    #[test]
+    fn try_mantle_finds_ledge_within_reach() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = build_mantle_scene(&mut pw);
+        let target = pw.try_mantle(char_id, Vec3::X);
+        assert!(target.is_some(), "expected a mantle target ahead of the wall");
+        let target = target.unwrap();
+        assert!(
+            target.position.y > 1.0,
+            "ledge target should be above the character's feet, got {}",
+            target.position.y
+        );
+    }
+
+    #[test]
+    fn try_mantle_returns_none_with_nothing_ahead() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        pw.step();
+        assert!(pw.try_mantle(char_id, Vec3::X).is_none());
+    }
+
+    #[test]
+    fn update_mantle_moves_character_to_target_and_finishes() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = build_mantle_scene(&mut pw);
+        let target = pw.try_mantle(char_id, Vec3::X).unwrap();
+        pw.start_mantle(char_id, target);
+        assert!(pw.char_map.get(&char_id).unwrap().is_mantling());
+
+        let duration = pw.char_map.get(&char_id).unwrap().mantle_duration;
+        let mut still_mantling = true;
+        for _ in 0..120 {
+            still_mantling = pw.update_mantle(char_id, duration / 60.0);
+            if !still_mantling {
+                break;
+            }
+        }
+        assert!(!still_mantling, "mantle should finish within its duration");
+        assert!(!pw.char_map.get(&char_id).unwrap().is_mantling());
+
+        pw.step();
+        let end_pos = pw.body_transform(char_id).unwrap().w_axis;
+        assert!((end_pos.x - target.position.x).abs() < 1e-3);
+        assert!((end_pos.y - target.position.y).abs() < 1e-3);
+    }
+
+    #[test]
+    fn update_mantle_with_no_active_mantle_returns_false() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let char_id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+        assert!(!pw.update_mantle(char_id, 1.0 / 60.0));
+    }
+
+    // --- interpolated_pose: fixed-timestep interpolation ---
+    #[test]
+    fn interpolated_pose_is_none_before_first_step() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let box_id = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.5, 0.5, 0.5), 1.0, Layers::DEFAULT);
+        assert!(pw.interpolated_pose(box_id, 0.5).is_none());
+    }
+
+    #[test]
+    fn interpolated_pose_matches_body_transform_after_first_step() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let box_id = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.5, 0.5, 0.5), 1.0, Layers::DEFAULT);
+        pw.step();
+
+        // No previous pose is recorded yet (the body didn't exist before
+        // this step), so alpha should have no effect and both should match
+        // the live transform.
+        let live = pw.body_transform(box_id).unwrap();
+        let at_zero = pw.interpolated_pose(box_id, 0.0).unwrap();
+        let at_one = pw.interpolated_pose(box_id, 1.0).unwrap();
+        assert!(live.w_axis.distance(at_zero.w_axis) < 1e-4);
+        assert!(live.w_axis.distance(at_one.w_axis) < 1e-4);
+    }
+
+    #[test]
+    fn interpolated_pose_blends_between_steps() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let box_id = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.5, 0.5, 0.5), 1.0, Layers::DEFAULT);
+        pw.step();
+        let before = pw.body_transform(box_id).unwrap().w_axis.y;
+        pw.step();
+        let after = pw.body_transform(box_id).unwrap().w_axis.y;
+
+        let halfway = pw.interpolated_pose(box_id, 0.5).unwrap().w_axis.y;
+        assert!(
+            halfway > after.min(before) && halfway < before.max(after),
+            "expected halfway ({halfway}) strictly between before ({before}) and after ({after})"
+        );
+
+        let start = pw.interpolated_pose(box_id, 0.0).unwrap().w_axis.y;
+        let end = pw.interpolated_pose(box_id, 1.0).unwrap().w_axis.y;
+        assert!((start - before).abs() < 1e-4);
+        assert!((end - after).abs() < 1e-4);
+    }
+
     // --- apply_radial_impulse: direction and falloff ---
     #[test]
     fn radial_impulse_direction_away_from_center() {
@@ -3150,6 +4998,81 @@ mod tests {
         assert!(toi > 0.0, "TOI should be positive");
     }
 
+    #[cfg(feature = "async-physics")]
+    #[test]
+    fn raycast_batch_matches_serial_raycast() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let _box = pw.add_dynamic_box(
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::new(0.5, 0.5, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        pw.step(); // Update query pipeline
+
+        let requests = vec![
+            RayRequest {
+                origin: Vec3::ZERO,
+                direction: Vec3::X,
+                max_distance: 20.0,
+            },
+            RayRequest {
+                origin: Vec3::ZERO,
+                direction: Vec3::NEG_X,
+                max_distance: 20.0,
+            },
+            RayRequest {
+                origin: Vec3::new(0.0, 10.0, 0.0),
+                direction: Vec3::NEG_Y,
+                max_distance: 1.0,
+            },
+        ];
+        let results = pw.raycast_batch(&requests);
+        assert_eq!(results.len(), requests.len());
+
+        let expected = pw.raycast(Vec3::ZERO, Vec3::X, 20.0).unwrap();
+        let hit = results[0].expect("first ray should hit the box");
+        assert!((hit.position - expected.0).abs().max_element() < 1e-4);
+        assert!((hit.distance - expected.3).abs() < 1e-4);
+
+        assert!(results[1].is_none(), "ray facing away from the box should miss");
+        assert!(
+            results[2].is_none(),
+            "ray far above the box with a short max_distance should miss"
+        );
+    }
+
+    #[cfg(feature = "async-physics")]
+    #[test]
+    fn step_async_advances_the_simulation_and_returns_overlap_result() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let id = pw.add_dynamic_box(
+            Vec3::new(0.0, 10.0, 0.0),
+            Vec3::new(0.5, 0.5, 0.5),
+            1.0,
+            Layers::DEFAULT,
+        );
+        let before_y = pw.body_transform(id).unwrap().w_axis.y;
+
+        let job = pw.step_async(|| 2 + 2);
+
+        let after_y = pw.body_transform(id).unwrap().w_axis.y;
+        assert!(after_y < before_y, "box should have fallen during step_async");
+        assert_eq!(job.overlap_result, 4);
+    }
+
+    #[cfg(feature = "async-physics")]
+    #[test]
+    fn step_async_runs_overlap_fn_exactly_once() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+
+        let job = pw.step_async(|| calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst));
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(job.overlap_result, 0);
+    }
+
     // --- add_destructible_box: verify body created ---
     #[test]
     fn add_destructible_box_creates_body() {
@@ -3801,17 +5724,12 @@ mod tests {
     }
 
     // --- CharState::is_grounded / CharacterController::is_grounded ---
-    // Note: CharState only has one variant (Grounded), so is_grounded always returns true.
-    // These mutations are equivalent mutants. Verify the invariant:
     #[test]
-    fn mutation_r4_char_state_only_variant() {
-        // All CharState values are grounded (single variant enum)
-        for state in CharState::all() {
-            assert!(
-                state.is_grounded(),
-                "All CharState variants should be grounded"
-            );
-        }
+    fn mutation_r4_char_state_grounded_vs_swimming() {
+        assert!(CharState::Grounded.is_grounded());
+        assert!(!CharState::Swimming.is_grounded());
+        assert!(CharState::Swimming.is_swimming());
+        assert!(!CharState::Grounded.is_swimming());
     }
 
     // --- CharacterController::has_coyote_time boundary (< → <=) ---
@@ -3839,13 +5757,11 @@ mod tests {
         let mut cc = CharacterController::new(0.5, 2.0);
         // Simulate being in-air past coyote time
         cc.time_since_grounded = 1.0; // way past coyote limit
-                                      // CharState only has Grounded variant, so is_grounded() is always true...
-                                      // Actually we can't make is_grounded false since CharState only has one variant.
-                                      // The || → && mutation is effectively equivalent because is_grounded() is always true.
-                                      // Verify the tautology:
+                                      // `CharacterController::new` defaults to `CharState::Grounded`, so
+                                      // is_grounded() is still true here even past coyote time.
         assert!(
             cc.can_jump(),
-            "can_jump is always true when CharState only has Grounded"
+            "can_jump should be true while state is Grounded, regardless of coyote time"
         );
     }
 
@@ -4636,6 +6552,25 @@ mod tests {
             coyote_time_limit: 0.15,
             jump_buffer_limit: 0.15,
             pending_jump_velocity: 0.0,
+            mantle_reach: 0.6,
+            mantle_max_ledge_height: 1.0,
+            mantle_duration: 0.35,
+            mantling: None,
+            swim_speed: 3.0,
+            buoyancy_response: 4.0,
+            swim_surface_depth: 0.3,
+            swim_surface_y: None,
+            stance: Stance::Standing,
+            requested_stance: Stance::Standing,
+            standing_height: 1.6,
+            crouch_height: 1.0,
+            crawl_height: 0.6,
+            standing_max_step: 0.3,
+            crouch_max_step: 0.15,
+            crawl_max_step: 0.05,
+            standing_max_climb_angle_deg: 45.0,
+            crouch_max_climb_angle_deg: 30.0,
+            crawl_max_climb_angle_deg: 15.0,
         };
         assert!(ctrl.is_grounded(), "Controller should be grounded");
     }
@@ -4655,6 +6590,25 @@ mod tests {
             coyote_time_limit: 0.15,
             jump_buffer_limit: 0.15,
             pending_jump_velocity: 0.0,
+            mantle_reach: 0.6,
+            mantle_max_ledge_height: 1.0,
+            mantle_duration: 0.35,
+            mantling: None,
+            swim_speed: 3.0,
+            buoyancy_response: 4.0,
+            swim_surface_depth: 0.3,
+            swim_surface_y: None,
+            stance: Stance::Standing,
+            requested_stance: Stance::Standing,
+            standing_height: 1.6,
+            crouch_height: 1.0,
+            crawl_height: 0.6,
+            standing_max_step: 0.3,
+            crouch_max_step: 0.15,
+            crawl_max_step: 0.05,
+            standing_max_climb_angle_deg: 45.0,
+            crouch_max_climb_angle_deg: 30.0,
+            crawl_max_climb_angle_deg: 15.0,
         };
         assert!(ctrl.can_jump(), "Grounded controller should be able to jump");
     }
@@ -512,8 +512,9 @@ mod char_state_tests {
     #[test]
     fn test_char_state_all() {
         let all = CharState::all();
-        assert_eq!(all.len(), 1);
+        assert_eq!(all.len(), 2);
         assert!(all.contains(&CharState::Grounded));
+        assert!(all.contains(&CharState::Swimming));
     }
 }
 
@@ -0,0 +1,761 @@
+//! Rope / Cable Simulation System
+//!
+//! Verlet integration-based 1D particle chain — the linear counterpart to
+//! `cloth`'s 2D grid:
+//! - Particle chain with distance (stretch) and bending constraints
+//! - Attachment points that track a rigid body in [`PhysicsWorld`]
+//! - Per-segment tension queries
+//! - Breakable segments once tension exceeds a configurable threshold
+
+use crate::{BodyId, PhysicsWorld};
+use glam::Vec3;
+use std::collections::HashMap;
+
+/// Unique identifier for rope instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RopeId(pub u64);
+
+/// A particle in the rope simulation
+#[derive(Debug, Clone)]
+pub struct RopeParticle {
+    /// Current position
+    pub position: Vec3,
+    /// Previous position (for Verlet integration)
+    pub prev_position: Vec3,
+    /// Accumulated forces this frame
+    pub acceleration: Vec3,
+    /// Inverse mass (0 = pinned/infinite mass)
+    pub inv_mass: f32,
+    /// Whether this particle is pinned (fixed position)
+    pub pinned: bool,
+}
+
+impl RopeParticle {
+    /// Create a new particle
+    pub fn new(position: Vec3, mass: f32) -> Self {
+        Self {
+            position,
+            prev_position: position,
+            acceleration: Vec3::ZERO,
+            inv_mass: if mass > 0.0 { 1.0 / mass } else { 0.0 },
+            pinned: false,
+        }
+    }
+
+    /// Create a pinned particle
+    pub fn pinned(position: Vec3) -> Self {
+        Self {
+            position,
+            prev_position: position,
+            acceleration: Vec3::ZERO,
+            inv_mass: 0.0,
+            pinned: true,
+        }
+    }
+
+    /// Apply force to particle
+    pub fn apply_force(&mut self, force: Vec3) {
+        if !self.pinned {
+            self.acceleration += force * self.inv_mass;
+        }
+    }
+
+    /// Integrate using Verlet integration
+    pub fn integrate(&mut self, dt: f32, damping: f32) {
+        if self.pinned {
+            return;
+        }
+
+        let velocity = self.position - self.prev_position;
+        self.prev_position = self.position;
+        self.position += velocity * damping + self.acceleration * dt * dt;
+        self.acceleration = Vec3::ZERO;
+    }
+
+    /// Get velocity
+    pub fn velocity(&self) -> Vec3 {
+        self.position - self.prev_position
+    }
+}
+
+/// A stretch constraint between two adjacent particles. Tracks its own
+/// tension so a rope can be queried or severed segment-by-segment.
+#[derive(Debug, Clone, Copy)]
+pub struct RopeSegment {
+    /// First particle index
+    pub p1: usize,
+    /// Second particle index
+    pub p2: usize,
+    /// Rest length
+    pub rest_length: f32,
+    /// Stiffness (0-1, higher = stiffer)
+    pub stiffness: f32,
+    /// Set once this segment's tension exceeded the rope's break threshold;
+    /// a broken segment is no longer solved, letting the two halves hang free.
+    pub broken: bool,
+}
+
+impl RopeSegment {
+    /// Create a new segment
+    pub fn new(p1: usize, p2: usize, rest_length: f32) -> Self {
+        Self {
+            p1,
+            p2,
+            rest_length,
+            stiffness: 1.0,
+            broken: false,
+        }
+    }
+
+    /// Current distance between the two constrained particles
+    pub fn current_length(&self, particles: &[RopeParticle]) -> f32 {
+        (particles[self.p2].position - particles[self.p1].position).length()
+    }
+
+    /// Tension as a ratio of current length to rest length: `1.0` at rest,
+    /// greater than `1.0` while stretched, less than `1.0` while slack.
+    pub fn tension(&self, particles: &[RopeParticle]) -> f32 {
+        if self.rest_length < 0.0001 {
+            return 0.0;
+        }
+        self.current_length(particles) / self.rest_length
+    }
+
+    /// Solve the constraint (no-op once broken)
+    pub fn solve(&self, particles: &mut [RopeParticle]) {
+        if self.broken {
+            return;
+        }
+
+        let p1 = &particles[self.p1];
+        let p2 = &particles[self.p2];
+
+        let delta = p2.position - p1.position;
+        let current_length = delta.length();
+
+        if current_length < 0.0001 {
+            return;
+        }
+
+        let diff = (current_length - self.rest_length) / current_length;
+        let correction = delta * diff * 0.5 * self.stiffness;
+
+        let w1 = p1.inv_mass;
+        let w2 = p2.inv_mass;
+        let total_weight = w1 + w2;
+
+        if total_weight > 0.0 {
+            if !particles[self.p1].pinned {
+                particles[self.p1].position += correction * (w1 / total_weight);
+            }
+            if !particles[self.p2].pinned {
+                particles[self.p2].position -= correction * (w2 / total_weight);
+            }
+        }
+    }
+}
+
+/// A bending constraint between particles two apart, keeping the rope from
+/// folding back sharply on itself. Unlike [`RopeSegment`]s, bends never
+/// break — they're a soft shape-preservation hint, not the rope's structure.
+#[derive(Debug, Clone, Copy)]
+pub struct BendConstraint {
+    pub p1: usize,
+    pub p2: usize,
+    pub rest_length: f32,
+    pub stiffness: f32,
+}
+
+impl BendConstraint {
+    pub fn new(p1: usize, p2: usize, rest_length: f32) -> Self {
+        Self {
+            p1,
+            p2,
+            rest_length,
+            stiffness: 1.0,
+        }
+    }
+
+    /// Solve the constraint
+    pub fn solve(&self, particles: &mut [RopeParticle]) {
+        let p1 = &particles[self.p1];
+        let p2 = &particles[self.p2];
+
+        let delta = p2.position - p1.position;
+        let current_length = delta.length();
+
+        if current_length < 0.0001 {
+            return;
+        }
+
+        let diff = (current_length - self.rest_length) / current_length;
+        let correction = delta * diff * 0.5 * self.stiffness;
+
+        let w1 = p1.inv_mass;
+        let w2 = p2.inv_mass;
+        let total_weight = w1 + w2;
+
+        if total_weight > 0.0 {
+            if !particles[self.p1].pinned {
+                particles[self.p1].position += correction * (w1 / total_weight);
+            }
+            if !particles[self.p2].pinned {
+                particles[self.p2].position -= correction * (w2 / total_weight);
+            }
+        }
+    }
+}
+
+/// Anchors a rope endpoint to a rigid body tracked by [`PhysicsWorld`].
+#[derive(Debug, Clone, Copy)]
+pub struct RopeAttachment {
+    pub body: BodyId,
+    /// Offset from the body's origin, in the body's local frame.
+    pub local_offset: Vec3,
+}
+
+/// Configuration for rope simulation
+#[derive(Debug, Clone)]
+pub struct RopeConfig {
+    /// Number of segments (particle count is `segment_count + 1`)
+    pub segment_count: usize,
+    /// Rest length of each segment
+    pub segment_length: f32,
+    /// Mass per particle
+    pub particle_mass: f32,
+    /// Stretch constraint stiffness (0-1)
+    pub stiffness: f32,
+    /// Bending constraint stiffness (0-1)
+    pub bend_stiffness: f32,
+    /// Velocity damping (0-1, lower = more damping)
+    pub damping: f32,
+    /// Constraint solver iterations
+    pub solver_iterations: usize,
+    /// Gravity
+    pub gravity: Vec3,
+    /// Tension above which a segment snaps, or `None` for an unbreakable rope
+    pub break_tension: Option<f32>,
+}
+
+impl Default for RopeConfig {
+    fn default() -> Self {
+        Self {
+            segment_count: 10,
+            segment_length: 0.2,
+            particle_mass: 0.2,
+            stiffness: 1.0,
+            bend_stiffness: 0.2,
+            damping: 0.98,
+            solver_iterations: 4,
+            gravity: Vec3::new(0.0, -9.81, 0.0),
+            break_tension: None,
+        }
+    }
+}
+
+/// A rope instance: a straight chain of particles laid out along +X from
+/// `origin` at creation time, free to sag under gravity and constraint
+/// solving from there.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    pub id: RopeId,
+    pub config: RopeConfig,
+    pub particles: Vec<RopeParticle>,
+    pub segments: Vec<RopeSegment>,
+    pub bends: Vec<BendConstraint>,
+    pub start_attachment: Option<RopeAttachment>,
+    pub end_attachment: Option<RopeAttachment>,
+}
+
+impl Rope {
+    /// Create a new rope from config, laid out along +X starting at `origin`
+    pub fn new(id: RopeId, config: RopeConfig, origin: Vec3) -> Self {
+        let particle_count = config.segment_count + 1;
+        let mut particles = Vec::with_capacity(particle_count);
+        for i in 0..particle_count {
+            let pos = origin + Vec3::X * (i as f32 * config.segment_length);
+            particles.push(RopeParticle::new(pos, config.particle_mass));
+        }
+
+        let mut segments = Vec::with_capacity(config.segment_count);
+        for i in 0..config.segment_count {
+            let mut seg = RopeSegment::new(i, i + 1, config.segment_length);
+            seg.stiffness = config.stiffness;
+            segments.push(seg);
+        }
+
+        let mut bends = Vec::new();
+        if config.segment_count >= 2 {
+            for i in 0..(config.segment_count - 1) {
+                let mut bend = BendConstraint::new(i, i + 2, config.segment_length * 2.0);
+                bend.stiffness = config.bend_stiffness;
+                bends.push(bend);
+            }
+        }
+
+        Self {
+            id,
+            config,
+            particles,
+            segments,
+            bends,
+            start_attachment: None,
+            end_attachment: None,
+        }
+    }
+
+    /// Pin the first particle in place
+    pub fn pin_start(&mut self) {
+        self.particles[0].pinned = true;
+        self.particles[0].inv_mass = 0.0;
+    }
+
+    /// Pin the last particle in place
+    pub fn pin_end(&mut self) {
+        let last = self.particles.len() - 1;
+        self.particles[last].pinned = true;
+        self.particles[last].inv_mass = 0.0;
+    }
+
+    /// Anchor the start of the rope to a rigid body, pinning that particle
+    pub fn attach_start(&mut self, attachment: RopeAttachment) {
+        self.start_attachment = Some(attachment);
+        self.pin_start();
+    }
+
+    /// Anchor the end of the rope to a rigid body, pinning that particle
+    pub fn attach_end(&mut self, attachment: RopeAttachment) {
+        self.end_attachment = Some(attachment);
+        self.pin_end();
+    }
+
+    /// Release the start attachment without unpinning the particle (callers
+    /// that want it free to swing should also call a manual unpin).
+    pub fn detach_start(&mut self) {
+        self.start_attachment = None;
+    }
+
+    /// Release the end attachment without unpinning the particle
+    pub fn detach_end(&mut self) {
+        self.end_attachment = None;
+    }
+
+    /// Pulls attached particles to their rigid body's current world position
+    /// plus `local_offset`. No-op for whichever end has no attachment.
+    pub fn sync_attachments(&mut self, physics: &PhysicsWorld) {
+        if let Some(attachment) = self.start_attachment {
+            if let Some(transform) = physics.body_transform(attachment.body) {
+                let world_pos = transform.w_axis.truncate() + attachment.local_offset;
+                self.particles[0].position = world_pos;
+                self.particles[0].prev_position = world_pos;
+            }
+        }
+        if let Some(attachment) = self.end_attachment {
+            if let Some(transform) = physics.body_transform(attachment.body) {
+                let world_pos = transform.w_axis.truncate() + attachment.local_offset;
+                let last = self.particles.len() - 1;
+                self.particles[last].position = world_pos;
+                self.particles[last].prev_position = world_pos;
+            }
+        }
+    }
+
+    /// Tension of a specific segment, or `None` if `index` is out of bounds
+    pub fn tension(&self, index: usize) -> Option<f32> {
+        self.segments.get(index).map(|s| s.tension(&self.particles))
+    }
+
+    /// Highest tension across all unbroken segments (`0.0` if there are none)
+    pub fn max_tension(&self) -> f32 {
+        self.segments
+            .iter()
+            .filter(|s| !s.broken)
+            .map(|s| s.tension(&self.particles))
+            .fold(0.0, f32::max)
+    }
+
+    /// Whether any segment has snapped
+    pub fn is_broken(&self) -> bool {
+        self.segments.iter().any(|s| s.broken)
+    }
+
+    /// Number of snapped segments
+    pub fn broken_segment_count(&self) -> usize {
+        self.segments.iter().filter(|s| s.broken).count()
+    }
+
+    /// Update rope simulation
+    pub fn update(&mut self, dt: f32) {
+        for particle in &mut self.particles {
+            let gravity_force = self.config.gravity * (1.0 / particle.inv_mass.max(0.001));
+            particle.apply_force(gravity_force);
+        }
+
+        for particle in &mut self.particles {
+            particle.integrate(dt, self.config.damping);
+        }
+
+        for _ in 0..self.config.solver_iterations {
+            for segment in &self.segments {
+                segment.solve(&mut self.particles);
+            }
+            for bend in &self.bends {
+                bend.solve(&mut self.particles);
+            }
+        }
+
+        if let Some(threshold) = self.config.break_tension {
+            for segment in &mut self.segments {
+                if !segment.broken && segment.tension(&self.particles) > threshold {
+                    segment.broken = true;
+                }
+            }
+        }
+    }
+
+    /// Get all particle positions
+    pub fn get_positions(&self) -> Vec<Vec3> {
+        self.particles.iter().map(|p| p.position).collect()
+    }
+
+    /// Get particle count
+    pub fn particle_count(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Get segment count
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+}
+
+/// Manager for multiple rope simulations
+#[derive(Debug, Default)]
+pub struct RopeManager {
+    ropes: HashMap<RopeId, Rope>,
+    next_id: u64,
+}
+
+impl RopeManager {
+    /// Create a new rope manager
+    pub fn new() -> Self {
+        Self {
+            ropes: HashMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Create a new rope
+    pub fn create(&mut self, config: RopeConfig, origin: Vec3) -> RopeId {
+        let id = RopeId(self.next_id);
+        self.next_id += 1;
+        self.ropes.insert(id, Rope::new(id, config, origin));
+        id
+    }
+
+    /// Remove a rope
+    pub fn remove(&mut self, id: RopeId) -> bool {
+        self.ropes.remove(&id).is_some()
+    }
+
+    /// Get a rope
+    pub fn get(&self, id: RopeId) -> Option<&Rope> {
+        self.ropes.get(&id)
+    }
+
+    /// Get a mutable rope
+    pub fn get_mut(&mut self, id: RopeId) -> Option<&mut Rope> {
+        self.ropes.get_mut(&id)
+    }
+
+    /// Pull every rope's rigid-body attachments to their current world
+    /// position before `update` runs.
+    pub fn sync_attachments(&mut self, physics: &PhysicsWorld) {
+        for rope in self.ropes.values_mut() {
+            rope.sync_attachments(physics);
+        }
+    }
+
+    /// Update all ropes
+    pub fn update(&mut self, dt: f32) {
+        for rope in self.ropes.values_mut() {
+            rope.update(dt);
+        }
+    }
+
+    /// Get rope count
+    pub fn count(&self) -> usize {
+        self.ropes.len()
+    }
+
+    /// Iterate over all ropes
+    pub fn iter(&self) -> impl Iterator<Item = &Rope> {
+        self.ropes.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_particle_creation() {
+        let particle = RopeParticle::new(Vec3::new(1.0, 2.0, 3.0), 0.5);
+        assert_eq!(particle.position, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(particle.inv_mass, 2.0);
+        assert!(!particle.pinned);
+    }
+
+    #[test]
+    fn test_pinned_particle_ignores_force() {
+        let mut particle = RopeParticle::pinned(Vec3::ZERO);
+        particle.apply_force(Vec3::new(10.0, 0.0, 0.0));
+        assert_eq!(particle.acceleration, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_particle_integrate_falls_under_gravity() {
+        let mut particle = RopeParticle::new(Vec3::ZERO, 1.0);
+        particle.apply_force(Vec3::new(0.0, -10.0, 0.0));
+        particle.integrate(0.016, 1.0);
+        assert!(particle.position.y < 0.0);
+    }
+
+    #[test]
+    fn test_rope_creation_particle_and_segment_counts() {
+        let config = RopeConfig {
+            segment_count: 5,
+            ..Default::default()
+        };
+        let rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        assert_eq!(rope.particle_count(), 6);
+        assert_eq!(rope.segment_count(), 5);
+        assert_eq!(rope.bends.len(), 4);
+    }
+
+    #[test]
+    fn test_rope_lays_out_along_x() {
+        let config = RopeConfig {
+            segment_count: 3,
+            segment_length: 1.0,
+            ..Default::default()
+        };
+        let rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        assert_eq!(rope.particles[0].position, Vec3::ZERO);
+        assert_eq!(rope.particles[3].position, Vec3::new(3.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_pin_start_and_end() {
+        let mut rope = Rope::new(RopeId(1), RopeConfig::default(), Vec3::ZERO);
+        rope.pin_start();
+        rope.pin_end();
+        assert!(rope.particles[0].pinned);
+        assert!(rope.particles[rope.particles.len() - 1].pinned);
+    }
+
+    #[test]
+    fn test_segment_tension_at_rest_is_one() {
+        let config = RopeConfig {
+            segment_count: 2,
+            segment_length: 1.0,
+            ..Default::default()
+        };
+        let rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        assert!((rope.tension(0).unwrap() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_segment_tension_out_of_bounds_is_none() {
+        let rope = Rope::new(RopeId(1), RopeConfig::default(), Vec3::ZERO);
+        assert!(rope.tension(9999).is_none());
+    }
+
+    #[test]
+    fn test_segment_tension_rises_when_stretched() {
+        let mut particles = vec![
+            RopeParticle::pinned(Vec3::ZERO),
+            RopeParticle::new(Vec3::new(2.0, 0.0, 0.0), 1.0),
+        ];
+        let segment = RopeSegment::new(0, 1, 1.0);
+        assert!((segment.tension(&particles) - 2.0).abs() < 1e-5);
+        segment.solve(&mut particles);
+        assert!(segment.tension(&particles) < 2.0);
+    }
+
+    #[test]
+    fn test_broken_segment_is_not_solved() {
+        let mut particles = vec![
+            RopeParticle::pinned(Vec3::ZERO),
+            RopeParticle::new(Vec3::new(2.0, 0.0, 0.0), 1.0),
+        ];
+        let mut segment = RopeSegment::new(0, 1, 1.0);
+        segment.broken = true;
+        segment.solve(&mut particles);
+        assert_eq!(particles[1].position, Vec3::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_rope_update_breaks_overstretched_segment() {
+        let config = RopeConfig {
+            segment_count: 1,
+            segment_length: 1.0,
+            stiffness: 0.0, // don't self-correct, so the stretch persists
+            break_tension: Some(1.5),
+            gravity: Vec3::ZERO,
+            ..Default::default()
+        };
+        let mut rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        rope.pin_start();
+        rope.particles[1].position = Vec3::new(3.0, 0.0, 0.0);
+        rope.particles[1].prev_position = Vec3::new(3.0, 0.0, 0.0);
+
+        rope.update(0.016);
+
+        assert!(rope.is_broken());
+        assert_eq!(rope.broken_segment_count(), 1);
+    }
+
+    #[test]
+    fn test_rope_update_leaves_unstretched_rope_intact() {
+        let config = RopeConfig {
+            segment_count: 3,
+            break_tension: Some(1.5),
+            ..Default::default()
+        };
+        let mut rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        rope.pin_start();
+
+        for _ in 0..10 {
+            rope.update(0.016);
+        }
+
+        assert!(!rope.is_broken());
+    }
+
+    #[test]
+    fn test_max_tension_ignores_broken_segments() {
+        let mut rope = Rope::new(
+            RopeId(1),
+            RopeConfig {
+                segment_count: 2,
+                segment_length: 1.0,
+                ..Default::default()
+            },
+            Vec3::ZERO,
+        );
+        rope.segments[0].broken = true;
+        rope.particles[1].position = Vec3::new(10.0, 0.0, 0.0); // would dominate max if counted
+        assert!(rope.max_tension() < 10.0);
+    }
+
+    #[test]
+    fn test_rope_manager_create_get_remove() {
+        let mut manager = RopeManager::new();
+        let id = manager.create(RopeConfig::default(), Vec3::ZERO);
+        assert_eq!(manager.count(), 1);
+        assert!(manager.get(id).is_some());
+        assert!(manager.remove(id));
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[test]
+    fn test_rope_manager_update_does_not_panic() {
+        let mut manager = RopeManager::new();
+        manager.create(RopeConfig::default(), Vec3::ZERO);
+        manager.update(0.016);
+    }
+
+    #[test]
+    fn test_rope_manager_get_nonexistent() {
+        let manager = RopeManager::new();
+        assert!(manager.get(RopeId(999)).is_none());
+    }
+
+    #[test]
+    fn test_attach_start_pins_and_records_attachment() {
+        let mut physics = PhysicsWorld::new(Vec3::new(0.0, -9.81, 0.0));
+        let body = physics.add_dynamic_box(
+            Vec3::new(5.0, 5.0, 5.0),
+            Vec3::splat(0.5),
+            1.0,
+            crate::Layers::DEFAULT,
+        );
+        let mut rope = Rope::new(RopeId(1), RopeConfig::default(), Vec3::ZERO);
+        rope.attach_start(RopeAttachment {
+            body,
+            local_offset: Vec3::ZERO,
+        });
+
+        assert!(rope.particles[0].pinned);
+        rope.sync_attachments(&physics);
+        assert!((rope.particles[0].position - Vec3::new(5.0, 5.0, 5.0)).length() < 0.01);
+    }
+
+    #[test]
+    fn test_detach_start_stops_further_syncing() {
+        let mut physics = PhysicsWorld::new(Vec3::new(0.0, -9.81, 0.0));
+        let body = physics.add_dynamic_box(
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::splat(0.5),
+            1.0,
+            crate::Layers::DEFAULT,
+        );
+        let mut rope = Rope::new(RopeId(1), RopeConfig::default(), Vec3::ZERO);
+        rope.attach_start(RopeAttachment {
+            body,
+            local_offset: Vec3::ZERO,
+        });
+        rope.sync_attachments(&physics);
+        rope.detach_start();
+
+        let pinned_pos = rope.particles[0].position;
+        // Move the body; without an attachment the particle should stay put.
+        physics.set_velocity(body, Vec3::new(100.0, 0.0, 0.0));
+        for _ in 0..5 {
+            physics.step();
+        }
+        rope.sync_attachments(&physics);
+        assert_eq!(rope.particles[0].position, pinned_pos);
+    }
+
+    #[test]
+    fn test_get_positions_matches_particle_count() {
+        let config = RopeConfig {
+            segment_count: 4,
+            ..Default::default()
+        };
+        let rope = Rope::new(RopeId(1), config, Vec3::ZERO);
+        assert_eq!(rope.get_positions().len(), 5);
+    }
+
+    #[test]
+    fn test_config_default() {
+        let config = RopeConfig::default();
+        assert_eq!(config.segment_count, 10);
+        assert_eq!(config.segment_length, 0.2);
+        assert!(config.break_tension.is_none());
+    }
+
+    #[test]
+    fn test_unbreakable_rope_never_breaks() {
+        let mut rope = Rope::new(
+            RopeId(1),
+            RopeConfig {
+                segment_count: 1,
+                segment_length: 1.0,
+                stiffness: 0.0,
+                break_tension: None,
+                gravity: Vec3::ZERO,
+                ..Default::default()
+            },
+            Vec3::ZERO,
+        );
+        rope.pin_start();
+        rope.particles[1].position = Vec3::new(100.0, 0.0, 0.0);
+
+        rope.update(0.016);
+
+        assert!(!rope.is_broken());
+    }
+}
@@ -0,0 +1,111 @@
+//! Physics scene snapshotting for save/load and rollback (e.g. rewinding and
+//! resimulating in netcode, or "undo" in an editor).
+//!
+//! A [`PhysicsSnapshot`] captures the pose and velocities of every tracked
+//! rigid body plus every character controller's state, at the moment
+//! [`crate::PhysicsWorld::snapshot`] is called. Colliders, joints, and the
+//! body set itself are not captured, so restoring a snapshot cannot
+//! resurrect a body removed since it was taken; see
+//! [`crate::PhysicsWorld::restore`].
+
+use crate::{BodyId, CharacterController};
+use glam::{Quat, Vec3};
+
+/// One rigid body's pose and velocities at the moment of
+/// [`crate::PhysicsWorld::snapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodySnapshot {
+    pub id: BodyId,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub linear_velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+
+/// A point-in-time capture of a [`crate::PhysicsWorld`]'s dynamic state,
+/// restorable via [`crate::PhysicsWorld::restore`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicsSnapshot {
+    pub bodies: Vec<BodySnapshot>,
+    pub characters: Vec<(BodyId, CharacterController)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Layers, PhysicsWorld};
+    use glam::Vec3;
+
+    #[test]
+    fn snapshot_captures_every_tracked_body() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let a = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::splat(0.5), 1.0, Layers::DEFAULT);
+        let b = pw.add_dynamic_box(Vec3::new(2.0, 5.0, 0.0), Vec3::splat(0.5), 1.0, Layers::DEFAULT);
+
+        let snap = pw.snapshot();
+
+        let ids: Vec<BodyId> = snap.bodies.iter().map(|b| b.id).collect();
+        assert!(ids.contains(&a));
+        assert!(ids.contains(&b));
+    }
+
+    #[test]
+    fn restore_rolls_back_position_and_velocity() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let id = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::splat(0.5), 1.0, Layers::DEFAULT);
+
+        let snap = pw.snapshot();
+        let before = pw.body_transform(id).unwrap().w_axis;
+
+        for _ in 0..30 {
+            pw.step();
+        }
+        let after_fall = pw.body_transform(id).unwrap().w_axis;
+        assert!(
+            after_fall.y < before.y,
+            "body should have fallen before restore"
+        );
+
+        pw.restore(&snap);
+        let restored = pw.body_transform(id).unwrap().w_axis;
+        assert!(
+            (restored.y - before.y).abs() < 1e-4,
+            "expected y ~= {}, got {}",
+            before.y,
+            restored.y
+        );
+        assert_eq!(pw.get_velocity(id), Some(Vec3::ZERO));
+    }
+
+    #[test]
+    fn restore_skips_bodies_removed_since_snapshot() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let id = pw.add_dynamic_box(Vec3::new(0.0, 5.0, 0.0), Vec3::splat(0.5), 1.0, Layers::DEFAULT);
+
+        let snap = pw.snapshot();
+        pw.break_destructible(id);
+
+        // Must not panic when a snapshotted body no longer exists.
+        pw.restore(&snap);
+        assert!(pw.body_transform(id).is_none());
+    }
+
+    #[test]
+    fn restore_preserves_character_controller_state() {
+        let mut pw = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+        let _ground = pw.create_ground_plane(Vec3::new(10.0, 0.5, 10.0), 0.9);
+        let id = pw.add_character(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.4, 0.9, 0.4));
+
+        let snap = pw.snapshot();
+
+        if let Some(ctrl) = pw.char_map.get_mut(&id) {
+            ctrl.vertical_velocity = -42.0;
+        }
+        assert_eq!(pw.char_map.get(&id).unwrap().vertical_velocity, -42.0);
+
+        pw.restore(&snap);
+        assert_eq!(pw.char_map.get(&id).unwrap().vertical_velocity, 0.0);
+    }
+}
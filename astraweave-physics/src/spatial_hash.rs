@@ -60,6 +60,7 @@ grid.clear();
 - Large objects (5 radius) → Cell size: 10-20 units
 */
 
+use crate::DebugLine;
 use glam::Vec3;
 
 /// Axis-Aligned Bounding Box for collision detection
@@ -305,6 +306,56 @@ impl<T: Copy + Eq + Ord> SpatialHash<T> {
             cell_size: self.cell_size,
         }
     }
+
+    /// Wireframe box outline for every occupied cell, for the
+    /// `spatial_hash_cells` category of a physics debug-render overlay.
+    /// `PhysicsWorld` doesn't own a `SpatialHash` itself, so callers that
+    /// build their own grid call this directly and merge the result into
+    /// their line buffer when that category is enabled.
+    pub fn debug_cell_lines(&self, color: [f32; 3]) -> Vec<DebugLine> {
+        let mut lines = Vec::with_capacity(self.grid.len() * 12);
+        for &(cx, cy, cz) in self.grid.keys() {
+            let min = Vec3::new(
+                cx as f32 * self.cell_size,
+                cy as f32 * self.cell_size,
+                cz as f32 * self.cell_size,
+            );
+            let max = min + Vec3::splat(self.cell_size);
+            push_box_edges(&mut lines, min, max, color);
+        }
+        lines
+    }
+}
+
+/// Appends the 12 edges of an axis-aligned box spanning `min`..`max`.
+fn push_box_edges(lines: &mut Vec<DebugLine>, min: Vec3, max: Vec3, color: [f32; 3]) {
+    let corners = [
+        Vec3::new(min.x, min.y, min.z),
+        Vec3::new(max.x, min.y, min.z),
+        Vec3::new(max.x, min.y, max.z),
+        Vec3::new(min.x, min.y, max.z),
+        Vec3::new(min.x, max.y, min.z),
+        Vec3::new(max.x, max.y, min.z),
+        Vec3::new(max.x, max.y, max.z),
+        Vec3::new(min.x, max.y, max.z),
+    ];
+    let edges: [(usize, usize); 12] = [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (4, 5),
+        (5, 6),
+        (6, 7),
+        (7, 4),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+    ];
+    for (a, b) in edges {
+        lines.push(DebugLine::from_vec3(corners[a], corners[b], color));
+    }
 }
 
 /// Spatial hash statistics for debugging/profiling
@@ -1035,4 +1086,21 @@ mod tests {
             results.len()
         );
     }
+
+    #[test]
+    fn test_debug_cell_lines_one_edge_per_box_side() {
+        let mut grid = SpatialHash::<u32>::new(10.0);
+        grid.insert(1, AABB::from_sphere(Vec3::new(5.0, 5.0, 5.0), 1.0));
+
+        let lines = grid.debug_cell_lines([1.0, 0.0, 0.0]);
+
+        assert_eq!(lines.len(), 12 * grid.cell_count());
+    }
+
+    #[test]
+    fn test_debug_cell_lines_empty_grid_has_no_lines() {
+        let grid = SpatialHash::<u32>::new(10.0);
+
+        assert!(grid.debug_cell_lines([0.0, 1.0, 0.0]).is_empty());
+    }
 }
@@ -2101,7 +2101,7 @@ mod misc_types_mutations {
     fn char_state_names() {
         assert_eq!(CharState::Grounded.name(), "Grounded");
         assert!(CharState::Grounded.is_grounded());
-        assert_eq!(CharState::all().len(), 1);
+        assert_eq!(CharState::all().len(), 2);
     }
 
     #[test]
@@ -0,0 +1,136 @@
+//! Bridges [`crate::template::PromptTemplate`]s into
+//! [`astraweave_asset::AssetDatabase`], so designers can hot-reload prompt
+//! text through the same pipeline as meshes/textures instead of restarting
+//! the process to pick up an edited `.hbs` file.
+//!
+//! [`TemplateAssetRegistry::register`] loads a template file, adds it to a
+//! [`crate::library::PromptLibrary`], and records it under
+//! [`astraweave_asset::cell_loader::AssetKind::PromptTemplate`] in the given
+//! [`astraweave_asset::AssetDatabase`]. Once
+//! [`astraweave_asset::AssetDatabase::hot_reload_rx`] fires (e.g. because an
+//! [`astraweave_asset::AssetWatcher`] observed a filesystem change),
+//! [`TemplateAssetRegistry::reload_changed`] re-reads every tracked file
+//! whose content hash no longer matches what was last registered and
+//! refreshes the library in place.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use astraweave_asset::cell_loader::AssetKind;
+use astraweave_asset::AssetDatabase;
+
+use crate::library::PromptLibrary;
+use crate::loader::PromptLoader;
+
+/// Tracks which on-disk file backs each registered template id, so
+/// [`Self::reload_changed`] knows what to re-read.
+pub struct TemplateAssetRegistry {
+    loader: PromptLoader,
+    /// template id -> (source path, content hash at last (re)load)
+    tracked: HashMap<String, (PathBuf, u64)>,
+}
+
+impl Default for TemplateAssetRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemplateAssetRegistry {
+    pub fn new() -> Self {
+        Self {
+            loader: PromptLoader::new(),
+            tracked: HashMap::new(),
+        }
+    }
+
+    /// Loads `path` as a template, adds it to `library`, and registers it
+    /// with `db` under [`AssetKind::PromptTemplate`]. Returns the template's
+    /// id (its registered name in `library`).
+    pub fn register(
+        &mut self,
+        db: &mut AssetDatabase,
+        library: &mut PromptLibrary,
+        path: &Path,
+    ) -> Result<String> {
+        let template = self.loader.load_file(path)?;
+        let id = template.id.clone();
+        library.add_template(&id, template);
+
+        db.register_asset(path, AssetKind::PromptTemplate, vec![])?;
+        let hash = content_hash(path)?;
+        self.tracked.insert(id.clone(), (path.to_path_buf(), hash));
+        Ok(id)
+    }
+
+    /// Re-reads every tracked template file whose content hash has changed
+    /// since it was last (re)loaded, refreshing `library` in place. Returns
+    /// the ids of templates that were actually reloaded.
+    pub fn reload_changed(&mut self, library: &mut PromptLibrary) -> Result<Vec<String>> {
+        let mut reloaded = Vec::new();
+        for (id, (path, last_hash)) in self.tracked.iter_mut() {
+            let hash = content_hash(path)?;
+            if hash == *last_hash {
+                continue;
+            }
+            let template = self.loader.load_file(path)?;
+            library.add_template(id, template);
+            *last_hash = hash;
+            reloaded.push(id.clone());
+        }
+        Ok(reloaded)
+    }
+}
+
+fn content_hash(path: &Path) -> Result<u64> {
+    use std::hash::{Hash, Hasher};
+    let bytes = std::fs::read(path)?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn register_adds_template_to_library_and_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("greet.hbs");
+        std::fs::write(&path, "Hello {{objective}}").unwrap();
+
+        let mut db = AssetDatabase::new();
+        let mut library = PromptLibrary::new();
+        let mut registry = TemplateAssetRegistry::new();
+
+        let id = registry.register(&mut db, &mut library, &path).unwrap();
+        assert!(library.has_template(&id));
+    }
+
+    #[test]
+    fn reload_changed_picks_up_edited_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("greet.hbs");
+        std::fs::write(&path, "Hello {{objective}}").unwrap();
+
+        let mut db = AssetDatabase::new();
+        let mut library = PromptLibrary::new();
+        let mut registry = TemplateAssetRegistry::new();
+        let id = registry.register(&mut db, &mut library, &path).unwrap();
+
+        // No change yet.
+        assert!(registry.reload_changed(&mut library).unwrap().is_empty());
+
+        let mut f = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        writeln!(f, " and {{{{me.pos.x}}}}").unwrap();
+
+        let reloaded = registry.reload_changed(&mut library).unwrap();
+        assert_eq!(reloaded, vec![id]);
+    }
+}
@@ -45,6 +45,7 @@ fn main() -> anyhow::Result<()> {
 ```
 */
 
+pub mod asset_integration;
 pub mod context;
 pub mod engine;
 pub mod helpers;
@@ -52,6 +53,7 @@ pub mod library;
 pub mod loader;
 pub mod optimization;
 pub mod sanitize;
+pub mod snapshot_vars;
 pub mod template;
 pub mod terrain_prompts; // Phase 10: AI-orchestrated terrain generation
 
@@ -425,6 +427,13 @@ pub struct TemplateMetadata {
     #[serde(default)]
     pub required_variables: Vec<String>,
 
+    /// Agent archetype this template is tuned for (e.g. "scout", "medic"),
+    /// consulted by [`crate::library::PromptLibrary::get_for_archetype`] to
+    /// pick a per-archetype variant over the default template. `None` means
+    /// the template applies to any archetype.
+    #[serde(default)]
+    pub archetype: Option<String>,
+
     /// Optional variables with defaults
     #[serde(default)]
     pub optional_variables: HashMap<String, serde_json::Value>,
@@ -462,6 +471,11 @@ impl TemplateMetadata {
         !self.tags.is_empty()
     }
 
+    /// Returns true if this template is scoped to a specific archetype.
+    pub fn has_archetype(&self) -> bool {
+        self.archetype.is_some()
+    }
+
     /// Returns true if this template has required variables.
     pub fn has_required_variables(&self) -> bool {
         !self.required_variables.is_empty()
@@ -60,6 +60,27 @@ impl PromptLibrary {
         self.templates.remove(name)
     }
 
+    /// Selects the template whose [`TemplateMetadata::archetype`] matches
+    /// `archetype`, falling back to `fallback_name` (an archetype-agnostic
+    /// template) if no per-archetype variant is registered. Errors only if
+    /// neither exists.
+    pub fn get_for_archetype(
+        &self,
+        archetype: &str,
+        fallback_name: &str,
+    ) -> anyhow::Result<crate::template::PromptTemplate> {
+        let by_archetype = self.templates.values().find(|t| {
+            t.metadata
+                .as_ref()
+                .and_then(|m| m.archetype.as_deref())
+                == Some(archetype)
+        });
+        match by_archetype {
+            Some(t) => Ok(t.clone()),
+            None => self.get_template(fallback_name),
+        }
+    }
+
     /// List all templates
     pub fn list_templates(&self) -> Vec<String> {
         self.templates.keys().cloned().collect()
@@ -0,0 +1,94 @@
+//! Validates a template's variables against [`astraweave_core::WorldSnapshot`]
+//! fields.
+//!
+//! [`crate::template::PromptTemplate::new`] already extracts every
+//! `{{variable}}` a template references into
+//! [`crate::template::PromptTemplate::variables`]; this module checks that
+//! each one's root path segment (e.g. `me` in `{{me.pos.x}}`) actually names
+//! a top-level [`astraweave_core::WorldSnapshot`] field, catching typos and
+//! stale references before a template ever reaches
+//! [`crate::template::PromptTemplate::render`].
+
+/// Top-level field names on [`astraweave_core::WorldSnapshot`] that
+/// templates are allowed to reference.
+pub const WORLD_SNAPSHOT_FIELDS: &[&str] = &[
+    "t", "player", "me", "enemies", "pois", "obstacles", "objective",
+];
+
+/// A template variable whose root path segment doesn't name a
+/// [`astraweave_core::WorldSnapshot`] field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariable {
+    pub variable: String,
+}
+
+impl std::fmt::Display for UnknownVariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown WorldSnapshot field referenced by template variable `{}`",
+            self.variable
+        )
+    }
+}
+
+/// Checks every entry in `variables` (as produced by
+/// [`crate::template::PromptTemplate::variables`]) against
+/// [`WORLD_SNAPSHOT_FIELDS`], returning the offending variables in
+/// first-seen order. Dotted paths (`me.pos.x`) and helper-block variables
+/// (`#each enemies`, exposed here as the bare name) are checked by their
+/// root segment only -- this module doesn't know each field's internal
+/// shape.
+pub fn validate_snapshot_variables(variables: &[String]) -> Result<(), Vec<UnknownVariable>> {
+    let unknown: Vec<UnknownVariable> = variables
+        .iter()
+        .filter(|v| {
+            let root = v.split('.').next().unwrap_or(v.as_str());
+            !WORLD_SNAPSHOT_FIELDS.contains(&root)
+        })
+        .map(|v| UnknownVariable {
+            variable: v.clone(),
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(unknown)
+    }
+}
+
+/// Convenience wrapper around [`validate_snapshot_variables`] for a
+/// [`crate::template::PromptTemplate`] directly.
+pub fn validate_template(
+    template: &crate::template::PromptTemplate,
+) -> Result<(), Vec<UnknownVariable>> {
+    validate_snapshot_variables(&template.variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::PromptTemplate;
+
+    #[test]
+    fn accepts_known_root_fields() {
+        let vars = vec!["me.pos.x".to_string(), "objective".to_string()];
+        assert!(validate_snapshot_variables(&vars).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_root_field() {
+        let vars = vec!["not_a_field".to_string()];
+        let err = validate_snapshot_variables(&vars).unwrap_err();
+        assert_eq!(err[0].variable, "not_a_field");
+    }
+
+    #[test]
+    fn validate_template_extracts_variables_from_source() {
+        let template = PromptTemplate::new("t", "Move toward {{objective}} avoiding {{bogus}}");
+        let err = validate_template(&template).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err[0].variable, "bogus");
+    }
+}
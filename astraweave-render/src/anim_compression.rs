@@ -0,0 +1,501 @@
+//! Animation compression: error-bounded keyframe decimation plus
+//! quantized rotation storage.
+//!
+//! [`AnimationClip`] stores every imported keyframe as raw `f32` data,
+//! which is wasteful for mocap-dense clips where most of those keyframes
+//! lie almost exactly on the line (or slerp arc) between their neighbors.
+//! [`AnimationCompressor`] removes such redundant keyframes — dropping
+//! one only if doing so keeps every skipped sample within a configured
+//! error tolerance of the original curve — and quantizes rotation
+//! keyframes with the "smallest three" scheme: the largest-magnitude
+//! quaternion component is dropped (it's reconstructable from the unit
+//! constraint) and the other three are packed as signed fixed-point
+//! values, for roughly 48 bits per rotation keyframe instead of 128.
+//! [`CompressedAnimationClip::sample`] mirrors [`AnimationClip::sample`]
+//! for the compressed representation.
+
+use crate::animation::{
+    AnimationChannel, AnimationClip, ChannelData, Interpolation, Skeleton, Transform,
+};
+use glam::{Quat, Vec3};
+
+// ============================================================================
+// Quantized rotations ("smallest three")
+// ============================================================================
+
+/// Magnitude bound of the three smallest components of a normalized
+/// quaternion once the largest is dropped (guaranteed by the unit
+/// constraint once every component's absolute value is <= the largest).
+const SMALLEST_THREE_MAX: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const QUANT_BITS: u32 = 15;
+const QUANT_MAX: i32 = (1 << (QUANT_BITS - 1)) - 1;
+
+/// A quaternion packed into ~48 bits: 2 bits identifying which component
+/// was dropped, plus the other three quantized to 15-bit fixed point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizedQuat(u64);
+
+impl QuantizedQuat {
+    pub fn quantize(q: Quat) -> Self {
+        let q = q.normalize();
+        let components = [q.x, q.y, q.z, q.w];
+        let largest_index = components
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.abs().partial_cmp(&b.1.abs()).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(3);
+
+        // q and -q represent the same rotation; canonicalize sign so the
+        // dropped (largest) component is always implicitly positive.
+        let sign = if components[largest_index] < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        let mut bits = largest_index as u64;
+        let mut slot = 0u32;
+        for (i, &c) in components.iter().enumerate() {
+            if i == largest_index {
+                continue;
+            }
+            let clamped = (c * sign).clamp(-SMALLEST_THREE_MAX, SMALLEST_THREE_MAX);
+            let quantized = ((clamped / SMALLEST_THREE_MAX) * QUANT_MAX as f32).round() as i32;
+            let offset_encoded = (quantized + QUANT_MAX) as u64 & 0x7FFF;
+            bits |= offset_encoded << (2 + QUANT_BITS * slot);
+            slot += 1;
+        }
+
+        Self(bits)
+    }
+
+    pub fn dequantize(self) -> Quat {
+        let largest_index = (self.0 & 0b11) as usize;
+        let mut remaining = [0f32; 3];
+        for (slot, value) in remaining.iter_mut().enumerate() {
+            let offset_encoded = (self.0 >> (2 + QUANT_BITS * slot as u32)) & 0x7FFF;
+            let quantized = offset_encoded as i32 - QUANT_MAX;
+            *value = (quantized as f32 / QUANT_MAX as f32) * SMALLEST_THREE_MAX;
+        }
+
+        let sum_sq: f32 = remaining.iter().map(|c| c * c).sum();
+        let largest = (1.0 - sum_sq).max(0.0).sqrt();
+
+        let mut components = [0f32; 4];
+        let mut slot = 0;
+        for (i, c) in components.iter_mut().enumerate() {
+            *c = if i == largest_index {
+                largest
+            } else {
+                let v = remaining[slot];
+                slot += 1;
+                v
+            };
+        }
+
+        Quat::from_xyzw(components[0], components[1], components[2], components[3]).normalize()
+    }
+}
+
+// ============================================================================
+// Error-bounded keyframe decimation
+// ============================================================================
+
+/// Drops any keyframe whose value can be reconstructed from its neighbors
+/// (via linear interpolation) within `tolerance`. Always keeps the first
+/// and last keyframes. Uses the Douglas-Peucker line-simplification
+/// algorithm parameterized by time.
+fn decimate_vec3(times: &[f32], values: &[Vec3], tolerance: f32) -> (Vec<f32>, Vec<Vec3>) {
+    if times.len() <= 2 {
+        return (times.to_vec(), values.to_vec());
+    }
+
+    let mut keep = vec![false; times.len()];
+    keep[0] = true;
+    *keep.last_mut().unwrap() = true;
+    decimate_vec3_range(times, values, 0, times.len() - 1, tolerance, &mut keep);
+
+    keep.iter()
+        .enumerate()
+        .filter(|(_, k)| **k)
+        .map(|(i, _)| (times[i], values[i]))
+        .unzip()
+}
+
+fn decimate_vec3_range(
+    times: &[f32],
+    values: &[Vec3],
+    start: usize,
+    end: usize,
+    tolerance: f32,
+    keep: &mut [bool],
+) {
+    if end <= start + 1 {
+        return;
+    }
+
+    let (t0, t1) = (times[start], times[end]);
+    let (v0, v1) = (values[start], values[end]);
+    let mut max_error = 0.0f32;
+    let mut max_index = start;
+
+    for i in start + 1..end {
+        let t = if t1 > t0 { (times[i] - t0) / (t1 - t0) } else { 0.0 };
+        let error = (values[i] - v0.lerp(v1, t)).length();
+        if error > max_error {
+            max_error = error;
+            max_index = i;
+        }
+    }
+
+    if max_error > tolerance {
+        keep[max_index] = true;
+        decimate_vec3_range(times, values, start, max_index, tolerance, keep);
+        decimate_vec3_range(times, values, max_index, end, tolerance, keep);
+    }
+}
+
+/// The angle, in radians, between two rotations (accounting for the
+/// quaternion double-cover, where `q` and `-q` are the same rotation).
+fn quat_angle_between(a: Quat, b: Quat) -> f32 {
+    2.0 * a.dot(b).clamp(-1.0, 1.0).abs().acos()
+}
+
+/// Rotation counterpart of [`decimate_vec3`]: reconstructs skipped
+/// keyframes via slerp and measures error as the angle between the
+/// reconstructed and original rotation.
+fn decimate_quat(times: &[f32], values: &[Quat], tolerance_radians: f32) -> (Vec<f32>, Vec<Quat>) {
+    if times.len() <= 2 {
+        return (times.to_vec(), values.to_vec());
+    }
+
+    let mut keep = vec![false; times.len()];
+    keep[0] = true;
+    *keep.last_mut().unwrap() = true;
+    decimate_quat_range(times, values, 0, times.len() - 1, tolerance_radians, &mut keep);
+
+    keep.iter()
+        .enumerate()
+        .filter(|(_, k)| **k)
+        .map(|(i, _)| (times[i], values[i]))
+        .unzip()
+}
+
+fn decimate_quat_range(
+    times: &[f32],
+    values: &[Quat],
+    start: usize,
+    end: usize,
+    tolerance_radians: f32,
+    keep: &mut [bool],
+) {
+    if end <= start + 1 {
+        return;
+    }
+
+    let (t0, t1) = (times[start], times[end]);
+    let (v0, v1) = (values[start], values[end]);
+    let mut max_error = 0.0f32;
+    let mut max_index = start;
+
+    for i in start + 1..end {
+        let t = if t1 > t0 { (times[i] - t0) / (t1 - t0) } else { 0.0 };
+        let error = quat_angle_between(values[i], v0.slerp(v1, t));
+        if error > max_error {
+            max_error = error;
+            max_index = i;
+        }
+    }
+
+    if max_error > tolerance_radians {
+        keep[max_index] = true;
+        decimate_quat_range(times, values, start, max_index, tolerance_radians, keep);
+        decimate_quat_range(times, values, max_index, end, tolerance_radians, keep);
+    }
+}
+
+// ============================================================================
+// Compressed clip
+// ============================================================================
+
+/// Error tolerances used when decimating an [`AnimationClip`]'s channels.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationCompressor {
+    /// Maximum translation error introduced by dropping a keyframe, in
+    /// scene units.
+    pub position_tolerance: f32,
+    /// Maximum scale error introduced by dropping a keyframe.
+    pub scale_tolerance: f32,
+    /// Maximum rotation error introduced by dropping a keyframe, in
+    /// radians.
+    pub rotation_tolerance_radians: f32,
+}
+
+impl Default for AnimationCompressor {
+    fn default() -> Self {
+        Self {
+            position_tolerance: 0.001,
+            scale_tolerance: 0.001,
+            rotation_tolerance_radians: 0.1_f32.to_radians(),
+        }
+    }
+}
+
+impl AnimationCompressor {
+    /// Decimates and quantizes every channel of `clip` into a
+    /// [`CompressedAnimationClip`].
+    pub fn compress(&self, clip: &AnimationClip) -> CompressedAnimationClip {
+        let channels = clip
+            .channels
+            .iter()
+            .map(|channel| self.compress_channel(channel))
+            .collect();
+
+        CompressedAnimationClip {
+            name: clip.name.clone(),
+            duration: clip.duration,
+            channels,
+        }
+    }
+
+    fn compress_channel(&self, channel: &AnimationChannel) -> CompressedAnimationChannel {
+        let (times, data) = match &channel.data {
+            ChannelData::Translation(values) => {
+                let (times, values) =
+                    decimate_vec3(&channel.times, values, self.position_tolerance);
+                (times, CompressedChannelData::Translation(values))
+            }
+            ChannelData::Scale(values) => {
+                let (times, values) = decimate_vec3(&channel.times, values, self.scale_tolerance);
+                (times, CompressedChannelData::Scale(values))
+            }
+            ChannelData::Rotation(values) => {
+                let (times, values) =
+                    decimate_quat(&channel.times, values, self.rotation_tolerance_radians);
+                let quantized = values.into_iter().map(QuantizedQuat::quantize).collect();
+                (times, CompressedChannelData::Rotation(quantized))
+            }
+        };
+
+        CompressedAnimationChannel {
+            target_joint_index: channel.target_joint_index,
+            times,
+            data,
+            interpolation: channel.interpolation,
+        }
+    }
+}
+
+/// Compressed counterpart of [`ChannelData`].
+#[derive(Debug, Clone)]
+pub enum CompressedChannelData {
+    Translation(Vec<Vec3>),
+    Rotation(Vec<QuantizedQuat>),
+    Scale(Vec<Vec3>),
+}
+
+/// Compressed counterpart of [`AnimationChannel`].
+#[derive(Debug, Clone)]
+pub struct CompressedAnimationChannel {
+    pub target_joint_index: usize,
+    pub times: Vec<f32>,
+    pub data: CompressedChannelData,
+    pub interpolation: Interpolation,
+}
+
+/// Compressed counterpart of [`AnimationClip`], produced by
+/// [`AnimationCompressor::compress`].
+#[derive(Debug, Clone)]
+pub struct CompressedAnimationClip {
+    pub name: String,
+    pub duration: f32,
+    pub channels: Vec<CompressedAnimationChannel>,
+}
+
+impl CompressedAnimationClip {
+    /// Samples the compressed clip the same way
+    /// [`AnimationClip::sample`] does, dequantizing rotation keyframes
+    /// on the fly.
+    pub fn sample(&self, time: f32, skeleton: &Skeleton) -> Vec<Transform> {
+        let mut transforms: Vec<Transform> = skeleton
+            .joints
+            .iter()
+            .map(|joint| joint.local_transform)
+            .collect();
+
+        for channel in &self.channels {
+            let joint_idx = channel.target_joint_index;
+            if joint_idx >= transforms.len() {
+                continue;
+            }
+
+            let (idx0, idx1, t) = find_keyframes(&channel.times, time);
+
+            transforms[joint_idx] = match &channel.data {
+                CompressedChannelData::Translation(values) => Transform {
+                    translation: interpolate_vec3(
+                        values[idx0],
+                        values[idx1],
+                        t,
+                        channel.interpolation,
+                    ),
+                    ..transforms[joint_idx]
+                },
+                CompressedChannelData::Scale(values) => Transform {
+                    scale: interpolate_vec3(values[idx0], values[idx1], t, channel.interpolation),
+                    ..transforms[joint_idx]
+                },
+                CompressedChannelData::Rotation(values) => {
+                    let q0 = values[idx0].dequantize();
+                    let q1 = values[idx1].dequantize();
+                    let rotation = match channel.interpolation {
+                        Interpolation::Step => q0,
+                        Interpolation::Linear | Interpolation::CubicSpline => q0.slerp(q1, t),
+                    };
+                    Transform {
+                        rotation,
+                        ..transforms[joint_idx]
+                    }
+                }
+            };
+        }
+
+        transforms
+    }
+}
+
+fn interpolate_vec3(v0: Vec3, v1: Vec3, t: f32, interpolation: Interpolation) -> Vec3 {
+    match interpolation {
+        Interpolation::Step => v0,
+        Interpolation::Linear | Interpolation::CubicSpline => v0.lerp(v1, t),
+    }
+}
+
+/// Same keyframe-bracketing logic as `AnimationClip::find_keyframes`.
+fn find_keyframes(times: &[f32], time: f32) -> (usize, usize, f32) {
+    if times.is_empty() {
+        return (0, 0, 0.0);
+    }
+    if times.len() == 1 || time <= times[0] {
+        return (0, 0, 0.0);
+    }
+    if let Some(&last_time) = times.last() {
+        if time >= last_time {
+            let last_idx = times.len() - 1;
+            return (last_idx, last_idx, 0.0);
+        }
+    }
+    for i in 0..times.len() - 1 {
+        if time >= times[i] && time < times[i + 1] {
+            let t = (time - times[i]) / (times[i + 1] - times[i]);
+            return (i, i + 1, t);
+        }
+    }
+    (0, 0, 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantized_quat_round_trips_within_tolerance() {
+        let original = Quat::from_euler(glam::EulerRot::XYZ, 0.4, -0.9, 1.2).normalize();
+        let quantized = QuantizedQuat::quantize(original);
+        let restored = quantized.dequantize();
+        assert!(quat_angle_between(original, restored) < 0.01);
+    }
+
+    #[test]
+    fn decimate_vec3_drops_collinear_midpoints() {
+        let times = vec![0.0, 1.0, 2.0, 3.0];
+        let values = vec![
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+        ];
+
+        let (out_times, out_values) = decimate_vec3(&times, &values, 0.001);
+
+        assert_eq!(out_times, vec![0.0, 3.0]);
+        assert_eq!(out_values, vec![Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0)]);
+    }
+
+    #[test]
+    fn decimate_vec3_keeps_keyframes_that_exceed_tolerance() {
+        let times = vec![0.0, 1.0, 2.0];
+        let values = vec![Vec3::ZERO, Vec3::new(0.0, 5.0, 0.0), Vec3::ZERO];
+
+        let (out_times, _) = decimate_vec3(&times, &values, 0.001);
+
+        assert_eq!(out_times, vec![0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn compress_reduces_channel_length_for_redundant_translation_keyframes() {
+        let clip = AnimationClip {
+            name: "idle".to_string(),
+            duration: 3.0,
+            channels: vec![AnimationChannel {
+                target_joint_index: 0,
+                times: vec![0.0, 1.0, 2.0, 3.0],
+                data: ChannelData::Translation(vec![
+                    Vec3::ZERO,
+                    Vec3::new(1.0, 0.0, 0.0),
+                    Vec3::new(2.0, 0.0, 0.0),
+                    Vec3::new(3.0, 0.0, 0.0),
+                ]),
+                interpolation: Interpolation::Linear,
+            }],
+        };
+
+        let compressed = AnimationCompressor::default().compress(&clip);
+
+        assert_eq!(compressed.channels[0].times.len(), 2);
+    }
+
+    #[test]
+    fn compressed_clip_samples_close_to_the_original() {
+        use crate::animation::Joint;
+        use glam::Mat4;
+
+        let skeleton = Skeleton {
+            joints: vec![Joint {
+                name: "root".to_string(),
+                parent_index: None,
+                inverse_bind_matrix: Mat4::IDENTITY,
+                local_transform: Transform::default(),
+            }],
+            root_indices: vec![0],
+        };
+
+        let rotations = vec![
+            Quat::IDENTITY,
+            Quat::from_rotation_y(0.5),
+            Quat::from_rotation_y(1.0),
+        ];
+        let clip = AnimationClip {
+            name: "turn".to_string(),
+            duration: 2.0,
+            channels: vec![AnimationChannel {
+                target_joint_index: 0,
+                times: vec![0.0, 1.0, 2.0],
+                data: ChannelData::Rotation(rotations),
+                interpolation: Interpolation::Linear,
+            }],
+        };
+
+        let compressed = AnimationCompressor::default().compress(&clip);
+
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0] {
+            let original = clip.sample(t, &skeleton)[0].rotation;
+            let restored = compressed.sample(t, &skeleton)[0].rotation;
+            assert!(
+                quat_angle_between(original, restored) < 0.05,
+                "sample at t={t} diverged too much"
+            );
+        }
+    }
+}
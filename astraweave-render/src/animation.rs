@@ -270,8 +270,11 @@ impl AnimationState {
 // Pose Computation (Joint Matrices)
 // ============================================================================
 
-/// Compute world-space joint matrices from local transforms and skeleton hierarchy
-pub fn compute_joint_matrices(
+/// Compute world-space joint matrices (pre-inverse-bind) from local
+/// transforms and skeleton hierarchy. Used by [`compute_joint_matrices`] and
+/// by the IK module (see [`crate::ik`]), which needs raw joint world
+/// transforms rather than the skinning-ready matrices.
+pub fn compute_world_transforms(
     skeleton: &Skeleton,
     local_transforms: &[Transform],
 ) -> Result<Vec<Mat4>, anyhow::Error> {
@@ -326,6 +329,16 @@ pub fn compute_joint_matrices(
         )?;
     }
 
+    Ok(world_matrices)
+}
+
+/// Compute world-space joint matrices from local transforms and skeleton hierarchy
+pub fn compute_joint_matrices(
+    skeleton: &Skeleton,
+    local_transforms: &[Transform],
+) -> Result<Vec<Mat4>, anyhow::Error> {
+    let world_matrices = compute_world_transforms(skeleton, local_transforms)?;
+
     // Apply inverse bind matrices to get final skinning matrices
     let mut skinning_matrices = Vec::with_capacity(skeleton.joints.len());
     for (i, joint) in skeleton.joints.iter().enumerate() {
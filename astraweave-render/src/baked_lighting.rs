@@ -0,0 +1,94 @@
+//! Runtime sampling of offline-baked lighting produced by
+//! [`astraweave_asset::light_baking`]: static geometry samples its baked
+//! [`BakedLightmap`] by UV, while dynamic objects sample the coarser
+//! [`IrradianceVolume`] by world position instead of paying for a full
+//! lightmap lookup.
+
+use astraweave_asset::light_baking::{BakedLightmap, IrradianceVolume};
+use glam::Vec3;
+
+/// Baked lighting available for one loaded scene/cell.
+#[derive(Debug, Clone, Default)]
+pub struct BakedLightingSet {
+    lightmaps: Vec<BakedLightmap>,
+    volume: Option<IrradianceVolume>,
+}
+
+impl BakedLightingSet {
+    pub fn new(lightmaps: Vec<BakedLightmap>, volume: Option<IrradianceVolume>) -> Self {
+        Self { lightmaps, volume }
+    }
+
+    /// Irradiance for a static object's `lightmap_index` at UV `(u, v)`.
+    /// Falls back to black if the index is out of range so a missing bake
+    /// darkens the surface instead of panicking the render loop.
+    pub fn sample_static(&self, lightmap_index: usize, u: f32, v: f32) -> Vec3 {
+        self.lightmaps
+            .get(lightmap_index)
+            .map(|lightmap| lightmap.sample_uv(u, v))
+            .unwrap_or(Vec3::ZERO)
+    }
+
+    /// Irradiance for a dynamic object at world-space `position`, sampled
+    /// from the irradiance volume if one was baked for this scene.
+    pub fn sample_dynamic(&self, position: Vec3) -> Vec3 {
+        self.volume
+            .as_ref()
+            .map(|volume| volume.sample(position))
+            .unwrap_or(Vec3::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_asset::light_baking::{bake_irradiance_volume, bake_lightmap, BakeLight, LightmapTexel};
+
+    fn light() -> BakeLight {
+        BakeLight {
+            position: Vec3::new(0.0, 1.0, 0.0),
+            color: Vec3::ONE,
+            intensity: 10.0,
+            range: 100.0,
+        }
+    }
+
+    #[test]
+    fn sample_static_returns_black_for_missing_lightmap() {
+        let set = BakedLightingSet::default();
+        assert_eq!(set.sample_static(0, 0.5, 0.5), Vec3::ZERO);
+    }
+
+    #[test]
+    fn sample_static_reads_baked_lightmap() {
+        let texels = vec![
+            LightmapTexel {
+                position: Vec3::ZERO,
+                normal: Vec3::Y
+            };
+            4
+        ];
+        let lightmap = bake_lightmap(&texels, 2, 2, &[light()], Vec3::ZERO).unwrap();
+        let set = BakedLightingSet::new(vec![lightmap], None);
+        assert!(set.sample_static(0, 0.0, 0.0).length() > 0.0);
+    }
+
+    #[test]
+    fn sample_dynamic_returns_black_without_volume() {
+        let set = BakedLightingSet::default();
+        assert_eq!(set.sample_dynamic(Vec3::ZERO), Vec3::ZERO);
+    }
+
+    #[test]
+    fn sample_dynamic_reads_baked_volume() {
+        let volume = bake_irradiance_volume(
+            Vec3::ZERO,
+            Vec3::splat(10.0),
+            (2, 2, 2),
+            &[light()],
+            Vec3::ZERO,
+        );
+        let set = BakedLightingSet::new(vec![], Some(volume));
+        assert!(set.sample_dynamic(Vec3::ZERO).length() > 0.0);
+    }
+}
@@ -0,0 +1,490 @@
+//! Camera rig components for gameplay cameras, replacing the hand-rolled
+//! cameras every example otherwise writes from scratch: third-person follow
+//! with a spring arm resolved against a caller-supplied collision probe,
+//! first-person, orbit, priority-based blending between named virtual
+//! cameras ([`CameraRigBlender`]), and trauma-based shake ([`CameraShake`]).
+//!
+//! This crate has no physics dependency, so collision resolution for the
+//! follow rig's spring arm takes a `collision_check` closure — the same
+//! decoupling [`astraweave_physics::projectile::ProjectileManager`] uses for
+//! its own raycasts — rather than depending on astraweave-physics directly.
+
+use crate::camera::Camera;
+use glam::Vec3;
+
+/// A resolved camera pose, independent of any particular rig — what
+/// [`CameraRigBlender`] blends between and what callers feed into
+/// [`Camera`] for rendering.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraPose {
+    pub position: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub fovy: f32,
+}
+
+impl CameraPose {
+    /// Linearly interpolates toward `other` by `t` in `[0, 1]`.
+    pub fn lerp(&self, other: &CameraPose, t: f32) -> CameraPose {
+        let t = t.clamp(0.0, 1.0);
+        CameraPose {
+            position: self.position.lerp(other.position, t),
+            yaw: self.yaw + (other.yaw - self.yaw) * t,
+            pitch: self.pitch + (other.pitch - self.pitch) * t,
+            fovy: self.fovy + (other.fovy - self.fovy) * t,
+        }
+    }
+
+    /// Bakes this pose into a renderable [`Camera`], keeping the aspect
+    /// ratio and clip planes of `template` (a pose has no notion of
+    /// viewport aspect).
+    pub fn into_camera(self, template: &Camera) -> Camera {
+        Camera {
+            position: self.position,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            fovy: self.fovy,
+            aspect: template.aspect,
+            znear: template.znear,
+            zfar: template.zfar,
+        }
+    }
+}
+
+/// Third-person follow rig: orbits `target` at `arm_length` behind/above,
+/// pulling the arm in (a spring arm) when [`FollowRig::update`]'s collision
+/// probe reports a closer obstruction, and relaxing back out smoothly once
+/// clear.
+pub struct FollowRig {
+    pub target: Vec3,
+    pub arm_length: f32,
+    pub height_offset: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub fovy: f32,
+    /// Arm length after the last [`Self::update`] call; always `<= arm_length`.
+    pub resolved_length: f32,
+    /// How quickly `resolved_length` relaxes back out to `arm_length` per
+    /// second once unobstructed. Pulling in is instantaneous (never clip
+    /// through geometry for even one frame).
+    pub spring_speed: f32,
+}
+
+impl FollowRig {
+    pub fn new(target: Vec3, arm_length: f32) -> Self {
+        Self {
+            target,
+            arm_length,
+            height_offset: 1.5,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 60f32.to_radians(),
+            resolved_length: arm_length,
+            spring_speed: 8.0,
+        }
+    }
+
+    fn pivot(&self) -> Vec3 {
+        self.target + Vec3::new(0.0, self.height_offset, 0.0)
+    }
+
+    fn position_at(&self, length: f32) -> Vec3 {
+        self.pivot() - Camera::dir(self.yaw, self.pitch) * length
+    }
+
+    /// Advances the spring arm and returns the resolved pose.
+    /// `collision_check(from, to)` should raycast from the pivot toward the
+    /// unobstructed desired position and return `Some(hit_distance)` on a
+    /// hit, `None` if clear.
+    pub fn update(
+        &mut self,
+        dt: f32,
+        collision_check: impl FnOnce(Vec3, Vec3) -> Option<f32>,
+    ) -> CameraPose {
+        let desired = self.position_at(self.arm_length);
+        let target_length = match collision_check(self.pivot(), desired) {
+            Some(hit_distance) => hit_distance.clamp(0.0, self.arm_length),
+            None => self.arm_length,
+        };
+
+        if target_length < self.resolved_length {
+            self.resolved_length = target_length;
+        } else {
+            let t = (self.spring_speed * dt).clamp(0.0, 1.0);
+            self.resolved_length += (target_length - self.resolved_length) * t;
+        }
+
+        CameraPose {
+            position: self.position_at(self.resolved_length),
+            yaw: self.yaw,
+            pitch: self.pitch,
+            fovy: self.fovy,
+        }
+    }
+}
+
+/// Orbit rig: circles `target` at a fixed `distance`, no collision
+/// resolution (suited to menus, photo mode, cutscene turntables).
+pub struct OrbitRig {
+    pub target: Vec3,
+    pub distance: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub fovy: f32,
+}
+
+impl OrbitRig {
+    pub fn new(target: Vec3, distance: f32) -> Self {
+        Self {
+            target,
+            distance,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 60f32.to_radians(),
+        }
+    }
+
+    pub fn pose(&self) -> CameraPose {
+        CameraPose {
+            position: self.target - Camera::dir(self.yaw, self.pitch) * self.distance,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            fovy: self.fovy,
+        }
+    }
+}
+
+/// First-person rig: the camera sits at `eye_offset` from a tracked
+/// entity's `position` (typically the character's head bone or a fixed
+/// eye height above its feet).
+pub struct FirstPersonRig {
+    pub position: Vec3,
+    pub eye_offset: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub fovy: f32,
+}
+
+impl FirstPersonRig {
+    pub fn new(position: Vec3, eye_offset: Vec3) -> Self {
+        Self {
+            position,
+            eye_offset,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 60f32.to_radians(),
+        }
+    }
+
+    pub fn pose(&self) -> CameraPose {
+        CameraPose {
+            position: self.position + self.eye_offset,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            fovy: self.fovy,
+        }
+    }
+}
+
+/// Trauma-based camera shake (Squirrel Eiserloh's GDC model): trauma decays
+/// linearly over time while shake amplitude scales with `trauma^2`, giving
+/// a sharp falloff at low intensity and punchy peaks under heavy trauma.
+/// Uses summed sine waves at incommensurate frequencies per axis rather
+/// than a full noise function, which is plenty for camera-scale jitter and
+/// avoids pulling in a noise crate dependency.
+pub struct CameraShake {
+    pub trauma: f32,
+    pub decay_per_sec: f32,
+    pub max_offset: Vec3,
+    pub max_roll: f32,
+    /// Per-instance frequency multiplier so multiple shakes don't sync up.
+    pub frequency: f32,
+}
+
+impl CameraShake {
+    pub fn new() -> Self {
+        Self {
+            trauma: 0.0,
+            decay_per_sec: 1.0,
+            max_offset: Vec3::new(0.15, 0.15, 0.0),
+            max_roll: 10f32.to_radians(),
+            frequency: 15.0,
+        }
+    }
+
+    /// Adds trauma, clamped to `1.0`. Call once per impact/explosion.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Decays trauma toward zero. Call once per frame.
+    pub fn tick(&mut self, dt: f32) {
+        self.trauma = (self.trauma - self.decay_per_sec * dt).max(0.0);
+    }
+
+    /// Positional offset and roll (radians) for the current trauma level at
+    /// `time` (seconds, monotonically increasing — e.g. total elapsed run
+    /// time), to be added on top of a base [`CameraPose`].
+    pub fn offset(&self, time: f32) -> (Vec3, f32) {
+        let shake = self.trauma * self.trauma;
+        let x = (self.frequency * time).sin();
+        let y = (self.frequency * 1.37 * time + 1.7).sin();
+        let roll = (self.frequency * 0.91 * time + 3.1).sin();
+        (
+            Vec3::new(x * self.max_offset.x, y * self.max_offset.y, 0.0) * shake,
+            roll * self.max_roll * shake,
+        )
+    }
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One named candidate camera in a [`CameraRigBlender`]. Higher `priority`
+/// wins; ties keep whichever camera was already active, then fall back to
+/// insertion order.
+pub struct VirtualCamera {
+    pub name: String,
+    pub priority: i32,
+    pub pose: CameraPose,
+}
+
+/// Blends between named [`VirtualCamera`]s by priority: the highest-priority
+/// registered camera is the blend target, and switching targets crossfades
+/// smoothly over `blend_seconds` instead of popping.
+pub struct CameraRigBlender {
+    cameras: Vec<VirtualCamera>,
+    active_name: Option<String>,
+    blend_from: CameraPose,
+    blend_elapsed: f32,
+    blend_duration: f32,
+    current: CameraPose,
+}
+
+impl CameraRigBlender {
+    pub fn new(initial: CameraPose) -> Self {
+        Self {
+            cameras: Vec::new(),
+            active_name: None,
+            blend_from: initial,
+            blend_elapsed: 0.0,
+            blend_duration: 0.0,
+            current: initial,
+        }
+    }
+
+    /// Registers or updates a virtual camera's pose/priority by name.
+    pub fn set_camera(&mut self, camera: VirtualCamera) {
+        if let Some(existing) = self.cameras.iter_mut().find(|c| c.name == camera.name) {
+            *existing = camera;
+        } else {
+            self.cameras.push(camera);
+        }
+    }
+
+    /// Removes a virtual camera by name. If it was active, the blender
+    /// keeps rendering its last pose as `current` until another `update`
+    /// picks a new highest-priority camera.
+    pub fn remove_camera(&mut self, name: &str) {
+        self.cameras.retain(|c| c.name != name);
+    }
+
+    fn highest_priority(&self) -> Option<&VirtualCamera> {
+        let best = self.cameras.iter().map(|c| c.priority).max()?;
+        let active = self.active_name.as_deref();
+        self.cameras
+            .iter()
+            .find(|c| c.priority == best && Some(c.name.as_str()) == active)
+            .or_else(|| self.cameras.iter().find(|c| c.priority == best))
+    }
+
+    /// Advances the blend and returns the current pose. Starts a new
+    /// `blend_seconds`-long crossfade whenever the highest-priority camera
+    /// changes identity.
+    pub fn update(&mut self, dt: f32, blend_seconds: f32) -> CameraPose {
+        let Some((target_name, target_pose)) = self
+            .highest_priority()
+            .map(|c| (c.name.clone(), c.pose))
+        else {
+            return self.current;
+        };
+
+        if self.active_name.as_deref() != Some(target_name.as_str()) {
+            self.blend_from = self.current;
+            self.blend_elapsed = 0.0;
+            self.blend_duration = blend_seconds.max(0.0);
+            self.active_name = Some(target_name);
+        }
+
+        self.blend_elapsed += dt;
+        let t = if self.blend_duration <= 0.0 {
+            1.0
+        } else {
+            self.blend_elapsed / self.blend_duration
+        };
+
+        self.current = self.blend_from.lerp(&target_pose, t);
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_pose_lerp_halfway() {
+        let a = CameraPose {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 1.0,
+        };
+        let b = CameraPose {
+            position: Vec3::new(10.0, 0.0, 0.0),
+            yaw: 1.0,
+            pitch: 0.0,
+            fovy: 2.0,
+        };
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.position, Vec3::new(5.0, 0.0, 0.0));
+        assert!((mid.yaw - 0.5).abs() < 1e-6);
+        assert!((mid.fovy - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_follow_rig_pulls_in_on_collision() {
+        let mut rig = FollowRig::new(Vec3::ZERO, 10.0);
+        let pose = rig.update(0.1, |_from, _to| Some(3.0));
+        assert!((rig.resolved_length - 3.0).abs() < 1e-6);
+        assert!((pose.position - rig.pivot()).length() < 3.0 + 1e-3);
+    }
+
+    #[test]
+    fn test_follow_rig_relaxes_back_out_when_clear() {
+        let mut rig = FollowRig::new(Vec3::ZERO, 10.0);
+        rig.resolved_length = 2.0;
+        rig.spring_speed = 100.0; // fast relax for a deterministic test
+        rig.update(1.0, |_from, _to| None);
+        assert!(rig.resolved_length > 9.0);
+    }
+
+    #[test]
+    fn test_follow_rig_never_exceeds_arm_length() {
+        let mut rig = FollowRig::new(Vec3::ZERO, 5.0);
+        // Collision reports a distance beyond the arm's natural length;
+        // should still clamp to arm_length, not extend past it.
+        rig.update(0.1, |_from, _to| Some(50.0));
+        assert!(rig.resolved_length <= 5.0);
+    }
+
+    #[test]
+    fn test_orbit_rig_pose_matches_distance() {
+        let rig = OrbitRig::new(Vec3::ZERO, 5.0);
+        let pose = rig.pose();
+        assert!((pose.position.length() - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_first_person_rig_adds_eye_offset() {
+        let rig = FirstPersonRig::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.8, 0.0));
+        let pose = rig.pose();
+        assert_eq!(pose.position, Vec3::new(1.0, 1.8, 0.0));
+    }
+
+    #[test]
+    fn test_camera_shake_zero_trauma_has_no_offset() {
+        let shake = CameraShake::new();
+        let (offset, roll) = shake.offset(1.0);
+        assert_eq!(offset, Vec3::ZERO);
+        assert_eq!(roll, 0.0);
+    }
+
+    #[test]
+    fn test_camera_shake_decays_over_time() {
+        let mut shake = CameraShake::new();
+        shake.add_trauma(1.0);
+        shake.tick(0.5);
+        assert!((shake.trauma - 0.5).abs() < 1e-6);
+        shake.tick(1.0);
+        assert_eq!(shake.trauma, 0.0);
+    }
+
+    #[test]
+    fn test_camera_shake_add_trauma_clamps_to_one() {
+        let mut shake = CameraShake::new();
+        shake.add_trauma(5.0);
+        assert_eq!(shake.trauma, 1.0);
+    }
+
+    #[test]
+    fn test_blender_picks_highest_priority_camera() {
+        let base = CameraPose {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 1.0,
+        };
+        let mut blender = CameraRigBlender::new(base);
+        blender.set_camera(VirtualCamera {
+            name: "low".to_string(),
+            priority: 0,
+            pose: CameraPose {
+                position: Vec3::new(1.0, 0.0, 0.0),
+                ..base
+            },
+        });
+        blender.set_camera(VirtualCamera {
+            name: "high".to_string(),
+            priority: 10,
+            pose: CameraPose {
+                position: Vec3::new(2.0, 0.0, 0.0),
+                ..base
+            },
+        });
+
+        // Instant blend (0-second duration) should land exactly on target.
+        let pose = blender.update(0.016, 0.0);
+        assert_eq!(pose.position, Vec3::new(2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_blender_crossfades_over_blend_duration() {
+        let base = CameraPose {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 1.0,
+        };
+        let mut blender = CameraRigBlender::new(base);
+        blender.set_camera(VirtualCamera {
+            name: "target".to_string(),
+            priority: 1,
+            pose: CameraPose {
+                position: Vec3::new(10.0, 0.0, 0.0),
+                ..base
+            },
+        });
+
+        let halfway = blender.update(1.0, 2.0);
+        assert!((halfway.position.x - 5.0).abs() < 1e-3);
+
+        let done = blender.update(1.0, 2.0);
+        assert!((done.position.x - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_blender_with_no_cameras_returns_initial_pose() {
+        let base = CameraPose {
+            position: Vec3::new(3.0, 3.0, 3.0),
+            yaw: 0.0,
+            pitch: 0.0,
+            fovy: 1.0,
+        };
+        let mut blender = CameraRigBlender::new(base);
+        assert_eq!(blender.update(0.1, 1.0), base);
+    }
+}
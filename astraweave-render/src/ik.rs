@@ -0,0 +1,326 @@
+//! Inverse Kinematics: two-bone limb solver, foot placement, and look-at
+//! constraints, applied after animation sampling and before GPU skinning.
+//!
+//! These solvers operate on the `Vec<Transform>` local joint pose produced by
+//! [`crate::animation::AnimationClip::sample`], adjusting a small number of
+//! joints in-place before the pose is turned into skinning matrices via
+//! [`crate::animation::compute_joint_matrices`]. Every solver takes a
+//! `weight` in `[0, 1]` so callers can blend IK on/off per entity instead of
+//! hard-switching it (see [`IkWeights`]).
+
+use crate::animation::{compute_world_transforms, Skeleton, Transform};
+use glam::{Mat4, Quat, Vec3};
+
+/// Per-entity enable weights for the IK solvers in this module. `0.0` means
+/// fully disabled (pure animated pose); `1.0` means the IK correction is
+/// fully applied. Intermediate values blend between the two.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IkWeights {
+    pub two_bone: f32,
+    pub foot: f32,
+    pub look_at: f32,
+}
+
+impl Default for IkWeights {
+    fn default() -> Self {
+        Self {
+            two_bone: 0.0,
+            foot: 0.0,
+            look_at: 0.0,
+        }
+    }
+}
+
+/// Interior angle of a triangle at the vertex between sides `adjacent1` and
+/// `adjacent2`, given the length of the `opposite` side (law of cosines).
+fn law_of_cosines_angle(adjacent1: f32, adjacent2: f32, opposite: f32) -> f32 {
+    let cos_angle = ((adjacent1 * adjacent1 + adjacent2 * adjacent2 - opposite * opposite)
+        / (2.0 * adjacent1 * adjacent2))
+        .clamp(-1.0, 1.0);
+    cos_angle.acos()
+}
+
+/// World-space result of [`solve_two_bone_ik`]: rotation deltas to
+/// premultiply onto the root and mid joints' current world rotations, plus
+/// the resulting joint positions (useful for debug drawing).
+#[derive(Debug, Clone, Copy)]
+pub struct TwoBoneIkSolution {
+    pub root_rotation_delta: Quat,
+    pub mid_rotation_delta: Quat,
+    pub mid_world_position: Vec3,
+    pub tip_world_position: Vec3,
+}
+
+/// Classic two-bone IK (arm/leg) solver: given the current world positions of
+/// a root, mid, and tip joint (e.g. hip/knee/foot or shoulder/elbow/hand),
+/// bends the chain so the tip reaches `target` while bending towards `pole`
+/// (the desired knee/elbow direction). Bone lengths are preserved exactly;
+/// `target` is clamped to the chain's reachable range.
+pub fn solve_two_bone_ik(
+    root_pos: Vec3,
+    mid_pos: Vec3,
+    tip_pos: Vec3,
+    target: Vec3,
+    pole: Vec3,
+) -> TwoBoneIkSolution {
+    let upper_len = (mid_pos - root_pos).length();
+    let lower_len = (tip_pos - mid_pos).length();
+    let max_reach = (upper_len + lower_len) * 0.999;
+    let min_reach = ((upper_len - lower_len).abs() * 1.001).max(1e-4);
+
+    let to_target = target - root_pos;
+    let target_dist = to_target.length().clamp(min_reach, max_reach.max(min_reach));
+    let target_dir = if to_target.length_squared() > 1e-8 {
+        to_target.normalize()
+    } else {
+        (mid_pos - root_pos).normalize_or_zero()
+    };
+
+    let upper_dir = (mid_pos - root_pos).normalize_or_zero();
+    let lower_dir = (tip_pos - mid_pos).normalize_or_zero();
+
+    let new_root_angle = law_of_cosines_angle(upper_len, target_dist, lower_len);
+    let new_mid_angle = law_of_cosines_angle(upper_len, lower_len, target_dist);
+
+    // Bend plane normal derived from the pole vector, so the chain bends
+    // towards it (e.g. a knee pointing forward, an elbow pointing back).
+    let to_pole = pole - root_pos;
+    let bend_axis = target_dir.cross(to_pole);
+    let bend_axis = if bend_axis.length_squared() > 1e-8 {
+        bend_axis.normalize()
+    } else {
+        Vec3::Y
+    };
+
+    let new_upper_dir = Quat::from_axis_angle(bend_axis, new_root_angle) * target_dir;
+    let root_rotation_delta = Quat::from_rotation_arc(upper_dir, new_upper_dir);
+
+    let new_lower_dir =
+        Quat::from_axis_angle(bend_axis, -(std::f32::consts::PI - new_mid_angle)) * new_upper_dir;
+    let mid_rotation_delta = Quat::from_rotation_arc(lower_dir, new_lower_dir);
+
+    let mid_world_position = root_pos + new_upper_dir * upper_len;
+    let tip_world_position = mid_world_position + new_lower_dir * lower_len;
+
+    TwoBoneIkSolution {
+        root_rotation_delta,
+        mid_rotation_delta,
+        mid_world_position,
+        tip_world_position,
+    }
+}
+
+/// Applies [`solve_two_bone_ik`] to a `[root, mid, tip]` joint chain in
+/// `local_transforms`, blending the result in by `weight`. `local_transforms`
+/// is the pose produced by [`crate::animation::AnimationClip::sample`].
+pub fn apply_two_bone_ik(
+    skeleton: &Skeleton,
+    local_transforms: &mut [Transform],
+    chain: [usize; 3],
+    target: Vec3,
+    pole: Vec3,
+    weight: f32,
+) -> Result<(), anyhow::Error> {
+    if weight <= 0.0 {
+        return Ok(());
+    }
+    let weight = weight.min(1.0);
+    let [root_idx, mid_idx, tip_idx] = chain;
+
+    let world = compute_world_transforms(skeleton, local_transforms)?;
+    let root_pos = world[root_idx].w_axis.truncate();
+    let mid_pos = world[mid_idx].w_axis.truncate();
+    let tip_pos = world[tip_idx].w_axis.truncate();
+
+    let solution = solve_two_bone_ik(root_pos, mid_pos, tip_pos, target, pole);
+
+    apply_world_rotation_delta(
+        skeleton,
+        local_transforms,
+        &world,
+        root_idx,
+        solution.root_rotation_delta,
+        weight,
+    );
+    // Re-derive the mid joint's world transform after the root moved, since
+    // the mid rotation delta was computed relative to the pre-solve pose.
+    let world_after_root = compute_world_transforms(skeleton, local_transforms)?;
+    apply_world_rotation_delta(
+        skeleton,
+        local_transforms,
+        &world_after_root,
+        mid_idx,
+        solution.mid_rotation_delta,
+        weight,
+    );
+
+    Ok(())
+}
+
+/// Blends `world_rotation_delta` (premultiplied onto `joint_idx`'s current
+/// world rotation) into its local transform, scaled by `weight`.
+fn apply_world_rotation_delta(
+    skeleton: &Skeleton,
+    local_transforms: &mut [Transform],
+    world_before: &[Mat4],
+    joint_idx: usize,
+    world_rotation_delta: Quat,
+    weight: f32,
+) {
+    let parent_world_rotation = skeleton.joints[joint_idx]
+        .parent_index
+        .map(|p| Quat::from_mat4(&world_before[p]))
+        .unwrap_or(Quat::IDENTITY);
+    let old_world_rotation = Quat::from_mat4(&world_before[joint_idx]);
+    let new_world_rotation = (world_rotation_delta * old_world_rotation).normalize();
+    let new_local_rotation = parent_world_rotation.inverse() * new_world_rotation;
+
+    let local = &mut local_transforms[joint_idx];
+    local.rotation = local.rotation.slerp(new_local_rotation, weight);
+}
+
+/// Ground query used by [`solve_foot_placement`]. Kept as a trait rather than
+/// a hard dependency on `astraweave-physics`, so callers can adapt
+/// `PhysicsWorld::raycast` (or a terrain heightmap) without a crate-level
+/// dependency edge from render to physics.
+pub trait GroundQuery {
+    /// Cast a ray straight down from `origin` for up to `max_distance`;
+    /// returns `(hit_point, hit_normal)` if the ray hit ground.
+    fn cast_down(&self, origin: Vec3, max_distance: f32) -> Option<(Vec3, Vec3)>;
+}
+
+/// Result of a successful [`solve_foot_placement`] call.
+#[derive(Debug, Clone, Copy)]
+pub struct FootIkResult {
+    pub target_position: Vec3,
+    pub ground_normal: Vec3,
+}
+
+/// Foot placement IK: probes for ground beneath `foot_world_pos` and returns
+/// a corrected foot position blended towards the hit point by `weight`.
+/// Returns `None` if no ground was found within the probe range.
+pub fn solve_foot_placement(
+    foot_world_pos: Vec3,
+    probe_height: f32,
+    probe_depth: f32,
+    ground: &dyn GroundQuery,
+    weight: f32,
+) -> Option<FootIkResult> {
+    let probe_origin = foot_world_pos + Vec3::Y * probe_height;
+    let (hit_pos, ground_normal) = ground.cast_down(probe_origin, probe_height + probe_depth)?;
+    let target_position = foot_world_pos.lerp(hit_pos, weight.clamp(0.0, 1.0));
+    Some(FootIkResult {
+        target_position,
+        ground_normal,
+    })
+}
+
+/// Look-at IK: rotates `current_forward` towards `to_target`, clamped to
+/// `max_angle_rad` and blended in by `weight`. Returns a rotation delta to
+/// premultiply onto the aiming joint's (head, eye, spine, ...) current world
+/// rotation.
+pub fn solve_look_at(
+    current_forward: Vec3,
+    to_target: Vec3,
+    max_angle_rad: f32,
+    weight: f32,
+) -> Quat {
+    let current = current_forward.normalize_or_zero();
+    let desired = to_target.normalize_or_zero();
+    if current == Vec3::ZERO || desired == Vec3::ZERO {
+        return Quat::IDENTITY;
+    }
+
+    let full_rotation = Quat::from_rotation_arc(current, desired);
+    let (axis, angle) = full_rotation.to_axis_angle();
+    let clamped_rotation = Quat::from_axis_angle(axis, angle.min(max_angle_rad.max(0.0)));
+
+    Quat::IDENTITY.slerp(clamped_rotation, weight.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_bone_ik_preserves_bone_lengths() {
+        let root = Vec3::new(0.0, 1.0, 0.0);
+        let mid = Vec3::new(0.0, 0.5, 0.0);
+        let tip = Vec3::new(0.0, 0.0, 0.0);
+        let target = Vec3::new(0.4, 0.6, 0.0);
+        let pole = Vec3::new(0.0, 0.5, 1.0);
+
+        let solution = solve_two_bone_ik(root, mid, tip, target, pole);
+
+        let upper_len = (mid - root).length();
+        let lower_len = (tip - mid).length();
+        assert!(((solution.mid_world_position - root).length() - upper_len).abs() < 1e-3);
+        assert!(
+            ((solution.tip_world_position - solution.mid_world_position).length() - lower_len)
+                .abs()
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn two_bone_ik_clamps_target_beyond_max_reach() {
+        let root = Vec3::ZERO;
+        let mid = Vec3::new(0.0, -0.5, 0.0);
+        let tip = Vec3::new(0.0, -1.0, 0.0);
+        // Far outside the chain's max reach of 1.0.
+        let target = Vec3::new(0.0, -100.0, 0.0);
+        let pole = Vec3::new(1.0, -0.5, 0.0);
+
+        let solution = solve_two_bone_ik(root, mid, tip, target, pole);
+        let reach = (solution.tip_world_position - root).length();
+        assert!(reach <= 1.0 + 1e-3, "reach should be clamped, got {}", reach);
+    }
+
+    #[test]
+    fn foot_placement_blends_by_weight() {
+        struct FlatGround;
+        impl GroundQuery for FlatGround {
+            fn cast_down(&self, origin: Vec3, _max_distance: f32) -> Option<(Vec3, Vec3)> {
+                Some((Vec3::new(origin.x, 0.0, origin.z), Vec3::Y))
+            }
+        }
+
+        let foot_pos = Vec3::new(0.0, 0.5, 0.0);
+        let result = solve_foot_placement(foot_pos, 1.0, 1.0, &FlatGround, 0.5).unwrap();
+        assert!((result.target_position.y - 0.25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn foot_placement_returns_none_without_ground() {
+        struct NoGround;
+        impl GroundQuery for NoGround {
+            fn cast_down(&self, _origin: Vec3, _max_distance: f32) -> Option<(Vec3, Vec3)> {
+                None
+            }
+        }
+
+        let result = solve_foot_placement(Vec3::ZERO, 1.0, 1.0, &NoGround, 1.0);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn look_at_zero_weight_is_identity() {
+        let delta = solve_look_at(Vec3::Z, Vec3::X, std::f32::consts::PI, 0.0);
+        assert!(delta.angle_between(Quat::IDENTITY) < 1e-5);
+    }
+
+    #[test]
+    fn look_at_clamps_to_max_angle() {
+        let delta = solve_look_at(Vec3::Z, Vec3::X, 0.1, 1.0);
+        let (_, angle) = delta.to_axis_angle();
+        assert!(angle <= 0.1 + 1e-4);
+    }
+
+    #[test]
+    fn ik_weights_default_is_fully_disabled() {
+        let weights = IkWeights::default();
+        assert_eq!(weights.two_bone, 0.0);
+        assert_eq!(weights.foot, 0.0);
+        assert_eq!(weights.look_at, 0.0);
+    }
+}
@@ -0,0 +1,124 @@
+//! Runtime side of vegetation/prop impostor rendering: past
+//! [`ImpostorPolicy::max_full_mesh_distance`], instances draw as a
+//! billboard against a pre-baked octahedral atlas (see
+//! `astraweave_asset_pipeline::impostor`) instead of their full mesh,
+//! cutting draw cost in dense scenes like forests.
+
+use crate::culling::InstanceAABB;
+use glam::Vec3;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Distance threshold controlling the full-mesh/impostor switch.
+#[derive(Debug, Clone, Copy)]
+pub struct ImpostorPolicy {
+    /// Instances farther than this from the camera render as impostor
+    /// billboards instead of their full mesh.
+    pub max_full_mesh_distance: f32,
+}
+
+/// Instance indices split into the full-mesh and impostor draw paths.
+#[derive(Debug, Clone, Default)]
+pub struct ImpostorPartition {
+    pub full_mesh: Vec<u32>,
+    pub impostor: Vec<u32>,
+}
+
+/// Split `visible` instance indices (already frustum/distance culled, e.g.
+/// from [`crate::visibility::VisibilityStage::cull`]) into full-mesh and
+/// impostor sets based on `policy`.
+pub fn partition_by_distance(
+    aabbs: &[InstanceAABB],
+    visible: &[u32],
+    camera_pos: Vec3,
+    policy: &ImpostorPolicy,
+) -> ImpostorPartition {
+    let by_index: HashMap<u32, &InstanceAABB> =
+        aabbs.iter().map(|a| (a.instance_index, a)).collect();
+
+    let classified: Vec<(u32, bool)> = visible
+        .par_iter()
+        .filter_map(|&idx| {
+            let aabb = by_index.get(&idx)?;
+            let center = Vec3::from(aabb.center);
+            let use_impostor = center.distance(camera_pos) > policy.max_full_mesh_distance;
+            Some((idx, use_impostor))
+        })
+        .collect();
+
+    let mut partition = ImpostorPartition::default();
+    for (idx, use_impostor) in classified {
+        if use_impostor {
+            partition.impostor.push(idx);
+        } else {
+            partition.full_mesh.push(idx);
+        }
+    }
+    partition
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aabb(center: Vec3, idx: u32) -> InstanceAABB {
+        InstanceAABB::new(center, Vec3::splat(0.5), idx)
+    }
+
+    #[test]
+    fn near_instance_stays_full_mesh() {
+        let aabbs = vec![aabb(Vec3::new(5.0, 0.0, 0.0), 0)];
+        let policy = ImpostorPolicy {
+            max_full_mesh_distance: 50.0,
+        };
+        let partition = partition_by_distance(&aabbs, &[0], Vec3::ZERO, &policy);
+        assert_eq!(partition.full_mesh, vec![0]);
+        assert!(partition.impostor.is_empty());
+    }
+
+    #[test]
+    fn far_instance_switches_to_impostor() {
+        let aabbs = vec![aabb(Vec3::new(500.0, 0.0, 0.0), 0)];
+        let policy = ImpostorPolicy {
+            max_full_mesh_distance: 50.0,
+        };
+        let partition = partition_by_distance(&aabbs, &[0], Vec3::ZERO, &policy);
+        assert_eq!(partition.impostor, vec![0]);
+        assert!(partition.full_mesh.is_empty());
+    }
+
+    #[test]
+    fn splits_mixed_distances_correctly() {
+        let aabbs = vec![aabb(Vec3::new(5.0, 0.0, 0.0), 0), aabb(Vec3::new(500.0, 0.0, 0.0), 1)];
+        let policy = ImpostorPolicy {
+            max_full_mesh_distance: 50.0,
+        };
+        let mut partition = partition_by_distance(&aabbs, &[0, 1], Vec3::ZERO, &policy);
+        partition.full_mesh.sort();
+        partition.impostor.sort();
+        assert_eq!(partition.full_mesh, vec![0]);
+        assert_eq!(partition.impostor, vec![1]);
+    }
+
+    #[test]
+    fn ignores_indices_missing_from_aabbs() {
+        let aabbs = vec![aabb(Vec3::ZERO, 0)];
+        let policy = ImpostorPolicy {
+            max_full_mesh_distance: 50.0,
+        };
+        let partition = partition_by_distance(&aabbs, &[0, 99], Vec3::ZERO, &policy);
+        assert_eq!(partition.full_mesh, vec![0]);
+        assert!(partition.impostor.is_empty());
+    }
+
+    #[test]
+    fn empty_visible_set_produces_empty_partition() {
+        let aabbs = vec![aabb(Vec3::ZERO, 0)];
+        let policy = ImpostorPolicy {
+            max_full_mesh_distance: 50.0,
+        };
+        let partition = partition_by_distance(&aabbs, &[], Vec3::ZERO, &policy);
+        assert!(partition.full_mesh.is_empty());
+        assert!(partition.impostor.is_empty());
+    }
+}
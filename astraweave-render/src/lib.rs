@@ -32,6 +32,7 @@
 //! | `ssao` | Screen-space ambient occlusion |
 
 pub mod camera;
+pub mod camera_rig;
 pub mod clustered;
 pub mod clustered_forward; // Complete clustered forward rendering
 pub mod clustered_megalights; // MegaLights: GPU-accelerated light culling (Phase 1)
@@ -53,15 +54,21 @@ pub mod types; // clustered-lighting WGSL placeholders & tests // gpu upload & c
                // See MATERIALS.md for canonical materials arrays and WGSL bindings
 pub mod animation;
 pub mod asset_index;
+pub mod anim_compression; // Error-bounded keyframe decimation + quantized rotation storage
+pub mod retarget; // Skeleton retargeting between differing rigs
+pub mod baked_lighting; // Runtime sampling of offline-baked lightmaps and irradiance volumes
 pub mod biome_audio;
 pub mod biome_detector;
 pub mod biome_material;
 pub mod biome_transition;
 pub mod culling; // GPU-driven frustum culling (Phase 2 Task 3)
 pub mod culling_node; // Culling node for render graph
+pub mod visibility; // Per-camera entity frustum + distance culling stage
 pub mod graph; // minimal render graph scaffolding (Phase 2)
 pub mod graph_adapter; // runs a graph on Renderer frames
+pub mod impostor; // Distance-based full-mesh/impostor billboard switch
 pub mod hdri_catalog;
+pub mod ik; // Two-bone limb IK, foot placement, and look-at constraints layered on animation
 pub mod material; // shared authored materials API + GPU arrays
 pub mod material_extended; // Phase PBR-E: Advanced materials (clearcoat, anisotropy, SSS, sheen, transmission)
 #[cfg(feature = "textures")]
@@ -81,6 +88,7 @@ pub mod skinning_gpu; // Phase 2 Task 5 Phase D: GPU skinning pipeline
 
 pub mod instancing;
 pub mod lod_generator; // Week 5 Action 19: LOD generation with quadric error metrics
+pub mod lod_select; // Runtime distance-based LOD selection with cross-fade
 pub mod vertex_compression; // Week 5 Action 19: Vertex compression // Week 5 Action 19: GPU instancing for draw call reduction (octahedral normals, half-float UVs)
 
 #[cfg(test)]
@@ -91,6 +99,8 @@ mod mutation_tests; // Phase 10B: Comprehensive mutation-killing tests
 
 // Nanite virtualized geometry system
 #[cfg(feature = "nanite")]
+pub mod nanite_cluster_hierarchy;
+#[cfg(feature = "nanite")]
 pub mod nanite_gpu_culling;
 #[cfg(feature = "nanite")]
 pub mod nanite_render;
@@ -98,6 +108,9 @@ pub mod nanite_render;
 pub mod nanite_visibility; // NEW: GPU-driven culling and visibility
 
 pub use camera::{Camera, CameraController};
+pub use camera_rig::{
+    CameraPose, CameraRigBlender, CameraShake, FirstPersonRig, FollowRig, OrbitRig, VirtualCamera,
+};
 pub use environment::{
     SkyConfig, SkyRenderer, TimeOfDay, WeatherParticles, WeatherSystem, WeatherType,
 };
@@ -121,6 +134,8 @@ pub mod transparency; // Transparency depth sorting and render pass // Advanced
 
 // GPU memory management and SSAO
 pub mod gpu_memory; // GPU memory budget tracking and enforcement
+pub mod mip_streaming; // Screen-coverage-driven mip selection and pressure-aware eviction
+pub mod pipeline_cache; // Background pipeline compilation and on-disk driver cache persistence
 #[cfg(feature = "ssao")]
 pub mod ssao; // Screen-space ambient occlusion
 
@@ -168,9 +183,14 @@ pub use transparency::{create_blend_state, BlendMode, TransparencyManager, Trans
 
 // Phase 2 Task 5: Skeletal Animation exports
 pub use animation::{
-    compute_joint_matrices, skin_vertex_cpu, AnimationChannel, AnimationClip, AnimationState,
-    ChannelData, Interpolation, Joint, JointMatrixGPU, JointPalette, Skeleton, Transform,
-    MAX_JOINTS,
+    compute_joint_matrices, compute_world_transforms, skin_vertex_cpu, AnimationChannel,
+    AnimationClip, AnimationState, ChannelData, Interpolation, Joint, JointMatrixGPU,
+    JointPalette, Skeleton, Transform, MAX_JOINTS,
+};
+
+pub use ik::{
+    apply_two_bone_ik, solve_foot_placement, solve_look_at, solve_two_bone_ik, FootIkResult,
+    GroundQuery, IkWeights, TwoBoneIkSolution,
 };
 
 #[cfg(feature = "skinning-gpu")]
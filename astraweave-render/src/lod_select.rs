@@ -0,0 +1,144 @@
+//! Runtime level-of-detail selection: given a distance to the camera and a
+//! set of distance thresholds, picks which precomputed LOD mesh (see
+//! [`crate::lod_generator`]) to draw and how far into a cross-fade to the
+//! next-coarser level the transition is, so LOD switches dissolve instead
+//! of popping.
+
+/// Distance thresholds controlling which LOD level is active, and how wide
+/// a band around each threshold two adjacent levels cross-fade over.
+#[derive(Debug, Clone)]
+pub struct LodPolicy {
+    /// Distance at which level `i` gives way to level `i + 1`, sorted
+    /// ascending. `thresholds.len()` levels of detail beyond level 0 exist.
+    pub thresholds: Vec<f32>,
+    /// Width, in the same units as `thresholds`, of the distance band
+    /// centered on each threshold over which the two levels cross-fade.
+    /// Zero disables cross-fading (levels pop).
+    pub fade_band: f32,
+}
+
+/// Which level to draw for a given distance, and how far through a
+/// cross-fade into the next-coarser level the transition is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodSelection {
+    /// Level to draw, at full opacity outside a fade band.
+    pub level: usize,
+    /// Next-coarser level being faded in, if `distance` is within the fade
+    /// band around `level`'s threshold.
+    pub next_level: Option<usize>,
+    /// Blend weight of `next_level` in `[0, 1]`; 0 = fully `level`, 1 =
+    /// fully `next_level`.
+    pub blend: f32,
+}
+
+/// Selects a LOD level (and optional cross-fade target) for `distance`.
+pub fn select_lod(distance: f32, policy: &LodPolicy) -> LodSelection {
+    let half_band = policy.fade_band.max(0.0) * 0.5;
+
+    // A level only counts as "reached" once distance has cleared the full
+    // fade band past its threshold, so `level` stays the finer level for
+    // the whole fade-in of the coarser one.
+    let level = policy
+        .thresholds
+        .iter()
+        .take_while(|&&t| distance >= t + half_band)
+        .count();
+    let last_level = policy.thresholds.len();
+    if level >= last_level {
+        return LodSelection {
+            level: last_level,
+            next_level: None,
+            blend: 0.0,
+        };
+    }
+
+    let threshold = policy.thresholds[level];
+    let fade_start = threshold - half_band;
+    if half_band <= 0.0 || distance < fade_start {
+        return LodSelection {
+            level,
+            next_level: None,
+            blend: 0.0,
+        };
+    }
+
+    let fade_end = threshold + half_band;
+    let blend = ((distance - fade_start) / (fade_end - fade_start)).clamp(0.0, 1.0);
+    LodSelection {
+        level,
+        next_level: Some(level + 1),
+        blend,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> LodPolicy {
+        LodPolicy {
+            thresholds: vec![10.0, 30.0],
+            fade_band: 4.0,
+        }
+    }
+
+    #[test]
+    fn near_distance_selects_finest_level_with_no_fade() {
+        let sel = select_lod(2.0, &policy());
+        assert_eq!(sel.level, 0);
+        assert_eq!(sel.next_level, None);
+        assert_eq!(sel.blend, 0.0);
+    }
+
+    #[test]
+    fn far_distance_selects_coarsest_level_with_no_fade() {
+        let sel = select_lod(1000.0, &policy());
+        assert_eq!(sel.level, 2);
+        assert_eq!(sel.next_level, None);
+        assert_eq!(sel.blend, 0.0);
+    }
+
+    #[test]
+    fn distance_inside_fade_band_blends_toward_next_level() {
+        let sel = select_lod(9.0, &policy()); // threshold=10, half_band=2 -> band [8,12)
+        assert_eq!(sel.level, 0);
+        assert_eq!(sel.next_level, Some(1));
+        assert!(
+            (sel.blend - 0.25).abs() < 1e-4,
+            "expected blend ~0.25, got {}",
+            sel.blend
+        );
+    }
+
+    #[test]
+    fn distance_past_fade_band_commits_to_next_level() {
+        let sel = select_lod(12.0, &policy());
+        assert_eq!(sel.level, 1);
+        assert_eq!(sel.next_level, None);
+    }
+
+    #[test]
+    fn zero_fade_band_pops_without_blending() {
+        let policy = LodPolicy {
+            thresholds: vec![10.0],
+            fade_band: 0.0,
+        };
+        let just_before = select_lod(9.9, &policy);
+        let just_after = select_lod(10.0, &policy);
+        assert_eq!(just_before.level, 0);
+        assert_eq!(just_before.next_level, None);
+        assert_eq!(just_after.level, 1);
+        assert_eq!(just_after.next_level, None);
+    }
+
+    #[test]
+    fn empty_thresholds_always_selects_level_zero() {
+        let policy = LodPolicy {
+            thresholds: vec![],
+            fade_band: 4.0,
+        };
+        let sel = select_lod(500.0, &policy);
+        assert_eq!(sel.level, 0);
+        assert_eq!(sel.next_level, None);
+    }
+}
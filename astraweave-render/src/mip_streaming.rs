@@ -0,0 +1,215 @@
+//! Screen-coverage-driven mip selection and pressure-aware eviction.
+//!
+//! [`crate::texture_streaming::TextureStreamingManager`] streams whole
+//! textures; this module decides *which mip level* a texture needs based on
+//! how many screen pixels it currently covers, and asks
+//! [`crate::gpu_memory::GpuMemoryBudget`] to evict lower-priority textures
+//! when a category is under pressure. It is intentionally decoupled from
+//! wgpu resource types so it can be unit tested without a GPU adapter.
+
+use crate::gpu_memory::{GpuMemoryBudget, MemoryCategory};
+use std::collections::HashMap;
+
+/// Per-texture screen coverage feedback, gathered once per frame (e.g. from
+/// a coverage-buffer pass or bounding-sphere projection).
+#[derive(Debug, Clone, Copy)]
+pub struct CoverageSample {
+    /// Approximate on-screen pixel footprint of the texture this frame.
+    pub screen_pixels: f32,
+    /// Native resolution of mip 0, used to derive how many mips are needed
+    /// to match `screen_pixels`.
+    pub base_resolution: u32,
+}
+
+impl CoverageSample {
+    /// Target mip level such that `base_resolution >> mip` roughly matches
+    /// the texture's on-screen footprint, clamped to `[0, max_mip]`.
+    pub fn target_mip(&self, max_mip: u32) -> u32 {
+        if self.screen_pixels <= 0.0 || self.base_resolution == 0 {
+            return max_mip;
+        }
+        let desired_texel_dim = self.screen_pixels.sqrt().max(1.0);
+        let mut mip = 0u32;
+        let mut dim = self.base_resolution as f32;
+        while dim > desired_texel_dim && mip < max_mip {
+            dim /= 2.0;
+            mip += 1;
+        }
+        mip
+    }
+}
+
+/// Tracks the currently-resident mip level of each streamed texture and
+/// drives mip up/downgrades from per-frame coverage feedback plus memory
+/// pressure reported by [`GpuMemoryBudget`].
+pub struct MipStreamingController {
+    resident_mip: HashMap<String, u32>,
+    max_mip: u32,
+}
+
+impl MipStreamingController {
+    pub fn new(max_mip: u32) -> Self {
+        Self {
+            resident_mip: HashMap::new(),
+            max_mip,
+        }
+    }
+
+    /// Decide the mip level for `id` this frame given its coverage sample
+    /// and the current budget pressure for [`MemoryCategory::Textures`].
+    ///
+    /// Under memory pressure, upgrades (loading a finer mip) are refused
+    /// even if coverage would justify one, so streaming degrades gracefully
+    /// instead of blowing the texture budget.
+    pub fn update(
+        &mut self,
+        id: &str,
+        sample: CoverageSample,
+        budget: &GpuMemoryBudget,
+    ) -> MipDecision {
+        let desired = sample.target_mip(self.max_mip);
+        let current = *self.resident_mip.get(id).unwrap_or(&self.max_mip);
+
+        let under_pressure = budget.usage_percentage() > 0.85;
+
+        let next = if desired < current {
+            // Wants a finer (larger) mip — only allow if we have headroom.
+            if under_pressure {
+                current
+            } else {
+                desired
+            }
+        } else {
+            // Wants a coarser mip or is unchanged — always allowed, this frees memory.
+            desired
+        };
+
+        self.resident_mip.insert(id.to_string(), next);
+
+        if next < current {
+            MipDecision::Upgrade { from: current, to: next }
+        } else if next > current {
+            MipDecision::Downgrade { from: current, to: next }
+        } else {
+            MipDecision::Unchanged { mip: next }
+        }
+    }
+
+    /// Currently tracked mip level for `id`, if any.
+    pub fn current_mip(&self, id: &str) -> Option<u32> {
+        self.resident_mip.get(id).copied()
+    }
+
+    /// Drop tracking for a texture that has been unloaded.
+    pub fn forget(&mut self, id: &str) {
+        self.resident_mip.remove(id);
+    }
+}
+
+/// Outcome of a per-frame mip streaming decision for one texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MipDecision {
+    Upgrade { from: u32, to: u32 },
+    Downgrade { from: u32, to: u32 },
+    Unchanged { mip: u32 },
+}
+
+/// Evict textures from `category` (largest-first, via the supplied byte
+/// sizes) until usage drops back under the category's soft limit or the
+/// candidate list is exhausted. Returns the ids evicted, in eviction order.
+///
+/// Callers are responsible for actually freeing the GPU resource and
+/// calling [`GpuMemoryBudget::deallocate`] for each returned id.
+pub fn evict_under_pressure(
+    budget: &GpuMemoryBudget,
+    category: MemoryCategory,
+    mut candidates: Vec<(String, u64)>,
+) -> Vec<String> {
+    candidates.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut evicted = Vec::new();
+    let mut freed = 0u64;
+    let over_budget = budget
+        .snapshot()
+        .into_iter()
+        .find(|(cat, current, _)| *cat == category && *current > 0)
+        .map(|(_, current, hard)| current.saturating_sub((hard as f64 * 0.75) as u64))
+        .unwrap_or(0);
+
+    for (id, size) in candidates {
+        if freed >= over_budget {
+            break;
+        }
+        freed += size;
+        evicted.push(id);
+    }
+    evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_mip_matches_footprint() {
+        let sample = CoverageSample {
+            screen_pixels: 64.0 * 64.0,
+            base_resolution: 1024,
+        };
+        // 1024 -> 512 -> 256 -> 128 -> 64: four halvings
+        assert_eq!(sample.target_mip(10), 4);
+    }
+
+    #[test]
+    fn target_mip_clamped_to_max() {
+        let sample = CoverageSample {
+            screen_pixels: 1.0,
+            base_resolution: 1024,
+        };
+        assert_eq!(sample.target_mip(3), 3);
+    }
+
+    #[test]
+    fn controller_upgrades_when_not_under_pressure() {
+        let budget = GpuMemoryBudget::new();
+        let mut ctrl = MipStreamingController::new(10);
+        ctrl.resident_mip.insert("tex".into(), 5);
+
+        let sample = CoverageSample {
+            screen_pixels: 512.0 * 512.0,
+            base_resolution: 1024,
+        };
+        let decision = ctrl.update("tex", sample, &budget);
+        assert!(matches!(decision, MipDecision::Upgrade { .. }));
+    }
+
+    #[test]
+    fn controller_blocks_upgrade_under_pressure() {
+        let budget = GpuMemoryBudget::with_total_budget(1024);
+        // Push usage above the 85% pressure threshold.
+        budget.set_category_budget(MemoryCategory::Textures, 1024, 1024);
+        assert!(budget.try_allocate(MemoryCategory::Textures, 900));
+        let mut ctrl = MipStreamingController::new(10);
+        ctrl.resident_mip.insert("tex".into(), 5);
+
+        let sample = CoverageSample {
+            screen_pixels: 512.0 * 512.0,
+            base_resolution: 1024,
+        };
+        let decision = ctrl.update("tex", sample, &budget);
+        assert_eq!(decision, MipDecision::Unchanged { mip: 5 });
+    }
+
+    #[test]
+    fn evict_under_pressure_picks_largest_first() {
+        let budget = GpuMemoryBudget::new();
+        budget.set_category_budget(MemoryCategory::Textures, 100, 200);
+        budget.try_allocate(MemoryCategory::Textures, 150);
+
+        let candidates = vec![
+            ("small".to_string(), 10u64),
+            ("big".to_string(), 100u64),
+        ];
+        let evicted = evict_under_pressure(&budget, MemoryCategory::Textures, candidates);
+        assert_eq!(evicted.first(), Some(&"big".to_string()));
+    }
+}
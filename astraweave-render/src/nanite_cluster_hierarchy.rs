@@ -0,0 +1,177 @@
+//! Runtime cluster-hierarchy selection for Nanite meshlets.
+//!
+//! `astraweave_asset::nanite_preprocess::generate_lod_hierarchy` builds an
+//! offline [`MeshletHierarchy`], but nothing at runtime picked which of its
+//! meshlets to actually draw for a given camera. [`ClusterHierarchy`] wraps
+//! a `MeshletHierarchy` for one mesh and [`ClusterHierarchy::select_clusters`]
+//! walks its meshlets, picks a target LOD per meshlet via
+//! [`LODSelector`]'s screen-space-error metric, frustum-culls the survivors
+//! with [`Frustum`], and optionally occlusion-culls them against a
+//! caller-supplied test (this crate's occlusion culling is otherwise a
+//! GPU compute pipeline in [`crate::nanite_gpu_culling`]; the callback lets
+//! a CPU-side selection pass reuse whatever occlusion result — a Hi-Z
+//! readback, a prior frame's visibility, a raycast — the caller already has
+//! on hand). The result is packed into [`SelectedCluster`]s, ready to
+//! upload as a GPU indirection buffer.
+
+use crate::nanite_visibility::{Frustum, LODSelector};
+use astraweave_asset::nanite_preprocess::MeshletHierarchy;
+use glam::{Mat4, Vec3};
+
+/// One meshlet selected for rendering this frame, in a GPU-upload-friendly layout.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SelectedCluster {
+    pub meshlet_index: u32,
+    pub lod_level: u32,
+    pub instance_id: u32,
+    pub _pad: u32,
+}
+
+/// Counts from one [`ClusterHierarchy::select_clusters`] call, for HUD/profiling.
+#[derive(Clone, Debug, Default)]
+pub struct ClusterCullStats {
+    pub total_considered: u32,
+    pub lod_rejected: u32,
+    pub frustum_culled: u32,
+    pub occlusion_culled: u32,
+    pub selected: u32,
+}
+
+/// Runtime view over an offline [`MeshletHierarchy`] for one mesh.
+pub struct ClusterHierarchy<'a> {
+    hierarchy: &'a MeshletHierarchy,
+}
+
+impl<'a> ClusterHierarchy<'a> {
+    pub fn new(hierarchy: &'a MeshletHierarchy) -> Self {
+        Self { hierarchy }
+    }
+
+    /// Selects the meshlets to render for one instance of this hierarchy's
+    /// mesh under `world_transform`. `is_occluded`, if given, is consulted
+    /// per-meshlet (world-space bounding sphere center and radius) after
+    /// frustum culling passes; without one, occlusion culling is skipped.
+    pub fn select_clusters(
+        &self,
+        instance_id: u32,
+        world_transform: Mat4,
+        camera_pos: Vec3,
+        frustum: &Frustum,
+        lod_selector: &LODSelector,
+        is_occluded: Option<&dyn Fn(Vec3, f32) -> bool>,
+    ) -> (Vec<SelectedCluster>, ClusterCullStats) {
+        let mut stats = ClusterCullStats::default();
+        let mut selected = Vec::new();
+        let (scale, _, _) = world_transform.to_scale_rotation_translation();
+        let max_scale = scale.x.max(scale.y).max(scale.z);
+        let max_lod = self.hierarchy.lod_count.saturating_sub(1);
+
+        for (index, meshlet) in self.hierarchy.meshlets.iter().enumerate() {
+            stats.total_considered += 1;
+
+            let center = world_transform.transform_point3(meshlet.bounds.center());
+            let radius = (meshlet.bounds.diagonal() * 0.5) * max_scale;
+
+            let target_lod = lod_selector.select_lod(center, radius, meshlet.lod_error.max(f32::EPSILON), camera_pos, max_lod);
+            if meshlet.lod_level != target_lod {
+                stats.lod_rejected += 1;
+                continue;
+            }
+
+            if !frustum.test_sphere(center, radius) {
+                stats.frustum_culled += 1;
+                continue;
+            }
+
+            if let Some(is_occluded) = is_occluded {
+                if is_occluded(center, radius) {
+                    stats.occlusion_culled += 1;
+                    continue;
+                }
+            }
+
+            selected.push(SelectedCluster {
+                meshlet_index: index as u32,
+                lod_level: meshlet.lod_level,
+                instance_id,
+                _pad: 0,
+            });
+            stats.selected += 1;
+        }
+
+        (selected, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_asset::nanite_preprocess::generate_lod_hierarchy;
+
+    fn cube_hierarchy() -> MeshletHierarchy {
+        let positions = vec![
+            [-1.0, -1.0, -1.0],
+            [1.0, -1.0, -1.0],
+            [1.0, 1.0, -1.0],
+            [-1.0, 1.0, -1.0],
+            [-1.0, -1.0, 1.0],
+            [1.0, -1.0, 1.0],
+            [1.0, 1.0, 1.0],
+            [-1.0, 1.0, 1.0],
+        ];
+        let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+        let tangents = vec![[1.0, 0.0, 0.0, 1.0]; positions.len()];
+        let uvs = vec![[0.0, 0.0]; positions.len()];
+        let indices: Vec<u32> = vec![0, 1, 2, 0, 2, 3, 4, 6, 5, 4, 7, 6, 0, 4, 5, 0, 5, 1, 1, 5, 6, 1, 6, 2, 2, 6, 7, 2, 7, 3, 3, 7, 4, 3, 4, 0];
+        generate_lod_hierarchy(&positions, &normals, &tangents, &uvs, &indices, 2).expect("lod hierarchy generation")
+    }
+
+    #[test]
+    fn selects_something_for_a_camera_looking_straight_at_the_mesh() {
+        let hierarchy = cube_hierarchy();
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_rh(std::f32::consts::FRAC_PI_3, 1.0, 0.1, 1000.0);
+        let frustum = Frustum::from_matrix(proj * view);
+        let lod_selector = LODSelector::new(1080.0, std::f32::consts::FRAC_PI_3);
+
+        let cluster_hierarchy = ClusterHierarchy::new(&hierarchy);
+        let (selected, stats) = cluster_hierarchy.select_clusters(0, Mat4::IDENTITY, Vec3::new(0.0, 0.0, 10.0), &frustum, &lod_selector, None);
+
+        assert!(!selected.is_empty());
+        assert_eq!(stats.total_considered as usize, hierarchy.meshlets.len());
+    }
+
+    #[test]
+    fn frustum_culls_a_mesh_far_outside_the_view() {
+        let hierarchy = cube_hierarchy();
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_rh(std::f32::consts::FRAC_PI_3, 1.0, 0.1, 1000.0);
+        let frustum = Frustum::from_matrix(proj * view);
+        let lod_selector = LODSelector::new(1080.0, std::f32::consts::FRAC_PI_3);
+
+        let cluster_hierarchy = ClusterHierarchy::new(&hierarchy);
+        let far_away = Mat4::from_translation(Vec3::new(10_000.0, 10_000.0, 10_000.0));
+        let (selected, stats) = cluster_hierarchy.select_clusters(0, far_away, Vec3::new(0.0, 0.0, 10.0), &frustum, &lod_selector, None);
+
+        assert!(selected.is_empty());
+        assert!(stats.frustum_culled > 0);
+    }
+
+    #[test]
+    fn occlusion_callback_rejects_every_meshlet_when_it_always_returns_true() {
+        let hierarchy = cube_hierarchy();
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_rh(std::f32::consts::FRAC_PI_3, 1.0, 0.1, 1000.0);
+        let frustum = Frustum::from_matrix(proj * view);
+        let lod_selector = LODSelector::new(1080.0, std::f32::consts::FRAC_PI_3);
+
+        let cluster_hierarchy = ClusterHierarchy::new(&hierarchy);
+        let always_occluded: &dyn Fn(Vec3, f32) -> bool = &|_, _| true;
+        let (selected, stats) =
+            cluster_hierarchy.select_clusters(0, Mat4::IDENTITY, Vec3::new(0.0, 0.0, 10.0), &frustum, &lod_selector, Some(always_occluded));
+
+        assert!(selected.is_empty());
+        assert!(stats.occlusion_culled > 0);
+    }
+}
@@ -0,0 +1,179 @@
+//! Background pipeline compilation and on-disk `wgpu::PipelineCache` reuse.
+//!
+//! Compiling a render/compute pipeline can take tens of milliseconds on some
+//! drivers, and doing it on the frame that first needs the pipeline causes a
+//! visible hitch. This module lets callers request a pipeline, get a
+//! placeholder immediately, and swap in the real pipeline once a background
+//! thread finishes compiling it. It also persists wgpu's driver pipeline
+//! cache blob to disk, keyed by adapter, so warm compiles are fast across
+//! runs on the same machine.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+/// A pipeline that may still be compiling. Renderers should check
+/// [`PipelineSlot::get`] each frame and fall back to a placeholder pipeline
+/// (e.g. an "unlit magenta" material) while `None`.
+pub struct PipelineSlot {
+    ready: Mutex<Option<Arc<wgpu::RenderPipeline>>>,
+}
+
+impl PipelineSlot {
+    fn pending() -> Self {
+        Self {
+            ready: Mutex::new(None),
+        }
+    }
+
+    /// The compiled pipeline, if compilation has finished.
+    #[allow(clippy::expect_used)]
+    pub fn get(&self) -> Option<Arc<wgpu::RenderPipeline>> {
+        self.ready.lock().expect("pipeline slot mutex poisoned").clone()
+    }
+
+    #[allow(clippy::expect_used)]
+    fn fulfill(&self, pipeline: wgpu::RenderPipeline) {
+        *self.ready.lock().expect("pipeline slot mutex poisoned") = Some(Arc::new(pipeline));
+    }
+}
+
+/// Manages background compilation of render pipelines and persistence of
+/// wgpu's opaque driver pipeline cache blob.
+pub struct AsyncPipelineCompiler {
+    device: Arc<wgpu::Device>,
+    slots: Mutex<HashMap<String, Arc<PipelineSlot>>>,
+    tx: mpsc::Sender<CompileJob>,
+}
+
+struct CompileJob {
+    slot: Arc<PipelineSlot>,
+    build: Box<dyn FnOnce(&wgpu::Device) -> wgpu::RenderPipeline + Send>,
+}
+
+impl AsyncPipelineCompiler {
+    /// Spawn the background compiler thread. `device` must be safe to use
+    /// concurrently with the render thread (wgpu devices are `Send + Sync`).
+    pub fn new(device: Arc<wgpu::Device>) -> Self {
+        let (tx, rx) = mpsc::channel::<CompileJob>();
+        let compile_device = device.clone();
+        std::thread::spawn(move || {
+            while let Ok(job) = rx.recv() {
+                let pipeline = (job.build)(&compile_device);
+                job.slot.fulfill(pipeline);
+            }
+        });
+
+        Self {
+            device,
+            slots: Mutex::new(HashMap::new()),
+            tx,
+        }
+    }
+
+    /// Request a pipeline identified by `key`, compiling it in the
+    /// background via `build` if it isn't already known. Returns
+    /// immediately with a slot that resolves once compilation finishes;
+    /// repeated requests for the same `key` return the same slot.
+    #[allow(clippy::expect_used)]
+    pub fn request(
+        &self,
+        key: &str,
+        build: impl FnOnce(&wgpu::Device) -> wgpu::RenderPipeline + Send + 'static,
+    ) -> Arc<PipelineSlot> {
+        let mut slots = self.slots.lock().expect("pipeline cache mutex poisoned");
+        if let Some(existing) = slots.get(key) {
+            return existing.clone();
+        }
+
+        let slot = Arc::new(PipelineSlot::pending());
+        slots.insert(key.to_string(), slot.clone());
+        let _ = self.tx.send(CompileJob {
+            slot: slot.clone(),
+            build: Box::new(build),
+        });
+        slot
+    }
+
+    pub fn device(&self) -> &Arc<wgpu::Device> {
+        &self.device
+    }
+}
+
+/// Path to the persisted pipeline cache blob for a given adapter, so
+/// different GPUs/drivers on the same machine don't clobber each other's
+/// cache (wgpu's cache blob format is driver-specific).
+pub fn cache_path_for_adapter(cache_dir: &Path, adapter_info: &wgpu::AdapterInfo) -> PathBuf {
+    let key = format!(
+        "{}-{}-{:?}",
+        adapter_info.vendor, adapter_info.device, adapter_info.backend
+    );
+    cache_dir.join(format!("pipeline_cache_{}.bin", sanitize(&key)))
+}
+
+fn sanitize(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Load a previously-saved pipeline cache blob, if present and if the
+/// device supports `Features::PIPELINE_CACHE`.
+///
+/// # Safety
+/// `wgpu::PipelineCacheDescriptor::data` must come from a prior save on the
+/// same driver/adapter (enforced here by [`cache_path_for_adapter`]);
+/// mismatched blobs are safely ignored by the driver, not a memory hazard,
+/// but wgpu still marks the constructor `unsafe` because malformed data is
+/// implementation-defined.
+pub fn load_pipeline_cache(device: &wgpu::Device, path: &Path) -> Option<wgpu::PipelineCache> {
+    if !device.features().contains(wgpu::Features::PIPELINE_CACHE) {
+        return None;
+    }
+    let data = std::fs::read(path).ok()?;
+    Some(unsafe {
+        device.create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+            label: Some("astraweave-pipeline-cache"),
+            data: Some(&data),
+            fallback: true,
+        })
+    })
+}
+
+/// Persist the driver pipeline cache blob to disk so the next run starts
+/// warm.
+pub fn save_pipeline_cache(cache: &wgpu::PipelineCache, path: &Path) -> std::io::Result<()> {
+    let data = cache.get_data().unwrap_or_default();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_path_is_stable_for_same_adapter() {
+        let dir = PathBuf::from("/tmp/aw-pipeline-cache-test");
+        let info = wgpu::AdapterInfo {
+            name: "Test GPU".into(),
+            vendor: 0x10de,
+            device: 0x1234,
+            device_type: wgpu::DeviceType::DiscreteGpu,
+            driver: String::new(),
+            driver_info: String::new(),
+            backend: wgpu::Backend::Vulkan,
+        };
+        let p1 = cache_path_for_adapter(&dir, &info);
+        let p2 = cache_path_for_adapter(&dir, &info);
+        assert_eq!(p1, p2);
+    }
+
+    #[test]
+    fn sanitize_strips_non_alphanumeric() {
+        assert_eq!(sanitize("a-b:c"), "a_b_c");
+    }
+}
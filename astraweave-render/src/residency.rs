@@ -160,6 +160,7 @@ mod tests {
                     dependencies: vec![],
                     last_modified: 0,
                     size_bytes: 5 * 1024 * 1024, // 5 MB
+                    audio: None,
                 },
             );
         }
@@ -188,6 +189,7 @@ mod tests {
                     dependencies: vec![],
                     last_modified: 0,
                     size_bytes: 6 * 1024 * 1024, // 6 MB
+                    audio: None,
                 },
             );
         }
@@ -218,6 +220,7 @@ mod tests {
                 dependencies: vec![],
                 last_modified: 0,
                 size_bytes: size_mb * 1024 * 1024,
+                audio: None,
             },
         );
     }
@@ -0,0 +1,304 @@
+//! Skeleton retargeting between differing rigs.
+//!
+//! Loaded [`AnimationClip`]s are tied to their source skeleton's joint
+//! indices, so a mocap clip authored against one rig's joint order can't
+//! drive a character built on a different rig even if the bones represent
+//! the same parts of the body. [`SkeletonRetargeter`] maps joints from a
+//! source skeleton onto a target skeleton by (normalized) name, falling
+//! back to a standard humanoid bone alias table for common naming
+//! mismatches (e.g. `mixamorig:LeftArm` vs. `LeftUpperArm`), corrects
+//! rotation channels for rest-pose differences between the two rigs, and
+//! produces a retargeted [`AnimationClip`] whose channels target the
+//! destination skeleton's joint indices. Translation channels are copied
+//! unchanged — this does not rescale root motion for differing bone
+//! lengths/proportions between rigs.
+
+use crate::animation::{AnimationChannel, AnimationClip, ChannelData, Skeleton};
+use glam::Quat;
+use std::collections::HashMap;
+
+/// Alternate spellings for standard humanoid bones, grouped so any two
+/// aliases in the same group are treated as the same joint. Used as a
+/// fallback when a source and target bone aren't named identically.
+const HUMANOID_BONE_ALIASES: &[&[&str]] = &[
+    &["hips", "pelvis", "mixamorighips"],
+    &["spine", "spine1", "mixamorigspine"],
+    &["chest", "spine2", "upperchest", "mixamorigspine2"],
+    &["neck", "mixamorigneck"],
+    &["head", "mixamorighead"],
+    &["leftshoulder", "mixamorigleftshoulder"],
+    &["leftupperarm", "leftarm", "mixamorigleftarm"],
+    &["leftlowerarm", "leftforearm", "mixamorigleftforearm"],
+    &["lefthand", "mixamoriglefthand"],
+    &["rightshoulder", "mixamorigrightshoulder"],
+    &["rightupperarm", "rightarm", "mixamorigrightarm"],
+    &["rightlowerarm", "rightforearm", "mixamorigrightforearm"],
+    &["righthand", "mixamorigrighthand"],
+    &["leftupperleg", "leftthigh", "leftupleg", "mixamorigleftupleg"],
+    &["leftlowerleg", "leftshin", "leftleg", "mixamorigleftleg"],
+    &["leftfoot", "mixamorigleftfoot"],
+    &["rightupperleg", "rightthigh", "rightupleg", "mixamorigrightupleg"],
+    &["rightlowerleg", "rightshin", "rightleg", "mixamorigrightleg"],
+    &["rightfoot", "mixamorigrightfoot"],
+];
+
+fn normalize_bone_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+fn alias_group(name: &str) -> Option<usize> {
+    let normalized = normalize_bone_name(name);
+    HUMANOID_BONE_ALIASES
+        .iter()
+        .position(|group| group.contains(&normalized.as_str()))
+}
+
+/// Maps joints from a source skeleton to a target skeleton and retargets
+/// [`AnimationClip`]s between them.
+pub struct SkeletonRetargeter {
+    /// `source_joint_index -> target_joint_index`
+    joint_map: HashMap<usize, usize>,
+    /// Per-source-joint rotation correction applied when retargeting, to
+    /// account for rest-pose differences between the two rigs.
+    rest_pose_correction: HashMap<usize, Quat>,
+}
+
+impl SkeletonRetargeter {
+    /// Builds a joint mapping from `source` to `target` by normalized
+    /// name, falling back to [`HUMANOID_BONE_ALIASES`], and precomputes
+    /// each mapped joint's rest-pose rotation correction from the two
+    /// rigs' bind-pose local rotations.
+    pub fn new(source: &Skeleton, target: &Skeleton) -> Self {
+        let target_by_name: HashMap<String, usize> = target
+            .joints
+            .iter()
+            .enumerate()
+            .map(|(i, j)| (normalize_bone_name(&j.name), i))
+            .collect();
+        let target_by_alias: HashMap<usize, usize> = target
+            .joints
+            .iter()
+            .enumerate()
+            .filter_map(|(i, j)| alias_group(&j.name).map(|group| (group, i)))
+            .collect();
+
+        let mut joint_map = HashMap::new();
+        let mut rest_pose_correction = HashMap::new();
+
+        for (source_idx, source_joint) in source.joints.iter().enumerate() {
+            let normalized = normalize_bone_name(&source_joint.name);
+            let target_idx = target_by_name.get(&normalized).copied().or_else(|| {
+                alias_group(&source_joint.name)
+                    .and_then(|group| target_by_alias.get(&group).copied())
+            });
+
+            let Some(target_idx) = target_idx else {
+                continue;
+            };
+            joint_map.insert(source_idx, target_idx);
+
+            let source_rest = source_joint.local_transform.rotation;
+            let target_rest = target.joints[target_idx].local_transform.rotation;
+            // Rotates a source-space delta rotation into target space.
+            rest_pose_correction.insert(source_idx, target_rest * source_rest.inverse());
+        }
+
+        Self {
+            joint_map,
+            rest_pose_correction,
+        }
+    }
+
+    /// Number of source joints that found a match on the target skeleton.
+    pub fn mapped_joint_count(&self) -> usize {
+        self.joint_map.len()
+    }
+
+    pub fn target_joint_for(&self, source_joint_index: usize) -> Option<usize> {
+        self.joint_map.get(&source_joint_index).copied()
+    }
+
+    /// Produces a copy of `clip` whose channels target the destination
+    /// skeleton's joint indices, with rotation channels corrected for
+    /// rest-pose differences. Channels for source joints with no mapped
+    /// target joint are dropped.
+    pub fn retarget_clip(&self, clip: &AnimationClip) -> AnimationClip {
+        let mut channels = Vec::with_capacity(clip.channels.len());
+
+        for channel in &clip.channels {
+            let Some(&target_joint_index) = self.joint_map.get(&channel.target_joint_index) else {
+                continue;
+            };
+
+            let data = match &channel.data {
+                ChannelData::Rotation(values) => {
+                    let correction = self
+                        .rest_pose_correction
+                        .get(&channel.target_joint_index)
+                        .copied()
+                        .unwrap_or(Quat::IDENTITY);
+                    ChannelData::Rotation(values.iter().map(|&q| correction * q).collect())
+                }
+                other => other.clone(),
+            };
+
+            channels.push(AnimationChannel {
+                target_joint_index,
+                times: channel.times.clone(),
+                data,
+                interpolation: channel.interpolation,
+            });
+        }
+
+        AnimationClip {
+            name: clip.name.clone(),
+            duration: clip.duration,
+            channels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{Interpolation, Joint, Transform};
+    use glam::{Mat4, Vec3};
+
+    fn joint(name: &str, rotation: Quat) -> Joint {
+        Joint {
+            name: name.to_string(),
+            parent_index: None,
+            inverse_bind_matrix: Mat4::IDENTITY,
+            local_transform: Transform {
+                rotation,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn matches_joints_by_exact_normalized_name() {
+        let source = Skeleton {
+            joints: vec![joint("Hips", Quat::IDENTITY), joint("Spine", Quat::IDENTITY)],
+            root_indices: vec![0],
+        };
+        let target = Skeleton {
+            joints: vec![joint("spine", Quat::IDENTITY), joint("hips", Quat::IDENTITY)],
+            root_indices: vec![1],
+        };
+
+        let retargeter = SkeletonRetargeter::new(&source, &target);
+        assert_eq!(retargeter.mapped_joint_count(), 2);
+        assert_eq!(retargeter.target_joint_for(0), Some(1)); // Hips -> hips
+        assert_eq!(retargeter.target_joint_for(1), Some(0)); // Spine -> spine
+    }
+
+    #[test]
+    fn falls_back_to_humanoid_aliases_when_names_differ() {
+        let source = Skeleton {
+            joints: vec![joint("mixamorig:LeftArm", Quat::IDENTITY)],
+            root_indices: vec![0],
+        };
+        let target = Skeleton {
+            joints: vec![joint("LeftUpperArm", Quat::IDENTITY)],
+            root_indices: vec![0],
+        };
+
+        let retargeter = SkeletonRetargeter::new(&source, &target);
+        assert_eq!(retargeter.target_joint_for(0), Some(0));
+    }
+
+    #[test]
+    fn leaves_unmatched_source_joints_unmapped() {
+        let source = Skeleton {
+            joints: vec![joint("Tail", Quat::IDENTITY)],
+            root_indices: vec![0],
+        };
+        let target = Skeleton {
+            joints: vec![joint("Hips", Quat::IDENTITY)],
+            root_indices: vec![0],
+        };
+
+        let retargeter = SkeletonRetargeter::new(&source, &target);
+        assert_eq!(retargeter.mapped_joint_count(), 0);
+        assert_eq!(retargeter.target_joint_for(0), None);
+    }
+
+    #[test]
+    fn retarget_clip_remaps_channel_target_and_drops_unmapped_channels() {
+        let source = Skeleton {
+            joints: vec![
+                joint("Hips", Quat::IDENTITY),
+                joint("Tail", Quat::IDENTITY),
+            ],
+            root_indices: vec![0],
+        };
+        let target = Skeleton {
+            joints: vec![joint("Spine", Quat::IDENTITY), joint("Hips", Quat::IDENTITY)],
+            root_indices: vec![1],
+        };
+        let retargeter = SkeletonRetargeter::new(&source, &target);
+
+        let clip = AnimationClip {
+            name: "walk".to_string(),
+            duration: 1.0,
+            channels: vec![
+                AnimationChannel {
+                    target_joint_index: 0, // Hips -> mapped to target index 1
+                    times: vec![0.0],
+                    data: ChannelData::Translation(vec![Vec3::ZERO]),
+                    interpolation: Interpolation::Linear,
+                },
+                AnimationChannel {
+                    target_joint_index: 1, // Tail -> unmapped, dropped
+                    times: vec![0.0],
+                    data: ChannelData::Translation(vec![Vec3::ZERO]),
+                    interpolation: Interpolation::Linear,
+                },
+            ],
+        };
+
+        let retargeted = retargeter.retarget_clip(&clip);
+        assert_eq!(retargeted.channels.len(), 1);
+        assert_eq!(retargeted.channels[0].target_joint_index, 1);
+    }
+
+    #[test]
+    fn retarget_clip_applies_rest_pose_rotation_correction() {
+        let source_rest = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let target_rest = Quat::IDENTITY;
+        let source = Skeleton {
+            joints: vec![joint("Hips", source_rest)],
+            root_indices: vec![0],
+        };
+        let target = Skeleton {
+            joints: vec![joint("Hips", target_rest)],
+            root_indices: vec![0],
+        };
+        let retargeter = SkeletonRetargeter::new(&source, &target);
+
+        let animated_rotation = Quat::from_rotation_x(0.3);
+        let clip = AnimationClip {
+            name: "test".to_string(),
+            duration: 1.0,
+            channels: vec![AnimationChannel {
+                target_joint_index: 0,
+                times: vec![0.0],
+                data: ChannelData::Rotation(vec![animated_rotation]),
+                interpolation: Interpolation::Step,
+            }],
+        };
+
+        let retargeted = retargeter.retarget_clip(&clip);
+        let expected = (target_rest * source_rest.inverse()) * animated_rotation;
+        match &retargeted.channels[0].data {
+            ChannelData::Rotation(values) => {
+                assert!(values[0].abs_diff_eq(expected, 1e-5));
+            }
+            _ => panic!("expected a rotation channel"),
+        }
+    }
+}
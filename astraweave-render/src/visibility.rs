@@ -0,0 +1,186 @@
+//! Per-camera entity visibility stage bridging [`MeshRegistry`] AABBs and
+//! entity transforms into the frustum culling primitives in [`crate::culling`].
+//!
+//! [`culling::cpu_frustum_cull`] and [`culling::FrustumPlanes`] operate on a
+//! single frustum and a pre-built `Vec<InstanceAABB>`; this module adds the
+//! two things a multi-camera scene needs on top of that: turning per-entity
+//! mesh handles + world transforms into world-space AABBs, and testing each
+//! one against several cameras (frustum *and* distance) in parallel with
+//! rayon, so [`crate::instancing`] and the clustered renderers only ever see
+//! the instances a given camera can actually see.
+
+use crate::culling::{FrustumPlanes, InstanceAABB};
+use crate::mesh_registry::{MeshHandle, MeshRegistry};
+use glam::{Mat4, Vec3};
+use rayon::prelude::*;
+
+/// A single renderable entity: which mesh it uses, where it is in the world,
+/// and the index it occupies in the instance buffer consumed by
+/// [`crate::instancing`]. Visibility results are keyed by `instance_index`.
+#[derive(Debug, Clone, Copy)]
+pub struct VisibilityEntity {
+    pub mesh: MeshHandle,
+    pub transform: Mat4,
+    pub instance_index: u32,
+}
+
+/// One camera's frustum, world position, and view distance for culling.
+#[derive(Debug, Clone, Copy)]
+pub struct CullingCamera {
+    pub frustum: FrustumPlanes,
+    pub position: Vec3,
+    /// Instances farther than this from `position` are culled regardless of
+    /// frustum visibility. Use `f32::INFINITY` to disable distance culling.
+    pub max_distance: f32,
+}
+
+/// Visible instance indices for one camera, in the order they were found.
+pub type VisibleSet = Vec<u32>;
+
+/// Builds world-space [`InstanceAABB`]s from entities + a [`MeshRegistry`],
+/// then culls them against any number of cameras in parallel.
+#[derive(Default)]
+pub struct VisibilityStage {
+    aabbs: Vec<InstanceAABB>,
+}
+
+impl VisibilityStage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recompute world-space AABBs for `entities`, in parallel. Entities
+    /// whose mesh has no registered AABB (not yet uploaded, or an empty
+    /// mesh) are skipped.
+    pub fn update(&mut self, entities: &[VisibilityEntity], registry: &MeshRegistry) {
+        self.aabbs = entities
+            .par_iter()
+            .filter_map(|entity| {
+                let (local_min, local_max) = registry.get_gpu(entity.mesh)?.aabb?;
+                Some(InstanceAABB::from_transform(
+                    &entity.transform,
+                    local_min,
+                    local_max,
+                    entity.instance_index,
+                ))
+            })
+            .collect();
+    }
+
+    /// World-space AABBs as of the last call to [`Self::update`].
+    pub fn aabbs(&self) -> &[InstanceAABB] {
+        &self.aabbs
+    }
+
+    /// Frustum- and distance-cull the current AABBs against each camera,
+    /// independently and in parallel. Returns one visible-index set per
+    /// camera, in `cameras` order, ready to feed into
+    /// [`crate::instancing::InstanceManager`] or the clustered renderers.
+    pub fn cull(&self, cameras: &[CullingCamera]) -> Vec<VisibleSet> {
+        cameras
+            .par_iter()
+            .map(|camera| {
+                self.aabbs
+                    .iter()
+                    .filter(|aabb| {
+                        let center = Vec3::from(aabb.center);
+                        let extent = Vec3::from(aabb.extent);
+                        distance_visible(center, camera.position, camera.max_distance)
+                            && camera.frustum.test_aabb(center, extent)
+                    })
+                    .map(|aabb| aabb.instance_index)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// True if `center` is within `max_distance` of `camera_pos`. Operates on
+/// the AABB center rather than the nearest point on the box, matching
+/// [`FrustumPlanes::test_aabb`]'s own center+extent approximation.
+fn distance_visible(center: Vec3, camera_pos: Vec3, max_distance: f32) -> bool {
+    if !max_distance.is_finite() {
+        return true;
+    }
+    center.distance_squared(camera_pos) <= max_distance * max_distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera(position: Vec3, max_distance: f32) -> CullingCamera {
+        // Wide-open frustum looking down -Z from `position` so tests can
+        // isolate distance culling from frustum culling.
+        let view = Mat4::look_to_rh(position, -Vec3::Z, Vec3::Y);
+        let proj = Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 1000.0);
+        CullingCamera {
+            frustum: FrustumPlanes::from_view_proj(&(proj * view)),
+            position,
+            max_distance,
+        }
+    }
+
+    fn aabb_stage(centers: &[Vec3]) -> VisibilityStage {
+        let aabbs = centers
+            .iter()
+            .enumerate()
+            .map(|(i, c)| InstanceAABB::new(*c, Vec3::splat(0.5), i as u32))
+            .collect();
+        VisibilityStage { aabbs }
+    }
+
+    #[test]
+    fn distance_visible_within_range() {
+        assert!(distance_visible(Vec3::ZERO, Vec3::new(5.0, 0.0, 0.0), 10.0));
+    }
+
+    #[test]
+    fn distance_visible_out_of_range() {
+        assert!(!distance_visible(Vec3::ZERO, Vec3::new(50.0, 0.0, 0.0), 10.0));
+    }
+
+    #[test]
+    fn distance_visible_infinite_disables_culling() {
+        assert!(distance_visible(
+            Vec3::ZERO,
+            Vec3::new(1_000_000.0, 0.0, 0.0),
+            f32::INFINITY
+        ));
+    }
+
+    #[test]
+    fn cull_keeps_near_instance_and_drops_far_one() {
+        let stage = aabb_stage(&[Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, -500.0)]);
+        let cam = camera(Vec3::ZERO, 100.0);
+
+        let visible = stage.cull(&[cam]);
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0], vec![0]);
+    }
+
+    #[test]
+    fn cull_runs_multiple_cameras_independently() {
+        let stage = aabb_stage(&[Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, -50.0)]);
+        let near_cam = camera(Vec3::ZERO, 10.0);
+        let far_cam = camera(Vec3::ZERO, 100.0);
+
+        let visible = stage.cull(&[near_cam, far_cam]);
+        assert_eq!(visible[0], vec![0]);
+        assert_eq!(visible[1], vec![0, 1]);
+    }
+
+    #[test]
+    fn update_skips_entities_without_registered_meshes() {
+        let mut stage = VisibilityStage::new();
+        let registry = MeshRegistry::new();
+        let entities = [VisibilityEntity {
+            mesh: MeshHandle(1),
+            transform: Mat4::IDENTITY,
+            instance_index: 0,
+        }];
+
+        stage.update(&entities, &registry);
+        assert!(stage.aabbs().is_empty());
+    }
+}
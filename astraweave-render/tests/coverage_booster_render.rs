@@ -13331,6 +13331,7 @@ fn test_residency_hot_reload_wave26() {
                 dependencies: vec![],
                 last_modified: 12345,
                 size_bytes: 1024 * 1024,
+                audio: None,
             },
         );
     }
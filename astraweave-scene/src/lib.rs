@@ -12,6 +12,8 @@
 //! - **[`world_partition`]** — Spatial world partitioning for large open worlds.
 //! - **[`streaming`]** — Async cell streaming with distance-based loading.
 //! - **[`gpu_resource_manager`]** — GPU resource lifecycle management.
+//! - **[`spline`]** — Catmull-Rom/Bezier curves with arc-length parameterization
+//!   for moving platforms, camera dollies, and scripted NPC paths.
 //!
 //! # Feature Flags
 //!
@@ -26,6 +28,9 @@ use serde::{Deserialize, Serialize};
 pub mod error;
 pub mod gpu_resource_manager;
 pub mod partitioned_scene;
+#[cfg(feature = "ecs")]
+pub mod prefab;
+pub mod spline;
 pub mod streaming;
 pub mod world_partition;
 
@@ -408,6 +413,24 @@ pub mod ecs {
     #[derive(Clone, Debug)]
     pub struct CJointIndices(pub Vec<u32>);
 
+    /// Opaque physics collider descriptor attached by a prefab. This crate
+    /// doesn't depend on `astraweave-physics` (physics optionally depends on
+    /// `astraweave-scene`, not the other way around, to avoid a cycle), so a
+    /// collider is stored as a shape tag plus flat params rather than a real
+    /// physics type; a physics system resolves `CColliderDesc` into its own
+    /// collider representation when it sees one on a freshly spawned entity.
+    #[derive(Clone, Debug)]
+    pub struct CColliderDesc {
+        pub shape: String,
+        pub params: Vec<f32>,
+    }
+
+    /// Opaque reference to an AI behavior profile (e.g. an `astraweave-ai`
+    /// planner config name), resolved by the AI system rather than this
+    /// crate, for the same dependency-direction reason as [`CColliderDesc`].
+    #[derive(Clone, Debug)]
+    pub struct CAiProfile(pub String);
+
     // ========================================================================
     // Phase 2 Task 5: Skeletal Animation Components
     // ========================================================================
@@ -627,6 +650,51 @@ pub mod ecs {
         pub joint_index: usize,
     }
 
+    /// Named joint sockets (e.g. "weapon_r", "backpack") resolved by joint
+    /// name against a skeleton's imported joint list, kept on the skeleton
+    /// entity alongside [`CSkeleton`]. Resolving by name instead of a raw
+    /// index means an attachment survives joint reordering, and calling
+    /// [`Self::resolve`] again after a model hot-reload re-derives the
+    /// table from the new joint list.
+    #[derive(Clone, Debug, Default)]
+    pub struct CSkeletonSockets(pub BTreeMap<String, usize>);
+
+    impl CSkeletonSockets {
+        /// Resolve `socket_joint_names` (socket name -> imported joint name)
+        /// against `skeleton_joint_names`. Sockets whose joint name is not
+        /// found are dropped rather than erroring, since a hot-reloaded
+        /// model may simply no longer have that bone.
+        pub fn resolve(
+            socket_joint_names: &BTreeMap<String, String>,
+            skeleton_joint_names: &[String],
+        ) -> Self {
+            let mut sockets = BTreeMap::new();
+            for (socket, joint_name) in socket_joint_names {
+                if let Some(index) = skeleton_joint_names.iter().position(|n| n == joint_name) {
+                    sockets.insert(socket.clone(), index);
+                }
+            }
+            Self(sockets)
+        }
+
+        /// Joint index for a named socket, if it resolved.
+        pub fn joint_index(&self, socket_name: &str) -> Option<usize> {
+            self.0.get(socket_name).copied()
+        }
+    }
+
+    /// Attaches an entity to a named socket on a skeleton (e.g. a sword to
+    /// "weapon_r"), with a local offset transform for how it sits in the
+    /// socket. Resolved to a joint index each frame via the skeleton
+    /// entity's [`CSkeletonSockets`], so it keeps working across a hot
+    /// reload as long as the socket name is re-resolved.
+    #[derive(Clone, Debug)]
+    pub struct CSocketAttachment {
+        pub skeleton_entity: EntityId,
+        pub socket_name: String,
+        pub offset: Transform,
+    }
+
     /// Helper structure for managing scene graph operations
     pub struct SceneGraph;
 
@@ -673,6 +741,34 @@ pub mod ecs {
             Self::attach(world, child, new_parent);
         }
 
+        /// Despawn an entity together with every descendant in its subtree
+        /// (children, grandchildren, ...). Detaches from the parent first so
+        /// the parent's `CChildren` never retains a dangling reference, then
+        /// walks the subtree collecting every entity before despawning any
+        /// of them, so a cycle in `CChildren` (which should never happen,
+        /// but isn't structurally prevented by `attach`/`reparent`) can't
+        /// cause infinite recursion.
+        pub fn despawn_with_descendants(world: &mut EcsWorld, entity: EntityId) {
+            Self::detach(world, entity);
+
+            let mut subtree = Vec::new();
+            let mut visited = BTreeSet::new();
+            let mut stack = vec![entity];
+            while let Some(current) = stack.pop() {
+                if !visited.insert(current) {
+                    continue;
+                }
+                subtree.push(current);
+                if let Some(children) = world.get::<CChildren>(current) {
+                    stack.extend(children.0.clone());
+                }
+            }
+
+            for e in subtree {
+                world.despawn(e);
+            }
+        }
+
         /// Mark an entity and all descendants as dirty
         #[allow(dead_code)]
         fn mark_dirty_recursive(
@@ -879,6 +975,90 @@ pub mod ecs {
         }
     }
 
+    /// Named event fired during an animation's playback (footstep, hit-frame,
+    /// VFX cue), returned by [`update_animations_with_events`] for the caller
+    /// to forward into its own audio/VFX/gameplay event handling.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FiredAnimationEvent {
+        pub entity: EntityId,
+        pub name: String,
+    }
+
+    /// Same as [`update_animations`], but also fires each clip's authored
+    /// [`astraweave_asset::gltf_loader::AnimationEvent`]s whose time falls
+    /// within this frame's advance (`clip_events[i]` must correspond to
+    /// `clip_durations[i]`), so audio, VFX, and gameplay (attack hit
+    /// windows) can sync precisely with animation playback.
+    pub fn update_animations_with_events(
+        world: &mut EcsWorld,
+        dt: f32,
+        clip_durations: &[f32],
+        clip_events: &[Vec<astraweave_asset::gltf_loader::AnimationEvent>],
+    ) -> Vec<FiredAnimationEvent> {
+        let mut fired = Vec::new();
+
+        // Collect entities with animators
+        let mut entities = Vec::new();
+        world.each_mut::<CAnimator>(|e, _| entities.push(e));
+
+        for entity in entities {
+            if let Some(animator) = world.get_mut::<CAnimator>(entity) {
+                if animator.state != PlaybackState::Playing {
+                    continue;
+                }
+
+                let clip_duration = clip_durations
+                    .get(animator.clip_index)
+                    .copied()
+                    .unwrap_or(1.0);
+                let prev_time = animator.time;
+
+                // Advance time
+                animator.time += dt * animator.speed;
+
+                // Handle looping/clamping
+                let looped = if animator.looping {
+                    // Wrap around
+                    if animator.time > clip_duration {
+                        animator.time %= clip_duration;
+                    }
+                    if animator.time < 0.0 {
+                        animator.time = clip_duration + (animator.time % clip_duration);
+                    }
+                    animator.time < prev_time
+                } else {
+                    // Clamp and stop at end
+                    animator.time = animator.time.clamp(0.0, clip_duration);
+                    if animator.time >= clip_duration {
+                        animator.state = PlaybackState::Stopped;
+                    }
+                    false
+                };
+
+                if let Some(events) = clip_events.get(animator.clip_index) {
+                    for event in events {
+                        let crossed = if looped {
+                            event.time >= prev_time || event.time < animator.time
+                        } else {
+                            event.time >= prev_time && event.time < animator.time
+                        };
+                        if crossed {
+                            fired.push(FiredAnimationEvent {
+                                entity,
+                                name: event.name.clone(),
+                            });
+                        }
+                    }
+                }
+
+                // Mark for recomputation
+                world.insert(entity, CDirtyAnimation);
+            }
+        }
+
+        fired
+    }
+
     /// System to compute joint matrices from animation state
     /// This uses the animation sampling and joint matrix computation from astraweave-render
     /// Note: This is a stub - full implementation requires AnimationClip from render crate
@@ -967,6 +1147,269 @@ pub mod ecs {
             }
         }
     }
+
+    /// System to sync named socket attachments to scene graph nodes.
+    /// Like [`sync_bone_attachments`], but resolves the target joint by
+    /// name via [`CSkeletonSockets`] and applies the attachment's local
+    /// offset transform on top of the joint's world transform.
+    pub fn sync_socket_attachments(world: &mut EcsWorld) {
+        let mut attachments = Vec::new();
+        world.each_mut::<CSocketAttachment>(|e, _| attachments.push(e));
+
+        for entity in attachments {
+            let Some(attachment) = world.get::<CSocketAttachment>(entity).cloned() else {
+                continue;
+            };
+            let skeleton_entity = attachment.skeleton_entity;
+
+            let joint_index = world
+                .get::<CSkeletonSockets>(skeleton_entity)
+                .and_then(|sockets| sockets.joint_index(&attachment.socket_name));
+            let Some(joint_index) = joint_index else {
+                continue;
+            };
+
+            if let Some(matrices) = world.get::<CJointMatrices>(skeleton_entity) {
+                if joint_index < matrices.matrices.len() {
+                    let joint_world_matrix = matrices.matrices[joint_index] * attachment.offset.matrix();
+                    world.insert(entity, CTransformWorld(joint_world_matrix));
+
+                    if let Some(parent_comp) = world.get::<CParent>(entity) {
+                        let parent_world = world
+                            .get::<CTransformWorld>(parent_comp.0)
+                            .map(|t| t.0)
+                            .unwrap_or(Mat4::IDENTITY);
+                        let parent_inv = parent_world.inverse();
+                        let local_mat = parent_inv * joint_world_matrix;
+                        let (scale, rotation, translation) = local_mat.to_scale_rotation_translation();
+                        world.insert(
+                            entity,
+                            CTransformLocal(Transform {
+                                translation,
+                                rotation,
+                                scale,
+                            }),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // ========================================================================
+    // Spline Followers
+    // ========================================================================
+
+    /// Drives an entity's [`CTransformLocal`] along a [`crate::spline::Spline`]
+    /// at constant speed, looked up by index into the `splines` slice passed
+    /// to [`update_spline_followers`] (mirrors [`CAnimator::clip_index`]
+    /// indexing into `clip_durations`). Used for moving platforms, camera
+    /// dollies, and scripted NPC paths.
+    #[derive(Clone, Copy, Debug)]
+    pub struct CSplineFollower {
+        pub spline_index: usize,
+        /// Current position along the spline's arc length, in world units.
+        pub distance: f32,
+        /// Arc-length units per second.
+        pub speed: f32,
+        pub looping: bool,
+        /// If true, the entity's rotation is set to face the spline's
+        /// travel direction each frame.
+        pub orient_to_tangent: bool,
+    }
+
+    impl CSplineFollower {
+        pub fn new(spline_index: usize, speed: f32) -> Self {
+            Self {
+                spline_index,
+                distance: 0.0,
+                speed,
+                looping: true,
+                orient_to_tangent: false,
+            }
+        }
+    }
+
+    /// System to advance [`CSplineFollower`]s and write the sampled position
+    /// (and optionally orientation) to [`CTransformLocal`]. Call once per
+    /// frame with the current delta time.
+    pub fn update_spline_followers(
+        world: &mut EcsWorld,
+        dt: f32,
+        splines: &[crate::spline::Spline],
+    ) {
+        let mut entities = Vec::new();
+        world.each_mut::<CSplineFollower>(|e, _| entities.push(e));
+
+        for entity in entities {
+            let Some(mut follower) = world.get::<CSplineFollower>(entity).copied() else {
+                continue;
+            };
+            let Some(spline) = splines.get(follower.spline_index) else {
+                continue;
+            };
+
+            follower.distance += follower.speed * dt;
+            let total = spline.total_length();
+            if !follower.looping && total > 0.0 {
+                follower.distance = follower.distance.clamp(0.0, total);
+            }
+            let distance = follower.distance;
+            let orient_to_tangent = follower.orient_to_tangent;
+            world.insert(entity, follower);
+
+            let position = spline.sample_uniform(distance);
+            let rotation = if orient_to_tangent {
+                let tangent = spline.tangent_uniform(distance);
+                if tangent.length_squared() > 1e-6 {
+                    Quat::from_rotation_arc(Vec3::Z, tangent)
+                } else {
+                    Quat::IDENTITY
+                }
+            } else {
+                world
+                    .get::<CTransformLocal>(entity)
+                    .map(|t| t.0.rotation)
+                    .unwrap_or(Quat::IDENTITY)
+            };
+
+            let scale = world
+                .get::<CTransformLocal>(entity)
+                .map(|t| t.0.scale)
+                .unwrap_or(Vec3::ONE);
+
+            world.insert(
+                entity,
+                CTransformLocal(Transform {
+                    translation: position,
+                    rotation,
+                    scale,
+                }),
+            );
+            world.insert(entity, CDirtyTransform);
+        }
+    }
+
+    // ========================================================================
+    // Level of Detail
+    // ========================================================================
+
+    /// Per-entity mesh levels-of-detail, finest (index 0) to coarsest, and
+    /// the distance thresholds between them. `thresholds[i]` is where level
+    /// `i` gives way to level `i + 1`, sorted ascending; `fade_band` is the
+    /// width of the distance band around each threshold over which two
+    /// adjacent levels cross-fade instead of popping (zero disables
+    /// cross-fading).
+    #[derive(Clone, Debug)]
+    pub struct CLevelOfDetail {
+        pub mesh_levels: Vec<u32>,
+        pub thresholds: Vec<f32>,
+        pub fade_band: f32,
+    }
+
+    /// Written by [`update_level_of_detail`] alongside [`CMesh`] when the
+    /// entity is inside a cross-fade band between two LOD levels. Absent
+    /// (or `next_mesh: None`) means draw only [`CMesh`] at full opacity.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct CLodBlend {
+        pub next_mesh: Option<u32>,
+        pub blend: f32,
+    }
+
+    /// Picks a LOD level (and optional cross-fade target) for `distance`
+    /// given ascending `thresholds` and a `fade_band` centered on each one.
+    /// A level only counts as "reached" once `distance` has cleared the
+    /// full fade band past its threshold, so the returned level stays the
+    /// finer one for the whole fade-in of the coarser one.
+    fn select_lod_level(distance: f32, thresholds: &[f32], fade_band: f32) -> (usize, Option<usize>, f32) {
+        let half_band = fade_band.max(0.0) * 0.5;
+        let level = thresholds
+            .iter()
+            .take_while(|&&t| distance >= t + half_band)
+            .count();
+        let last_level = thresholds.len();
+        if level >= last_level {
+            return (last_level, None, 0.0);
+        }
+
+        let threshold = thresholds[level];
+        let fade_start = threshold - half_band;
+        if half_band <= 0.0 || distance < fade_start {
+            return (level, None, 0.0);
+        }
+
+        let fade_end = threshold + half_band;
+        let blend = ((distance - fade_start) / (fade_end - fade_start)).clamp(0.0, 1.0);
+        (level, Some(level + 1), blend)
+    }
+
+    /// Selects each [`CLevelOfDetail`] entity's active mesh based on
+    /// distance from `camera_pos`, writing the result to [`CMesh`] and
+    /// [`CLodBlend`]. Call once per frame after world transforms are up to
+    /// date.
+    pub fn update_level_of_detail(world: &mut EcsWorld, camera_pos: Vec3) {
+        let mut entities = Vec::new();
+        world.each_mut::<CLevelOfDetail>(|e, _| entities.push(e));
+
+        for entity in entities {
+            let Some(lod) = world.get::<CLevelOfDetail>(entity).cloned() else {
+                continue;
+            };
+
+            let position = world
+                .get::<CTransformWorld>(entity)
+                .map(|t| t.0.w_axis.truncate())
+                .unwrap_or(Vec3::ZERO);
+            let distance = camera_pos.distance(position);
+            let (level, next_level, blend) =
+                select_lod_level(distance, &lod.thresholds, lod.fade_band);
+
+            let Some(&mesh) = lod.mesh_levels.get(level) else {
+                continue;
+            };
+            world.insert(entity, CMesh(mesh));
+
+            let next_mesh = next_level.and_then(|level| lod.mesh_levels.get(level).copied());
+            world.insert(entity, CLodBlend { next_mesh, blend });
+        }
+    }
+
+    #[cfg(test)]
+    mod lod_tests {
+        use super::select_lod_level;
+
+        #[test]
+        fn near_distance_selects_finest_level_with_no_fade() {
+            let (level, next, blend) = select_lod_level(2.0, &[10.0, 30.0], 4.0);
+            assert_eq!(level, 0);
+            assert_eq!(next, None);
+            assert_eq!(blend, 0.0);
+        }
+
+        #[test]
+        fn far_distance_selects_coarsest_level_with_no_fade() {
+            let (level, next, blend) = select_lod_level(1000.0, &[10.0, 30.0], 4.0);
+            assert_eq!(level, 2);
+            assert_eq!(next, None);
+            assert_eq!(blend, 0.0);
+        }
+
+        #[test]
+        fn distance_inside_fade_band_blends_toward_next_level() {
+            let (level, next, blend) = select_lod_level(9.0, &[10.0, 30.0], 4.0);
+            assert_eq!(level, 0);
+            assert_eq!(next, Some(1));
+            assert!((blend - 0.25).abs() < 1e-4, "expected ~0.25, got {blend}");
+        }
+
+        #[test]
+        fn zero_fade_band_pops_without_blending() {
+            let just_before = select_lod_level(9.9, &[10.0], 0.0);
+            let just_after = select_lod_level(10.0, &[10.0], 0.0);
+            assert_eq!(just_before, (0, None, 0.0));
+            assert_eq!(just_after, (1, None, 0.0));
+        }
+    }
 }
 
 #[cfg(not(feature = "ecs"))]
@@ -2328,6 +2328,75 @@ mod ecs_system_mutation_tests {
         assert!(c.0.contains(&child));
     }
 
+    #[test]
+    fn mutation_despawn_with_descendants_removes_whole_subtree() {
+        let mut world = EcsWorld::new();
+        let grandparent = world.spawn();
+        let parent = world.spawn();
+        let child = world.spawn();
+
+        world.insert(grandparent, CTransformLocal(Transform::identity()));
+        world.insert(parent, CTransformLocal(Transform::identity()));
+        world.insert(child, CTransformLocal(Transform::identity()));
+
+        SceneGraph::attach(&mut world, parent, grandparent);
+        SceneGraph::attach(&mut world, child, parent);
+
+        SceneGraph::despawn_with_descendants(&mut world, parent);
+
+        assert!(
+            world.get::<CTransformLocal>(parent).is_none(),
+            "parent must be despawned"
+        );
+        assert!(
+            world.get::<CTransformLocal>(child).is_none(),
+            "descendant must be despawned along with its ancestor"
+        );
+    }
+
+    #[test]
+    fn mutation_despawn_with_descendants_detaches_from_parent() {
+        let mut world = EcsWorld::new();
+        let grandparent = world.spawn();
+        let parent = world.spawn();
+
+        world.insert(grandparent, CTransformLocal(Transform::identity()));
+        world.insert(parent, CTransformLocal(Transform::identity()));
+
+        SceneGraph::attach(&mut world, parent, grandparent);
+        SceneGraph::despawn_with_descendants(&mut world, parent);
+
+        let c = world.get::<CChildren>(grandparent).unwrap();
+        assert!(
+            !c.0.contains(&parent),
+            "grandparent must not retain a reference to the despawned subtree root"
+        );
+    }
+
+    #[test]
+    fn mutation_despawn_with_descendants_leaves_siblings_intact() {
+        let mut world = EcsWorld::new();
+        let parent = world.spawn();
+        let child_a = world.spawn();
+        let child_b = world.spawn();
+
+        world.insert(parent, CTransformLocal(Transform::identity()));
+        world.insert(child_a, CTransformLocal(Transform::identity()));
+        world.insert(child_b, CTransformLocal(Transform::identity()));
+
+        SceneGraph::attach(&mut world, child_a, parent);
+        SceneGraph::attach(&mut world, child_b, parent);
+
+        SceneGraph::despawn_with_descendants(&mut world, child_a);
+
+        assert!(
+            world.get::<CTransformLocal>(child_b).is_some(),
+            "sibling of the despawned entity must survive"
+        );
+        let c = world.get::<CChildren>(parent).unwrap();
+        assert!(c.0.contains(&child_b) && !c.0.contains(&child_a));
+    }
+
     // ── update_world_transforms ──
 
     #[test]
@@ -2624,6 +2693,80 @@ mod ecs_system_mutation_tests {
         );
     }
 
+    // ── update_animations_with_events ──
+
+    #[test]
+    fn mutation_update_animations_with_events_fires_event_crossed_this_frame() {
+        use astraweave_asset::gltf_loader::AnimationEvent;
+
+        let mut world = EcsWorld::new();
+        let entity = world.spawn();
+        let mut animator = CAnimator::new(0);
+        animator.play();
+        world.insert(entity, animator);
+
+        let clip_durations = [10.0];
+        let clip_events = [vec![AnimationEvent {
+            name: "footstep_l".to_string(),
+            time: 0.3,
+        }]];
+
+        let fired = update_animations_with_events(&mut world, 0.5, &clip_durations, &clip_events);
+
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].entity, entity);
+        assert_eq!(fired[0].name, "footstep_l");
+    }
+
+    #[test]
+    fn mutation_update_animations_with_events_does_not_refire_before_reached() {
+        use astraweave_asset::gltf_loader::AnimationEvent;
+
+        let mut world = EcsWorld::new();
+        let entity = world.spawn();
+        let mut animator = CAnimator::new(0);
+        animator.play();
+        world.insert(entity, animator);
+
+        let clip_durations = [10.0];
+        let clip_events = [vec![AnimationEvent {
+            name: "footstep_l".to_string(),
+            time: 5.0,
+        }]];
+
+        let fired = update_animations_with_events(&mut world, 0.5, &clip_durations, &clip_events);
+        assert!(
+            fired.is_empty(),
+            "event at t=5.0 should not fire when only advancing to t=0.5"
+        );
+    }
+
+    #[test]
+    fn mutation_update_animations_with_events_fires_on_loop_wraparound() {
+        use astraweave_asset::gltf_loader::AnimationEvent;
+
+        let mut world = EcsWorld::new();
+        let entity = world.spawn();
+        let mut animator = CAnimator::new(0).with_looping(true);
+        animator.play();
+        animator.time = 9.5; // advance by 1.0 → wraps to 0.5
+        world.insert(entity, animator);
+
+        let clip_durations = [10.0];
+        let clip_events = [vec![AnimationEvent {
+            name: "loop_point".to_string(),
+            time: 0.0,
+        }]];
+
+        let fired = update_animations_with_events(&mut world, 1.0, &clip_durations, &clip_events);
+
+        assert_eq!(
+            fired.len(),
+            1,
+            "event at the loop seam must fire once when playback wraps"
+        );
+    }
+
     // ── Animation boundary/edge-case tests for mutation hardening ──
 
     #[test]
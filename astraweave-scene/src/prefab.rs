@@ -0,0 +1,367 @@
+//! Data-driven prefab format: a serializable entity bundle (transform,
+//! mesh/material references, an opaque physics collider descriptor, an
+//! opaque AI profile reference, and nested children) that can be registered
+//! by GUID in a [`PrefabRegistry`] and instantiated into an ECS `World` with
+//! [`PrefabRegistry::spawn`]. A child can either be inlined directly in the
+//! parent's RON/TOML, or reference another registered prefab by GUID so
+//! common sub-assemblies (a weapon, a wheel) are defined once and reused.
+//!
+//! [`PrefabDef`] deliberately doesn't carry physics or AI *types* — this
+//! crate can't depend on `astraweave-physics`/`astraweave-ai` without
+//! creating a dependency cycle (physics optionally depends on this crate),
+//! so colliders and AI profiles are stored on [`crate::ecs::CColliderDesc`]/
+//! [`crate::ecs::CAiProfile`] as opaque descriptors for those systems to
+//! resolve later.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use glam::{Quat, Vec3};
+use serde::{Deserialize, Serialize};
+
+use astraweave_ecs::{Entity as EntityId, World as EcsWorld};
+
+use crate::ecs::{CAiProfile, CColliderDesc, CMaterial, CMesh, CTransformLocal, SceneGraph};
+use crate::Transform;
+
+/// A physics collider slot on a [`PrefabEntity`], carried through to
+/// [`CColliderDesc`] on spawn.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrefabCollider {
+    pub shape: String,
+    #[serde(default)]
+    pub params: Vec<f32>,
+}
+
+/// A child slot within a [`PrefabEntity`]: either an inline entity bundle,
+/// or a reference to another registered prefab instantiated in place.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum PrefabChild {
+    Inline(PrefabEntity),
+    PrefabRef {
+        guid: String,
+        #[serde(default)]
+        overrides: PrefabOverrides,
+    },
+}
+
+/// One entity within a [`PrefabDef`]: its local transform plus optional
+/// rendering/physics/AI component data, and nested children.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PrefabEntity {
+    #[serde(default)]
+    pub transform: Transform,
+    #[serde(default)]
+    pub mesh: Option<u32>,
+    #[serde(default)]
+    pub material: Option<u32>,
+    #[serde(default)]
+    pub collider: Option<PrefabCollider>,
+    #[serde(default)]
+    pub ai_profile: Option<String>,
+    #[serde(default)]
+    pub children: Vec<PrefabChild>,
+}
+
+/// Partial overrides applied to the root entity of a [`PrefabRegistry::spawn_with_overrides`]
+/// call, or to a nested [`PrefabChild::PrefabRef`]. Unset fields fall back to
+/// the referenced prefab's own values.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PrefabOverrides {
+    pub translation: Option<Vec3>,
+    pub rotation: Option<Quat>,
+    pub scale: Option<Vec3>,
+    pub mesh: Option<u32>,
+    pub material: Option<u32>,
+}
+
+impl PrefabOverrides {
+    fn apply(&self, entity: &PrefabEntity) -> PrefabEntity {
+        let mut result = entity.clone();
+        if let Some(translation) = self.translation {
+            result.transform.translation = translation;
+        }
+        if let Some(rotation) = self.rotation {
+            result.transform.rotation = rotation;
+        }
+        if let Some(scale) = self.scale {
+            result.transform.scale = scale;
+        }
+        if self.mesh.is_some() {
+            result.mesh = self.mesh;
+        }
+        if self.material.is_some() {
+            result.material = self.material;
+        }
+        result
+    }
+}
+
+/// A named, serializable entity bundle. The root's `guid` is assigned by the
+/// [`PrefabRegistry`] it's registered under, not stored on the definition
+/// itself, so the same [`PrefabDef`] can be loaded under different GUIDs
+/// (e.g. while iterating on a duplicate during authoring).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrefabDef {
+    pub name: String,
+    pub root: PrefabEntity,
+}
+
+impl PrefabDef {
+    /// Parses a prefab definition from RON text.
+    pub fn from_ron_str(s: &str) -> Result<Self> {
+        ron::from_str(s).context("invalid prefab RON")
+    }
+
+    /// Serializes this prefab definition to pretty-printed RON.
+    pub fn to_ron_string(&self) -> Result<String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .context("failed to serialize prefab to RON")
+    }
+
+    /// Parses a prefab definition from TOML text.
+    pub fn from_toml_str(s: &str) -> Result<Self> {
+        toml::from_str(s).context("invalid prefab TOML")
+    }
+
+    /// Serializes this prefab definition to pretty-printed TOML.
+    pub fn to_toml_string(&self) -> Result<String> {
+        toml::to_string_pretty(self).context("failed to serialize prefab to TOML")
+    }
+}
+
+/// Registry of [`PrefabDef`]s keyed by GUID, with a `spawn` API that
+/// instantiates a prefab (and its nested children/prefab references) into an
+/// ECS `World`.
+#[derive(Default)]
+pub struct PrefabRegistry {
+    prefabs: HashMap<String, PrefabDef>,
+}
+
+impl PrefabRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `def` under `guid`, replacing any prefab already registered
+    /// under that GUID.
+    pub fn register(&mut self, guid: impl Into<String>, def: PrefabDef) {
+        self.prefabs.insert(guid.into(), def);
+    }
+
+    /// Returns the prefab definition registered under `guid`, if any.
+    pub fn get(&self, guid: &str) -> Option<&PrefabDef> {
+        self.prefabs.get(guid)
+    }
+
+    /// Instantiates the prefab registered under `guid` with no overrides.
+    pub fn spawn(&self, world: &mut EcsWorld, guid: &str) -> Result<EntityId> {
+        self.spawn_with_overrides(world, guid, &PrefabOverrides::default())
+    }
+
+    /// Instantiates the prefab registered under `guid`, applying `overrides`
+    /// to its root entity only — nested children keep their authored values
+    /// unless they're themselves a [`PrefabChild::PrefabRef`] with its own
+    /// `overrides`.
+    pub fn spawn_with_overrides(
+        &self,
+        world: &mut EcsWorld,
+        guid: &str,
+        overrides: &PrefabOverrides,
+    ) -> Result<EntityId> {
+        let def = self
+            .prefabs
+            .get(guid)
+            .with_context(|| format!("no prefab registered under guid '{guid}'"))?;
+        let root = overrides.apply(&def.root);
+        self.instantiate_entity(world, &root)
+    }
+
+    fn instantiate_entity(&self, world: &mut EcsWorld, entity_def: &PrefabEntity) -> Result<EntityId> {
+        let entity = world.spawn();
+        world.insert(entity, CTransformLocal(entity_def.transform));
+        if let Some(mesh) = entity_def.mesh {
+            world.insert(entity, CMesh(mesh));
+        }
+        if let Some(material) = entity_def.material {
+            world.insert(entity, CMaterial(material));
+        }
+        if let Some(collider) = &entity_def.collider {
+            world.insert(
+                entity,
+                CColliderDesc {
+                    shape: collider.shape.clone(),
+                    params: collider.params.clone(),
+                },
+            );
+        }
+        if let Some(profile) = &entity_def.ai_profile {
+            world.insert(entity, CAiProfile(profile.clone()));
+        }
+
+        for child in &entity_def.children {
+            let child_entity = match child {
+                PrefabChild::Inline(child_def) => self.instantiate_entity(world, child_def)?,
+                PrefabChild::PrefabRef { guid, overrides } => {
+                    self.spawn_with_overrides(world, guid, overrides)?
+                }
+            };
+            SceneGraph::attach(world, child_entity, entity);
+        }
+
+        Ok(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple_prefab() -> PrefabDef {
+        PrefabDef {
+            name: "Barrel".into(),
+            root: PrefabEntity {
+                transform: Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+                mesh: Some(7),
+                material: Some(2),
+                collider: Some(PrefabCollider {
+                    shape: "capsule".into(),
+                    params: vec![0.5, 1.2],
+                }),
+                ai_profile: None,
+                children: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn ron_round_trip_preserves_fields() {
+        let def = simple_prefab();
+        let ron = def.to_ron_string().expect("serialize");
+        let parsed = PrefabDef::from_ron_str(&ron).expect("deserialize");
+        assert_eq!(parsed.name, "Barrel");
+        assert_eq!(parsed.root.mesh, Some(7));
+        assert_eq!(parsed.root.collider.unwrap().shape, "capsule");
+    }
+
+    #[test]
+    fn toml_round_trip_preserves_fields() {
+        let def = simple_prefab();
+        let toml_str = def.to_toml_string().expect("serialize");
+        let parsed = PrefabDef::from_toml_str(&toml_str).expect("deserialize");
+        assert_eq!(parsed.name, "Barrel");
+        assert_eq!(parsed.root.material, Some(2));
+    }
+
+    #[test]
+    fn spawn_instantiates_root_components() {
+        let mut registry = PrefabRegistry::new();
+        registry.register("barrel", simple_prefab());
+
+        let mut world = EcsWorld::new();
+        let entity = registry.spawn(&mut world, "barrel").expect("spawn");
+
+        assert_eq!(
+            world.get::<CTransformLocal>(entity).unwrap().0.translation,
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(world.get::<CMesh>(entity).unwrap().0, 7);
+        assert_eq!(world.get::<CColliderDesc>(entity).unwrap().shape, "capsule");
+    }
+
+    #[test]
+    fn spawn_missing_guid_errors() {
+        let registry = PrefabRegistry::new();
+        let mut world = EcsWorld::new();
+        assert!(registry.spawn(&mut world, "does-not-exist").is_err());
+    }
+
+    #[test]
+    fn spawn_with_overrides_replaces_root_transform_and_mesh() {
+        let mut registry = PrefabRegistry::new();
+        registry.register("barrel", simple_prefab());
+
+        let mut world = EcsWorld::new();
+        let overrides = PrefabOverrides {
+            translation: Some(Vec3::new(5.0, 5.0, 5.0)),
+            mesh: Some(99),
+            ..Default::default()
+        };
+        let entity = registry
+            .spawn_with_overrides(&mut world, "barrel", &overrides)
+            .expect("spawn");
+
+        assert_eq!(
+            world.get::<CTransformLocal>(entity).unwrap().0.translation,
+            Vec3::new(5.0, 5.0, 5.0)
+        );
+        assert_eq!(world.get::<CMesh>(entity).unwrap().0, 99);
+        // Unrelated fields are untouched by the partial override.
+        assert_eq!(world.get::<CMaterial>(entity).unwrap().0, 2);
+    }
+
+    #[test]
+    fn nested_inline_children_are_attached_to_the_parent() {
+        let mut registry = PrefabRegistry::new();
+        registry.register(
+            "cart",
+            PrefabDef {
+                name: "Cart".into(),
+                root: PrefabEntity {
+                    mesh: Some(1),
+                    children: vec![PrefabChild::Inline(PrefabEntity {
+                        transform: Transform::from_translation(Vec3::new(0.5, 0.0, 0.0)),
+                        mesh: Some(2),
+                        ..Default::default()
+                    })],
+                    ..Default::default()
+                },
+            },
+        );
+
+        let mut world = EcsWorld::new();
+        let parent = registry.spawn(&mut world, "cart").expect("spawn");
+
+        let children = world.get::<crate::ecs::CChildren>(parent).unwrap();
+        assert_eq!(children.0.len(), 1);
+        let child = children.0[0];
+        assert_eq!(world.get::<CMesh>(child).unwrap().0, 2);
+    }
+
+    #[test]
+    fn nested_prefab_ref_instantiates_and_attaches_the_referenced_prefab() {
+        let mut registry = PrefabRegistry::new();
+        registry.register("wheel", simple_prefab());
+        registry.register(
+            "cart",
+            PrefabDef {
+                name: "Cart".into(),
+                root: PrefabEntity {
+                    mesh: Some(1),
+                    children: vec![PrefabChild::PrefabRef {
+                        guid: "wheel".into(),
+                        overrides: PrefabOverrides {
+                            translation: Some(Vec3::new(2.0, 0.0, 0.0)),
+                            ..Default::default()
+                        },
+                    }],
+                    ..Default::default()
+                },
+            },
+        );
+
+        let mut world = EcsWorld::new();
+        let parent = registry.spawn(&mut world, "cart").expect("spawn");
+
+        let children = world.get::<crate::ecs::CChildren>(parent).unwrap();
+        assert_eq!(children.0.len(), 1);
+        let wheel = children.0[0];
+        assert_eq!(
+            world.get::<CTransformLocal>(wheel).unwrap().0.translation,
+            Vec3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(world.get::<CColliderDesc>(wheel).unwrap().shape, "capsule");
+    }
+}
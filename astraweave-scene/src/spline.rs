@@ -0,0 +1,328 @@
+//! General-purpose spline curves (Catmull-Rom / Bezier) with arc-length
+//! parameterization, so followers can move along a path at a constant
+//! speed instead of drifting with the underlying parametric curve's
+//! uneven segment spacing. Shared by moving platforms, camera dollies, and
+//! scripted NPC paths — [`ecs::CSplineFollower`] is the ECS-facing system
+//! built on top of it.
+
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+/// How [`Spline::points`] are interpolated between control points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SplineMode {
+    /// Passes through every point; tangents are derived from neighboring
+    /// points, so authoring only needs positions.
+    CatmullRom,
+    /// Cubic Bezier; uses each point's authored `out_tangent`/next point's
+    /// `in_tangent` as control handles.
+    Bezier,
+}
+
+/// A single authored control point on a [`Spline`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SplinePoint {
+    pub position: Vec3,
+    /// Incoming Bezier handle, relative to `position`. Ignored in
+    /// [`SplineMode::CatmullRom`].
+    pub in_tangent: Vec3,
+    /// Outgoing Bezier handle, relative to `position`. Ignored in
+    /// [`SplineMode::CatmullRom`].
+    pub out_tangent: Vec3,
+}
+
+impl SplinePoint {
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            in_tangent: Vec3::ZERO,
+            out_tangent: Vec3::ZERO,
+        }
+    }
+}
+
+const ARC_LENGTH_SAMPLES_PER_SEGMENT: usize = 16;
+
+/// A piecewise curve through [`SplinePoint`]s with a precomputed arc-length
+/// table, so [`Self::sample_uniform`] can walk it at a constant speed
+/// regardless of how unevenly the control points are spaced.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Spline {
+    pub mode: SplineMode,
+    pub points: Vec<SplinePoint>,
+    pub closed: bool,
+    /// Cumulative arc length at each sample, parallel to `sample_params`.
+    #[serde(skip)]
+    sample_lengths: Vec<f32>,
+    /// Segment-space parameter (0..segment_count) at each sample.
+    #[serde(skip)]
+    sample_params: Vec<f32>,
+}
+
+impl Spline {
+    /// Builds a spline and its arc-length table from authored control
+    /// points. Needs at least 2 points to be sampleable.
+    pub fn new(mode: SplineMode, points: Vec<SplinePoint>, closed: bool) -> Self {
+        let mut spline = Self {
+            mode,
+            points,
+            closed,
+            sample_lengths: Vec::new(),
+            sample_params: Vec::new(),
+        };
+        spline.rebuild_arc_length_table();
+        spline
+    }
+
+    /// Recomputes the arc-length table. Call this after mutating `points`,
+    /// `mode`, or `closed`.
+    pub fn rebuild_arc_length_table(&mut self) {
+        self.sample_lengths.clear();
+        self.sample_params.clear();
+
+        let segments = self.segment_count();
+        if segments == 0 {
+            return;
+        }
+
+        let mut cumulative = 0.0f32;
+        let mut prev = self.evaluate_param(0.0);
+        self.sample_lengths.push(0.0);
+        self.sample_params.push(0.0);
+
+        let total_samples = segments * ARC_LENGTH_SAMPLES_PER_SEGMENT;
+        for i in 1..=total_samples {
+            let t = (i as f32 / total_samples as f32) * segments as f32;
+            let point = self.evaluate_param(t);
+            cumulative += point.distance(prev);
+            self.sample_lengths.push(cumulative);
+            self.sample_params.push(t);
+            prev = point;
+        }
+    }
+
+    /// Number of interpolated segments between control points (0 if there
+    /// are fewer than 2 points).
+    pub fn segment_count(&self) -> usize {
+        if self.points.len() < 2 {
+            return 0;
+        }
+        if self.closed {
+            self.points.len()
+        } else {
+            self.points.len() - 1
+        }
+    }
+
+    /// Total arc length of the curve.
+    pub fn total_length(&self) -> f32 {
+        self.sample_lengths.last().copied().unwrap_or(0.0)
+    }
+
+    fn point_at(&self, index: i32) -> Vec3 {
+        let len = self.points.len() as i32;
+        let wrapped = if self.closed {
+            index.rem_euclid(len)
+        } else {
+            index.clamp(0, len - 1)
+        };
+        self.points[wrapped as usize].position
+    }
+
+    /// Evaluates the curve at segment-space parameter `t` (0..segment_count).
+    fn evaluate_param(&self, t: f32) -> Vec3 {
+        let segments = self.segment_count();
+        if segments == 0 {
+            return self.points.first().map(|p| p.position).unwrap_or(Vec3::ZERO);
+        }
+        let t = t.clamp(0.0, segments as f32);
+        let segment = (t as usize).min(segments - 1);
+        let local_t = t - segment as f32;
+
+        match self.mode {
+            SplineMode::CatmullRom => {
+                let p0 = self.point_at(segment as i32 - 1);
+                let p1 = self.point_at(segment as i32);
+                let p2 = self.point_at(segment as i32 + 1);
+                let p3 = self.point_at(segment as i32 + 2);
+                catmull_rom(p0, p1, p2, p3, local_t)
+            }
+            SplineMode::Bezier => {
+                let start = &self.points[segment];
+                let end_index = if self.closed {
+                    (segment + 1) % self.points.len()
+                } else {
+                    segment + 1
+                };
+                let end = &self.points[end_index];
+                let c0 = start.position;
+                let c1 = start.position + start.out_tangent;
+                let c2 = end.position + end.in_tangent;
+                let c3 = end.position;
+                cubic_bezier(c0, c1, c2, c3, local_t)
+            }
+        }
+    }
+
+    /// Samples the curve at `distance` along its arc length, wrapping for
+    /// closed splines and clamping to the endpoints otherwise.
+    pub fn sample_uniform(&self, distance: f32) -> Vec3 {
+        let t = self.param_at_distance(distance);
+        self.evaluate_param(t)
+    }
+
+    /// Forward direction of travel at `distance` along the arc, useful for
+    /// orienting a follower to face along the path.
+    pub fn tangent_uniform(&self, distance: f32) -> Vec3 {
+        let t = self.param_at_distance(distance);
+        let epsilon = 0.01;
+        let ahead = self.evaluate_param((t + epsilon).min(self.segment_count() as f32));
+        let behind = self.evaluate_param((t - epsilon).max(0.0));
+        (ahead - behind).normalize_or_zero()
+    }
+
+    fn param_at_distance(&self, distance: f32) -> f32 {
+        if self.sample_lengths.is_empty() {
+            return 0.0;
+        }
+        let total = self.total_length();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let distance = if self.closed {
+            distance.rem_euclid(total)
+        } else {
+            distance.clamp(0.0, total)
+        };
+
+        match self
+            .sample_lengths
+            .binary_search_by(|len| len.partial_cmp(&distance).unwrap())
+        {
+            Ok(i) => self.sample_params[i],
+            Err(0) => self.sample_params[0],
+            Err(i) if i >= self.sample_lengths.len() => *self.sample_params.last().unwrap(),
+            Err(i) => {
+                let lo = self.sample_lengths[i - 1];
+                let hi = self.sample_lengths[i];
+                let local_t = if hi > lo {
+                    (distance - lo) / (hi - lo)
+                } else {
+                    0.0
+                };
+                self.sample_params[i - 1] + local_t * (self.sample_params[i] - self.sample_params[i - 1])
+            }
+        }
+    }
+}
+
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * ((2.0 * p1)
+        + (-p0 + p2) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t3)
+}
+
+fn cubic_bezier(c0: Vec3, c1: Vec3, c2: Vec3, c3: Vec3, t: f32) -> Vec3 {
+    let u = 1.0 - t;
+    u * u * u * c0 + 3.0 * u * u * t * c1 + 3.0 * u * t * t * c2 + t * t * t * c3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_points() -> Vec<SplinePoint> {
+        vec![
+            SplinePoint::new(Vec3::new(0.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(10.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(20.0, 0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_control_points() {
+        let spline = Spline::new(SplineMode::CatmullRom, line_points(), false);
+        assert!(spline.sample_uniform(0.0).distance(Vec3::ZERO) < 0.01);
+        assert!(spline
+            .sample_uniform(spline.total_length())
+            .distance(Vec3::new(20.0, 0.0, 0.0))
+            < 0.01);
+    }
+
+    #[test]
+    fn sample_uniform_is_actually_arc_length_uniform() {
+        // Uneven point spacing: [0,0,0] -> [1,0,0] -> [21,0,0]
+        let points = vec![
+            SplinePoint::new(Vec3::new(0.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(1.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(21.0, 0.0, 0.0)),
+        ];
+        let spline = Spline::new(SplineMode::CatmullRom, points, false);
+        let total = spline.total_length();
+
+        // Walking half the arc length should land roughly halfway along x,
+        // not halfway through parameter space (which would land near x=1).
+        let midpoint = spline.sample_uniform(total * 0.5);
+        assert!(
+            midpoint.x > 5.0,
+            "arc-length sampling should not bunch up near the short segment, got x={}",
+            midpoint.x
+        );
+    }
+
+    #[test]
+    fn closed_spline_wraps_distance() {
+        let points = vec![
+            SplinePoint::new(Vec3::new(0.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(10.0, 0.0, 0.0)),
+            SplinePoint::new(Vec3::new(10.0, 0.0, 10.0)),
+            SplinePoint::new(Vec3::new(0.0, 0.0, 10.0)),
+        ];
+        let spline = Spline::new(SplineMode::CatmullRom, points, true);
+        let total = spline.total_length();
+        let at_zero = spline.sample_uniform(0.0);
+        let wrapped = spline.sample_uniform(total * 3.0 + 1.0);
+        let one_in = spline.sample_uniform(1.0);
+        assert!(wrapped.distance(one_in) < 0.5);
+        let _ = at_zero;
+    }
+
+    #[test]
+    fn bezier_matches_endpoints() {
+        let mut points = line_points();
+        points[0].out_tangent = Vec3::new(2.0, 0.0, 0.0);
+        points[1].in_tangent = Vec3::new(-2.0, 0.0, 0.0);
+        points[1].out_tangent = Vec3::new(2.0, 0.0, 0.0);
+        points[2].in_tangent = Vec3::new(-2.0, 0.0, 0.0);
+        let spline = Spline::new(SplineMode::Bezier, points, false);
+        assert!(spline.sample_uniform(0.0).distance(Vec3::ZERO) < 0.01);
+        assert!(
+            spline
+                .sample_uniform(spline.total_length())
+                .distance(Vec3::new(20.0, 0.0, 0.0))
+                < 0.01
+        );
+    }
+
+    #[test]
+    fn tangent_uniform_points_along_travel_direction() {
+        let spline = Spline::new(SplineMode::CatmullRom, line_points(), false);
+        let tangent = spline.tangent_uniform(5.0);
+        assert!(tangent.x > 0.9, "expected forward tangent, got {:?}", tangent);
+    }
+
+    #[test]
+    fn empty_and_single_point_splines_do_not_panic() {
+        let empty = Spline::new(SplineMode::CatmullRom, vec![], false);
+        assert_eq!(empty.total_length(), 0.0);
+        assert_eq!(empty.sample_uniform(5.0), Vec3::ZERO);
+
+        let single = Spline::new(SplineMode::CatmullRom, vec![SplinePoint::new(Vec3::ONE)], false);
+        assert_eq!(single.total_length(), 0.0);
+        assert_eq!(single.sample_uniform(5.0), Vec3::ONE);
+    }
+}
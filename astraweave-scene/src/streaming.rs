@@ -7,7 +7,7 @@ use crate::world_partition::{CellEntityBlueprint, CellState, GridCoord, LRUCache
 use anyhow::Result;
 use std::collections::HashSet;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
 
 /// Events emitted by the streaming system
 #[derive(Debug, Clone)]
@@ -31,6 +31,11 @@ pub struct StreamingConfig {
     pub streaming_radius: f32,
     /// Maximum concurrent loading tasks
     pub max_concurrent_loads: usize,
+    /// Maximum number of already-loaded cells activated into the world per
+    /// [`WorldPartitionManager::update`] call. Bounds the per-frame cost of
+    /// spawning entities for cells that finished loading, independent of
+    /// `max_concurrent_loads` (which only bounds concurrent async loads).
+    pub max_activations_per_update: usize,
 }
 
 impl Default for StreamingConfig {
@@ -40,6 +45,7 @@ impl Default for StreamingConfig {
             lru_cache_size: 5,
             streaming_radius: 500.0, // 500 meters
             max_concurrent_loads: 4,
+            max_activations_per_update: 4,
         }
     }
 }
@@ -66,10 +72,17 @@ pub struct WorldPartitionManager {
     loading_cells: HashSet<GridCoord>,
     metrics: StreamingMetrics,
     event_listeners: Vec<Box<dyn Fn(StreamingEvent) + Send + Sync>>,
+    /// Background loads report back here; [`Self::update`] drains at most
+    /// `config.max_activations_per_update` of these per call, so a burst of
+    /// loads finishing at once doesn't spawn every cell's entities in a
+    /// single frame.
+    load_result_tx: mpsc::UnboundedSender<(GridCoord, std::result::Result<(), String>)>,
+    load_result_rx: mpsc::UnboundedReceiver<(GridCoord, std::result::Result<(), String>)>,
 }
 
 impl WorldPartitionManager {
     pub fn new(partition: Arc<RwLock<WorldPartition>>, config: StreamingConfig) -> Self {
+        let (load_result_tx, load_result_rx) = mpsc::unbounded_channel();
         Self {
             partition,
             lru_cache: LRUCache::new(config.lru_cache_size),
@@ -78,6 +91,8 @@ impl WorldPartitionManager {
             loading_cells: HashSet::new(),
             metrics: StreamingMetrics::default(),
             event_listeners: Vec::new(),
+            load_result_tx,
+            load_result_rx,
         }
     }
 
@@ -103,17 +118,25 @@ impl WorldPartitionManager {
         // Determine which cells should be active based on camera position
         let desired_cells =
             partition.cells_in_radius(camera_position, self.config.streaming_radius);
+        let cell_size = partition.config.cell_size;
 
         drop(partition); // Release read lock
 
-        // Cells to load (in desired but not active/loading)
-        let to_load: Vec<GridCoord> = desired_cells
+        // Cells to load (in desired but not active/loading), nearest first so
+        // a saturated concurrent-load budget fills in with the cells the
+        // camera needs soonest rather than in arbitrary hash-set order.
+        let mut to_load: Vec<GridCoord> = desired_cells
             .iter()
             .filter(|coord| {
                 !self.active_cells.contains(coord) && !self.loading_cells.contains(coord)
             })
             .copied()
             .collect();
+        to_load.sort_by(|a, b| {
+            let da = a.to_world_center(cell_size).distance_squared(camera_position);
+            let db = b.to_world_center(cell_size).distance_squared(camera_position);
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        });
 
         // Cells to unload (in active but not desired)
         let to_unload: Vec<GridCoord> = self
@@ -132,6 +155,23 @@ impl WorldPartitionManager {
             self.start_load_cell(coord).await?;
         }
 
+        // Activate cells whose background load finished, up to this update's
+        // activation budget; anything left in the channel is picked up next call.
+        let mut activated = 0;
+        while activated < self.config.max_activations_per_update {
+            match self.load_result_rx.try_recv() {
+                Ok((coord, Ok(()))) => {
+                    self.finish_load_cell(coord).await?;
+                    activated += 1;
+                }
+                Ok((coord, Err(error))) => {
+                    self.handle_load_failure(coord, error).await;
+                    activated += 1;
+                }
+                Err(_) => break,
+            }
+        }
+
         // Unload cells that are out of range
         for coord in to_unload {
             self.unload_cell(coord).await?;
@@ -174,6 +214,7 @@ impl WorldPartitionManager {
         // Spawn actual async loading task
         let partition_clone = Arc::clone(&self.partition);
         let coord_clone = coord;
+        let result_tx = self.load_result_tx.clone();
 
         tokio::spawn(async move {
             // Construct cell file path
@@ -238,7 +279,7 @@ impl WorldPartitionManager {
                         }
                     }
 
-                    Ok::<(), anyhow::Error>(())
+                    let _ = result_tx.send((coord_clone, Ok(())));
                 }
                 Err(e) => {
                     // Handle load failure
@@ -246,13 +287,14 @@ impl WorldPartitionManager {
                     if let Some(cell) = partition.get_cell_mut(coord_clone) {
                         cell.state = CellState::Unloaded;
                     }
-                    Err(e)
+                    let _ = result_tx.send((coord_clone, Err(e.to_string())));
                 }
             }
         });
 
-        // The spawned task will handle updating cell state asynchronously
-        // Loading will complete in the background; check cell state later via partition.get_cell()
+        // The spawned task reports completion through `load_result_tx`;
+        // `update` activates it (moving loading_cells -> active_cells,
+        // emitting CellLoaded/CellLoadFailed) once its per-tick budget allows.
         Ok(())
     }
 
@@ -271,8 +313,8 @@ impl WorldPartitionManager {
         astraweave_asset::cell_loader::load_asset(asset_ref, assets_root).await
     }
 
-    /// Finish loading a cell (called after async load completes)
-    #[allow(dead_code)]
+    /// Finish loading a cell (called from `update` once its background load
+    /// completed and the per-tick activation budget allows it)
     async fn finish_load_cell(&mut self, coord: GridCoord) -> Result<()> {
         self.loading_cells.remove(&coord);
         self.active_cells.insert(coord);
@@ -288,8 +330,7 @@ impl WorldPartitionManager {
         Ok(())
     }
 
-    /// Handle load failure
-    #[allow(dead_code)]
+    /// Handle load failure (drained from `load_result_rx` by `update`)
     async fn handle_load_failure(&mut self, coord: GridCoord, error: String) {
         self.loading_cells.remove(&coord);
 
@@ -495,6 +536,7 @@ mod tests {
             lru_cache_size: 20,
             streaming_radius: 1000.0,
             max_concurrent_loads: 8,
+            ..Default::default()
         };
         assert_eq!(cfg.max_active_cells, 100);
         assert_eq!(cfg.lru_cache_size, 20);
@@ -729,4 +771,46 @@ mod tests {
         assert!(*count1.lock().unwrap() > 0);
         assert!(*count2.lock().unwrap() > 0);
     }
+
+    #[tokio::test]
+    async fn test_update_loads_nearest_cell_first_when_slots_are_limited() {
+        let partition = Arc::new(RwLock::new(WorldPartition::new(GridConfig::default())));
+        let cfg = StreamingConfig {
+            streaming_radius: 1000.0,
+            max_concurrent_loads: 1,
+            ..Default::default()
+        };
+        let mut mgr = WorldPartitionManager::new(Arc::clone(&partition), cfg);
+
+        mgr.update(glam::Vec3::new(0.0, 0.0, 0.0)).await.unwrap();
+
+        assert!(mgr.is_cell_loading(GridCoord::new(0, 0, 0)));
+        assert!(!mgr.is_cell_loading(GridCoord::new(4, 0, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_update_activates_completed_loads_up_to_the_budget() {
+        let partition = Arc::new(RwLock::new(WorldPartition::new(GridConfig::default())));
+        let mut mgr = WorldPartitionManager::new(
+            Arc::clone(&partition),
+            StreamingConfig {
+                max_activations_per_update: 1,
+                ..Default::default()
+            },
+        );
+
+        let c1 = GridCoord::new(0, 0, 0);
+        let c2 = GridCoord::new(1, 0, 0);
+        mgr.load_result_tx.send((c1, Ok(()))).unwrap();
+        mgr.load_result_tx.send((c2, Ok(()))).unwrap();
+        mgr.loading_cells.insert(c1);
+        mgr.loading_cells.insert(c2);
+
+        mgr.update(glam::Vec3::ZERO).await.unwrap();
+        let active_after_first = mgr.active_cells().len();
+        assert_eq!(active_after_first, 1);
+
+        mgr.update(glam::Vec3::ZERO).await.unwrap();
+        assert_eq!(mgr.active_cells().len(), 2);
+    }
 }
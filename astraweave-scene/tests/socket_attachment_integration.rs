@@ -0,0 +1,117 @@
+//! Integration Test: Named Socket Attachments
+//!
+//! Validates that CSkeletonSockets resolves socket names to joint indices
+//! by joint name, that sync_socket_attachments applies the socket's offset
+//! transform, and that re-resolving after a simulated hot-reload picks up
+//! joints that moved to a different index.
+
+#[cfg(feature = "ecs")]
+mod tests {
+    use astraweave_ecs::World;
+    use astraweave_scene::ecs::*;
+    use astraweave_scene::Transform;
+    use glam::{Mat4, Vec3};
+    use std::collections::BTreeMap;
+
+    fn socket_map() -> BTreeMap<String, String> {
+        BTreeMap::from([("weapon_r".to_string(), "hand_r".to_string())])
+    }
+
+    #[test]
+    fn resolve_finds_joint_by_name() {
+        let joint_names = vec!["root".to_string(), "hand_r".to_string()];
+        let sockets = CSkeletonSockets::resolve(&socket_map(), &joint_names);
+        assert_eq!(sockets.joint_index("weapon_r"), Some(1));
+    }
+
+    #[test]
+    fn resolve_drops_socket_with_missing_joint() {
+        let joint_names = vec!["root".to_string()];
+        let sockets = CSkeletonSockets::resolve(&socket_map(), &joint_names);
+        assert_eq!(sockets.joint_index("weapon_r"), None);
+    }
+
+    #[test]
+    fn sync_applies_socket_offset_on_top_of_joint() {
+        let mut world = World::new();
+        let skeleton_entity = world.spawn();
+
+        world.insert(
+            skeleton_entity,
+            CSkeletonSockets::resolve(&socket_map(), &["root".to_string(), "hand_r".to_string()]),
+        );
+        world.insert(
+            skeleton_entity,
+            CJointMatrices {
+                matrices: vec![
+                    Mat4::IDENTITY,
+                    Mat4::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+                ],
+                ..Default::default()
+            },
+        );
+
+        let sword_entity = world.spawn();
+        world.insert(
+            sword_entity,
+            CSocketAttachment {
+                skeleton_entity,
+                socket_name: "weapon_r".to_string(),
+                offset: Transform::from_translation(Vec3::new(0.1, 0.0, 0.0)),
+            },
+        );
+
+        sync_socket_attachments(&mut world);
+
+        let sword_pos = world
+            .get::<CTransformWorld>(sword_entity)
+            .unwrap()
+            .0
+            .w_axis
+            .truncate();
+        assert!((sword_pos - Vec3::new(0.1, 1.0, 0.0)).length() < 1e-4);
+    }
+
+    #[test]
+    fn sync_skips_attachment_after_socket_no_longer_resolves() {
+        let mut world = World::new();
+        let skeleton_entity = world.spawn();
+
+        // Hot-reloaded model no longer has a "hand_r" joint.
+        world.insert(
+            skeleton_entity,
+            CSkeletonSockets::resolve(&socket_map(), &["root".to_string()]),
+        );
+        world.insert(skeleton_entity, CJointMatrices::default());
+
+        let sword_entity = world.spawn();
+        world.insert(
+            sword_entity,
+            CSocketAttachment {
+                skeleton_entity,
+                socket_name: "weapon_r".to_string(),
+                offset: Transform::identity(),
+            },
+        );
+
+        sync_socket_attachments(&mut world);
+
+        assert!(world.get::<CTransformWorld>(sword_entity).is_none());
+    }
+
+    #[test]
+    fn re_resolve_after_hot_reload_follows_joint_reorder() {
+        let before = CSkeletonSockets::resolve(
+            &socket_map(),
+            &["hand_r".to_string(), "root".to_string()],
+        );
+        assert_eq!(before.joint_index("weapon_r"), Some(0));
+
+        // Model re-imported with joints in a different order.
+        let after = CSkeletonSockets::resolve(
+            &socket_map(),
+            &["root".to_string(), "hand_r".to_string()],
+        );
+        assert_eq!(after.joint_index("weapon_r"), Some(1));
+    }
+}
@@ -0,0 +1,387 @@
+//! Frame-budgeted cooperative task scheduler for engine background work.
+//!
+//! Subsystems that do chunky off-frame work — asset IO finalization,
+//! navmesh bakes, terrain generation, thumbnail rendering — enqueue [`Job`]s
+//! with a [`Priority`] and an optional per-frame time cap, instead of
+//! spawning threads or running to completion inline. [`TaskScheduler::run_frame`]
+//! steps queued jobs, highest priority first, until either the shared
+//! per-frame budget or an individual job's own cap is spent, so no single
+//! background system can spike frame time.
+//!
+//! Jobs are expected to be cooperative: [`Job::step`] should do a small,
+//! bounded slice of work and return promptly rather than running to
+//! completion in one call.
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Relative importance of a queued job. Higher-priority queues are drained
+/// before lower ones each frame, but a job that hits its own
+/// `per_frame_budget_ms` cap yields to the next runnable job regardless of
+/// priority, so a slow high-priority job can't starve everything else.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Priority {
+    Critical,
+    High,
+    Normal,
+    Low,
+}
+
+const PRIORITIES: [Priority; 4] = [
+    Priority::Critical,
+    Priority::High,
+    Priority::Normal,
+    Priority::Low,
+];
+
+/// One unit of cooperative background work.
+pub trait Job: Send {
+    /// Performs a bounded slice of work. Return [`JobProgress::Continue`]
+    /// to be stepped again next opportunity, or [`JobProgress::Done`] once
+    /// finished.
+    fn step(&mut self) -> JobProgress;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobProgress {
+    Continue,
+    Done,
+}
+
+/// Handle returned by [`TaskScheduler::enqueue`], usable to [`TaskScheduler::cancel`]
+/// the job before it completes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct JobHandle(u64);
+
+/// A job that finished during a [`TaskScheduler::run_frame`] call.
+#[derive(Clone, Debug)]
+pub struct CompletedJob {
+    pub handle: JobHandle,
+    pub name: String,
+}
+
+/// Summary of one [`TaskScheduler::run_frame`] call.
+#[derive(Clone, Debug)]
+pub struct FrameReport {
+    pub completed: Vec<CompletedJob>,
+    pub jobs_stepped: usize,
+    pub elapsed_ms: f32,
+    /// True if the frame's time budget ran out with runnable work still
+    /// queued (as opposed to the queue simply draining).
+    pub budget_exhausted: bool,
+}
+
+struct Entry {
+    id: u64,
+    name: String,
+    job: Box<dyn Job>,
+    per_frame_budget_ms: Option<f32>,
+    used_this_frame_ms: f32,
+}
+
+/// Central cooperative scheduler for background engine work. Not
+/// thread-safe by itself — pair with a lock or run it from a single owning
+/// system, the same way the rest of the engine's per-frame systems work.
+#[derive(Default)]
+pub struct TaskScheduler {
+    queues: [VecDeque<Entry>; 4],
+    next_id: u64,
+}
+
+impl TaskScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enqueues `job` under `priority`. `per_frame_budget_ms`, if set, caps
+    /// how much of the shared per-frame budget this one job may consume in
+    /// a single [`Self::run_frame`] call, even if the shared budget has
+    /// time left.
+    pub fn enqueue(
+        &mut self,
+        name: impl Into<String>,
+        priority: Priority,
+        per_frame_budget_ms: Option<f32>,
+        job: Box<dyn Job>,
+    ) -> JobHandle {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue_for(priority).push_back(Entry {
+            id,
+            name: name.into(),
+            job,
+            per_frame_budget_ms,
+            used_this_frame_ms: 0.0,
+        });
+        JobHandle(id)
+    }
+
+    /// Removes a queued job before it runs to completion. Returns `false`
+    /// if the handle is unknown (already completed or already cancelled).
+    pub fn cancel(&mut self, handle: JobHandle) -> bool {
+        for queue in &mut self.queues {
+            if let Some(pos) = queue.iter().position(|e| e.id == handle.0) {
+                queue.remove(pos);
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.queues.iter().map(|q| q.len()).sum()
+    }
+
+    /// Steps queued jobs, highest priority first, until `budget_ms` of
+    /// wall-clock time has elapsed or every job has either finished or hit
+    /// its own per-frame cap. Per-job usage counters reset at the start of
+    /// each call.
+    pub fn run_frame(&mut self, budget_ms: f32) -> FrameReport {
+        for queue in &mut self.queues {
+            for entry in queue.iter_mut() {
+                entry.used_this_frame_ms = 0.0;
+            }
+        }
+
+        let start = Instant::now();
+        let mut completed = Vec::new();
+        let mut jobs_stepped = 0usize;
+
+        loop {
+            if elapsed_ms(start) >= budget_ms {
+                break;
+            }
+            let Some((priority_idx, mut entry)) = self.pop_next_runnable() else {
+                break;
+            };
+
+            let step_start = Instant::now();
+            let progress = entry.job.step();
+            entry.used_this_frame_ms += elapsed_ms(step_start);
+            jobs_stepped += 1;
+
+            match progress {
+                JobProgress::Done => completed.push(CompletedJob {
+                    handle: JobHandle(entry.id),
+                    name: entry.name.clone(),
+                }),
+                JobProgress::Continue => self.queues[priority_idx].push_back(entry),
+            }
+        }
+
+        let elapsed_ms = elapsed_ms(start);
+        FrameReport {
+            completed,
+            jobs_stepped,
+            elapsed_ms,
+            budget_exhausted: elapsed_ms >= budget_ms && self.has_runnable_work(),
+        }
+    }
+
+    fn queue_for(&mut self, priority: Priority) -> &mut VecDeque<Entry> {
+        &mut self.queues[priority_index(priority)]
+    }
+
+    /// Pops the first job (priority order, then queue order) that hasn't
+    /// already hit its own per-frame cap.
+    fn pop_next_runnable(&mut self) -> Option<(usize, Entry)> {
+        for idx in 0..PRIORITIES.len() {
+            if let Some(pos) = self.queues[idx].iter().position(is_runnable) {
+                return self.queues[idx].remove(pos).map(|e| (idx, e));
+            }
+        }
+        None
+    }
+
+    fn has_runnable_work(&self) -> bool {
+        self.queues
+            .iter()
+            .any(|q| q.iter().any(is_runnable))
+    }
+}
+
+fn is_runnable(entry: &Entry) -> bool {
+    entry
+        .per_frame_budget_ms
+        .map(|cap| entry.used_this_frame_ms < cap)
+        .unwrap_or(true)
+}
+
+fn priority_index(priority: Priority) -> usize {
+    PRIORITIES
+        .iter()
+        .position(|p| *p == priority)
+        .expect("PRIORITIES covers every Priority variant")
+}
+
+fn elapsed_ms(since: Instant) -> f32 {
+    since.elapsed().as_secs_f32() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A job that finishes after a fixed number of steps.
+    struct CountingJob {
+        remaining: u32,
+        log: std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+        tag: &'static str,
+    }
+
+    impl Job for CountingJob {
+        fn step(&mut self) -> JobProgress {
+            self.log.borrow_mut().push(self.tag);
+            self.remaining -= 1;
+            if self.remaining == 0 {
+                JobProgress::Done
+            } else {
+                JobProgress::Continue
+            }
+        }
+    }
+
+    #[test]
+    fn drains_queue_when_budget_is_generous() {
+        let mut scheduler = TaskScheduler::new();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        scheduler.enqueue(
+            "job-a",
+            Priority::Normal,
+            None,
+            Box::new(CountingJob {
+                remaining: 3,
+                log: log.clone(),
+                tag: "a",
+            }),
+        );
+
+        let report = scheduler.run_frame(1000.0);
+
+        assert_eq!(report.completed.len(), 1);
+        assert_eq!(report.completed[0].name, "job-a");
+        assert_eq!(scheduler.pending_count(), 0);
+        assert_eq!(log.borrow().len(), 3);
+    }
+
+    #[test]
+    fn higher_priority_jobs_run_before_lower_priority_ones() {
+        let mut scheduler = TaskScheduler::new();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+
+        scheduler.enqueue(
+            "low",
+            Priority::Low,
+            None,
+            Box::new(CountingJob {
+                remaining: 1,
+                log: log.clone(),
+                tag: "low",
+            }),
+        );
+        scheduler.enqueue(
+            "critical",
+            Priority::Critical,
+            None,
+            Box::new(CountingJob {
+                remaining: 1,
+                log: log.clone(),
+                tag: "critical",
+            }),
+        );
+
+        scheduler.run_frame(1000.0);
+
+        assert_eq!(*log.borrow(), vec!["critical", "low"]);
+    }
+
+    #[test]
+    fn per_job_budget_yields_to_other_jobs() {
+        let mut scheduler = TaskScheduler::new();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+
+        // A job with a zero-ms cap can take its very first step (a job
+        // always gets to run once so it can make *some* progress) but
+        // shouldn't hog every remaining slot in the frame.
+        scheduler.enqueue(
+            "capped",
+            Priority::Critical,
+            Some(0.0),
+            Box::new(CountingJob {
+                remaining: 5,
+                log: log.clone(),
+                tag: "capped",
+            }),
+        );
+        scheduler.enqueue(
+            "uncapped",
+            Priority::Low,
+            None,
+            Box::new(CountingJob {
+                remaining: 1,
+                log: log.clone(),
+                tag: "uncapped",
+            }),
+        );
+
+        scheduler.run_frame(1000.0);
+
+        let entries = log.borrow();
+        assert_eq!(entries.first(), Some(&"capped"));
+        assert!(entries.contains(&"uncapped"));
+        assert_eq!(scheduler.pending_count(), 1, "capped job still has work left");
+    }
+
+    #[test]
+    fn cancel_removes_a_queued_job() {
+        let mut scheduler = TaskScheduler::new();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let handle = scheduler.enqueue(
+            "job",
+            Priority::Normal,
+            None,
+            Box::new(CountingJob {
+                remaining: 1,
+                log: log.clone(),
+                tag: "job",
+            }),
+        );
+
+        assert!(scheduler.cancel(handle));
+        assert!(!scheduler.cancel(handle), "already cancelled");
+
+        let report = scheduler.run_frame(1000.0);
+        assert!(report.completed.is_empty());
+        assert!(log.borrow().is_empty());
+    }
+
+    #[test]
+    fn zero_budget_runs_nothing_but_reports_pending_work() {
+        let mut scheduler = TaskScheduler::new();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        scheduler.enqueue(
+            "job",
+            Priority::Normal,
+            None,
+            Box::new(CountingJob {
+                remaining: 1,
+                log,
+                tag: "job",
+            }),
+        );
+
+        let report = scheduler.run_frame(0.0);
+
+        assert_eq!(report.jobs_stepped, 0);
+        assert!(report.budget_exhausted);
+        assert_eq!(scheduler.pending_count(), 1);
+    }
+
+    #[test]
+    fn empty_scheduler_reports_no_exhaustion() {
+        let mut scheduler = TaskScheduler::new();
+        let report = scheduler.run_frame(5.0);
+        assert!(!report.budget_exhausted);
+        assert_eq!(report.jobs_stepped, 0);
+    }
+}
@@ -0,0 +1,292 @@
+//! Server-authoritative validation of client-reported movement and actions.
+//!
+//! [`CAntiCheat`](crate::CAntiCheat) and [`crate::ValidationResult`] existed
+//! with nothing actually driving them: `astraweave_ai::execution_bridge`'s
+//! `pay_action_cost` already refuses actions that are on cooldown or that
+//! the entity can't afford, but it does so silently — a client that keeps
+//! submitting illegal `PlanIntent`s never accumulates anomaly flags or
+//! loses trust. [`validate_reported_state`] re-checks the same physics
+//! constraints astraweave-ai enforces (movement speed, cooldown
+//! compliance) against an entity's [`CAntiCheat`] record, decays trust on
+//! violations, and returns [`EnforcementRequest`]s for whatever policy
+//! action the trust score now calls for.
+
+use crate::{CAntiCheat, TelemetryData, TelemetryEvent, TelemetrySeverity};
+use astraweave_core::constraint_engine::check_action_cost;
+use astraweave_core::{CActivePlan, CCooldowns, CDesiredPos, CPos, Constraints};
+use astraweave_ecs::{Entity, World};
+
+/// Server-configured limits and trust thresholds for [`validate_reported_state`].
+#[derive(Clone, Debug)]
+pub struct AntiCheatPolicy {
+    /// Maximum tiles an entity may move toward its [`CDesiredPos`] in one
+    /// validation pass before it's flagged as suspicious.
+    pub max_tiles_per_tick: i32,
+    /// A move beyond `max_tiles_per_tick * teleport_multiplier` is reported
+    /// as an outright teleport rather than merely "too fast".
+    pub teleport_multiplier: i32,
+    /// Trust score multiplier applied per anomaly (e.g. `0.7` costs 30%).
+    pub trust_decay_per_violation: f32,
+    /// Trust score regained per clean validation pass, added additively
+    /// and capped at `1.0`.
+    pub trust_recovery_per_clean_pass: f32,
+    /// Trust score at or below which [`EnforcementAction::RubberBand`] is requested.
+    pub rubber_band_trust_threshold: f32,
+    /// Trust score at or below which [`EnforcementAction::Kick`] is requested.
+    pub kick_trust_threshold: f32,
+}
+
+impl Default for AntiCheatPolicy {
+    fn default() -> Self {
+        Self {
+            max_tiles_per_tick: 3,
+            teleport_multiplier: 4,
+            trust_decay_per_violation: 0.7,
+            trust_recovery_per_clean_pass: 0.02,
+            rubber_band_trust_threshold: 0.5,
+            kick_trust_threshold: 0.15,
+        }
+    }
+}
+
+/// A policy response to a trust score drop. The security crate only
+/// requests these; carrying them out (snapping a position, dropping a
+/// connection) is the job of the movement system / networking layer that
+/// owns those resources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnforcementAction {
+    /// Anomaly logged but trust is still high enough to take no action.
+    Warn,
+    /// Reset the entity's `CDesiredPos` back to its current `CPos`.
+    RubberBand,
+    /// Trust has fallen below the kick threshold; the caller should
+    /// disconnect the player.
+    Kick,
+}
+
+/// One enforcement decision produced by [`validate_reported_state`].
+#[derive(Clone, Debug)]
+pub struct EnforcementRequest {
+    pub entity: Entity,
+    pub player_id: String,
+    pub action: EnforcementAction,
+    pub reasons: Vec<String>,
+}
+
+/// Validates every entity with a [`CAntiCheat`] record: movement toward
+/// `CDesiredPos` against `policy.max_tiles_per_tick`, and (if present) the
+/// entity's [`CActivePlan`] current step against its [`CCooldowns`]. Trust
+/// scores are decayed per anomaly and recovered on clean passes; entities
+/// whose trust falls to a policy threshold get an [`EnforcementRequest`].
+/// Telemetry events are pushed to the [`TelemetryData`] resource, matching
+/// `input_validation_system`'s convention of fetching it from `world`.
+pub fn validate_reported_state(world: &mut World, policy: &AntiCheatPolicy) -> Vec<EnforcementRequest> {
+    let mut requests = Vec::new();
+
+    for entity in world.entities_with::<CAntiCheat>() {
+        let mut anomalies = Vec::new();
+
+        if let (Some(pos), Some(desired)) = (world.get::<CPos>(entity), world.get::<CDesiredPos>(entity)) {
+            let distance = pos.pos.manhattan_distance(&desired.pos);
+            if distance > policy.max_tiles_per_tick * policy.teleport_multiplier {
+                anomalies.push("teleport_detected".to_string());
+            } else if distance > policy.max_tiles_per_tick {
+                anomalies.push("impossible_movement".to_string());
+            }
+        }
+
+        if let Some(plan) = world.get::<CActivePlan>(entity) {
+            if let Some(step) = plan.current_step() {
+                let cooldowns = world
+                    .get::<CCooldowns>(entity)
+                    .map(|c| c.map.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+                    .unwrap_or_default();
+                let constraints = Constraints {
+                    enforce_cooldowns: true,
+                    enforce_los: false,
+                    enforce_stamina: false,
+                };
+                if check_action_cost(&cooldowns, None, step, &constraints).is_err() {
+                    anomalies.push("cooldown_violation".to_string());
+                }
+            }
+        }
+
+        let Some(anti_cheat) = world.get_mut::<CAntiCheat>(entity) else {
+            continue;
+        };
+
+        if anomalies.is_empty() {
+            anti_cheat.trust_score = (anti_cheat.trust_score + policy.trust_recovery_per_clean_pass).min(1.0);
+            continue;
+        }
+
+        for _ in &anomalies {
+            anti_cheat.trust_score *= policy.trust_decay_per_violation;
+        }
+        anti_cheat.anomaly_flags.extend(anomalies.iter().cloned());
+        anti_cheat.last_validation = crate::now_secs();
+
+        let player_id = anti_cheat.player_id.clone();
+        let trust_score = anti_cheat.trust_score;
+        let timestamp = anti_cheat.last_validation;
+
+        let action = if trust_score <= policy.kick_trust_threshold {
+            EnforcementAction::Kick
+        } else if trust_score <= policy.rubber_band_trust_threshold {
+            EnforcementAction::RubberBand
+        } else {
+            EnforcementAction::Warn
+        };
+
+        if let Some(telemetry) = world.get_resource_mut::<TelemetryData>() {
+            telemetry.events.push(TelemetryEvent {
+                timestamp,
+                event_type: "anti_cheat_violation".to_string(),
+                severity: TelemetrySeverity::Warning,
+                data: serde_json::json!({
+                    "player_id": player_id,
+                    "anomalies": anomalies,
+                    "trust_score": trust_score,
+                }),
+            });
+
+            if action != EnforcementAction::Warn {
+                telemetry.events.push(TelemetryEvent {
+                    timestamp,
+                    event_type: "anti_cheat_enforcement".to_string(),
+                    severity: TelemetrySeverity::Critical,
+                    data: serde_json::json!({
+                        "player_id": player_id,
+                        "action": format!("{action:?}"),
+                        "trust_score": trust_score,
+                    }),
+                });
+            }
+        }
+
+        requests.push(EnforcementRequest {
+            entity,
+            player_id,
+            action,
+            reasons: anomalies,
+        });
+    }
+
+    requests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_core::IVec2;
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    fn anti_cheat(player_id: &str) -> CAntiCheat {
+        CAntiCheat {
+            player_id: player_id.to_string(),
+            trust_score: 1.0,
+            last_validation: 0,
+            anomaly_flags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn clean_movement_recovers_trust_and_requests_nothing() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let e = world.spawn();
+        let mut ac = anti_cheat("p1");
+        ac.trust_score = 0.9;
+        world.insert(e, ac);
+        world.insert(e, CPos { pos: IVec2::new(0, 0) });
+        world.insert(e, CDesiredPos { pos: IVec2::new(1, 0) });
+
+        let requests = validate_reported_state(&mut world, &AntiCheatPolicy::default());
+
+        assert!(requests.is_empty());
+        assert!(world.get::<CAntiCheat>(e).unwrap().trust_score > 0.9);
+    }
+
+    #[test]
+    fn excessive_movement_is_flagged_as_impossible() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let e = world.spawn();
+        world.insert(e, anti_cheat("p1"));
+        world.insert(e, CPos { pos: IVec2::new(0, 0) });
+        world.insert(e, CDesiredPos { pos: IVec2::new(5, 0) });
+
+        let policy = AntiCheatPolicy::default();
+        let requests = validate_reported_state(&mut world, &policy);
+
+        assert!(requests.is_empty(), "one violation shouldn't yet cross the warn threshold");
+        assert!(world
+            .get::<CAntiCheat>(e)
+            .unwrap()
+            .anomaly_flags
+            .contains(&"impossible_movement".to_string()));
+        assert!(!world.get_resource::<TelemetryData>().unwrap().events.is_empty());
+    }
+
+    #[test]
+    fn far_beyond_the_limit_is_flagged_as_a_teleport() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let e = world.spawn();
+        world.insert(e, anti_cheat("p1"));
+        world.insert(e, CPos { pos: IVec2::new(0, 0) });
+        world.insert(e, CDesiredPos { pos: IVec2::new(100, 0) });
+
+        validate_reported_state(&mut world, &AntiCheatPolicy::default());
+
+        assert!(world
+            .get::<CAntiCheat>(e)
+            .unwrap()
+            .anomaly_flags
+            .contains(&"teleport_detected".to_string()));
+    }
+
+    #[test]
+    fn repeated_violations_drive_trust_down_to_rubber_band() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let e = world.spawn();
+        world.insert(e, anti_cheat("p1"));
+        world.insert(e, CPos { pos: IVec2::new(0, 0) });
+        world.insert(e, CDesiredPos { pos: IVec2::new(100, 0) });
+
+        let policy = AntiCheatPolicy::default();
+        let mut last = Vec::new();
+        for _ in 0..5 {
+            last = validate_reported_state(&mut world, &policy);
+        }
+
+        assert_eq!(last.len(), 1);
+        assert!(matches!(
+            last[0].action,
+            EnforcementAction::RubberBand | EnforcementAction::Kick
+        ));
+    }
+
+    #[test]
+    fn entities_without_anti_cheat_are_ignored() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let e = world.spawn();
+        world.insert(e, CPos { pos: IVec2::new(0, 0) });
+        world.insert(e, CDesiredPos { pos: IVec2::new(100, 0) });
+
+        let requests = validate_reported_state(&mut world, &AntiCheatPolicy::default());
+
+        assert!(requests.is_empty());
+        assert!(world.get_resource::<TelemetryData>().unwrap().events.is_empty());
+    }
+}
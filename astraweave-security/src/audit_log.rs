@@ -0,0 +1,217 @@
+//! Tamper-evident audit log for anti-cheat and validation events.
+//!
+//! Telemetry events are ephemeral — [`crate::telemetry_collection_system`]
+//! trims old ones and nothing guarantees the record wasn't edited after the
+//! fact. [`AuditLog`] instead keeps an append-only, SHA-256 hash-chained
+//! record of validation failures, trust score changes, and plan
+//! sanitization rejections (each entry's hash covers the previous entry's
+//! hash, so any edit or reorder breaks the chain), and periodically signs a
+//! checkpoint of the current chain head with an Ed25519 key so an operator
+//! can prove the log wasn't altered after the checkpoint was taken.
+
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+/// The kind of event an [`AuditEntry`] records.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum AuditEventKind {
+    ValidationFailure { player_id: String, reason: String },
+    TrustScoreChanged { player_id: String, old_score: f32, new_score: f32 },
+    PlanSanitizationRejected { player_id: String, pattern: String },
+}
+
+/// One append-only entry in an [`AuditLog`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub kind: AuditEventKind,
+    /// SHA-256 hash of the previous entry's `entry_hash` (all zeros for the first entry).
+    pub prev_hash: String,
+    /// SHA-256 hash of (`sequence`, `timestamp`, `kind`, `prev_hash`).
+    pub entry_hash: String,
+}
+
+impl AuditEntry {
+    fn compute_hash(sequence: u64, timestamp: u64, kind: &AuditEventKind, prev_hash: &str) -> String {
+        let canonical = serde_json::json!({
+            "sequence": sequence,
+            "timestamp": timestamp,
+            "kind": kind,
+            "prev_hash": prev_hash,
+        });
+        crate::hash_data(canonical.to_string().as_bytes())
+    }
+}
+
+/// A signed snapshot of the log's chain head at a point in time, proving
+/// nothing before it was altered afterward.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignedCheckpoint {
+    pub sequence: u64,
+    pub entry_hash: String,
+    pub signature: [u8; 64],
+}
+
+/// An append-only, hash-chained audit log of anti-cheat events.
+#[derive(Default)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `kind` as a new entry, chained onto the previous entry's hash.
+    pub fn record(&mut self, kind: AuditEventKind, timestamp: u64) -> &AuditEntry {
+        let sequence = self.entries.len() as u64;
+        let prev_hash = self.entries.last().map(|e| e.entry_hash.clone()).unwrap_or_else(|| "0".repeat(64));
+        let entry_hash = AuditEntry::compute_hash(sequence, timestamp, &kind, &prev_hash);
+
+        self.entries.push(AuditEntry {
+            sequence,
+            timestamp,
+            kind,
+            prev_hash,
+            entry_hash,
+        });
+        self.entries.last().expect("just pushed")
+    }
+
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Verifies every entry's hash matches its recorded `entry_hash` and
+    /// chains correctly onto the previous entry. Returns the index of the
+    /// first broken entry, if any.
+    pub fn verify_chain(&self) -> Result<(), usize> {
+        let mut prev_hash = "0".repeat(64);
+        for entry in &self.entries {
+            if entry.prev_hash != prev_hash {
+                return Err(entry.sequence as usize);
+            }
+            let expected = AuditEntry::compute_hash(entry.sequence, entry.timestamp, &entry.kind, &entry.prev_hash);
+            if expected != entry.entry_hash {
+                return Err(entry.sequence as usize);
+            }
+            prev_hash = entry.entry_hash.clone();
+        }
+        Ok(())
+    }
+
+    /// Signs the current chain head with `signing_key`, producing evidence
+    /// that everything recorded so far hasn't been altered since.
+    pub fn checkpoint(&self, signing_key: &SigningKey) -> Option<SignedCheckpoint> {
+        let last = self.entries.last()?;
+        let signature = crate::generate_signature(last.entry_hash.as_bytes(), signing_key);
+        Some(SignedCheckpoint {
+            sequence: last.sequence,
+            entry_hash: last.entry_hash.clone(),
+            signature: signature.to_bytes(),
+        })
+    }
+
+    /// Verifies a [`SignedCheckpoint`] against the log's current entries:
+    /// the checkpointed sequence must exist, its hash must match, and the
+    /// signature must verify.
+    pub fn verify_checkpoint(&self, checkpoint: &SignedCheckpoint, verifying_key: &VerifyingKey) -> bool {
+        let Some(entry) = self.entries.get(checkpoint.sequence as usize) else {
+            return false;
+        };
+        if entry.entry_hash != checkpoint.entry_hash {
+            return false;
+        }
+        let signature = Signature::from_bytes(&checkpoint.signature);
+        crate::verify_signature(checkpoint.entry_hash.as_bytes(), &signature, verifying_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(player: &str) -> AuditEventKind {
+        AuditEventKind::ValidationFailure {
+            player_id: player.to_string(),
+            reason: "impossible_movement".to_string(),
+        }
+    }
+
+    #[test]
+    fn recorded_entries_chain_and_verify() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+        log.record(failure("p2"), 2);
+        log.record(failure("p1"), 3);
+
+        assert_eq!(log.entries().len(), 3);
+        assert_eq!(log.verify_chain(), Ok(()));
+    }
+
+    #[test]
+    fn first_entry_chains_from_the_zero_hash() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+        assert_eq!(log.entries()[0].prev_hash, "0".repeat(64));
+    }
+
+    #[test]
+    fn tampering_with_an_entry_breaks_verification() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+        log.record(failure("p2"), 2);
+
+        // Mutate an already-recorded entry directly (simulating tampering).
+        log.entries[0].timestamp = 999;
+
+        assert!(log.verify_chain().is_err());
+    }
+
+    #[test]
+    fn checkpoint_is_none_for_an_empty_log() {
+        let log = AuditLog::new();
+        let (signing_key, _) = crate::generate_keypair();
+        assert!(log.checkpoint(&signing_key).is_none());
+    }
+
+    #[test]
+    fn checkpoint_verifies_against_the_log_that_produced_it() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+        log.record(failure("p2"), 2);
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let checkpoint = log.checkpoint(&signing_key).unwrap();
+
+        assert!(log.verify_checkpoint(&checkpoint, &verifying_key));
+    }
+
+    #[test]
+    fn checkpoint_fails_to_verify_after_the_log_is_tampered_with() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let checkpoint = log.checkpoint(&signing_key).unwrap();
+
+        log.entries[0].timestamp = 999;
+        log.entries[0].entry_hash = "tampered".to_string();
+
+        assert!(!log.verify_checkpoint(&checkpoint, &verifying_key));
+    }
+
+    #[test]
+    fn checkpoint_does_not_verify_under_a_different_key() {
+        let mut log = AuditLog::new();
+        log.record(failure("p1"), 1);
+
+        let (signing_key, _) = crate::generate_keypair();
+        let (_, other_verifying_key) = crate::generate_keypair();
+        let checkpoint = log.checkpoint(&signing_key).unwrap();
+
+        assert!(!log.verify_checkpoint(&checkpoint, &other_verifying_key));
+    }
+}
@@ -0,0 +1,249 @@
+//! Capability-scoped script API registration
+//!
+//! [`ScriptSandbox::allowed_functions`](crate::ScriptSandbox) has no
+//! registration API of its own — this module gives the engine a typed place
+//! to register host functions tagged with the capability they require, and
+//! gives each script a [`CapabilitySet`] so untrusted mod scripts only see
+//! the functions their tier permits.
+
+use crate::{ExecutionLimits, ScriptSandbox};
+use rhai::Engine;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// A capability a registered host function requires the caller to hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Read-only queries against ECS world state.
+    ReadWorld,
+    /// Mutating an existing entity's components.
+    MutateEntity,
+    /// Spawning new entities.
+    SpawnEntity,
+    /// Outbound network access.
+    Network,
+}
+
+/// The capabilities granted to one script. Trusted engine scripts get every
+/// tag via [`CapabilitySet::all`]; untrusted mod scripts get whatever their
+/// tier declares.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CapabilitySet(HashSet<Capability>);
+
+impl CapabilitySet {
+    /// No capabilities granted.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Every capability granted.
+    pub fn all() -> Self {
+        Self(HashSet::from([
+            Capability::ReadWorld,
+            Capability::MutateEntity,
+            Capability::SpawnEntity,
+            Capability::Network,
+        ]))
+    }
+
+    /// Returns `self` with `capability` added.
+    pub fn with(mut self, capability: Capability) -> Self {
+        self.0.insert(capability);
+        self
+    }
+
+    pub fn grants(&self, capability: Capability) -> bool {
+        self.0.contains(&capability)
+    }
+}
+
+impl FromIterator<Capability> for CapabilitySet {
+    fn from_iter<I: IntoIterator<Item = Capability>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// One host function the engine has made available to scripts, tagged with
+/// the [`Capability`] a script needs to call it.
+struct RegisteredFunction {
+    capability: Capability,
+    register: Box<dyn Fn(&mut Engine) + Send + Sync>,
+}
+
+/// Central registry of host functions the engine exposes to Rhai scripts,
+/// each tagged with the capability a script needs to call it. Building an
+/// engine or [`ScriptSandbox`] from the registry only registers the
+/// functions a given [`CapabilitySet`] permits, so an untrusted mod script
+/// literally cannot see — let alone call — functions outside its tier.
+#[derive(Default)]
+pub struct ScriptApiRegistry {
+    functions: HashMap<String, RegisteredFunction>,
+}
+
+impl ScriptApiRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a host function under `name`, gated behind `capability`.
+    /// `register` is invoked with a fresh [`Engine`] to perform the actual
+    /// `engine.register_fn(...)` call once a script's capabilities allow it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        capability: Capability,
+        register: impl Fn(&mut Engine) + Send + Sync + 'static,
+    ) {
+        self.functions.insert(
+            name.into(),
+            RegisteredFunction {
+                capability,
+                register: Box::new(register),
+            },
+        );
+    }
+
+    /// The capability `name` requires, if it's registered.
+    pub fn capability_of(&self, name: &str) -> Option<Capability> {
+        self.functions.get(name).map(|f| f.capability)
+    }
+
+    /// Names of every function `capabilities` currently permits.
+    pub fn allowed_function_names(&self, capabilities: &CapabilitySet) -> Vec<&str> {
+        self.functions
+            .iter()
+            .filter(|(_, f)| capabilities.grants(f.capability))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Builds a fresh [`Engine`] with only the functions `capabilities`
+    /// permits registered on it.
+    pub fn engine_for(&self, capabilities: &CapabilitySet) -> Engine {
+        let mut engine = Engine::new();
+        for func in self.functions.values() {
+            if capabilities.grants(func.capability) {
+                (func.register)(&mut engine);
+            }
+        }
+        engine
+    }
+
+    /// Builds a [`ScriptSandbox`] whose engine and `allowed_functions` map
+    /// reflect only the functions `capabilities` grants.
+    pub fn build_sandbox(
+        &self,
+        capabilities: &CapabilitySet,
+        execution_limits: ExecutionLimits,
+    ) -> ScriptSandbox {
+        let engine = self.engine_for(capabilities);
+        let allowed_functions = self
+            .functions
+            .iter()
+            .filter(|(_, f)| capabilities.grants(f.capability))
+            .map(|(name, f)| (name.clone(), format!("{:?}", f.capability)))
+            .collect();
+
+        ScriptSandbox {
+            engine: Arc::new(Mutex::new(engine)),
+            allowed_functions,
+            execution_limits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_registry() -> ScriptApiRegistry {
+        let mut registry = ScriptApiRegistry::new();
+        registry.register("get_health", Capability::ReadWorld, |engine| {
+            engine.register_fn("get_health", || 100_i64);
+        });
+        registry.register("damage_entity", Capability::MutateEntity, |engine| {
+            engine.register_fn("damage_entity", |_amount: i64| ());
+        });
+        registry.register("spawn_prop", Capability::SpawnEntity, |engine| {
+            engine.register_fn("spawn_prop", || 0_i64);
+        });
+        registry
+    }
+
+    #[test]
+    fn capability_set_grants_only_what_was_added() {
+        let caps = CapabilitySet::none().with(Capability::ReadWorld);
+        assert!(caps.grants(Capability::ReadWorld));
+        assert!(!caps.grants(Capability::MutateEntity));
+    }
+
+    #[test]
+    fn all_grants_every_capability() {
+        let caps = CapabilitySet::all();
+        assert!(caps.grants(Capability::ReadWorld));
+        assert!(caps.grants(Capability::MutateEntity));
+        assert!(caps.grants(Capability::SpawnEntity));
+        assert!(caps.grants(Capability::Network));
+    }
+
+    #[test]
+    fn engine_for_untrusted_tier_lacks_privileged_functions() {
+        let registry = sample_registry();
+        let caps = CapabilitySet::none().with(Capability::ReadWorld);
+        let mut engine = registry.engine_for(&caps);
+
+        assert_eq!(
+            engine.eval::<i64>("get_health()").unwrap(),
+            100
+        );
+        assert!(engine.eval::<i64>("spawn_prop()").is_err());
+    }
+
+    #[test]
+    fn engine_for_trusted_tier_has_every_function() {
+        let registry = sample_registry();
+        let mut engine = registry.engine_for(&CapabilitySet::all());
+
+        assert_eq!(engine.eval::<i64>("get_health()").unwrap(), 100);
+        assert_eq!(engine.eval::<i64>("spawn_prop()").unwrap(), 0);
+    }
+
+    #[test]
+    fn allowed_function_names_reflects_capability_set() {
+        let registry = sample_registry();
+        let caps = CapabilitySet::none().with(Capability::ReadWorld);
+        let names = registry.allowed_function_names(&caps);
+
+        assert_eq!(names, vec!["get_health"]);
+    }
+
+    #[test]
+    fn capability_of_unknown_function_is_none() {
+        let registry = sample_registry();
+        assert_eq!(registry.capability_of("does_not_exist"), None);
+        assert_eq!(
+            registry.capability_of("damage_entity"),
+            Some(Capability::MutateEntity)
+        );
+    }
+
+    #[test]
+    fn build_sandbox_populates_allowed_functions_map() {
+        let registry = sample_registry();
+        let caps = CapabilitySet::none()
+            .with(Capability::ReadWorld)
+            .with(Capability::MutateEntity);
+        let limits = ExecutionLimits {
+            max_operations: 1000,
+            max_memory_bytes: 1024,
+            timeout_ms: 500,
+        };
+
+        let sandbox = registry.build_sandbox(&caps, limits);
+
+        assert_eq!(sandbox.allowed_functions.len(), 2);
+        assert!(sandbox.allowed_functions.contains_key("get_health"));
+        assert!(sandbox.allowed_functions.contains_key("damage_entity"));
+        assert!(!sandbox.allowed_functions.contains_key("spawn_prop"));
+    }
+}
@@ -0,0 +1,228 @@
+//! Guardrailed NPC dialogue generation.
+//!
+//! The core AI loop (`astraweave-core`/`astraweave-llm`) only produces
+//! [`astraweave_core::PlanIntent`] action plans; nothing assembles a
+//! persona and lore context into a prompt for free-form NPC speech, and
+//! nothing content-filters what comes back before it reaches a chat box.
+//! This module closes that gap: [`DialogueContext`] assembles a prompt
+//! from a [`CompanionProfile`] and lore snippets (as loaded from an
+//! `AssetKind::Dialogue` asset), [`generate_dialogue`] runs it through an
+//! [`LlmClient`] and [`crate::llm_sanitizer::sanitize_prompt`]'s content
+//! filter before returning it, and [`generate_dialogue_streaming`] does
+//! the same for typewriter-style progressive delivery.
+
+use crate::llm_sanitizer::sanitize_prompt;
+use crate::{LLMValidator, TelemetryData};
+use anyhow::{bail, Result};
+use astraweave_core::CompanionProfile;
+use astraweave_llm::LlmClient;
+use futures_util::StreamExt;
+use std::pin::Pin;
+
+/// The persona, lore, and recent history a dialogue prompt is built from.
+#[derive(Clone, Debug, Default)]
+pub struct DialogueContext {
+    pub persona: CompanionProfile,
+    /// Lore snippets relevant to this conversation, e.g. parsed from an
+    /// `AssetKind::Dialogue` asset's `[dialogue]` entries.
+    pub lore: Vec<String>,
+    /// Prior lines of this conversation, oldest first.
+    pub recent_lines: Vec<String>,
+}
+
+/// Assembles `context` and `player_line` into a single prompt for an
+/// [`LlmClient`]. Kept as a standalone function (rather than folded into
+/// [`generate_dialogue`]) so callers can inspect or log the prompt
+/// without also making the LLM call.
+pub fn build_dialogue_prompt(context: &DialogueContext, player_line: &str) -> String {
+    let mut prompt = String::new();
+    prompt.push_str(&format!(
+        "You are {}, an NPC. Background: {}\n",
+        context.persona.name, context.persona.background
+    ));
+    if !context.persona.personality_traits.is_empty() {
+        prompt.push_str(&format!(
+            "Personality: {}\n",
+            context.persona.personality_traits.join(", ")
+        ));
+    }
+    for line in &context.lore {
+        prompt.push_str(&format!("Lore: {line}\n"));
+    }
+    for line in &context.recent_lines {
+        prompt.push_str(&format!("{line}\n"));
+    }
+    prompt.push_str(&format!("Player: {player_line}\n{}:", context.persona.name));
+    prompt
+}
+
+/// Generates one line of NPC dialogue: builds the prompt, calls `client`,
+/// and content-filters the response with `validator` before returning it.
+/// A blocked response is an error rather than a partially-redacted line,
+/// matching [`crate::sanitize_llm_prompt`]'s "banned is fatal" behavior.
+pub async fn generate_dialogue(
+    client: &dyn LlmClient,
+    context: &DialogueContext,
+    player_line: &str,
+    validator: &LLMValidator,
+    telemetry: &mut TelemetryData,
+) -> Result<String> {
+    let prompt = build_dialogue_prompt(context, player_line);
+    let response = client.complete(&prompt).await?;
+
+    let report = sanitize_prompt(&response, validator, telemetry);
+    if report.blocked {
+        bail!(
+            "generated dialogue blocked by content filter: {:?}",
+            report.matched_patterns
+        );
+    }
+
+    Ok(response)
+}
+
+/// Streaming counterpart to [`generate_dialogue`] for typewriter-style UI.
+///
+/// The content filter needs the full response to check banned patterns,
+/// so this buffers `client`'s stream to completion, validates it, and
+/// only then re-emits it as a single-chunk stream -- callers get the same
+/// [`LlmClient::complete_streaming`]-shaped return value they'd use for an
+/// unfiltered client, but lose true token-by-token delivery in exchange
+/// for never displaying unvalidated text.
+pub async fn generate_dialogue_streaming(
+    client: &dyn LlmClient,
+    context: &DialogueContext,
+    player_line: &str,
+    validator: &LLMValidator,
+    telemetry: &mut TelemetryData,
+) -> Result<Pin<Box<dyn futures_util::Stream<Item = Result<String>> + Send>>> {
+    let prompt = build_dialogue_prompt(context, player_line);
+    let mut stream = client.complete_streaming(&prompt).await?;
+
+    let mut full = String::new();
+    while let Some(chunk) = stream.next().await {
+        full.push_str(&chunk?);
+    }
+
+    let report = sanitize_prompt(&full, validator, telemetry);
+    if report.blocked {
+        bail!(
+            "generated dialogue blocked by content filter: {:?}",
+            report.matched_patterns
+        );
+    }
+
+    Ok(Box::pin(futures_util::stream::once(async move { Ok(full) })))
+}
+
+/// Deterministic [`LlmClient`] for dialogue tests: always returns the same
+/// line for a given persona name, with no randomness or network I/O.
+pub struct MockDialogueClient;
+
+#[async_trait::async_trait]
+impl LlmClient for MockDialogueClient {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let speaker = prompt
+            .strip_prefix("You are ")
+            .and_then(|rest| rest.split(',').next())
+            .unwrap_or("NPC");
+        Ok(format!("{speaker}: I have nothing more to say on that."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TelemetryEvent;
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::<TelemetryEvent>::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    fn validator() -> LLMValidator {
+        LLMValidator {
+            banned_patterns: vec![r"system\s*\(".to_string()],
+            allowed_domains: Vec::new(),
+            max_prompt_length: 10_000,
+            enable_content_filtering: true,
+        }
+    }
+
+    fn context() -> DialogueContext {
+        DialogueContext {
+            persona: CompanionProfile {
+                name: "Rook".to_string(),
+                personality_traits: vec!["gruff".to_string(), "loyal".to_string()],
+                background: "A veteran scout.".to_string(),
+            },
+            lore: vec!["The valley was abandoned after the war.".to_string()],
+            recent_lines: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn build_dialogue_prompt_includes_persona_lore_and_player_line() {
+        let prompt = build_dialogue_prompt(&context(), "What happened here?");
+        assert!(prompt.contains("Rook"));
+        assert!(prompt.contains("veteran scout"));
+        assert!(prompt.contains("abandoned after the war"));
+        assert!(prompt.contains("Player: What happened here?"));
+    }
+
+    #[tokio::test]
+    async fn generate_dialogue_returns_the_mock_clients_response() {
+        let mut tel = telemetry();
+        let response = generate_dialogue(
+            &MockDialogueClient,
+            &context(),
+            "What happened here?",
+            &validator(),
+            &mut tel,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.contains("Rook"));
+    }
+
+    #[tokio::test]
+    async fn generate_dialogue_blocks_output_matching_a_banned_pattern() {
+        struct BadClient;
+        #[async_trait::async_trait]
+        impl LlmClient for BadClient {
+            async fn complete(&self, _prompt: &str) -> Result<String> {
+                Ok("sure, just call system( \"rm -rf /\" )".to_string())
+            }
+        }
+
+        let mut tel = telemetry();
+        let result =
+            generate_dialogue(&BadClient, &context(), "help me", &validator(), &mut tel).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn generate_dialogue_streaming_buffers_then_returns_full_text() {
+        let mut tel = telemetry();
+        let mut stream = generate_dialogue_streaming(
+            &MockDialogueClient,
+            &context(),
+            "What happened here?",
+            &validator(),
+            &mut tel,
+        )
+        .await
+        .unwrap();
+
+        let mut collected = String::new();
+        while let Some(chunk) = stream.next().await {
+            collected.push_str(&chunk.unwrap());
+        }
+        assert!(collected.contains("Rook"));
+    }
+}
@@ -8,9 +8,29 @@
 //! - Script execution sandboxing with Rhai
 //! - Input validation and anti-cheat measures
 //! - Telemetry and monitoring systems
-
+//! - Mod loading with capability-scoped script and asset overlays
+//! - Rhai script hot-reload with persistent scope migration
+//! - Runtime enforcement of script execution limits with a watchdog
+//! - Server-authoritative validation of client-reported movement and actions
+//! - Ed25519-signed asset manifests with startup integrity verification
+//! - Regex-based LLM prompt injection detection and output sanitization
+//! - Pluggable telemetry export (JSON lines, OTLP, in-game overlay)
+//! - Sliding-window rate limiting and abuse detection for networked RPCs
+//! - Tamper-evident, hash-chained audit log with signed checkpoints
+
+pub mod anti_cheat;
+pub mod audit_log;
+pub mod capability;
 pub mod deserialization;
+pub mod dialogue;
+pub mod llm_sanitizer;
+pub mod manifest_signing;
+pub mod mod_loader;
 pub mod path;
+pub mod rate_limiter;
+pub mod script_hot_reload;
+pub mod script_watchdog;
+pub mod telemetry_export;
 
 use anyhow::Result;
 use astraweave_ecs::{App, Plugin, World};
@@ -171,13 +191,34 @@ impl Plugin for SecurityPlugin {
 
         app.world.insert_resource(llm_validator);
 
+        app.world.insert_resource(crate::anti_cheat::AntiCheatPolicy::default());
+        app.world
+            .insert_resource(Vec::<crate::anti_cheat::EnforcementRequest>::new());
+
         // Add security systems
         app.add_system("pre_simulation", input_validation_system);
+        app.add_system("pre_simulation", anti_cheat_validation_system);
         app.add_system("post_simulation", telemetry_collection_system);
         app.add_system("post_simulation", anomaly_detection_system);
     }
 }
 
+/// Bridges [`crate::anti_cheat::validate_reported_state`] into the ECS
+/// schedule: reads the [`crate::anti_cheat::AntiCheatPolicy`] resource and
+/// appends the resulting [`crate::anti_cheat::EnforcementRequest`]s to the
+/// `Vec<EnforcementRequest>` resource for a movement/networking system to
+/// drain each tick.
+fn anti_cheat_validation_system(world: &mut World) {
+    let policy = world
+        .get_resource::<crate::anti_cheat::AntiCheatPolicy>()
+        .cloned()
+        .unwrap_or_default();
+    let mut requests = crate::anti_cheat::validate_reported_state(world, &policy);
+    if let Some(queue) = world.get_resource_mut::<Vec<crate::anti_cheat::EnforcementRequest>>() {
+        queue.append(&mut requests);
+    }
+}
+
 /// Input validation system
 fn input_validation_system(world: &mut World) {
     let entities: Vec<_> = world.entities_with::<CAntiCheat>();
@@ -230,6 +271,13 @@ fn input_validation_system(world: &mut World) {
 
 /// Telemetry collection system
 fn telemetry_collection_system(world: &mut World) {
+    // Export before trimming below: the pipeline tracks its position by
+    // index into `events`, which trimming invalidates.
+    let events_snapshot = world.get_resource::<TelemetryData>().map(|t| t.events.clone());
+    if let (Some(events), Some(pipeline)) = (events_snapshot, world.get_resource_mut::<crate::telemetry_export::TelemetryExportPipeline>()) {
+        let _ = pipeline.export_new(&events);
+    }
+
     if let Some(telemetry) = world.get_resource_mut::<TelemetryData>() {
         // Clean up old events (keep last 1000)
         if telemetry.events.len() > 1000 {
@@ -426,6 +474,16 @@ pub fn hash_data(data: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Current wall-clock time as Unix seconds, for timestamping telemetry
+/// events, script watchdog deadlines, and manifest/rate-limit bookkeeping
+/// across this crate.
+pub(crate) fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock before UNIX epoch")
+        .as_secs()
+}
+
 #[cfg(test)]
 mod anticheat_tests;
 #[cfg(test)]
@@ -0,0 +1,401 @@
+//! Ed25519 signing and startup integrity verification for asset manifests.
+//!
+//! `astraweave-asset`'s `AssetDatabase` records a content hash per asset but
+//! nothing ever signs that record, so a tampered manifest (or a swapped-out
+//! asset file with its hash edited to match) is indistinguishable from a
+//! legitimate one at load time. [`ManifestSigner`] signs a snapshot of the
+//! database's entries — as a whole manifest, or one pack entry at a time —
+//! and [`ManifestVerifier`] re-checks the signature plus each entry's
+//! recorded hash against the file on disk, either flagging or refusing
+//! tampered assets depending on [`IntegrityCheckMode`].
+
+use crate::{TelemetryData, TelemetryEvent, TelemetrySeverity};
+use astraweave_asset::AssetMetadata;
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// A deterministic, signable snapshot of an [`astraweave_asset::AssetDatabase`].
+///
+/// Entries are held in a `BTreeMap` (rather than the database's own
+/// `HashMap`) so that serialization order — and therefore the signed byte
+/// sequence — is stable across runs.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AssetManifest {
+    pub entries: BTreeMap<String, AssetMetadata>,
+}
+
+impl AssetManifest {
+    /// Builds a manifest snapshot from an `AssetDatabase`'s `assets` map.
+    pub fn from_assets(assets: &std::collections::HashMap<String, AssetMetadata>) -> Self {
+        Self {
+            entries: assets.iter().map(|(guid, meta)| (guid.clone(), meta.clone())).collect(),
+        }
+    }
+
+    fn canonical_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.entries).expect("AssetManifest entries always serialize")
+    }
+}
+
+/// An [`AssetManifest`] together with its Ed25519 signature.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignedManifest {
+    pub manifest: AssetManifest,
+    pub signature: [u8; 64],
+}
+
+/// A single manifest entry signed on its own, for verifying one asset pack
+/// without needing the whole manifest present (e.g. a DLC pack shipped
+/// separately from the base game's asset database).
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignedEntry {
+    pub guid: String,
+    pub metadata: AssetMetadata,
+    pub signature: [u8; 64],
+}
+
+/// Signs asset manifests and individual pack entries.
+pub struct ManifestSigner {
+    signing_key: SigningKey,
+}
+
+impl ManifestSigner {
+    pub fn new(signing_key: SigningKey) -> Self {
+        Self { signing_key }
+    }
+
+    pub fn sign_manifest(&self, manifest: AssetManifest) -> SignedManifest {
+        let signature = crate::generate_signature(&manifest.canonical_bytes(), &self.signing_key);
+        SignedManifest {
+            manifest,
+            signature: signature.to_bytes(),
+        }
+    }
+
+    pub fn sign_entry(&self, guid: impl Into<String>, metadata: AssetMetadata) -> SignedEntry {
+        let guid = guid.into();
+        let bytes = serde_json::to_vec(&metadata).expect("AssetMetadata always serializes");
+        let signature = crate::generate_signature(&bytes, &self.signing_key);
+        SignedEntry {
+            guid,
+            metadata,
+            signature: signature.to_bytes(),
+        }
+    }
+}
+
+/// Whether [`ManifestVerifier::verify`] rejects tampered assets outright or
+/// just records telemetry so anti-cheat/ops can act on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityCheckMode {
+    /// Record a `Critical` telemetry event per violation but let startup continue.
+    FlagOnly,
+    /// Return an error on the first violation, refusing to proceed.
+    Enforce,
+}
+
+/// A single integrity violation found while verifying a manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityViolation {
+    /// The manifest's own Ed25519 signature didn't verify.
+    BadSignature,
+    /// An entry's recorded content hash no longer matches the file on disk.
+    HashMismatch { guid: String },
+    /// An entry references a file that no longer exists.
+    MissingFile { guid: String },
+    /// An entry's path escapes `asset_root` (e.g. via a `..` component).
+    UnsafePath { guid: String },
+}
+
+/// Verifies signed manifests and pack entries against the asset files on disk.
+pub struct ManifestVerifier {
+    verifying_key: VerifyingKey,
+    mode: IntegrityCheckMode,
+}
+
+impl ManifestVerifier {
+    pub fn new(verifying_key: VerifyingKey, mode: IntegrityCheckMode) -> Self {
+        Self { verifying_key, mode }
+    }
+
+    /// Verifies `signed`'s signature, then re-hashes every entry's file
+    /// under `asset_root` and compares it against the recorded hash.
+    /// Violations are pushed to `telemetry` as `Critical` events; in
+    /// [`IntegrityCheckMode::Enforce`] the first violation short-circuits
+    /// with `Err`, returning all violations found up to that point.
+    pub fn verify(
+        &self,
+        signed: &SignedManifest,
+        asset_root: &Path,
+        telemetry: &mut TelemetryData,
+    ) -> Result<Vec<IntegrityViolation>, Vec<IntegrityViolation>> {
+        let mut violations = Vec::new();
+
+        let signature = Signature::from_bytes(&signed.signature);
+        if !crate::verify_signature(&signed.manifest.canonical_bytes(), &signature, &self.verifying_key) {
+            violations.push(IntegrityViolation::BadSignature);
+            self.record(telemetry, "manifest_signature_invalid", &serde_json::json!({}));
+            if self.mode == IntegrityCheckMode::Enforce {
+                return Err(violations);
+            }
+        }
+
+        for (guid, meta) in &signed.manifest.entries {
+            // A manifest we've already determined is untrusted (bad
+            // signature, or just a `..`-laced entry) must not be allowed to
+            // steer `fs::read` outside `asset_root` — reject the path
+            // before joining rather than after, in every mode.
+            if has_parent_dir_component(&meta.path) {
+                violations.push(IntegrityViolation::UnsafePath { guid: guid.clone() });
+                self.record(
+                    telemetry,
+                    "asset_path_traversal",
+                    &serde_json::json!({ "guid": guid, "path": meta.path }),
+                );
+                if self.mode == IntegrityCheckMode::Enforce {
+                    return Err(violations);
+                }
+                continue;
+            }
+
+            let file_path = asset_root.join(&meta.path);
+            let Ok(bytes) = fs::read(&file_path) else {
+                violations.push(IntegrityViolation::MissingFile { guid: guid.clone() });
+                self.record(
+                    telemetry,
+                    "asset_file_missing",
+                    &serde_json::json!({ "guid": guid, "path": meta.path }),
+                );
+                if self.mode == IntegrityCheckMode::Enforce {
+                    return Err(violations);
+                }
+                continue;
+            };
+
+            if crate::hash_data(&bytes) != meta.hash {
+                violations.push(IntegrityViolation::HashMismatch { guid: guid.clone() });
+                self.record(
+                    telemetry,
+                    "asset_hash_mismatch",
+                    &serde_json::json!({ "guid": guid, "path": meta.path }),
+                );
+                if self.mode == IntegrityCheckMode::Enforce {
+                    return Err(violations);
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+
+    fn record(&self, telemetry: &mut TelemetryData, event_type: &str, data: &serde_json::Value) {
+        telemetry.events.push(TelemetryEvent {
+            timestamp: crate::now_secs(),
+            event_type: event_type.to_string(),
+            severity: TelemetrySeverity::Critical,
+            data: data.clone(),
+        });
+    }
+}
+
+/// `true` if `path` contains a `..` component, which could otherwise walk
+/// a manifest entry outside `asset_root` when joined onto it.
+fn has_parent_dir_component(path: &str) -> bool {
+    Path::new(path)
+        .components()
+        .any(|c| c == std::path::Component::ParentDir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astraweave_asset::AssetKind;
+    use std::collections::HashMap;
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    fn write_asset(dir: &Path, name: &str, contents: &[u8]) -> AssetMetadata {
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        AssetMetadata {
+            guid: name.to_string(),
+            path: name.to_string(),
+            kind: AssetKind::Other,
+            hash: crate::hash_data(contents),
+            dependencies: Vec::new(),
+            last_modified: 0,
+            size_bytes: contents.len() as u64,
+            audio: None,
+        }
+    }
+
+    #[test]
+    fn valid_manifest_verifies_with_no_violations() {
+        let dir = std::env::temp_dir().join("manifest_signing_valid");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = write_asset(&dir, "a.bin", b"hello");
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        let verifier = ManifestVerifier::new(verifying_key, IntegrityCheckMode::Enforce);
+        let mut tel = telemetry();
+        let violations = verifier.verify(&signed, &dir, &mut tel).unwrap();
+
+        assert!(violations.is_empty());
+        assert!(tel.events.is_empty());
+    }
+
+    #[test]
+    fn tampered_signature_is_rejected() {
+        let dir = std::env::temp_dir().join("manifest_signing_bad_sig");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = write_asset(&dir, "a.bin", b"hello");
+
+        let (signing_key, _) = crate::generate_keypair();
+        let (_, other_verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        let verifier = ManifestVerifier::new(other_verifying_key, IntegrityCheckMode::Enforce);
+        let mut tel = telemetry();
+        let result = verifier.verify(&signed, &dir, &mut tel);
+
+        assert_eq!(result.unwrap_err(), vec![IntegrityViolation::BadSignature]);
+    }
+
+    #[test]
+    fn modified_file_fails_hash_check_in_flag_only_mode() {
+        let dir = std::env::temp_dir().join("manifest_signing_hash_mismatch");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = write_asset(&dir, "a.bin", b"hello");
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        fs::write(dir.join("a.bin"), b"tampered!").unwrap();
+
+        let verifier = ManifestVerifier::new(verifying_key, IntegrityCheckMode::FlagOnly);
+        let mut tel = telemetry();
+        let violations = verifier.verify(&signed, &dir, &mut tel).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(&violations[0], IntegrityViolation::HashMismatch { guid } if guid == "a.bin"));
+        assert!(!tel.events.is_empty());
+    }
+
+    #[test]
+    fn enforce_mode_stops_at_first_violation() {
+        let dir = std::env::temp_dir().join("manifest_signing_enforce");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = write_asset(&dir, "a.bin", b"hello");
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        fs::write(dir.join("a.bin"), b"tampered!").unwrap();
+
+        let verifier = ManifestVerifier::new(verifying_key, IntegrityCheckMode::Enforce);
+        let mut tel = telemetry();
+        let result = verifier.verify(&signed, &dir, &mut tel);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let dir = std::env::temp_dir().join("manifest_signing_missing");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = AssetMetadata {
+            guid: "ghost".to_string(),
+            path: "does_not_exist.bin".to_string(),
+            kind: AssetKind::Other,
+            hash: String::new(),
+            dependencies: Vec::new(),
+            last_modified: 0,
+            size_bytes: 0,
+            audio: None,
+        };
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        let verifier = ManifestVerifier::new(verifying_key, IntegrityCheckMode::FlagOnly);
+        let mut tel = telemetry();
+        let violations = verifier.verify(&signed, &dir, &mut tel).unwrap();
+
+        assert_eq!(violations, vec![IntegrityViolation::MissingFile { guid: "ghost".to_string() }]);
+    }
+
+    #[test]
+    fn entry_path_with_parent_dir_component_is_rejected_without_reading_it() {
+        let dir = std::env::temp_dir().join("manifest_signing_traversal");
+        fs::create_dir_all(&dir).unwrap();
+        let meta = AssetMetadata {
+            guid: "evil".to_string(),
+            path: "../secrets.bin".to_string(),
+            kind: AssetKind::Other,
+            hash: String::new(),
+            dependencies: Vec::new(),
+            last_modified: 0,
+            size_bytes: 0,
+            audio: None,
+        };
+
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let mut assets = HashMap::new();
+        assets.insert(meta.guid.clone(), meta);
+        let manifest = AssetManifest::from_assets(&assets);
+        let signed = ManifestSigner::new(signing_key).sign_manifest(manifest);
+
+        let verifier = ManifestVerifier::new(verifying_key, IntegrityCheckMode::FlagOnly);
+        let mut tel = telemetry();
+        let violations = verifier.verify(&signed, &dir, &mut tel).unwrap();
+
+        assert_eq!(violations, vec![IntegrityViolation::UnsafePath { guid: "evil".to_string() }]);
+        assert!(!tel.events.is_empty());
+    }
+
+    #[test]
+    fn sign_entry_produces_an_independently_verifiable_signature() {
+        let (signing_key, verifying_key) = crate::generate_keypair();
+        let meta = AssetMetadata {
+            guid: "pack-entry".to_string(),
+            path: "pack/a.bin".to_string(),
+            kind: AssetKind::Texture,
+            hash: crate::hash_data(b"pack contents"),
+            dependencies: Vec::new(),
+            last_modified: 0,
+            size_bytes: 13,
+            audio: None,
+        };
+
+        let signed_entry = ManifestSigner::new(signing_key).sign_entry(meta.guid.clone(), meta.clone());
+        let bytes = serde_json::to_vec(&meta).unwrap();
+        let signature = Signature::from_bytes(&signed_entry.signature);
+
+        assert!(crate::verify_signature(&bytes, &signature, &verifying_key));
+    }
+}
@@ -0,0 +1,637 @@
+//! Mod loading framework
+//!
+//! Discovers mod packages (a directory with a `mod.toml` manifest, an
+//! optional assets directory, and an optional script entry point), mounts
+//! their assets as a priority-ordered VFS overlay with conflict tracking,
+//! and loads each mod's script into its own [`ScriptSandbox`]. Mods can be
+//! enabled or disabled at runtime, which recomputes the overlay and
+//! reloads/unloads that mod's sandbox without touching the others.
+//!
+//! Ties together [`crate::path::safe_under`] (traversal-safe asset
+//! resolution), [`crate::deserialization::parse_toml_limited`]
+//! (size-limited manifest parsing), and [`ScriptSandbox`] (per-mod script
+//! execution).
+
+use crate::capability::{CapabilitySet, ScriptApiRegistry};
+use crate::deserialization::parse_toml_limited;
+use crate::path::safe_under;
+use crate::{ExecutionLimits, ScriptSandbox};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// On-disk manifest at the root of a mod package (`mod.toml`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModManifest {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    /// Directory, relative to the mod's root, whose contents are mounted
+    /// into the asset overlay.
+    #[serde(default)]
+    pub assets_dir: Option<String>,
+    /// Rhai script, relative to the mod's root, loaded into this mod's
+    /// sandbox when it's enabled.
+    #[serde(default)]
+    pub script_entry: Option<String>,
+    /// Higher priority wins asset overlay conflicts; ties break on `id`.
+    #[serde(default)]
+    pub load_priority: i32,
+}
+
+/// A discovered mod package: its manifest, the directory it was found in,
+/// and whether it's currently contributing to the overlay/sandboxes.
+#[derive(Clone, Debug)]
+pub struct ModPackage {
+    pub manifest: ModManifest,
+    pub root: PathBuf,
+    pub enabled: bool,
+}
+
+impl ModPackage {
+    /// Resolves `manifest.assets_dir` under [`Self::root`], rejecting any
+    /// attempt to escape it (a `..` component, an absolute path that would
+    /// otherwise replace `root` outright, or a symlink escape) the same way
+    /// [`Self::script_path`]'s caller validates `script_entry`. `None` means
+    /// there's no assets directory to mount at all, whether because the
+    /// manifest didn't declare one or because the declared one was unsafe.
+    pub fn assets_path(&self) -> Option<PathBuf> {
+        let dir = self.manifest.assets_dir.as_ref()?;
+        safe_under(&self.root, Path::new(dir)).ok()
+    }
+
+    pub fn script_path(&self) -> Option<PathBuf> {
+        self.manifest
+            .script_entry
+            .as_ref()
+            .map(|entry| self.root.join(entry))
+    }
+}
+
+/// One asset-relative path resolved to whichever enabled mod currently
+/// provides it. `shadowed_mods` records lower-priority mods that also ship
+/// this path, so conflicts are surfaced instead of silently dropped.
+#[derive(Clone, Debug)]
+pub struct AssetOverlayEntry {
+    pub winning_mod: String,
+    pub absolute_path: PathBuf,
+    pub shadowed_mods: Vec<String>,
+}
+
+/// Discovers, enables/disables, and mounts mod packages found under a root
+/// directory (typically `mods/`).
+#[derive(Default)]
+pub struct ModManager {
+    mods_root: PathBuf,
+    packages: HashMap<String, ModPackage>,
+    overlay: HashMap<String, AssetOverlayEntry>,
+    sandboxes: HashMap<String, Arc<Mutex<ScriptSandbox>>>,
+    /// Host functions mod scripts may call, gated by [`Self::mod_capabilities`].
+    /// Empty by default, so mods get a sandbox that can call nothing until
+    /// the engine wires one up via [`Self::with_script_api`].
+    script_api: Arc<ScriptApiRegistry>,
+    /// The capability tier every mod script is sandboxed to. Untrusted mod
+    /// code all shares one tier; there's no per-mod escalation.
+    mod_capabilities: CapabilitySet,
+}
+
+impl ModManager {
+    /// Create a manager that will discover mods under `mods_root`.
+    pub fn new(mods_root: impl Into<PathBuf>) -> Self {
+        Self {
+            mods_root: mods_root.into(),
+            packages: HashMap::new(),
+            overlay: HashMap::new(),
+            sandboxes: HashMap::new(),
+            script_api: Arc::new(ScriptApiRegistry::new()),
+            mod_capabilities: CapabilitySet::none(),
+        }
+    }
+
+    /// Sets the host-function registry and capability tier mod scripts are
+    /// sandboxed against. Scripts already loaded aren't retroactively
+    /// rebuilt; call this before [`Self::discover`].
+    pub fn with_script_api(mut self, registry: Arc<ScriptApiRegistry>, capabilities: CapabilitySet) -> Self {
+        self.script_api = registry;
+        self.mod_capabilities = capabilities;
+        self
+    }
+
+    /// Scans `mods_root` for immediate subdirectories containing a
+    /// `mod.toml` manifest, replacing any previously discovered packages.
+    /// Newly discovered mods start enabled. Returns the discovered mod ids.
+    pub fn discover(&mut self) -> Result<Vec<String>> {
+        self.packages.clear();
+        self.sandboxes.clear();
+        let mut discovered = Vec::new();
+
+        if !self.mods_root.is_dir() {
+            self.overlay.clear();
+            return Ok(discovered);
+        }
+
+        for entry in std::fs::read_dir(&self.mods_root)? {
+            let root = entry?.path();
+            if !root.is_dir() {
+                continue;
+            }
+            let manifest_path = root.join("mod.toml");
+            if !manifest_path.is_file() {
+                continue;
+            }
+            let manifest: ModManifest = parse_toml_limited(&manifest_path)
+                .with_context(|| format!("parsing manifest at {}", manifest_path.display()))?;
+            let id = manifest.id.clone();
+            self.packages.insert(
+                id.clone(),
+                ModPackage {
+                    manifest,
+                    root,
+                    enabled: true,
+                },
+            );
+            discovered.push(id);
+        }
+
+        self.rebuild_overlay()?;
+        for id in self.packages.keys().cloned().collect::<Vec<_>>() {
+            self.load_script(&id)?;
+        }
+        Ok(discovered)
+    }
+
+    /// Enables a discovered mod, recomputing the asset overlay and loading
+    /// its script sandbox. No-op if already enabled.
+    pub fn enable(&mut self, id: &str) -> Result<()> {
+        let pkg = self
+            .packages
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("unknown mod: {id}"))?;
+        pkg.enabled = true;
+        self.rebuild_overlay()?;
+        self.load_script(id)?;
+        Ok(())
+    }
+
+    /// Disables a mod, dropping its sandbox and recomputing the overlay so
+    /// any assets it was shadowing become visible again. No restart needed.
+    pub fn disable(&mut self, id: &str) -> Result<()> {
+        let pkg = self
+            .packages
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("unknown mod: {id}"))?;
+        pkg.enabled = false;
+        self.sandboxes.remove(id);
+        self.rebuild_overlay()
+    }
+
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.packages.get(id).map(|p| p.enabled).unwrap_or(false)
+    }
+
+    pub fn packages(&self) -> impl Iterator<Item = &ModPackage> {
+        self.packages.values()
+    }
+
+    /// Resolves an asset-relative path (e.g. `"textures/grass.png"`) to the
+    /// absolute path of whichever enabled mod currently provides it.
+    pub fn resolve_asset(&self, relative_path: &str) -> Option<&Path> {
+        self.overlay
+            .get(relative_path)
+            .map(|entry| entry.absolute_path.as_path())
+    }
+
+    /// Overlay entries where more than one enabled mod ships the same path.
+    pub fn conflicts(&self) -> impl Iterator<Item = (&str, &AssetOverlayEntry)> {
+        self.overlay
+            .iter()
+            .filter(|(_, entry)| !entry.shadowed_mods.is_empty())
+            .map(|(path, entry)| (path.as_str(), entry))
+    }
+
+    /// The sandbox loaded for `id`'s script entry, if it has one and is enabled.
+    pub fn sandbox(&self, id: &str) -> Option<Arc<Mutex<ScriptSandbox>>> {
+        self.sandboxes.get(id).cloned()
+    }
+
+    /// Recomputes the asset overlay from currently enabled mods. Higher
+    /// `load_priority` wins; ties break on `id` ascending for determinism.
+    fn rebuild_overlay(&mut self) -> Result<()> {
+        self.overlay.clear();
+
+        let mut enabled: Vec<&ModPackage> =
+            self.packages.values().filter(|pkg| pkg.enabled).collect();
+        enabled.sort_by(|a, b| {
+            a.manifest
+                .load_priority
+                .cmp(&b.manifest.load_priority)
+                .then_with(|| a.manifest.id.cmp(&b.manifest.id))
+        });
+
+        for pkg in enabled {
+            let Some(assets_dir) = pkg.assets_path() else {
+                continue;
+            };
+            if !assets_dir.is_dir() {
+                continue;
+            }
+
+            for absolute_path in walk_files(&assets_dir)? {
+                let relative = absolute_path
+                    .strip_prefix(&assets_dir)
+                    .expect("walked path is under assets_dir")
+                    .to_string_lossy()
+                    .replace('\\', "/");
+
+                match self.overlay.get_mut(&relative) {
+                    Some(existing) => {
+                        existing.shadowed_mods.push(existing.winning_mod.clone());
+                        existing.winning_mod = pkg.manifest.id.clone();
+                        existing.absolute_path = absolute_path;
+                    }
+                    None => {
+                        self.overlay.insert(
+                            relative,
+                            AssetOverlayEntry {
+                                winning_mod: pkg.manifest.id.clone(),
+                                absolute_path,
+                                shadowed_mods: Vec::new(),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compiles `id`'s script entry (if it has one) into a fresh sandbox.
+    /// Removes any prior sandbox for `id` first so re-enabling reloads it.
+    fn load_script(&mut self, id: &str) -> Result<()> {
+        self.sandboxes.remove(id);
+
+        let pkg = self
+            .packages
+            .get(id)
+            .ok_or_else(|| anyhow!("unknown mod: {id}"))?;
+        if !pkg.enabled {
+            return Ok(());
+        }
+        let Some(script_path) = pkg.script_path() else {
+            return Ok(());
+        };
+        if !script_path.is_file() {
+            bail!("script entry not found: {}", script_path.display());
+        }
+        // Defense in depth: the manifest already names the file, but confirm
+        // it can't have been pointed outside the mod's own root.
+        safe_under(
+            &pkg.root,
+            Path::new(pkg.manifest.script_entry.as_ref().expect("checked above")),
+        )?;
+
+        let source = std::fs::read_to_string(&script_path)?;
+
+        // Build the sandbox from the capability-scoped registry rather than
+        // a bare `Engine::new()`, so mod scripts only see the host
+        // functions `self.mod_capabilities` grants them.
+        let sandbox = self.script_api.build_sandbox(
+            &self.mod_capabilities,
+            ExecutionLimits {
+                max_operations: 10_000,
+                max_memory_bytes: 1024 * 1024,
+                timeout_ms: 1000,
+            },
+        );
+        {
+            let mut engine = sandbox.engine.lock().unwrap();
+            engine.set_max_operations(10_000);
+            engine.set_max_string_size(1_000);
+            engine
+                .compile(&source)
+                .with_context(|| format!("compiling script for mod {id}"))?;
+        }
+        self.sandboxes.insert(id.to_string(), Arc::new(Mutex::new(sandbox)));
+        Ok(())
+    }
+}
+
+fn walk_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut out = Vec::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                out.push(path);
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_manifest(root: &Path, manifest: &str) {
+        fs::write(root.join("mod.toml"), manifest).unwrap();
+    }
+
+    #[test]
+    fn discover_finds_mods_with_manifests() {
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("hello_mod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "hello_mod"
+            name = "Hello Mod"
+            version = "1.0.0"
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        let discovered = manager.discover().unwrap();
+
+        assert_eq!(discovered, vec!["hello_mod".to_string()]);
+        assert!(manager.is_enabled("hello_mod"));
+    }
+
+    #[test]
+    fn discover_skips_directories_without_manifest() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("not_a_mod")).unwrap();
+
+        let mut manager = ModManager::new(temp.path());
+        let discovered = manager.discover().unwrap();
+
+        assert!(discovered.is_empty());
+    }
+
+    #[test]
+    fn discover_on_missing_root_returns_empty() {
+        let mut manager = ModManager::new(Path::new("/nonexistent/mods/root"));
+        let discovered = manager.discover().unwrap();
+        assert!(discovered.is_empty());
+    }
+
+    #[test]
+    fn assets_are_mounted_into_the_overlay() {
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("skin_mod");
+        let assets_dir = mod_dir.join("assets");
+        fs::create_dir_all(assets_dir.join("textures")).unwrap();
+        fs::write(assets_dir.join("textures/grass.png"), b"png-bytes").unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "skin_mod"
+            name = "Skin Mod"
+            version = "1.0.0"
+            assets_dir = "assets"
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        manager.discover().unwrap();
+
+        let resolved = manager.resolve_asset("textures/grass.png").unwrap();
+        assert_eq!(
+            resolved,
+            assets_dir.join("textures/grass.png").as_path()
+        );
+    }
+
+    #[test]
+    fn assets_dir_with_parent_dir_component_is_rejected_without_mounting_it() {
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("evil_mod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        // A sibling directory the mod has no business exposing.
+        let secret_dir = temp.path().join("secret");
+        fs::create_dir_all(&secret_dir).unwrap();
+        fs::write(secret_dir.join("passwords.txt"), b"hunter2").unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "evil_mod"
+            name = "Evil Mod"
+            version = "1.0.0"
+            assets_dir = "../secret"
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        manager.discover().unwrap();
+
+        assert!(manager
+            .packages()
+            .find(|p| p.manifest.id == "evil_mod")
+            .unwrap()
+            .assets_path()
+            .is_none());
+        assert!(manager.resolve_asset("passwords.txt").is_none());
+        assert_eq!(manager.conflicts().count(), 0);
+    }
+
+    #[test]
+    fn higher_priority_mod_wins_and_conflict_is_recorded() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let low = temp.path().join("low_prio");
+        fs::create_dir_all(low.join("assets")).unwrap();
+        fs::write(low.join("assets/texture.png"), b"low").unwrap();
+        write_manifest(
+            &low,
+            r#"
+            id = "low_prio"
+            name = "Low"
+            version = "1.0.0"
+            assets_dir = "assets"
+            load_priority = 0
+            "#,
+        );
+
+        let high = temp.path().join("high_prio");
+        fs::create_dir_all(high.join("assets")).unwrap();
+        fs::write(high.join("assets/texture.png"), b"high").unwrap();
+        write_manifest(
+            &high,
+            r#"
+            id = "high_prio"
+            name = "High"
+            version = "1.0.0"
+            assets_dir = "assets"
+            load_priority = 10
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        manager.discover().unwrap();
+
+        let resolved = manager.resolve_asset("texture.png").unwrap();
+        assert!(resolved.starts_with(&high));
+
+        let conflicts: Vec<_> = manager.conflicts().collect();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].1.winning_mod, "high_prio");
+        assert_eq!(conflicts[0].1.shadowed_mods, vec!["low_prio".to_string()]);
+    }
+
+    #[test]
+    fn disable_removes_sandbox_and_uncovers_shadowed_asset() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let low = temp.path().join("low_prio");
+        fs::create_dir_all(low.join("assets")).unwrap();
+        fs::write(low.join("assets/texture.png"), b"low").unwrap();
+        write_manifest(
+            &low,
+            r#"
+            id = "low_prio"
+            name = "Low"
+            version = "1.0.0"
+            assets_dir = "assets"
+            load_priority = 0
+            "#,
+        );
+
+        let high = temp.path().join("high_prio");
+        fs::create_dir_all(high.join("assets")).unwrap();
+        fs::write(high.join("assets/texture.png"), b"high").unwrap();
+        fs::write(high.join("script.rhai"), "let x = 1;").unwrap();
+        write_manifest(
+            &high,
+            r#"
+            id = "high_prio"
+            name = "High"
+            version = "1.0.0"
+            assets_dir = "assets"
+            script_entry = "script.rhai"
+            load_priority = 10
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        manager.discover().unwrap();
+        assert!(manager.sandbox("high_prio").is_some());
+
+        manager.disable("high_prio").unwrap();
+
+        assert!(manager.sandbox("high_prio").is_none());
+        assert!(!manager.is_enabled("high_prio"));
+        let resolved = manager.resolve_asset("texture.png").unwrap();
+        assert!(resolved.starts_with(&low));
+        assert_eq!(manager.conflicts().count(), 0);
+    }
+
+    #[test]
+    fn enable_reloads_the_script_sandbox() {
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("script_mod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        fs::write(mod_dir.join("script.rhai"), "let x = 1;").unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "script_mod"
+            name = "Script Mod"
+            version = "1.0.0"
+            script_entry = "script.rhai"
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        manager.discover().unwrap();
+        assert!(manager.sandbox("script_mod").is_some());
+
+        manager.disable("script_mod").unwrap();
+        assert!(manager.sandbox("script_mod").is_none());
+
+        manager.enable("script_mod").unwrap();
+        assert!(manager.sandbox("script_mod").is_some());
+    }
+
+    #[test]
+    fn load_script_grants_only_the_capabilities_wired_in() {
+        use crate::capability::Capability;
+
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("api_mod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        fs::write(
+            mod_dir.join("script.rhai"),
+            "let health = get_health(); spawn_prop()",
+        )
+        .unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "api_mod"
+            name = "Api Mod"
+            version = "1.0.0"
+            script_entry = "script.rhai"
+            "#,
+        );
+
+        let mut registry = ScriptApiRegistry::new();
+        registry.register("get_health", Capability::ReadWorld, |engine| {
+            engine.register_fn("get_health", || 100_i64);
+        });
+        registry.register("spawn_prop", Capability::SpawnEntity, |engine| {
+            engine.register_fn("spawn_prop", || 0_i64);
+        });
+
+        let mut manager = ModManager::new(temp.path())
+            .with_script_api(Arc::new(registry), CapabilitySet::none().with(Capability::ReadWorld));
+
+        let discovered = manager.discover().unwrap();
+        assert_eq!(discovered, vec!["api_mod".to_string()]);
+
+        let sandbox = manager.sandbox("api_mod").unwrap();
+        assert!(sandbox.lock().unwrap().allowed_functions.contains_key("get_health"));
+        assert!(!sandbox.lock().unwrap().allowed_functions.contains_key("spawn_prop"));
+    }
+
+    #[test]
+    fn invalid_script_fails_discovery() {
+        let temp = tempfile::tempdir().unwrap();
+        let mod_dir = temp.path().join("broken_mod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        fs::write(mod_dir.join("script.rhai"), "let x = ;").unwrap();
+        write_manifest(
+            &mod_dir,
+            r#"
+            id = "broken_mod"
+            name = "Broken Mod"
+            version = "1.0.0"
+            script_entry = "script.rhai"
+            "#,
+        );
+
+        let mut manager = ModManager::new(temp.path());
+        let result = manager.discover();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn enable_unknown_mod_errors() {
+        let mut manager = ModManager::new(Path::new("/tmp/does-not-matter"));
+        assert!(manager.enable("nope").is_err());
+    }
+
+    #[test]
+    fn resolve_missing_asset_returns_none() {
+        let temp = tempfile::tempdir().unwrap();
+        let manager = ModManager::new(temp.path());
+        assert!(manager.resolve_asset("nothing.png").is_none());
+    }
+}
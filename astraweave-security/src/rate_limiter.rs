@@ -0,0 +1,343 @@
+//! Sliding-window rate limiting and abuse detection for networked RPCs.
+//!
+//! Nothing in this crate limited how often a client could send any given
+//! RPC — a scripted client spamming `ClientProposePlan` or `ClientInput`
+//! costs the server real compute with no anti-cheat consequence.
+//! [`RateLimiter`] tracks a per-(entity, RPC name) sliding window of recent
+//! call timestamps, and [`enforce_rate_limit`] feeds violations into the
+//! same [`CAntiCheat`] trust bookkeeping [`crate::anti_cheat::validate_reported_state`]
+//! uses, so a spamming client eventually gets warned, throttled, or
+//! disconnected through the machinery that already handles cheating.
+
+use crate::{CAntiCheat, TelemetryData, TelemetryEvent, TelemetrySeverity};
+use astraweave_ecs::{Entity, World};
+use std::collections::{HashMap, VecDeque};
+
+/// Server-configured limits for [`RateLimiter`]/[`enforce_rate_limit`].
+#[derive(Clone, Debug)]
+pub struct RateLimitPolicy {
+    /// Width of the sliding window, in seconds.
+    pub window_secs: u64,
+    /// Calls allowed within the window before it's flagged as abuse.
+    pub max_calls_per_window: usize,
+    /// Trust score subtracted (additively, floored at `0.0`) per violation.
+    pub trust_penalty_per_violation: f32,
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self {
+            window_secs: 1,
+            max_calls_per_window: 20,
+            trust_penalty_per_violation: 0.1,
+        }
+    }
+}
+
+/// What [`enforce_rate_limit`] recommends for the RPC that triggered it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RpcDecision {
+    /// Within policy; process the RPC normally.
+    Allow,
+    /// Over the limit; drop this call but keep the connection.
+    Throttle,
+    /// Over double the limit; the caller should disconnect the client.
+    Disconnect,
+}
+
+/// How many [`RateLimiter::record_call`]s between idle-window sweeps.
+/// A `(entity, rpc)` entry that stops being called (disconnect, or the
+/// client just stops spamming that RPC) never trims itself again — only
+/// this periodic sweep reclaims it, so the map can't grow without bound
+/// over a long-running server no matter how many distinct clients/RPCs
+/// come and go.
+const SWEEP_INTERVAL_CALLS: u32 = 256;
+
+/// Tracks recent call timestamps per `(entity, RPC name)`. Insert as a
+/// `World` resource alongside [`CAntiCheat`] components.
+#[derive(Default)]
+pub struct RateLimiter {
+    windows: HashMap<(Entity, String), VecDeque<u64>>,
+    calls_since_sweep: u32,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one call to `rpc_name` by `entity` at `now`, evicts
+    /// timestamps that have aged out of the window, and classifies the
+    /// resulting call rate against `policy`.
+    pub fn record_call(&mut self, entity: Entity, rpc_name: &str, policy: &RateLimitPolicy, now: u64) -> RpcDecision {
+        let window = self.windows.entry((entity, rpc_name.to_string())).or_default();
+        window.push_back(now);
+        while let Some(&oldest) = window.front() {
+            if now.saturating_sub(oldest) > policy.window_secs {
+                window.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let decision = if window.len() > policy.max_calls_per_window * 2 {
+            RpcDecision::Disconnect
+        } else if window.len() > policy.max_calls_per_window {
+            RpcDecision::Throttle
+        } else {
+            RpcDecision::Allow
+        };
+
+        self.calls_since_sweep += 1;
+        if self.calls_since_sweep >= SWEEP_INTERVAL_CALLS {
+            self.calls_since_sweep = 0;
+            self.evict_idle(now, policy.window_secs);
+        }
+
+        decision
+    }
+
+    /// Drops every `(entity, rpc)` window whose most recent call is older
+    /// than `idle_after_secs` relative to `now` — such a window carries no
+    /// information for future rate-limit decisions (a fresh call would
+    /// trim it back to empty anyway), so keeping it around only leaks
+    /// memory for clients that went idle or disconnected. Called
+    /// automatically every [`SWEEP_INTERVAL_CALLS`] calls; also safe to
+    /// call directly (e.g. on a periodic maintenance tick).
+    pub fn evict_idle(&mut self, now: u64, idle_after_secs: u64) {
+        self.windows
+            .retain(|_, window| window.back().is_some_and(|&newest| now.saturating_sub(newest) <= idle_after_secs));
+    }
+
+    /// Drops every window belonging to `entity`. Call this from a
+    /// despawn/disconnect hook to reclaim its windows immediately instead
+    /// of waiting for the next idle sweep.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.windows.retain(|(e, _), _| *e != entity);
+    }
+}
+
+/// Records `rpc_name` being called by `entity`, and on any non-`Allow`
+/// decision decays the entity's [`CAntiCheat`] trust score and records
+/// telemetry, mirroring [`crate::anti_cheat::validate_reported_state`]'s
+/// borrow ordering (mutate the component, extract owned locals, then fetch
+/// the telemetry resource) so both borrows of `world` succeed under NLL.
+pub fn enforce_rate_limit(
+    world: &mut World,
+    limiter: &mut RateLimiter,
+    policy: &RateLimitPolicy,
+    entity: Entity,
+    rpc_name: &str,
+    now: u64,
+) -> RpcDecision {
+    let decision = limiter.record_call(entity, rpc_name, policy, now);
+    if decision == RpcDecision::Allow {
+        return decision;
+    }
+
+    let Some(anti_cheat) = world.get_mut::<CAntiCheat>(entity) else {
+        return decision;
+    };
+    anti_cheat.trust_score = (anti_cheat.trust_score - policy.trust_penalty_per_violation).max(0.0);
+    anti_cheat.anomaly_flags.push(format!("rpc_rate_limit:{rpc_name}"));
+    anti_cheat.last_validation = now;
+    let player_id = anti_cheat.player_id.clone();
+    let trust_score = anti_cheat.trust_score;
+
+    if let Some(telemetry) = world.get_resource_mut::<TelemetryData>() {
+        telemetry.events.push(TelemetryEvent {
+            timestamp: now,
+            event_type: "rpc_rate_limit_exceeded".to_string(),
+            severity: if decision == RpcDecision::Disconnect { TelemetrySeverity::Critical } else { TelemetrySeverity::Warning },
+            data: serde_json::json!({
+                "player_id": player_id,
+                "rpc": rpc_name,
+                "decision": format!("{decision:?}"),
+                "trust_score": trust_score,
+            }),
+        });
+    }
+
+    decision
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    fn anti_cheat() -> CAntiCheat {
+        CAntiCheat {
+            player_id: "p1".to_string(),
+            trust_score: 1.0,
+            last_validation: 0,
+            anomaly_flags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn calls_within_the_limit_are_allowed() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy::default();
+        let entity = World::new().spawn();
+
+        for i in 0..policy.max_calls_per_window {
+            assert_eq!(limiter.record_call(entity, "ping", &policy, i as u64), RpcDecision::Allow);
+        }
+    }
+
+    #[test]
+    fn calls_over_the_limit_are_throttled_then_disconnected() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 100,
+            max_calls_per_window: 2,
+            trust_penalty_per_violation: 0.1,
+        };
+        let entity = World::new().spawn();
+
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Allow);
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Allow);
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Throttle);
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Throttle);
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Disconnect);
+    }
+
+    #[test]
+    fn old_calls_age_out_of_the_window() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 1,
+            max_calls_per_window: 1,
+            trust_penalty_per_violation: 0.1,
+        };
+        let entity = World::new().spawn();
+
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 0), RpcDecision::Allow);
+        assert_eq!(limiter.record_call(entity, "ping", &policy, 10), RpcDecision::Allow);
+    }
+
+    #[test]
+    fn different_rpcs_have_independent_windows() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 100,
+            max_calls_per_window: 1,
+            trust_penalty_per_violation: 0.1,
+        };
+        let entity = World::new().spawn();
+
+        assert_eq!(limiter.record_call(entity, "move", &policy, 0), RpcDecision::Allow);
+        assert_eq!(limiter.record_call(entity, "attack", &policy, 0), RpcDecision::Allow);
+    }
+
+    #[test]
+    fn enforce_rate_limit_decays_trust_and_records_telemetry_on_throttle() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let entity = world.spawn();
+        world.insert(entity, anti_cheat());
+
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 100,
+            max_calls_per_window: 1,
+            trust_penalty_per_violation: 0.2,
+        };
+
+        enforce_rate_limit(&mut world, &mut limiter, &policy, entity, "ping", 0);
+        let decision = enforce_rate_limit(&mut world, &mut limiter, &policy, entity, "ping", 0);
+
+        assert_eq!(decision, RpcDecision::Throttle);
+        assert!(world.get::<CAntiCheat>(entity).unwrap().trust_score < 1.0);
+        assert!(!world.get_resource::<TelemetryData>().unwrap().events.is_empty());
+    }
+
+    #[test]
+    fn evict_idle_drops_windows_with_no_recent_calls() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy::default();
+        let entity = World::new().spawn();
+
+        limiter.record_call(entity, "ping", &policy, 0);
+        assert_eq!(limiter.windows.len(), 1);
+
+        limiter.evict_idle(1_000, 60);
+        assert!(limiter.windows.is_empty());
+    }
+
+    #[test]
+    fn evict_idle_keeps_windows_with_recent_calls() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy::default();
+        let entity = World::new().spawn();
+
+        limiter.record_call(entity, "ping", &policy, 100);
+        limiter.evict_idle(105, 60);
+
+        assert_eq!(limiter.windows.len(), 1);
+    }
+
+    #[test]
+    fn remove_entity_drops_only_that_entitys_windows() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy::default();
+        let mut world = World::new();
+        let a = world.spawn();
+        let b = world.spawn();
+
+        limiter.record_call(a, "ping", &policy, 0);
+        limiter.record_call(b, "ping", &policy, 0);
+
+        limiter.remove_entity(a);
+
+        assert_eq!(limiter.windows.len(), 1);
+        assert!(limiter.windows.contains_key(&(b, "ping".to_string())));
+    }
+
+    #[test]
+    fn record_call_periodically_sweeps_idle_windows_on_its_own() {
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 1,
+            max_calls_per_window: 1_000,
+            trust_penalty_per_violation: 0.1,
+        };
+        let mut world = World::new();
+        let stale_entity = world.spawn();
+        let active_entity = world.spawn();
+
+        limiter.record_call(stale_entity, "ping", &policy, 0);
+        for i in 0..SWEEP_INTERVAL_CALLS {
+            limiter.record_call(active_entity, "ping", &policy, 100 + i as u64);
+        }
+
+        assert!(!limiter.windows.contains_key(&(stale_entity, "ping".to_string())));
+    }
+
+    #[test]
+    fn enforce_rate_limit_is_a_no_op_without_a_cantiheat_component() {
+        let mut world = World::new();
+        world.insert_resource(telemetry());
+        let entity = world.spawn();
+
+        let mut limiter = RateLimiter::new();
+        let policy = RateLimitPolicy {
+            window_secs: 100,
+            max_calls_per_window: 0,
+            trust_penalty_per_violation: 0.2,
+        };
+
+        let decision = enforce_rate_limit(&mut world, &mut limiter, &policy, entity, "ping", 0);
+
+        assert_eq!(decision, RpcDecision::Throttle);
+        assert!(world.get_resource::<TelemetryData>().unwrap().events.is_empty());
+    }
+}
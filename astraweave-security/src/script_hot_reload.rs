@@ -0,0 +1,327 @@
+//! Rhai script hot-reload
+//!
+//! Wires [`AssetDatabase`]'s hot-reload signal (as raised by
+//! `astraweave_asset::AssetWatcher` for `Script`-kind assets) into the
+//! scripting plugin: a changed `.rhai` file is recompiled into its
+//! sandbox's engine, its persistent script-scope variables are carried
+//! across the reload via `rhai::Scope`, and compile failures are reported
+//! as [`TelemetryEvent`]s instead of silently leaving the old script
+//! running unannounced.
+
+use crate::{ScriptSandbox, TelemetryData, TelemetryEvent, TelemetrySeverity};
+use astraweave_asset::{AssetDatabase, AssetKind};
+use rhai::Scope;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// One script's sandbox plus the persistent scope and last-seen source
+/// carried across reloads.
+struct WatchedScript {
+    path: String,
+    sandbox: Arc<Mutex<ScriptSandbox>>,
+    scope: Scope<'static>,
+    last_seen_source: String,
+}
+
+/// Recompiles `.rhai` scripts in place when [`AssetDatabase`]'s hot-reload
+/// signal fires and a watched script's contents actually changed,
+/// preserving persistent scope variables where the reloaded script still
+/// declares them.
+pub struct ScriptHotReloader {
+    db: Arc<Mutex<AssetDatabase>>,
+    scripts: HashMap<String, WatchedScript>,
+}
+
+impl ScriptHotReloader {
+    pub fn new(db: Arc<Mutex<AssetDatabase>>) -> Self {
+        Self {
+            db,
+            scripts: HashMap::new(),
+        }
+    }
+
+    /// Registers a script's sandbox for hot-reload tracking. `guid` is the
+    /// asset GUID under which `AssetDatabase` tracks the `.rhai` file at
+    /// `path` (expected to be registered there as [`AssetKind::Script`]).
+    pub fn watch(
+        &mut self,
+        guid: impl Into<String>,
+        path: impl Into<String>,
+        sandbox: Arc<Mutex<ScriptSandbox>>,
+    ) {
+        let path = path.into();
+        let last_seen_source = std::fs::read_to_string(&path).unwrap_or_default();
+        self.scripts.insert(
+            guid.into(),
+            WatchedScript {
+                path,
+                sandbox,
+                scope: Scope::new(),
+                last_seen_source,
+            },
+        );
+    }
+
+    pub fn unwatch(&mut self, guid: &str) {
+        self.scripts.remove(guid);
+    }
+
+    pub fn is_watching(&self, guid: &str) -> bool {
+        self.scripts.contains_key(guid)
+    }
+
+    /// Checks watched scripts for content changes since the last poll and
+    /// recompiles the ones that changed. Only runs the (cheap) per-script
+    /// diff when `AssetDatabase`'s hot-reload signal has fired since the
+    /// last call. Returns the guids that were reloaded successfully;
+    /// compile or eval failures leave the previous script and scope
+    /// untouched and are reported via `telemetry`.
+    pub fn poll(&mut self, telemetry: &mut TelemetryData) -> Vec<String> {
+        {
+            let mut db = self.db.lock().unwrap();
+            if !db.hot_reload_rx.has_changed().unwrap_or(false) {
+                return Vec::new();
+            }
+            db.hot_reload_rx.borrow_and_update();
+        }
+
+        let mut reloaded = Vec::new();
+        for guid in self.scripts.keys().cloned().collect::<Vec<_>>() {
+            if !self.is_script_asset(&guid) {
+                continue;
+            }
+
+            let current_source = {
+                let watched = &self.scripts[&guid];
+                std::fs::read_to_string(&watched.path).unwrap_or_default()
+            };
+            if current_source == self.scripts[&guid].last_seen_source {
+                continue;
+            }
+
+            match self.reload_one(&guid, &current_source) {
+                Ok(()) => reloaded.push(guid.clone()),
+                Err(err) => {
+                    let path = self.scripts[&guid].path.clone();
+                    telemetry.events.push(TelemetryEvent {
+                        timestamp: crate::now_secs(),
+                        event_type: "script_hot_reload_failed".to_string(),
+                        severity: TelemetrySeverity::Critical,
+                        data: serde_json::json!({
+                            "guid": guid,
+                            "path": path,
+                            "error": err,
+                        }),
+                    });
+                }
+            }
+            self.scripts.get_mut(&guid).unwrap().last_seen_source = current_source;
+        }
+        reloaded
+    }
+
+    /// Watched scripts are always intended to be `Script`-kind assets, but
+    /// if the caller registered the wrong guid (or the database doesn't
+    /// know about it yet) skip rather than reload something unexpected.
+    fn is_script_asset(&self, guid: &str) -> bool {
+        self.db
+            .lock()
+            .unwrap()
+            .get_asset(guid)
+            .map(|meta| meta.kind == AssetKind::Script)
+            .unwrap_or(true)
+    }
+
+    fn reload_one(&mut self, guid: &str, source: &str) -> Result<(), String> {
+        let watched = self
+            .scripts
+            .get_mut(guid)
+            .ok_or_else(|| format!("no watched script for guid {guid}"))?;
+
+        let engine_arc = watched.sandbox.lock().unwrap().engine.clone();
+        let engine = engine_arc.lock().unwrap();
+
+        // Compile first so a syntax error leaves the previous scope/engine
+        // state untouched, then re-run against the existing scope so
+        // variables the new script still declares keep their values.
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+        engine
+            .eval_ast_with_scope::<rhai::Dynamic>(&mut watched.scope, &ast)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    fn sandbox() -> Arc<Mutex<ScriptSandbox>> {
+        let mut engine = rhai::Engine::new();
+        engine.set_max_operations(10_000);
+        Arc::new(Mutex::new(ScriptSandbox {
+            engine: Arc::new(Mutex::new(engine)),
+            allowed_functions: HashMap::new(),
+            execution_limits: crate::ExecutionLimits {
+                max_operations: 10_000,
+                max_memory_bytes: 1024 * 1024,
+                timeout_ms: 1000,
+            },
+        }))
+    }
+
+    fn register_script(db: &mut AssetDatabase, path: &std::path::Path) -> String {
+        db.register_asset(path, AssetKind::Script, vec![]).unwrap()
+    }
+
+    #[test]
+    fn poll_with_no_signal_returns_empty() {
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let mut reloader = ScriptHotReloader::new(db);
+        let mut tel = telemetry();
+        assert!(reloader.poll(&mut tel).is_empty());
+    }
+
+    #[test]
+    fn poll_recompiles_changed_script_and_reports_success() {
+        let temp = tempfile::tempdir().unwrap();
+        let script_path = temp.path().join("greet.rhai");
+        std::fs::write(&script_path, "let greeting = \"hello\";").unwrap();
+
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let guid = register_script(&mut db.lock().unwrap(), &script_path);
+
+        let mut reloader = ScriptHotReloader::new(db.clone());
+        reloader.watch(
+            guid.clone(),
+            script_path.to_string_lossy().to_string(),
+            sandbox(),
+        );
+
+        // Simulate AssetWatcher noticing the file changed.
+        std::fs::write(&script_path, "let greeting = \"goodbye\";").unwrap();
+        db.lock().unwrap().invalidate_asset(&guid).unwrap();
+
+        let mut tel = telemetry();
+        let reloaded = reloader.poll(&mut tel);
+
+        assert_eq!(reloaded, vec![guid]);
+        assert!(tel.events.is_empty());
+    }
+
+    #[test]
+    fn poll_ignores_unchanged_script_even_when_signal_fires() {
+        let temp = tempfile::tempdir().unwrap();
+        let script_path = temp.path().join("static.rhai");
+        std::fs::write(&script_path, "let x = 1;").unwrap();
+
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let guid = register_script(&mut db.lock().unwrap(), &script_path);
+
+        let mut reloader = ScriptHotReloader::new(db.clone());
+        reloader.watch(
+            guid.clone(),
+            script_path.to_string_lossy().to_string(),
+            sandbox(),
+        );
+
+        // Some unrelated asset was invalidated; our script's file didn't change.
+        db.lock().unwrap().invalidate_asset("unrelated").unwrap();
+
+        let mut tel = telemetry();
+        assert!(reloader.poll(&mut tel).is_empty());
+    }
+
+    #[test]
+    fn poll_reports_compile_errors_as_critical_telemetry() {
+        let temp = tempfile::tempdir().unwrap();
+        let script_path = temp.path().join("broken.rhai");
+        std::fs::write(&script_path, "let x = 1;").unwrap();
+
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let guid = register_script(&mut db.lock().unwrap(), &script_path);
+
+        let mut reloader = ScriptHotReloader::new(db.clone());
+        reloader.watch(
+            guid.clone(),
+            script_path.to_string_lossy().to_string(),
+            sandbox(),
+        );
+
+        let mut file = std::fs::File::create(&script_path).unwrap();
+        write!(file, "let x = ;").unwrap();
+        drop(file);
+        db.lock().unwrap().invalidate_asset(&guid).unwrap();
+
+        let mut tel = telemetry();
+        let reloaded = reloader.poll(&mut tel);
+
+        assert!(reloaded.is_empty());
+        assert_eq!(tel.events.len(), 1);
+        assert_eq!(tel.events[0].severity, TelemetrySeverity::Critical);
+        assert_eq!(tel.events[0].event_type, "script_hot_reload_failed");
+    }
+
+    #[test]
+    fn persistent_scope_variables_survive_reload() {
+        let temp = tempfile::tempdir().unwrap();
+        let script_path = temp.path().join("counter.rhai");
+        std::fs::write(&script_path, "let count = 0;").unwrap();
+
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let guid = register_script(&mut db.lock().unwrap(), &script_path);
+
+        let mut reloader = ScriptHotReloader::new(db.clone());
+        reloader.watch(
+            guid.clone(),
+            script_path.to_string_lossy().to_string(),
+            sandbox(),
+        );
+
+        // Simulate the script's initial load elsewhere having already
+        // populated persistent scope state.
+        reloader
+            .scripts
+            .get_mut(&guid)
+            .unwrap()
+            .scope
+            .set_value("count", 5_i64);
+
+        std::fs::write(&script_path, "count += 1;").unwrap();
+        db.lock().unwrap().invalidate_asset(&guid).unwrap();
+
+        let mut tel = telemetry();
+        let reloaded = reloader.poll(&mut tel);
+        assert_eq!(reloaded, vec![guid.clone()]);
+
+        let count: i64 = reloader
+            .scripts
+            .get(&guid)
+            .unwrap()
+            .scope
+            .get_value("count")
+            .unwrap();
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn unwatch_stops_tracking_a_script() {
+        let db = Arc::new(Mutex::new(AssetDatabase::new()));
+        let mut reloader = ScriptHotReloader::new(db);
+        reloader.watch("guid-1", "path.rhai", sandbox());
+        assert!(reloader.is_watching("guid-1"));
+
+        reloader.unwatch("guid-1");
+        assert!(!reloader.is_watching("guid-1"));
+    }
+}
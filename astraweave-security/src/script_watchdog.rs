@@ -0,0 +1,295 @@
+//! Runtime enforcement for [`ExecutionLimits`]
+//!
+//! [`ScriptSandbox::execution_limits`] carries `max_operations`,
+//! `max_memory_bytes` and `timeout_ms`, but nothing previously enforced
+//! them: [`crate::execute_script_sandboxed`]'s `tokio::time::timeout` only
+//! gives up on *waiting* for the blocking task — the Rhai engine (and the
+//! sandbox's `Mutex`) keeps running underneath it forever, so a runaway
+//! script leaves the sandbox permanently locked. [`run_watched`] wires the
+//! limits into the engine itself: an [`rhai::Engine::on_progress`] hook
+//! counts operations and is also the only point at which Rhai lets a
+//! script be aborted mid-evaluation, so a background watchdog thread flips
+//! a shared flag on timeout and the next progress tick terminates the
+//! script. `max_memory_bytes` has no direct Rhai hook to observe live heap
+//! use, so it's applied as a best-effort cap on string/array/map sizes
+//! (Rhai's own construction-time limits) derived from the byte budget.
+
+use crate::{ExecutionLimits, ScriptSandbox, TelemetryData, TelemetryEvent, TelemetrySeverity};
+use rhai::Dynamic;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Why a watched script stopped before returning a value normally.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchdogViolation {
+    OperationLimitExceeded { operations: u64, limit: u64 },
+    TimedOut { limit_ms: u64 },
+}
+
+/// Result of [`run_watched`].
+#[derive(Debug)]
+pub enum WatchdogOutcome {
+    Completed(Dynamic),
+    Violated(WatchdogViolation),
+    ScriptError(String),
+}
+
+/// Compiles and evaluates `script` against `sandbox`'s engine, enforcing
+/// `sandbox.execution_limits`. Violations are recorded as `Critical`
+/// [`TelemetryEvent`]s in `telemetry` in addition to being returned.
+pub fn run_watched(
+    script: &str,
+    sandbox: &ScriptSandbox,
+    context: HashMap<String, Dynamic>,
+    telemetry: &mut TelemetryData,
+) -> WatchdogOutcome {
+    let limits = sandbox.execution_limits.clone();
+    let operations = Arc::new(AtomicU64::new(0));
+    let abort = Arc::new(AtomicBool::new(false));
+
+    let watchdog_abort = abort.clone();
+    let timeout_ms = limits.timeout_ms;
+    let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+    let watchdog = std::thread::spawn(move || {
+        if done_rx.recv_timeout(Duration::from_millis(timeout_ms)).is_err() {
+            watchdog_abort.store(true, Ordering::SeqCst);
+        }
+    });
+
+    let outcome = {
+        let mut engine = sandbox.engine.lock().unwrap();
+        apply_memory_caps(&mut engine, &limits);
+
+        let progress_operations = operations.clone();
+        let progress_abort = abort.clone();
+        let max_operations = limits.max_operations;
+        engine.on_progress(move |count| {
+            progress_operations.store(count, Ordering::Relaxed);
+            if progress_abort.load(Ordering::Relaxed) || count > max_operations {
+                Some(Dynamic::UNIT)
+            } else {
+                None
+            }
+        });
+
+        let result = compile_and_eval(&engine, script, context);
+        engine.on_progress(|_| None);
+        result
+    };
+
+    let _ = done_tx.send(());
+    let _ = watchdog.join();
+
+    classify(outcome, &operations, &abort, &limits, telemetry)
+}
+
+fn compile_and_eval(
+    engine: &rhai::Engine,
+    script: &str,
+    context: HashMap<String, Dynamic>,
+) -> Result<Dynamic, String> {
+    let ast = engine.compile(script).map_err(|e| e.to_string())?;
+    let mut scope = rhai::Scope::new();
+    for (key, value) in context {
+        scope.push(key, value);
+    }
+    engine
+        .eval_ast_with_scope::<Dynamic>(&mut scope, &ast)
+        .map_err(|e| e.to_string())
+}
+
+fn classify(
+    result: Result<Dynamic, String>,
+    operations: &AtomicU64,
+    abort: &AtomicBool,
+    limits: &ExecutionLimits,
+    telemetry: &mut TelemetryData,
+) -> WatchdogOutcome {
+    let value = match result {
+        Ok(value) => return WatchdogOutcome::Completed(value),
+        Err(err) => err,
+    };
+
+    let ops = operations.load(Ordering::Relaxed);
+    if ops > limits.max_operations {
+        let violation = WatchdogViolation::OperationLimitExceeded {
+            operations: ops,
+            limit: limits.max_operations,
+        };
+        record_violation(telemetry, &violation);
+        return WatchdogOutcome::Violated(violation);
+    }
+    if abort.load(Ordering::Relaxed) {
+        let violation = WatchdogViolation::TimedOut {
+            limit_ms: limits.timeout_ms,
+        };
+        record_violation(telemetry, &violation);
+        return WatchdogOutcome::Violated(violation);
+    }
+
+    WatchdogOutcome::ScriptError(value)
+}
+
+/// Derives best-effort string/array/map size caps from `max_memory_bytes`.
+/// Rhai has no API to observe live heap usage during evaluation, so this
+/// bounds the size of any single collection a script can construct instead
+/// of tracking cumulative allocation.
+fn apply_memory_caps(engine: &mut rhai::Engine, limits: &ExecutionLimits) {
+    let string_cap = (limits.max_memory_bytes / 4).max(64);
+    let collection_cap = (limits.max_memory_bytes / 64).max(16);
+    engine.set_max_string_size(string_cap);
+    engine.set_max_array_size(collection_cap);
+    engine.set_max_map_size(collection_cap);
+}
+
+fn record_violation(telemetry: &mut TelemetryData, violation: &WatchdogViolation) {
+    let (event_type, data) = match violation {
+        WatchdogViolation::OperationLimitExceeded { operations, limit } => (
+            "script_operation_limit_exceeded",
+            serde_json::json!({ "operations": operations, "limit": limit }),
+        ),
+        WatchdogViolation::TimedOut { limit_ms } => (
+            "script_execution_timed_out",
+            serde_json::json!({ "limit_ms": limit_ms }),
+        ),
+    };
+    telemetry.events.push(TelemetryEvent {
+        timestamp: crate::now_secs(),
+        event_type: event_type.to_string(),
+        severity: TelemetrySeverity::Critical,
+        data,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn sandbox(execution_limits: ExecutionLimits) -> ScriptSandbox {
+        ScriptSandbox {
+            engine: Arc::new(Mutex::new(rhai::Engine::new())),
+            allowed_functions: HashMap::new(),
+            execution_limits,
+        }
+    }
+
+    fn telemetry() -> TelemetryData {
+        TelemetryData {
+            events: Vec::new(),
+            session_start: std::time::Instant::now(),
+            anomaly_count: 0,
+        }
+    }
+
+    #[test]
+    fn completes_normally_within_limits() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: 10_000,
+            max_memory_bytes: 1_000_000,
+            timeout_ms: 5_000,
+        });
+        let mut telemetry = telemetry();
+
+        let outcome = run_watched("1 + 1", &sandbox, HashMap::new(), &mut telemetry);
+
+        match outcome {
+            WatchdogOutcome::Completed(value) => assert_eq!(value.as_int().unwrap(), 2),
+            other => panic!("expected Completed, got {other:?}"),
+        }
+        assert!(telemetry.events.is_empty());
+    }
+
+    #[test]
+    fn operation_limit_is_enforced() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: 50,
+            max_memory_bytes: 1_000_000,
+            timeout_ms: 5_000,
+        });
+        let mut telemetry = telemetry();
+
+        let outcome = run_watched("let x = 0; loop { x += 1; }", &sandbox, HashMap::new(), &mut telemetry);
+
+        match outcome {
+            WatchdogOutcome::Violated(WatchdogViolation::OperationLimitExceeded { limit, .. }) => {
+                assert_eq!(limit, 50);
+            }
+            other => panic!("expected OperationLimitExceeded, got {other:?}"),
+        }
+        assert_eq!(telemetry.events.len(), 1);
+        assert_eq!(telemetry.events[0].severity, TelemetrySeverity::Critical);
+        assert_eq!(telemetry.events[0].event_type, "script_operation_limit_exceeded");
+    }
+
+    #[test]
+    fn timeout_is_enforced_when_operation_count_stays_low() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: u64::MAX,
+            max_memory_bytes: 1_000_000,
+            timeout_ms: 20,
+        });
+        let mut telemetry = telemetry();
+
+        let outcome = run_watched("let x = 0; loop { x += 1; }", &sandbox, HashMap::new(), &mut telemetry);
+
+        match outcome {
+            WatchdogOutcome::Violated(WatchdogViolation::TimedOut { limit_ms }) => {
+                assert_eq!(limit_ms, 20);
+            }
+            other => panic!("expected TimedOut, got {other:?}"),
+        }
+        assert_eq!(telemetry.events[0].event_type, "script_execution_timed_out");
+    }
+
+    #[test]
+    fn malformed_script_reports_script_error_not_a_violation() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: 1_000,
+            max_memory_bytes: 1_000_000,
+            timeout_ms: 1_000,
+        });
+        let mut telemetry = telemetry();
+
+        let outcome = run_watched("let x = ;", &sandbox, HashMap::new(), &mut telemetry);
+
+        assert!(matches!(outcome, WatchdogOutcome::ScriptError(_)));
+        assert!(telemetry.events.is_empty());
+    }
+
+    #[test]
+    fn oversized_string_literal_is_rejected_by_memory_derived_cap() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: 1_000,
+            max_memory_bytes: 16,
+            timeout_ms: 1_000,
+        });
+        let mut telemetry = telemetry();
+        let script = format!("\"{}\"", "a".repeat(200));
+
+        let outcome = run_watched(&script, &sandbox, HashMap::new(), &mut telemetry);
+
+        assert!(matches!(outcome, WatchdogOutcome::ScriptError(_)));
+    }
+
+    #[test]
+    fn progress_hook_does_not_leak_into_the_next_run() {
+        let sandbox = sandbox(ExecutionLimits {
+            max_operations: 20,
+            max_memory_bytes: 1_000_000,
+            timeout_ms: 1_000,
+        });
+        let mut telemetry = telemetry();
+
+        let first = run_watched("let x = 0; loop { x += 1; }", &sandbox, HashMap::new(), &mut telemetry);
+        assert!(matches!(first, WatchdogOutcome::Violated(_)));
+
+        let second = run_watched("1 + 1", &sandbox, HashMap::new(), &mut telemetry);
+        match second {
+            WatchdogOutcome::Completed(value) => assert_eq!(value.as_int().unwrap(), 2),
+            other => panic!("expected Completed, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Pluggable telemetry export backends.
+//!
+//! [`TelemetryData`] only ever accumulated events in memory and printed a
+//! one-line summary once a minute — nothing shipped events anywhere durable
+//! or visible in-game. [`TelemetryExporter`] is a small sink trait with
+//! three implementations: [`JsonLinesExporter`] (a rotating JSON-lines file
+//! writer), [`OtlpExporter`] (ships events as OTLP-shaped JSON log records
+//! to a collector), and [`OverlayTelemetrySink`] (an in-memory ring buffer a
+//! debug overlay can read from as an ECS resource). [`TelemetryExportPipeline`]
+//! fans a batch of events out to however many exporters are configured, each
+//! filtered independently by an [`ExportFilter`].
+//!
+//! This follows the same policy-struct-per-subsystem pattern as
+//! [`crate::anti_cheat::AntiCheatPolicy`] rather than growing
+//! [`crate::SecurityConfig`] — export destinations are ops/ordnance
+//! concerns, not gameplay security toggles.
+
+use crate::{TelemetryEvent, TelemetrySeverity};
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+fn severity_rank(severity: &TelemetrySeverity) -> u8 {
+    match severity {
+        TelemetrySeverity::Info => 0,
+        TelemetrySeverity::Warning => 1,
+        TelemetrySeverity::Error => 2,
+        TelemetrySeverity::Critical => 3,
+    }
+}
+
+/// Per-exporter severity floor and sampling rate. `Error`/`Critical` events
+/// always pass; `Info`/`Warning` events are kept with probability
+/// `sample_rate` so a chatty low-severity stream doesn't flood a collector.
+#[derive(Clone, Debug)]
+pub struct ExportFilter {
+    pub min_severity: TelemetrySeverity,
+    pub sample_rate: f32,
+}
+
+impl Default for ExportFilter {
+    fn default() -> Self {
+        Self {
+            min_severity: TelemetrySeverity::Info,
+            sample_rate: 1.0,
+        }
+    }
+}
+
+impl ExportFilter {
+    pub fn allow(&self, event: &TelemetryEvent) -> bool {
+        if severity_rank(&event.severity) < severity_rank(&self.min_severity) {
+            return false;
+        }
+        if matches!(event.severity, TelemetrySeverity::Critical | TelemetrySeverity::Error) {
+            return true;
+        }
+        rand::random::<f32>() < self.sample_rate
+    }
+}
+
+/// A sink that telemetry events can be shipped to.
+pub trait TelemetryExporter: Send {
+    fn export(&mut self, events: &[TelemetryEvent]) -> Result<()>;
+}
+
+/// Appends events as newline-delimited JSON, rotating the file to
+/// `<path>.1` once it exceeds `max_bytes`.
+pub struct JsonLinesExporter {
+    path: PathBuf,
+    max_bytes: u64,
+    filter: ExportFilter,
+}
+
+impl JsonLinesExporter {
+    pub fn new(path: impl Into<PathBuf>, max_bytes: u64, filter: ExportFilter) -> Self {
+        Self {
+            path: path.into(),
+            max_bytes,
+            filter,
+        }
+    }
+
+    fn rotate_if_needed(&self) -> Result<()> {
+        if let Ok(meta) = std::fs::metadata(&self.path) {
+            if meta.len() >= self.max_bytes {
+                std::fs::rename(&self.path, self.path.with_extension("1.jsonl"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TelemetryExporter for JsonLinesExporter {
+    fn export(&mut self, events: &[TelemetryEvent]) -> Result<()> {
+        self.rotate_if_needed()?;
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        for event in events.iter().filter(|e| self.filter.allow(e)) {
+            writeln!(file, "{}", serde_json::to_string(event)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Ships events to an OTLP/HTTP-compatible collector as JSON log records.
+///
+/// This sends OTLP's JSON log-record shape over plain HTTP, not the full
+/// OTLP protobuf wire format — enough for a collector configured to accept
+/// `logs/v1` JSON, not a complete OTLP client.
+pub struct OtlpExporter {
+    endpoint: String,
+    client: reqwest::blocking::Client,
+    filter: ExportFilter,
+}
+
+impl OtlpExporter {
+    pub fn new(endpoint: impl Into<String>, filter: ExportFilter) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            client: reqwest::blocking::Client::new(),
+            filter,
+        }
+    }
+
+    fn to_log_record(event: &TelemetryEvent) -> serde_json::Value {
+        serde_json::json!({
+            "timeUnixNano": event.timestamp * 1_000_000_000,
+            "severityText": format!("{:?}", event.severity),
+            "body": { "stringValue": event.event_type },
+            "attributes": event.data,
+        })
+    }
+}
+
+impl TelemetryExporter for OtlpExporter {
+    fn export(&mut self, events: &[TelemetryEvent]) -> Result<()> {
+        let records: Vec<_> = events.iter().filter(|e| self.filter.allow(e)).map(Self::to_log_record).collect();
+        if records.is_empty() {
+            return Ok(());
+        }
+        let payload = serde_json::json!({
+            "resourceLogs": [{ "scopeLogs": [{ "logRecords": records }] }],
+        });
+        self.client.post(&self.endpoint).json(&payload).send()?.error_for_status()?;
+        Ok(())
+    }
+}
+
+/// An in-memory ring buffer an in-game debug overlay can read from directly
+/// as an ECS resource, without touching a file or network at all.
+pub struct OverlayTelemetrySink {
+    buffer: VecDeque<TelemetryEvent>,
+    capacity: usize,
+    filter: ExportFilter,
+}
+
+impl OverlayTelemetrySink {
+    pub fn new(capacity: usize, filter: ExportFilter) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            capacity: capacity.max(1),
+            filter,
+        }
+    }
+
+    pub fn recent(&self) -> impl Iterator<Item = &TelemetryEvent> {
+        self.buffer.iter()
+    }
+}
+
+impl TelemetryExporter for OverlayTelemetrySink {
+    fn export(&mut self, events: &[TelemetryEvent]) -> Result<()> {
+        for event in events.iter().filter(|e| self.filter.allow(e)) {
+            if self.buffer.len() == self.capacity {
+                self.buffer.pop_front();
+            }
+            self.buffer.push_back(event.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Fans newly-recorded telemetry events out to every configured exporter.
+/// One exporter failing doesn't stop the others; failures are collected and
+/// returned together once every exporter has had a chance to run.
+#[derive(Default)]
+pub struct TelemetryExportPipeline {
+    exporters: Vec<Box<dyn TelemetryExporter>>,
+    last_exported: usize,
+}
+
+impl TelemetryExportPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_exporter(&mut self, exporter: Box<dyn TelemetryExporter>) {
+        self.exporters.push(exporter);
+    }
+
+    /// Exports every event in `all_events` after the point reached by the
+    /// previous call. Intended to run before [`TelemetryData`]'s own
+    /// history trimming, since trimming invalidates the position tracked here.
+    pub fn export_new(&mut self, all_events: &[TelemetryEvent]) -> Result<()> {
+        if self.last_exported >= all_events.len() {
+            return Ok(());
+        }
+        let fresh = &all_events[self.last_exported..];
+        let mut errors = Vec::new();
+        for exporter in &mut self.exporters {
+            if let Err(err) = exporter.export(fresh) {
+                errors.push(err.to_string());
+            }
+        }
+        self.last_exported = all_events.len();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            anyhow::bail!("telemetry export errors: {}", errors.join("; "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(severity: TelemetrySeverity) -> TelemetryEvent {
+        TelemetryEvent {
+            timestamp: 0,
+            event_type: "test_event".to_string(),
+            severity,
+            data: serde_json::json!({}),
+        }
+    }
+
+    #[test]
+    fn export_filter_always_admits_critical_events() {
+        let filter = ExportFilter {
+            min_severity: TelemetrySeverity::Info,
+            sample_rate: 0.0,
+        };
+        assert!(filter.allow(&event(TelemetrySeverity::Critical)));
+    }
+
+    #[test]
+    fn export_filter_rejects_events_below_min_severity() {
+        let filter = ExportFilter {
+            min_severity: TelemetrySeverity::Error,
+            sample_rate: 1.0,
+        };
+        assert!(!filter.allow(&event(TelemetrySeverity::Info)));
+    }
+
+    #[test]
+    fn json_lines_exporter_appends_one_line_per_event() {
+        let dir = std::env::temp_dir().join("telemetry_export_jsonl");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("events.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let mut exporter = JsonLinesExporter::new(&path, 1_000_000, ExportFilter::default());
+        exporter.export(&[event(TelemetrySeverity::Info), event(TelemetrySeverity::Warning)]).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+    }
+
+    #[test]
+    fn json_lines_exporter_rotates_past_the_size_limit() {
+        let dir = std::env::temp_dir().join("telemetry_export_rotate");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("events.jsonl");
+        let rotated = path.with_extension("1.jsonl");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated);
+
+        let mut exporter = JsonLinesExporter::new(&path, 1, ExportFilter::default());
+        exporter.export(&[event(TelemetrySeverity::Info)]).unwrap();
+        exporter.export(&[event(TelemetrySeverity::Info)]).unwrap();
+
+        assert!(rotated.exists());
+    }
+
+    #[test]
+    fn overlay_sink_evicts_oldest_past_capacity() {
+        let mut sink = OverlayTelemetrySink::new(2, ExportFilter::default());
+        sink.export(&[event(TelemetrySeverity::Info), event(TelemetrySeverity::Info), event(TelemetrySeverity::Info)]).unwrap();
+
+        assert_eq!(sink.recent().count(), 2);
+    }
+
+    struct CountingExporter {
+        count: usize,
+    }
+
+    impl TelemetryExporter for CountingExporter {
+        fn export(&mut self, events: &[TelemetryEvent]) -> Result<()> {
+            self.count += events.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn pipeline_only_exports_events_new_since_the_last_call() {
+        let mut pipeline = TelemetryExportPipeline::new();
+        pipeline.add_exporter(Box::new(CountingExporter { count: 0 }));
+
+        let mut all_events = vec![event(TelemetrySeverity::Info)];
+        pipeline.export_new(&all_events).unwrap();
+        all_events.push(event(TelemetrySeverity::Info));
+        all_events.push(event(TelemetrySeverity::Info));
+        pipeline.export_new(&all_events).unwrap();
+
+        assert_eq!(pipeline.last_exported, 3);
+    }
+}
@@ -2,17 +2,25 @@
 //!
 //! Handles Steam initialization and the callback loop (heartbeat).
 //! **CRITICAL**: `update()` must be called every frame!
+//!
+//! Only compiled with the `steam` feature enabled, so gameplay crates that
+//! merely depend on `astraweave_steam::Platform` never link the Steamworks
+//! SDK.
 
 use anyhow::{anyhow, Result};
-use steamworks::Client;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use steamworks::{Client, GameOverlayActivated};
 
-use crate::platform::Platform;
+use crate::platform::{default_save_directory, Platform};
 
 /// Steam integration client
 ///
 /// Wraps the Steamworks SDK client and manages the callback loop.
 pub struct SteamIntegration {
     client: Client,
+    overlay_active: Arc<AtomicBool>,
 }
 
 impl SteamIntegration {
@@ -31,9 +39,18 @@ impl SteamIntegration {
     pub fn init() -> Result<Self> {
         let client = Client::init().map_err(|e| anyhow!("Steam initialization failed: {:?}", e))?;
 
+        let overlay_active = Arc::new(AtomicBool::new(false));
+        let overlay_flag = overlay_active.clone();
+        client.register_callback(move |event: GameOverlayActivated| {
+            overlay_flag.store(event.active, Ordering::Relaxed);
+        });
+
         tracing::info!("Steam initialized successfully");
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            overlay_active,
+        })
     }
 
     /// Update the Steam callbacks
@@ -121,6 +138,40 @@ impl Platform for SteamIntegration {
         self.client.friends().name()
     }
 
+    fn user_id(&self) -> String {
+        self.client.user().steam_id().raw().to_string()
+    }
+
+    fn set_rich_presence(&self, key: &str, value: &str) -> Result<()> {
+        let ok = self.client.friends().set_rich_presence(key, Some(value));
+        if ok {
+            Ok(())
+        } else {
+            Err(anyhow!("Failed to set rich presence key {}", key))
+        }
+    }
+
+    fn clear_rich_presence(&self) -> Result<()> {
+        self.client.friends().clear_rich_presence();
+        Ok(())
+    }
+
+    fn save_directory(&self) -> Result<PathBuf> {
+        // Steam Cloud mirrors whatever the game writes to its own local
+        // save directory; there's no separate Steamworks API for "give me
+        // the save path", so use the same convention as the null/dev path.
+        default_save_directory("saves")
+    }
+
+    fn activate_overlay(&self, dialog: &str) -> Result<()> {
+        self.client.friends().activate_game_overlay(dialog);
+        Ok(())
+    }
+
+    fn is_overlay_active(&self) -> bool {
+        self.overlay_active.load(Ordering::Relaxed)
+    }
+
     fn is_available(&self) -> bool {
         true
     }
@@ -1,14 +1,28 @@
 #![forbid(unsafe_code)]
 //! # AstraWeave Steam Integration
 //!
-//! Steamworks SDK integration for AstraWeave game engine.
+//! Platform services abstraction for AstraWeave, with a Steamworks SDK
+//! backend for PC/Steam builds.
 //!
 //! ## Features
 //!
-//! - **Achievements**: Track and unlock player achievements
+//! - **Achievements & Stats**: Track and unlock player achievements
 //! - **Cloud Saves**: Sync saves across devices via Steam Cloud
-//! - **Stats**: Track player statistics
-//! - **Platform Trait**: Testable abstraction for mocking
+//! - **Rich Presence**: Publish status for friends lists and overlays
+//! - **User Identity**: Query the platform-assigned user id and name
+//! - **Save Directories**: Resolve where local save files should live
+//! - **Overlay Hooks**: Open and query the platform overlay
+//! - **Platform Trait**: Testable abstraction so gameplay code never links
+//!   `steamworks` (or any future console SDK) directly
+//!
+//! ## Feature Flags
+//!
+//! - `steam` — links the real Steamworks SDK and enables
+//!   [`SteamIntegration`]. Off by default.
+//! - `mock` — enables `MockPlatform` for unit tests.
+//!
+//! With neither enabled, [`NullPlatform`] is still available for local dev
+//! builds that don't need platform services at all.
 //!
 //! ## Critical Usage Notes
 //!
@@ -22,13 +36,13 @@
 //! use astraweave_steam::{SteamIntegration, Platform};
 //!
 //! // Initialize (use 480 for testing)
-//! let steam = SteamIntegration::init(480)?;
+//! let steam = SteamIntegration::init()?;
 //!
 //! // Game loop
 //! loop {
 //!     // CRITICAL: Call every frame!
 //!     steam.update();
-//!     
+//!
 //!     // Use platform features
 //!     steam.unlock_achievement("first_blood")?;
 //! }
@@ -46,12 +60,14 @@
 //! platform.unlock_achievement("test").unwrap();
 //! ```
 
+#[cfg(feature = "steam")]
 pub mod client;
 pub mod platform;
 
 // Re-exports
+#[cfg(feature = "steam")]
 pub use client::SteamIntegration;
-pub use platform::Platform;
+pub use platform::{NullPlatform, Platform};
 
 #[cfg(any(test, feature = "mock"))]
 pub use platform::MockPlatform;
@@ -63,6 +79,7 @@ pub const TEST_APP_ID: u32 = 480;
 mod tests {
     use super::*;
 
+    #[cfg(feature = "steam")]
     #[test]
     fn test_exports() {
         // Verify exports compile - init takes no parameters
@@ -85,6 +102,13 @@ mod tests {
         assert!(platform.is_available());
     }
 
+    #[test]
+    fn test_null_platform_is_a_platform() {
+        let platform = NullPlatform::default();
+        assert!(!platform.is_available());
+        assert_eq!(platform.player_name(), "Player");
+    }
+
     #[test]
     fn test_test_app_id() {
         assert_eq!(TEST_APP_ID, 480);
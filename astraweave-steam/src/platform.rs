@@ -1,14 +1,20 @@
 //! Platform trait for testable Steam integration
 //!
-//! This trait defines the interface between the game and the platform (Steam).
-//! During testing, a MockPlatform can be used instead of the real Steam client.
+//! This trait defines the interface between the game and the platform
+//! (Steam, a console SDK, or nothing at all). Gameplay code should depend
+//! only on `Platform`, never on `steamworks` directly, so it keeps working
+//! unmodified when the `steam` feature is off or a console backend is
+//! swapped in. Use [`NullPlatform`] for local dev without Steam running,
+//! and `MockPlatform` (behind the `mock` feature) for unit tests.
 
 use anyhow::Result;
+use std::path::PathBuf;
 
-/// Platform abstraction for Steam features
+/// Platform abstraction covering achievements/stats, cloud saves, rich
+/// presence, user identity, save directories, and overlay hooks.
 ///
 /// Implement this trait to provide platform-specific functionality.
-/// Use `MockPlatform` for testing without Steam.
+/// Use [`NullPlatform`] for dev builds and `MockPlatform` for testing.
 pub trait Platform: Send + Sync {
     /// Unlock an achievement by name
     fn unlock_achievement(&self, name: &str) -> Result<()>;
@@ -37,10 +43,140 @@ pub trait Platform: Send + Sync {
     /// Get the player's display name
     fn player_name(&self) -> String;
 
+    /// Get the player's platform-assigned user id (e.g. a SteamID64), as a
+    /// string so callers aren't coupled to any one platform's id type.
+    fn user_id(&self) -> String;
+
+    /// Publish a rich-presence key/value pair (e.g. `"status"` ->
+    /// `"In the Ember Caves"`) for friends lists and overlays to display.
+    fn set_rich_presence(&self, key: &str, value: &str) -> Result<()>;
+
+    /// Clear all previously published rich-presence data.
+    fn clear_rich_presence(&self) -> Result<()>;
+
+    /// Directory this platform wants local save files written to, created
+    /// if it doesn't already exist.
+    fn save_directory(&self) -> Result<PathBuf>;
+
+    /// Ask the platform to open its overlay to `dialog` (e.g. `"friends"`,
+    /// `"achievements"`). A no-op where no overlay exists.
+    fn activate_overlay(&self, dialog: &str) -> Result<()>;
+
+    /// Whether the platform overlay is currently drawn over the game.
+    fn is_overlay_active(&self) -> bool;
+
     /// Check if the platform is available
     fn is_available(&self) -> bool;
 }
 
+/// Resolves (and creates) the local save directory games in this engine
+/// use when there's no platform-specific location to defer to.
+pub(crate) fn default_save_directory(app_name: &str) -> Result<PathBuf> {
+    let base = dirs::data_dir()
+        .ok_or_else(|| anyhow::anyhow!("could not determine platform data directory"))?;
+    let dir = base.join("AstraWeave").join(app_name);
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Does-nothing platform for dev builds that don't need Steam or a console
+/// SDK. Always compiled (unlike `MockPlatform`, which is test-only) so
+/// gameplay code has something to link against by default.
+pub struct NullPlatform {
+    player_name: String,
+}
+
+impl NullPlatform {
+    pub fn new(player_name: impl Into<String>) -> Self {
+        Self {
+            player_name: player_name.into(),
+        }
+    }
+}
+
+impl Default for NullPlatform {
+    fn default() -> Self {
+        Self::new("Player")
+    }
+}
+
+impl Platform for NullPlatform {
+    fn unlock_achievement(&self, name: &str) -> Result<()> {
+        tracing::debug!("[NullPlatform] Achievement unlocked: {}", name);
+        Ok(())
+    }
+
+    fn set_stat_i32(&self, name: &str, value: i32) -> Result<()> {
+        tracing::debug!("[NullPlatform] Stat {} = {}", name, value);
+        Ok(())
+    }
+
+    fn set_stat_f32(&self, name: &str, value: f32) -> Result<()> {
+        tracing::debug!("[NullPlatform] Stat {} = {}", name, value);
+        Ok(())
+    }
+
+    fn get_stat_i32(&self, _name: &str) -> Result<i32> {
+        Ok(0)
+    }
+
+    fn cloud_save(&self, filename: &str, data: &[u8]) -> Result<()> {
+        tracing::debug!(
+            "[NullPlatform] Cloud save skipped: {} ({} bytes)",
+            filename,
+            data.len()
+        );
+        Ok(())
+    }
+
+    fn cloud_load(&self, filename: &str) -> Result<Vec<u8>> {
+        tracing::debug!("[NullPlatform] Cloud load skipped: {}", filename);
+        Ok(vec![])
+    }
+
+    fn cloud_enabled(&self) -> bool {
+        false
+    }
+
+    fn store_stats(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn player_name(&self) -> String {
+        self.player_name.clone()
+    }
+
+    fn user_id(&self) -> String {
+        "0".to_string()
+    }
+
+    fn set_rich_presence(&self, key: &str, value: &str) -> Result<()> {
+        tracing::debug!("[NullPlatform] Rich presence {} = {}", key, value);
+        Ok(())
+    }
+
+    fn clear_rich_presence(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn save_directory(&self) -> Result<PathBuf> {
+        default_save_directory("dev-saves")
+    }
+
+    fn activate_overlay(&self, dialog: &str) -> Result<()> {
+        tracing::debug!("[NullPlatform] Overlay requested: {}", dialog);
+        Ok(())
+    }
+
+    fn is_overlay_active(&self) -> bool {
+        false
+    }
+
+    fn is_available(&self) -> bool {
+        false
+    }
+}
+
 /// Mock platform for testing without Steam
 #[cfg(any(test, feature = "mock"))]
 pub struct MockPlatform {
@@ -104,6 +240,33 @@ impl Platform for MockPlatform {
         self.player_name.clone()
     }
 
+    fn user_id(&self) -> String {
+        "76561197960287930".to_string()
+    }
+
+    fn set_rich_presence(&self, key: &str, value: &str) -> Result<()> {
+        tracing::info!("[MockPlatform] Rich presence {} = {}", key, value);
+        Ok(())
+    }
+
+    fn clear_rich_presence(&self) -> Result<()> {
+        tracing::info!("[MockPlatform] Rich presence cleared");
+        Ok(())
+    }
+
+    fn save_directory(&self) -> Result<PathBuf> {
+        default_save_directory("mock-saves")
+    }
+
+    fn activate_overlay(&self, dialog: &str) -> Result<()> {
+        tracing::info!("[MockPlatform] Overlay requested: {}", dialog);
+        Ok(())
+    }
+
+    fn is_overlay_active(&self) -> bool {
+        false
+    }
+
     fn is_available(&self) -> bool {
         true
     }
@@ -144,4 +307,47 @@ mod tests {
         let platform = MockPlatform::new("TestPlayer");
         assert!(platform.is_available());
     }
+
+    #[test]
+    fn test_mock_platform_rich_presence() {
+        let platform = MockPlatform::new("TestPlayer");
+        assert!(platform.set_rich_presence("status", "In battle").is_ok());
+        assert!(platform.clear_rich_presence().is_ok());
+    }
+
+    #[test]
+    fn test_mock_platform_user_id_nonempty() {
+        let platform = MockPlatform::new("TestPlayer");
+        assert!(!platform.user_id().is_empty());
+    }
+
+    #[test]
+    fn test_mock_platform_overlay() {
+        let platform = MockPlatform::new("TestPlayer");
+        assert!(platform.activate_overlay("friends").is_ok());
+        assert!(!platform.is_overlay_active());
+    }
+
+    #[test]
+    fn test_null_platform_is_never_available() {
+        let platform = NullPlatform::default();
+        assert!(!platform.is_available());
+        assert!(!platform.cloud_enabled());
+    }
+
+    #[test]
+    fn test_null_platform_stats_and_achievements_are_ok_noops() {
+        let platform = NullPlatform::new("DevPlayer");
+        assert!(platform.unlock_achievement("test").is_ok());
+        assert!(platform.set_stat_i32("kills", 1).is_ok());
+        assert_eq!(platform.get_stat_i32("kills").unwrap(), 0);
+        assert_eq!(platform.player_name(), "DevPlayer");
+    }
+
+    #[test]
+    fn test_null_platform_save_directory_is_created() {
+        let platform = NullPlatform::default();
+        let dir = platform.save_directory().unwrap();
+        assert!(dir.is_dir());
+    }
 }
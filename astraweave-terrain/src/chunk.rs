@@ -255,6 +255,11 @@ impl ChunkManager {
         self.chunks.len()
     }
 
+    /// Get the world-space size of one chunk
+    pub fn chunk_size(&self) -> f32 {
+        self.chunk_size
+    }
+
     /// Set the maximum number of loaded chunks
     pub fn set_max_loaded_chunks(&mut self, max_chunks: usize) {
         self.max_loaded_chunks = max_chunks;
@@ -19,6 +19,8 @@ pub mod meshing;
 pub mod noise_gen;
 pub mod noise_simd; // SIMD-optimized noise generation (Week 3 Action 8)
 pub mod partition_integration;
+#[cfg(feature = "physics-bridge")]
+pub mod physics_bridge;
 pub mod scatter;
 pub mod solver; // Phase 10: AI-Orchestrated Dynamic Terrain
 pub mod streaming_diagnostics; // Week 4 Action 14: Diagnostics overlay
@@ -51,6 +53,8 @@ pub use partition_integration::{
     PartitionCoord, VoxelPartitionConfig, VoxelPartitionEvent, VoxelPartitionManager,
     VoxelPartitionStats,
 };
+#[cfg(feature = "physics-bridge")]
+pub use physics_bridge::TerrainColliderManager;
 pub use scatter::{ScatterConfig, ScatterResult, VegetationInstance, VegetationScatter};
 pub use solver::{ResolvedLocation, SolverError, TerrainSolver, ValidationStatus};
 pub use streaming_diagnostics::{
@@ -0,0 +1,355 @@
+//! Streaming heightfield colliders for `astraweave-terrain` chunks.
+//!
+//! [`TerrainColliderManager`] mirrors a [`crate::ChunkManager`]'s
+//! load/unload lifecycle on the physics side: as chunks stream in it
+//! builds a heightfield collider for each one on a background thread (so
+//! terrain streaming never hitches `PhysicsWorld::step`) and inserts it
+//! via [`astraweave_physics::PhysicsWorld::add_static_heightfield`] once
+//! the build finishes; as chunks stream out it tears the corresponding
+//! collider back down. Border rows/columns are stitched against whichever
+//! neighbor chunks are already loaded so adjacent heightfields agree on
+//! their shared edge instead of leaving seams a character could catch on
+//! or fall through.
+//!
+//! This module lives in `astraweave-terrain` rather than
+//! `astraweave-physics` because it needs [`crate::ChunkManager`]/
+//! [`crate::ChunkId`], and `astraweave-physics` must not depend back on
+//! `astraweave-terrain` (which already depends on `astraweave-physics`
+//! transitively via `astraweave-gameplay`) or the package graph cycles.
+
+use crate::{ChunkId, ChunkManager};
+use astraweave_physics::{vector, BodyId, DMatrix, PhysicsWorld, Real, Vector};
+use glam::Vec3;
+use std::collections::HashMap;
+use std::thread::JoinHandle;
+
+/// Heights of the four immediate neighbors' shared edge, sampled from
+/// whichever of them happen to be loaded, used to stitch a chunk's
+/// heightfield so it matches up with its neighbors instead of leaving a
+/// seam at the chunk boundary.
+struct NeighborEdges {
+    /// Heights along the -X edge, taken from the neighbor's +X edge.
+    neg_x: Option<Vec<f32>>,
+    /// Heights along the +X edge, taken from the neighbor's -X edge.
+    pos_x: Option<Vec<f32>>,
+    /// Heights along the -Z edge, taken from the neighbor's +Z edge.
+    neg_z: Option<Vec<f32>>,
+    /// Heights along the +Z edge, taken from the neighbor's -Z edge.
+    pos_z: Option<Vec<f32>>,
+}
+
+/// A finished heightfield sample grid, ready to become a physics collider.
+/// Built off the main thread by [`build_heightfield`].
+struct BuiltHeightfield {
+    heights: Vec<f32>,
+    resolution: usize,
+    world_center: Vec3,
+    chunk_size: f32,
+}
+
+/// Streams heightfield colliders for terrain chunks in and out of a
+/// [`PhysicsWorld`], matching a [`ChunkManager`]'s own load/unload calls.
+pub struct TerrainColliderManager {
+    /// Bodies for chunks whose collider has already been inserted.
+    bodies: HashMap<ChunkId, BodyId>,
+    /// Chunks whose heightfield is being built on a background thread.
+    pending: Vec<(ChunkId, JoinHandle<BuiltHeightfield>)>,
+}
+
+impl Default for TerrainColliderManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerrainColliderManager {
+    /// Creates an empty manager with no colliders and nothing in flight.
+    pub fn new() -> Self {
+        Self {
+            bodies: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// True once `chunk_id`'s collider has been inserted into the physics
+    /// world (as opposed to still being built or not requested at all).
+    pub fn has_collider(&self, chunk_id: ChunkId) -> bool {
+        self.bodies.contains_key(&chunk_id)
+    }
+
+    /// The body backing `chunk_id`'s collider, if it has been inserted.
+    pub fn body_of(&self, chunk_id: ChunkId) -> Option<BodyId> {
+        self.bodies.get(&chunk_id).copied()
+    }
+
+    /// Number of heightfield builds currently running on background
+    /// threads, not yet inserted into the physics world.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Queues a heightfield build for `chunk_id` on a background thread.
+    /// Call [`Self::poll_ready`] on subsequent ticks to insert it into
+    /// `world` once the build finishes. No-op if `chunk_id` isn't loaded
+    /// in `chunks`, or if it's already queued or already has a collider.
+    pub fn queue_chunk(&mut self, chunks: &ChunkManager, chunk_id: ChunkId) {
+        if self.bodies.contains_key(&chunk_id)
+            || self.pending.iter().any(|(id, _)| *id == chunk_id)
+        {
+            return;
+        }
+        let Some(chunk) = chunks.get_chunk(chunk_id) else {
+            return;
+        };
+        let chunk_size = chunks.chunk_size();
+        let heightmap = chunk.heightmap();
+        let resolution = heightmap.resolution() as usize;
+        let data = heightmap.data().to_vec();
+        let world_center = chunk_id.to_center_pos(chunk_size);
+
+        let edges = NeighborEdges {
+            neg_x: chunks
+                .get_chunk(ChunkId::new(chunk_id.x - 1, chunk_id.z))
+                .map(|n| edge_column(n.heightmap().data(), resolution, resolution - 1)),
+            pos_x: chunks
+                .get_chunk(ChunkId::new(chunk_id.x + 1, chunk_id.z))
+                .map(|n| edge_column(n.heightmap().data(), resolution, 0)),
+            neg_z: chunks
+                .get_chunk(ChunkId::new(chunk_id.x, chunk_id.z - 1))
+                .map(|n| edge_row(n.heightmap().data(), resolution, resolution - 1)),
+            pos_z: chunks
+                .get_chunk(ChunkId::new(chunk_id.x, chunk_id.z + 1))
+                .map(|n| edge_row(n.heightmap().data(), resolution, 0)),
+        };
+
+        let handle = std::thread::spawn(move || {
+            build_heightfield(data, resolution, world_center, chunk_size, edges)
+        });
+        self.pending.push((chunk_id, handle));
+    }
+
+    /// Inserts colliders for any queued builds that have finished, and
+    /// returns the chunk IDs that were inserted this call.
+    pub fn poll_ready(&mut self, world: &mut PhysicsWorld) -> Vec<ChunkId> {
+        let mut ready_indices = Vec::new();
+        for (i, (_, handle)) in self.pending.iter().enumerate() {
+            if handle.is_finished() {
+                ready_indices.push(i);
+            }
+        }
+
+        let mut inserted = Vec::new();
+        for i in ready_indices.into_iter().rev() {
+            let (chunk_id, handle) = self.pending.remove(i);
+            let Ok(built) = handle.join() else {
+                continue;
+            };
+            let id = insert_heightfield(world, &built);
+            self.bodies.insert(chunk_id, id);
+            inserted.push(chunk_id);
+        }
+        inserted
+    }
+
+    /// Removes `chunk_id`'s collider from `world` (if inserted) and
+    /// abandons its build (if still in flight). Returns `true` if there
+    /// was anything to remove or abandon.
+    pub fn remove_chunk(&mut self, world: &mut PhysicsWorld, chunk_id: ChunkId) -> bool {
+        let had_pending = if let Some(i) = self.pending.iter().position(|(id, _)| *id == chunk_id)
+        {
+            self.pending.remove(i);
+            true
+        } else {
+            false
+        };
+
+        let had_body = if let Some(id) = self.bodies.remove(&chunk_id) {
+            world.remove_body(id);
+            true
+        } else {
+            false
+        };
+
+        had_pending || had_body
+    }
+}
+
+fn edge_row(data: &[f32], resolution: usize, z: usize) -> Vec<f32> {
+    data[z * resolution..z * resolution + resolution].to_vec()
+}
+
+fn edge_column(data: &[f32], resolution: usize, x: usize) -> Vec<f32> {
+    (0..resolution).map(|z| data[z * resolution + x]).collect()
+}
+
+/// Builds a heightfield sample grid for a chunk, overwriting its border
+/// with whichever neighbor edges are available so the two chunks agree
+/// exactly on their shared boundary.
+fn build_heightfield(
+    mut data: Vec<f32>,
+    resolution: usize,
+    world_center: Vec3,
+    chunk_size: f32,
+    edges: NeighborEdges,
+) -> BuiltHeightfield {
+    if let Some(neg_x) = &edges.neg_x {
+        for (z, &h) in neg_x.iter().enumerate() {
+            data[z * resolution] = h;
+        }
+    }
+    if let Some(pos_x) = &edges.pos_x {
+        for (z, &h) in pos_x.iter().enumerate() {
+            data[z * resolution + (resolution - 1)] = h;
+        }
+    }
+    if let Some(neg_z) = &edges.neg_z {
+        data[0..resolution].copy_from_slice(neg_z);
+    }
+    if let Some(pos_z) = &edges.pos_z {
+        let start = (resolution - 1) * resolution;
+        data[start..start + resolution].copy_from_slice(pos_z);
+    }
+
+    BuiltHeightfield {
+        heights: data,
+        resolution,
+        world_center,
+        chunk_size,
+    }
+}
+
+/// Inserts a finished heightfield build as a fixed body in `world`.
+fn insert_heightfield(world: &mut PhysicsWorld, built: &BuiltHeightfield) -> BodyId {
+    // `DMatrix::from_row_slice` lays `built.heights` out row-major, which
+    // matches `Heightmap::get_height`'s `z * resolution + x` indexing, so
+    // matrix rows follow Z and columns follow X.
+    let heights = DMatrix::from_row_slice(built.resolution, built.resolution, &built.heights);
+    let scale: Vector<Real> = vector![built.chunk_size, 1.0, built.chunk_size];
+    let translation: Vector<Real> = vector![built.world_center.x, 0.0, built.world_center.z];
+
+    world.add_static_heightfield(heights, scale, translation, 0.9)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiomeType, Heightmap, TerrainChunk};
+
+    const RESOLUTION: u32 = 5;
+    const CHUNK_SIZE: f32 = 16.0;
+
+    fn flat_chunk(id: ChunkId, height: f32) -> TerrainChunk {
+        let data = vec![height; (RESOLUTION * RESOLUTION) as usize];
+        let heightmap = Heightmap::from_data(data, RESOLUTION).unwrap();
+        let biome_map = vec![BiomeType::Grassland; (RESOLUTION * RESOLUTION) as usize];
+        TerrainChunk::new(id, heightmap, biome_map)
+    }
+
+    fn chunks_with(entries: &[(ChunkId, f32)]) -> ChunkManager {
+        let mut chunks = ChunkManager::new(CHUNK_SIZE, RESOLUTION);
+        for &(id, height) in entries {
+            chunks.add_chunk(flat_chunk(id, height));
+        }
+        chunks
+    }
+
+    #[test]
+    fn queue_and_poll_inserts_a_collider() {
+        let chunks = chunks_with(&[(ChunkId::new(0, 0), 3.0)]);
+        let mut mgr = TerrainColliderManager::new();
+        let mut world = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+
+        mgr.queue_chunk(&chunks, ChunkId::new(0, 0));
+        assert_eq!(mgr.pending_count(), 1);
+
+        // The background thread may take a moment; poll until it lands.
+        let mut inserted = Vec::new();
+        for _ in 0..200 {
+            inserted = mgr.poll_ready(&mut world);
+            if !inserted.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        assert_eq!(inserted, vec![ChunkId::new(0, 0)]);
+        assert!(mgr.has_collider(ChunkId::new(0, 0)));
+        assert_eq!(mgr.pending_count(), 0);
+        assert!(world
+            .handle_of(mgr.body_of(ChunkId::new(0, 0)).unwrap())
+            .is_some());
+    }
+
+    #[test]
+    fn queue_chunk_is_a_noop_for_an_unknown_chunk() {
+        let chunks = ChunkManager::new(CHUNK_SIZE, RESOLUTION);
+        let mut mgr = TerrainColliderManager::new();
+
+        mgr.queue_chunk(&chunks, ChunkId::new(7, 7));
+
+        assert_eq!(mgr.pending_count(), 0);
+    }
+
+    #[test]
+    fn queue_chunk_does_not_duplicate_an_already_pending_or_inserted_chunk() {
+        let chunks = chunks_with(&[(ChunkId::new(0, 0), 3.0)]);
+        let mut mgr = TerrainColliderManager::new();
+
+        mgr.queue_chunk(&chunks, ChunkId::new(0, 0));
+        mgr.queue_chunk(&chunks, ChunkId::new(0, 0));
+
+        assert_eq!(mgr.pending_count(), 1);
+    }
+
+    #[test]
+    fn remove_chunk_tears_down_an_inserted_collider() {
+        let chunks = chunks_with(&[(ChunkId::new(0, 0), 3.0)]);
+        let mut mgr = TerrainColliderManager::new();
+        let mut world = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+
+        mgr.queue_chunk(&chunks, ChunkId::new(0, 0));
+        for _ in 0..200 {
+            if !mgr.poll_ready(&mut world).is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        let body = mgr.body_of(ChunkId::new(0, 0)).unwrap();
+
+        assert!(mgr.remove_chunk(&mut world, ChunkId::new(0, 0)));
+
+        assert!(!mgr.has_collider(ChunkId::new(0, 0)));
+        assert!(world.handle_of(body).is_none());
+    }
+
+    #[test]
+    fn remove_chunk_cancels_a_still_pending_build() {
+        let chunks = chunks_with(&[(ChunkId::new(0, 0), 3.0)]);
+        let mut mgr = TerrainColliderManager::new();
+        let mut world = PhysicsWorld::new(Vec3::new(0.0, -9.8, 0.0));
+
+        mgr.queue_chunk(&chunks, ChunkId::new(0, 0));
+        assert!(mgr.remove_chunk(&mut world, ChunkId::new(0, 0)));
+
+        assert_eq!(mgr.pending_count(), 0);
+        assert!(!mgr.has_collider(ChunkId::new(0, 0)));
+    }
+
+    #[test]
+    fn build_heightfield_stitches_shared_edges_from_neighbors() {
+        let resolution = 3usize;
+        let data = vec![0.0; resolution * resolution];
+        let edges = NeighborEdges {
+            neg_x: Some(vec![1.0, 2.0, 3.0]),
+            pos_x: None,
+            neg_z: None,
+            pos_z: None,
+        };
+
+        let built = build_heightfield(data, resolution, Vec3::ZERO, CHUNK_SIZE, edges);
+
+        for (z, &expected) in [1.0, 2.0, 3.0].iter().enumerate() {
+            assert_eq!(built.heights[z * resolution], expected);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Global time control: pause, slow-motion, and independent per-group time
+//! scales, with smooth ramping for bullet-time-style effects.
+//!
+//! Fixed-timestep runners, physics steps, animation sampling, and particle
+//! systems are expected to call [`TimeService::tick`] once per real-time
+//! frame with the unscaled frame delta, then scale their own `dt` via
+//! [`TimeService::scaled_dt`] (or [`TimeService::group_scale`]) instead of
+//! reading wall-clock time directly.
+
+use std::collections::HashMap;
+
+/// A named channel with its own time scale, independent of the others.
+/// [`TimeGroup::World`] drives gameplay simulation (physics, AI, animation,
+/// particles); [`TimeGroup::Ui`] and [`TimeGroup::Audio`] are unaffected by
+/// [`TimeService::set_paused`] by default so menus stay interactive and
+/// voice-over doesn't crawl during bullet time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeGroup {
+    World,
+    Ui,
+    Audio,
+}
+
+/// A time scale that can smoothly ramp from one value to another over real
+/// time, rather than snapping.
+#[derive(Clone, Copy, Debug)]
+struct ScaleRamp {
+    current: f32,
+    start: f32,
+    target: f32,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl ScaleRamp {
+    fn settled(scale: f32) -> Self {
+        Self {
+            current: scale,
+            start: scale,
+            target: scale,
+            duration: 0.0,
+            elapsed: 0.0,
+        }
+    }
+
+    fn ramp_to(&mut self, target: f32, duration: f32) {
+        self.start = self.current;
+        self.target = target;
+        self.duration = duration;
+        self.elapsed = 0.0;
+    }
+
+    fn advance(&mut self, real_dt: f32) {
+        if self.duration <= 0.0 {
+            self.current = self.target;
+            return;
+        }
+        self.elapsed = (self.elapsed + real_dt).min(self.duration);
+        let t = self.elapsed / self.duration;
+        self.current = self.start + (self.target - self.start) * t;
+    }
+}
+
+/// Global time control service. Owns a master pause flag plus independent
+/// scales per [`TimeGroup`].
+pub struct TimeService {
+    paused: bool,
+    scales: HashMap<TimeGroup, ScaleRamp>,
+}
+
+impl Default for TimeService {
+    fn default() -> Self {
+        let mut scales = HashMap::new();
+        scales.insert(TimeGroup::World, ScaleRamp::settled(1.0));
+        scales.insert(TimeGroup::Ui, ScaleRamp::settled(1.0));
+        scales.insert(TimeGroup::Audio, ScaleRamp::settled(1.0));
+        Self {
+            paused: false,
+            scales,
+        }
+    }
+}
+
+impl TimeService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pausing zeroes [`TimeGroup::World`]'s effective scale but leaves
+    /// [`TimeGroup::Ui`] and [`TimeGroup::Audio`] running, so pause menus
+    /// and their sound effects keep working.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn toggle_paused(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Immediately sets `group`'s scale, cancelling any in-progress ramp.
+    /// Negative values are clamped to zero.
+    pub fn set_scale(&mut self, group: TimeGroup, scale: f32) {
+        self.scales.insert(group, ScaleRamp::settled(scale.max(0.0)));
+    }
+
+    /// Smoothly ramps `group`'s scale to `target` over `duration` seconds of
+    /// real (unscaled) time, for bullet-time ease-in/out. A non-positive
+    /// `duration` applies `target` immediately, same as [`Self::set_scale`].
+    pub fn ramp_scale(&mut self, group: TimeGroup, target: f32, duration: f32) {
+        let target = target.max(0.0);
+        if duration <= 0.0 {
+            self.set_scale(group, target);
+            return;
+        }
+        self.scales
+            .entry(group)
+            .or_insert_with(|| ScaleRamp::settled(1.0))
+            .ramp_to(target, duration);
+    }
+
+    /// Advances all in-progress ramps by `real_dt` seconds of unscaled time.
+    pub fn tick(&mut self, real_dt: f32) {
+        for ramp in self.scales.values_mut() {
+            ramp.advance(real_dt);
+        }
+    }
+
+    /// The current effective scale for `group`, accounting for pause and
+    /// any in-progress ramp.
+    pub fn group_scale(&self, group: TimeGroup) -> f32 {
+        if self.paused && group == TimeGroup::World {
+            return 0.0;
+        }
+        self.scales.get(&group).map(|r| r.current).unwrap_or(1.0)
+    }
+
+    pub fn world_scale(&self) -> f32 {
+        self.group_scale(TimeGroup::World)
+    }
+
+    pub fn ui_scale(&self) -> f32 {
+        self.group_scale(TimeGroup::Ui)
+    }
+
+    pub fn audio_scale(&self) -> f32 {
+        self.group_scale(TimeGroup::Audio)
+    }
+
+    /// Convenience for subsystems: scales `real_dt` by `group`'s current
+    /// effective time scale.
+    pub fn scaled_dt(&self, group: TimeGroup, real_dt: f32) -> f32 {
+        real_dt * self.group_scale(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_unscaled_and_unpaused() {
+        let time = TimeService::new();
+        assert!(!time.is_paused());
+        assert_eq!(time.world_scale(), 1.0);
+        assert_eq!(time.ui_scale(), 1.0);
+        assert_eq!(time.audio_scale(), 1.0);
+    }
+
+    #[test]
+    fn pause_zeroes_world_scale_but_not_ui_or_audio() {
+        let mut time = TimeService::new();
+        time.set_paused(true);
+        assert_eq!(time.world_scale(), 0.0);
+        assert_eq!(time.ui_scale(), 1.0);
+        assert_eq!(time.audio_scale(), 1.0);
+
+        time.set_paused(false);
+        assert_eq!(time.world_scale(), 1.0);
+    }
+
+    #[test]
+    fn set_scale_takes_effect_immediately() {
+        let mut time = TimeService::new();
+        time.set_scale(TimeGroup::World, 0.25);
+        assert_eq!(time.world_scale(), 0.25);
+        assert_eq!(time.scaled_dt(TimeGroup::World, 1.0 / 60.0), 0.25 / 60.0);
+    }
+
+    #[test]
+    fn set_scale_clamps_negative_to_zero() {
+        let mut time = TimeService::new();
+        time.set_scale(TimeGroup::World, -2.0);
+        assert_eq!(time.world_scale(), 0.0);
+    }
+
+    #[test]
+    fn ramp_scale_interpolates_over_duration() {
+        let mut time = TimeService::new();
+        time.ramp_scale(TimeGroup::World, 0.2, 1.0);
+        assert_eq!(time.world_scale(), 1.0, "no time has passed yet");
+
+        time.tick(0.5);
+        let halfway = time.world_scale();
+        assert!(
+            (halfway - 0.6).abs() < 1e-4,
+            "expected halfway between 1.0 and 0.2, got {halfway}"
+        );
+
+        time.tick(0.5);
+        assert!((time.world_scale() - 0.2).abs() < 1e-4);
+
+        // Further ticks should hold at the target, not overshoot.
+        time.tick(1.0);
+        assert!((time.world_scale() - 0.2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ramp_scale_with_zero_duration_is_immediate() {
+        let mut time = TimeService::new();
+        time.ramp_scale(TimeGroup::Audio, 0.5, 0.0);
+        assert_eq!(time.audio_scale(), 0.5);
+    }
+
+    #[test]
+    fn groups_ramp_independently() {
+        let mut time = TimeService::new();
+        time.ramp_scale(TimeGroup::World, 0.0, 1.0);
+        time.tick(0.5);
+        assert!((time.world_scale() - 0.5).abs() < 1e-4);
+        assert_eq!(time.ui_scale(), 1.0);
+        assert_eq!(time.audio_scale(), 1.0);
+    }
+}